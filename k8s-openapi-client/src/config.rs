@@ -0,0 +1,162 @@
+/// The resolved configuration for connecting to an API server.
+///
+/// All certificates and keys are PEM-encoded. A config is usually loaded from a kubeconfig with [`Config::from_kubeconfig`](#method.from_kubeconfig),
+/// but it can also be constructed directly.
+#[derive(Clone, Debug)]
+pub struct Config {
+	/// The URL of the API server, like `https://127.0.0.1:6443`. It must not have a path.
+	pub server: ::http::Uri,
+
+	/// The certificate of the CA that signed the API server's certificate, if it isn't signed by a CA that is already trusted.
+	pub certificate_authority: Option<Vec<u8>>,
+
+	/// Whether the API server's certificate is accepted without being validated. This is insecure.
+	pub accept_invalid_certs: bool,
+
+	/// The certificate and private key to authenticate with, if any.
+	pub client_certificate: Option<ClientCertificate>,
+
+	/// The credentials sent in the `Authorization` header of every request, if any.
+	pub authentication: Option<Authentication>,
+
+	/// The namespace to use by default.
+	pub namespace: String,
+}
+
+/// The directory where the service account's token, CA certificate and namespace are mounted in a pod.
+pub const SERVICE_ACCOUNT_DIR: &str = "/var/run/secrets/kubernetes.io/serviceaccount";
+
+/// A PEM-encoded client certificate and its private key.
+#[derive(Clone)]
+pub struct ClientCertificate {
+	/// The certificate.
+	pub certificate: Vec<u8>,
+
+	/// The private key of the certificate.
+	pub key: Vec<u8>,
+}
+
+impl ::std::fmt::Debug for ClientCertificate {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		// Don't print the private key
+		f.debug_struct("ClientCertificate")
+			.field("certificate", &String::from_utf8_lossy(&self.certificate))
+			.field("key", &"...")
+			.finish()
+	}
+}
+
+/// The credentials sent in the `Authorization` header of every request.
+#[derive(Clone)]
+pub enum Authentication {
+	/// A bearer token.
+	Token(String),
+
+	/// A username and password for basic authentication.
+	Basic {
+		username: String,
+		password: String,
+	},
+}
+
+impl Authentication {
+	/// The value of the `Authorization` header for these credentials.
+	pub(crate) fn header_value(&self) -> String {
+		match self {
+			Authentication::Token(token) => format!("Bearer {}", token),
+			Authentication::Basic { username, password } => format!("Basic {}", ::base64::encode(&format!("{}:{}", username, password))),
+		}
+	}
+}
+
+impl ::std::fmt::Debug for Authentication {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		// Don't print the token or password
+		match self {
+			Authentication::Token(_) => f.debug_tuple("Token").field(&"...").finish(),
+			Authentication::Basic { username, .. } => f.debug_struct("Basic").field("username", username).field("password", &"...").finish(),
+		}
+	}
+}
+
+impl Config {
+	/// Loads the config from the kubeconfig, merging the files listed in the `KUBECONFIG` environment variable if it's set,
+	/// or from `~/.kube/config` otherwise. See [`KubeConfig::load`](kubeconfig/struct.KubeConfig.html#method.load).
+	///
+	/// The config is that of the given context, or of the kubeconfig's current context if `None`.
+	pub fn from_kubeconfig(context: Option<&str>) -> Result<Self, ::Error> {
+		::kubeconfig::KubeConfig::load()?.resolve(context)
+	}
+
+	/// Loads the config from the kubeconfig at the given path.
+	///
+	/// The config is that of the given context, or of the kubeconfig's current context if `None`.
+	/// Relative paths of certificates and keys in the kubeconfig are resolved relative to the directory of the kubeconfig.
+	pub fn from_kubeconfig_file(path: &::std::path::Path, context: Option<&str>) -> Result<Self, ::Error> {
+		::kubeconfig::KubeConfig::from_file(path)?.resolve(context)
+	}
+
+	/// Loads the config of a pod's service account, for a client that runs inside the cluster.
+	///
+	/// The API server is the one in the `KUBERNETES_SERVICE_HOST` and `KUBERNETES_SERVICE_PORT` environment variables,
+	/// and the token, CA certificate and namespace are read from the files in [`SERVICE_ACCOUNT_DIR`](constant.SERVICE_ACCOUNT_DIR.html).
+	pub fn in_cluster() -> Result<Self, ::Error> {
+		Config::in_cluster_from(::std::path::Path::new(SERVICE_ACCOUNT_DIR))
+	}
+
+	/// Like [`Config::in_cluster`](#method.in_cluster), but reads the service account's files from the given directory.
+	///
+	/// The directory must contain a `token` file. The `ca.crt` and `namespace` files are used if they exist.
+	pub fn in_cluster_from(service_account_dir: &::std::path::Path) -> Result<Self, ::Error> {
+		let (host, port) = match (::std::env::var("KUBERNETES_SERVICE_HOST"), ::std::env::var("KUBERNETES_SERVICE_PORT")) {
+			(Ok(host), Ok(port)) => (host, port),
+			_ => return Err(::Error::Config("KUBERNETES_SERVICE_HOST and KUBERNETES_SERVICE_PORT must be set to use the in-cluster config".to_string())),
+		};
+
+		let server =
+			if host.contains(':') {
+				// IPv6 address
+				format!("https://[{}]:{}", host, port)
+			}
+			else {
+				format!("https://{}:{}", host, port)
+			};
+
+		let token = ::std::fs::read_to_string(service_account_dir.join("token")).map_err(::Error::Io)?.trim().to_string();
+		let certificate_authority = read_if_exists(&service_account_dir.join("ca.crt"))?;
+		let namespace = match read_if_exists(&service_account_dir.join("namespace"))? {
+			Some(namespace) => String::from_utf8(namespace).map_err(|err| ::Error::Config(format!("service account namespace is invalid: {}", err)))?.trim().to_string(),
+			None => "default".to_string(),
+		};
+
+		Ok(Config {
+			server: Config::parse_server(&server)?,
+			certificate_authority,
+			accept_invalid_certs: false,
+			client_certificate: None,
+			authentication: Some(Authentication::Token(token)),
+			namespace,
+		})
+	}
+
+	/// Checks that the server URL doesn't have a path, since the paths of requests are appended to it.
+	pub(crate) fn parse_server(server: &str) -> Result<::http::Uri, ::Error> {
+		let server: ::http::Uri = server.parse().map_err(|err: ::http::uri::InvalidUri| ::Error::Http(err.into()))?;
+		if let Some(path_and_query) = server.path_and_query() {
+			if path_and_query != "/" {
+				return Err(::Error::Config(format!("server URL {} has path and query {}", server, path_and_query)));
+			}
+		}
+
+		Ok(server)
+	}
+}
+
+/// Returns the contents of the given file, or `None` if it doesn't exist.
+fn read_if_exists(path: &::std::path::Path) -> Result<Option<Vec<u8>>, ::Error> {
+	match ::std::fs::read(path) {
+		Ok(contents) => Ok(Some(contents)),
+		Err(ref err) if err.kind() == ::std::io::ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(::Error::Io(err)),
+	}
+}
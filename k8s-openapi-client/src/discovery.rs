@@ -0,0 +1,69 @@
+//! Caching the result of discovering the resources served by the API server.
+//!
+//! Discovery takes one request per group version, so a client that maps kinds to resources repeatedly, like one that applies manifests,
+//! should discover once and reuse the result. A [`DiscoveryCache`](struct.DiscoveryCache.html) holds the `RestMapper` returned by
+//! [`Client::discover`](../struct.Client.html#method.discover) and discovers again once it's older than the cache's time-to-live,
+//! so that resources added later, like those of new custom resource definitions, are picked up.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! extern crate k8s_openapi_client;
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let config = k8s_openapi_client::Config::from_kubeconfig(None)?;
+//!     let client = k8s_openapi_client::Client::new(&config)?;
+//!
+//!     let mut cache = k8s_openapi_client::discovery::DiscoveryCache::new(std::time::Duration::from_secs(600));
+//!
+//!     let rest_mapper = cache.rest_mapper(&client)?;
+//!     if let Some(deployments) = rest_mapper.resource_for("apps", "Deployment") {
+//!         println!("{} {}", deployments.api_version(), deployments.plural);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// Caches the `RestMapper` of the API server for a fixed time. See the [module docs](index.html) for details.
+#[derive(Debug)]
+pub struct DiscoveryCache {
+	ttl: std::time::Duration,
+	cached: Option<(std::time::Instant, k8s_openapi::discovery::RestMapper)>,
+}
+
+impl DiscoveryCache {
+	/// Constructs an empty cache whose `RestMapper` is discovered again once it's older than the given time-to-live.
+	pub fn new(ttl: std::time::Duration) -> Self {
+		DiscoveryCache {
+			ttl,
+			cached: None,
+		}
+	}
+
+	/// Returns the cached `RestMapper`, after discovering it with the given client if the cache is empty or stale.
+	///
+	/// If discovery fails, the stale `RestMapper` is discarded and the error is returned.
+	pub fn rest_mapper(&mut self, client: &::Client) -> Result<&k8s_openapi::discovery::RestMapper, ::Error> {
+		let now = std::time::Instant::now();
+
+		let is_fresh = match &self.cached {
+			Some((discovered_at, _)) => now.duration_since(*discovered_at) < self.ttl,
+			None => false,
+		};
+
+		if !is_fresh {
+			self.cached = None;
+			self.cached = Some((now, client.discover()?));
+		}
+
+		Ok(&self.cached.as_ref().expect("cached was just set").1)
+	}
+
+	/// Empties the cache, so that the next call to [`rest_mapper`](#method.rest_mapper) discovers again.
+	///
+	/// This is useful after creating a custom resource definition, or when the server says that a resource from the cached `RestMapper` doesn't exist.
+	pub fn invalidate(&mut self) {
+		self.cached = None;
+	}
+}
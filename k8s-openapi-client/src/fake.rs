@@ -0,0 +1,779 @@
+//! An in-memory fake of the API server, for unit-testing code that uses the API functions of `k8s-openapi` without a cluster.
+//!
+//! [`ApiServer::execute`](struct.ApiServer.html#method.execute) takes the `http::Request` returned by an API function and returns
+//! the `http::Response` that the API server would have returned, which can then be parsed with `k8s_openapi::Response::try_from_parts`
+//! like a response from a real server.
+//!
+//! The fake doesn't know the schemas of any resources. It stores objects as JSON under the path they were created at,
+//! so it works with any path that the API functions use, like `/api/v1/namespaces/{namespace}/pods/{name}`
+//! or `/apis/apps/v1/deployments`. It implements:
+//!
+//! - create (`POST`), with `metadata.generateName`, and `409 Conflict` for objects that already exist.
+//! - read (`GET`), and `404 Not Found` for objects that don't exist.
+//! - replace (`PUT`), with `409 Conflict` if the object's `metadata.resourceVersion` is stale.
+//! - patch (`PATCH`), as a JSON patch if the request's `Content-Type` is `application/json-patch+json`, and as a JSON merge patch otherwise.
+//! - delete (`DELETE`) of an object or of a collection.
+//! - list (`GET`), across all namespaces or in one namespace, with the `labelSelector`, `fieldSelector`, `limit` and `continue` parameters.
+//! - watch (`GET` with `watch=true` or a `/watch/` path), with the `resourceVersion` parameter.
+//!
+//! Every change to an object increments a single resource version counter, like the API server's etcd revision. `status` subresources
+//! are read and replaced as the `status` field of their object, and other subresources aren't supported. Since a request is executed
+//! synchronously, a watch response contains the events that have already happened and then ends, as if the watch timed out.
+//!
+//! The fake doesn't validate objects, doesn't require their namespaces to exist, and doesn't implement defaulting, finalizers, garbage collection,
+//! or the conversion of objects between the API versions of a resource. Objects created at `/apis/apps/v1beta2/...` aren't visible at `/apis/apps/v1/...`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use] extern crate k8s_openapi;
+//! extern crate k8s_openapi_client;
+//!
+//! use k8s_openapi::api::core::v1 as api;
+//! use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let api_server = k8s_openapi_client::fake::ApiServer::new();
+//!
+//!     let config_map = api::ConfigMap {
+//!         metadata: Some(meta::ObjectMeta {
+//!             name: Some("foo".to_string()),
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     };
+//!     let request = api::ConfigMap::create_core_v1_namespaced_config_map("default", &config_map, None)?;
+//!     let response = api_server.execute(request);
+//!     assert_eq!(response.status(), k8s_openapi::http::StatusCode::CREATED);
+//!
+//!     let request = api::ConfigMap::read_core_v1_namespaced_config_map("foo", "default", None, None, None)?;
+//!     let response = api_server.execute(request);
+//!     let (response, _) = k8s_openapi::Response::try_from_parts(response.status(), response.body())?;
+//!     match response {
+//!         api::ReadCoreV1NamespacedConfigMapResponse::Ok(config_map) => {
+//!             let metadata = config_map.metadata.unwrap();
+//!             assert_eq!(metadata.namespace.unwrap(), "default");
+//!             assert_eq!(metadata.resource_version.unwrap(), "1");
+//!         },
+//!         other => return Err(format!("unexpected response {:?}", other).into()),
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use serde_json::{ self, Value };
+
+/// An in-memory fake of the API server. See the [module docs](index.html) for details.
+///
+/// Requests can be executed from multiple threads.
+#[derive(Debug, Default)]
+pub struct ApiServer {
+	state: std::sync::Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+	resource_version: u64,
+	objects: std::collections::BTreeMap<Key, Value>,
+	kinds: std::collections::BTreeMap<(String, String), String>,
+	events: Vec<Event>,
+}
+
+/// The API group version (like `v1` or `apps/v1`), plural name and namespace of a collection of objects.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Collection {
+	group_version: String,
+	plural: String,
+	namespace: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Key {
+	collection: Collection,
+	name: String,
+}
+
+#[derive(Debug)]
+struct Event {
+	type_: &'static str,
+	key: Key,
+	object: Value,
+	resource_version: u64,
+}
+
+/// The collection, object and subresource that a request's path refers to.
+struct Target {
+	collection: Collection,
+	name: Option<String>,
+	subresource: Option<String>,
+	watch: bool,
+}
+
+impl ApiServer {
+	/// Constructs a fake API server with no objects.
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Executes the given request, like one returned by an API function of `k8s-openapi`, and returns the response.
+	pub fn execute(&self, request: ::http::Request<Vec<u8>>) -> ::http::Response<Vec<u8>> {
+		let (status_code, body) = match self.execute_inner(&request) {
+			Ok((status_code, body)) => (status_code, body),
+			Err(status) => (status.code, status.into_body()),
+		};
+
+		let mut response = ::http::Response::new(body);
+		*response.status_mut() = status_code;
+		response.headers_mut().insert(::http::header::CONTENT_TYPE, ::http::header::HeaderValue::from_static("application/json"));
+		response
+	}
+
+	fn execute_inner(&self, request: &::http::Request<Vec<u8>>) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let target = parse_path(request.uri().path()).ok_or_else(|| Status::not_found_path(request.uri().path()))?;
+		let query: std::collections::BTreeMap<String, String> =
+			::url::form_urlencoded::parse(request.uri().query().unwrap_or("").as_bytes())
+			.into_owned()
+			.collect();
+
+		let mut state = self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+
+		let method = request.method();
+		match (target.name, target.subresource.as_deref()) {
+			(_, Some(subresource)) if subresource != "status" =>
+				Err(Status::not_found(format!("the server could not find the requested resource ({} {})", target.collection.plural, subresource))),
+
+			(None, None) if method == ::http::Method::GET && (target.watch || is_true(query.get("watch"))) =>
+				state.watch(&target.collection, None, &query),
+			(None, None) if method == ::http::Method::GET => state.list(&target.collection, &query),
+			(None, None) if method == ::http::Method::POST => state.create(target.collection, parse_body(request.body())?),
+			(None, None) if method == ::http::Method::DELETE => state.delete_collection(&target.collection, &query),
+
+			(Some(name), _) if method == ::http::Method::GET && (target.watch || is_true(query.get("watch"))) =>
+				state.watch(&target.collection, Some(&name), &query),
+			(Some(name), _) if method == ::http::Method::GET => state.read(Key { collection: target.collection, name }),
+			(Some(name), subresource) if method == ::http::Method::PUT =>
+				state.replace(Key { collection: target.collection, name }, subresource.is_some(), parse_body(request.body())?),
+			(Some(name), subresource) if method == ::http::Method::PATCH => {
+				let content_type = request.headers().get(::http::header::CONTENT_TYPE).and_then(|value| value.to_str().ok());
+				state.patch(Key { collection: target.collection, name }, subresource.is_some(), content_type, parse_body(request.body())?)
+			},
+			(Some(name), None) if method == ::http::Method::DELETE => state.delete(Key { collection: target.collection, name }),
+
+			_ => Err(Status::new(
+				::http::StatusCode::METHOD_NOT_ALLOWED, "MethodNotAllowed",
+				format!("the server does not allow this method on the requested resource ({} {})", method, request.uri().path()))),
+		}
+	}
+}
+
+impl State {
+	fn create(&mut self, collection: Collection, mut object: Value) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let (name, kind) = {
+			let metadata = metadata_mut(&mut object)?;
+
+			match (metadata.get("namespace").and_then(Value::as_str), &collection.namespace) {
+				(Some(namespace), Some(expected)) if namespace != expected =>
+					return Err(Status::new(
+						::http::StatusCode::BAD_REQUEST, "BadRequest",
+						"the namespace of the provided object does not match the namespace sent on the request".to_string())),
+				(_, Some(namespace)) => { metadata.insert("namespace".to_string(), Value::String(namespace.clone())); },
+				(_, None) => (),
+			}
+
+			let name = match (metadata.get("name").and_then(Value::as_str), metadata.get("generateName").and_then(Value::as_str)) {
+				(Some(name), _) if !name.is_empty() => name.to_string(),
+				(_, Some(generate_name)) if !generate_name.is_empty() => format!("{}{:05x}", generate_name, self.resource_version + 1),
+				_ => return Err(Status::invalid(collection.plural.clone(), String::new(), "metadata.name: Required value: name or generateName is required")),
+			};
+			metadata.insert("name".to_string(), Value::String(name.clone()));
+
+			(name, object.get("kind").and_then(Value::as_str).map(ToString::to_string))
+		};
+
+		let key = Key { collection, name };
+		if self.objects.contains_key(&key) {
+			return Err(Status::already_exists(&key));
+		}
+
+		if let Some(kind) = kind {
+			self.kinds.insert((key.collection.group_version.clone(), key.collection.plural.clone()), kind);
+		}
+
+		let resource_version = self.next_resource_version();
+		{
+			let metadata = metadata_mut(&mut object)?;
+			metadata.insert("uid".to_string(), Value::String(format!("00000000-0000-0000-0000-{:012x}", resource_version)));
+			metadata.insert("resourceVersion".to_string(), Value::String(resource_version.to_string()));
+			metadata.insert(
+				"creationTimestamp".to_string(),
+				Value::String(::k8s_openapi::chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()));
+		}
+
+		self.objects.insert(key.clone(), object.clone());
+		self.events.push(Event { type_: "ADDED", key, object: object.clone(), resource_version });
+
+		Ok((::http::StatusCode::CREATED, to_vec(&object)))
+	}
+
+	fn read(&self, key: Key) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		match self.objects.get(&key) {
+			Some(object) => Ok((::http::StatusCode::OK, to_vec(object))),
+			None => Err(Status::object_not_found(&key)),
+		}
+	}
+
+	fn replace(&mut self, key: Key, status: bool, object: Value) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let mut object = object;
+		if !object.is_object() {
+			return Err(Status::bad_request("the request body is not a JSON object".to_string()));
+		}
+
+		check_name(&key, &object)?;
+
+		let existing = self.objects.get(&key).ok_or_else(|| Status::object_not_found(&key))?;
+
+		check_resource_version(&key, existing, &object)?;
+
+		if status {
+			// Only the status of the object can be replaced through its status subresource
+			let mut new_object = existing.clone();
+			new_object.as_object_mut().expect("stored objects are JSON objects").insert("status".to_string(), object["status"].take());
+			object = new_object;
+		}
+
+		self.update(key, existing.clone(), object)
+	}
+
+	fn patch(&mut self, key: Key, status: bool, content_type: Option<&str>, patch: Value) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let existing = self.objects.get(&key).ok_or_else(|| Status::object_not_found(&key))?.clone();
+
+		let mut object = existing.clone();
+		match content_type.map(|content_type| content_type.split(';').next().unwrap_or("").trim()) {
+			Some("application/json-patch+json") =>
+				json_patch(&mut object, &patch).map_err(|message| Status::invalid(key.collection.plural.clone(), key.name.clone(), &message))?,
+			None | Some("") | Some("application/merge-patch+json") | Some("application/strategic-merge-patch+json") =>
+				merge_patch(&mut object, &patch),
+			Some(content_type) =>
+				return Err(Status::new(
+					::http::StatusCode::UNSUPPORTED_MEDIA_TYPE, "UnsupportedMediaType",
+					format!("the body of the request was in an unknown format - accepted media types include: application/json-patch+json, application/merge-patch+json; got {}", content_type))),
+		}
+
+		if !object.is_object() {
+			return Err(Status::invalid(key.collection.plural.clone(), key.name.clone(), "the patched object is not a JSON object"));
+		}
+
+		check_name(&key, &object)?;
+		check_resource_version(&key, &existing, &object)?;
+
+		if status {
+			// Only the status of the object can be patched through its status subresource
+			let mut new_object = existing.clone();
+			new_object.as_object_mut().expect("stored objects are JSON objects").insert("status".to_string(), object["status"].take());
+			object = new_object;
+		}
+
+		self.update(key, existing, object)
+	}
+
+	/// Stores the new version of an existing object. Fields of the metadata that clients can't change are preserved from the existing object.
+	fn update(&mut self, key: Key, existing: Value, mut object: Value) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let resource_version = self.next_resource_version();
+		{
+			let existing_metadata = existing.get("metadata").and_then(Value::as_object);
+			let metadata = metadata_mut(&mut object)?;
+			for field in &["name", "namespace", "uid", "creationTimestamp"] {
+				match existing_metadata.and_then(|existing_metadata| existing_metadata.get(*field)) {
+					Some(value) => { metadata.insert(field.to_string(), value.clone()); },
+					None => { metadata.remove(*field); },
+				}
+			}
+			metadata.insert("resourceVersion".to_string(), Value::String(resource_version.to_string()));
+		}
+
+		self.objects.insert(key.clone(), object.clone());
+		self.events.push(Event { type_: "MODIFIED", key, object: object.clone(), resource_version });
+
+		Ok((::http::StatusCode::OK, to_vec(&object)))
+	}
+
+	fn delete(&mut self, key: Key) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		if !self.objects.contains_key(&key) {
+			return Err(Status::object_not_found(&key));
+		}
+
+		let uid = self.remove(&key);
+		Ok((::http::StatusCode::OK, to_vec(&json!({
+			"apiVersion": "v1",
+			"kind": "Status",
+			"metadata": {},
+			"status": "Success",
+			"details": {
+				"name": key.name,
+				"kind": key.collection.plural,
+				"uid": uid,
+			},
+		}))))
+	}
+
+	fn delete_collection(&mut self, collection: &Collection, query: &std::collections::BTreeMap<String, String>) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let filter = Filter::new(collection, None, query)?;
+		let keys: Vec<_> = self.objects.iter().filter(|(key, object)| filter.matches(key, object)).map(|(key, _)| key.clone()).collect();
+		for key in keys {
+			self.remove(&key);
+		}
+
+		Ok((::http::StatusCode::OK, to_vec(&json!({
+			"apiVersion": "v1",
+			"kind": "Status",
+			"metadata": {},
+			"status": "Success",
+		}))))
+	}
+
+	/// Removes the given object, and returns its UID.
+	fn remove(&mut self, key: &Key) -> Value {
+		let mut object = self.objects.remove(key).expect("object exists");
+		let resource_version = self.next_resource_version();
+		let metadata = metadata_mut(&mut object).expect("stored objects have metadata");
+		metadata.insert("resourceVersion".to_string(), Value::String(resource_version.to_string()));
+		let uid = metadata.get("uid").cloned().unwrap_or(Value::Null);
+		self.events.push(Event { type_: "DELETED", key: key.clone(), object, resource_version });
+		uid
+	}
+
+	fn list(&self, collection: &Collection, query: &std::collections::BTreeMap<String, String>) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let filter = Filter::new(collection, None, query)?;
+
+		let limit = match query.get("limit") {
+			Some(limit) => match limit.parse::<usize>() {
+				Ok(0) => None,
+				Ok(limit) => Some(limit),
+				Err(_) => return Err(Status::bad_request(format!("invalid limit {:?}", limit))),
+			},
+			None => None,
+		};
+
+		// The continue token is the namespace and name of the last object of the previous page
+		let after = match query.get("continue") {
+			Some(continue_) if !continue_.is_empty() => match continue_.find('/') {
+				Some(pos) => Some((&continue_[..pos], &continue_[(pos + 1)..])),
+				None => return Err(Status::bad_request(format!("invalid continue token {:?}", continue_))),
+			},
+			_ => None,
+		};
+
+		let mut items = vec![];
+		let mut continue_ = None;
+		for (key, object) in &self.objects {
+			if !filter.matches(key, object) {
+				continue;
+			}
+
+			if let Some(after) = after {
+				if (key.collection.namespace.as_ref().map_or("", String::as_str), &*key.name) <= after {
+					continue;
+				}
+			}
+
+			if limit == Some(items.len()) {
+				// There are more objects than fit in this page
+				let (last, _): (&Key, &Value) = items[items.len() - 1];
+				continue_ = Some(format!("{}/{}", last.collection.namespace.as_ref().map_or("", String::as_str), last.name));
+				break;
+			}
+
+			items.push((key, object));
+		}
+
+		let mut metadata = serde_json::Map::new();
+		metadata.insert("resourceVersion".to_string(), Value::String(self.resource_version.to_string()));
+		if let Some(continue_) = continue_ {
+			metadata.insert("continue".to_string(), Value::String(continue_));
+		}
+
+		let mut list = serde_json::Map::new();
+		list.insert("apiVersion".to_string(), Value::String(collection.group_version.clone()));
+		if let Some(kind) = self.kinds.get(&(collection.group_version.clone(), collection.plural.clone())) {
+			list.insert("kind".to_string(), Value::String(format!("{}List", kind)));
+		}
+		list.insert("metadata".to_string(), Value::Object(metadata));
+		list.insert("items".to_string(), Value::Array(items.into_iter().map(|(_, object)| object.clone()).collect()));
+
+		Ok((::http::StatusCode::OK, to_vec(&Value::Object(list))))
+	}
+
+	fn watch(&self, collection: &Collection, name: Option<&str>, query: &std::collections::BTreeMap<String, String>) -> Result<(::http::StatusCode, Vec<u8>), Status> {
+		let filter = Filter::new(collection, name, query)?;
+
+		let mut body = vec![];
+		let mut write_event = |type_: &str, object: &Value| {
+			serde_json::to_writer(&mut body, &json!({ "type": type_, "object": object })).expect("events can be serialized");
+			body.push(b'\n');
+		};
+
+		match query.get("resourceVersion").map(String::as_str) {
+			None | Some("") | Some("0") =>
+				// Like the API server, start with the current objects
+				for (key, object) in &self.objects {
+					if filter.matches(key, object) {
+						write_event("ADDED", object);
+					}
+				},
+
+			Some(resource_version) => {
+				let resource_version: u64 =
+					resource_version.parse().map_err(|_| Status::bad_request(format!("invalid resource version {:?}", resource_version)))?;
+				for event in &self.events {
+					if event.resource_version > resource_version && filter.matches(&event.key, &event.object) {
+						write_event(event.type_, &event.object);
+					}
+				}
+			},
+		}
+
+		Ok((::http::StatusCode::OK, body))
+	}
+
+	fn next_resource_version(&mut self) -> u64 {
+		self.resource_version += 1;
+		self.resource_version
+	}
+}
+
+/// Selects the objects of a collection, optionally of a single name, that match the label and field selectors of a list or watch request.
+struct Filter<'a> {
+	collection: &'a Collection,
+	name: Option<&'a str>,
+	label_selector: ::k8s_openapi::label_selector::Selector,
+	field_selector: Vec<(String, ::k8s_openapi::field_selector::Operator)>,
+}
+
+impl<'a> Filter<'a> {
+	fn new(collection: &'a Collection, name: Option<&'a str>, query: &std::collections::BTreeMap<String, String>) -> Result<Self, Status> {
+		let label_selector = match query.get("labelSelector") {
+			Some(label_selector) => label_selector.parse().map_err(|err| Status::bad_request(format!("invalid label selector {:?}: {}", label_selector, err)))?,
+			None => Default::default(),
+		};
+
+		let field_selector = match query.get("fieldSelector") {
+			Some(field_selector) =>
+				::k8s_openapi::field_selector::parse_untyped(field_selector)
+				.map_err(|err| Status::bad_request(format!("invalid field selector {:?}: {}", field_selector, err)))?
+				.into_iter()
+				.map(|(path, operator)| (path.to_string(), operator))
+				.collect(),
+			None => vec![],
+		};
+
+		Ok(Filter { collection, name, label_selector, field_selector })
+	}
+
+	fn matches(&self, key: &Key, object: &Value) -> bool {
+		if key.collection.group_version != self.collection.group_version || key.collection.plural != self.collection.plural {
+			return false;
+		}
+
+		// A list or watch without a namespace is across all namespaces
+		if self.collection.namespace.is_some() && key.collection.namespace != self.collection.namespace {
+			return false;
+		}
+
+		if let Some(name) = self.name {
+			if key.name != name {
+				return false;
+			}
+		}
+
+		let labels: Option<std::collections::BTreeMap<String, String>> =
+			object.get("metadata").and_then(|metadata| metadata.get("labels")).and_then(|labels| serde_json::from_value(labels.clone()).ok());
+		if !self.label_selector.matches(&labels.unwrap_or_default()) {
+			return false;
+		}
+
+		self.field_selector.iter().all(|(path, operator)| {
+			// Like the API server, a field that isn't set has an empty value
+			let field = path.split('.').try_fold(object, |value, component| value.get(component));
+			let field = match field {
+				Some(Value::String(field)) => field.clone(),
+				Some(Value::Null) | None => String::new(),
+				Some(field) => field.to_string(),
+			};
+			match operator {
+				::k8s_openapi::field_selector::Operator::Equals(value) => field == *value,
+				::k8s_openapi::field_selector::Operator::NotEquals(value) => field != *value,
+			}
+		})
+	}
+}
+
+/// A `Status` object returned for a failed request.
+#[derive(Debug)]
+struct Status {
+	code: ::http::StatusCode,
+	reason: &'static str,
+	message: String,
+	details: Option<Value>,
+}
+
+impl Status {
+	fn new(code: ::http::StatusCode, reason: &'static str, message: String) -> Self {
+		Status { code, reason, message, details: None }
+	}
+
+	fn bad_request(message: String) -> Self {
+		Status::new(::http::StatusCode::BAD_REQUEST, "BadRequest", message)
+	}
+
+	fn not_found(message: String) -> Self {
+		Status::new(::http::StatusCode::NOT_FOUND, "NotFound", message)
+	}
+
+	fn not_found_path(path: &str) -> Self {
+		Status::not_found(format!("the server could not find the requested resource {}", path))
+	}
+
+	fn object_not_found(key: &Key) -> Self {
+		Status {
+			details: Some(json!({ "name": key.name, "kind": key.collection.plural })),
+			..Status::not_found(format!("{} {:?} not found", key.collection.plural, key.name))
+		}
+	}
+
+	fn already_exists(key: &Key) -> Self {
+		Status {
+			details: Some(json!({ "name": key.name, "kind": key.collection.plural })),
+			..Status::new(::http::StatusCode::CONFLICT, "AlreadyExists", format!("{} {:?} already exists", key.collection.plural, key.name))
+		}
+	}
+
+	fn conflict(key: &Key) -> Self {
+		Status {
+			details: Some(json!({ "name": key.name, "kind": key.collection.plural })),
+			..Status::new(
+				::http::StatusCode::CONFLICT, "Conflict",
+				format!(
+					"Operation cannot be fulfilled on {} {:?}: the object has been modified; please apply your changes to the latest version and try again",
+					key.collection.plural, key.name))
+		}
+	}
+
+	fn invalid(plural: String, name: String, message: &str) -> Self {
+		Status {
+			details: Some(json!({ "name": name, "kind": plural })),
+			..Status::new(::http::StatusCode::UNPROCESSABLE_ENTITY, "Invalid", format!("{} {:?} is invalid: {}", plural, name, message))
+		}
+	}
+
+	fn into_body(self) -> Vec<u8> {
+		let mut status = json!({
+			"apiVersion": "v1",
+			"kind": "Status",
+			"metadata": {},
+			"status": "Failure",
+			"message": self.message,
+			"reason": self.reason,
+			"code": self.code.as_u16(),
+		});
+		if let Some(details) = self.details {
+			status["details"] = details;
+		}
+		to_vec(&status)
+	}
+}
+
+/// Parses the path of a request into the collection, object and subresource that it refers to, like the API server's request info resolver.
+fn parse_path(path: &str) -> Option<Target> {
+	let mut components: Vec<&str> = path.split('/').filter(|component| !component.is_empty()).collect();
+
+	let group_version = match components.first() {
+		Some(&"api") if components.len() >= 2 => {
+			let group_version = components[1].to_string();
+			components.drain(..2);
+			group_version
+		},
+		Some(&"apis") if components.len() >= 3 => {
+			let group_version = format!("{}/{}", components[1], components[2]);
+			components.drain(..3);
+			group_version
+		},
+		_ => return None,
+	};
+
+	let watch = components.first() == Some(&"watch");
+	if watch {
+		components.remove(0);
+	}
+
+	// `/namespaces/{name}/status` and `/namespaces/{name}/finalize` are subresources of a namespace, not collections in it
+	let namespace =
+		if components.len() >= 3 && components[0] == "namespaces" && !(components.len() == 3 && (components[2] == "status" || components[2] == "finalize")) {
+			let namespace = components[1].to_string();
+			components.drain(..2);
+			Some(namespace)
+		}
+		else {
+			None
+		};
+
+	let (plural, name, subresource) = match &components[..] {
+		[plural] => (plural, None, None),
+		[plural, name] => (plural, Some(name.to_string()), None),
+		[plural, name, subresource] if !watch => (plural, Some(name.to_string()), Some(subresource.to_string())),
+		_ => return None,
+	};
+
+	Some(Target {
+		collection: Collection {
+			group_version,
+			plural: plural.to_string(),
+			namespace,
+		},
+		name,
+		subresource,
+		watch,
+	})
+}
+
+fn parse_body(body: &[u8]) -> Result<Value, Status> {
+	serde_json::from_slice(body).map_err(|err| Status::bad_request(format!("the request body is not valid JSON: {}", err)))
+}
+
+fn is_true(value: Option<&String>) -> bool {
+	matches!(value.map(String::as_str), Some("true") | Some("1"))
+}
+
+fn to_vec(value: &Value) -> Vec<u8> {
+	serde_json::to_vec(value).expect("JSON values can be serialized")
+}
+
+/// Returns the metadata of the given object, adding it if it doesn't have any.
+fn metadata_mut(object: &mut Value) -> Result<&mut serde_json::Map<String, Value>, Status> {
+	let object = object.as_object_mut().ok_or_else(|| Status::bad_request("the request body is not a JSON object".to_string()))?;
+	let metadata = object.entry("metadata").or_insert_with(|| Value::Object(Default::default()));
+	if metadata.is_null() {
+		*metadata = Value::Object(Default::default());
+	}
+	metadata.as_object_mut().ok_or_else(|| Status::bad_request("the metadata of the object is not a JSON object".to_string()))
+}
+
+/// Fails if the new version of an object has a name that isn't that of the existing object.
+fn check_name(key: &Key, object: &Value) -> Result<(), Status> {
+	match object.get("metadata").and_then(|metadata| metadata.get("name")).and_then(Value::as_str) {
+		Some(name) if name != key.name => Err(Status::bad_request("the name of the object does not match the name on the URL".to_string())),
+		_ => Ok(()),
+	}
+}
+
+/// Fails with a conflict if the new version of an object has a resource version that isn't that of the existing object.
+fn check_resource_version(key: &Key, existing: &Value, object: &Value) -> Result<(), Status> {
+	fn get_resource_version(object: &Value) -> Option<&str> {
+		object.get("metadata").and_then(|metadata| metadata.get("resourceVersion")).and_then(Value::as_str)
+	}
+
+	match get_resource_version(object) {
+		Some(resource_version) if !resource_version.is_empty() && Some(resource_version) != get_resource_version(existing) => Err(Status::conflict(key)),
+		_ => Ok(()),
+	}
+}
+
+/// Applies a JSON merge patch (RFC 7386).
+fn merge_patch(target: &mut Value, patch: &Value) {
+	match patch {
+		Value::Object(patch) => {
+			if !target.is_object() {
+				*target = Value::Object(Default::default());
+			}
+			let target = target.as_object_mut().expect("target was just made an object");
+
+			for (key, value) in patch {
+				if value.is_null() {
+					target.remove(key);
+				}
+				else {
+					merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+				}
+			}
+		},
+
+		patch => *target = patch.clone(),
+	}
+}
+
+/// Applies a JSON patch (RFC 6902).
+fn json_patch(target: &mut Value, patch: &Value) -> Result<(), String> {
+	let operations = patch.as_array().ok_or_else(|| "the JSON patch is not an array".to_string())?;
+
+	for operation in operations {
+		let string_field = |name: &str| operation.get(name).and_then(Value::as_str).ok_or_else(|| format!("JSON patch operation {} has no {:?}", operation, name));
+		let value_field = || operation.get("value").cloned().ok_or_else(|| format!("JSON patch operation {} has no \"value\"", operation));
+
+		let path = string_field("path")?;
+		match string_field("op")? {
+			"add" => json_patch_add(target, path, value_field()?)?,
+			"remove" => { json_patch_remove(target, path)?; },
+			"replace" => {
+				let value = value_field()?;
+				*target.pointer_mut(path).ok_or_else(|| format!("path {:?} doesn't exist", path))? = value;
+			},
+			"move" => {
+				let value = json_patch_remove(target, string_field("from")?)?;
+				json_patch_add(target, path, value)?;
+			},
+			"copy" => {
+				let from = string_field("from")?;
+				let value = target.pointer(from).ok_or_else(|| format!("path {:?} doesn't exist", from))?.clone();
+				json_patch_add(target, path, value)?;
+			},
+			"test" => {
+				let value = value_field()?;
+				if target.pointer(path) != Some(&value) {
+					return Err(format!("test of path {:?} failed", path));
+				}
+			},
+			op => return Err(format!("unknown JSON patch operation {:?}", op)),
+		}
+	}
+
+	Ok(())
+}
+
+/// Splits a JSON pointer into the pointer of its parent and the unescaped last reference token.
+fn split_pointer(path: &str) -> Result<(&str, String), String> {
+	let pos = path.rfind('/').ok_or_else(|| format!("invalid path {:?}", path))?;
+	Ok((&path[..pos], path[(pos + 1)..].replace("~1", "/").replace("~0", "~")))
+}
+
+fn json_patch_add(target: &mut Value, path: &str, value: Value) -> Result<(), String> {
+	if path.is_empty() {
+		*target = value;
+		return Ok(());
+	}
+
+	let (parent, token) = split_pointer(path)?;
+	match target.pointer_mut(parent) {
+		Some(Value::Object(parent)) => { parent.insert(token, value); },
+		Some(Value::Array(parent)) if token == "-" => parent.push(value),
+		Some(Value::Array(parent)) => match token.parse::<usize>() {
+			Ok(index) if index <= parent.len() => parent.insert(index, value),
+			_ => return Err(format!("invalid array index in path {:?}", path)),
+		},
+		_ => return Err(format!("path {:?} doesn't exist", parent)),
+	}
+
+	Ok(())
+}
+
+fn json_patch_remove(target: &mut Value, path: &str) -> Result<Value, String> {
+	let (parent, token) = split_pointer(path)?;
+	let removed = match target.pointer_mut(parent) {
+		Some(Value::Object(parent)) => parent.remove(&token),
+		Some(Value::Array(parent)) => match token.parse::<usize>() {
+			Ok(index) if index < parent.len() => Some(parent.remove(index)),
+			_ => None,
+		},
+		_ => None,
+	};
+	removed.ok_or_else(|| format!("path {:?} doesn't exist", path))
+}
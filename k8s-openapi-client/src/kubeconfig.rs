@@ -0,0 +1,416 @@
+//! The kubeconfig format, as used by `kubectl` to describe API servers and how to authenticate with them.
+//!
+//! A [`KubeConfig`](struct.KubeConfig.html) is usually loaded with [`KubeConfig::load`](struct.KubeConfig.html#method.load),
+//! which merges the files in the `KUBECONFIG` environment variable like `kubectl` does. It's then resolved into a [`Config`](../struct.Config.html)
+//! for one of its contexts with [`KubeConfig::resolve`](struct.KubeConfig.html#method.resolve).
+
+/// A kubeconfig.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct KubeConfig {
+	/// The clusters, ie API servers, by name.
+	#[serde(default)]
+	pub clusters: Vec<NamedCluster>,
+
+	/// The contexts, ie pairs of a cluster and a user, by name.
+	#[serde(default)]
+	pub contexts: Vec<NamedContext>,
+
+	/// The name of the context to use by default.
+	#[serde(rename = "current-context", default)]
+	pub current_context: Option<String>,
+
+	/// The users, ie credentials, by name.
+	#[serde(default)]
+	pub users: Vec<NamedUser>,
+}
+
+/// A cluster of a kubeconfig and its name.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct NamedCluster {
+	/// The name of the cluster.
+	pub name: String,
+
+	/// The cluster.
+	pub cluster: Cluster,
+}
+
+/// An API server.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct Cluster {
+	/// The URL of the API server.
+	pub server: String,
+
+	/// The path of a PEM file with the certificate of the CA that signed the API server's certificate.
+	#[serde(rename = "certificate-authority", default)]
+	pub certificate_authority: Option<std::path::PathBuf>,
+
+	/// The base64-encoded contents of a PEM file with the certificate of the CA that signed the API server's certificate.
+	/// Takes precedence over `certificate_authority`.
+	#[serde(rename = "certificate-authority-data", default)]
+	pub certificate_authority_data: Option<String>,
+
+	/// Whether the API server's certificate is accepted without being validated.
+	#[serde(rename = "insecure-skip-tls-verify", default)]
+	pub insecure_skip_tls_verify: bool,
+}
+
+/// A context of a kubeconfig and its name.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct NamedContext {
+	/// The name of the context.
+	pub name: String,
+
+	/// The context.
+	pub context: Context,
+}
+
+/// A pair of a cluster and a user, and the namespace to use by default.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct Context {
+	/// The name of the cluster.
+	pub cluster: String,
+
+	/// The name of the user, or `None` to not authenticate.
+	#[serde(default)]
+	pub user: Option<String>,
+
+	/// The namespace to use by default.
+	#[serde(default)]
+	pub namespace: Option<String>,
+}
+
+/// A user of a kubeconfig and its name.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct NamedUser {
+	/// The name of the user.
+	pub name: String,
+
+	/// The user.
+	pub user: User,
+}
+
+/// The credentials of a user.
+#[derive(Clone, Default, Deserialize, PartialEq)]
+pub struct User {
+	/// The path of a PEM file with the client certificate.
+	#[serde(rename = "client-certificate", default)]
+	pub client_certificate: Option<std::path::PathBuf>,
+
+	/// The base64-encoded contents of a PEM file with the client certificate. Takes precedence over `client_certificate`.
+	#[serde(rename = "client-certificate-data", default)]
+	pub client_certificate_data: Option<String>,
+
+	/// The path of a PEM file with the private key of the client certificate.
+	#[serde(rename = "client-key", default)]
+	pub client_key: Option<std::path::PathBuf>,
+
+	/// The base64-encoded contents of a PEM file with the private key of the client certificate. Takes precedence over `client_key`.
+	#[serde(rename = "client-key-data", default)]
+	pub client_key_data: Option<String>,
+
+	/// A bearer token.
+	#[serde(default)]
+	pub token: Option<String>,
+
+	/// The path of a file with a bearer token. `token` takes precedence over this.
+	#[serde(rename = "tokenFile", default)]
+	pub token_file: Option<std::path::PathBuf>,
+
+	/// The username for basic authentication.
+	#[serde(default)]
+	pub username: Option<String>,
+
+	/// The password for basic authentication.
+	#[serde(default)]
+	pub password: Option<String>,
+
+	/// A credential plugin to run to get a bearer token or client certificate.
+	#[serde(default)]
+	pub exec: Option<ExecConfig>,
+}
+
+impl std::fmt::Debug for User {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		// Don't print credentials
+		f.debug_struct("User")
+			.field("client_certificate", &self.client_certificate)
+			.field("client_certificate_data", &self.client_certificate_data.as_ref().map(|_| "..."))
+			.field("client_key", &self.client_key)
+			.field("client_key_data", &self.client_key_data.as_ref().map(|_| "..."))
+			.field("token", &self.token.as_ref().map(|_| "..."))
+			.field("token_file", &self.token_file)
+			.field("username", &self.username)
+			.field("password", &self.password.as_ref().map(|_| "..."))
+			.field("exec", &self.exec)
+			.finish()
+	}
+}
+
+/// A credential plugin, ie a command that prints an `ExecCredential` with a bearer token or client certificate.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ExecConfig {
+	/// The API version of the `ExecCredential`, like `client.authentication.k8s.io/v1beta1`.
+	#[serde(rename = "apiVersion")]
+	pub api_version: String,
+
+	/// The command to run. If it's a relative path with more than one component, it's relative to the directory of the kubeconfig.
+	pub command: String,
+
+	/// The arguments of the command.
+	#[serde(default)]
+	pub args: Vec<String>,
+
+	/// Environment variables to set for the command, in addition to those of this process.
+	#[serde(default)]
+	pub env: Vec<ExecEnvVar>,
+}
+
+/// An environment variable of a credential plugin.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ExecEnvVar {
+	/// The name of the variable.
+	pub name: String,
+
+	/// The value of the variable.
+	pub value: String,
+}
+
+impl KubeConfig {
+	/// Loads the kubeconfig the way `kubectl` does.
+	///
+	/// If the `KUBECONFIG` environment variable is set, it's a list of paths separated like the `PATH` environment variable,
+	/// and the files that exist are loaded and merged in that order. Otherwise the kubeconfig is loaded from `~/.kube/config`.
+	pub fn load() -> Result<Self, ::Error> {
+		if let Some(paths) = std::env::var_os("KUBECONFIG") {
+			let paths: Vec<_> = std::env::split_paths(&paths).filter(|path| !path.as_os_str().is_empty()).collect();
+			if !paths.is_empty() {
+				let mut result: Option<KubeConfig> = None;
+
+				for path in paths {
+					if !path.exists() {
+						continue;
+					}
+
+					let kubeconfig = KubeConfig::from_file(&path)?;
+					result = Some(match result {
+						Some(result) => result.merge(kubeconfig),
+						None => kubeconfig,
+					});
+				}
+
+				return result.ok_or_else(|| ::Error::Config("none of the files in KUBECONFIG exist".to_string()));
+			}
+		}
+
+		let mut path = ::dirs::home_dir().ok_or_else(|| ::Error::Config("can't find home directory".to_string()))?;
+		path.push(".kube");
+		path.push("config");
+		KubeConfig::from_file(&path)
+	}
+
+	/// Loads a kubeconfig from the given file. Relative paths in the kubeconfig are made relative to the directory of the file.
+	pub fn from_file(path: &std::path::Path) -> Result<Self, ::Error> {
+		let file = std::fs::File::open(path).map_err(::Error::Io)?;
+		let mut kubeconfig: KubeConfig = ::serde_yaml::from_reader(std::io::BufReader::new(file)).map_err(::Error::Yaml)?;
+
+		let base_dir = path.parent().unwrap_or_else(|| std::path::Path::new(""));
+		kubeconfig.make_paths_relative_to(base_dir);
+
+		Ok(kubeconfig)
+	}
+
+	/// Merges two kubeconfigs the way `kubectl` does, ie the first one to define a cluster, context or user of a given name wins,
+	/// and so does the first one to set the current context.
+	pub fn merge(mut self, other: KubeConfig) -> Self {
+		for cluster in other.clusters {
+			if !self.clusters.iter().any(|c| c.name == cluster.name) {
+				self.clusters.push(cluster);
+			}
+		}
+
+		for context in other.contexts {
+			if !self.contexts.iter().any(|c| c.name == context.name) {
+				self.contexts.push(context);
+			}
+		}
+
+		for user in other.users {
+			if !self.users.iter().any(|u| u.name == user.name) {
+				self.users.push(user);
+			}
+		}
+
+		match &self.current_context {
+			Some(current_context) if !current_context.is_empty() => (),
+			_ => self.current_context = other.current_context,
+		}
+
+		self
+	}
+
+	/// Resolves the config of the given context, or of the current context if `None`.
+	///
+	/// This reads the files that the kubeconfig refers to, and runs the credential plugin of the user if it has one.
+	/// The plugin is only run once, so the config's credentials aren't refreshed when they expire.
+	pub fn resolve(&self, context: Option<&str>) -> Result<::Config, ::Error> {
+		let context_name = match context.or(self.current_context.as_deref()) {
+			Some(context) if !context.is_empty() => context,
+			_ => return Err(::Error::Config("kubeconfig doesn't have a current context".to_string())),
+		};
+
+		let context =
+			&self.contexts.iter()
+			.find(|c| c.name == context_name).ok_or_else(|| ::Error::Config(format!("couldn't find context named {}", context_name)))?
+			.context;
+
+		let cluster =
+			&self.clusters.iter()
+			.find(|c| c.name == context.cluster).ok_or_else(|| ::Error::Config(format!("couldn't find cluster named {}", context.cluster)))?
+			.cluster;
+
+		let certificate_authority = read_data_or_file(
+			&cluster.certificate_authority_data, &cluster.certificate_authority,
+			|| format!("certificate-authority-data of cluster {}", context.cluster))?;
+
+		let mut config = ::Config {
+			server: ::Config::parse_server(&cluster.server)?,
+			certificate_authority,
+			accept_invalid_certs: cluster.insecure_skip_tls_verify,
+			client_certificate: None,
+			authentication: None,
+			namespace: context.namespace.clone().unwrap_or_else(|| "default".to_string()),
+		};
+
+		if let Some(user_name) = &context.user {
+			let user =
+				&self.users.iter()
+				.find(|u| &u.name == user_name).ok_or_else(|| ::Error::Config(format!("couldn't find user named {}", user_name)))?
+				.user;
+
+			user.resolve(user_name, &mut config)?;
+		}
+
+		Ok(config)
+	}
+
+	fn make_paths_relative_to(&mut self, base_dir: &std::path::Path) {
+		let make_relative = |path: &mut Option<std::path::PathBuf>| if let Some(path) = path {
+			*path = base_dir.join(&*path);
+		};
+
+		for cluster in &mut self.clusters {
+			make_relative(&mut cluster.cluster.certificate_authority);
+		}
+
+		for user in &mut self.users {
+			let user = &mut user.user;
+			make_relative(&mut user.client_certificate);
+			make_relative(&mut user.client_key);
+			make_relative(&mut user.token_file);
+
+			if let Some(exec) = &mut user.exec {
+				// Like `kubectl`, a command like `./foo` is relative to the kubeconfig, but a command like `foo` is looked up in `PATH`
+				let command = std::path::Path::new(&exec.command);
+				if command.is_relative() && command.components().count() > 1 {
+					exec.command = base_dir.join(command).to_string_lossy().into_owned();
+				}
+			}
+		}
+	}
+}
+
+impl User {
+	fn resolve(&self, name: &str, config: &mut ::Config) -> Result<(), ::Error> {
+		let client_certificate = read_data_or_file(&self.client_certificate_data, &self.client_certificate, || format!("client-certificate-data of user {}", name))?;
+		let client_key = read_data_or_file(&self.client_key_data, &self.client_key, || format!("client-key-data of user {}", name))?;
+		config.client_certificate = match (client_certificate, client_key) {
+			(Some(certificate), Some(key)) => Some(::ClientCertificate { certificate, key }),
+			(None, None) => None,
+			_ => return Err(::Error::Config(format!("user {} has only one of a client certificate and a client key", name))),
+		};
+
+		let token = match (&self.token, &self.token_file) {
+			(Some(token), _) => Some(token.clone()),
+			(None, Some(token_file)) => Some(std::fs::read_to_string(token_file).map_err(::Error::Io)?.trim().to_string()),
+			(None, None) => None,
+		};
+
+		config.authentication = match (token, &self.username, &self.password) {
+			(Some(_), Some(_), _) | (Some(_), _, Some(_)) =>
+				return Err(::Error::Config(format!("user {} has both a token and a username or password", name))),
+			(Some(token), None, None) => Some(::Authentication::Token(token)),
+			(None, Some(username), password) => Some(::Authentication::Basic {
+				username: username.clone(),
+				password: password.clone().unwrap_or_default(),
+			}),
+			(None, None, Some(_)) => return Err(::Error::Config(format!("user {} has a password but no username", name))),
+			(None, None, None) => None,
+		};
+
+		if let Some(exec) = &self.exec {
+			let credential = exec.run()?;
+
+			if let Some(token) = credential.token {
+				config.authentication = Some(::Authentication::Token(token));
+			}
+
+			match (credential.client_certificate_data, credential.client_key_data) {
+				(Some(certificate), Some(key)) => config.client_certificate = Some(::ClientCertificate { certificate: certificate.into_bytes(), key: key.into_bytes() }),
+				(None, None) => (),
+				_ => return Err(::Error::Config(format!("credential plugin of user {} returned only one of a client certificate and a client key", name))),
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl ExecConfig {
+	/// Runs the credential plugin and returns the status of the `ExecCredential` that it printed.
+	fn run(&self) -> Result<ExecCredentialStatus, ::Error> {
+		let exec_info = format!(
+			r#"{{"apiVersion":{},"kind":"ExecCredential","spec":{{}}}}"#,
+			::k8s_openapi::serde_json::to_string(&self.api_version).map_err(::Error::Json)?);
+
+		let mut command = std::process::Command::new(&self.command);
+		command.args(&self.args);
+		command.envs(self.env.iter().map(|env| (&env.name, &env.value)));
+		command.env("KUBERNETES_EXEC_INFO", exec_info);
+		command.stdin(std::process::Stdio::null());
+		command.stderr(std::process::Stdio::inherit());
+
+		let output = command.output().map_err(::Error::Io)?;
+		if !output.status.success() {
+			return Err(::Error::Config(format!("credential plugin {} failed: {}", self.command, output.status)));
+		}
+
+		let credential: ExecCredential = ::k8s_openapi::serde_json::from_slice(&output.stdout).map_err(::Error::Json)?;
+		Ok(credential.status)
+	}
+}
+
+#[derive(Deserialize)]
+struct ExecCredential {
+	status: ExecCredentialStatus,
+}
+
+#[derive(Deserialize)]
+struct ExecCredentialStatus {
+	#[serde(rename = "clientCertificateData", default)]
+	client_certificate_data: Option<String>,
+	#[serde(rename = "clientKeyData", default)]
+	client_key_data: Option<String>,
+	#[serde(default)]
+	token: Option<String>,
+}
+
+/// Returns the decoded base64 data if it's set, otherwise the contents of the file if it's set.
+fn read_data_or_file<F>(data: &Option<String>, file: &Option<std::path::PathBuf>, data_name: F) -> Result<Option<Vec<u8>>, ::Error> where F: FnOnce() -> String {
+	match (data, file) {
+		(Some(data), _) => Ok(Some(
+			::base64::decode(data)
+			.map_err(|err| ::Error::Config(format!("couldn't decode {}: {}", data_name(), err)))?)),
+		(None, Some(file)) => Ok(Some(std::fs::read(file).map_err(::Error::Io)?)),
+		(None, None) => Ok(None),
+	}
+}
@@ -0,0 +1,335 @@
+//! A synchronous client for the Kubernetes API bindings in the [`k8s-openapi`](https://docs.rs/k8s-openapi) crate.
+//!
+//! The API functions in `k8s-openapi` only prepare `http::Request`s and parse responses, so they can be used with any HTTP client.
+//! This crate provides a [`Client`](struct.Client.html) built on `reqwest` that executes those requests against the API server
+//! described by a [`Config`](struct.Config.html), and parses the responses with `k8s_openapi::Response::try_from_parts`.
+//!
+//! A `Config` is loaded from a kubeconfig with [`Config::from_kubeconfig`](struct.Config.html#method.from_kubeconfig),
+//! or from the service account of the pod the client is running in with [`Config::in_cluster`](struct.Config.html#method.in_cluster).
+//!
+//! [`Client::discover`](struct.Client.html#method.discover) discovers the resources served by the API server, and the [`discovery`](discovery/index.html) module
+//! caches the result.
+//!
+//! The [`fake`](fake/index.html) module has an in-memory fake of the API server, for unit-testing code that uses the API functions without a cluster.
+//!
+//! `k8s-openapi` needs at least one of its version features to be enabled. Enable them either on `k8s-openapi` directly,
+//! or with the features of the same names on this crate, like `v1_12`. [`Client::server_version`](struct.Client.html#method.server_version)
+//! detects the version of the API server, for selecting one of several enabled versions at runtime.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! #[macro_use] extern crate k8s_openapi;
+//! extern crate k8s_openapi_client;
+//!
+//! use k8s_openapi::api::core::v1 as api;
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let config = k8s_openapi_client::Config::from_kubeconfig(None)?;
+//!     let client = k8s_openapi_client::Client::new(&config)?;
+//!
+//!     k8s_if_le_1_7! {
+//!         let request = api::Pod::list_core_v1_namespaced_pod(
+//!             "kube-system",
+//!             None, None, None, None, None, None, None)?;
+//!     }
+//!     k8s_if_ge_1_8! {
+//!         let request = api::Pod::list_core_v1_namespaced_pod(
+//!             "kube-system",
+//!             None, None, None, None, None, None, None, None, None)?;
+//!     }
+//!
+//!     match client.execute(request)?.into_value()? {
+//!         api::ListCoreV1NamespacedPodResponse::Ok(pod_list) =>
+//!             for pod in pod_list.items {
+//!                 println!("{:#?}", pod);
+//!             },
+//!
+//!         other => return Err(format!("unexpected response {:?}", other).into()),
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+extern crate base64;
+extern crate dirs;
+extern crate futures;
+extern crate k8s_openapi;
+extern crate reqwest;
+extern crate serde;
+#[macro_use] extern crate serde_derive;
+#[macro_use] extern crate serde_json;
+extern crate serde_yaml;
+extern crate url;
+
+use k8s_openapi::http;
+
+mod config;
+pub use config::{ Authentication, ClientCertificate, Config, SERVICE_ACCOUNT_DIR };
+
+pub mod discovery;
+
+pub mod fake;
+
+pub mod kubeconfig;
+
+#[cfg(windows)] #[path = "tls_winapi.rs"] mod tls;
+#[cfg(not(windows))] #[path = "tls_openssl.rs"] mod tls;
+
+/// A synchronous client for the Kubernetes API server.
+#[derive(Debug)]
+pub struct Client {
+	inner: reqwest::Client,
+	server: http::Uri,
+}
+
+impl Client {
+	/// Constructs a client for the API server described by the given config.
+	pub fn new(config: &Config) -> Result<Self, Error> {
+		let mut builder = reqwest::Client::builder();
+
+		if let Some(certificate_authority) = &config.certificate_authority {
+			builder = builder.add_root_certificate(reqwest::Certificate::from_pem(certificate_authority).map_err(Error::Reqwest)?);
+		}
+
+		if config.accept_invalid_certs {
+			builder = builder.danger_accept_invalid_certs(true);
+		}
+
+		if let Some(ClientCertificate { certificate, key }) = &config.client_certificate {
+			let identity = tls::pkcs12(certificate, key)?;
+			builder = builder.identity(reqwest::Identity::from_pkcs12_der(&identity, "").map_err(Error::Reqwest)?);
+		}
+
+		if let Some(authentication) = &config.authentication {
+			let mut value: http::header::HeaderValue = authentication.header_value().parse().map_err(|err: http::header::InvalidHeaderValue| Error::Http(err.into()))?;
+			value.set_sensitive(true);
+
+			let mut headers = http::HeaderMap::new();
+			headers.insert(http::header::AUTHORIZATION, value);
+			builder = builder.default_headers(headers);
+		}
+
+		let inner = builder.build().map_err(Error::Reqwest)?;
+
+		Ok(Client {
+			inner,
+			server: config.server.clone(),
+		})
+	}
+
+	/// Executes the given request, like one returned by an API function of `k8s-openapi`.
+	///
+	/// The request's URL is relative to the API server, so only its path and query are used.
+	pub fn execute(&self, request: http::Request<Vec<u8>>) -> Result<Response, Error> {
+		let (method, url, body) = {
+			let (parts, body) = request.into_parts();
+			let mut url: http::uri::Parts = parts.uri.into();
+			let path = url.path_and_query.take().ok_or_else(|| Error::Config("request doesn't have path and query".to_string()))?;
+			let mut url: http::uri::Parts = self.server.clone().into();
+			url.path_and_query = Some(path);
+			let url = http::Uri::from_parts(url).map_err(|err| Error::Http(err.into()))?;
+
+			(parts.method, url.to_string(), body)
+		};
+
+		let inner = self.inner.request(method, &url).body(body).send().map_err(Error::Reqwest)?;
+		Ok(Response { inner })
+	}
+
+	/// Discovers the resources served by the API server, and returns the `RestMapper` that maps their kinds to their plural names, scopes, verbs and preferred versions.
+	///
+	/// See [`k8s_openapi::discovery`](https://docs.rs/k8s-openapi/*/k8s_openapi/discovery/index.html) for details,
+	/// and the [`discovery`](discovery/index.html) module for caching the result.
+	pub fn discover(&self) -> Result<k8s_openapi::discovery::RestMapper, Error> {
+		let mut discovery = k8s_openapi::discovery::Discovery::new();
+
+		while let Some(request) = discovery.next_request() {
+			let request = request.map_err(|err| match err {
+				k8s_openapi::RequestError::Http(err) => Error::Http(err),
+				k8s_openapi::RequestError::Json(err) => Error::Json(err),
+			})?;
+			let response = self.execute(request)?;
+			let status_code = response.status_code();
+			let response = response.into_value()?;
+			discovery.handle_response(status_code, response).map_err(Error::Discovery)?;
+		}
+
+		Ok(discovery.into_rest_mapper())
+	}
+
+	/// Detects the version of the API server.
+	///
+	/// See [`k8s_openapi::server_version`](https://docs.rs/k8s-openapi/*/k8s_openapi/server_version/index.html) for selecting the enabled version that matches it.
+	pub fn server_version(&self) -> Result<k8s_openapi::server_version::ServerVersion, Error> {
+		let request = k8s_openapi::server_version::ServerVersion::request().map_err(|err| match err {
+			k8s_openapi::RequestError::Http(err) => Error::Http(err),
+			k8s_openapi::RequestError::Json(err) => Error::Json(err),
+		})?;
+		let response = self.execute(request)?;
+		let status_code = response.status_code();
+		let response = response.into_value()?;
+		k8s_openapi::server_version::ServerVersion::from_response(status_code, response).map_err(Error::ServerVersion)
+	}
+}
+
+/// The response to a request executed by a [`Client`](struct.Client.html).
+///
+/// The response body can be read directly with the `std::io::Read` impl, or parsed into one or more values of a `k8s_openapi::Response` type.
+#[derive(Debug)]
+pub struct Response {
+	inner: reqwest::Response,
+}
+
+impl Response {
+	/// The HTTP status code of the response.
+	pub fn status_code(&self) -> http::StatusCode {
+		self.inner.status()
+	}
+
+	/// Parses the response body into a single value of the given response type, like `ListCoreV1NamespacedPodResponse`.
+	pub fn into_value<R>(self) -> Result<R, Error> where R: k8s_openapi::Response {
+		match self.into_values().next() {
+			Some(result) => result,
+			None => Err(Error::Response(k8s_openapi::ResponseError::NeedMoreData)),
+		}
+	}
+
+	/// Parses the response body into a sequence of values of the given response type, like the events of a watch
+	/// or the pieces of a pod's logs. The body is read as the iterator is advanced.
+	pub fn into_values<R>(self) -> Values<R> where R: k8s_openapi::Response {
+		let status_code = self.status_code();
+
+		let chunks = futures::stream::iter_result(Chunks {
+			reader: self,
+			buf: Box::new([0u8; 4096]),
+		});
+
+		Values(futures::Stream::wait(k8s_openapi::stream::ResponseStream::new(status_code, chunks)))
+	}
+}
+
+impl std::io::Read for Response {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		self.inner.read(buf)
+	}
+}
+
+/// An iterator of the values parsed from the body of a [`Response`](struct.Response.html).
+/// See [`Response::into_values`](struct.Response.html#method.into_values).
+pub struct Values<R>(futures::stream::Wait<k8s_openapi::stream::ResponseStream<futures::stream::IterResult<Chunks>, R>>);
+
+impl<R> Iterator for Values<R> where R: k8s_openapi::Response {
+	type Item = Result<R, Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.0.next()? {
+			Ok(value) => Some(Ok(value)),
+			Err(k8s_openapi::stream::Error::Stream(err)) => Some(Err(Error::Io(err))),
+			Err(k8s_openapi::stream::Error::Response(err)) => Some(Err(Error::Response(err))),
+		}
+	}
+}
+
+#[doc(hidden)]
+/// The chunks of a response body, read as they're requested.
+pub struct Chunks {
+	reader: Response,
+	buf: Box<[u8; 4096]>,
+}
+
+impl Iterator for Chunks {
+	type Item = std::io::Result<Vec<u8>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match std::io::Read::read(&mut self.reader, &mut *self.buf) {
+			Ok(0) => None,
+			Ok(read) => Some(Ok(self.buf[..read].to_vec())),
+			Err(err) => Some(Err(err)),
+		}
+	}
+}
+
+/// The type of errors from this crate.
+#[derive(Debug)]
+pub enum Error {
+	/// The config is invalid or incomplete, like a kubeconfig that doesn't have the requested context.
+	Config(String),
+
+	/// An error from discovering the resources served by the API server.
+	Discovery(k8s_openapi::discovery::Error),
+
+	/// An error from constructing the URL of a request.
+	Http(http::Error),
+
+	/// An error from reading a file, like a kubeconfig or a certificate, from running a credential plugin, or from reading a response body.
+	Io(std::io::Error),
+
+	/// An error from parsing the output of a credential plugin.
+	Json(k8s_openapi::serde_json::Error),
+
+	/// An error from the HTTP client.
+	Reqwest(reqwest::Error),
+
+	/// An error from parsing a response body.
+	Response(k8s_openapi::ResponseError),
+
+	/// An error from detecting the version of the API server.
+	ServerVersion(k8s_openapi::server_version::Error),
+
+	/// An error from loading a certificate or private key.
+	Tls(Box<std::error::Error + Send + Sync>),
+
+	/// An error from parsing a kubeconfig.
+	Yaml(serde_yaml::Error),
+}
+
+impl std::fmt::Display for Error {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Error::Config(message) => write!(f, "{}", message),
+			Error::Discovery(err) => write!(f, "{}", err),
+			Error::Http(err) => write!(f, "{}", err),
+			Error::Io(err) => write!(f, "{}", err),
+			Error::Json(err) => write!(f, "{}", err),
+			Error::Reqwest(err) => write!(f, "{}", err),
+			Error::Response(err) => write!(f, "{}", err),
+			Error::ServerVersion(err) => write!(f, "{}", err),
+			Error::Tls(err) => write!(f, "{}", err),
+			Error::Yaml(err) => write!(f, "{}", err),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn description(&self) -> &str {
+		match self {
+			Error::Config(message) => message,
+			Error::Discovery(err) => err.description(),
+			Error::Http(err) => err.description(),
+			Error::Io(err) => err.description(),
+			Error::Json(err) => err.description(),
+			Error::Reqwest(err) => err.description(),
+			Error::Response(err) => err.description(),
+			Error::ServerVersion(err) => err.description(),
+			Error::Tls(err) => err.description(),
+			Error::Yaml(err) => err.description(),
+		}
+	}
+
+	fn cause(&self) -> Option<&std::error::Error> {
+		match self {
+			Error::Config(_) => None,
+			Error::Discovery(err) => Some(err),
+			Error::Http(err) => Some(err),
+			Error::Io(err) => Some(err),
+			Error::Json(err) => Some(err),
+			Error::Reqwest(err) => Some(err),
+			Error::Response(err) => Some(err),
+			Error::ServerVersion(err) => Some(err),
+			Error::Tls(err) => Some(&**err),
+			Error::Yaml(err) => Some(err),
+		}
+	}
+}
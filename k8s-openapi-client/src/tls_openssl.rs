@@ -0,0 +1,15 @@
+extern crate openssl;
+
+/// Converts a PEM-encoded client certificate and private key into a DER-encoded PKCS#12 archive with an empty password.
+pub(crate) fn pkcs12(public_key: &[u8], private_key: &[u8]) -> Result<Vec<u8>, ::Error> {
+	let public_key = openssl::x509::X509::from_pem(public_key).map_err(tls_error)?;
+
+	let private_key = openssl::pkey::PKey::private_key_from_pem(private_key).map_err(tls_error)?;
+
+	let pkcs12 = openssl::pkcs12::Pkcs12::builder().build("", "admin", &private_key, &public_key).map_err(tls_error)?;
+	pkcs12.to_der().map_err(tls_error)
+}
+
+fn tls_error(err: openssl::error::ErrorStack) -> ::Error {
+	::Error::Tls(err.into())
+}
@@ -5,7 +5,8 @@
 
 extern crate winapi;
 
-pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::Path) -> Result<Vec<u8>, ::Error> {
+/// Converts a PEM-encoded client certificate and private key into a DER-encoded PKCS#12 archive with an empty password.
+pub(crate) fn pkcs12(public_key: &[u8], private_key: &[u8]) -> Result<Vec<u8>, ::Error> {
 	unsafe {
 		let public_key = parse_pem(public_key)?;
 
@@ -14,7 +15,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 		let cert_store = {
 			let cert_store = winapi::um::wincrypt::CertOpenStore(winapi::um::wincrypt::CERT_STORE_PROV_MEMORY, 0, 0, 0, ::std::ptr::null());
 			if cert_store.is_null() {
-				Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+				return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 			}
 
 			CertStore(cert_store)
@@ -30,7 +31,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 				winapi::um::wincrypt::CERT_STORE_ADD_NEW,
 				&mut public_key_context,
 			) != winapi::shared::minwindef::TRUE {
-				Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+				return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 			}
 
 			CertContext(public_key_context)
@@ -48,7 +49,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 				::std::ptr::null_mut(),
 				&mut private_key_decoded_buf_len,
 			) != winapi::shared::minwindef::TRUE {
-				Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+				return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 			}
 
 			let mut private_key_decoded_buf = vec![0u8; private_key_decoded_buf_len as _];
@@ -62,7 +63,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 				private_key_decoded_buf.as_mut_ptr() as _,
 				&mut private_key_decoded_buf_len,
 			) != winapi::shared::minwindef::TRUE {
-				Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+				return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 			}
 			private_key_decoded_buf.resize(private_key_decoded_buf_len as _, 0);
 
@@ -77,7 +78,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 				0,
 			);
 			if !winapi::shared::bcrypt::BCRYPT_SUCCESS(err) {
-				Err(format!("0x{:08X}", err))?;
+				return Err(::Error::Tls(format!("0x{:08X}", err).into()));
 			}
 
 			NCryptObject(crypto_provider)
@@ -96,7 +97,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 				winapi2::um::ncrypt::NCRYPT_SILENT_FLAG,
 			);
 			if !winapi::shared::bcrypt::BCRYPT_SUCCESS(err) {
-				Err(format!("0x{:08X}", err))?;
+				return Err(::Error::Tls(format!("0x{:08X}", err).into()));
 			}
 
 			NCryptObject(private_key)
@@ -112,7 +113,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 				0,
 			);
 			if !winapi::shared::bcrypt::BCRYPT_SUCCESS(err) {
-				Err(format!("0x{:08X}", err))?;
+				return Err(::Error::Tls(format!("0x{:08X}", err).into()));
 			}
 		}
 
@@ -130,7 +131,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 			0,
 			&private_key_context as *const _ as _,
 		) != winapi::shared::minwindef::TRUE {
-			Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+			return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 		}
 
 		let mut private_key_data = winapi::um::wincrypt::CRYPT_DATA_BLOB {
@@ -145,7 +146,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 			::std::ptr::null_mut(),
 			winapi::um::wincrypt::EXPORT_PRIVATE_KEYS,
 		) != winapi::shared::minwindef::TRUE {
-			Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+			return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 		}
 
 		let mut result = vec![0u8; private_key_data.cbData as _];
@@ -159,7 +160,7 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 			::std::ptr::null_mut(),
 			winapi::um::wincrypt::EXPORT_PRIVATE_KEYS,
 		) != winapi::shared::minwindef::TRUE {
-			Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+			return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 		}
 
 		result.resize(private_key_data.cbData as _, 0);
@@ -168,13 +169,8 @@ pub(crate) fn pkcs12(public_key: &::std::path::Path, private_key: &::std::path::
 	}
 }
 
-pub(crate) fn x509_from_pem(path: &::std::path::Path) -> Result<Vec<u8>, ::Error> {
-	parse_pem(path)
-}
-
-fn parse_pem(path: &::std::path::Path) -> Result<Vec<u8>, ::Error> {
+fn parse_pem(buf: &[u8]) -> Result<Vec<u8>, ::Error> {
 	unsafe {
-		let buf = ::std::fs::read(path)?;
 
 		let mut result_len = 0;
 		if winapi::um::wincrypt::CryptStringToBinaryA(
@@ -186,7 +182,7 @@ fn parse_pem(path: &::std::path::Path) -> Result<Vec<u8>, ::Error> {
 			::std::ptr::null_mut(),
 			::std::ptr::null_mut(),
 		) != winapi::shared::minwindef::TRUE {
-			Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+			return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 		}
 
 		let mut result = vec![0u8; result_len as _];
@@ -199,7 +195,7 @@ fn parse_pem(path: &::std::path::Path) -> Result<Vec<u8>, ::Error> {
 			::std::ptr::null_mut(),
 			::std::ptr::null_mut(),
 		) != winapi::shared::minwindef::TRUE {
-			Err(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()))?;
+			return Err(::Error::Tls(format!("0x{:08X}", winapi::um::errhandlingapi::GetLastError()).into()));
 		}
 		result.resize(result_len as _, 0);
 
@@ -216,7 +212,7 @@ mod winapi2 {
 
 	pub mod shared {
 		pub mod bcrypt {
-			use ::client::winapi::um::winnt::{ WCHAR };
+			use ::tls::winapi::um::winnt::{ WCHAR };
 
 			pub const LEGACY_RSAPRIVATE_BLOB: &[WCHAR] = wide!('C''A''P''I''P''R''I''V''A''T''E''B''L''O''B');
 		}
@@ -227,10 +223,10 @@ mod winapi2 {
 		pub mod ncrypt {
 			#![allow(non_camel_case_types)]
 
-			use ::client::winapi::shared::bcrypt::{ BCryptBufferDesc };
-			use ::client::winapi::shared::minwindef::{ DWORD, PBYTE };
-			use ::client::winapi::um::winnt::{ LONG, LPCWSTR, WCHAR };
-			use ::client::winapi::um::ncrypt::{ NCRYPT_HANDLE, NCRYPT_KEY_HANDLE, NCRYPT_PROV_HANDLE };
+			use ::tls::winapi::shared::bcrypt::{ BCryptBufferDesc };
+			use ::tls::winapi::shared::minwindef::{ DWORD, PBYTE };
+			use ::tls::winapi::um::winnt::{ LONG, LPCWSTR, WCHAR };
+			use ::tls::winapi::um::ncrypt::{ NCRYPT_HANDLE, NCRYPT_KEY_HANDLE, NCRYPT_PROV_HANDLE };
 
 			pub type SECURITY_STATUS = LONG;
 
@@ -0,0 +1,835 @@
+pub(crate) fn derive(input: &::syn::DeriveInput) -> Result<::proc_macro2::TokenStream, ::syn::Error> {
+	let ident = &input.ident;
+	let vis = &input.vis;
+
+	match &input.data {
+		::syn::Data::Struct(::syn::DataStruct { fields: ::syn::Fields::Named(_), .. }) => (),
+		_ => return Err(::syn::Error::new_spanned(input, "#[derive(CustomResource)] only supports structs with named fields")),
+	}
+
+	if !input.generics.params.is_empty() {
+		return Err(::syn::Error::new_spanned(&input.generics, "#[derive(CustomResource)] doesn't support generic types"));
+	}
+
+	let attrs = K8sAttrs::parse(input)?;
+	let kind = attrs.kind.unwrap_or_else(|| ident.to_string());
+	let list_ident = format_ident!("{}List", ident);
+
+	// Custom resources have the same operation IDs as the API server gives them in its OpenAPI spec,
+	// like `createStableExampleComV1NamespacedCronTab` for the `CronTab` kind of the `stable.example.com/v1` API group version.
+	let group_version: String =
+		attrs.group.split(&['.', '-'][..]).map(::capitalize)
+		.chain(std::iter::once(::capitalize(&attrs.version)))
+		.collect();
+
+	let resource_ty = quote! { #ident };
+	let list_ty = quote! { #list_ident };
+	let status_ty = quote! { ::k8s_openapi::apimachinery::pkg::apis::meta::v1::Status };
+	let watch_event_ty = quote! { ::k8s_openapi::apimachinery::pkg::apis::meta::v1::WatchEvent };
+
+	let operations = if attrs.namespaced {
+		vec![
+			Operation {
+				id: format!("create{}Namespaced{}", group_version, kind),
+				description: format!("create a {}", kind),
+				method: Method::Post,
+				path: Path { watch: false, namespace: true, name: false },
+				body: Some(quote! { #resource_ty }),
+				params: &[PRETTY],
+				responses: create_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("delete{}CollectionNamespaced{}", group_version, kind),
+				description: format!("delete collection of {}", kind),
+				method: Method::Delete,
+				path: Path { watch: false, namespace: true, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: delete_collection_responses(&status_ty, &list_ty),
+			},
+			Operation {
+				id: format!("delete{}Namespaced{}", group_version, kind),
+				description: format!("delete a {}", kind),
+				method: Method::Delete,
+				path: Path { watch: false, namespace: true, name: true },
+				body: None,
+				params: DELETE_PARAMS,
+				responses: delete_responses(&status_ty, &resource_ty),
+			},
+			Operation {
+				id: format!("list{}{}ForAllNamespaces", group_version, kind),
+				description: format!("list or watch objects of kind {}", kind),
+				method: Method::Get,
+				path: Path { watch: false, namespace: false, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: list_responses(&list_ty),
+			},
+			Operation {
+				id: format!("list{}Namespaced{}", group_version, kind),
+				description: format!("list or watch objects of kind {}", kind),
+				method: Method::Get,
+				path: Path { watch: false, namespace: true, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: list_responses(&list_ty),
+			},
+			Operation {
+				id: format!("patch{}Namespaced{}", group_version, kind),
+				description: format!("partially update the specified {}", kind),
+				method: Method::Patch,
+				path: Path { watch: false, namespace: true, name: true },
+				body: Some(quote! { ::k8s_openapi::apimachinery::pkg::apis::meta::v1::Patch }),
+				params: &[PRETTY],
+				responses: ok_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("read{}Namespaced{}", group_version, kind),
+				description: format!("read the specified {}", kind),
+				method: Method::Get,
+				path: Path { watch: false, namespace: true, name: true },
+				body: None,
+				params: &[PRETTY],
+				responses: ok_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("replace{}Namespaced{}", group_version, kind),
+				description: format!("replace the specified {}", kind),
+				method: Method::Put,
+				path: Path { watch: false, namespace: true, name: true },
+				body: Some(quote! { #resource_ty }),
+				params: &[PRETTY],
+				responses: replace_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("watch{}{}ListForAllNamespaces", group_version, kind),
+				description: format!("watch individual changes to a list of {}", kind),
+				method: Method::Get,
+				path: Path { watch: true, namespace: false, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: watch_responses(&watch_event_ty),
+			},
+			Operation {
+				id: format!("watch{}Namespaced{}", group_version, kind),
+				description: format!("watch changes to an object of kind {}", kind),
+				method: Method::Get,
+				path: Path { watch: true, namespace: true, name: true },
+				body: None,
+				params: LIST_PARAMS,
+				responses: watch_responses(&watch_event_ty),
+			},
+			Operation {
+				id: format!("watch{}Namespaced{}List", group_version, kind),
+				description: format!("watch individual changes to a list of {}", kind),
+				method: Method::Get,
+				path: Path { watch: true, namespace: true, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: watch_responses(&watch_event_ty),
+			},
+		]
+	}
+	else {
+		vec![
+			Operation {
+				id: format!("create{}{}", group_version, kind),
+				description: format!("create a {}", kind),
+				method: Method::Post,
+				path: Path { watch: false, namespace: false, name: false },
+				body: Some(quote! { #resource_ty }),
+				params: &[PRETTY],
+				responses: create_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("delete{}Collection{}", group_version, kind),
+				description: format!("delete collection of {}", kind),
+				method: Method::Delete,
+				path: Path { watch: false, namespace: false, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: delete_collection_responses(&status_ty, &list_ty),
+			},
+			Operation {
+				id: format!("delete{}{}", group_version, kind),
+				description: format!("delete a {}", kind),
+				method: Method::Delete,
+				path: Path { watch: false, namespace: false, name: true },
+				body: None,
+				params: DELETE_PARAMS,
+				responses: delete_responses(&status_ty, &resource_ty),
+			},
+			Operation {
+				id: format!("list{}{}", group_version, kind),
+				description: format!("list or watch objects of kind {}", kind),
+				method: Method::Get,
+				path: Path { watch: false, namespace: false, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: list_responses(&list_ty),
+			},
+			Operation {
+				id: format!("patch{}{}", group_version, kind),
+				description: format!("partially update the specified {}", kind),
+				method: Method::Patch,
+				path: Path { watch: false, namespace: false, name: true },
+				body: Some(quote! { ::k8s_openapi::apimachinery::pkg::apis::meta::v1::Patch }),
+				params: &[PRETTY],
+				responses: ok_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("read{}{}", group_version, kind),
+				description: format!("read the specified {}", kind),
+				method: Method::Get,
+				path: Path { watch: false, namespace: false, name: true },
+				body: None,
+				params: &[PRETTY],
+				responses: ok_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("replace{}{}", group_version, kind),
+				description: format!("replace the specified {}", kind),
+				method: Method::Put,
+				path: Path { watch: false, namespace: false, name: true },
+				body: Some(quote! { #resource_ty }),
+				params: &[PRETTY],
+				responses: replace_responses(&resource_ty),
+			},
+			Operation {
+				id: format!("watch{}{}", group_version, kind),
+				description: format!("watch changes to an object of kind {}", kind),
+				method: Method::Get,
+				path: Path { watch: true, namespace: false, name: true },
+				body: None,
+				params: LIST_PARAMS,
+				responses: watch_responses(&watch_event_ty),
+			},
+			Operation {
+				id: format!("watch{}{}List", group_version, kind),
+				description: format!("watch individual changes to a list of {}", kind),
+				method: Method::Get,
+				path: Path { watch: true, namespace: false, name: false },
+				body: None,
+				params: LIST_PARAMS,
+				responses: watch_responses(&watch_event_ty),
+			},
+		]
+	};
+
+	let mut result = quote! { };
+
+	for operation in operations {
+		result.extend(operation.generate(ident, vis, &kind, &attrs.group, &attrs.version, &attrs.plural));
+	}
+
+	let list_doc = format!(" {}List is a list of {}", kind, kind);
+
+	result.extend(quote! {
+		#[doc = #list_doc]
+		#[derive(Debug, Default)]
+		#vis struct #list_ident {
+			/// APIVersion defines the versioned schema of this representation of an object.
+			pub api_version: Option<String>,
+
+			/// List of objects.
+			pub items: Vec<#ident>,
+
+			/// Kind is a string value representing the REST resource this object represents.
+			pub kind: Option<String>,
+
+			/// Standard list metadata.
+			pub metadata: Option<::k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta>,
+		}
+
+		impl<'de> ::k8s_openapi::serde::Deserialize<'de> for #list_ident {
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::k8s_openapi::serde::Deserializer<'de> {
+				let mut data: ::k8s_openapi::serde_json::Map<String, ::k8s_openapi::serde_json::Value> = ::k8s_openapi::serde::Deserialize::deserialize(deserializer)?;
+
+				let api_version = data.remove("apiVersion").map(::k8s_openapi::serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+				let kind = data.remove("kind").map(::k8s_openapi::serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+				let metadata = data.remove("metadata").map(::k8s_openapi::serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+				let items = data.remove("items").ok_or_else(|| ::k8s_openapi::serde::de::Error::missing_field("items"))?;
+
+				Ok(#list_ident {
+					api_version: api_version.map_err(::k8s_openapi::serde::de::Error::custom)?,
+					kind: kind.map_err(::k8s_openapi::serde::de::Error::custom)?,
+					metadata: metadata.map_err(::k8s_openapi::serde::de::Error::custom)?,
+					items: ::k8s_openapi::serde_json::from_value(items).map_err(::k8s_openapi::serde::de::Error::custom)?,
+				})
+			}
+		}
+
+		impl ::k8s_openapi::serde::Serialize for #list_ident {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::k8s_openapi::serde::Serializer {
+				use ::k8s_openapi::serde::ser::SerializeMap;
+
+				let mut map = serializer.serialize_map(None)?;
+				if let Some(api_version) = &self.api_version {
+					map.serialize_entry("apiVersion", api_version)?;
+				}
+				map.serialize_entry("items", &self.items)?;
+				if let Some(kind) = &self.kind {
+					map.serialize_entry("kind", kind)?;
+				}
+				if let Some(metadata) = &self.metadata {
+					map.serialize_entry("metadata", metadata)?;
+				}
+				map.end()
+			}
+		}
+
+		impl ::k8s_openapi::List for #list_ident {
+			type Item = #ident;
+
+			::k8s_openapi::k8s_if_le_1_7! {
+				fn continue_token(&self) -> Option<&str> {
+					None
+				}
+			}
+
+			::k8s_openapi::k8s_if_ge_1_8! {
+				fn continue_token(&self) -> Option<&str> {
+					self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+				}
+			}
+
+			fn into_items(self) -> Vec<Self::Item> {
+				self.items
+			}
+		}
+	});
+
+	Ok(result)
+}
+
+/// The values of the `#[k8s(...)]` attribute of a custom resource type.
+struct K8sAttrs {
+	group: String,
+	version: String,
+	kind: Option<String>,
+	plural: String,
+	namespaced: bool,
+}
+
+impl K8sAttrs {
+	fn parse(input: &::syn::DeriveInput) -> Result<Self, ::syn::Error> {
+		let mut group = None;
+		let mut version = None;
+		let mut kind = None;
+		let mut plural = None;
+		let mut namespaced = false;
+
+		for attr in &input.attrs {
+			if !attr.path.is_ident("k8s") {
+				continue;
+			}
+
+			let nested = match attr.parse_meta()? {
+				::syn::Meta::List(list) => list.nested,
+				meta => return Err(::syn::Error::new_spanned(meta, "expected #[k8s(...)]")),
+			};
+
+			for meta in nested {
+				match meta {
+					::syn::NestedMeta::Meta(::syn::Meta::Path(ref path)) if path.is_ident("namespaced") => namespaced = true,
+
+					::syn::NestedMeta::Meta(::syn::Meta::NameValue(::syn::MetaNameValue { ref path, lit: ::syn::Lit::Str(ref value), .. })) => {
+						let field =
+							if path.is_ident("group") { &mut group }
+							else if path.is_ident("version") { &mut version }
+							else if path.is_ident("kind") { &mut kind }
+							else if path.is_ident("plural") { &mut plural }
+							else { return Err(::syn::Error::new_spanned(path, "expected one of `group`, `version`, `kind`, `plural` or `namespaced`")); };
+						*field = Some(value.value());
+					},
+
+					meta => return Err(::syn::Error::new_spanned(meta, "expected one of `group`, `version`, `kind`, `plural` or `namespaced`")),
+				}
+			}
+		}
+
+		let missing = |name: &str| ::syn::Error::new_spanned(&input.ident, format!("#[derive(CustomResource)] requires #[k8s({} = \"...\")]", name));
+
+		Ok(K8sAttrs {
+			group: group.ok_or_else(|| missing("group"))?,
+			version: version.ok_or_else(|| missing("version"))?,
+			kind,
+			plural: plural.ok_or_else(|| missing("plural"))?,
+			namespaced,
+		})
+	}
+}
+
+/// An API operation of a custom resource, like `create` or `list`.
+struct Operation {
+	id: String,
+	description: String,
+	method: Method,
+	path: Path,
+	body: Option<::proc_macro2::TokenStream>,
+	params: &'static [Param],
+	responses: Vec<Response>,
+}
+
+#[derive(Clone, Copy)]
+enum Method {
+	Delete,
+	Get,
+	Patch,
+	Post,
+	Put,
+}
+
+/// The parts of the URL of an operation besides the API group version and plural name.
+struct Path {
+	watch: bool,
+	namespace: bool,
+	name: bool,
+}
+
+/// A query string parameter of an operation.
+struct Param {
+	name: &'static str,
+	query_name: &'static str,
+	ty: ParamType,
+	description: &'static str,
+}
+
+#[derive(Clone, Copy)]
+enum ParamType {
+	Bool,
+	I64,
+	Str,
+}
+
+/// A variant of the response type of an operation.
+enum Response {
+	/// A response with the given status code whose body is a JSON value of the given type.
+	Json(&'static str, &'static str, ::proc_macro2::TokenStream),
+
+	/// A response with the given status code whose body is a sequence of JSON values of the given type, one per line.
+	JsonLines(&'static str, &'static str, ::proc_macro2::TokenStream),
+
+	/// A successful response whose body is either a `Status` or a JSON value of the given type,
+	/// parsed as the `OkStatus` and `OkValue` variants respectively.
+	StatusOr(::proc_macro2::TokenStream, ::proc_macro2::TokenStream),
+
+	/// A response with the given status code whose body is ignored.
+	Empty(&'static str, &'static str),
+}
+
+const CONTINUE: Param = Param {
+	name: "continue_",
+	query_name: "continue",
+	ty: ParamType::Str,
+	description: "The continue option should be set when retrieving more results from the server. Since this value is server defined, clients may only use the continue value from a previous query result with identical query parameters (except for the value of continue).",
+};
+
+const FIELD_SELECTOR: Param = Param {
+	name: "field_selector",
+	query_name: "fieldSelector",
+	ty: ParamType::Str,
+	description: "A selector to restrict the list of returned objects by their fields. Defaults to everything.",
+};
+
+const GRACE_PERIOD_SECONDS: Param = Param {
+	name: "grace_period_seconds",
+	query_name: "gracePeriodSeconds",
+	ty: ParamType::I64,
+	description: "The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately.",
+};
+
+const LABEL_SELECTOR: Param = Param {
+	name: "label_selector",
+	query_name: "labelSelector",
+	ty: ParamType::Str,
+	description: "A selector to restrict the list of returned objects by their labels. Defaults to everything.",
+};
+
+const LIMIT: Param = Param {
+	name: "limit",
+	query_name: "limit",
+	ty: ParamType::I64,
+	description: "limit is a maximum number of responses to return for a list call. If more items exist, the server will set the `continue` field on the list metadata to a value that can be used with the same initial query to retrieve the next set of results.",
+};
+
+const ORPHAN_DEPENDENTS: Param = Param {
+	name: "orphan_dependents",
+	query_name: "orphanDependents",
+	ty: ParamType::Bool,
+	description: "Deprecated: please use the PropagationPolicy. Should the dependent objects be orphaned.",
+};
+
+const PRETTY: Param = Param {
+	name: "pretty",
+	query_name: "pretty",
+	ty: ParamType::Str,
+	description: "If 'true', then the output is pretty printed.",
+};
+
+const PROPAGATION_POLICY: Param = Param {
+	name: "propagation_policy",
+	query_name: "propagationPolicy",
+	ty: ParamType::Str,
+	description: "Whether and how garbage collection will be performed. Acceptable values are: 'Orphan', 'Background' and 'Foreground'.",
+};
+
+const RESOURCE_VERSION: Param = Param {
+	name: "resource_version",
+	query_name: "resourceVersion",
+	ty: ParamType::Str,
+	description: "When specified with a watch call, shows changes that occur after that particular version of a resource. Defaults to changes from the beginning of history.",
+};
+
+const TIMEOUT_SECONDS: Param = Param {
+	name: "timeout_seconds",
+	query_name: "timeoutSeconds",
+	ty: ParamType::I64,
+	description: "Timeout for the list/watch call. This limits the duration of the call, regardless of any activity or inactivity.",
+};
+
+const WATCH: Param = Param {
+	name: "watch",
+	query_name: "watch",
+	ty: ParamType::Bool,
+	description: "Watch for changes to the described resources and return them as a stream of add, update, and remove notifications. Specify resourceVersion.",
+};
+
+const DELETE_PARAMS: &[Param] = &[GRACE_PERIOD_SECONDS, ORPHAN_DEPENDENTS, PRETTY, PROPAGATION_POLICY];
+
+const LIST_PARAMS: &[Param] = &[CONTINUE, FIELD_SELECTOR, LABEL_SELECTOR, LIMIT, PRETTY, RESOURCE_VERSION, TIMEOUT_SECONDS, WATCH];
+
+fn create_responses(resource_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::Json("Ok", "OK", resource_ty.clone()),
+		Response::Json("Created", "CREATED", resource_ty.clone()),
+		Response::Json("Accepted", "ACCEPTED", resource_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+fn delete_collection_responses(status_ty: &::proc_macro2::TokenStream, list_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::StatusOr(status_ty.clone(), list_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+fn delete_responses(status_ty: &::proc_macro2::TokenStream, resource_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::StatusOr(status_ty.clone(), resource_ty.clone()),
+		Response::Json("Accepted", "ACCEPTED", status_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+fn list_responses(list_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::Json("Ok", "OK", list_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+fn ok_responses(resource_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::Json("Ok", "OK", resource_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+fn replace_responses(resource_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::Json("Ok", "OK", resource_ty.clone()),
+		Response::Json("Created", "CREATED", resource_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+fn watch_responses(watch_event_ty: &::proc_macro2::TokenStream) -> Vec<Response> {
+	vec![
+		Response::JsonLines("Ok", "OK", watch_event_ty.clone()),
+		Response::Empty("Unauthorized", "UNAUTHORIZED"),
+	]
+}
+
+impl Operation {
+	/// Generates the function that prepares the request of this operation, and the response type that parses its response,
+	/// in the same shape as the code generated for the operations of built-in resource kinds.
+	fn generate(
+		&self,
+		ident: &::syn::Ident,
+		vis: &::syn::Visibility,
+		kind: &str,
+		group: &str,
+		version: &str,
+		plural: &str,
+	) -> ::proc_macro2::TokenStream {
+		let fn_name = get_rust_ident(&self.id);
+		let fn_ident = format_ident!("{}", fn_name);
+		let response_name = format!("{}Response", ::capitalize(&self.id));
+		let response_ident = format_ident!("{}", response_name);
+
+		// Function
+
+		let mut doc = vec![
+			format!(" {}", self.description),
+			"".to_string(),
+			format!(" Use [`{0}`](./enum.{0}.html) to parse the HTTP response.", response_name),
+			"".to_string(),
+			" # Arguments".to_string(),
+		];
+		let mut fn_params = vec![];
+		let mut format_args = vec![];
+
+		if self.path.name {
+			doc.extend(vec!["".to_string(), " * `name`".to_string(), "".to_string(), format!("     name of the {}", kind)]);
+			fn_params.push(quote! { name: &str, });
+			format_args.push(quote! { name = name, });
+		}
+
+		if self.path.namespace {
+			doc.extend(vec!["".to_string(), " * `namespace`".to_string(), "".to_string(), "     object name and auth scope, such as for teams and projects".to_string()]);
+			fn_params.push(quote! { namespace: &str, });
+			format_args.push(quote! { namespace = namespace, });
+		}
+
+		if let Some(body) = &self.body {
+			doc.extend(vec!["".to_string(), " * `body`".to_string()]);
+			fn_params.push(quote! { body: &#body, });
+		}
+
+		let mut query_pairs = vec![];
+		for param in self.params {
+			doc.extend(vec!["".to_string(), format!(" * `{}`", param.name), "".to_string(), format!("     {}", param.description)]);
+
+			let param_ident = format_ident!("{}", param.name);
+			let query_name = param.query_name;
+			match param.ty {
+				ParamType::Bool => {
+					fn_params.push(quote! { #param_ident: Option<bool>, });
+					query_pairs.push(quote! {
+						if let Some(#param_ident) = #param_ident {
+							__query_pairs.append_pair(#query_name, &#param_ident.to_string());
+						}
+					});
+				},
+
+				ParamType::I64 => {
+					fn_params.push(quote! { #param_ident: Option<i64>, });
+					query_pairs.push(quote! {
+						if let Some(#param_ident) = #param_ident {
+							__query_pairs.append_pair(#query_name, &#param_ident.to_string());
+						}
+					});
+				},
+
+				ParamType::Str => {
+					fn_params.push(quote! { #param_ident: Option<&str>, });
+					query_pairs.push(quote! {
+						if let Some(#param_ident) = #param_ident {
+							__query_pairs.append_pair(#query_name, #param_ident);
+						}
+					});
+				},
+			}
+		}
+
+		let mut url = format!("/apis/{}/{}", group, version);
+		if self.path.watch {
+			url.push_str("/watch");
+		}
+		if self.path.namespace {
+			url.push_str("/namespaces/{namespace}");
+		}
+		url.push('/');
+		url.push_str(plural);
+		if self.path.name {
+			url.push_str("/{name}");
+		}
+		url.push('?');
+
+		let url =
+			if format_args.is_empty() {
+				quote! { #url.to_string() }
+			}
+			else {
+				quote! { format!(#url, #(#format_args)*) }
+			};
+
+		let method = match self.method {
+			Method::Delete => quote! { delete },
+			Method::Get => quote! { get },
+			Method::Patch => quote! { patch },
+			Method::Post => quote! { post },
+			Method::Put => quote! { put },
+		};
+
+		let body =
+			if self.body.is_some() {
+				quote! { let __body = ::k8s_openapi::serde_json::to_vec(&body).map_err(::k8s_openapi::RequestError::Json)?; }
+			}
+			else {
+				quote! { let __body = vec![]; }
+			};
+
+		// Response type
+
+		let response_doc = format!(" Parses the HTTP response of [`{0}::{1}`](./struct.{0}.html#method.{1})", ident, fn_name);
+
+		let mut variants = vec![];
+		let mut arms = vec![];
+		let mut framing = quote! { ::k8s_openapi::ResponseFraming::JsonValue };
+		let mut list_response = None;
+
+		for response in &self.responses {
+			match response {
+				Response::Json(variant, status_code, ty) => {
+					let variant = format_ident!("{}", variant);
+					let status_code = format_ident!("{}", status_code);
+					variants.push(quote! { #variant(#ty), });
+					arms.push(quote! {
+						::k8s_openapi::http::StatusCode::#status_code => {
+							let result = match ::k8s_openapi::serde_json::from_slice(buf) {
+								Ok(value) => value,
+								Err(ref err) if err.is_eof() => return Err(::k8s_openapi::ResponseError::NeedMoreData),
+								Err(err) => return Err(::k8s_openapi::ResponseError::Json(err)),
+							};
+							Ok((#response_ident::#variant(result), buf.len()))
+						},
+					});
+
+					if self.id.starts_with("list") {
+						list_response = Some(quote! {
+							impl ::k8s_openapi::ListResponse for #response_ident {
+								type List = #ty;
+
+								fn into_list(self) -> Option<Self::List> {
+									match self {
+										#response_ident::#variant(list) => Some(list),
+										_ => None,
+									}
+								}
+							}
+						});
+					}
+				},
+
+				Response::JsonLines(variant, status_code, ty) => {
+					let variant = format_ident!("{}", variant);
+					let status_code = format_ident!("{}", status_code);
+					variants.push(quote! { #variant(#ty), });
+					arms.push(quote! {
+						::k8s_openapi::http::StatusCode::#status_code => {
+							let line_len = match buf.iter().position(|&b| b == b'\n') {
+								Some(newline) => newline + 1,
+								None => return Err(::k8s_openapi::ResponseError::NeedMoreData),
+							};
+							let result = ::k8s_openapi::serde_json::from_slice(&buf[..line_len]).map_err(::k8s_openapi::ResponseError::Json)?;
+							Ok((#response_ident::#variant(result), line_len))
+						},
+					});
+					framing = quote! { ::k8s_openapi::ResponseFraming::JsonLines };
+				},
+
+				Response::StatusOr(status_ty, ty) => {
+					variants.push(quote! { OkStatus(#status_ty), OkValue(#ty), });
+					arms.push(quote! {
+						::k8s_openapi::http::StatusCode::OK => {
+							let result: ::k8s_openapi::tagged::StatusOr<_> = match ::k8s_openapi::serde_json::from_slice(buf) {
+								Ok(value) => value,
+								Err(ref err) if err.is_eof() => return Err(::k8s_openapi::ResponseError::NeedMoreData),
+								Err(err) => return Err(::k8s_openapi::ResponseError::Json(err)),
+							};
+							match result {
+								::k8s_openapi::tagged::StatusOr::Status(result) => Ok((#response_ident::OkStatus(result), buf.len())),
+								::k8s_openapi::tagged::StatusOr::Value(result) => Ok((#response_ident::OkValue(result), buf.len())),
+							}
+						},
+					});
+				},
+
+				Response::Empty(variant, status_code) => {
+					let variant = format_ident!("{}", variant);
+					let status_code = format_ident!("{}", status_code);
+					variants.push(quote! { #variant, });
+					arms.push(quote! {
+						::k8s_openapi::http::StatusCode::#status_code => Ok((#response_ident::#variant, 0)),
+					});
+				},
+			}
+		}
+
+		quote! {
+			impl #ident {
+				#(#[doc = #doc])*
+				#[allow(clippy::too_many_arguments)]
+				#vis fn #fn_ident(
+					#(#fn_params)*
+				) -> Result<::k8s_openapi::http::Request<Vec<u8>>, ::k8s_openapi::RequestError> {
+					let __url = #url;
+					let mut __query_pairs = ::k8s_openapi::url::form_urlencoded::Serializer::new(__url);
+					#(#query_pairs)*
+					let __url = __query_pairs.finish();
+
+					let mut __request = ::k8s_openapi::http::Request::#method(__url);
+					#body
+					__request.body(__body).map_err(::k8s_openapi::RequestError::Http)
+				}
+			}
+
+			#[doc = #response_doc]
+			#[allow(dead_code)] // Callers usually only match on some of the variants
+			#[derive(Debug)]
+			#vis enum #response_ident {
+				#(#variants)*
+				Other,
+			}
+
+			impl ::k8s_openapi::Response for #response_ident {
+				fn try_from_parts(status_code: ::k8s_openapi::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::k8s_openapi::ResponseError> {
+					match status_code {
+						#(#arms)*
+						_ => Ok((#response_ident::Other, 0)),
+					}
+				}
+
+				fn framing() -> ::k8s_openapi::ResponseFraming {
+					#framing
+				}
+			}
+
+			#list_response
+		}
+	}
+}
+
+/// Converts an operation ID like `createFooComV1NamespacedFooBar` into a function name like `create_foo_com_v1_namespaced_foo_bar`,
+/// the same way as the code generator does for the operations of built-in resource kinds.
+fn get_rust_ident(name: &str) -> String {
+	let mut result = String::new();
+
+	let chars =
+		name.chars()
+		.zip(std::iter::once(None).chain(name.chars().map(|c| Some(c.is_uppercase()))))
+		.zip(name.chars().skip(1).map(|c| Some(c.is_uppercase())).chain(std::iter::once(None)));
+
+	for ((c, previous), next) in chars {
+		if c.is_uppercase() {
+			match (previous, next) {
+				(Some(false), _) |
+				(Some(true), Some(false)) => result.push('_'),
+				_ => (),
+			}
+
+			result.extend(c.to_lowercase());
+		}
+		else {
+			result.push(match c {
+				'-' => '_',
+				c => c,
+			});
+		}
+	}
+
+	result
+}
@@ -0,0 +1,262 @@
+pub(crate) fn derive(input: &::syn::DeriveInput) -> Result<::proc_macro2::TokenStream, ::syn::Error> {
+	let ident = &input.ident;
+
+	let mut generics = input.generics.clone();
+	for type_param in generics.type_params_mut() {
+		type_param.bounds.push(parse_quote!(::k8s_openapi::schema::JsonSchema));
+	}
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	let container_attrs = SerdeAttrs::parse(&input.attrs)?;
+
+	let schema = match &input.data {
+		::syn::Data::Struct(::syn::DataStruct { fields: ::syn::Fields::Named(fields), .. }) => {
+			let mut properties = vec![];
+
+			for field in &fields.named {
+				let field_attrs = SerdeAttrs::parse(&field.attrs)?;
+				if field_attrs.skip {
+					continue;
+				}
+				if field_attrs.flatten {
+					return Err(::syn::Error::new_spanned(field, "#[derive(JsonSchema)] doesn't support #[serde(flatten)]"));
+				}
+
+				let field_ident = field.ident.as_ref().expect("named field has no name");
+				let name = match field_attrs.rename {
+					Some(rename) => rename,
+					None => rename_field(&field_ident.to_string(), container_attrs.rename_all.as_deref())?,
+				};
+
+				let ty = &field.ty;
+				let description = match doc_comment(&field.attrs) {
+					Some(description) => quote! { property.description = Some(#description.to_string()); },
+					None => quote! { },
+				};
+				let required =
+					if field_attrs.default || is_option(ty) {
+						quote! { }
+					}
+					else {
+						quote! { required.push(#name.to_string()); }
+					};
+
+				properties.push(quote! {
+					{
+						let mut property = <#ty as ::k8s_openapi::schema::JsonSchema>::json_schema();
+						#description
+						properties.insert(#name.to_string(), property);
+						#required
+					}
+				});
+			}
+
+			quote! {
+				let mut properties = ::std::collections::BTreeMap::new();
+				let mut required: Vec<String> = vec![];
+				#(#properties)*
+
+				let mut schema = ::k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps {
+					properties: Some(properties),
+					type_: Some("object".to_string()),
+					..Default::default()
+				};
+				if !required.is_empty() {
+					schema.required = Some(required);
+				}
+			}
+		},
+
+		::syn::Data::Struct(::syn::DataStruct { fields: ::syn::Fields::Unnamed(fields), .. }) if fields.unnamed.len() == 1 => {
+			let ty = &fields.unnamed[0].ty;
+			quote! {
+				let mut schema = <#ty as ::k8s_openapi::schema::JsonSchema>::json_schema();
+			}
+		},
+
+		::syn::Data::Enum(data) => {
+			let mut values = vec![];
+
+			for variant in &data.variants {
+				if let ::syn::Fields::Named(_) | ::syn::Fields::Unnamed(_) = variant.fields {
+					return Err(::syn::Error::new_spanned(variant, "#[derive(JsonSchema)] only supports enums whose variants don't have fields"));
+				}
+
+				let variant_attrs = SerdeAttrs::parse(&variant.attrs)?;
+				if variant_attrs.skip {
+					continue;
+				}
+
+				let name = match variant_attrs.rename {
+					Some(rename) => rename,
+					None => rename_variant(&variant.ident.to_string(), container_attrs.rename_all.as_deref())?,
+				};
+
+				values.push(quote! {
+					::k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSON(::k8s_openapi::serde_json::Value::String(#name.to_string()))
+				});
+			}
+
+			quote! {
+				let mut schema = ::k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps {
+					enum_: Some(vec![#(#values),*]),
+					type_: Some("string".to_string()),
+					..Default::default()
+				};
+			}
+		},
+
+		_ => return Err(::syn::Error::new_spanned(
+			input,
+			"#[derive(JsonSchema)] only supports structs with named fields, newtype structs, and enums whose variants don't have fields")),
+	};
+
+	let description = match doc_comment(&input.attrs) {
+		Some(description) => quote! { schema.description = Some(#description.to_string()); },
+		None => quote! { },
+	};
+
+	Ok(quote! {
+		impl #impl_generics ::k8s_openapi::schema::JsonSchema for #ident #ty_generics #where_clause {
+			#[allow(unused_mut)]
+			fn json_schema() -> ::k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::JSONSchemaProps {
+				#schema
+				#description
+				schema
+			}
+		}
+	})
+}
+
+/// The serde attributes of a container, field or variant that affect its schema.
+#[derive(Default)]
+struct SerdeAttrs {
+	default: bool,
+	flatten: bool,
+	rename: Option<String>,
+	rename_all: Option<String>,
+	skip: bool,
+}
+
+impl SerdeAttrs {
+	fn parse(attrs: &[::syn::Attribute]) -> Result<Self, ::syn::Error> {
+		let mut result: SerdeAttrs = Default::default();
+
+		for attr in attrs {
+			if !attr.path.is_ident("serde") {
+				continue;
+			}
+
+			let nested = match attr.parse_meta()? {
+				::syn::Meta::List(list) => list.nested,
+				meta => return Err(::syn::Error::new_spanned(meta, "expected #[serde(...)]")),
+			};
+
+			for meta in nested {
+				match meta {
+					::syn::NestedMeta::Meta(::syn::Meta::Path(ref path)) if path.is_ident("default") => result.default = true,
+					::syn::NestedMeta::Meta(::syn::Meta::Path(ref path)) if path.is_ident("flatten") => result.flatten = true,
+					::syn::NestedMeta::Meta(::syn::Meta::Path(ref path)) if path.is_ident("skip") || path.is_ident("skip_deserializing") => result.skip = true,
+
+					::syn::NestedMeta::Meta(::syn::Meta::NameValue(ref name_value)) if name_value.path.is_ident("default") => result.default = true,
+
+					::syn::NestedMeta::Meta(::syn::Meta::NameValue(::syn::MetaNameValue { ref path, lit: ::syn::Lit::Str(ref value), .. })) if path.is_ident("rename") =>
+						result.rename = Some(value.value()),
+
+					::syn::NestedMeta::Meta(::syn::Meta::NameValue(::syn::MetaNameValue { ref path, lit: ::syn::Lit::Str(ref value), .. })) if path.is_ident("rename_all") =>
+						result.rename_all = Some(value.value()),
+
+					// Other attributes, like `skip_serializing_if`, don't affect the schema
+					_ => (),
+				}
+			}
+		}
+
+		Ok(result)
+	}
+}
+
+/// Returns the doc comment of an item, with the lines trimmed and joined with newlines.
+fn doc_comment(attrs: &[::syn::Attribute]) -> Option<String> {
+	let lines: Vec<_> =
+		attrs.iter()
+		.filter(|attr| attr.path.is_ident("doc"))
+		.filter_map(|attr| match attr.parse_meta() {
+			Ok(::syn::Meta::NameValue(::syn::MetaNameValue { lit: ::syn::Lit::Str(value), .. })) => Some(value.value().trim().to_string()),
+			_ => None,
+		})
+		.collect();
+
+	let doc_comment = lines.join("\n");
+	let doc_comment = doc_comment.trim();
+	if doc_comment.is_empty() {
+		None
+	}
+	else {
+		Some(doc_comment.to_string())
+	}
+}
+
+fn is_option(ty: &::syn::Type) -> bool {
+	match ty {
+		::syn::Type::Path(::syn::TypePath { qself: None, path }) => matches!(path.segments.last(), Some(segment) if segment.ident == "Option"),
+		_ => false,
+	}
+}
+
+/// Renames a field like serde's `rename_all`. Fields are expected to be in snake_case.
+fn rename_field(name: &str, rename_all: Option<&str>) -> Result<String, ::syn::Error> {
+	let name = name.trim_start_matches("r#");
+
+	Ok(match rename_all {
+		None | Some("snake_case") => name.to_string(),
+		Some("lowercase") => name.to_lowercase(),
+		Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => name.to_uppercase(),
+		Some("PascalCase") => name.split('_').map(::capitalize).collect(),
+		Some("camelCase") => {
+			let pascal_case: String = name.split('_').map(::capitalize).collect();
+			uncapitalize(&pascal_case)
+		},
+		Some("kebab-case") => name.replace('_', "-"),
+		Some("SCREAMING-KEBAB-CASE") => name.replace('_', "-").to_uppercase(),
+		Some(rename_all) => return Err(unknown_rename_all(rename_all)),
+	})
+}
+
+/// Renames a variant like serde's `rename_all`. Variants are expected to be in PascalCase.
+fn rename_variant(name: &str, rename_all: Option<&str>) -> Result<String, ::syn::Error> {
+	let snake_case = || {
+		let mut result = String::new();
+		for (i, c) in name.char_indices() {
+			if c.is_uppercase() && i > 0 {
+				result.push('_');
+			}
+			result.extend(c.to_lowercase());
+		}
+		result
+	};
+
+	Ok(match rename_all {
+		None | Some("PascalCase") => name.to_string(),
+		Some("lowercase") => name.to_lowercase(),
+		Some("UPPERCASE") => name.to_uppercase(),
+		Some("camelCase") => uncapitalize(name),
+		Some("snake_case") => snake_case(),
+		Some("SCREAMING_SNAKE_CASE") => snake_case().to_uppercase(),
+		Some("kebab-case") => snake_case().replace('_', "-"),
+		Some("SCREAMING-KEBAB-CASE") => snake_case().replace('_', "-").to_uppercase(),
+		Some(rename_all) => return Err(unknown_rename_all(rename_all)),
+	})
+}
+
+fn uncapitalize(s: &str) -> String {
+	let mut chars = s.chars();
+	match chars.next() {
+		Some(first) => first.to_lowercase().chain(chars).collect(),
+		None => String::new(),
+	}
+}
+
+fn unknown_rename_all(rename_all: &str) -> ::syn::Error {
+	::syn::Error::new(::proc_macro2::Span::call_site(), format!("unknown rename_all rule {:?}", rename_all))
+}
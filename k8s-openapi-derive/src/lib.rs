@@ -0,0 +1,109 @@
+//! Custom derives for the Kubernetes API bindings in the [`k8s-openapi`](https://docs.rs/k8s-openapi) crate.
+//!
+//! # `#[derive(CustomResource)]`
+//!
+//! Generates the API operations of a custom resource type, in the same shape as the API operations of the built-in resource types of `k8s-openapi`.
+//! The `api` feature of `k8s-openapi` must be enabled.
+//!
+//! The type is the whole custom resource, ie it has the `apiVersion`, `kind` and `metadata` fields as well as the resource-specific ones like `spec`.
+//! Its group, version and plural name are set with the `#[k8s(...)]` attribute. The kind defaults to the name of the type,
+//! and the resource is cluster-scoped unless `namespaced` is set.
+//!
+//! The derive generates:
+//!
+//! - The functions that prepare the requests of the API operations of the resource, as associated functions of the type.
+//!   Their names are the operation IDs the API server gives them in its OpenAPI spec, like `create_stable_example_com_v1_namespaced_cron_tab`.
+//!
+//! - A response type for each function, like `CreateStableExampleComV1NamespacedCronTabResponse`, that implements `k8s_openapi::Response`.
+//!   The response types of list operations also implement `k8s_openapi::ListResponse`.
+//!
+//! - A list type, like `CronTabList`, that implements `k8s_openapi::List`.
+//!
+//! ```rust,ignore
+//! #[macro_use] extern crate k8s_openapi_derive;
+//! #[macro_use] extern crate serde_derive;
+//!
+//! use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+//!
+//! #[derive(CustomResource, Debug, Deserialize, Serialize)]
+//! #[k8s(group = "stable.example.com", version = "v1", plural = "crontabs", namespaced)]
+//! struct CronTab {
+//!     #[serde(rename = "apiVersion")]
+//!     api_version: Option<String>,
+//!     kind: Option<String>,
+//!     metadata: Option<meta::ObjectMeta>,
+//!     spec: Option<CronTabSpec>,
+//! }
+//!
+//! let request = CronTab::list_stable_example_com_v1_namespaced_cron_tab("default", None, None, None, None, None, None, None, None)?;
+//! assert_eq!(request.uri(), "/apis/stable.example.com/v1/namespaces/default/crontabs?");
+//! ```
+//!
+//! # `#[derive(JsonSchema)]`
+//!
+//! Implements `k8s_openapi::schema::JsonSchema` for a type, so that the `JSONSchemaProps` of a custom resource definition's validation
+//! can be generated from the Rust types of the custom resource instead of being written by hand. The `schema` feature of `k8s-openapi` must be enabled.
+//!
+//! - A struct with named fields has the schema of an object. Each field is a property with the schema of the field's type.
+//!   Fields are required unless their type is `Option<_>` or they have `#[serde(default)]`.
+//!
+//! - A newtype struct has the schema of its field.
+//!
+//! - An enum whose variants don't have fields has the schema of a string whose values are the names of the variants.
+//!
+//! The doc comments of the type and of its fields are used as the descriptions of the schemas.
+//! The `rename`, `rename_all`, `default`, `skip` and `skip_deserializing` serde attributes are respected.
+//!
+//! ```rust,ignore
+//! #[macro_use] extern crate k8s_openapi_derive;
+//! #[macro_use] extern crate serde_derive;
+//!
+//! use k8s_openapi::schema::JsonSchema;
+//!
+//! /// The spec of a FooBar.
+//! #[derive(Deserialize, JsonSchema, Serialize)]
+//! #[serde(rename_all = "camelCase")]
+//! struct FooBarSpec {
+//!     /// The number of replicas.
+//!     replicas: i32,
+//!
+//!     image_pull_policy: Option<String>,
+//! }
+//!
+//! let schema = FooBarSpec::json_schema();
+//! assert_eq!(schema.required, Some(vec!["replicas".to_string()]));
+//! ```
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use] extern crate quote;
+#[macro_use] extern crate syn;
+
+mod custom_resource;
+mod json_schema;
+
+#[proc_macro_derive(CustomResource, attributes(k8s))]
+pub fn derive_custom_resource(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+	match custom_resource::derive(&input) {
+		Ok(output) => output.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+#[proc_macro_derive(JsonSchema, attributes(serde))]
+pub fn derive_json_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+	match json_schema::derive(&input) {
+		Ok(output) => output.into(),
+		Err(err) => err.to_compile_error().into(),
+	}
+}
+
+fn capitalize(s: &str) -> String {
+	let mut chars = s.chars();
+	match chars.next() {
+		Some(first) => first.to_uppercase().chain(chars).collect(),
+		None => String::new(),
+	}
+}
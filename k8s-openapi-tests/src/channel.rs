@@ -0,0 +1,209 @@
+use k8s_openapi::channel::{ self, Connection, ExitStatus, Output, PortForward, PortForwardOutput, RemoteCommand };
+
+/// An in-memory byte stream that reads what the API server sent, and collects what the client wrote.
+struct Duplex {
+	input: ::std::io::Cursor<Vec<u8>>,
+	output: Vec<u8>,
+}
+
+impl Duplex {
+	fn new(frames: &[Vec<u8>]) -> Self {
+		Duplex {
+			input: ::std::io::Cursor::new(frames.concat()),
+			output: vec![],
+		}
+	}
+}
+
+impl ::std::io::Read for Duplex {
+	fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+		// Read one byte at a time so that frames are split across reads
+		let len = ::std::cmp::min(buf.len(), 1);
+		self.input.read(&mut buf[..len])
+	}
+}
+
+impl ::std::io::Write for Duplex {
+	fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+		self.output.write(buf)
+	}
+
+	fn flush(&mut self) -> ::std::io::Result<()> {
+		Ok(())
+	}
+}
+
+/// An unmasked frame from the API server.
+fn frame(fin: bool, opcode: u8, payload: &[u8]) -> Vec<u8> {
+	let mut frame = vec![if fin { 0x80 | opcode } else { opcode }];
+	if payload.len() < 126 {
+		frame.push(payload.len() as u8);
+	}
+	else {
+		frame.push(126);
+		frame.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+	}
+	frame.extend_from_slice(payload);
+	frame
+}
+
+/// A binary message from the API server on the given channel.
+fn message(channel: u8, data: &[u8]) -> Vec<u8> {
+	let mut payload = vec![channel];
+	payload.extend_from_slice(data);
+	frame(true, 0x2, &payload)
+}
+
+/// Decodes the masked frames that the client wrote, by reading them back with a `Connection`.
+fn written_messages(written: Vec<u8>) -> Vec<(u8, Vec<u8>)> {
+	let mut connection = Connection::new(::std::io::Cursor::new(written));
+	let mut messages = vec![];
+	while let Some(message) = connection.read_message().unwrap() {
+		messages.push(message);
+	}
+	messages
+}
+
+#[test]
+fn exec() {
+	let large_output = vec![b'x'; 1000];
+
+	let mut remote_command = RemoteCommand::new(Duplex::new(&[
+		// A fragmented message, with a ping in the middle
+		frame(false, 0x2, b"\x01hel"),
+		frame(true, 0x9, b"ping"),
+		frame(true, 0x0, b"lo\n"),
+		message(channel::STDERR, b"warning\n"),
+		message(channel::STDOUT, &large_output),
+		message(channel::ERROR, br#"{"metadata":{},"status":"Success"}"#),
+		frame(true, 0x8, b""),
+	]));
+
+	let mut stdout = vec![];
+	let mut stderr = vec![];
+	let exit_status = remote_command.wait(&mut stdout, &mut stderr).unwrap();
+	assert_eq!(exit_status, ExitStatus::Success);
+	assert_eq!(stdout, [&b"hello\n"[..], &large_output].concat());
+	assert_eq!(stderr, b"warning\n");
+
+	assert_eq!(remote_command.read_output().unwrap(), None);
+
+	// The client answered the ping with a masked pong, and the close with a masked close
+	let written = remote_command.into_inner().into_inner().output;
+	assert_eq!(written[0], 0x8A);
+	assert_eq!(written[1], 0x80 | 4);
+	let pong: Vec<u8> = written[6..10].iter().zip(written[2..6].iter().cycle()).map(|(b, mask)| b ^ mask).collect();
+	assert_eq!(pong, b"ping");
+	assert_eq!(&written[10..12], &[0x88, 0x80]);
+	assert_eq!(written.len(), 16);
+}
+
+#[test]
+fn exit_code() {
+	let mut remote_command = RemoteCommand::new(Duplex::new(&[
+		message(channel::STDOUT, b"output"),
+		message(channel::ERROR, br#"{
+			"metadata": {},
+			"status": "Failure",
+			"message": "command terminated with non-zero exit code: Error executing in Docker Container: 3",
+			"reason": "NonZeroExitCode",
+			"details": { "causes": [{ "reason": "ExitCode", "message": "3" }] }
+		}"#),
+	]));
+
+	assert_eq!(remote_command.read_output().unwrap(), Some(Output::Stdout(b"output".to_vec())));
+	assert_eq!(remote_command.read_output().unwrap(), Some(Output::Exit(ExitStatus::ExitCode(3))));
+	assert_eq!(remote_command.read_output().unwrap(), None);
+
+	let mut remote_command = RemoteCommand::new(Duplex::new(&[
+		message(channel::ERROR, br#"{
+			"metadata": {},
+			"status": "Failure",
+			"message": "container not found (\"foo\")"
+		}"#),
+	]));
+
+	match remote_command.wait(&mut ::std::io::sink(), &mut ::std::io::sink()).unwrap() {
+		ExitStatus::Failure(status) => assert_eq!(status.message.as_deref(), Some("container not found (\"foo\")")),
+		exit_status => panic!("{:?}", exit_status),
+	}
+
+	// The connection ended before the command finished
+	let mut remote_command = RemoteCommand::new(Duplex::new(&[message(channel::STDOUT, b"output")]));
+	match remote_command.wait(&mut ::std::io::sink(), &mut ::std::io::sink()) {
+		Err(channel::Error::Protocol(message)) => assert_eq!(message, "connection closed before the command finished"),
+		result => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn stdin_and_resize() {
+	let mut remote_command = RemoteCommand::new(Duplex::new(&[]));
+	remote_command.write_stdin(b"input\n").unwrap();
+	remote_command.resize(80, 24).unwrap();
+	remote_command.write_stdin(&vec![b'x'; 70000]).unwrap();
+
+	let mut connection = remote_command.into_inner();
+	connection.close().unwrap();
+
+	let written = connection.into_inner().output;
+
+	// Every frame written by the client is masked
+	assert_eq!(written[1] & 0x80, 0x80);
+
+	assert_eq!(written_messages(written), [
+		(channel::STDIN, b"input\n".to_vec()),
+		(channel::RESIZE, br#"{"Width":80,"Height":24}"#.to_vec()),
+		(channel::STDIN, vec![b'x'; 70000]),
+	]);
+}
+
+#[test]
+fn port_forward() {
+	let mut port_forward = PortForward::new(Duplex::new(&[
+		// The first message on each channel starts with the port number
+		message(0, &[0x50, 0x00]),
+		message(1, &[0x50, 0x00]),
+		message(2, &[0xBB, 0x01, b'a']),
+		message(0, b"HTTP/1.1 200 OK\r\n"),
+		message(3, &[0xBB, 0x01]),
+		message(3, b"connection refused"),
+	]), &[80, 443]);
+
+	port_forward.write(80, b"GET / HTTP/1.1\r\n").unwrap();
+	match port_forward.write(8080, b"") {
+		Err(channel::Error::Protocol(message)) => assert_eq!(message, "port 8080 isn't forwarded"),
+		result => panic!("{:?}", result),
+	}
+
+	assert_eq!(port_forward.read().unwrap(), Some(PortForwardOutput::Data(443, b"a".to_vec())));
+	assert_eq!(port_forward.read().unwrap(), Some(PortForwardOutput::Data(80, b"HTTP/1.1 200 OK\r\n".to_vec())));
+	assert_eq!(port_forward.read().unwrap(), Some(PortForwardOutput::Error(443, "connection refused".to_string())));
+	assert_eq!(port_forward.read().unwrap(), None);
+
+	let written = port_forward.into_inner().into_inner().output;
+	assert_eq!(written_messages(written), [(0, b"GET / HTTP/1.1\r\n".to_vec())]);
+
+	let mut port_forward = PortForward::new(Duplex::new(&[message(0, &[0x51, 0x00])]), &[80]);
+	match port_forward.read() {
+		Err(channel::Error::Protocol(message)) => assert_eq!(message, "expected port 80 on channel 0 but got port 81"),
+		result => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn upgrade_request() {
+	let request = ::k8s_openapi::api::core::v1::Pod::connect_core_v1_post_namespaced_pod_exec(
+		"foo", "default", Some("ls"), None, Some(true), None, Some(true), None).unwrap();
+	let request = channel::upgrade_request(request).unwrap();
+
+	assert_eq!(request.method(), ::http::Method::GET);
+	assert_eq!(request.uri().path(), "/api/v1/namespaces/default/pods/foo/exec");
+	assert_eq!(request.headers()[::http::header::CONNECTION], "Upgrade");
+	assert_eq!(request.headers()[::http::header::UPGRADE], "websocket");
+	assert_eq!(request.headers()[::http::header::SEC_WEBSOCKET_VERSION], "13");
+	assert_eq!(request.headers()[::http::header::SEC_WEBSOCKET_PROTOCOL], channel::PROTOCOL);
+
+	// 16 base64-encoded bytes
+	assert_eq!(request.headers()[::http::header::SEC_WEBSOCKET_KEY].len(), 24);
+}
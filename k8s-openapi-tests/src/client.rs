@@ -0,0 +1,285 @@
+//! The client used by the tests that talk to an API server.
+//!
+//! By default the client replays the exchanges recorded in `test-data/replays/<version>/<test name>.json`, so the tests don't need a cluster.
+//! Response bodies are replayed in small chunks so that the tests also exercise parsing values that span multiple reads.
+//!
+//! If the `K8S_RECORD` environment variable is set, the client instead executes the requests against the cluster of the kubeconfig's
+//! current context (or of the context in the `K8S_CONTEXT` environment variable), and overwrites the recording of the test when it passes.
+
+/// The size of the chunks that recorded response bodies are replayed in.
+const REPLAY_CHUNK_SIZE: usize = 16;
+
+k8s_if_1_7! { const VERSION: &str = "v1_7"; }
+k8s_if_1_8! { const VERSION: &str = "v1_8"; }
+k8s_if_1_9! { const VERSION: &str = "v1_9"; }
+k8s_if_1_10! { const VERSION: &str = "v1_10"; }
+k8s_if_1_11! { const VERSION: &str = "v1_11"; }
+k8s_if_1_12! { const VERSION: &str = "v1_12"; }
+
+pub(crate) enum Client {
+	Record {
+		inner: ::k8s_openapi_client::Client,
+		recorder: ::std::rc::Rc<::std::cell::RefCell<Recorder>>,
+	},
+
+	Replay {
+		path: ::std::path::PathBuf,
+		exchanges: ::std::cell::RefCell<::std::vec::IntoIter<Exchange>>,
+	},
+}
+
+impl Client {
+	/// Creates a client for the test of the given name, like `pod-list`.
+	pub(crate) fn new(test_name: &str) -> Result<Self, ::Error> {
+		let mut path: ::std::path::PathBuf = env!("CARGO_MANIFEST_DIR").into();
+		path.push("test-data");
+		path.push("replays");
+		path.push(VERSION);
+		path.push(format!("{}.json", test_name));
+
+		if ::std::env::var_os("K8S_RECORD").is_some() {
+			let context = ::std::env::var("K8S_CONTEXT").ok();
+			let config = ::k8s_openapi_client::Config::from_kubeconfig(context.as_deref())?;
+			Ok(Client::record(::k8s_openapi_client::Client::new(&config)?, path))
+		}
+		else {
+			Client::replay(path)
+		}
+	}
+
+	/// Creates a client that executes requests with the given client, and writes the exchanges to the given path.
+	fn record(inner: ::k8s_openapi_client::Client, path: ::std::path::PathBuf) -> Self {
+		Client::Record {
+			inner,
+			recorder: ::std::rc::Rc::new(::std::cell::RefCell::new(Recorder {
+				path,
+				exchanges: vec![],
+				response_bodies: vec![],
+			})),
+		}
+	}
+
+	/// Creates a client that replays the exchanges recorded at the given path.
+	fn replay(path: ::std::path::PathBuf) -> Result<Self, ::Error> {
+		let file =
+			::std::fs::File::open(&path)
+			.map_err(|err| format!("couldn't open recording {}, run the test with K8S_RECORD set to record it: {}", path.display(), err))?;
+		let exchanges: Vec<Exchange> = ::serde_json::from_reader(::std::io::BufReader::new(file))?;
+
+		Ok(Client::Replay {
+			path,
+			exchanges: ::std::cell::RefCell::new(exchanges.into_iter()),
+		})
+	}
+
+	pub(crate) fn execute(&self, request: ::http::Request<Vec<u8>>) -> Result<Response, ::Error> {
+		let method = request.method().to_string();
+		let uri = request.uri().to_string();
+		let request_body = String::from_utf8(request.body().clone())?;
+
+		match self {
+			Client::Record { inner, recorder } => {
+				let inner = inner.execute(request)?;
+
+				let index = {
+					let mut recorder = recorder.borrow_mut();
+					recorder.exchanges.push(Exchange {
+						method,
+						uri,
+						request_body,
+						status_code: inner.status_code().as_u16(),
+						response_body: String::new(),
+					});
+					recorder.response_bodies.push(vec![]);
+					recorder.exchanges.len() - 1
+				};
+
+				Ok(Response {
+					status_code: inner.status_code(),
+					body: ResponseBody::Record {
+						inner: Box::new(inner),
+						recorder: recorder.clone(),
+						index,
+					},
+				})
+			},
+
+			Client::Replay { path, exchanges } => {
+				let exchange =
+					exchanges.borrow_mut().next()
+					.ok_or_else(|| format!("request {} {} was not recorded in {}", method, uri, path.display()))?;
+
+				if exchange.method != method || exchange.uri != uri || exchange.request_body != request_body {
+					return Err(format!(
+						"request {} {} {} doesn't match recorded request {} {} {}",
+						method, uri, request_body,
+						exchange.method, exchange.uri, exchange.request_body).into());
+				}
+
+				Ok(Response {
+					status_code: ::http::StatusCode::from_u16(exchange.status_code)?,
+					body: ResponseBody::Replay {
+						body: exchange.response_body.into_bytes(),
+						position: 0,
+					},
+				})
+			},
+		}
+	}
+}
+
+/// The response to a request executed by a `Client`.
+pub(crate) struct Response {
+	status_code: ::http::StatusCode,
+	body: ResponseBody,
+}
+
+enum ResponseBody {
+	Record {
+		inner: Box<::k8s_openapi_client::Response>,
+		recorder: ::std::rc::Rc<::std::cell::RefCell<Recorder>>,
+		index: usize,
+	},
+
+	Replay {
+		body: Vec<u8>,
+		position: usize,
+	},
+}
+
+impl Response {
+	pub(crate) fn status_code(&self) -> ::http::StatusCode {
+		self.status_code
+	}
+}
+
+impl ::std::io::Read for Response {
+	fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+		match &mut self.body {
+			ResponseBody::Record { inner, recorder, index } => {
+				let read = inner.read(buf)?;
+				recorder.borrow_mut().response_bodies[*index].extend_from_slice(&buf[..read]);
+				Ok(read)
+			},
+
+			ResponseBody::Replay { body, position } => {
+				let read = ::std::cmp::min(::std::cmp::min(buf.len(), REPLAY_CHUNK_SIZE), body.len() - *position);
+				buf[..read].copy_from_slice(&body[*position..(*position + read)]);
+				*position += read;
+				Ok(read)
+			},
+		}
+	}
+}
+
+/// A request and its response.
+///
+/// Only the part of the response body that the test read is recorded, since watches and logs don't end on their own.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct Exchange {
+	method: String,
+	uri: String,
+	request_body: String,
+	status_code: u16,
+	response_body: String,
+}
+
+/// Records the exchanges of a test, and writes them to the test's recording when the test's client and responses have been dropped.
+pub(crate) struct Recorder {
+	path: ::std::path::PathBuf,
+	exchanges: Vec<Exchange>,
+	response_bodies: Vec<Vec<u8>>,
+}
+
+impl Drop for Recorder {
+	fn drop(&mut self) {
+		// Don't overwrite the recording with that of a failed test
+		if ::std::thread::panicking() {
+			return;
+		}
+
+		for (exchange, response_body) in self.exchanges.iter_mut().zip(&self.response_bodies) {
+			exchange.response_body = String::from_utf8_lossy(response_body).into_owned();
+		}
+
+		::std::fs::create_dir_all(self.path.parent().expect("recording path has no parent")).expect("couldn't create recordings directory");
+		let file = ::std::fs::File::create(&self.path).expect("couldn't create recording");
+		::serde_json::to_writer_pretty(::std::io::BufWriter::new(file), &self.exchanges).expect("couldn't write recording");
+	}
+}
+
+#[test]
+fn record_and_replay() {
+	use ::std::io::{ BufRead, Read, Write };
+
+	let body = r#"{"kind":"APIVersions","versions":["v1"],"serverAddressByClientCIDRs":[]}"#;
+
+	// A server that responds to one request
+	let listener = ::std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+	let server = format!("http://{}", listener.local_addr().unwrap());
+	let server_thread = ::std::thread::spawn(move || {
+		let (stream, _) = listener.accept().unwrap();
+		let mut reader = ::std::io::BufReader::new(stream.try_clone().unwrap());
+		let mut line = String::new();
+		loop {
+			line.clear();
+			reader.read_line(&mut line).unwrap();
+			if line == "\r\n" {
+				break;
+			}
+		}
+		write!(&stream, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+	});
+
+	let mut path = ::std::env::temp_dir();
+	path.push(format!("k8s-openapi-tests-replay-{}", ::std::process::id()));
+	path.push("test.json");
+
+	{
+		let config = ::k8s_openapi_client::Config {
+			server: server.parse().unwrap(),
+			certificate_authority: None,
+			accept_invalid_certs: false,
+			client_certificate: None,
+			authentication: None,
+			namespace: "default".to_string(),
+		};
+		let client = Client::record(::k8s_openapi_client::Client::new(&config).unwrap(), path.clone());
+		let mut response = client.execute(::http::Request::get("/api?").body(vec![]).unwrap()).unwrap();
+		assert_eq!(response.status_code(), ::http::StatusCode::OK);
+		let mut recorded_body = String::new();
+		response.read_to_string(&mut recorded_body).unwrap();
+		assert_eq!(recorded_body, body);
+	}
+
+	server_thread.join().unwrap();
+
+	let client = Client::replay(path.clone()).unwrap();
+
+	match client.execute(::http::Request::get("/apis?").body(vec![]).unwrap()) {
+		Err(::Error(err, _)) => assert_eq!(err.to_string(), "request GET /apis?  doesn't match recorded request GET /api? "),
+		Ok(_) => panic!("replayed a request that doesn't match the recording"),
+	}
+
+	let client = Client::replay(path.clone()).unwrap();
+	let mut response = client.execute(::http::Request::get("/api?").body(vec![]).unwrap()).unwrap();
+	assert_eq!(response.status_code(), ::http::StatusCode::OK);
+	let mut buf = [0u8; 4096];
+	let mut replayed_body = vec![];
+	loop {
+		let read = response.read(&mut buf).unwrap();
+		assert!(read <= REPLAY_CHUNK_SIZE);
+		if read == 0 {
+			break;
+		}
+		replayed_body.extend_from_slice(&buf[..read]);
+	}
+	assert_eq!(replayed_body, body.as_bytes());
+
+	match client.execute(::http::Request::get("/api?").body(vec![]).unwrap()) {
+		Err(::Error(err, _)) => assert!(err.to_string().starts_with("request GET /api? was not recorded in ")),
+		Ok(_) => panic!("replayed more exchanges than were recorded"),
+	}
+
+	::std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
@@ -0,0 +1,73 @@
+#[test]
+fn from() {
+	use ::k8s_openapi::api::core::v1 as core;
+	use ::k8s_openapi::api::apps::v1beta1 as apps;
+	use ::k8s_openapi::api::extensions::v1beta1 as extensions;
+
+	// extensions/v1beta1 and apps/v1beta1 Deployments are structurally identical, so the conversion is a From impl
+	let deployment = extensions::Deployment {
+		api_version: Some("extensions/v1beta1".to_string()),
+		kind: Some("Deployment".to_string()),
+		spec: Some(extensions::DeploymentSpec {
+			replicas: Some(3),
+			rollback_to: Some(extensions::RollbackConfig { revision: Some(2) }),
+			template: core::PodTemplateSpec::default(),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	let deployment: apps::Deployment = deployment.into();
+	assert_eq!(deployment, apps::Deployment {
+		api_version: Some("apps/v1beta1".to_string()),
+		kind: Some("Deployment".to_string()),
+		spec: Some(apps::DeploymentSpec {
+			replicas: Some(3),
+			rollback_to: Some(apps::RollbackConfig { revision: Some(2) }),
+			template: core::PodTemplateSpec::default(),
+			..Default::default()
+		}),
+		..Default::default()
+	});
+}
+
+k8s_if_ge_1_8! {
+	#[test]
+	fn try_from() {
+		use ::k8s_openapi::api::core::v1 as core;
+		use ::k8s_openapi::api::apps::v1beta2 as apps;
+		use ::k8s_openapi::api::extensions::v1beta1 as extensions;
+
+		use std::convert::TryFrom;
+
+		// apps/v1beta2 doesn't have spec.rollbackTo, so the conversion is a TryFrom impl that fails if it's set.
+		// apps/v1beta2 also requires spec.selector.
+		let deployment = extensions::Deployment {
+			api_version: Some("extensions/v1beta1".to_string()),
+			kind: Some("Deployment".to_string()),
+			spec: Some(extensions::DeploymentSpec {
+				replicas: Some(3),
+				rollback_to: Some(extensions::RollbackConfig { revision: Some(2) }),
+				selector: Some(Default::default()),
+				template: core::PodTemplateSpec::default(),
+				..Default::default()
+			}),
+			..Default::default()
+		};
+
+		match apps::Deployment::try_from(deployment.clone()) {
+			Err(::k8s_openapi::conversion::Error::DroppedFields(fields)) => assert_eq!(fields, vec!["spec.rollbackTo".to_string()]),
+			result => panic!("{:?}", result),
+		}
+
+		let (converted, dropped_fields): (apps::Deployment, _) = ::k8s_openapi::conversion::convert_lossy(deployment.clone()).unwrap();
+		assert_eq!(converted.spec.unwrap().replicas, Some(3));
+		assert_eq!(dropped_fields, vec!["spec.rollbackTo".to_string()]);
+
+		let mut deployment = deployment;
+		deployment.spec.as_mut().unwrap().rollback_to = None;
+		let deployment = apps::Deployment::try_from(deployment).unwrap();
+		assert_eq!(deployment.api_version, Some("apps/v1beta2".to_string()));
+		assert_eq!(deployment.spec.unwrap().replicas, Some(3));
+	}
+}
@@ -0,0 +1,118 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+#[derive(::k8s_openapi_derive::CustomResource, Debug, Default, Deserialize, Serialize)]
+#[k8s(group = "example.com", version = "v1", plural = "foos", namespaced)]
+struct Foo {
+	#[serde(rename = "apiVersion")]
+	api_version: Option<String>,
+	kind: Option<String>,
+	metadata: Option<meta::ObjectMeta>,
+	spec: Option<FooSpec>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct FooSpec {
+	replicas: i32,
+}
+
+#[derive(::k8s_openapi_derive::CustomResource, Debug, Default, Deserialize, Serialize)]
+#[k8s(group = "cluster.example.com", version = "v1beta1", kind = "ClusterBar", plural = "clusterbars")]
+struct Bar {
+	#[serde(rename = "apiVersion")]
+	api_version: Option<String>,
+	kind: Option<String>,
+	metadata: Option<meta::ObjectMeta>,
+}
+
+#[test]
+fn requests() {
+	let foo = Foo {
+		api_version: Some("example.com/v1".to_string()),
+		kind: Some("Foo".to_string()),
+		metadata: Some(meta::ObjectMeta { name: Some("foo1".to_string()), ..Default::default() }),
+		spec: Some(FooSpec { replicas: 3 }),
+	};
+
+	let request = Foo::create_example_com_v1_namespaced_foo("default", &foo, None).unwrap();
+	assert_eq!(request.method(), ::http::Method::POST);
+	assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos?");
+	assert_eq!(
+		::serde_json::from_slice::<::serde_json::Value>(request.body()).unwrap(),
+		::serde_json::json!({ "apiVersion": "example.com/v1", "kind": "Foo", "metadata": { "name": "foo1" }, "spec": { "replicas": 3 } }));
+
+	let request = Foo::read_example_com_v1_namespaced_foo("foo1", "default", None).unwrap();
+	assert_eq!(request.method(), ::http::Method::GET);
+	assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos/foo1?");
+
+	let request = Foo::replace_example_com_v1_namespaced_foo("foo1", "default", &foo, Some("true")).unwrap();
+	assert_eq!(request.method(), ::http::Method::PUT);
+	assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos/foo1?&pretty=true");
+
+	let request = Foo::delete_example_com_v1_namespaced_foo("foo1", "default", Some(0), None, None, Some("Foreground")).unwrap();
+	assert_eq!(request.method(), ::http::Method::DELETE);
+	assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos/foo1?&gracePeriodSeconds=0&propagationPolicy=Foreground");
+
+	let request = Foo::list_example_com_v1_foo_for_all_namespaces(None, None, Some("app=foo"), Some(10), None, None, None, None).unwrap();
+	assert_eq!(request.uri(), "/apis/example.com/v1/foos?&labelSelector=app%3Dfoo&limit=10");
+
+	let request = Foo::watch_example_com_v1_namespaced_foo_list("default", None, None, None, None, None, Some("123"), None, None).unwrap();
+	assert_eq!(request.uri(), "/apis/example.com/v1/watch/namespaces/default/foos?&resourceVersion=123");
+
+	let request = Bar::read_cluster_example_com_v1beta1_cluster_bar("bar1", None).unwrap();
+	assert_eq!(request.uri(), "/apis/cluster.example.com/v1beta1/clusterbars/bar1?");
+
+	let request = Bar::delete_cluster_example_com_v1beta1_collection_cluster_bar(None, None, None, None, None, None, None, None).unwrap();
+	assert_eq!(request.method(), ::http::Method::DELETE);
+	assert_eq!(request.uri(), "/apis/cluster.example.com/v1beta1/clusterbars?");
+
+	let request = Bar::watch_cluster_example_com_v1beta1_cluster_bar("bar1", None, None, None, None, None, None, None, None).unwrap();
+	assert_eq!(request.uri(), "/apis/cluster.example.com/v1beta1/watch/clusterbars/bar1?");
+}
+
+#[test]
+fn responses() {
+	let mut response_body = ::k8s_openapi::ResponseBody::new(::http::StatusCode::OK);
+	let list = br#"{"apiVersion":"example.com/v1","kind":"FooList","metadata":{"resourceVersion":"123"},"items":[{"apiVersion":"example.com/v1","kind":"Foo","metadata":{"name":"foo1"},"spec":{"replicas":3}}]}"#;
+	match response_body.append_slice_and_parse::<ListExampleComV1NamespacedFooResponse>(&list[..10]) {
+		Err(::k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("{:?}", result),
+	}
+	let list: FooList = match response_body.append_slice_and_parse::<ListExampleComV1NamespacedFooResponse>(&list[10..]) {
+		Ok(response) => ::k8s_openapi::ListResponse::into_list(response).unwrap(),
+		result => panic!("{:?}", result),
+	};
+	assert_eq!(list.metadata.as_ref().and_then(|metadata| metadata.resource_version.as_ref()), Some(&"123".to_string()));
+	assert_eq!(::k8s_openapi::List::continue_token(&list), None);
+	let items = ::k8s_openapi::List::into_items(list);
+	assert_eq!(items.len(), 1);
+	assert_eq!(items[0].spec.as_ref().map(|spec| spec.replicas), Some(3));
+
+	match ::k8s_openapi::Response::try_from_parts(::http::StatusCode::CREATED, br#"{"apiVersion":"example.com/v1","kind":"Foo","spec":{"replicas":3}}"#) {
+		Ok((CreateExampleComV1NamespacedFooResponse::Created(foo), _)) => assert_eq!(foo.spec.map(|spec| spec.replicas), Some(3)),
+		result => panic!("{:?}", result),
+	}
+
+	match ::k8s_openapi::Response::try_from_parts(::http::StatusCode::UNPROCESSABLE_ENTITY, br#"{"apiVersion":"v1","kind":"Status","status":"Failure"}"#) {
+		Ok((CreateExampleComV1NamespacedFooResponse::Other, 0)) => (),
+		result => panic!("{:?}", result),
+	}
+
+	match ::k8s_openapi::Response::try_from_parts(::http::StatusCode::OK, br#"{"apiVersion":"v1","kind":"Status","status":"Success"}"#) {
+		Ok((DeleteExampleComV1NamespacedFooResponse::OkStatus(status), _)) => assert_eq!(status.status, Some("Success".to_string())),
+		result => panic!("{:?}", result),
+	}
+
+	match ::k8s_openapi::Response::try_from_parts(::http::StatusCode::OK, br#"{"apiVersion":"example.com/v1","kind":"Foo","metadata":{"name":"foo1"}}"#) {
+		Ok((DeleteExampleComV1NamespacedFooResponse::OkValue(foo), _)) => assert_eq!(foo.kind, Some("Foo".to_string())),
+		result => panic!("{:?}", result),
+	}
+
+	let events = b"{\"type\":\"ADDED\",\"object\":{\"apiVersion\":\"cluster.example.com/v1beta1\",\"kind\":\"ClusterBar\"}}\n{\"type\":";
+	match ::k8s_openapi::Response::try_from_parts(::http::StatusCode::OK, events) {
+		Ok((WatchClusterExampleComV1beta1ClusterBarListResponse::Ok(event), read)) => {
+			assert_eq!(event.type_, "ADDED");
+			assert_eq!(read, events.len() - "{\"type\":".len());
+		},
+		result => panic!("{:?}", result),
+	}
+}
@@ -1,27 +1,10 @@
 #[test]
 fn create() {
-	k8s_if_1_8! {
-		use ::k8s_openapi::v1_8::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
-		use ::k8s_openapi::v1_8::apimachinery::pkg::apis::meta::v1 as meta;
-	}
-	k8s_if_1_9! {
-		use ::k8s_openapi::v1_9::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
-		use ::k8s_openapi::v1_9::apimachinery::pkg::apis::meta::v1 as meta;
-	}
-	k8s_if_1_10! {
-		use ::k8s_openapi::v1_10::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
-		use ::k8s_openapi::v1_10::apimachinery::pkg::apis::meta::v1 as meta;
-	}
-	k8s_if_1_11! {
-		use ::k8s_openapi::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
-		use ::k8s_openapi::v1_11::apimachinery::pkg::apis::meta::v1 as meta;
-	}
-	k8s_if_1_12! {
-		use ::k8s_openapi::v1_12::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
-		use ::k8s_openapi::v1_12::apimachinery::pkg::apis::meta::v1 as meta;
-	}
+	use ::k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
+	use ::k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
 
-	#[derive(Debug, Default, Deserialize, Serialize)]
+	#[derive(::k8s_openapi_derive::CustomResource, Debug, Default, Deserialize, Serialize)]
+	#[k8s(group = "k8s-openapi-tests-custom-resource-definition.com", version = "v1", plural = "foobars", namespaced)]
 	struct FooBar {
 		#[serde(rename = "apiVersion")]
 		pub api_version: Option<String>,
@@ -30,7 +13,7 @@ fn create() {
 		pub spec: Option<FooBarSpec>,
 	}
 
-	#[derive(Debug, Default, Deserialize, Serialize)]
+	#[derive(Debug, Default, Deserialize, ::k8s_openapi_derive::JsonSchema, Serialize)]
 	struct FooBarSpec {
 		prop1: String,
 		prop2: Vec<bool>,
@@ -38,53 +21,7 @@ fn create() {
 		prop3: Option<i32>,
 	}
 
-	#[derive(Debug)]
-	enum CreateFooBarResponse {
-		Created(FooBar),
-		UnprocessableEntity(meta::Status),
-		Other,
-	}
-
-	impl ::k8s_openapi::Response for CreateFooBarResponse {
-		fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::k8s_openapi::ResponseError> {
-			match status_code {
-				::http::StatusCode::CREATED => {
-					let result = match ::serde_json::from_slice(buf) {
-						Ok(value) => value,
-						Err(ref err) if err.is_eof() => return Err(::k8s_openapi::ResponseError::NeedMoreData),
-						Err(err) => return Err(::k8s_openapi::ResponseError::Json(err)),
-					};
-					Ok((CreateFooBarResponse::Created(result), buf.len()))
-				},
-				::http::StatusCode::UNPROCESSABLE_ENTITY => {
-					let result = match ::serde_json::from_slice(buf) {
-						Ok(value) => value,
-						Err(ref err) if err.is_eof() => return Err(::k8s_openapi::ResponseError::NeedMoreData),
-						Err(err) => return Err(::k8s_openapi::ResponseError::Json(err)),
-					};
-					Ok((CreateFooBarResponse::UnprocessableEntity(result), buf.len()))
-				},
-				_ => Ok((CreateFooBarResponse::Other, 0)),
-			}
-		}
-	}
-
-	#[derive(Debug)]
-	enum DeleteFooBarResponse {
-		Ok,
-		Other,
-	}
-
-	impl ::k8s_openapi::Response for DeleteFooBarResponse {
-		fn try_from_parts(status_code: ::http::StatusCode, _: &[u8]) -> Result<(Self, usize), ::k8s_openapi::ResponseError> {
-			match status_code {
-				::http::StatusCode::OK => Ok((DeleteFooBarResponse::Ok, 0)),
-				_ => Ok((DeleteFooBarResponse::Other, 0)),
-			}
-		}
-	}
-
-	let client = ::Client::new().expect("couldn't create client");
+	let client = ::client("custom_resource_definition-create").expect("couldn't create client");
 
 	let custom_resource_definition_spec = apiextensions::CustomResourceDefinitionSpec {
 		group: "k8s-openapi-tests-custom-resource-definition.com".to_string(),
@@ -106,32 +43,7 @@ fn create() {
 			validation: Some(apiextensions::CustomResourceValidation {
 				open_api_v3_schema: Some(apiextensions::JSONSchemaProps {
 					properties: Some(vec![
-						("spec".to_string(), apiextensions::JSONSchemaProps {
-							properties: Some(vec![
-								("prop1".to_string(), apiextensions::JSONSchemaProps {
-									type_: Some("string".to_string()),
-									..Default::default()
-								}),
-								("prop2".to_string(), apiextensions::JSONSchemaProps {
-									type_: Some("array".to_string()),
-									items: Some(apiextensions::JSONSchemaPropsOrArray::Schema(Box::new(apiextensions::JSONSchemaProps {
-										type_: Some("boolean".to_string()),
-										..Default::default()
-									}))),
-									..Default::default()
-								}),
-								("prop3".to_string(), apiextensions::JSONSchemaProps {
-									format: Some("int32".to_string()),
-									type_: Some("integer".to_string()),
-									..Default::default()
-								}),
-							].into_iter().collect()),
-							required: Some(vec![
-								"prop1".to_string(),
-								"prop2".to_string(),
-							]),
-							..Default::default()
-						}),
+						("spec".to_string(), <FooBarSpec as ::k8s_openapi::schema::JsonSchema>::json_schema()),
 					].into_iter().collect()),
 					..Default::default()
 				}),
@@ -218,24 +130,32 @@ fn create() {
 		..Default::default()
 	};
 	let request =
-		::http::Request::post("/apis/k8s-openapi-tests-custom-resource-definition.com/v1/namespaces/default/foobars")
-		.body(::serde_json::to_vec(&fb1).expect("couldn't create custom resource definition"))
+		FooBar::create_k8s_openapi_tests_custom_resource_definition_com_v1_namespaced_foo_bar("default", &fb1, None)
 		.expect("couldn't create custom resource");
 	let response = client.execute(request).expect("couldn't create custom resource");
-	let fb1 =
+	::get_single_value(response, |response, status_code, _| match response {
+		CreateK8sOpenapiTestsCustomResourceDefinitionComV1NamespacedFooBarResponse::Created(fb) => Ok(::ValueResult::GotValue(fb)),
+		other => Err(format!("{:?} {}", other, status_code).into()),
+	}).expect("couldn't create custom resource");
+
+	let request =
+		FooBar::list_k8s_openapi_tests_custom_resource_definition_com_v1_namespaced_foo_bar("default", None, None, None, None, None, None, None, None)
+		.expect("couldn't list custom resources");
+	let response = client.execute(request).expect("couldn't list custom resources");
+	let foo_bar_list =
 		::get_single_value(response, |response, status_code, _| match response {
-			CreateFooBarResponse::Created(fb) => Ok(::ValueResult::GotValue(fb)),
+			ListK8sOpenapiTestsCustomResourceDefinitionComV1NamespacedFooBarResponse::Ok(list) => Ok(::ValueResult::GotValue(list)),
 			other => Err(format!("{:?} {}", other, status_code).into()),
-		}).expect("couldn't create custom resource");
+		}).expect("couldn't list custom resources");
+	assert_eq!(vec!["fb1"], foo_bar_list.items.iter().map(|fb| fb.metadata.as_ref().unwrap().name.as_ref().unwrap()).collect::<Vec<_>>());
 
-	let fb1_self_link = {
-		let metadata = fb1.metadata.expect("couldn't get custom resource metadata");
-		metadata.self_link.expect("couldn't get custom resource self link")
-	};
-	let request = ::http::Request::delete(fb1_self_link).body(vec![]).expect("couldn't delete custom resource");
+	let request =
+		FooBar::delete_k8s_openapi_tests_custom_resource_definition_com_v1_namespaced_foo_bar("fb1", "default", None, None, None, None)
+		.expect("couldn't delete custom resource");
 	let response = client.execute(request).expect("couldn't delete custom resource");
 	::get_single_value(response, |response, status_code, _| match response {
-		DeleteFooBarResponse::Ok => Ok(::ValueResult::GotValue(())),
+		DeleteK8sOpenapiTestsCustomResourceDefinitionComV1NamespacedFooBarResponse::OkStatus(_) |
+		DeleteK8sOpenapiTestsCustomResourceDefinitionComV1NamespacedFooBarResponse::OkValue(_) => Ok(::ValueResult::GotValue(())),
 		other => Err(format!("{:?} {}", other, status_code).into()),
 	}).expect("couldn't delete custom resource");
 
@@ -250,13 +170,15 @@ fn create() {
 				("prop1".to_string(), ::serde_json::Value::String("value1".to_string())),
 			].into_iter().collect())),
 		].into_iter().collect());
+		// The body doesn't deserialize as a FooBar, so the request is created for a default FooBar and then given this body
 		let request =
-			::http::Request::post("/apis/k8s-openapi-tests-custom-resource-definition.com/v1/namespaces/default/foobars")
-			.body(::serde_json::to_vec(&fb2).expect("couldn't create custom resource definition"))
-			.expect("couldn't create custom resource");
+			FooBar::create_k8s_openapi_tests_custom_resource_definition_com_v1_namespaced_foo_bar("default", &Default::default(), None)
+			.expect("couldn't create custom resource")
+			.map(|_| ::serde_json::to_vec(&fb2).expect("couldn't create custom resource"));
 		let response = client.execute(request).expect("couldn't create custom resource");
 		::get_single_value(response, |response, status_code, _| match response {
-			CreateFooBarResponse::UnprocessableEntity(_) => Ok(::ValueResult::GotValue(())),
+			CreateK8sOpenapiTestsCustomResourceDefinitionComV1NamespacedFooBarResponse::Other if status_code == ::http::StatusCode::UNPROCESSABLE_ENTITY =>
+				Ok(::ValueResult::GotValue(())),
 			other => Err(format!("{:?} {}", other, status_code).into()),
 		}).expect("expected custom resource creation to fail validation");
 	}
@@ -273,13 +195,15 @@ fn create() {
 				("prop2".to_string(), ::serde_json::Value::Bool(true)),
 			].into_iter().collect())),
 		].into_iter().collect());
+		// The body doesn't deserialize as a FooBar, so the request is created for a default FooBar and then given this body
 		let request =
-			::http::Request::post("/apis/k8s-openapi-tests-custom-resource-definition.com/v1/namespaces/default/foobars")
-			.body(::serde_json::to_vec(&fb3).expect("couldn't create custom resource definition"))
-			.expect("couldn't create custom resource");
+			FooBar::create_k8s_openapi_tests_custom_resource_definition_com_v1_namespaced_foo_bar("default", &Default::default(), None)
+			.expect("couldn't create custom resource")
+			.map(|_| ::serde_json::to_vec(&fb3).expect("couldn't create custom resource"));
 		let response = client.execute(request).expect("couldn't create custom resource");
 		::get_single_value(response, |response, status_code, _| match response {
-			CreateFooBarResponse::UnprocessableEntity(_) => Ok(::ValueResult::GotValue(())),
+			CreateK8sOpenapiTestsCustomResourceDefinitionComV1NamespacedFooBarResponse::Other if status_code == ::http::StatusCode::UNPROCESSABLE_ENTITY =>
+				Ok(::ValueResult::GotValue(())),
 			other => Err(format!("{:?} {}", other, status_code).into()),
 		}).expect("expected custom resource creation to fail validation");
 	}
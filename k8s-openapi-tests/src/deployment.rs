@@ -25,7 +25,7 @@ fn list() {
 		use ::k8s_openapi::v1_12::apimachinery::pkg::util as util;
 	}
 
-	let client = ::Client::new().expect("couldn't create client");
+	let client = ::client("deployment-list").expect("couldn't create client");
 
 	k8s_if_1_7! {
 		let request =
@@ -0,0 +1,101 @@
+/// Runs discovery against the documents recorded in `test-data/discovery/`. A document that isn't recorded, like the resources of `metrics.k8s.io`,
+/// is served as `503 Service Unavailable`, like an aggregated API server that's down.
+fn discover() -> ::k8s_openapi::discovery::RestMapper {
+	let mut discovery = ::k8s_openapi::discovery::Discovery::new();
+
+	while let Some(request) = discovery.next_request() {
+		let request = request.unwrap();
+		assert_eq!(request.method(), ::http::Method::GET);
+
+		let mut path: ::std::path::PathBuf = env!("CARGO_MANIFEST_DIR").into();
+		path.push("test-data");
+		path.push("discovery");
+		path.push(format!("{}.json", request.uri().path().trim_matches('/').replace('/', "-")));
+
+		let (status_code, body) = match ::std::fs::read(&path) {
+			Ok(body) => (::http::StatusCode::OK, body),
+			Err(ref err) if err.kind() == ::std::io::ErrorKind::NotFound => (::http::StatusCode::SERVICE_UNAVAILABLE, b"service unavailable".to_vec()),
+			Err(err) => panic!("{}: {}", path.display(), err),
+		};
+
+		let (response, _) = ::k8s_openapi::Response::try_from_parts(status_code, &body).unwrap();
+		discovery.handle_response(status_code, response).unwrap();
+	}
+
+	discovery.into_rest_mapper()
+}
+
+#[test]
+fn rest_mapper() {
+	let rest_mapper = discover();
+
+	assert_eq!(rest_mapper.preferred_version(""), Some("v1"));
+	assert_eq!(rest_mapper.preferred_version("apps"), Some("v1"));
+	assert_eq!(rest_mapper.preferred_version("batch"), None);
+	assert_eq!(rest_mapper.versions("apps"), Some(&["v1".to_string(), "v1beta1".to_string()][..]));
+
+	let deployments = rest_mapper.resource_for("apps", "Deployment").unwrap();
+	assert_eq!(deployments.api_version(), "apps/v1");
+	assert_eq!(deployments.plural, "deployments");
+	assert_eq!(deployments.singular, "deployment");
+	assert_eq!(deployments.short_names, vec!["deploy".to_string()]);
+	assert!(deployments.namespaced);
+	assert!(deployments.supports("watch"));
+	assert!(!deployments.supports("proxy"));
+	assert_eq!(deployments.api_resource(), ::k8s_openapi::dynamic::ApiResource {
+		group: "apps".to_string(),
+		version: "v1".to_string(),
+		plural: "deployments".to_string(),
+	});
+
+	assert_eq!(rest_mapper.resource_for_version("apps", "v1beta1", "Deployment").unwrap().api_version(), "apps/v1beta1");
+	assert_eq!(rest_mapper.resource_for_version("apps", "v1beta1", "DaemonSet"), None);
+	assert_eq!(rest_mapper.kind_for("apps", "deploy"), Some(deployments));
+
+	let pods = rest_mapper.resource_for("", "Pod").unwrap();
+	assert_eq!(pods.api_version(), "v1");
+	assert_eq!(pods.plural, "pods");
+	assert_eq!(rest_mapper.kind_for("", "po"), Some(pods));
+	assert_eq!(rest_mapper.kind_for("", "pod"), Some(pods));
+	assert_eq!(rest_mapper.kind_for("", "Pods"), Some(pods));
+	assert_eq!(rest_mapper.kind_for("apps", "po"), None);
+
+	let namespaces = rest_mapper.resource_for("", "Namespace").unwrap();
+	assert_eq!(namespaces.plural, "namespaces");
+	assert!(!namespaces.namespaced);
+
+	// Subresources are skipped, including those with a different kind
+	assert!(rest_mapper.mappings().iter().all(|mapping| !mapping.plural.contains('/')));
+	assert_eq!(rest_mapper.resource_for("", "Eviction"), None);
+	assert_eq!(rest_mapper.resource_for("apps", "Scale"), None);
+	assert_eq!(rest_mapper.resource_for("apps", "DeploymentRollback"), None);
+
+	// The preferred version is used if it serves the kind, otherwise the first version that does
+	let foos = rest_mapper.resource_for("example.com", "Foo").unwrap();
+	assert_eq!(foos.api_version(), "example.com/v1");
+	assert_eq!(foos.singular, "foo");
+	let legacy_foos = rest_mapper.resource_for("example.com", "LegacyFoo").unwrap();
+	assert_eq!(legacy_foos.api_version(), "example.com/v1alpha1");
+	assert!(!legacy_foos.namespaced);
+	assert_eq!(rest_mapper.kind_for("example.com", "legacyfoo"), Some(legacy_foos));
+
+	// The unavailable group version is reported, and doesn't prevent discovering the others
+	assert_eq!(rest_mapper.failed_group_versions(), &[("metrics.k8s.io/v1beta1".to_string(), ::http::StatusCode::SERVICE_UNAVAILABLE)][..]);
+	assert_eq!(rest_mapper.preferred_version("metrics.k8s.io"), Some("v1beta1"));
+	assert_eq!(rest_mapper.resource_for("metrics.k8s.io", "PodMetrics"), None);
+}
+
+#[test]
+fn unavailable() {
+	let mut discovery = ::k8s_openapi::discovery::Discovery::new();
+
+	let request = discovery.next_request().unwrap().unwrap();
+	assert_eq!(request.uri(), "/api/");
+
+	let (response, _) = ::k8s_openapi::Response::try_from_parts(::http::StatusCode::FORBIDDEN, b"forbidden").unwrap();
+	assert_eq!(
+		discovery.handle_response(::http::StatusCode::FORBIDDEN, response),
+		Err(::k8s_openapi::discovery::Error::UnexpectedResponse(::http::StatusCode::FORBIDDEN)));
+
+	assert!(discovery.next_request().is_none());
+}
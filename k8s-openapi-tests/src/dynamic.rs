@@ -0,0 +1,89 @@
+#[test]
+fn object() {
+	use ::k8s_openapi::dynamic::DynamicObject;
+
+	let json = ::k8s_openapi::serde_json::json!({
+		"apiVersion": "example.com/v1",
+		"kind": "Foo",
+		"metadata": { "name": "foo1", "namespace": "default" },
+		"spec": { "replicas": 3 },
+	});
+
+	let foo: DynamicObject = ::k8s_openapi::serde_json::from_value(json.clone()).unwrap();
+	assert_eq!(foo.api_version, Some("example.com/v1".to_string()));
+	assert_eq!(foo.kind, Some("Foo".to_string()));
+	assert_eq!(foo.metadata.as_ref().and_then(|metadata| metadata.name.as_ref()), Some(&"foo1".to_string()));
+	assert_eq!(foo.data.keys().collect::<Vec<_>>(), vec!["spec"]);
+
+	assert_eq!(::k8s_openapi::serde_json::to_value(&foo).unwrap(), json);
+}
+
+#[test]
+fn requests() {
+	use ::k8s_openapi::dynamic::{ ApiResource, Patch };
+
+	let foos = ApiResource { group: "example.com".to_string(), version: "v1".to_string(), plural: "foos".to_string() };
+	let pods = ApiResource { group: "".to_string(), version: "v1".to_string(), plural: "pods".to_string() };
+
+	let request = foos.read("foo1", Some("default")).unwrap();
+	assert_eq!(request.method(), ::http::Method::GET);
+	assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos/foo1?");
+
+	let request = pods.list(None, None, None, Some("app=foo"), Some(10), None).unwrap();
+	assert_eq!(request.uri(), "/api/v1/pods?labelSelector=app%3Dfoo&limit=10");
+
+	let request = foos.watch(Some("default"), None, None, Some("123"), None).unwrap();
+	assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos?resourceVersion=123&watch=true");
+
+	let request = foos.patch("foo1", None, &Patch::Merge(::k8s_openapi::serde_json::json!({ "spec": { "replicas": 5 } }))).unwrap();
+	assert_eq!(request.method(), ::http::Method::PATCH);
+	assert_eq!(request.uri(), "/apis/example.com/v1/foos/foo1?");
+	assert_eq!(request.headers()[::http::header::CONTENT_TYPE], "application/merge-patch+json");
+	assert_eq!(request.body(), br#"{"spec":{"replicas":5}}"#);
+}
+
+#[test]
+fn responses() {
+	use ::k8s_openapi::dynamic::{ DeleteResponse, ListResponse };
+
+	let mut response_body = ::k8s_openapi::ResponseBody::new(::http::StatusCode::OK);
+	let list = br#"{"apiVersion":"example.com/v1","kind":"FooList","metadata":{"resourceVersion":"123"},"items":[{"apiVersion":"example.com/v1","kind":"Foo","metadata":{"name":"foo1"}}]}"#;
+	match response_body.append_slice_and_parse::<ListResponse>(&list[..10]) {
+		Err(::k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("{:?}", result),
+	}
+	match response_body.append_slice_and_parse::<ListResponse>(&list[10..]) {
+		Ok(ListResponse::Ok(list)) => {
+			assert_eq!(list.metadata.and_then(|metadata| metadata.resource_version), Some("123".to_string()));
+			assert_eq!(list.items.len(), 1);
+			assert_eq!(list.items[0].kind, Some("Foo".to_string()));
+		},
+		result => panic!("{:?}", result),
+	}
+
+	match <DeleteResponse as ::k8s_openapi::Response>::try_from_parts(::http::StatusCode::OK, br#"{"apiVersion":"v1","kind":"Status","status":"Success"}"#) {
+		Ok((DeleteResponse::OkStatus(status), _)) => assert_eq!(status.status, Some("Success".to_string())),
+		result => panic!("{:?}", result),
+	}
+
+	match <DeleteResponse as ::k8s_openapi::Response>::try_from_parts(::http::StatusCode::OK, br#"{"apiVersion":"example.com/v1","kind":"Foo"}"#) {
+		Ok((DeleteResponse::OkValue(foo), _)) => assert_eq!(foo.kind, Some("Foo".to_string())),
+		result => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn list_continue_token() {
+	use ::k8s_openapi::dynamic::DynamicObjectList;
+
+	let list: DynamicObjectList = ::k8s_openapi::serde_json::from_str(r#"{"apiVersion":"example.com/v1","kind":"FooList","metadata":{"continue":"abc"},"items":[]}"#).unwrap();
+
+	// v1.7 doesn't support chunked lists
+	k8s_if_le_1_7! {
+		assert_eq!(::k8s_openapi::List::continue_token(&list), None);
+	}
+
+	k8s_if_ge_1_8! {
+		assert_eq!(::k8s_openapi::List::continue_token(&list), Some("abc"));
+	}
+}
@@ -0,0 +1,242 @@
+use k8s_openapi::api::core::v1 as api;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+use k8s_openapi_client::fake::ApiServer;
+
+fn config_map(name: &str, labels: &[(&str, &str)], data: &[(&str, &str)]) -> api::ConfigMap {
+	api::ConfigMap {
+		metadata: Some(meta::ObjectMeta {
+			name: Some(name.to_string()),
+			labels: Some(labels.iter().map(|&(key, value)| (key.to_string(), value.to_string())).collect()),
+			..Default::default()
+		}),
+		data: Some(data.iter().map(|&(key, value)| (key.to_string(), value.to_string())).collect()),
+		..Default::default()
+	}
+}
+
+fn execute(api_server: &ApiServer, request: Result<::http::Request<Vec<u8>>, ::k8s_openapi::RequestError>) -> (::http::StatusCode, ::serde_json::Value) {
+	let response = api_server.execute(request.expect("couldn't create request"));
+	let value = ::serde_json::from_slice(response.body()).expect("couldn't parse response");
+	(response.status(), value)
+}
+
+fn list(api_server: &ApiServer, namespace: &str, label_selector: Option<&str>, limit: Option<i64>, continue_: Option<&str>) -> ::serde_json::Value {
+	k8s_if_le_1_7! {
+		assert!(limit.is_none() && continue_.is_none());
+		let request = api::ConfigMap::list_core_v1_namespaced_config_map(namespace, None, None, label_selector, None, None, None, None);
+	}
+	k8s_if_ge_1_8! {
+		let request = api::ConfigMap::list_core_v1_namespaced_config_map(namespace, continue_, None, None, label_selector, limit, None, None, None, None);
+	}
+
+	let (status_code, list) = execute(api_server, request);
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	list
+}
+
+fn names(list: &::serde_json::Value) -> Vec<&str> {
+	list["items"].as_array().expect("list has no items").iter().map(|item| item["metadata"]["name"].as_str().expect("item has no name")).collect()
+}
+
+fn delete(api_server: &ApiServer, name: &str, namespace: &str) -> (::http::StatusCode, ::serde_json::Value) {
+	k8s_if_le_1_11! {
+		let request = api::ConfigMap::delete_core_v1_namespaced_config_map(name, namespace, None, None, None, None);
+	}
+	k8s_if_ge_1_12! {
+		let request = api::ConfigMap::delete_core_v1_namespaced_config_map(name, namespace, None, None, None, None, None);
+	}
+
+	execute(api_server, request)
+}
+
+#[test]
+fn create_read_replace_delete() {
+	let api_server = ApiServer::new();
+
+	let (status_code, created) = execute(&api_server, api::ConfigMap::create_core_v1_namespaced_config_map("default", &config_map("foo", &[], &[("a", "1")]), None));
+	assert_eq!(status_code, ::http::StatusCode::CREATED);
+	assert_eq!(created["metadata"]["namespace"], "default");
+	assert_eq!(created["metadata"]["resourceVersion"], "1");
+
+	let (status_code, status) = execute(&api_server, api::ConfigMap::create_core_v1_namespaced_config_map("default", &config_map("foo", &[], &[]), None));
+	assert_eq!(status_code, ::http::StatusCode::CONFLICT);
+	assert_eq!(status["reason"], "AlreadyExists");
+
+	// The same name in a different namespace is a different object
+	let (status_code, _) = execute(&api_server, api::ConfigMap::create_core_v1_namespaced_config_map("other", &config_map("foo", &[], &[]), None));
+	assert_eq!(status_code, ::http::StatusCode::CREATED);
+
+	// Parse the response with the generated response type
+	let response = api_server.execute(api::ConfigMap::read_core_v1_namespaced_config_map("foo", "default", None, None, None).unwrap());
+	let read: api::ConfigMap = match ::k8s_openapi::Response::try_from_parts(response.status(), response.body()) {
+		Ok((api::ReadCoreV1NamespacedConfigMapResponse::Ok(config_map), _)) => config_map,
+		other => panic!("{:?}", other),
+	};
+	assert_eq!(read.data.as_ref().unwrap()["a"], "1");
+
+	let (status_code, status) = execute(&api_server, api::ConfigMap::read_core_v1_namespaced_config_map("bar", "default", None, None, None));
+	assert_eq!(status_code, ::http::StatusCode::NOT_FOUND);
+	assert_eq!(status["reason"], "NotFound");
+
+	let mut replacement = read.clone();
+	replacement.data = Some(vec![("a".to_string(), "2".to_string())].into_iter().collect());
+	let (status_code, replaced) = execute(&api_server, api::ConfigMap::replace_core_v1_namespaced_config_map("foo", "default", &replacement, None));
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(replaced["data"]["a"], "2");
+	assert_eq!(replaced["metadata"]["resourceVersion"], "3");
+	assert_eq!(replaced["metadata"]["uid"], created["metadata"]["uid"]);
+
+	// `replacement` still has the old resource version
+	let (status_code, status) = execute(&api_server, api::ConfigMap::replace_core_v1_namespaced_config_map("foo", "default", &replacement, None));
+	assert_eq!(status_code, ::http::StatusCode::CONFLICT);
+	assert_eq!(status["reason"], "Conflict");
+
+	let (status_code, status) = delete(&api_server, "foo", "default");
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(status["status"], "Success");
+
+	let (status_code, _) = delete(&api_server, "foo", "default");
+	assert_eq!(status_code, ::http::StatusCode::NOT_FOUND);
+
+	let (status_code, _) = execute(&api_server, api::ConfigMap::replace_core_v1_namespaced_config_map("foo", "default", &replacement, None));
+	assert_eq!(status_code, ::http::StatusCode::NOT_FOUND);
+
+	let (status_code, _) = execute(&api_server, api::ConfigMap::read_core_v1_namespaced_config_map("foo", "other", None, None, None));
+	assert_eq!(status_code, ::http::StatusCode::OK);
+}
+
+#[test]
+fn patch() {
+	let api_server = ApiServer::new();
+
+	let (status_code, _) = execute(&api_server, api::ConfigMap::create_core_v1_namespaced_config_map("default", &config_map("foo", &[], &[("a", "1"), ("b", "2")]), None));
+	assert_eq!(status_code, ::http::StatusCode::CREATED);
+
+	let patch = |content_type: &str, body: &str| {
+		::http::Request::patch("/api/v1/namespaces/default/configmaps/foo")
+		.header(::http::header::CONTENT_TYPE, content_type)
+		.body(body.as_bytes().to_vec())
+		.map_err(::k8s_openapi::RequestError::Http)
+	};
+
+	let (status_code, patched) = execute(&api_server, patch("application/merge-patch+json", r#"{"data":{"a":null,"c":"3"}}"#));
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(patched["data"], ::k8s_openapi::serde_json::json!({ "b": "2", "c": "3" }));
+	assert_eq!(patched["metadata"]["resourceVersion"], "2");
+
+	let (status_code, patched) = execute(&api_server, patch(
+		"application/json-patch+json",
+		r#"[{"op":"test","path":"/data/b","value":"2"},{"op":"move","from":"/data/b","path":"/data/d"},{"op":"add","path":"/metadata/labels","value":{"x":"y"}}]"#));
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(patched["data"], ::k8s_openapi::serde_json::json!({ "c": "3", "d": "2" }));
+	assert_eq!(patched["metadata"]["labels"], ::k8s_openapi::serde_json::json!({ "x": "y" }));
+
+	let (status_code, status) = execute(&api_server, patch("application/json-patch+json", r#"[{"op":"test","path":"/data/b","value":"2"}]"#));
+	assert_eq!(status_code, ::http::StatusCode::UNPROCESSABLE_ENTITY);
+	assert_eq!(status["reason"], "Invalid");
+
+	// A patch with a stale resource version conflicts
+	let (status_code, _) = execute(&api_server, patch("application/merge-patch+json", r#"{"metadata":{"resourceVersion":"1"}}"#));
+	assert_eq!(status_code, ::http::StatusCode::CONFLICT);
+
+	// The name of an object can't be changed
+	let (status_code, status) = execute(&api_server, patch("application/merge-patch+json", r#"{"metadata":{"name":"bar"}}"#));
+	assert_eq!(status_code, ::http::StatusCode::BAD_REQUEST);
+	assert_eq!(status["reason"], "BadRequest");
+}
+
+#[test]
+fn list_and_watch() {
+	let api_server = ApiServer::new();
+
+	for &(name, namespace, tier) in &[("a", "default", "frontend"), ("b", "default", "backend"), ("c", "default", "frontend"), ("d", "other", "frontend")] {
+		let (status_code, _) = execute(&api_server, api::ConfigMap::create_core_v1_namespaced_config_map(namespace, &config_map(name, &[("tier", tier)], &[]), None));
+		assert_eq!(status_code, ::http::StatusCode::CREATED);
+	}
+
+	let all = list(&api_server, "default", None, None, None);
+	assert_eq!(names(&all), ["a", "b", "c"]);
+	assert_eq!(all["metadata"]["resourceVersion"], "4");
+
+	assert_eq!(names(&list(&api_server, "default", Some("tier=frontend"), None, None)), ["a", "c"]);
+
+	let (status_code, all_namespaces) = execute(&api_server, Ok(::http::Request::get("/api/v1/configmaps?labelSelector=tier%3Dfrontend").body(vec![]).unwrap()));
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(names(&all_namespaces), ["a", "c", "d"]);
+
+	k8s_if_ge_1_8! {
+		let first_page = list(&api_server, "default", None, Some(2), None);
+		assert_eq!(names(&first_page), ["a", "b"]);
+		let continue_ = first_page["metadata"]["continue"].as_str().expect("first page has no continue token");
+		let second_page = list(&api_server, "default", None, Some(2), Some(continue_));
+		assert_eq!(names(&second_page), ["c"]);
+		assert!(second_page["metadata"].get("continue").is_none());
+	}
+
+	let (status_code, _) = delete(&api_server, "a", "default");
+	assert_eq!(status_code, ::http::StatusCode::OK);
+
+	k8s_if_le_1_7! {
+		let request = api::ConfigMap::list_core_v1_namespaced_config_map("default", None, None, None, None, Some("2"), None, Some(true));
+	}
+	k8s_if_ge_1_8! {
+		let request = api::ConfigMap::list_core_v1_namespaced_config_map("default", None, None, None, None, None, None, Some("2"), None, Some(true));
+	}
+	let response = api_server.execute(request.unwrap());
+	assert_eq!(response.status(), ::http::StatusCode::OK);
+
+	// Parse the events with the generated response type
+	let mut response_body = ::k8s_openapi::ResponseBody::new(response.status());
+	let mut events = vec![];
+	for chunk in response.body().chunks(7) {
+		match response_body.append_slice_and_parse::<api::WatchCoreV1NamespacedConfigMapListResponse>(chunk) {
+			Ok(api::WatchCoreV1NamespacedConfigMapListResponse::Ok(event)) => events.push(event),
+			Ok(other) => panic!("{:?}", other),
+			Err(::k8s_openapi::ResponseError::NeedMoreData) => (),
+			Err(err) => panic!("{}", err),
+		}
+	}
+	assert!(response_body.is_empty());
+
+	// Only the events after resource version 2, ie the creation of "c" and the deletion of "a", and not the creation of "d" in the other namespace
+	assert_eq!(events.iter().map(|event| &*event.type_).collect::<Vec<_>>(), ["ADDED", "DELETED"]);
+	assert_eq!(events[1].object.0["metadata"]["name"], "a");
+	assert_eq!(events[1].object.0["metadata"]["resourceVersion"], "5");
+}
+
+#[test]
+fn field_selector() {
+	use ::k8s_openapi::field_selector::FieldSelector;
+
+	let api_server = ApiServer::new();
+
+	// The fake doesn't validate names, so they can have the characters that are escaped in field selectors
+	for &name in &["a", "b,c=d", r"e\f"] {
+		let (status_code, _) = execute(&api_server, api::ConfigMap::create_core_v1_namespaced_config_map("default", &config_map(name, &[], &[]), None));
+		assert_eq!(status_code, ::http::StatusCode::CREATED);
+	}
+
+	let list = |field_selector: &str| {
+		k8s_if_le_1_7! {
+			let request = api::ConfigMap::list_core_v1_namespaced_config_map("default", Some(field_selector), None, None, None, None, None, None);
+		}
+		k8s_if_ge_1_8! {
+			let request = api::ConfigMap::list_core_v1_namespaced_config_map("default", None, Some(field_selector), None, None, None, None, None, None, None);
+		}
+		execute(&api_server, request)
+	};
+
+	let field_selector = FieldSelector::new().equals(api::ConfigMapField::MetadataName, "b,c=d").to_string();
+	assert_eq!(field_selector, r"metadata.name=b\,c\=d");
+	let (status_code, selected) = list(&field_selector);
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(names(&selected), ["b,c=d"]);
+
+	let (status_code, selected) = list(r"metadata.name!=e\\f,metadata.namespace=default");
+	assert_eq!(status_code, ::http::StatusCode::OK);
+	assert_eq!(names(&selected), ["a", "b,c=d"]);
+
+	let (status_code, status) = list(r"metadata.name=e\f");
+	assert_eq!(status_code, ::http::StatusCode::BAD_REQUEST);
+	assert_eq!(status["reason"], "BadRequest");
+}
@@ -0,0 +1,34 @@
+#[test]
+fn build_and_parse() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	use ::k8s_openapi::field_selector::{ Error, FieldSelector, Operator, Requirement };
+
+	let field_selector =
+		FieldSelector::new()
+		.equals(api::PodField::SpecNodeName, "node1")
+		.not_equals(api::PodField::StatusPhase, "Running")
+		.equals(api::PodField::MetadataName, r"a,b=c\d");
+	assert_eq!(field_selector.to_string(), r"spec.nodeName=node1,status.phase!=Running,metadata.name=a\,b\=c\\d");
+	assert_eq!(field_selector.to_string().parse(), Ok(field_selector));
+
+	assert_eq!("metadata.namespace==default, status.phase=".parse(), Ok(FieldSelector(vec![
+		Requirement { field: api::PodField::MetadataNamespace, operator: Operator::Equals("default".to_string()) },
+		Requirement { field: api::PodField::StatusPhase, operator: Operator::Equals("".to_string()) },
+	])));
+	assert_eq!("".parse(), Ok(FieldSelector::<api::PodField>::new()));
+
+	assert_eq!("spec.nodename=node1".parse::<FieldSelector<api::PodField>>(), Err(Error::UnknownField("spec.nodename".to_string())));
+	assert_eq!("spec.nodeName".parse::<FieldSelector<api::PodField>>(), Err(Error::MissingOperator("spec.nodeName".to_string())));
+	assert_eq!(r"spec.nodeName=a\b".parse::<FieldSelector<api::PodField>>(), Err(Error::InvalidEscapeSequence(r"a\b".to_string())));
+
+	// Cluster-scoped kinds can't be selected by namespace
+	assert_eq!("metadata.namespace=default".parse::<FieldSelector<api::NodeField>>(), Err(Error::UnknownField("metadata.namespace".to_string())));
+	assert_eq!("spec.unschedulable=true".parse(), Ok(FieldSelector::new().equals(api::NodeField::SpecUnschedulable, "true")));
+
+	// Parsing without a kind doesn't check the fields
+	assert_eq!(::k8s_openapi::field_selector::parse_untyped(r"spec.foo!=a\,b\=c, bar==d"), Ok(vec![
+		("spec.foo", Operator::NotEquals("a,b=c".to_string())),
+		("bar", Operator::Equals("d".to_string())),
+	]));
+}
@@ -0,0 +1,81 @@
+use k8s_openapi_client::{ Authentication, Config, Error };
+
+/// Creates an empty directory for a test to write its files to
+fn test_dir(name: &str) -> ::std::path::PathBuf {
+	let mut dir = ::std::env::temp_dir();
+	dir.push(format!("k8s-openapi-tests-{}-{}", name, ::std::process::id()));
+	let _ = ::std::fs::remove_dir_all(&dir);
+	::std::fs::create_dir_all(&dir).expect("couldn't create test directory");
+	dir
+}
+
+// A single test, since it sets environment variables that are shared by the whole process
+#[test]
+fn in_cluster() {
+	let dir = test_dir("in-cluster");
+
+	::std::env::remove_var("KUBERNETES_SERVICE_HOST");
+	::std::env::remove_var("KUBERNETES_SERVICE_PORT");
+	match Config::in_cluster_from(&dir) {
+		Err(Error::Config(message)) => assert_eq!(message, "KUBERNETES_SERVICE_HOST and KUBERNETES_SERVICE_PORT must be set to use the in-cluster config"),
+		result => panic!("{:?}", result),
+	}
+
+	::std::env::set_var("KUBERNETES_SERVICE_HOST", "10.0.0.1");
+	::std::env::set_var("KUBERNETES_SERVICE_PORT", "443");
+
+	match Config::in_cluster_from(&dir) {
+		Err(Error::Io(ref err)) if err.kind() == ::std::io::ErrorKind::NotFound => (),
+		result => panic!("{:?}", result),
+	}
+
+	::std::fs::write(dir.join("token"), "service account token\n").unwrap();
+	let config = Config::in_cluster_from(&dir).unwrap();
+	assert_eq!(config.server, "https://10.0.0.1:443/");
+	assert!(config.certificate_authority.is_none());
+	assert!(config.client_certificate.is_none());
+	match config.authentication {
+		Some(Authentication::Token(token)) => assert_eq!(token, "service account token"),
+		authentication => panic!("{:?}", authentication),
+	}
+	assert_eq!(config.namespace, "default");
+
+	::std::fs::write(dir.join("ca.crt"), "ca certificate\n").unwrap();
+	::std::fs::write(dir.join("namespace"), "kube-system").unwrap();
+	::std::env::set_var("KUBERNETES_SERVICE_HOST", "fd00::1");
+	::std::env::set_var("KUBERNETES_SERVICE_PORT", "6443");
+	let config = Config::in_cluster_from(&dir).unwrap();
+
+	// The same config as that of an equivalent kubeconfig
+	::std::fs::write(dir.join("config"), r#"
+clusters:
+- name: cluster
+  cluster:
+    server: https://[fd00::1]:6443
+    certificate-authority: ca.crt
+contexts:
+- name: context
+  context:
+    cluster: cluster
+    user: user
+    namespace: kube-system
+current-context: context
+users:
+- name: user
+  user:
+    tokenFile: token
+"#).unwrap();
+	let expected = Config::from_kubeconfig_file(&dir.join("config"), None).unwrap();
+	assert_eq!(format!("{:?}", config), format!("{:?}", expected));
+	assert_eq!(config.server, "https://[fd00::1]:6443/");
+	assert_eq!(config.certificate_authority, Some(b"ca certificate\n".to_vec()));
+	assert_eq!(config.namespace, "kube-system");
+	match (config.authentication, expected.authentication) {
+		(Some(Authentication::Token(token)), Some(Authentication::Token(expected))) => assert_eq!(token, expected),
+		authentication => panic!("{:?}", authentication),
+	}
+
+	::std::env::remove_var("KUBERNETES_SERVICE_HOST");
+	::std::env::remove_var("KUBERNETES_SERVICE_PORT");
+	::std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,47 @@
+#[test]
+fn scaled_value() {
+	use ::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+	assert_eq!(IntOrString::Int(3).scaled_value(10, true), Ok(3));
+	assert_eq!(IntOrString::Int(3).percentage(), Ok(None));
+
+	let value = IntOrString::String("25%".to_string());
+	assert_eq!(value.percentage(), Ok(Some(25)));
+	assert_eq!(value.scaled_value(10, true), Ok(3));
+	assert_eq!(value.scaled_value(10, false), Ok(2));
+	assert_eq!(value.scaled_value(0, true), Ok(0));
+
+	// A leading sign is accepted, like Go's strconv.Atoi does
+	let value = IntOrString::String("-10%".to_string());
+	assert_eq!(value.percentage(), Ok(Some(-10)));
+	assert_eq!(value.scaled_value(15, true), Ok(-1));
+	assert_eq!(value.scaled_value(15, false), Ok(-2));
+	assert_eq!(IntOrString::String("+10%".to_string()).percentage(), Ok(Some(10)));
+
+	for invalid in &["25", "%", "-%", "--25%", "+-25%", "2.5%", "25 %", "http"] {
+		let value = IntOrString::String(invalid.to_string());
+		assert_eq!(value.percentage(), Err(::k8s_openapi::IntOrStringError::NotAPercentage(invalid.to_string())));
+		assert_eq!(value.scaled_value(10, true), Err(::k8s_openapi::IntOrStringError::NotAPercentage(invalid.to_string())));
+	}
+
+	let value = IntOrString::String("99999999999%".to_string());
+	assert_eq!(value.percentage(), Err(::k8s_openapi::IntOrStringError::OutOfRange("99999999999%".to_string())));
+
+	let value = IntOrString::String("1000%".to_string());
+	assert_eq!(value.scaled_value(::std::i32::MAX, true), Err(::k8s_openapi::IntOrStringError::OutOfRange("1000%".to_string())));
+}
+
+#[test]
+fn port_number() {
+	use ::k8s_openapi::api::core::v1 as api;
+	use ::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+	let ports = vec![
+		api::ContainerPort { container_port: 8080, name: Some("http".to_string()), ..Default::default() },
+		api::ContainerPort { container_port: 9090, ..Default::default() },
+	];
+
+	assert_eq!(IntOrString::Int(443).port_number(&ports), Some(443));
+	assert_eq!(IntOrString::String("http".to_string()).port_number(&ports), Some(8080));
+	assert_eq!(IntOrString::String("https".to_string()).port_number(&ports), None);
+}
@@ -0,0 +1,124 @@
+use k8s_openapi_client::{ Authentication, Config, Error };
+use k8s_openapi_client::kubeconfig::KubeConfig;
+
+fn fixture(name: &str) -> ::std::path::PathBuf {
+	let mut path: ::std::path::PathBuf = env!("CARGO_MANIFEST_DIR").into();
+	path.push("test-data");
+	path.push("kubeconfig");
+	path.push(name);
+	path
+}
+
+fn resolve(name: &str, context: Option<&str>) -> Result<Config, Error> {
+	Config::from_kubeconfig_file(&fixture(name), context)
+}
+
+fn assert_token(config: &Config, expected: &str) {
+	match &config.authentication {
+		Some(Authentication::Token(token)) => assert_eq!(token, expected),
+		authentication => panic!("{:?}", authentication),
+	}
+}
+
+fn assert_config_error<T>(result: Result<T, Error>, expected: &str) where T: ::std::fmt::Debug {
+	match result {
+		Err(Error::Config(message)) => assert_eq!(message, expected),
+		result => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn files() {
+	let kubeconfig = KubeConfig::from_file(&fixture("files.yaml")).unwrap();
+	assert_eq!(kubeconfig.clusters[0].cluster.certificate_authority, Some(fixture("ca.crt")));
+	assert_eq!(kubeconfig.users[1].user.token_file, Some(fixture("token")));
+
+	let config = resolve("files.yaml", None).unwrap();
+	assert_eq!(config.server, "https://files.example.com:6443");
+	assert_eq!(config.certificate_authority, Some(b"ca certificate\n".to_vec()));
+	assert!(!config.accept_invalid_certs);
+	let client_certificate = config.client_certificate.unwrap();
+	assert_eq!(client_certificate.certificate, b"client certificate\n");
+	assert_eq!(client_certificate.key, b"client key\n");
+	assert!(config.authentication.is_none());
+	assert_eq!(config.namespace, "default");
+
+	let config = resolve("files.yaml", Some("token-file")).unwrap();
+	assert!(config.client_certificate.is_none());
+	assert_token(&config, "file token");
+
+	assert_config_error(resolve("files.yaml", Some("does-not-exist")), "couldn't find context named does-not-exist");
+}
+
+#[test]
+fn inline() {
+	let config = resolve("inline.yaml", None).unwrap();
+	assert_eq!(config.server, "https://inline.example.com/");
+	assert_eq!(config.certificate_authority, Some(b"inline ca certificate".to_vec()));
+	let client_certificate = config.client_certificate.unwrap();
+	assert_eq!(client_certificate.certificate, b"inline client certificate");
+	assert_eq!(client_certificate.key, b"inline client key");
+	assert_eq!(config.namespace, "kube-system");
+
+	let config = resolve("inline.yaml", Some("token")).unwrap();
+	assert_token(&config, "inline-token");
+	assert_eq!(config.namespace, "default");
+
+	let config = resolve("inline.yaml", Some("basic")).unwrap();
+	assert!(config.certificate_authority.is_none());
+	assert!(config.accept_invalid_certs);
+	match config.authentication {
+		Some(Authentication::Basic { username, password }) => {
+			assert_eq!(username, "admin");
+			assert_eq!(password, "hunter2");
+		},
+		authentication => panic!("{:?}", authentication),
+	}
+
+	let config = resolve("inline.yaml", Some("anonymous")).unwrap();
+	assert!(config.client_certificate.is_none());
+	assert!(config.authentication.is_none());
+
+	assert_config_error(resolve("inline.yaml", Some("token-and-basic")), "user token-and-basic has both a token and a username or password");
+	assert_config_error(resolve("inline.yaml", Some("missing-user")), "couldn't find user named does-not-exist");
+}
+
+#[cfg(unix)]
+#[test]
+fn exec() {
+	let config = resolve("exec.yaml", None).unwrap();
+	assert!(config.client_certificate.is_none());
+	assert_token(&config, "exec-token");
+
+	let config = resolve("exec.yaml", Some("client-certificate")).unwrap();
+	let client_certificate = config.client_certificate.as_ref().unwrap();
+	assert_eq!(client_certificate.certificate, b"exec client certificate");
+	assert_eq!(client_certificate.key, b"exec client key");
+	assert_token(&config, "exec-token");
+
+	match resolve("exec.yaml", Some("failing")) {
+		Err(Error::Config(ref message)) if message.starts_with("credential plugin sh failed: ") => (),
+		result => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn merge() {
+	let kubeconfig =
+		KubeConfig::from_file(&fixture("merge-1.yaml")).unwrap()
+		.merge(KubeConfig::from_file(&fixture("merge-2.yaml")).unwrap());
+
+	assert_eq!(kubeconfig.clusters.iter().map(|c| &*c.name).collect::<Vec<_>>(), ["first", "second"]);
+	assert_eq!(kubeconfig.contexts.iter().map(|c| &*c.name).collect::<Vec<_>>(), ["first", "second"]);
+	assert_eq!(kubeconfig.users.len(), 1);
+	assert_eq!(kubeconfig.current_context.as_ref().map(String::as_str), Some("first"));
+
+	let config = kubeconfig.resolve(None).unwrap();
+	assert_eq!(config.server, "https://first-1.example.com/");
+	assert_token(&config, "token-1");
+
+	let config = kubeconfig.resolve(Some("second")).unwrap();
+	assert_eq!(config.server, "https://second.example.com/");
+	assert_token(&config, "token-1");
+	assert_eq!(config.namespace, "second");
+}
@@ -0,0 +1,102 @@
+#[test]
+fn parse() {
+	use ::k8s_openapi::label_selector::{ Error, Operator, Requirement, Selector };
+
+	let selector: Selector = "a=b, c == d,e!=f,g in (h, i),j notin (k),l,!m,n=".parse().expect("couldn't parse label selector");
+	assert_eq!(selector, Selector(vec![
+		Requirement { key: "a".to_string(), operator: Operator::Equals("b".to_string()) },
+		Requirement { key: "c".to_string(), operator: Operator::Equals("d".to_string()) },
+		Requirement { key: "e".to_string(), operator: Operator::NotEquals("f".to_string()) },
+		Requirement { key: "g".to_string(), operator: Operator::In(vec!["h".to_string(), "i".to_string()].into_iter().collect()) },
+		Requirement { key: "j".to_string(), operator: Operator::NotIn(vec!["k".to_string()].into_iter().collect()) },
+		Requirement { key: "l".to_string(), operator: Operator::Exists },
+		Requirement { key: "m".to_string(), operator: Operator::DoesNotExist },
+		Requirement { key: "n".to_string(), operator: Operator::Equals("".to_string()) },
+	]));
+	assert_eq!(selector.to_string(), "a=b,c=d,e!=f,g in (h,i),j notin (k),l,!m,n=");
+	assert_eq!(selector.to_string().parse::<Selector>(), Ok(selector));
+
+	assert_eq!("".parse::<Selector>(), Ok(Selector(vec![])));
+	assert_eq!("example.com/app=foo".parse::<Selector>().map(|selector| selector.to_string()), Ok("example.com/app=foo".to_string()));
+
+	assert_eq!("a in (b".parse::<Selector>(), Err(Error::UnexpectedEnd));
+	assert_eq!("a b".parse::<Selector>(), Err(Error::UnexpectedToken("b".to_string())));
+	assert_eq!("!a=b".parse::<Selector>(), Err(Error::UnexpectedToken("=".to_string())));
+	assert_eq!("a,".parse::<Selector>(), Err(Error::UnexpectedEnd));
+	assert_eq!("-a=b".parse::<Selector>(), Err(Error::InvalidKey("-a".to_string())));
+	assert_eq!("a=b/c".parse::<Selector>(), Err(Error::InvalidValue("b/c".to_string())));
+}
+
+#[test]
+fn matches() {
+	use ::k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	use ::k8s_openapi::label_selector::Selector;
+
+	let object_meta = meta::ObjectMeta {
+		labels: Some(vec![
+			("app".to_string(), "foo".to_string()),
+			("tier".to_string(), "frontend".to_string()),
+		].into_iter().collect()),
+		..Default::default()
+	};
+
+	let matches = |selector: &str| selector.parse::<Selector>().expect("couldn't parse label selector").matches(&object_meta);
+
+	assert!(matches(""));
+	assert!(matches("app=foo"));
+	assert!(!matches("app=bar"));
+	assert!(matches("app!=bar"));
+	assert!(matches("canary!=true"));
+	assert!(matches("tier in (frontend,backend)"));
+	assert!(!matches("tier notin (frontend)"));
+	assert!(matches("canary notin (true)"));
+	assert!(matches("app,!canary"));
+	assert!(!matches("canary"));
+	assert!(!matches("app=foo,canary"));
+
+	assert!(!"app".parse::<Selector>().expect("couldn't parse label selector").matches(&meta::ObjectMeta::default()));
+	assert!("!app".parse::<Selector>().expect("couldn't parse label selector").matches(&meta::ObjectMeta::default()));
+}
+
+#[test]
+fn label_selector() {
+	use ::k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+	use ::k8s_openapi::label_selector::{ Error, Selector };
+
+	let label_selector = meta::LabelSelector {
+		match_expressions: Some(vec![
+			meta::LabelSelectorRequirement { key: "tier".to_string(), operator: "In".to_string(), values: Some(vec!["frontend".to_string(), "backend".to_string()]) },
+			meta::LabelSelectorRequirement { key: "canary".to_string(), operator: "DoesNotExist".to_string(), values: None },
+		]),
+		match_labels: Some(vec![
+			("app".to_string(), "foo".to_string()),
+		].into_iter().collect()),
+	};
+
+	let selector = label_selector.to_selector().expect("couldn't convert label selector");
+	assert_eq!(selector.to_string(), "app=foo,tier in (backend,frontend),!canary");
+
+	let selector: Selector = "app=foo,app=bar,env!=prod,tier in (backend,frontend),!canary".parse().expect("couldn't parse label selector");
+	let label_selector: meta::LabelSelector = selector.into();
+	assert_eq!(label_selector, meta::LabelSelector {
+		match_expressions: Some(vec![
+			meta::LabelSelectorRequirement { key: "app".to_string(), operator: "In".to_string(), values: Some(vec!["bar".to_string()]) },
+			meta::LabelSelectorRequirement { key: "env".to_string(), operator: "NotIn".to_string(), values: Some(vec!["prod".to_string()]) },
+			meta::LabelSelectorRequirement { key: "tier".to_string(), operator: "In".to_string(), values: Some(vec!["backend".to_string(), "frontend".to_string()]) },
+			meta::LabelSelectorRequirement { key: "canary".to_string(), operator: "DoesNotExist".to_string(), values: None },
+		]),
+		match_labels: Some(vec![
+			("app".to_string(), "foo".to_string()),
+		].into_iter().collect()),
+	});
+
+	let label_selector = meta::LabelSelector {
+		match_expressions: Some(vec![
+			meta::LabelSelectorRequirement { key: "tier".to_string(), operator: "Gt".to_string(), values: Some(vec!["1".to_string()]) },
+		]),
+		match_labels: None,
+	};
+	assert_eq!(label_selector.to_selector(), Err(Error::InvalidOperator(r#"unknown operator "Gt""#.to_string())));
+}
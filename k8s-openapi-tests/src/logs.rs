@@ -1,25 +1,8 @@
 #[test]
 fn get() {
-	k8s_if_1_7! {
-		use ::k8s_openapi::v1_7::kubernetes::pkg::api::v1 as api;
-	}
-	k8s_if_1_8! {
-		use ::k8s_openapi::v1_8::api::core::v1 as api;
-	}
-	k8s_if_1_9! {
-		use ::k8s_openapi::v1_9::api::core::v1 as api;
-	}
-	k8s_if_1_10! {
-		use ::k8s_openapi::v1_10::api::core::v1 as api;
-	}
-	k8s_if_1_11! {
-		use ::k8s_openapi::v1_11::api::core::v1 as api;
-	}
-	k8s_if_1_12! {
-		use ::k8s_openapi::v1_12::api::core::v1 as api;
-	}
+	use ::k8s_openapi::api::core::v1 as api;
 
-	let client = ::Client::new().expect("couldn't create client");
+	let client = ::client("logs-get").expect("couldn't create client");
 
 	k8s_if_le_1_7! {
 		let request =
@@ -48,27 +31,127 @@ fn get() {
 		.metadata.as_ref().expect("couldn't get addon-manager pod metadata")
 		.name.as_ref().expect("couldn't get addon-manager pod name");
 
-	let request =
-		api::Pod::read_core_v1_namespaced_pod_log(
-			addon_manager_pod_name, "kube-system", Some("kube-addon-manager"), None, None, None, None, None, None, None)
-		.expect("couldn't get addon-manager pod logs");
+	let options = ::k8s_openapi::logs::LogOptions {
+		container: Some("kube-addon-manager".to_string()),
+		..Default::default()
+	};
+	let request = ::k8s_openapi::logs::request(addon_manager_pod_name, "kube-system", &options).expect("couldn't get addon-manager pod logs");
 	let response = client.execute(request).expect("couldn't get addon-manager pod logs");
-	let mut addon_manager_logs = String::new();
-	let strings = ::get_multiple_values(response, |response, status_code, _| match response {
-		api::ReadCoreV1NamespacedPodLogResponse::Ok(s) => Ok(::ValueResult::GotValue(s)),
-		other => Err(format!("{:?} {}", other, status_code).into()),
-	}).expect("couldn't get addon-manager pod logs");
-	for s in strings {
-		let s = s.expect("couldn't get addon-manager pod logs");
+	assert_eq!(response.status_code(), ::http::StatusCode::OK);
 
-		addon_manager_logs.push_str(&s);
+	let mut lines = ::k8s_openapi::logs::LogReader::new(response, options.timestamps);
+	let mut num_lines = 0;
+	loop {
+		let line =
+			lines.read_line()
+			.expect("couldn't get addon-manager pod logs")
+			.expect("did not find expected line in addon-manager pod logs");
+		assert!(line.timestamp.is_none());
 
-		if addon_manager_logs.contains("INFO: == Kubernetes addon manager started at") {
+		if line.content.starts_with("INFO: == Kubernetes addon manager started at") {
 			break;
 		}
 
-		if addon_manager_logs.len() > 4096 {
-			panic!("did not find expected text in addon-manager pod logs: {}", addon_manager_logs);
+		num_lines += 1;
+		if num_lines > 100 {
+			panic!("did not find expected line in addon-manager pod logs");
 		}
 	}
 }
+
+/// A response body that returns its data in chunks of the given size.
+struct Chunked<'a>(&'a [u8], usize);
+
+impl<'a> ::std::io::Read for Chunked<'a> {
+	fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+		let len = ::std::cmp::min(::std::cmp::min(buf.len(), self.1), self.0.len());
+		buf[..len].copy_from_slice(&self.0[..len]);
+		self.0 = &self.0[len..];
+		Ok(len)
+	}
+}
+
+#[test]
+fn lines() {
+	use ::k8s_openapi::logs::LogReader;
+
+	// "€" is split across chunks
+	let mut lines = LogReader::new(Chunked(b"first\nsecond\n\xE2\x82\xAC\n\nlast", 3), false);
+	let mut contents = vec![];
+	while let Some(line) = lines.read_line().unwrap() {
+		assert!(line.timestamp.is_none());
+		contents.push(line.content);
+	}
+	assert_eq!(contents, ["first", "second", "€", "", "last"]);
+
+	// Invalid UTF-8 fails the line but not the lines after it, and can be read as bytes
+	let mut lines = LogReader::new(Chunked(b"ok\n\xFF\xFE\nnext\n\xFF\n", 4), false);
+	assert_eq!(lines.read_line().unwrap().unwrap().content, "ok");
+	match lines.read_line() {
+		Err(::k8s_openapi::logs::Error::Utf8(err)) => assert_eq!(err.into_bytes(), b"\xFF\xFE"),
+		result => panic!("{:?}", result),
+	}
+	assert_eq!(lines.read_line().unwrap().unwrap().content, "next");
+	assert_eq!(lines.read_raw_line().unwrap().unwrap().content, b"\xFF");
+	assert!(lines.read_raw_line().unwrap().is_none());
+}
+
+#[test]
+fn timestamps_and_resume() {
+	use ::k8s_openapi::chrono::TimeZone;
+	use ::k8s_openapi::logs::{ LogOptions, LogReader };
+
+	let options = LogOptions {
+		container: Some("foo".to_string()),
+		follow: true,
+		tail_lines: Some(10),
+		timestamps: true,
+		..Default::default()
+	};
+	let request = ::k8s_openapi::logs::request("pod", "default", &options).unwrap();
+	assert_eq!(request.method(), ::http::Method::GET);
+	assert_eq!(request.uri(), "/api/v1/namespaces/default/pods/pod/log?&container=foo&follow=true&tailLines=10&timestamps=true");
+
+	// The connection ends in the middle of a line
+	let mut lines = LogReader::new(Chunked(b"\
+		2018-10-19T12:00:00.1Z a\n\
+		2018-10-19T12:00:01.000000001Z b\n\
+		2018-10-19T12:00:01.000000001Z c\n\
+		2018-10-19T12:00:01.5Z incomplete", 5), true);
+	let mut read = vec![];
+	while let Some(line) = lines.read_line().unwrap() {
+		read.push((line.timestamp.unwrap(), line.content));
+	}
+	assert_eq!(read, [
+		(::k8s_openapi::chrono::Utc.ymd(2018, 10, 19).and_hms_milli(12, 0, 0, 100), "a".to_string()),
+		(::k8s_openapi::chrono::Utc.ymd(2018, 10, 19).and_hms_nano(12, 0, 1, 1), "b".to_string()),
+		(::k8s_openapi::chrono::Utc.ymd(2018, 10, 19).and_hms_nano(12, 0, 1, 1), "c".to_string()),
+		(::k8s_openapi::chrono::Utc.ymd(2018, 10, 19).and_hms_milli(12, 0, 1, 500), "incomplete".to_string()),
+	]);
+
+	// Resume from the last complete line, with a precision of seconds
+	let resume_options = lines.resume_options(&options);
+	assert_eq!(resume_options.since_time, Some(::k8s_openapi::chrono::Utc.ymd(2018, 10, 19).and_hms_nano(12, 0, 1, 1)));
+	assert_eq!(resume_options.tail_lines, None);
+	let request = ::k8s_openapi::logs::request("pod", "default", &resume_options).unwrap();
+	assert_eq!(request.uri(), "/api/v1/namespaces/default/pods/pod/log?&container=foo&follow=true&sinceTime=2018-10-19T12%3A00%3A01Z&timestamps=true");
+
+	// The resumed stream sends the lines of that second again, which are skipped, except for the line that was incomplete
+	lines.resume(Chunked(b"\
+		2018-10-19T12:00:01Z before\n\
+		2018-10-19T12:00:01.000000001Z b\n\
+		2018-10-19T12:00:01.000000001Z c\n\
+		2018-10-19T12:00:01.5Z incomplete line\n\
+		2018-10-19T12:00:02+00:00 d\n", 7));
+	let mut read = vec![];
+	while let Some(line) = lines.read_line().unwrap() {
+		read.push(line.content);
+	}
+	assert_eq!(read, ["incomplete line", "d"]);
+
+	let mut lines = LogReader::new(Chunked(b"no timestamp\n", 100), true);
+	match lines.read_line() {
+		Err(::k8s_openapi::logs::Error::Timestamp(line)) => assert_eq!(line, "no timestamp"),
+		result => panic!("{:?}", result),
+	}
+}
@@ -0,0 +1,90 @@
+#[test]
+fn json() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	use ::k8s_openapi::Resource;
+
+	// A `PodList` as returned by the API server, whose items don't have their own apiVersion and kind,
+	// followed by a second JSON document with a resource of an unknown kind
+	let resources: Vec<Resource> = ::k8s_openapi::manifest::from_str(r#"{
+		"apiVersion": "v1",
+		"kind": "PodList",
+		"items": [
+			{ "metadata": { "name": "foo" } },
+			{ "metadata": { "name": "bar" } }
+		]
+	}
+	{ "apiVersion": "example.com/v1", "kind": "Foo", "spec": {} }"#).unwrap();
+
+	let pod_names: Vec<_> =
+		resources.iter()
+		.filter_map(|resource| match resource {
+			Resource::CoreV1Pod(api::Pod { metadata: Some(metadata), .. }) => metadata.name.as_ref().map(String::as_str),
+			_ => None,
+		})
+		.collect();
+	assert_eq!(pod_names, vec!["foo", "bar"]);
+
+	match &resources[2] {
+		Resource::Unknown(value) => assert_eq!(value["kind"], "Foo"),
+		resource => panic!("{:?}", resource),
+	}
+
+	assert_eq!(resources.len(), 3);
+
+	// Resources serialize back to their original JSON
+	assert_eq!(
+		::k8s_openapi::serde_json::to_value(&resources[0]).unwrap(),
+		::k8s_openapi::serde_json::json!({ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "foo" } }));
+}
+
+#[test]
+fn yaml() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	use ::k8s_openapi::Resource;
+
+	let resources: Vec<Resource> = ::k8s_openapi::manifest::from_str("\
+# A namespace and a config map in it
+---
+apiVersion: v1
+kind: Namespace
+metadata:
+  name: foo
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: bar
+  namespace: foo
+data:
+  key: value
+---
+# An empty document
+---
+").unwrap();
+
+	match &resources[..] {
+		[Resource::CoreV1Namespace(_), Resource::CoreV1ConfigMap(api::ConfigMap { data: Some(data), .. })] =>
+			assert_eq!(data.get("key").map(String::as_str), Some("value")),
+		resources => panic!("{:?}", resources),
+	}
+}
+
+#[test]
+fn other_type() {
+	use ::k8s_openapi::serde_json::{ json, Value };
+
+	// The items of lists are still flattened and given their list's apiVersion and kind
+	let values: Vec<Value> = ::k8s_openapi::manifest::from_str(r#"{
+		"apiVersion": "v1",
+		"kind": "PodList",
+		"items": [{ "metadata": { "name": "foo" } }]
+	}
+	"bar""#).unwrap();
+
+	assert_eq!(values, vec![
+		json!({ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "foo" } }),
+		json!("bar"),
+	]);
+}
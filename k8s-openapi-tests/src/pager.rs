@@ -0,0 +1,76 @@
+/// Parses the given JSON response body as a list of dynamic objects
+fn list_response(status_code: ::http::StatusCode, body: &str) -> ::k8s_openapi::dynamic::ListResponse {
+	let (response, _) = ::k8s_openapi::Response::try_from_parts(status_code, body.as_bytes()).expect("couldn't parse response");
+	response
+}
+
+k8s_if_ge_1_8! {
+	/// Returns the names of the given dynamic objects
+	fn names(objects: &[::k8s_openapi::dynamic::DynamicObject]) -> Vec<&str> {
+		objects.iter().filter_map(|object| object.metadata.as_ref().and_then(|metadata| metadata.name.as_ref())).map(String::as_str).collect()
+	}
+
+	#[test]
+	fn pages() {
+		use ::k8s_openapi::pager::{ OnExpired, Pager };
+
+		let foos = ::k8s_openapi::dynamic::ApiResource { group: "example.com".to_string(), version: "v1".to_string(), plural: "foos".to_string() };
+		let mut pager = Pager::new(OnExpired::Fail, |continue_| foos.list(None, continue_, None, None, Some(1), None));
+
+		let request = pager.next_request().unwrap().unwrap();
+		assert_eq!(request.uri(), "/apis/example.com/v1/foos?limit=1");
+		let items = pager.handle_response(::http::StatusCode::OK, list_response(::http::StatusCode::OK, r#"{
+			"metadata": { "continue": "abc" },
+			"items": [{ "metadata": { "name": "foo1" } }]
+		}"#)).unwrap();
+		assert_eq!(names(&items), vec!["foo1"]);
+
+		let request = pager.next_request().unwrap().unwrap();
+		assert_eq!(request.uri(), "/apis/example.com/v1/foos?continue=abc&limit=1");
+		let items = pager.handle_response(::http::StatusCode::OK, list_response(::http::StatusCode::OK, r#"{
+			"metadata": { "continue": "" },
+			"items": [{ "metadata": { "name": "foo2" } }]
+		}"#)).unwrap();
+		assert_eq!(names(&items), vec!["foo2"]);
+
+		assert!(pager.next_request().is_none());
+	}
+
+	#[test]
+	fn expired() {
+		use ::k8s_openapi::pager::{ Error, OnExpired, Pager };
+
+		let foos = ::k8s_openapi::dynamic::ApiResource { group: "example.com".to_string(), version: "v1".to_string(), plural: "foos".to_string() };
+		let first_page = r#"{ "metadata": { "continue": "abc" }, "items": [{ "metadata": { "name": "foo1" } }] }"#;
+
+		// Fail
+		let mut pager = Pager::new(OnExpired::Fail, |continue_| foos.list(None, continue_, None, None, Some(1), None));
+		pager.next_request().unwrap().unwrap();
+		pager.handle_response(::http::StatusCode::OK, list_response(::http::StatusCode::OK, first_page)).unwrap();
+		pager.next_request().unwrap().unwrap();
+		assert_eq!(pager.handle_response(::http::StatusCode::GONE, list_response(::http::StatusCode::GONE, "")), Err(Error::Expired));
+		assert!(pager.next_request().is_none());
+
+		// Restart
+		let mut pager = Pager::new(OnExpired::Restart, |continue_| foos.list(None, continue_, None, None, Some(1), None));
+		pager.next_request().unwrap().unwrap();
+		pager.handle_response(::http::StatusCode::OK, list_response(::http::StatusCode::OK, first_page)).unwrap();
+		pager.next_request().unwrap().unwrap();
+		assert_eq!(pager.handle_response(::http::StatusCode::GONE, list_response(::http::StatusCode::GONE, "")).map(|items| items.len()), Ok(0));
+		let request = pager.next_request().unwrap().unwrap();
+		assert_eq!(request.uri(), "/apis/example.com/v1/foos?limit=1");
+	}
+}
+
+#[test]
+fn unexpected_response() {
+	use ::k8s_openapi::pager::{ Error, OnExpired, Pager };
+
+	let foos = ::k8s_openapi::dynamic::ApiResource { group: "example.com".to_string(), version: "v1".to_string(), plural: "foos".to_string() };
+	let mut pager = Pager::new(OnExpired::Fail, |continue_| foos.list(None, continue_, None, None, None, None));
+
+	pager.next_request().unwrap().unwrap();
+	let result = pager.handle_response(::http::StatusCode::FORBIDDEN, list_response(::http::StatusCode::FORBIDDEN, ""));
+	assert_eq!(result.map(|items| items.len()), Err(Error::UnexpectedResponse(::http::StatusCode::FORBIDDEN)));
+	assert!(pager.next_request().is_none());
+}
@@ -1,25 +1,8 @@
 #[test]
 fn list() {
-	k8s_if_1_7! {
-		use ::k8s_openapi::v1_7::kubernetes::pkg::api::v1 as api;
-	}
-	k8s_if_1_8! {
-		use ::k8s_openapi::v1_8::api::core::v1 as api;
-	}
-	k8s_if_1_9! {
-		use ::k8s_openapi::v1_9::api::core::v1 as api;
-	}
-	k8s_if_1_10! {
-		use ::k8s_openapi::v1_10::api::core::v1 as api;
-	}
-	k8s_if_1_11! {
-		use ::k8s_openapi::v1_11::api::core::v1 as api;
-	}
-	k8s_if_1_12! {
-		use ::k8s_openapi::v1_12::api::core::v1 as api;
-	}
+	use ::k8s_openapi::api::core::v1 as api;
 
-	let client = ::Client::new().expect("couldn't create client");
+	let client = ::client("pod-list").expect("couldn't create client");
 
 	k8s_if_le_1_7! {
 		let request =
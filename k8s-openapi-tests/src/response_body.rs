@@ -0,0 +1,96 @@
+/// Appends the given body to a `ResponseBody` one byte at a time, and returns the first response that was parsed along with
+/// the number of bytes that had been appended by then
+fn parse_bytewise<R>(status_code: ::http::StatusCode, body: &[u8]) -> (R, usize) where R: ::k8s_openapi::Response {
+	let mut response_body = ::k8s_openapi::ResponseBody::new(status_code);
+	for (i, b) in body.iter().enumerate() {
+		match response_body.append_slice_and_parse(&[*b]) {
+			Ok(response) => return (response, i + 1),
+			Err(::k8s_openapi::ResponseError::NeedMoreData) => (),
+			Err(err) => panic!("{:?}", err),
+		}
+	}
+
+	panic!("body ended without a complete response");
+}
+
+#[test]
+fn json_value() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	// Strings with brackets and escaped quotes must not be mistaken for the end of the value
+	let body = br#"{
+		"metadata": { "name": "foo\"}]" },
+		"spec": { "containers": [{ "name": "bar", "image": "bar", "args": ["{", "\\", "]"] }] }
+	}"#;
+
+	let (response, read): (api::ReadCoreV1NamespacedPodResponse, _) = parse_bytewise(::http::StatusCode::OK, body);
+	assert_eq!(read, body.len());
+	match response {
+		api::ReadCoreV1NamespacedPodResponse::Ok(pod) => {
+			assert_eq!(pod.metadata.and_then(|metadata| metadata.name), Some("foo\"}]".to_string()));
+			assert_eq!(pod.spec.unwrap().containers[0].args, Some(vec!["{".to_string(), "\\".to_string(), "]".to_string()]));
+		},
+		other => panic!("{:?}", other),
+	}
+}
+
+#[test]
+fn json_lines() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	let body = b"{\"type\":\"ADDED\",\"object\":{\"metadata\":{\"name\":\"foo\"}}}\n{\"type\":\"DELETED\"";
+
+	// The first event is parsed once its newline is received, not as soon as its JSON value is complete
+	let (response, read): (api::WatchCoreV1NamespacedPodListResponse, _) = parse_bytewise(::http::StatusCode::OK, body);
+	assert_eq!(read, body.iter().position(|&b| b == b'\n').unwrap() + 1);
+	match response {
+		api::WatchCoreV1NamespacedPodListResponse::Ok(event) => assert_eq!(event.type_, "ADDED"),
+		other => panic!("{:?}", other),
+	}
+}
+
+#[test]
+fn unsuccessful() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	// Responses with unsuccessful status codes don't have to wait for the body
+	let (response, read): (api::ReadCoreV1NamespacedPodResponse, _) = parse_bytewise(::http::StatusCode::UNAUTHORIZED, br#"{"kind":"Status"}"#);
+	assert_eq!(read, 1);
+	match response {
+		api::ReadCoreV1NamespacedPodResponse::Unauthorized => (),
+		other => panic!("{:?}", other),
+	}
+}
+
+#[test]
+fn json_value_scalar() {
+	/// A response whose body is a top-level JSON number
+	#[derive(Debug, PartialEq)]
+	struct Number(i64);
+
+	impl ::k8s_openapi::Response for Number {
+		fn try_from_parts(_: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::k8s_openapi::ResponseError> {
+			match ::serde_json::from_slice(buf) {
+				Ok(value) => Ok((Number(value), buf.len())),
+				Err(ref err) if err.is_eof() => Err(::k8s_openapi::ResponseError::NeedMoreData),
+				Err(err) => Err(::k8s_openapi::ResponseError::Json(err)),
+			}
+		}
+
+		fn framing() -> ::k8s_openapi::ResponseFraming {
+			::k8s_openapi::ResponseFraming::JsonValue
+		}
+	}
+
+	// A number split across reads is only parsed once the whitespace after it is received
+	let mut response_body = ::k8s_openapi::ResponseBody::new(::http::StatusCode::OK);
+	match response_body.append_slice_and_parse::<Number>(b" 12") {
+		Err(::k8s_openapi::ResponseError::NeedMoreData) => (),
+		result => panic!("{:?}", result),
+	}
+	assert_eq!(response_body.append_slice_and_parse::<Number>(b"3\n").unwrap(), Number(123));
+
+	let (response, read): (Number, _) = parse_bytewise(::http::StatusCode::OK, b"-4567\n");
+	assert_eq!(response, Number(-4567));
+	assert_eq!(read, b"-4567\n".len());
+}
@@ -0,0 +1,325 @@
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
+use k8s_openapi::serde_json::json;
+
+fn schema(value: ::serde_json::Value) -> apiextensions::JSONSchemaProps {
+	::serde_json::from_value(value).expect("couldn't parse schema")
+}
+
+/// Validates the value, and returns the errors as the API server would format them.
+fn errors(schema: &apiextensions::JSONSchemaProps, value: ::serde_json::Value) -> Vec<String> {
+	match ::k8s_openapi::schema::validate(schema, &value) {
+		Ok(()) => vec![],
+		Err(errors) => errors.into_iter().map(|err| err.to_string()).collect(),
+	}
+}
+
+#[test]
+fn types() {
+	let schema = schema(json!({
+		"type": "object",
+		"properties": {
+			"array": { "type": "array" },
+			"boolean": { "type": "boolean" },
+			"integer": { "type": "integer" },
+			"number": { "type": "number" },
+			"object": { "type": "object" },
+			"string": { "type": "string" },
+		},
+	}));
+
+	assert_eq!(errors(&schema, json!({
+		"array": [],
+		"boolean": false,
+		"integer": 1.0,
+		"number": 1,
+		"object": {},
+		"string": "",
+		"untyped": null,
+	})), Vec::<String>::new());
+
+	assert_eq!(errors(&schema, json!({
+		"array": {},
+		"boolean": "true",
+		"integer": 1.5,
+		"number": null,
+		"object": [],
+		"string": 5,
+	})), [
+		r#"array in body must be of type array: "object""#,
+		r#"boolean in body must be of type boolean: "string""#,
+		r#"integer in body must be of type integer: "number""#,
+		r#"number in body must be of type number: "null""#,
+		r#"object in body must be of type object: "array""#,
+		r#"string in body must be of type string: "integer""#,
+	]);
+
+	assert_eq!(errors(&schema, json!("foo")), [r#"in body must be of type object: "string""#]);
+}
+
+#[test]
+fn scalars() {
+	let schema = schema(json!({
+		"properties": {
+			"enum": { "enum": ["a", 1, { "b": [2] }] },
+			"exclusive": { "minimum": 0, "exclusiveMinimum": true, "maximum": 10, "exclusiveMaximum": true },
+			"inclusive": { "minimum": 0, "maximum": 10, "multipleOf": 0.1 },
+			"string": { "minLength": 2, "maxLength": 3, "pattern": "^[a-zé]+$" },
+		},
+	}));
+
+	assert_eq!(errors(&schema, json!({ "enum": "a", "exclusive": 5, "inclusive": 0.3, "string": "éé" })), Vec::<String>::new());
+	assert_eq!(errors(&schema, json!({ "enum": 1.0, "exclusive": 9.5, "inclusive": 10, "string": "abc" })), Vec::<String>::new());
+	assert_eq!(errors(&schema, json!({ "enum": { "b": [2.0] }, "inclusive": 0 })), Vec::<String>::new());
+
+	assert_eq!(errors(&schema, json!({ "enum": "b", "exclusive": 0, "inclusive": 0.35, "string": "a" })), [
+		r#"enum in body should be one of ["a",1,{"b":[2]}]"#,
+		"exclusive in body should be greater than 0",
+		"inclusive in body should be a multiple of 0.1",
+		"string in body should be at least 2 chars long",
+	]);
+
+	assert_eq!(errors(&schema, json!({ "exclusive": 10, "inclusive": 10.1, "string": "ABCD" })), [
+		"exclusive in body should be less than 10",
+		"inclusive in body should be less than or equal to 10",
+		"string in body should be at most 3 chars long",
+		"string in body should match '^[a-zé]+$'",
+	]);
+}
+
+#[test]
+fn arrays_and_objects() {
+	let schema = schema(json!({
+		"type": "object",
+		"required": ["spec"],
+		"properties": {
+			"spec": {
+				"type": "object",
+				"required": ["containers"],
+				"properties": {
+					"containers": {
+						"type": "array",
+						"minItems": 1,
+						"items": {
+							"type": "object",
+							"required": ["name"],
+							"properties": {
+								"name": { "type": "string" },
+								"ports": { "type": "array", "maxItems": 2, "uniqueItems": true, "items": { "type": "integer" } },
+							},
+							"additionalProperties": false,
+						},
+					},
+					"labels": {
+						"type": "object",
+						"maxProperties": 2,
+						"additionalProperties": { "type": "string" },
+					},
+					"pair": {
+						"items": [{ "type": "string" }, { "type": "integer" }],
+						"additionalItems": false,
+					},
+					"env": {
+						"patternProperties": { "^[A-Z_]+$": { "type": "string" } },
+						"additionalProperties": false,
+					},
+				},
+			},
+		},
+	}));
+
+	assert_eq!(errors(&schema, json!({
+		"apiVersion": "example.com/v1",
+		"kind": "Foo",
+		"spec": {
+			"containers": [{ "name": "a", "ports": [80, 443] }],
+			"labels": { "app": "foo" },
+			"pair": ["a", 1],
+			"env": { "FOO_BAR": "1" },
+		},
+	})), Vec::<String>::new());
+
+	assert_eq!(errors(&schema, json!({})), ["spec in body is required"]);
+
+	assert_eq!(errors(&schema, json!({
+		"spec": {
+			"containers": [{ "name": "a", "ports": [80, 80.0, 443] }, { "image": "b" }],
+			"labels": { "app": "foo", "tier": 1, "version": "1" },
+			"pair": ["a", 1, true],
+			"env": { "foo": "1" },
+		},
+	})), [
+		"spec.containers.0.ports in body should have at most 2 items",
+		"spec.containers.0.ports in body shouldn't contain duplicates",
+		"spec.containers.1.name in body is required",
+		"spec.containers.1.image in body is a forbidden property",
+		"spec.env.foo in body is a forbidden property",
+		"spec.labels in body should have at most 2 properties",
+		r#"spec.labels.tier in body must be of type string: "integer""#,
+		"spec.pair in body can't have additional items",
+	]);
+
+	assert_eq!(errors(&schema, json!({ "spec": { "containers": [] } })), ["spec.containers in body should have at least 1 items"]);
+}
+
+#[test]
+fn combinators() {
+	let schema = schema(json!({
+		"properties": {
+			"allOf": { "allOf": [{ "type": "integer" }, { "minimum": 1 }] },
+			"anyOf": { "anyOf": [{ "type": "integer" }, { "pattern": "^[0-9]+%$" }] },
+			"oneOf": { "oneOf": [{ "required": ["a"] }, { "required": ["b"] }] },
+			"not": { "not": { "enum": ["forbidden"] } },
+		},
+	}));
+
+	assert_eq!(errors(&schema, json!({ "allOf": 1, "anyOf": "50%", "oneOf": { "a": 1 }, "not": "allowed" })), Vec::<String>::new());
+	assert_eq!(errors(&schema, json!({ "anyOf": 50, "oneOf": { "b": 1 } })), Vec::<String>::new());
+
+	assert_eq!(errors(&schema, json!({ "allOf": 0, "anyOf": "50", "oneOf": { "a": 1, "b": 1 }, "not": "forbidden" })), [
+		"allOf in body must validate all the schemas (allOf)",
+		"allOf in body should be greater than or equal to 1",
+		"anyOf in body must validate at least one schema (anyOf)",
+		"not in body must not validate the schema (not)",
+		"oneOf in body must validate one and only one schema (oneOf)",
+	]);
+
+	assert_eq!(errors(&schema, json!({ "oneOf": {} })), ["oneOf in body must validate one and only one schema (oneOf)"]);
+}
+
+#[test]
+fn derive() {
+	use ::k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+	use ::k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+	use ::k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+	use ::k8s_openapi::schema::JsonSchema;
+	use ::k8s_openapi_derive::JsonSchema;
+
+	/// A FooBar.
+	#[derive(Deserialize, JsonSchema)]
+	#[serde(rename_all = "camelCase")]
+	#[allow(dead_code)]
+	struct FooBar {
+		metadata: Option<meta::ObjectMeta>,
+
+		/// The spec of the FooBar.
+		///
+		/// Required.
+		spec: FooBarSpec,
+	}
+
+	/// The spec of a FooBar.
+	#[derive(Deserialize, JsonSchema)]
+	#[serde(rename_all = "camelCase")]
+	#[allow(dead_code)]
+	struct FooBarSpec {
+		/// The number of replicas.
+		replicas: i32,
+
+		image_pull_policy: Option<PullPolicy>,
+
+		#[serde(rename = "labels")]
+		extra_labels: ::std::collections::BTreeMap<String, String>,
+
+		#[serde(default)]
+		ports: Vec<u16>,
+
+		resources: Option<::std::collections::BTreeMap<String, Quantity>>,
+
+		target_port: IntOrString,
+
+		#[serde(skip)]
+		cache: (),
+
+		command: Command,
+	}
+
+	#[derive(Deserialize, JsonSchema)]
+	#[serde(rename_all = "kebab-case")]
+	#[allow(dead_code)]
+	enum PullPolicy {
+		Always,
+		IfNotPresent,
+		#[serde(rename = "never")]
+		Never,
+	}
+
+	/// A command and its arguments.
+	#[derive(Deserialize, JsonSchema)]
+	#[allow(dead_code)]
+	struct Command(Vec<String>);
+
+	let schema = FooBar::json_schema();
+	assert_eq!(::serde_json::to_value(&schema).unwrap(), json!({
+		"description": "A FooBar.",
+		"properties": {
+			"metadata": { "type": "object" },
+			"spec": {
+				"description": "The spec of the FooBar.\n\nRequired.",
+				"properties": {
+					"command": {
+						"description": "A command and its arguments.",
+						"items": { "type": "string" },
+						"type": "array",
+					},
+					"imagePullPolicy": {
+						"enum": ["always", "if-not-present", "never"],
+						"type": "string",
+					},
+					"labels": {
+						"additionalProperties": { "type": "string" },
+						"type": "object",
+					},
+					"ports": {
+						"items": { "minimum": 0.0, "type": "integer" },
+						"type": "array",
+					},
+					"replicas": {
+						"description": "The number of replicas.",
+						"format": "int32",
+						"type": "integer",
+					},
+					"resources": {
+						"additionalProperties": { "type": "string" },
+						"type": "object",
+					},
+					"targetPort": {
+						"anyOf": [{ "type": "integer" }, { "type": "string" }],
+					},
+				},
+				"required": ["replicas", "labels", "targetPort", "command"],
+				"type": "object",
+			},
+		},
+		"required": ["spec"],
+		"type": "object",
+	}));
+
+	assert_eq!(errors(&schema, json!({
+		"metadata": { "name": "foo" },
+		"spec": {
+			"replicas": 1,
+			"imagePullPolicy": "if-not-present",
+			"labels": {},
+			"resources": { "cpu": "500m" },
+			"targetPort": "http",
+			"command": ["sh"],
+		},
+	})), Vec::<String>::new());
+
+	assert_eq!(errors(&schema, json!({
+		"spec": {
+			"replicas": "1",
+			"imagePullPolicy": "IfNotPresent",
+			"labels": {},
+			"ports": [-1],
+			"targetPort": true,
+			"command": "sh",
+		},
+	})), [
+		r#"spec.command in body must be of type array: "string""#,
+		r#"spec.imagePullPolicy in body should be one of ["always","if-not-present","never"]"#,
+		"spec.ports.0 in body should be greater than or equal to 0",
+		r#"spec.replicas in body must be of type integer: "string""#,
+		"spec.targetPort in body must validate at least one schema (anyOf)",
+	]);
+}
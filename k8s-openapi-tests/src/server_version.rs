@@ -0,0 +1,131 @@
+use k8s_openapi::server_version::{ Dispatch, Error, ServerVersion, Version };
+
+fn info(major: &str, minor: &str, git_version: &str) -> ::k8s_openapi::apimachinery::pkg::version::Info {
+	::k8s_openapi::apimachinery::pkg::version::Info {
+		major: major.to_string(),
+		minor: minor.to_string(),
+		git_version: git_version.to_string(),
+		..Default::default()
+	}
+}
+
+fn server_version(major: u32, minor: u32) -> ServerVersion {
+	ServerVersion { major, minor, git_version: format!("v{}.{}.0", major, minor) }
+}
+
+#[test]
+fn from_info() {
+	assert_eq!(
+		ServerVersion::from_info(&info("1", "12", "v1.12.3")),
+		Ok(ServerVersion { major: 1, minor: 12, git_version: "v1.12.3".to_string() }));
+
+	assert_eq!(
+		ServerVersion::from_info(&info("1", "11+", "v1.11.2-gke.18")),
+		Ok(ServerVersion { major: 1, minor: 11, git_version: "v1.11.2-gke.18".to_string() }));
+
+	assert_eq!(
+		ServerVersion::from_info(&info("", "", "v1.9.11")),
+		Ok(ServerVersion { major: 1, minor: 9, git_version: "v1.9.11".to_string() }));
+
+	assert_eq!(ServerVersion::from_info(&info("", "", "unknown")), Err(Error::InvalidVersion("unknown".to_string())));
+}
+
+#[test]
+fn from_response() {
+	let body = br#"{"major":"1","minor":"12","gitVersion":"v1.12.3","gitCommit":"435f92c719f279a3a67808c80521ea17d5715c66","gitTreeState":"clean","buildDate":"2018-11-26T12:46:57Z","goVersion":"go1.10.4","compiler":"gc","platform":"linux/amd64"}"#;
+	let (response, _) = ::k8s_openapi::Response::try_from_parts(::http::StatusCode::OK, body).unwrap();
+	assert_eq!(
+		ServerVersion::from_response(::http::StatusCode::OK, response),
+		Ok(ServerVersion { major: 1, minor: 12, git_version: "v1.12.3".to_string() }));
+
+	let (response, _) = ::k8s_openapi::Response::try_from_parts(::http::StatusCode::FORBIDDEN, b"forbidden").unwrap();
+	assert_eq!(
+		ServerVersion::from_response(::http::StatusCode::FORBIDDEN, response),
+		Err(Error::UnexpectedResponse(::http::StatusCode::FORBIDDEN)));
+
+	assert_eq!(ServerVersion::request().unwrap().uri(), "/version/");
+}
+
+#[test]
+fn for_server() {
+	let enabled = Version::enabled();
+	assert_eq!(enabled.len(), 1);
+	let version = enabled[0];
+
+	assert_eq!(Version::for_server(&server_version(1, version.minor())), Ok(version));
+
+	// A newer server uses the highest enabled version that's older than it
+	assert_eq!(Version::for_server(&server_version(1, version.minor() + 2)), Ok(version));
+
+	let older = server_version(1, version.minor() - 1);
+	assert_eq!(Version::for_server(&older), Err(Error::UnsupportedVersion(older.clone())));
+
+	let other_major = server_version(2, version.minor());
+	assert_eq!(Version::for_server(&other_major), Err(Error::UnsupportedVersion(other_major.clone())));
+}
+
+#[test]
+fn dispatch() {
+	struct CodeVersionUri(&'static str);
+
+	impl Dispatch for CodeVersionUri {
+		type Output = (&'static str, String);
+
+		fn unsupported(self, version: Version) -> Self::Output {
+			panic!("{} is enabled, so it should have been dispatched to its method", version);
+		}
+
+		k8s_if_1_7! {
+			fn v1_7(self) -> Self::Output {
+				(self.0, ::k8s_openapi::v1_7::get_code_version().unwrap().uri().to_string())
+			}
+		}
+
+		k8s_if_1_8! {
+			fn v1_8(self) -> Self::Output {
+				(self.0, ::k8s_openapi::v1_8::get_code_version().unwrap().uri().to_string())
+			}
+		}
+
+		k8s_if_1_9! {
+			fn v1_9(self) -> Self::Output {
+				(self.0, ::k8s_openapi::v1_9::get_code_version().unwrap().uri().to_string())
+			}
+		}
+
+		k8s_if_1_10! {
+			fn v1_10(self) -> Self::Output {
+				(self.0, ::k8s_openapi::v1_10::get_code_version().unwrap().uri().to_string())
+			}
+		}
+
+		k8s_if_1_11! {
+			fn v1_11(self) -> Self::Output {
+				(self.0, ::k8s_openapi::v1_11::get_code_version().unwrap().uri().to_string())
+			}
+		}
+
+		k8s_if_1_12! {
+			fn v1_12(self) -> Self::Output {
+				(self.0, ::k8s_openapi::v1_12::get_code_version().unwrap().uri().to_string())
+			}
+		}
+	}
+
+	let version = Version::enabled()[0];
+	assert_eq!(version.dispatch(CodeVersionUri("foo")), ("foo", "/version/".to_string()));
+	assert_eq!(version.to_string(), format!("v1_{}", version.minor()));
+
+	// The methods of the versions that an implementation doesn't have default to `unsupported`
+	struct Unsupported;
+
+	impl Dispatch for Unsupported {
+		type Output = Version;
+
+		fn unsupported(self, version: Version) -> Self::Output {
+			version
+		}
+	}
+
+	assert_eq!(version.dispatch(Unsupported), version);
+}
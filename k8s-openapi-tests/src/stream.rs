@@ -0,0 +1,78 @@
+use futures::{ Future, Stream };
+
+/// Collects the responses parsed from the given response body chunks
+fn collect<R>(status_code: ::http::StatusCode, chunks: Vec<&'static [u8]>) -> Result<Vec<R>, ::k8s_openapi::stream::Error<()>> where R: ::k8s_openapi::Response {
+	let stream: ::k8s_openapi::stream::ResponseStream<_, R> = ::k8s_openapi::stream::ResponseStream::new(status_code, ::futures::stream::iter_ok(chunks));
+	stream.collect().wait()
+}
+
+#[test]
+fn watch_events() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	let events: Vec<api::WatchCoreV1NamespacedPodListResponse> = collect(::http::StatusCode::OK, vec![
+		br#"{"type":"ADDED","object":{"metadata":{"name":"#,
+		br#""foo"}}}"#,
+		b"\n",
+		br#"{"type":"MODIFIED","object":{"metadata":{"name":"foo"}}}
+{"type":"DELETED","object":{"metadata":{"name":"foo"}}}
+"#,
+	]).unwrap();
+
+	let types: Vec<_> = events.into_iter().map(|event| match event {
+		api::WatchCoreV1NamespacedPodListResponse::Ok(event) => event.type_,
+		other => panic!("{:?}", other),
+	}).collect();
+	assert_eq!(types, vec!["ADDED", "MODIFIED", "DELETED"]);
+}
+
+#[test]
+fn logs() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	// "é" is split across two chunks
+	let lines: Vec<api::ReadCoreV1NamespacedPodLogResponse> = collect(::http::StatusCode::OK, vec![
+		b"first line\nsecond \xC3",
+		b"\xA9 line\n",
+	]).unwrap();
+
+	let s: String = lines.into_iter().map(|line| match line {
+		api::ReadCoreV1NamespacedPodLogResponse::Ok(s) => s,
+		other => panic!("{:?}", other),
+	}).collect();
+	assert_eq!(s, "first line\nsecond \u{e9} line\n");
+}
+
+#[test]
+fn unauthorized() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	let responses: Vec<api::WatchCoreV1NamespacedPodListResponse> = collect(::http::StatusCode::UNAUTHORIZED, vec![
+		br#"{"kind":"Status","#,
+		br#""code":401}"#,
+	]).unwrap();
+	match &responses[..] {
+		[api::WatchCoreV1NamespacedPodListResponse::Unauthorized] => (),
+		responses => panic!("{:?}", responses),
+	}
+
+	let responses: Vec<api::WatchCoreV1NamespacedPodListResponse> = collect(::http::StatusCode::UNAUTHORIZED, vec![]).unwrap();
+	match &responses[..] {
+		[api::WatchCoreV1NamespacedPodListResponse::Unauthorized] => (),
+		responses => panic!("{:?}", responses),
+	}
+}
+
+#[test]
+fn truncated() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	let result: Result<Vec<api::WatchCoreV1NamespacedPodListResponse>, _> = collect(::http::StatusCode::OK, vec![
+		b"{\"type\":\"ADDED\",\"object\":{\"metadata\":{\"name\":\"foo\"}}}\n",
+		br#"{"type":"DELETED","object":"#,
+	]);
+	match result {
+		Err(::k8s_openapi::stream::Error::Response(::k8s_openapi::ResponseError::NeedMoreData)) => (),
+		result => panic!("{:?}", result),
+	}
+}
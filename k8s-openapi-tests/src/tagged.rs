@@ -0,0 +1,54 @@
+#[test]
+fn delete_response() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	let (response, _) = ::k8s_openapi::Response::try_from_parts(::http::StatusCode::OK, br#"{
+		"kind": "Status",
+		"apiVersion": "v1",
+		"status": "Success"
+	}"#).unwrap();
+	match response {
+		api::DeleteCoreV1NamespacedPodResponse::OkStatus(status) => assert_eq!(status.status, Some("Success".to_string())),
+		other => panic!("{:?}", other),
+	}
+
+	// Fields before `kind` are replayed to the object's deserializer
+	let (response, _) = ::k8s_openapi::Response::try_from_parts(::http::StatusCode::OK, br#"{
+		"metadata": { "name": "foo", "deletionTimestamp": "2018-10-01T00:00:00Z" },
+		"apiVersion": "v1",
+		"kind": "Pod",
+		"spec": { "containers": [{ "name": "foo", "image": "foo" }] }
+	}"#).unwrap();
+	match response {
+		api::DeleteCoreV1NamespacedPodResponse::OkValue(pod) => {
+			assert_eq!(pod.metadata.and_then(|metadata| metadata.name), Some("foo".to_string()));
+			assert_eq!(pod.spec.unwrap().containers[0].name, "foo");
+		},
+		other => panic!("{:?}", other),
+	}
+}
+
+#[test]
+fn resource() {
+	use ::k8s_openapi::api::core::v1 as api;
+
+	let resource: ::k8s_openapi::Resource = ::serde_json::from_str(r#"{
+		"metadata": { "name": "foo" },
+		"data": { "foo": "bar" },
+		"kind": "ConfigMap",
+		"apiVersion": "v1"
+	}"#).unwrap();
+	match resource {
+		::k8s_openapi::Resource::CoreV1ConfigMap(api::ConfigMap { metadata, data, .. }) => {
+			assert_eq!(metadata.and_then(|metadata| metadata.name), Some("foo".to_string()));
+			assert_eq!(data.and_then(|data| data.get("foo").cloned()), Some("bar".to_string()));
+		},
+		other => panic!("{:?}", other),
+	}
+
+	let resource: ::k8s_openapi::Resource = ::serde_json::from_str(r#"{ "apiVersion": "example.com/v1", "kind": "Foo", "spec": [1, 2] }"#).unwrap();
+	match resource {
+		::k8s_openapi::Resource::Unknown(value) => assert_eq!(value["spec"], ::serde_json::json!([1, 2])),
+		other => panic!("{:?}", other),
+	}
+}
@@ -7,15 +7,61 @@ fn main() -> Result<(), Box<std::error::Error>> {
     let versions = {
         let mut versions: std::collections::HashSet<_> = Default::default();
 
-        for v in MIN..=MAX {
-            if std::env::var(format!("CARGO_FEATURE_V1_{}", v)).is_ok() {
-                versions.insert(v);
+        // A version is enabled by its own feature, like `v1_12`, or by the feature of any of its API group versions, like `v1_12-apps-v1`
+        for (key, _) in std::env::vars() {
+            for v in MIN..=MAX {
+                let feature = format!("CARGO_FEATURE_V1_{}", v);
+                if key == feature || key.starts_with(&format!("{}_", feature)) {
+                    versions.insert(v);
+                }
             }
         }
 
         versions
     };
 
+    // The conditional compilation macros only consider the default version, since they're used to write code against the modules at the crate root.
+    // The default version depends on the features enabled by every crate in the dependency graph, so only binaries can rely on it.
+    let versions: std::collections::HashSet<_> = {
+        let mut f = {
+            let mut out_file: std::path::PathBuf = std::env::var_os("OUT_DIR").ok_or_else(|| "OUT_DIR not set")?.into();
+            out_file.push("enabled_version.rs");
+            std::io::BufWriter::new(std::fs::File::create(out_file)?)
+        };
+
+        for v in MIN..=MAX {
+            println!("cargo:rustc-check-cfg=cfg(k8s_openapi_v1_{})", v);
+            println!("cargo:rustc-check-cfg=cfg(k8s_openapi_default_v1_{})", v);
+        }
+        println!("cargo:rustc-check-cfg=cfg(k8s_openapi_apiextensions_v1beta1)");
+
+        // Every enabled version is compiled, and the highest one is the default version that is re-exported at the crate root.
+        // This isn't additive - enabling a higher version anywhere in the graph changes the re-export - so the crate docs tell libraries
+        // to use the `v1_*` modules instead.
+        match versions.iter().max() {
+            None => writeln!(
+                f,
+                r#"compile_error!("None of the v1_* features are enabled on the k8s-openapi crate. Enable at least one of them, like `v1_{}`.");"#,
+                MAX)?,
+
+            Some(default_version) => {
+                for v in &versions {
+                    println!("cargo:rustc-cfg=k8s_openapi_v1_{}", v);
+                }
+
+                println!("cargo:rustc-cfg=k8s_openapi_default_v1_{}", default_version);
+                writeln!(f, "pub use v1_{}::*;", default_version)?;
+
+                // The `schema` module uses the default version's apiextensions/v1beta1 types, which the API group version features can leave out
+                if std::env::var_os(format!("CARGO_FEATURE_V1_{}_APIEXTENSIONS_V1BETA1", default_version)).is_some() {
+                    println!("cargo:rustc-cfg=k8s_openapi_apiextensions_v1beta1");
+                }
+            },
+        }
+
+        versions.iter().max().cloned().into_iter().collect()
+    };
+
     let mut f = {
         let mut out_file: std::path::PathBuf = std::env::var_os("OUT_DIR").ok_or_else(|| "OUT_DIR not set")?.into();
         out_file.push("conditional_compilation_macros.rs");
@@ -23,7 +69,9 @@ fn main() -> Result<(), Box<std::error::Error>> {
     };
 
     for v in MIN..=MAX {
-        writeln!(f, "/// This macro evaluates to its contents if the `v1_{}` feature is enabled, otherwise it evaluates to nothing.", v)?;
+        writeln!(f, "/// This macro evaluates to its contents if the default version of the crate, ie the highest enabled `v1_*` feature, is `v1_{}`, otherwise it evaluates to nothing.", v)?;
+        writeln!(f, "///")?;
+        writeln!(f, "/// Libraries must not use this macro, since the default version can be changed by other crates in the dependency graph. See the crate docs.")?;
         writeln!(f, "///")?;
         writeln!(f, "/// # Examples")?;
         writeln!(f, "///")?;
@@ -47,7 +95,9 @@ fn main() -> Result<(), Box<std::error::Error>> {
         }
         writeln!(f)?;
 
-        writeln!(f, "/// This macro evaluates to its contents if the `v1_{}` or higher feature is enabled, otherwise it evaluates to nothing.", v)?;
+        writeln!(f, "/// This macro evaluates to its contents if the default version of the crate is `v1_{}` or higher, otherwise it evaluates to nothing.", v)?;
+        writeln!(f, "///")?;
+        writeln!(f, "/// Libraries must not use this macro, since the default version can be changed by other crates in the dependency graph. See the crate docs.")?;
         if cfg_ge(v, &versions) {
             writeln!(f, "#[macro_export] macro_rules! k8s_if_ge_1_{} {{ ($($tt:tt)*) => {{ $($tt)* }}; }}", v)?;
         }
@@ -56,7 +106,9 @@ fn main() -> Result<(), Box<std::error::Error>> {
         }
         writeln!(f)?;
 
-        writeln!(f, "/// This macro evaluates to its contents if the `v1_{}` or lower feature is enabled, otherwise it evaluates to nothing.", v)?;
+        writeln!(f, "/// This macro evaluates to its contents if the default version of the crate is `v1_{}` or lower, otherwise it evaluates to nothing.", v)?;
+        writeln!(f, "///")?;
+        writeln!(f, "/// Libraries must not use this macro, since the default version can be changed by other crates in the dependency graph. See the crate docs.")?;
         if cfg_le(v, &versions) {
             writeln!(f, "#[macro_export] macro_rules! k8s_if_le_1_{} {{ ($($tt:tt)*) => {{ $($tt)* }}; }}", v)?;
         }
@@ -70,6 +122,8 @@ fn main() -> Result<(), Box<std::error::Error>> {
     writeln!(f, "/// The match arms can be annotated with the other conditional compilation macros in this crate so that they're only emitted")?;
     writeln!(f, "/// if the predicate is true.")?;
     writeln!(f, "///")?;
+    writeln!(f, "/// Libraries must not use this macro, since the default version can be changed by other crates in the dependency graph. See the crate docs.")?;
+    writeln!(f, "///")?;
     writeln!(f, "/// # Examples")?;
     writeln!(f, "///")?;
     writeln!(f, "/// The `CustomResourceDefinition::create_apiextensions_v1beta1_custom_resource_definition` function returns an `HTTP 201 CREATED`")?;
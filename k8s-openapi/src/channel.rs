@@ -0,0 +1,543 @@
+//! The channel protocol of the streaming subresources of a pod, ie `exec`, `attach` and `portforward`.
+//!
+//! The request of an API function like `Pod::connect_core_v1_post_namespaced_pod_exec` must be upgraded to a WebSocket connection
+//! that uses the `v4.channel.k8s.io` protocol. [`upgrade_request`](fn.upgrade_request.html) adds the headers for that to the request.
+//! After the HTTP client has executed the request and the API server has responded with `101 Switching Protocols`,
+//! the connection is a bidirectional byte stream that carries WebSocket messages, and the first byte of each message is the channel of its data.
+//!
+//! [`Connection`](struct.Connection.html) reads and writes these messages over any `std::io::Read + std::io::Write` byte stream,
+//! like a TCP stream or an in-memory buffer. It's wrapped by [`RemoteCommand`](struct.RemoteCommand.html) for `exec` and `attach`,
+//! which have stdin, stdout, stderr, error and resize channels, and by [`PortForward`](struct.PortForward.html) for `portforward`,
+//! which has a data channel and an error channel for each forwarded port.
+//!
+//! The `Sec-WebSocket-Accept` header of the API server's response isn't validated.
+//!
+//! # Examples
+//!
+//! ```rust
+//! extern crate k8s_openapi;
+//!
+//! use k8s_openapi::channel::{ ExitStatus, RemoteCommand };
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! // An unmasked binary WebSocket message from the API server
+//! fn message(channel: u8, data: &[u8]) -> Vec<u8> {
+//!     let mut message = vec![0x82, 1 + data.len() as u8, channel];
+//!     message.extend_from_slice(data);
+//!     message
+//! }
+//!
+//! // The messages that the API server sent for a command that printed "hello" and exited successfully
+//! let mut messages = message(1, b"hello");
+//! messages.extend(message(3, br#"{"status":"Success"}"#));
+//!
+//! let mut remote_command = RemoteCommand::new(std::io::Cursor::new(messages));
+//! let mut stdout = vec![];
+//! let exit_status = remote_command.wait(&mut stdout, &mut std::io::sink())?;
+//! assert_eq!(stdout, b"hello");
+//! assert_eq!(exit_status, ExitStatus::Success);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::hash::{ BuildHasher, Hasher };
+use std::io::{ Read, Write };
+
+/// The WebSocket subprotocol of the channel protocol.
+pub const PROTOCOL: &str = "v4.channel.k8s.io";
+
+/// The channel of a pod's stdin.
+pub const STDIN: u8 = 0;
+
+/// The channel of a pod's stdout.
+pub const STDOUT: u8 = 1;
+
+/// The channel of a pod's stderr.
+pub const STDERR: u8 = 2;
+
+/// The channel of the `Status` of a remote command, sent by the API server when the command has finished.
+pub const ERROR: u8 = 3;
+
+/// The channel that resizes the TTY of a remote command.
+pub const RESIZE: u8 = 4;
+
+/// Converts the request of an API function like `Pod::connect_core_v1_post_namespaced_pod_exec` into a request that upgrades the connection
+/// to a WebSocket connection that uses the channel protocol.
+pub fn upgrade_request(request: http::Request<Vec<u8>>) -> Result<http::Request<Vec<u8>>, http::Error> {
+    let (mut parts, body) = request.into_parts();
+
+    // WebSocket handshakes must be GET requests
+    parts.method = http::Method::GET;
+
+    let mut key = [0u8; 16];
+    let mut rng = Rng::new();
+    for chunk in key.chunks_mut(4) {
+        chunk.copy_from_slice(&rng.next_mask());
+    }
+
+    let headers = &mut parts.headers;
+    headers.insert(http::header::CONNECTION, http::header::HeaderValue::from_static("Upgrade"));
+    headers.insert(http::header::UPGRADE, http::header::HeaderValue::from_static("websocket"));
+    headers.insert(http::header::SEC_WEBSOCKET_VERSION, http::header::HeaderValue::from_static("13"));
+    headers.insert(http::header::SEC_WEBSOCKET_KEY, base64::encode(&key).parse()?);
+    headers.insert(http::header::SEC_WEBSOCKET_PROTOCOL, http::header::HeaderValue::from_static(PROTOCOL));
+
+    Ok(http::Request::from_parts(parts, body))
+}
+
+/// A connection that uses the channel protocol, as seen by the client.
+///
+/// Messages written by the client are masked, as required by the WebSocket protocol. Pings from the API server are answered automatically.
+#[derive(Debug)]
+pub struct Connection<S> {
+    stream: S,
+    rng: Rng,
+    message: Option<Vec<u8>>,
+    closed: bool,
+}
+
+impl<S> Connection<S> where S: Read + Write {
+    /// Constructs a connection over the given byte stream, which has already been upgraded to a WebSocket connection.
+    pub fn new(stream: S) -> Self {
+        Connection {
+            stream,
+            rng: Rng::new(),
+            message: None,
+            closed: false,
+        }
+    }
+
+    /// Returns the inner byte stream.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Reads the next message, and returns its channel and data. Returns `None` if the connection has been closed.
+    pub fn read_message(&mut self) -> Result<Option<(u8, Vec<u8>)>, Error> {
+        loop {
+            if self.closed {
+                return Ok(None);
+            }
+
+            let (fin, opcode, payload) = match self.read_frame()? {
+                Some(frame) => frame,
+                None => {
+                    if self.message.is_some() {
+                        return Err(Error::Protocol("connection ended in the middle of a fragmented message".to_string()));
+                    }
+
+                    self.closed = true;
+                    return Ok(None);
+                },
+            };
+
+            let message = match opcode {
+                // Continuation
+                0x0 => match &mut self.message {
+                    Some(message) => {
+                        message.extend_from_slice(&payload);
+                        if fin { self.message.take() } else { None }
+                    },
+                    None => return Err(Error::Protocol("unexpected continuation frame".to_string())),
+                },
+
+                // Text or binary
+                0x1 | 0x2 => {
+                    if self.message.is_some() {
+                        return Err(Error::Protocol("unexpected data frame in the middle of a fragmented message".to_string()));
+                    }
+
+                    if fin {
+                        Some(payload)
+                    }
+                    else {
+                        self.message = Some(payload);
+                        None
+                    }
+                },
+
+                // Close
+                0x8 => {
+                    self.close()?;
+                    return Ok(None);
+                },
+
+                // Ping
+                0x9 => {
+                    self.write_frame(0xA, &payload)?;
+                    None
+                },
+
+                // Pong
+                0xA => None,
+
+                opcode => return Err(Error::Protocol(format!("unknown opcode {:#x}", opcode))),
+            };
+
+            if let Some(mut message) = message {
+                if message.is_empty() {
+                    // A message without a channel has no data
+                    continue;
+                }
+
+                let channel = message.remove(0);
+                return Ok(Some((channel, message)));
+            }
+        }
+    }
+
+    /// Writes a message with the given data on the given channel.
+    pub fn write_message(&mut self, channel: u8, data: &[u8]) -> Result<(), Error> {
+        let mut payload = Vec::with_capacity(1 + data.len());
+        payload.push(channel);
+        payload.extend_from_slice(data);
+        self.write_frame(0x2, &payload)
+    }
+
+    /// Closes the connection. The connection can't be used after this.
+    pub fn close(&mut self) -> Result<(), Error> {
+        if !self.closed {
+            self.closed = true;
+            self.write_frame(0x8, &[])?;
+        }
+
+        Ok(())
+    }
+
+    fn read_frame(&mut self) -> Result<Option<(bool, u8, Vec<u8>)>, Error> {
+        let mut header = [0u8; 2];
+        match self.stream.read(&mut header[..1]) {
+            Ok(0) => return Ok(None),
+            Ok(_) => (),
+            Err(err) => return Err(Error::Io(err)),
+        }
+        self.stream.read_exact(&mut header[1..]).map_err(Error::Io)?;
+
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0F;
+        let masked = header[1] & 0x80 != 0;
+
+        let len = match header[1] & 0x7F {
+            126 => {
+                let mut len = [0u8; 2];
+                self.stream.read_exact(&mut len).map_err(Error::Io)?;
+                u64::from(u16::from_be_bytes(len))
+            },
+            127 => {
+                let mut len = [0u8; 8];
+                self.stream.read_exact(&mut len).map_err(Error::Io)?;
+                u64::from_be_bytes(len)
+            },
+            len => u64::from(len),
+        };
+
+        let mask =
+            if masked {
+                let mut mask = [0u8; 4];
+                self.stream.read_exact(&mut mask).map_err(Error::Io)?;
+                Some(mask)
+            }
+            else {
+                None
+            };
+
+        let mut payload = vec![];
+        (&mut self.stream).take(len).read_to_end(&mut payload).map_err(Error::Io)?;
+        if payload.len() as u64 != len {
+            return Err(Error::Io(std::io::ErrorKind::UnexpectedEof.into()));
+        }
+
+        if let Some(mask) = mask {
+            apply_mask(&mut payload, mask);
+        }
+
+        Ok(Some((fin, opcode, payload)))
+    }
+
+    fn write_frame(&mut self, opcode: u8, payload: &[u8]) -> Result<(), Error> {
+        let mut frame = Vec::with_capacity(14 + payload.len());
+        frame.push(0x80 | opcode);
+
+        if payload.len() < 126 {
+            frame.push(0x80 | payload.len() as u8);
+        }
+        else if payload.len() <= usize::from(u16::MAX) {
+            frame.push(0x80 | 126);
+            frame.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+        }
+        else {
+            frame.push(0x80 | 127);
+            frame.extend_from_slice(&(payload.len() as u64).to_be_bytes());
+        }
+
+        let mask = self.rng.next_mask();
+        frame.extend_from_slice(&mask);
+
+        let payload_start = frame.len();
+        frame.extend_from_slice(payload);
+        apply_mask(&mut frame[payload_start..], mask);
+
+        self.stream.write_all(&frame).map_err(Error::Io)?;
+        self.stream.flush().map_err(Error::Io)?;
+        Ok(())
+    }
+}
+
+/// A remote command, ie the connection of an `exec` or `attach` request.
+#[derive(Debug)]
+pub struct RemoteCommand<S> {
+    connection: Connection<S>,
+}
+
+/// The output of a [`RemoteCommand`](struct.RemoteCommand.html).
+#[derive(Debug, PartialEq)]
+pub enum Output {
+    /// Data that the command wrote to stdout.
+    Stdout(Vec<u8>),
+
+    /// Data that the command wrote to stderr.
+    Stderr(Vec<u8>),
+
+    /// The command has finished.
+    Exit(ExitStatus),
+}
+
+/// How a [`RemoteCommand`](struct.RemoteCommand.html) finished.
+#[derive(Debug, PartialEq)]
+pub enum ExitStatus {
+    /// The command exited with exit code 0.
+    Success,
+
+    /// The command exited with the given non-zero exit code.
+    ExitCode(i32),
+
+    /// The command couldn't be run, like if the container doesn't have the command. The `Status` has the API server's error message.
+    Failure(Box<::apimachinery::pkg::apis::meta::v1::Status>),
+}
+
+impl ExitStatus {
+    /// Converts the `Status` sent on the error channel into an exit status.
+    pub fn from_status(status: ::apimachinery::pkg::apis::meta::v1::Status) -> Self {
+        if status.status.as_deref() == Some("Success") {
+            return ExitStatus::Success;
+        }
+
+        if status.reason.as_deref() == Some("NonZeroExitCode") {
+            let exit_code =
+                status.details.as_ref()
+                .and_then(|details| details.causes.as_ref())
+                .and_then(|causes| causes.iter().find(|cause| cause.reason.as_deref() == Some("ExitCode")))
+                .and_then(|cause| cause.message.as_ref())
+                .and_then(|message| message.parse().ok());
+            if let Some(exit_code) = exit_code {
+                return ExitStatus::ExitCode(exit_code);
+            }
+        }
+
+        ExitStatus::Failure(Box::new(status))
+    }
+}
+
+impl<S> RemoteCommand<S> where S: Read + Write {
+    /// Constructs a remote command over the given byte stream, which has already been upgraded to a WebSocket connection.
+    pub fn new(stream: S) -> Self {
+        RemoteCommand { connection: Connection::new(stream) }
+    }
+
+    /// Returns the inner connection.
+    pub fn into_inner(self) -> Connection<S> {
+        self.connection
+    }
+
+    /// Writes the given data to the command's stdin. The request must have set the `stdin` parameter to `true`.
+    pub fn write_stdin(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.connection.write_message(STDIN, data)
+    }
+
+    /// Resizes the command's TTY. The request must have set the `tty` parameter to `true`.
+    pub fn resize(&mut self, width: u16, height: u16) -> Result<(), Error> {
+        let size = format!(r#"{{"Width":{},"Height":{}}}"#, width, height);
+        self.connection.write_message(RESIZE, size.as_bytes())
+    }
+
+    /// Reads the next output of the command. Returns `None` if the connection has been closed.
+    pub fn read_output(&mut self) -> Result<Option<Output>, Error> {
+        match self.connection.read_message()? {
+            Some((STDOUT, data)) => Ok(Some(Output::Stdout(data))),
+            Some((STDERR, data)) => Ok(Some(Output::Stderr(data))),
+            Some((ERROR, data)) => {
+                let status = serde_json::from_slice(&data).map_err(Error::Json)?;
+                Ok(Some(Output::Exit(ExitStatus::from_status(status))))
+            },
+            Some((channel, _)) => Err(Error::Protocol(format!("unexpected message on channel {}", channel))),
+            None => Ok(None),
+        }
+    }
+
+    /// Copies the command's stdout and stderr to the given writers until the command finishes, and returns how it finished.
+    pub fn wait<O, E>(&mut self, stdout: &mut O, stderr: &mut E) -> Result<ExitStatus, Error> where O: Write + ?Sized, E: Write + ?Sized {
+        loop {
+            match self.read_output()? {
+                Some(Output::Stdout(data)) => stdout.write_all(&data).map_err(Error::Io)?,
+                Some(Output::Stderr(data)) => stderr.write_all(&data).map_err(Error::Io)?,
+                Some(Output::Exit(exit_status)) => return Ok(exit_status),
+                None => return Err(Error::Protocol("connection closed before the command finished".to_string())),
+            }
+        }
+    }
+}
+
+/// The connection of a `portforward` request.
+///
+/// The `i`th port of the request has data channel `2 * i` and error channel `2 * i + 1`. The first message from the API server on each channel
+/// starts with the port number, which this type checks and strips.
+#[derive(Debug)]
+pub struct PortForward<S> {
+    connection: Connection<S>,
+    ports: Vec<u16>,
+    received_port: Vec<bool>,
+}
+
+/// The output of a [`PortForward`](struct.PortForward.html).
+#[derive(Debug, PartialEq)]
+pub enum PortForwardOutput {
+    /// Data from the given port.
+    Data(u16, Vec<u8>),
+
+    /// An error message about the given port, like if nothing is listening on it.
+    Error(u16, String),
+}
+
+impl<S> PortForward<S> where S: Read + Write {
+    /// Constructs a port forward over the given byte stream, which has already been upgraded to a WebSocket connection.
+    /// The ports must be in the same order as in the request.
+    pub fn new(stream: S, ports: &[u16]) -> Self {
+        PortForward {
+            connection: Connection::new(stream),
+            ports: ports.to_vec(),
+            received_port: vec![false; ports.len() * 2],
+        }
+    }
+
+    /// Returns the inner connection.
+    pub fn into_inner(self) -> Connection<S> {
+        self.connection
+    }
+
+    /// Writes the given data to the given port.
+    pub fn write(&mut self, port: u16, data: &[u8]) -> Result<(), Error> {
+        let index = self.ports.iter().position(|&p| p == port).ok_or_else(|| Error::Protocol(format!("port {} isn't forwarded", port)))?;
+        self.connection.write_message((index * 2) as u8, data)
+    }
+
+    /// Reads the next output of the forwarded ports. Returns `None` if the connection has been closed.
+    pub fn read(&mut self) -> Result<Option<PortForwardOutput>, Error> {
+        loop {
+            let (channel, mut data) = match self.connection.read_message()? {
+                Some(message) => message,
+                None => return Ok(None),
+            };
+
+            let channel = usize::from(channel);
+            let port = *self.ports.get(channel / 2).ok_or_else(|| Error::Protocol(format!("unexpected message on channel {}", channel)))?;
+
+            if !self.received_port[channel] {
+                if data.len() < 2 {
+                    return Err(Error::Protocol(format!("first message on channel {} doesn't have a port number", channel)));
+                }
+
+                let received_port = u16::from_le_bytes([data[0], data[1]]);
+                if received_port != port {
+                    return Err(Error::Protocol(format!("expected port {} on channel {} but got port {}", port, channel, received_port)));
+                }
+
+                self.received_port[channel] = true;
+                data.drain(..2);
+                if data.is_empty() {
+                    continue;
+                }
+            }
+
+            if channel % 2 == 0 {
+                return Ok(Some(PortForwardOutput::Data(port, data)));
+            }
+            else {
+                return Ok(Some(PortForwardOutput::Error(port, String::from_utf8_lossy(&data).into_owned())));
+            }
+        }
+    }
+}
+
+/// The type of errors from the channel protocol.
+#[derive(Debug)]
+pub enum Error {
+    /// An error from reading or writing the byte stream.
+    Io(std::io::Error),
+
+    /// An error from parsing or serializing the JSON of a message, like the `Status` on the error channel.
+    Json(serde_json::Error),
+
+    /// The API server sent data that doesn't follow the protocol.
+    Protocol(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Json(err) => write!(f, "{}", err),
+            Error::Protocol(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::Io(err) => err.description(),
+            Error::Json(err) => err.description(),
+            Error::Protocol(message) => message,
+        }
+    }
+
+    fn cause(&self) -> Option<&std::error::Error> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::Protocol(_) => None,
+        }
+    }
+}
+
+fn apply_mask(payload: &mut [u8], mask: [u8; 4]) {
+    for (i, b) in payload.iter_mut().enumerate() {
+        *b ^= mask[i % 4];
+    }
+}
+
+/// A generator of masking keys, which the WebSocket protocol requires to be unpredictable to intermediaries.
+///
+/// Each key is the SipHash of a counter, with the hash keys that `RandomState` gets from the OS's random number generator.
+/// So the keys that were sent on the wire don't reveal the ones that follow, unlike the output of a seeded PRNG.
+#[derive(Debug)]
+struct Rng {
+    hash_keys: std::collections::hash_map::RandomState,
+    counter: u64,
+}
+
+impl Rng {
+    fn new() -> Self {
+        Rng {
+            hash_keys: std::collections::hash_map::RandomState::new(),
+            counter: 0,
+        }
+    }
+
+    fn next_mask(&mut self) -> [u8; 4] {
+        let mut hasher = self.hash_keys.build_hasher();
+        hasher.write_u64(self.counter);
+        self.counter += 1;
+        let value = hasher.finish() as u32;
+        value.to_be_bytes()
+    }
+}
@@ -0,0 +1,138 @@
+//! Conversions between the versions of a kind in different API groups and versions.
+//!
+//! Kinds like `Deployment` exist in several group-versions, like `apps/v1`, `apps/v1beta2`, `apps/v1beta1` and `extensions/v1beta1`.
+//! Every pair of versions of the same kind has a generated conversion:
+//!
+//! - If the two types are structurally identical, the conversion is a field-by-field `From` impl that can't fail.
+//!
+//! - Otherwise the conversion is a `TryFrom` impl that round-trips the value through its JSON representation with [`convert`](fn.convert.html).
+//!   It fails if the target type doesn't have a field that is set in the source value, since that field would be silently dropped.
+//!   Use [`convert_lossy`](fn.convert_lossy.html) instead to accept the dropped fields.
+//!
+//! In both cases, the `apiVersion` field of the converted value is set to the target's group-version.
+//! `convert` and `convert_lossy` don't know the target's group-version, so they leave `apiVersion` as it was in the source value.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use] extern crate k8s_openapi;
+//!
+//! use k8s_openapi::api::apps::v1beta1 as apps;
+//! use k8s_openapi::api::extensions::v1beta1 as extensions;
+//!
+//! use std::convert::TryFrom;
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! let deployment = extensions::Deployment {
+//!     api_version: Some("extensions/v1beta1".to_string()),
+//!     kind: Some("Deployment".to_string()),
+//!     ..Default::default()
+//! };
+//!
+//! let deployment = apps::Deployment::try_from(deployment)?;
+//! assert_eq!(deployment.api_version, Some("apps/v1beta1".to_string()));
+//! # Ok(())
+//! # }
+//! ```
+
+/// Converts a value into another type by round-tripping it through its JSON representation.
+///
+/// Returns an error if the value has fields that the target type doesn't have.
+pub fn convert<T, U>(value: T) -> Result<U, Error> where T: serde::Serialize, U: serde::Serialize + serde::de::DeserializeOwned {
+    let (result, dropped_fields) = convert_lossy(value)?;
+    if dropped_fields.is_empty() {
+        Ok(result)
+    }
+    else {
+        Err(Error::DroppedFields(dropped_fields))
+    }
+}
+
+/// Converts a value into another type by round-tripping it through its JSON representation.
+///
+/// Also returns the paths of the fields of the value that the target type doesn't have, like `"spec.rollbackTo"`.
+pub fn convert_lossy<T, U>(value: T) -> Result<(U, Vec<String>), Error> where T: serde::Serialize, U: serde::Serialize + serde::de::DeserializeOwned {
+    let original = serde_json::to_value(value).map_err(Error::Json)?;
+    let result: U = serde_json::from_value(original.clone()).map_err(Error::Json)?;
+    let converted = serde_json::to_value(&result).map_err(Error::Json)?;
+
+    let mut dropped_fields = vec![];
+    find_dropped_fields(&original, &converted, &mut String::new(), &mut dropped_fields);
+
+    Ok((result, dropped_fields))
+}
+
+/// The type of errors from converting a value into another version of its kind.
+#[derive(Debug)]
+pub enum Error {
+    /// The value could not be round-tripped through its JSON representation.
+    Json(serde_json::Error),
+
+    /// The value has fields that the target type doesn't have. Contains the paths of those fields.
+    DroppedFields(Vec<String>),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Json(err) => write!(f, "{}", err),
+            Error::DroppedFields(fields) => write!(f, "conversion would drop fields {:?}", fields),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::Json(err) => err.description(),
+            Error::DroppedFields(_) => "conversion would drop fields",
+        }
+    }
+
+    fn cause(&self) -> Option<&std::error::Error> {
+        match self {
+            Error::Json(err) => Some(err),
+            Error::DroppedFields(_) => None,
+        }
+    }
+}
+
+/// Appends the paths of the fields that are set in `original` but missing from `converted` to `dropped_fields`.
+fn find_dropped_fields(original: &serde_json::Value, converted: &serde_json::Value, path: &mut String, dropped_fields: &mut Vec<String>) {
+    let path_len = path.len();
+
+    match (original, converted) {
+        (serde_json::Value::Object(original), serde_json::Value::Object(converted)) =>
+            for (key, original) in original {
+                if original.is_null() {
+                    continue;
+                }
+
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+
+                match converted.get(key) {
+                    Some(converted) => find_dropped_fields(original, converted, path, dropped_fields),
+                    None => dropped_fields.push(path.clone()),
+                }
+
+                path.truncate(path_len);
+            },
+
+        (serde_json::Value::Array(original), serde_json::Value::Array(converted)) =>
+            for (i, original) in original.iter().enumerate() {
+                path.push_str(&format!("[{}]", i));
+
+                match converted.get(i) {
+                    Some(converted) => find_dropped_fields(original, converted, path, dropped_fields),
+                    None => dropped_fields.push(path.clone()),
+                }
+
+                path.truncate(path_len);
+            },
+
+        _ => (),
+    }
+}
@@ -0,0 +1,385 @@
+//! Discovering the resources served by the API server, and mapping their kinds to the resources that serve them.
+//!
+//! A [`Discovery`](struct.Discovery.html) walks the server's discovery documents: the versions of the core group from `/api/`,
+//! the other groups and their versions from `/apis/`, and the resources of each group version from `/api/{version}/` or `/apis/{group}/{version}/`.
+//! Like a [`Pager`](../pager/struct.Pager.html), it returns the request for each document in turn and takes the parsed response of each one,
+//! so it can be used with any HTTP client, synchronous or asynchronous.
+//!
+//! Once all the documents have been received, [`Discovery::into_rest_mapper`](struct.Discovery.html#method.into_rest_mapper) returns
+//! a [`RestMapper`](struct.RestMapper.html), which maps a group and kind to the plural name, scope, verbs and preferred version of its resource.
+//!
+//! Aggregated API servers like `metrics.k8s.io` can be unavailable while the rest of the API server is healthy. If the resources of a group version
+//! can't be retrieved, discovery continues with the other group versions, and the failed group version is reported by
+//! [`RestMapper::failed_group_versions`](struct.RestMapper.html#method.failed_group_versions).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! extern crate k8s_openapi;
+//!
+//! use k8s_openapi::http;
+//! use k8s_openapi::discovery::{ Discovery, DiscoveryResponse };
+//!
+//! // `execute` is some function that executes the request and parses the response,
+//! // like the one in the crate root's example.
+//! fn execute<R>(request: http::Request<Vec<u8>>) -> (http::StatusCode, R) { unimplemented!(); }
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let mut discovery = Discovery::new();
+//!
+//!     while let Some(request) = discovery.next_request() {
+//!         let (status_code, response): (_, DiscoveryResponse) = execute(request?);
+//!         discovery.handle_response(status_code, response)?;
+//!     }
+//!
+//!     let rest_mapper = discovery.into_rest_mapper();
+//!
+//!     let deployments = rest_mapper.resource_for("apps", "Deployment").ok_or("deployments are not served")?;
+//!     println!("{} {} namespaced={}", deployments.api_version(), deployments.plural, deployments.namespaced);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// Discovers the resources served by the API server. See the [module docs](index.html) for details.
+#[derive(Debug)]
+pub struct Discovery {
+    pending: std::collections::VecDeque<Document>,
+    in_flight: Option<Document>,
+    groups: Vec<Group>,
+    mappings: Vec<RestMapping>,
+    failed_group_versions: Vec<(String, http::StatusCode)>,
+}
+
+/// A discovery document that has been requested, or is yet to be requested.
+#[derive(Debug)]
+enum Document {
+    CoreVersions,
+    Groups,
+    Resources { group: String, version: String },
+}
+
+/// A group and its versions, in the order the server listed them.
+#[derive(Clone, Debug)]
+struct Group {
+    name: String,
+    versions: Vec<String>,
+    preferred_version: Option<String>,
+}
+
+impl Discovery {
+    /// Constructs a discovery that starts with the versions of the core group and the list of the other groups.
+    pub fn new() -> Self {
+        Discovery {
+            pending: vec![Document::CoreVersions, Document::Groups].into(),
+            in_flight: None,
+            groups: vec![],
+            mappings: vec![],
+            failed_group_versions: vec![],
+        }
+    }
+
+    /// Returns the request for the next discovery document, or `None` if all documents have been received.
+    pub fn next_request(&mut self) -> Option<Result<http::Request<Vec<u8>>, ::RequestError>> {
+        let document = self.pending.pop_front()?;
+
+        let url = match &document {
+            Document::CoreVersions => "/api/".to_string(),
+            Document::Groups => "/apis/".to_string(),
+            Document::Resources { group, version } if group.is_empty() => format!("/api/{}/", version),
+            Document::Resources { group, version } => format!("/apis/{}/{}/", group, version),
+        };
+
+        self.in_flight = Some(document);
+
+        let mut request = http::Request::get(url);
+        let body = vec![];
+        Some(request.body(body).map_err(::RequestError::Http))
+    }
+
+    /// Handles the response to the request returned by the last call to [`next_request`](#method.next_request).
+    ///
+    /// A failure to get the resources of a group version is recorded in the `RestMapper` instead of being returned as an error,
+    /// since the other group versions are still usable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no request whose response hasn't been handled yet.
+    pub fn handle_response(&mut self, status_code: http::StatusCode, response: DiscoveryResponse) -> Result<(), Error> {
+        let document = self.in_flight.take().expect("handle_response called without a request from next_request");
+
+        match (document, response) {
+            (Document::CoreVersions, DiscoveryResponse::APIVersions(api_versions)) => {
+                for version in &api_versions.versions {
+                    self.pending.push_back(Document::Resources { group: "".to_string(), version: version.clone() });
+                }
+
+                // The core group doesn't report a preferred version. The server lists its versions in order of preference.
+                self.groups.push(Group {
+                    name: "".to_string(),
+                    preferred_version: api_versions.versions.first().cloned(),
+                    versions: api_versions.versions,
+                });
+            },
+
+            (Document::Groups, DiscoveryResponse::APIGroupList(api_group_list)) => {
+                for group in api_group_list.groups {
+                    let versions: Vec<_> = group.versions.into_iter().map(|version| version.version).collect();
+
+                    for version in &versions {
+                        self.pending.push_back(Document::Resources { group: group.name.clone(), version: version.clone() });
+                    }
+
+                    self.groups.push(Group {
+                        name: group.name,
+                        versions,
+                        preferred_version: group.preferred_version.map(|preferred_version| preferred_version.version),
+                    });
+                }
+            },
+
+            (Document::Resources { group, version }, DiscoveryResponse::APIResourceList(api_resource_list)) => {
+                for resource in api_resource_list.resources {
+                    // Subresources like `deployments/scale` are served under their parent resource, and have the kind of the subresource's payload.
+                    if resource.name.contains('/') {
+                        continue;
+                    }
+
+                    self.mappings.push(RestMapping {
+                        group: group.clone(),
+                        version: version.clone(),
+                        singular: if resource.singular_name.is_empty() { resource.kind.to_lowercase() } else { resource.singular_name },
+                        kind: resource.kind,
+                        plural: resource.name,
+                        short_names: resource.short_names.unwrap_or_default(),
+                        namespaced: resource.namespaced,
+                        verbs: resource.verbs,
+                    });
+                }
+            },
+
+            (Document::Resources { group, version }, _) => {
+                let group_version = if group.is_empty() { version } else { format!("{}/{}", group, version) };
+                self.failed_group_versions.push((group_version, status_code));
+            },
+
+            (Document::CoreVersions, _) | (Document::Groups, _) => {
+                self.pending.clear();
+                return Err(Error::UnexpectedResponse(status_code));
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Consumes the discovery and returns the `RestMapper` of the resources that have been discovered.
+    pub fn into_rest_mapper(self) -> RestMapper {
+        RestMapper {
+            groups: self.groups,
+            mappings: self.mappings,
+            failed_group_versions: self.failed_group_versions,
+        }
+    }
+}
+
+impl Default for Discovery {
+    fn default() -> Self {
+        Discovery::new()
+    }
+}
+
+/// Maps the kinds of the resources served by the API server to the resources that serve them. It is returned by
+/// [`Discovery::into_rest_mapper`](struct.Discovery.html#method.into_rest_mapper).
+///
+/// Groups are identified by their name, which is empty for the core group.
+#[derive(Clone, Debug)]
+pub struct RestMapper {
+    groups: Vec<Group>,
+    mappings: Vec<RestMapping>,
+    failed_group_versions: Vec<(String, http::StatusCode)>,
+}
+
+impl RestMapper {
+    /// Returns the resource of the given kind in the given group, from the group's preferred version if it's served there,
+    /// otherwise from the first version of the group that serves it.
+    pub fn resource_for(&self, group: &str, kind: &str) -> Option<&RestMapping> {
+        self.find(group, |mapping| mapping.kind == kind)
+    }
+
+    /// Returns the resource of the given kind in the given version of the given group.
+    pub fn resource_for_version(&self, group: &str, version: &str, kind: &str) -> Option<&RestMapping> {
+        self.mappings.iter().find(|mapping| mapping.group == group && mapping.version == version && mapping.kind == kind)
+    }
+
+    /// Returns the resource in the given group that has the given plural, singular or short name, like `deployments`, `deployment` or `deploy`.
+    /// Like [`resource_for`](#method.resource_for), the group's preferred version is used if it serves the resource.
+    pub fn kind_for(&self, group: &str, resource: &str) -> Option<&RestMapping> {
+        self.find(group, |mapping| {
+            mapping.plural.eq_ignore_ascii_case(resource) ||
+            mapping.singular.eq_ignore_ascii_case(resource) ||
+            mapping.short_names.iter().any(|short_name| short_name.eq_ignore_ascii_case(resource))
+        })
+    }
+
+    /// Returns the preferred version of the given group, if the server serves it.
+    pub fn preferred_version(&self, group: &str) -> Option<&str> {
+        self.groups.iter()
+            .find(|g| g.name == group)
+            .and_then(|g| g.preferred_version.as_ref())
+            .map(String::as_str)
+    }
+
+    /// Returns the versions of the given group, in the order the server listed them.
+    pub fn versions(&self, group: &str) -> Option<&[String]> {
+        self.groups.iter().find(|g| g.name == group).map(|g| &g.versions[..])
+    }
+
+    /// Returns all the resources, in the order of their groups and versions. A resource served by more than one version has one mapping for each.
+    pub fn mappings(&self) -> &[RestMapping] {
+        &self.mappings
+    }
+
+    /// Returns the group versions whose resources could not be retrieved, like `metrics.k8s.io/v1beta1`, and the status code of the server's response.
+    /// The resources of these group versions are missing from the mapper.
+    pub fn failed_group_versions(&self) -> &[(String, http::StatusCode)] {
+        &self.failed_group_versions
+    }
+
+    fn find<F>(&self, group: &str, mut f: F) -> Option<&RestMapping> where F: FnMut(&RestMapping) -> bool {
+        let preferred_version = self.preferred_version(group);
+
+        self.mappings.iter()
+            .find(|mapping| mapping.group == group && Some(&*mapping.version) == preferred_version && f(mapping))
+            .or_else(|| self.mappings.iter().find(|mapping| mapping.group == group && f(mapping)))
+    }
+}
+
+/// A resource served by the API server, as described by its group version's discovery document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RestMapping {
+    /// The API group of the resource, like `apps`. This is empty for the resources in the core API group, like `pods`.
+    pub group: String,
+
+    /// The version of the resource, like `v1`.
+    pub version: String,
+
+    /// The kind of the resource, like `Deployment`.
+    pub kind: String,
+
+    /// The plural name of the resource, like `deployments`.
+    pub plural: String,
+
+    /// The singular name of the resource, like `deployment`. Servers that don't report it get the lowercased kind.
+    pub singular: String,
+
+    /// The short names of the resource, like `deploy`.
+    pub short_names: Vec<String>,
+
+    /// Whether objects of the resource are in namespaces.
+    pub namespaced: bool,
+
+    /// The verbs supported by the resource, like `get` and `watch`.
+    pub verbs: Vec<String>,
+}
+
+impl RestMapping {
+    /// The `apiVersion` of objects of the resource, like `apps/v1`, or `v1` for the core group.
+    pub fn api_version(&self) -> String {
+        if self.group.is_empty() {
+            self.version.clone()
+        }
+        else {
+            format!("{}/{}", self.group, self.version)
+        }
+    }
+
+    /// Returns the [`ApiResource`](../dynamic/struct.ApiResource.html) that prepares the requests of the API operations of the resource.
+    pub fn api_resource(&self) -> ::dynamic::ApiResource {
+        ::dynamic::ApiResource {
+            group: self.group.clone(),
+            version: self.version.clone(),
+            plural: self.plural.clone(),
+        }
+    }
+
+    /// Whether the resource supports the given verb, like `watch`.
+    pub fn supports(&self, verb: &str) -> bool {
+        self.verbs.iter().any(|v| v == verb)
+    }
+}
+
+/// Parses the HTTP response of a request returned by [`Discovery::next_request`](struct.Discovery.html#method.next_request).
+#[derive(Debug)]
+pub enum DiscoveryResponse {
+    APIVersions(::apimachinery::pkg::apis::meta::v1::APIVersions),
+    APIGroupList(::apimachinery::pkg::apis::meta::v1::APIGroupList),
+    APIResourceList(::apimachinery::pkg::apis::meta::v1::APIResourceList),
+    Other,
+}
+
+impl ::Response for DiscoveryResponse {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => {
+                let DiscoveryDocument(result) = match serde_json::from_slice(buf) {
+                    Ok(value) => value,
+                    Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
+                    Err(err) => return Err(::ResponseError::Json(err)),
+                };
+                Ok((result, buf.len()))
+            },
+            _ => Ok((DiscoveryResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// A discovery document, deserialized as one of the `DiscoveryResponse` variants depending on its `kind`.
+struct DiscoveryDocument(DiscoveryResponse);
+
+impl<'de> ::tagged::Tagged<'de> for DiscoveryDocument {
+    const TAGS: &'static [&'static str] = &["kind"];
+
+    fn deserialize_tagged<D>(tags: &[Option<String>], deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        match tags[0].as_ref().map(String::as_str) {
+            Some("APIVersions") => Ok(DiscoveryDocument(DiscoveryResponse::APIVersions(serde::Deserialize::deserialize(deserializer)?))),
+            Some("APIGroupList") => Ok(DiscoveryDocument(DiscoveryResponse::APIGroupList(serde::Deserialize::deserialize(deserializer)?))),
+            Some("APIResourceList") => Ok(DiscoveryDocument(DiscoveryResponse::APIResourceList(serde::Deserialize::deserialize(deserializer)?))),
+            Some(kind) => Err(serde::de::Error::custom(format!("unexpected kind {:?}", kind))),
+            None => Err(serde::de::Error::missing_field("kind")),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DiscoveryDocument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        ::tagged::deserialize(deserializer)
+    }
+}
+
+/// The type of errors from discovering the resources served by the API server.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The server responded to the request for the versions of the core group or the list of the other groups with an unsuccessful status code,
+    /// or with a document of the wrong kind.
+    UnexpectedResponse(http::StatusCode),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::UnexpectedResponse(status_code) => write!(f, "unexpected response {}", status_code),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::UnexpectedResponse(_) => "unexpected response",
+        }
+    }
+}
@@ -0,0 +1,808 @@
+//! Objects of resource kinds that are not known at compile time, like custom resources, and the API operations for them.
+//!
+//! A [`DynamicObject`](struct.DynamicObject.html) has the `apiVersion`, `kind` and typed `metadata` of the object,
+//! and holds the rest of its fields, like `spec` and `status`, as JSON values.
+//!
+//! The types of the metadata, and of the `Status` and `WatchEvent` in the responses, are type parameters that default to the ones at the crate root,
+//! ie of the default version. Libraries should set them to the types of the version module they use, like
+//! `DynamicObject<k8s_openapi::v1_9::apimachinery::pkg::apis::meta::v1::ObjectMeta>`, since the default version can be changed by other crates
+//! in the dependency graph.
+//!
+//! The API operations are methods of an [`ApiResource`](struct.ApiResource.html), which identifies the resource by its group, version and plural name at runtime.
+//! Like the generated API operations, they return an `http::Request`, and have a response type that implements [`Response`](../trait.Response.html).
+//! They are enabled by the `api` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use] extern crate k8s_openapi;
+//!
+//! use k8s_openapi::dynamic::{ ApiResource, DynamicObject };
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! let foos = ApiResource {
+//!     group: "example.com".to_string(),
+//!     version: "v1".to_string(),
+//!     plural: "foos".to_string(),
+//! };
+//!
+//! let foo: DynamicObject = k8s_openapi::serde_json::from_str(r#"{
+//!     "apiVersion": "example.com/v1",
+//!     "kind": "Foo",
+//!     "metadata": { "name": "foo1" },
+//!     "spec": { "replicas": 3 }
+//! }"#)?;
+//! assert_eq!(foo.data["spec"]["replicas"], 3);
+//!
+//! let request = foos.create(Some("default"), &foo)?;
+//! assert_eq!(request.uri(), "/apis/example.com/v1/namespaces/default/foos?");
+//! # Ok(())
+//! # }
+//! ```
+
+/// An object of a resource kind that is not known at compile time.
+///
+/// `M` is the type of its metadata, an `ObjectMeta`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamicObject<M = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    /// The `apiVersion` of the object, like `example.com/v1`.
+    pub api_version: Option<String>,
+
+    /// The `kind` of the object, like `Foo`.
+    pub kind: Option<String>,
+
+    /// The metadata of the object.
+    pub metadata: Option<M>,
+
+    /// The rest of the fields of the object, like `spec` and `status`.
+    pub data: serde_json::Map<String, serde_json::Value>,
+}
+
+impl<'de, M> serde::Deserialize<'de> for DynamicObject<M> where M: serde::de::DeserializeOwned {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let mut data: serde_json::Map<String, serde_json::Value> = serde::Deserialize::deserialize(deserializer)?;
+
+        let api_version = data.remove("apiVersion").map(serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+        let kind = data.remove("kind").map(serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+        let metadata = data.remove("metadata").map(serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+
+        Ok(DynamicObject {
+            api_version: api_version.map_err(serde::de::Error::custom)?,
+            kind: kind.map_err(serde::de::Error::custom)?,
+            metadata: metadata.map_err(serde::de::Error::custom)?,
+            data,
+        })
+    }
+}
+
+impl<M> serde::Serialize for DynamicObject<M> where M: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(api_version) = &self.api_version {
+            map.serialize_entry("apiVersion", api_version)?;
+        }
+        if let Some(kind) = &self.kind {
+            map.serialize_entry("kind", kind)?;
+        }
+        if let Some(metadata) = &self.metadata {
+            map.serialize_entry("metadata", metadata)?;
+        }
+        for (key, value) in &self.data {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// A list of objects of a resource kind that is not known at compile time.
+///
+/// `M` is the type of the metadata of the list, a `ListMeta`, and `O` is the type of the metadata of its objects, an `ObjectMeta`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamicObjectList<M = ::apimachinery::pkg::apis::meta::v1::ListMeta, O = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    /// The `apiVersion` of the list, like `example.com/v1`.
+    pub api_version: Option<String>,
+
+    /// The `kind` of the list, like `FooList`.
+    pub kind: Option<String>,
+
+    /// The metadata of the list.
+    pub metadata: Option<M>,
+
+    /// The objects in the list.
+    pub items: Vec<DynamicObject<O>>,
+}
+
+impl<'de, M, O> serde::Deserialize<'de> for DynamicObjectList<M, O> where M: serde::de::DeserializeOwned, O: serde::de::DeserializeOwned {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let mut data: serde_json::Map<String, serde_json::Value> = serde::Deserialize::deserialize(deserializer)?;
+
+        let api_version = data.remove("apiVersion").map(serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+        let kind = data.remove("kind").map(serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+        let metadata = data.remove("metadata").map(serde_json::from_value).map_or(Ok(None), |value| value.map(Some));
+        let items = data.remove("items").ok_or_else(|| serde::de::Error::missing_field("items"))?;
+
+        Ok(DynamicObjectList {
+            api_version: api_version.map_err(serde::de::Error::custom)?,
+            kind: kind.map_err(serde::de::Error::custom)?,
+            metadata: metadata.map_err(serde::de::Error::custom)?,
+            items: serde_json::from_value(items).map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+impl<M, O> serde::Serialize for DynamicObjectList<M, O> where M: serde::Serialize, O: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(api_version) = &self.api_version {
+            map.serialize_entry("apiVersion", api_version)?;
+        }
+        if let Some(kind) = &self.kind {
+            map.serialize_entry("kind", kind)?;
+        }
+        if let Some(metadata) = &self.metadata {
+            map.serialize_entry("metadata", metadata)?;
+        }
+        map.serialize_entry("items", &self.items)?;
+        map.end()
+    }
+}
+
+impl<M, O> ::List for DynamicObjectList<M, O> where M: ListMetadata {
+    type Item = DynamicObject<O>;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(ListMetadata::continue_token)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
+
+/// A trait implemented by the `ListMeta` type of every enabled version, so that a [`DynamicObjectList`](struct.DynamicObjectList.html)
+/// of any of them can be paged through.
+pub trait ListMetadata {
+    /// The value of the `continue` field, if the server has more items to return.
+    fn continue_token(&self) -> Option<&str>;
+}
+
+// v1.7 doesn't support chunked lists, so its `ListMeta` doesn't have a `continue` field
+#[cfg(k8s_openapi_v1_7)]
+impl ListMetadata for ::v1_7::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+}
+
+#[cfg(k8s_openapi_v1_8)]
+impl ListMetadata for ::v1_8::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn continue_token(&self) -> Option<&str> {
+        self.continue_.as_ref().map(String::as_str)
+    }
+}
+
+#[cfg(k8s_openapi_v1_9)]
+impl ListMetadata for ::v1_9::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn continue_token(&self) -> Option<&str> {
+        self.continue_.as_ref().map(String::as_str)
+    }
+}
+
+#[cfg(k8s_openapi_v1_10)]
+impl ListMetadata for ::v1_10::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn continue_token(&self) -> Option<&str> {
+        self.continue_.as_ref().map(String::as_str)
+    }
+}
+
+#[cfg(k8s_openapi_v1_11)]
+impl ListMetadata for ::v1_11::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn continue_token(&self) -> Option<&str> {
+        self.continue_.as_ref().map(String::as_str)
+    }
+}
+
+#[cfg(k8s_openapi_v1_12)]
+impl ListMetadata for ::v1_12::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn continue_token(&self) -> Option<&str> {
+        self.continue_.as_ref().map(String::as_str)
+    }
+}
+
+/// Identifies a resource kind at runtime by its API group, version and plural name, which are the parts of the URLs of its API operations.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ApiResource {
+    /// The API group of the resource, like `example.com`. This is empty for the resources in the core API group, like `pods`.
+    pub group: String,
+
+    /// The version of the resource, like `v1`.
+    pub version: String,
+
+    /// The plural name of the resource, like `foos`.
+    pub plural: String,
+}
+
+/// The body of a PATCH request. Each variant corresponds to one of the patch formats supported by the API server.
+#[cfg(feature = "api")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Patch {
+    /// A JSON patch, ie a list of operations like `{ "op": "replace", "path": "/spec/replicas", "value": 3 }`.
+    Json(serde_json::Value),
+
+    /// A JSON merge patch, ie a partial object whose fields replace those of the object.
+    Merge(serde_json::Value),
+
+    /// A strategic merge patch. This is only supported for built-in resource kinds, not custom resources.
+    StrategicMerge(serde_json::Value),
+}
+
+#[cfg(feature = "api")]
+impl ApiResource {
+    /// create an object of this resource
+    ///
+    /// Use [`CreateResponse`](./enum.CreateResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the object, or `None` for resources that are not namespaced.
+    ///
+    /// * `body`
+    pub fn create<M>(
+        &self,
+        namespace: Option<&str>,
+        body: &DynamicObject<M>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> where M: serde::Serialize {
+        let url = self.query_pairs(namespace, None).finish();
+
+        let mut request = http::Request::post(url);
+        let body = serde_json::to_vec(body).map_err(::RequestError::Json)?;
+        request.body(body).map_err(::RequestError::Http)
+    }
+
+    /// delete an object of this resource
+    ///
+    /// Use [`DeleteResponse`](./enum.DeleteResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the object, or `None` for resources that are not namespaced.
+    ///
+    /// * `grace_period_seconds`
+    ///
+    ///     The duration in seconds before the object should be deleted.
+    ///
+    /// * `propagation_policy`
+    ///
+    ///     Whether and how garbage collection will be performed, one of `Orphan`, `Background` or `Foreground`.
+    pub fn delete(
+        &self,
+        name: &str,
+        namespace: Option<&str>,
+        grace_period_seconds: Option<i64>,
+        propagation_policy: Option<&str>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        let mut query_pairs = self.query_pairs(namespace, Some(name));
+        if let Some(grace_period_seconds) = grace_period_seconds {
+            query_pairs.append_pair("gracePeriodSeconds", &grace_period_seconds.to_string());
+        }
+        if let Some(propagation_policy) = propagation_policy {
+            query_pairs.append_pair("propagationPolicy", propagation_policy);
+        }
+        let url = query_pairs.finish();
+
+        let mut request = http::Request::delete(url);
+        request.body(vec![]).map_err(::RequestError::Http)
+    }
+
+    /// delete a collection of objects of this resource
+    ///
+    /// Use [`DeleteCollectionResponse`](./enum.DeleteCollectionResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the objects, or `None` for resources that are not namespaced or to delete the objects in all namespaces.
+    ///
+    /// * `field_selector`
+    ///
+    ///     A selector to restrict the deleted objects by their fields.
+    ///
+    /// * `label_selector`
+    ///
+    ///     A selector to restrict the deleted objects by their labels.
+    pub fn delete_collection(
+        &self,
+        namespace: Option<&str>,
+        field_selector: Option<&str>,
+        label_selector: Option<&str>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        let mut query_pairs = self.query_pairs(namespace, None);
+        if let Some(field_selector) = field_selector {
+            query_pairs.append_pair("fieldSelector", field_selector);
+        }
+        if let Some(label_selector) = label_selector {
+            query_pairs.append_pair("labelSelector", label_selector);
+        }
+        let url = query_pairs.finish();
+
+        let mut request = http::Request::delete(url);
+        request.body(vec![]).map_err(::RequestError::Http)
+    }
+
+    /// list objects of this resource
+    ///
+    /// Use [`ListResponse`](./enum.ListResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the objects, or `None` for resources that are not namespaced or to list the objects in all namespaces.
+    ///
+    /// * `continue_`
+    ///
+    ///     The continue token from the metadata of the previous list, to get the next chunk of results.
+    ///
+    /// * `field_selector`
+    ///
+    ///     A selector to restrict the listed objects by their fields.
+    ///
+    /// * `label_selector`
+    ///
+    ///     A selector to restrict the listed objects by their labels.
+    ///
+    /// * `limit`
+    ///
+    ///     The maximum number of objects to return. The server sets the continue token in the metadata of the list if there are more.
+    ///
+    /// * `resource_version`
+    ///
+    ///     The resource version that the list must be at least as fresh as.
+    pub fn list(
+        &self,
+        namespace: Option<&str>,
+        continue_: Option<&str>,
+        field_selector: Option<&str>,
+        label_selector: Option<&str>,
+        limit: Option<i64>,
+        resource_version: Option<&str>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        let mut query_pairs = self.query_pairs(namespace, None);
+        if let Some(continue_) = continue_ {
+            query_pairs.append_pair("continue", continue_);
+        }
+        if let Some(field_selector) = field_selector {
+            query_pairs.append_pair("fieldSelector", field_selector);
+        }
+        if let Some(label_selector) = label_selector {
+            query_pairs.append_pair("labelSelector", label_selector);
+        }
+        if let Some(limit) = limit {
+            query_pairs.append_pair("limit", &limit.to_string());
+        }
+        if let Some(resource_version) = resource_version {
+            query_pairs.append_pair("resourceVersion", resource_version);
+        }
+        let url = query_pairs.finish();
+
+        let mut request = http::Request::get(url);
+        request.body(vec![]).map_err(::RequestError::Http)
+    }
+
+    /// partially update an object of this resource
+    ///
+    /// Use [`PatchResponse`](./enum.PatchResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the object, or `None` for resources that are not namespaced.
+    ///
+    /// * `body`
+    pub fn patch(
+        &self,
+        name: &str,
+        namespace: Option<&str>,
+        body: &Patch,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        let url = self.query_pairs(namespace, Some(name)).finish();
+
+        let (content_type, body) = match body {
+            Patch::Json(body) => ("application/json-patch+json", body),
+            Patch::Merge(body) => ("application/merge-patch+json", body),
+            Patch::StrategicMerge(body) => ("application/strategic-merge-patch+json", body),
+        };
+
+        let mut request = http::Request::patch(url);
+        request.header(http::header::CONTENT_TYPE, content_type);
+        let body = serde_json::to_vec(body).map_err(::RequestError::Json)?;
+        request.body(body).map_err(::RequestError::Http)
+    }
+
+    /// read an object of this resource
+    ///
+    /// Use [`ReadResponse`](./enum.ReadResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the object, or `None` for resources that are not namespaced.
+    pub fn read(
+        &self,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        let url = self.query_pairs(namespace, Some(name)).finish();
+
+        let mut request = http::Request::get(url);
+        request.body(vec![]).map_err(::RequestError::Http)
+    }
+
+    /// replace an object of this resource
+    ///
+    /// Use [`ReplaceResponse`](./enum.ReplaceResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the object, or `None` for resources that are not namespaced.
+    ///
+    /// * `body`
+    pub fn replace<M>(
+        &self,
+        name: &str,
+        namespace: Option<&str>,
+        body: &DynamicObject<M>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> where M: serde::Serialize {
+        let url = self.query_pairs(namespace, Some(name)).finish();
+
+        let mut request = http::Request::put(url);
+        let body = serde_json::to_vec(body).map_err(::RequestError::Json)?;
+        request.body(body).map_err(::RequestError::Http)
+    }
+
+    /// watch changes to objects of this resource
+    ///
+    /// Use [`WatchResponse`](./enum.WatchResponse.html) to parse the HTTP response.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace`
+    ///
+    ///     The namespace of the objects, or `None` for resources that are not namespaced or to watch the objects in all namespaces.
+    ///
+    /// * `field_selector`
+    ///
+    ///     A selector to restrict the watched objects by their fields.
+    ///
+    /// * `label_selector`
+    ///
+    ///     A selector to restrict the watched objects by their labels.
+    ///
+    /// * `resource_version`
+    ///
+    ///     The resource version to watch changes from.
+    ///
+    /// * `timeout_seconds`
+    ///
+    ///     Timeout for the watch call.
+    pub fn watch(
+        &self,
+        namespace: Option<&str>,
+        field_selector: Option<&str>,
+        label_selector: Option<&str>,
+        resource_version: Option<&str>,
+        timeout_seconds: Option<i64>,
+    ) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        let mut query_pairs = self.query_pairs(namespace, None);
+        if let Some(field_selector) = field_selector {
+            query_pairs.append_pair("fieldSelector", field_selector);
+        }
+        if let Some(label_selector) = label_selector {
+            query_pairs.append_pair("labelSelector", label_selector);
+        }
+        if let Some(resource_version) = resource_version {
+            query_pairs.append_pair("resourceVersion", resource_version);
+        }
+        if let Some(timeout_seconds) = timeout_seconds {
+            query_pairs.append_pair("timeoutSeconds", &timeout_seconds.to_string());
+        }
+        query_pairs.append_pair("watch", "true");
+        let url = query_pairs.finish();
+
+        let mut request = http::Request::get(url);
+        request.body(vec![]).map_err(::RequestError::Http)
+    }
+
+    /// Returns a serializer for the query string of the URL of the collection of this resource in the given namespace, or of the named object in it.
+    fn query_pairs(&self, namespace: Option<&str>, name: Option<&str>) -> url::form_urlencoded::Serializer<String> {
+        let mut url =
+            if self.group.is_empty() {
+                format!("/api/{}", self.version)
+            }
+            else {
+                format!("/apis/{}/{}", self.group, self.version)
+            };
+
+        if let Some(namespace) = namespace {
+            url.push_str("/namespaces/");
+            url.push_str(namespace);
+        }
+
+        url.push('/');
+        url.push_str(&self.plural);
+
+        if let Some(name) = name {
+            url.push('/');
+            url.push_str(name);
+        }
+
+        url.push('?');
+
+        let start_position = url.len();
+        url::form_urlencoded::Serializer::for_suffix(url, start_position)
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::create`](./struct.ApiResource.html#method.create)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum CreateResponse<M = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    Ok(DynamicObject<M>),
+    Created(DynamicObject<M>),
+    Accepted(DynamicObject<M>),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M> ::Response for CreateResponse<M> where M: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => Ok((CreateResponse::Ok(parse_json(buf)?), buf.len())),
+            http::StatusCode::CREATED => Ok((CreateResponse::Created(parse_json(buf)?), buf.len())),
+            http::StatusCode::ACCEPTED => Ok((CreateResponse::Accepted(parse_json(buf)?), buf.len())),
+            http::StatusCode::UNAUTHORIZED => Ok((CreateResponse::Unauthorized, 0)),
+            _ => Ok((CreateResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::delete`](./struct.ApiResource.html#method.delete)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum DeleteResponse<M = ::apimachinery::pkg::apis::meta::v1::ObjectMeta, S = ::apimachinery::pkg::apis::meta::v1::Status> {
+    OkStatus(S),
+    OkValue(DynamicObject<M>),
+    Accepted(S),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M, S> ::Response for DeleteResponse<M, S> where M: serde::de::DeserializeOwned, S: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => {
+                match parse_json(buf)? {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteResponse::OkValue(result), buf.len())),
+                }
+            },
+            http::StatusCode::ACCEPTED => Ok((DeleteResponse::Accepted(parse_json(buf)?), buf.len())),
+            http::StatusCode::UNAUTHORIZED => Ok((DeleteResponse::Unauthorized, 0)),
+            _ => Ok((DeleteResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::delete_collection`](./struct.ApiResource.html#method.delete_collection)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum DeleteCollectionResponse<M = ::apimachinery::pkg::apis::meta::v1::ListMeta, O = ::apimachinery::pkg::apis::meta::v1::ObjectMeta, S = ::apimachinery::pkg::apis::meta::v1::Status> {
+    OkStatus(S),
+    OkValue(DynamicObjectList<M, O>),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M, O, S> ::Response for DeleteCollectionResponse<M, O, S> where M: serde::de::DeserializeOwned, O: serde::de::DeserializeOwned, S: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => {
+                match parse_json(buf)? {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCollectionResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCollectionResponse::OkValue(result), buf.len())),
+                }
+            },
+            http::StatusCode::UNAUTHORIZED => Ok((DeleteCollectionResponse::Unauthorized, 0)),
+            _ => Ok((DeleteCollectionResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::list`](./struct.ApiResource.html#method.list)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum ListResponse<M = ::apimachinery::pkg::apis::meta::v1::ListMeta, O = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    Ok(DynamicObjectList<M, O>),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M, O> ::Response for ListResponse<M, O> where M: serde::de::DeserializeOwned, O: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => Ok((ListResponse::Ok(parse_json(buf)?), buf.len())),
+            http::StatusCode::UNAUTHORIZED => Ok((ListResponse::Unauthorized, 0)),
+            _ => Ok((ListResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl<M, O> ::ListResponse for ListResponse<M, O> where M: ListMetadata + serde::de::DeserializeOwned, O: serde::de::DeserializeOwned {
+    type List = DynamicObjectList<M, O>;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::patch`](./struct.ApiResource.html#method.patch)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum PatchResponse<M = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    Ok(DynamicObject<M>),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M> ::Response for PatchResponse<M> where M: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => Ok((PatchResponse::Ok(parse_json(buf)?), buf.len())),
+            http::StatusCode::UNAUTHORIZED => Ok((PatchResponse::Unauthorized, 0)),
+            _ => Ok((PatchResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::read`](./struct.ApiResource.html#method.read)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum ReadResponse<M = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    Ok(DynamicObject<M>),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M> ::Response for ReadResponse<M> where M: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => Ok((ReadResponse::Ok(parse_json(buf)?), buf.len())),
+            http::StatusCode::UNAUTHORIZED => Ok((ReadResponse::Unauthorized, 0)),
+            _ => Ok((ReadResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::replace`](./struct.ApiResource.html#method.replace)
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum ReplaceResponse<M = ::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+    Ok(DynamicObject<M>),
+    Created(DynamicObject<M>),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<M> ::Response for ReplaceResponse<M> where M: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => Ok((ReplaceResponse::Ok(parse_json(buf)?), buf.len())),
+            http::StatusCode::CREATED => Ok((ReplaceResponse::Created(parse_json(buf)?), buf.len())),
+            http::StatusCode::UNAUTHORIZED => Ok((ReplaceResponse::Unauthorized, 0)),
+            _ => Ok((ReplaceResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+/// Parses the HTTP response of [`ApiResource::watch`](./struct.ApiResource.html#method.watch)
+///
+/// The object of each event can be deserialized as a [`DynamicObject`](./struct.DynamicObject.html).
+#[cfg(feature = "api")]
+#[derive(Debug)]
+pub enum WatchResponse<E = ::apimachinery::pkg::apis::meta::v1::WatchEvent> {
+    Ok(E),
+    Unauthorized,
+    Other,
+}
+
+#[cfg(feature = "api")]
+impl<E> ::Response for WatchResponse<E> where E: serde::de::DeserializeOwned {
+    fn try_from_parts(status_code: http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
+        match status_code {
+            http::StatusCode::OK => {
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
+                    None => return Err(::ResponseError::NeedMoreData),
+                };
+                let result = serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchResponse::Ok(result), line_len))
+            },
+            http::StatusCode::UNAUTHORIZED => Ok((WatchResponse::Unauthorized, 0)),
+            _ => Ok((WatchResponse::Other, 0)),
+        }
+    }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
+}
+
+/// Parses the whole response body as a JSON value of the given type.
+#[cfg(feature = "api")]
+fn parse_json<T>(buf: &[u8]) -> Result<T, ::ResponseError> where T: serde::de::DeserializeOwned {
+    match serde_json::from_slice(buf) {
+        Ok(value) => Ok(value),
+        Err(ref err) if err.is_eof() => Err(::ResponseError::NeedMoreData),
+        Err(err) => Err(::ResponseError::Json(err)),
+    }
+}
@@ -0,0 +1,268 @@
+//! Building and parsing field selectors.
+//!
+//! The `field_selector` parameter of list and watch operations takes a field selector string like `"spec.nodeName=node1,status.phase!=Running"`.
+//! The API server only supports selecting on a few fields of each kind, so each kind that has list operations also has a generated enum
+//! of the fields that it supports, like `PodField` for `Pod`. [`FieldSelector`](struct.FieldSelector.html) is generic over this enum,
+//! so that a typo in a field name is a compile-time error instead of an HTTP 400 response from the API server.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use] extern crate k8s_openapi;
+//!
+//! use k8s_openapi::api::core::v1 as api;
+//!
+//! use k8s_openapi::field_selector::FieldSelector;
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! let field_selector =
+//!     FieldSelector::new()
+//!     .equals(api::PodField::SpecNodeName, "node1")
+//!     .not_equals(api::PodField::StatusPhase, "Running");
+//! assert_eq!(field_selector.to_string(), "spec.nodeName=node1,status.phase!=Running");
+//!
+//! // Parsing validates that the fields can be selected for the kind
+//! let field_selector: FieldSelector<api::PodField> = "spec.nodeName=node1".parse()?;
+//! assert!("spec.nodename=node1".parse::<FieldSelector<api::PodField>>().is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+/// A trait implemented by the generated enums of the fields of a kind that can be used in a field selector.
+pub trait Field: Sized {
+    /// Returns the path of this field, like `"spec.nodeName"`.
+    fn path(&self) -> &'static str;
+
+    /// Returns the field with the given path, or `None` if the path is not a field that can be used in a field selector.
+    fn from_path(path: &str) -> Option<Self>;
+}
+
+/// A field selector. An object matches the selector if it matches all of the selector's requirements.
+///
+/// An empty selector matches all objects.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldSelector<F>(pub Vec<Requirement<F>>);
+
+impl<F> FieldSelector<F> where F: Field {
+    /// Constructs an empty field selector.
+    pub fn new() -> Self {
+        FieldSelector(vec![])
+    }
+
+    /// Adds a requirement that the given field have the given value.
+    pub fn equals<V>(mut self, field: F, value: V) -> Self where V: Into<String> {
+        self.0.push(Requirement { field, operator: Operator::Equals(value.into()) });
+        self
+    }
+
+    /// Adds a requirement that the given field not have the given value.
+    pub fn not_equals<V>(mut self, field: F, value: V) -> Self where V: Into<String> {
+        self.0.push(Requirement { field, operator: Operator::NotEquals(value.into()) });
+        self
+    }
+}
+
+impl<F> Default for FieldSelector<F> where F: Field {
+    fn default() -> Self {
+        FieldSelector::new()
+    }
+}
+
+impl<F> std::fmt::Display for FieldSelector<F> where F: Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, requirement) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+
+            write!(f, "{}", requirement)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F> std::str::FromStr for FieldSelector<F> where F: Field {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let requirements =
+            parse_untyped(s)?.into_iter()
+            .map(|(path, operator)| {
+                let field = F::from_path(path).ok_or_else(|| Error::UnknownField(path.to_string()))?;
+                Ok(Requirement { field, operator })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(FieldSelector(requirements))
+    }
+}
+
+/// Parses a field selector without checking that its fields can be selected for any particular kind,
+/// and returns the path of each requirement's field along with its operator.
+///
+/// This is for code that handles objects of any kind, like a fake API server. Otherwise parse a [`FieldSelector`](struct.FieldSelector.html).
+pub fn parse_untyped(s: &str) -> Result<Vec<(&str, Operator)>, Error> {
+    let mut requirements = vec![];
+
+    if s.trim().is_empty() {
+        return Ok(requirements);
+    }
+
+    for requirement in split_unescaped(s, ',') {
+        let (path, operator, value) = split_requirement(requirement).ok_or_else(|| Error::MissingOperator(requirement.to_string()))?;
+
+        let value = unescape(value)?;
+
+        let operator = match operator {
+            "!=" => Operator::NotEquals(value),
+            _ => Operator::Equals(value),
+        };
+
+        requirements.push((path.trim(), operator));
+    }
+
+    Ok(requirements)
+}
+
+/// A single requirement of a [`FieldSelector`](struct.FieldSelector.html), ie a field and the operator that the field's value must satisfy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Requirement<F> {
+    /// The field that this requirement applies to.
+    pub field: F,
+
+    /// The operator that the field's value must satisfy.
+    pub operator: Operator,
+}
+
+impl<F> std::fmt::Display for Requirement<F> where F: Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (operator, value) = match &self.operator {
+            Operator::Equals(value) => ("=", value),
+            Operator::NotEquals(value) => ("!=", value),
+        };
+
+        write!(f, "{}{}", self.field.path(), operator)?;
+
+        for c in value.chars() {
+            if c == '\\' || c == ',' || c == '=' {
+                write!(f, "\\")?;
+            }
+
+            write!(f, "{}", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The operator of a [`Requirement`](struct.Requirement.html).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operator {
+    /// `field=value` or `field==value`. The field must have the given value.
+    Equals(String),
+
+    /// `field!=value`. The field must not have the given value.
+    NotEquals(String),
+}
+
+/// The type of errors from parsing a field selector.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A requirement of the selector does not have an `=`, `==` or `!=` operator.
+    MissingOperator(String),
+
+    /// A requirement of the selector uses a field that can't be used in a field selector for this kind.
+    UnknownField(String),
+
+    /// A value of the selector contains an invalid escape sequence.
+    InvalidEscapeSequence(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::MissingOperator(requirement) => write!(f, "field selector requirement {:?} does not have an operator", requirement),
+            Error::UnknownField(field) => write!(f, "field {:?} can't be used in a field selector for this kind", field),
+            Error::InvalidEscapeSequence(value) => write!(f, "field selector value {:?} has an invalid escape sequence", value),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::MissingOperator(_) => "field selector requirement does not have an operator",
+            Error::UnknownField(_) => "field can't be used in a field selector for this kind",
+            Error::InvalidEscapeSequence(_) => "field selector value has an invalid escape sequence",
+        }
+    }
+}
+
+/// Splits `s` at every `separator` that isn't escaped with a backslash.
+fn split_unescaped(s: &str, separator: char) -> Vec<&str> {
+    let mut result = vec![];
+
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+        }
+        else if c == '\\' {
+            escaped = true;
+        }
+        else if c == separator {
+            result.push(&s[start..i]);
+            start = i + c.len_utf8();
+        }
+    }
+
+    result.push(&s[start..]);
+
+    result
+}
+
+/// Splits a requirement at the first operator that isn't escaped with a backslash.
+fn split_requirement(requirement: &str) -> Option<(&str, &'static str, &str)> {
+    let mut escaped = false;
+
+    for (i, c) in requirement.char_indices() {
+        if escaped {
+            escaped = false;
+        }
+        else if c == '\\' {
+            escaped = true;
+        }
+        else {
+            for operator in &["!=", "==", "="] {
+                if requirement[i..].starts_with(operator) {
+                    return Some((&requirement[..i], operator, &requirement[(i + operator.len())..]));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn unescape(value: &str) -> Result<String, Error> {
+    let mut result = String::with_capacity(value.len());
+
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some(c @ '\\') | Some(c @ ',') | Some(c @ '=') => result.push(c),
+                _ => return Err(Error::InvalidEscapeSequence(value.to_string())),
+            }
+        }
+        else {
+            result.push(c);
+        }
+    }
+
+    Ok(result)
+}
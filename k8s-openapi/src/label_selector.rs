@@ -0,0 +1,455 @@
+//! Parsing, rendering and matching of label selectors.
+//!
+//! The `label_selector` parameter of list and watch operations takes a label selector string like `"app=foo,tier in (frontend, backend),!canary"`.
+//! [`Selector`](struct.Selector.html) can be parsed from and rendered to such a string, and converted to and from the `LabelSelector` type
+//! of every enabled version via `LabelSelector::to_selector` and `From<Selector> for LabelSelector`.
+//!
+//! A `Selector` can also be evaluated against the labels of an object's metadata, with the same semantics as the API server.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate k8s_openapi;
+//! use k8s_openapi::label_selector::Selector;
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! let selector: Selector = "app=foo, tier in (frontend, backend), !canary".parse()?;
+//! assert_eq!(selector.to_string(), "app=foo,tier in (backend,frontend),!canary");
+//!
+//! let labels: std::collections::BTreeMap<String, String> = vec![
+//!     ("app".to_string(), "foo".to_string()),
+//!     ("tier".to_string(), "frontend".to_string()),
+//! ].into_iter().collect();
+//! assert!(selector.matches(&labels));
+//! # Ok(())
+//! # }
+//! ```
+
+/// A label selector. An object matches the selector if it matches all of the selector's requirements.
+///
+/// An empty selector matches all objects.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Selector(pub Vec<Requirement>);
+
+impl Selector {
+    /// Returns true if the given labels match all of the requirements of this selector.
+    pub fn matches<L>(&self, labels: &L) -> bool where L: Labels + ?Sized {
+        let labels = labels.labels();
+        self.0.iter().all(|requirement| requirement.matches(labels))
+    }
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, requirement) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+
+            write!(f, "{}", requirement)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Selector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { tokens: tokenize(s).into_iter().peekable() };
+
+        let mut requirements = vec![];
+
+        if parser.tokens.peek().is_none() {
+            return Ok(Selector(requirements));
+        }
+
+        loop {
+            requirements.push(parser.requirement()?);
+
+            match parser.tokens.next() {
+                Some(Token::Comma) => (),
+                Some(token) => return Err(Error::UnexpectedToken(token.to_string())),
+                None => break,
+            }
+        }
+
+        Ok(Selector(requirements))
+    }
+}
+
+/// A single requirement of a [`Selector`](struct.Selector.html), ie a label key and the operator that the key's value must satisfy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Requirement {
+    /// The label key that this requirement applies to.
+    pub key: String,
+
+    /// The operator that the label's value must satisfy.
+    pub operator: Operator,
+}
+
+impl Requirement {
+    /// Returns true if the given labels satisfy this requirement.
+    ///
+    /// `None` is treated the same as an empty set of labels.
+    pub fn matches(&self, labels: Option<&std::collections::BTreeMap<String, String>>) -> bool {
+        let value = labels.and_then(|labels| labels.get(&self.key));
+
+        match (&self.operator, value) {
+            (Operator::Equals(expected), Some(value)) => value == expected,
+            (Operator::NotEquals(expected), Some(value)) => value != expected,
+            (Operator::In(values), Some(value)) => values.contains(value),
+            (Operator::NotIn(values), Some(value)) => !values.contains(value),
+            (Operator::Exists, Some(_)) => true,
+            (Operator::DoesNotExist, Some(_)) => false,
+
+            (Operator::Equals(_), None) |
+            (Operator::In(_), None) |
+            (Operator::Exists, None) => false,
+
+            (Operator::NotEquals(_), None) |
+            (Operator::NotIn(_), None) |
+            (Operator::DoesNotExist, None) => true,
+        }
+    }
+}
+
+impl std::fmt::Display for Requirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fn write_values(f: &mut std::fmt::Formatter, values: &std::collections::BTreeSet<String>) -> std::fmt::Result {
+            write!(f, "(")?;
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+
+                write!(f, "{}", value)?;
+            }
+            write!(f, ")")
+        }
+
+        match &self.operator {
+            Operator::Equals(value) => write!(f, "{}={}", self.key, value),
+            Operator::NotEquals(value) => write!(f, "{}!={}", self.key, value),
+            Operator::In(values) => {
+                write!(f, "{} in ", self.key)?;
+                write_values(f, values)
+            },
+            Operator::NotIn(values) => {
+                write!(f, "{} notin ", self.key)?;
+                write_values(f, values)
+            },
+            Operator::Exists => write!(f, "{}", self.key),
+            Operator::DoesNotExist => write!(f, "!{}", self.key),
+        }
+    }
+}
+
+/// The operator of a [`Requirement`](struct.Requirement.html).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Operator {
+    /// `key=value` or `key==value`. The label must exist and have the given value.
+    Equals(String),
+
+    /// `key!=value`. The label must not exist, or must have a value other than the given value.
+    NotEquals(String),
+
+    /// `key in (value1, value2)`. The label must exist and have one of the given values.
+    In(std::collections::BTreeSet<String>),
+
+    /// `key notin (value1, value2)`. The label must not exist, or must have a value other than the given values.
+    NotIn(std::collections::BTreeSet<String>),
+
+    /// `key`. The label must exist.
+    Exists,
+
+    /// `!key`. The label must not exist.
+    DoesNotExist,
+}
+
+impl Operator {
+    /// Constructs an operator from the `operator` and `values` fields of a `LabelSelectorRequirement`.
+    pub fn from_parts(operator: &str, values: &[String]) -> Result<Self, Error> {
+        match operator {
+            "In" | "NotIn" if values.is_empty() =>
+                Err(Error::InvalidOperator(format!("operator {} requires at least one value", operator))),
+
+            "In" => Ok(Operator::In(values.iter().cloned().collect())),
+            "NotIn" => Ok(Operator::NotIn(values.iter().cloned().collect())),
+
+            "Exists" | "DoesNotExist" if !values.is_empty() =>
+                Err(Error::InvalidOperator(format!("operator {} does not take any values", operator))),
+
+            "Exists" => Ok(Operator::Exists),
+            "DoesNotExist" => Ok(Operator::DoesNotExist),
+
+            operator => Err(Error::InvalidOperator(format!("unknown operator {:?}", operator))),
+        }
+    }
+
+    /// Converts this operator into the `operator` and `values` fields of a `LabelSelectorRequirement`.
+    pub fn into_parts(self) -> (&'static str, Option<Vec<String>>) {
+        match self {
+            Operator::Equals(value) => ("In", Some(vec![value])),
+            Operator::NotEquals(value) => ("NotIn", Some(vec![value])),
+            Operator::In(values) => ("In", Some(values.into_iter().collect())),
+            Operator::NotIn(values) => ("NotIn", Some(values.into_iter().collect())),
+            Operator::Exists => ("Exists", None),
+            Operator::DoesNotExist => ("DoesNotExist", None),
+        }
+    }
+}
+
+/// A trait implemented by types that have labels that can be matched by a [`Selector`](struct.Selector.html).
+///
+/// This is implemented for the `ObjectMeta` type of every enabled version.
+pub trait Labels {
+    /// Returns the labels of this value.
+    fn labels(&self) -> Option<&std::collections::BTreeMap<String, String>>;
+}
+
+impl Labels for std::collections::BTreeMap<String, String> {
+    fn labels(&self) -> Option<&std::collections::BTreeMap<String, String>> {
+        Some(self)
+    }
+}
+
+/// The type of errors from parsing a label selector.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The selector ended where more input was expected.
+    UnexpectedEnd,
+
+    /// The selector contains an unexpected token.
+    UnexpectedToken(String),
+
+    /// The selector contains a key that is not a valid label key.
+    InvalidKey(String),
+
+    /// The selector contains a value that is not a valid label value.
+    InvalidValue(String),
+
+    /// A `LabelSelectorRequirement` has an unknown operator, or values that do not match its operator.
+    InvalidOperator(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::UnexpectedEnd => write!(f, "unexpected end of label selector"),
+            Error::UnexpectedToken(token) => write!(f, "unexpected token {:?} in label selector", token),
+            Error::InvalidKey(key) => write!(f, "invalid label key {:?}", key),
+            Error::InvalidValue(value) => write!(f, "invalid label value {:?}", value),
+            Error::InvalidOperator(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::UnexpectedEnd => "unexpected end of label selector",
+            Error::UnexpectedToken(_) => "unexpected token in label selector",
+            Error::InvalidKey(_) => "invalid label key",
+            Error::InvalidValue(_) => "invalid label value",
+            Error::InvalidOperator(_) => "invalid label selector operator",
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Token {
+    Identifier(String),
+    Equals,
+    DoubleEquals,
+    NotEquals,
+    In,
+    NotIn,
+    Not,
+    OpenParen,
+    CloseParen,
+    Comma,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Token::Identifier(s) => write!(f, "{}", s),
+            Token::Equals => write!(f, "="),
+            Token::DoubleEquals => write!(f, "=="),
+            Token::NotEquals => write!(f, "!="),
+            Token::In => write!(f, "in"),
+            Token::NotIn => write!(f, "notin"),
+            Token::Not => write!(f, "!"),
+            Token::OpenParen => write!(f, "("),
+            Token::CloseParen => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Vec<Token> {
+    let mut result = vec![];
+
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            ',' => Token::Comma,
+            '=' => if let Some((_, '=')) = chars.peek() { chars.next(); Token::DoubleEquals } else { Token::Equals },
+            '!' => if let Some((_, '=')) = chars.peek() { chars.next(); Token::NotEquals } else { Token::Not },
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || "()=!,".contains(c) {
+                        break;
+                    }
+
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+
+                match &s[start..end] {
+                    "in" => Token::In,
+                    "notin" => Token::NotIn,
+                    identifier => Token::Identifier(identifier.to_string()),
+                }
+            },
+        };
+
+        result.push(token);
+    }
+
+    result
+}
+
+struct Parser {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn requirement(&mut self) -> Result<Requirement, Error> {
+        let key = match self.tokens.next() {
+            Some(Token::Not) => {
+                let key = self.key()?;
+                return Ok(Requirement { key, operator: Operator::DoesNotExist });
+            },
+            Some(Token::Identifier(key)) => validate_key(key)?,
+            Some(token) => return Err(Error::UnexpectedToken(token.to_string())),
+            None => return Err(Error::UnexpectedEnd),
+        };
+
+        let operator = match self.tokens.peek() {
+            Some(Token::Comma) | None => return Ok(Requirement { key, operator: Operator::Exists }),
+            Some(_) => self.tokens.next().unwrap(),
+        };
+
+        let operator = match operator {
+            Token::Equals | Token::DoubleEquals => Operator::Equals(self.single_value()?),
+            Token::NotEquals => Operator::NotEquals(self.single_value()?),
+            Token::In => Operator::In(self.set_values()?),
+            Token::NotIn => Operator::NotIn(self.set_values()?),
+            token => return Err(Error::UnexpectedToken(token.to_string())),
+        };
+
+        Ok(Requirement { key, operator })
+    }
+
+    fn key(&mut self) -> Result<String, Error> {
+        match self.tokens.next() {
+            Some(Token::Identifier(key)) => validate_key(key),
+            Some(token) => Err(Error::UnexpectedToken(token.to_string())),
+            None => Err(Error::UnexpectedEnd),
+        }
+    }
+
+    fn single_value(&mut self) -> Result<String, Error> {
+        // `key=` is a valid requirement for the label having an empty value.
+        match self.tokens.peek() {
+            Some(Token::Identifier(_)) => (),
+            Some(Token::Comma) | None => return Ok(String::new()),
+            Some(token) => return Err(Error::UnexpectedToken(token.to_string())),
+        }
+
+        match self.tokens.next() {
+            Some(Token::Identifier(value)) => validate_value(value),
+            _ => unreachable!(),
+        }
+    }
+
+    fn set_values(&mut self) -> Result<std::collections::BTreeSet<String>, Error> {
+        match self.tokens.next() {
+            Some(Token::OpenParen) => (),
+            Some(token) => return Err(Error::UnexpectedToken(token.to_string())),
+            None => return Err(Error::UnexpectedEnd),
+        }
+
+        let mut values: std::collections::BTreeSet<_> = Default::default();
+
+        loop {
+            // Empty values are allowed, as in `key in (,a)`
+            let value = match self.tokens.peek() {
+                Some(Token::Identifier(_)) => match self.tokens.next() {
+                    Some(Token::Identifier(value)) => validate_value(value)?,
+                    _ => unreachable!(),
+                },
+                _ => String::new(),
+            };
+            values.insert(value);
+
+            match self.tokens.next() {
+                Some(Token::Comma) => (),
+                Some(Token::CloseParen) => return Ok(values),
+                Some(token) => return Err(Error::UnexpectedToken(token.to_string())),
+                None => return Err(Error::UnexpectedEnd),
+            }
+        }
+    }
+}
+
+/// Validates a label key, ie an optional DNS subdomain prefix followed by `/`, followed by a name of at most 63 characters.
+fn validate_key(key: String) -> Result<String, Error> {
+    let is_valid = {
+        let (prefix, name) = match key.rfind('/') {
+            Some(index) => (Some(&key[..index]), &key[(index + 1)..]),
+            None => (None, &key[..]),
+        };
+
+        let prefix_is_valid = prefix.map_or(true, |prefix|
+            !prefix.is_empty() && prefix.len() <= 253 &&
+            prefix.split('.').all(|label|
+                !label.is_empty() && label.len() <= 63 &&
+                label.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-') &&
+                !label.starts_with('-') && !label.ends_with('-')));
+
+        prefix_is_valid && !name.is_empty() && is_valid_name(name)
+    };
+
+    if is_valid {
+        Ok(key)
+    }
+    else {
+        Err(Error::InvalidKey(key))
+    }
+}
+
+/// Validates a label value, ie an empty string or a name of at most 63 characters.
+fn validate_value(value: String) -> Result<String, Error> {
+    if value.is_empty() || is_valid_name(&value) {
+        Ok(value)
+    }
+    else {
+        Err(Error::InvalidValue(value))
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    name.len() <= 63 &&
+    name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.') &&
+    name.bytes().next().map_or(false, |b| b.is_ascii_alphanumeric()) &&
+    name.bytes().last().map_or(false, |b| b.is_ascii_alphanumeric())
+}
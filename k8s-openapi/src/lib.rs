@@ -1,6 +1,54 @@
 //! Bindings for the Kubernetes client API, generated from the OpenAPI spec.
 //!
 //! Each supported version of Kubernetes is represented by one top-level module (like `::v1_9`) and is enabled by a crate feature of the same name (like `v1_9`).
+//! At least one of these features must be enabled. The modules of the highest enabled version, the default version, are also re-exported at the crate root,
+//! so that a binary can use paths like `k8s_openapi::api::core::v1` regardless of which version it enabled. The `k8s_if_*` and `k8s_match` macros
+//! also test the default version. v1.7's API groups are under `kubernetes::pkg::api` and `kubernetes::pkg::apis`,
+//! so its version module has an `api` module that re-exports them with the same paths as the later versions.
+//!
+//! # Libraries must not use the default version
+//!
+//! Cargo unifies the features of a crate across the whole dependency graph, so the default version is the highest `v1_*` feature
+//! enabled by *any* crate in the graph, not the one that a particular crate enabled. If a library uses the crate root re-export
+//! or the `k8s_if_*` and `k8s_match` macros, another crate that enables a higher version changes the types that the library
+//! was written against, and the library can fail to compile.
+//!
+//! So a library must use the paths of the version modules of the versions it enables, like `k8s_openapi::v1_9::api::core::v1`,
+//! and use [`server_version::Dispatch`](server_version/trait.Dispatch.html) to support several of them.
+//! The crate root re-export and the conditional compilation macros are only meant for binaries, which decide which versions are enabled.
+//!
+//! A version's feature compiles all of its API groups. To compile only some of them, enable the features of individual API group versions instead,
+//! like `v1_12-apps-v1`. Each of these features also enables the features of the API group versions that its types refer to, like `v1_12-core-v1`.
+//! The types under `apimachinery` are always compiled.
+//!
+//! The functions that prepare HTTP requests for the API operations, their response types, and the `Response` trait and `ResponseBody` type that
+//! parse those responses are enabled by the `api` feature, which is enabled by default. Disable default features to only compile the resource types,
+//! without the `bytes`, `http` and `url` dependencies.
+//!
+//! The [`manifest`](manifest/index.html) module loads manifests of resources as values of a version's `Resource` enum, like `v1_12::Resource`.
+//! It supports JSON manifests, and also multi-document YAML manifests if the `yaml` feature is enabled.
+//!
+//! The [`pager`](pager/index.html) module pages through the results of list operations using their `continue` tokens.
+//!
+//! The [`discovery`](discovery/index.html) module discovers the resources served by the API server, and maps their kinds to their plural names,
+//! scopes, verbs and preferred versions.
+//!
+//! The [`dynamic`](dynamic/index.html) module has the `DynamicObject` type for objects of resource kinds that are not known at compile time, like custom resources,
+//! and the API operations for them.
+//!
+//! The [`channel`](channel/index.html) module reads and writes the channel protocol of the `exec`, `attach` and `portforward` subresources of pods,
+//! over any byte stream that has been upgraded to a WebSocket connection.
+//!
+//! The [`logs`](logs/index.html) module reads the logs of a pod's container line by line, and resumes followed logs after the connection ends.
+//!
+//! The [`schema`](schema/index.html) module validates values against the `JSONSchemaProps` of a custom resource definition, with the same semantics as the API server.
+//! It is enabled by the `schema` feature, along with the `apiextensions/v1beta1` API group version of the default version.
+//!
+//! The [`server_version`](server_version/index.html) module detects the version of the API server at runtime, and dispatches to the module of the enabled version
+//! that matches it, so that one program can enable several versions and talk to clusters of any of them.
+//!
+//! The [`stream`](stream/index.html) module parses a `futures::Stream` of response body chunks into a stream of responses, like the events of a watch.
+//! It is enabled by the `stream` feature.
 //!
 //!
 //! # Examples
@@ -10,24 +58,7 @@
 //! ```rust
 //! #[macro_use] extern crate k8s_openapi;
 //!
-//! k8s_if_1_7! {
-//!     use k8s_openapi::v1_7::kubernetes::pkg::api::v1 as api;
-//! }
-//! k8s_if_1_8! {
-//!     use k8s_openapi::v1_8::api::core::v1 as api;
-//! }
-//! k8s_if_1_9! {
-//!     use k8s_openapi::v1_9::api::core::v1 as api;
-//! }
-//! k8s_if_1_10! {
-//!     use k8s_openapi::v1_10::api::core::v1 as api;
-//! }
-//! k8s_if_1_11! {
-//!     use k8s_openapi::v1_11::api::core::v1 as api;
-//! }
-//! k8s_if_1_12! {
-//!     use k8s_openapi::v1_12::api::core::v1 as api;
-//! }
+//! use k8s_openapi::api::core::v1 as api;
 //!
 //! fn main() {
 //!     let pod_spec: api::PodSpec = Default::default();
@@ -43,24 +74,7 @@
 //! // Re-export of the http crate since it's used in the public API
 //! use k8s_openapi::http;
 //!
-//! k8s_if_1_7! {
-//!     use k8s_openapi::v1_7::kubernetes::pkg::api::v1 as api;
-//! }
-//! k8s_if_1_8! {
-//!     use k8s_openapi::v1_8::api::core::v1 as api;
-//! }
-//! k8s_if_1_9! {
-//!     use k8s_openapi::v1_9::api::core::v1 as api;
-//! }
-//! k8s_if_1_10! {
-//!     use k8s_openapi::v1_10::api::core::v1 as api;
-//! }
-//! k8s_if_1_11! {
-//!     use k8s_openapi::v1_11::api::core::v1 as api;
-//! }
-//! k8s_if_1_12! {
-//!     use k8s_openapi::v1_12::api::core::v1 as api;
-//! }
+//! use k8s_openapi::api::core::v1 as api;
 //!
 //! # struct Response;
 //! # impl Response {
@@ -128,8 +142,9 @@
 //!         // `ListCoreV1NamespacedPodResponse` type.
 //!         //
 //!         // `ResponseBody::append_slice_and_parse` internally calls
-//!         // `Response::try_from_parts` for the response type. So you would
-//!         // call that function directly if you were not using `ResponseBody`
+//!         // `Response::try_from_parts` for the response type once the data
+//!         // buffered so far has a complete response. So you would call that
+//!         // function directly if you were not using `ResponseBody`
 //!         let response = response_body.append_slice_and_parse(&buf[..read]);
 //!         match response {
 //!             // Successful response (HTTP 200 and parsed successfully)
@@ -170,12 +185,23 @@
 //! a synchronous client with this style of API.
 
 extern crate base64;
+#[cfg(feature = "api")]
 extern crate bytes;
 pub extern crate chrono;
+#[cfg(feature = "stream")]
+extern crate futures;
+#[cfg(feature = "api")]
 pub extern crate http;
-extern crate serde;
+#[cfg(feature = "schema")]
+extern crate regex;
+pub extern crate serde;
 pub extern crate serde_json;
-extern crate url;
+#[cfg(feature = "yaml")]
+extern crate serde_yaml;
+// Public for the code generated by `#[derive(CustomResource)]` in the k8s-openapi-derive crate
+#[cfg(feature = "api")]
+#[doc(hidden)]
+pub extern crate url;
 
 /// A wrapper around a list of bytes.
 ///
@@ -209,7 +235,36 @@ impl serde::Serialize for ByteString {
     }
 }
 
+/// The type of errors returned when resolving an `IntOrString` value as a percentage.
+#[derive(Debug, Eq, PartialEq)]
+pub enum IntOrStringError {
+    /// The value is a string that is not a percentage of the form `"N%"`.
+    NotAPercentage(String),
+
+    /// The value is a percentage that does not fit in an `i32`, or that scales to a value that does not fit in an `i32`.
+    OutOfRange(String),
+}
+
+impl std::fmt::Display for IntOrStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IntOrStringError::NotAPercentage(value) => write!(f, "{:?} is not a percentage", value),
+            IntOrStringError::OutOfRange(value) => write!(f, "{:?} is out of range", value),
+        }
+    }
+}
+
+impl std::error::Error for IntOrStringError {
+    fn description(&self) -> &str {
+        match self {
+            IntOrStringError::NotAPercentage(_) => "value is not a percentage",
+            IntOrStringError::OutOfRange(_) => "value is out of range",
+        }
+    }
+}
+
 /// The type of errors returned by the Kubernetes API functions that prepare the HTTP request.
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum RequestError {
     /// An error from preparing the HTTP request.
@@ -219,6 +274,7 @@ pub enum RequestError {
     Json(serde_json::Error),
 }
 
+#[cfg(feature = "api")]
 impl std::fmt::Display for RequestError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -228,6 +284,7 @@ impl std::fmt::Display for RequestError {
     }
 }
 
+#[cfg(feature = "api")]
 impl std::error::Error for RequestError {
     fn description(&self) -> &str {
         match self {
@@ -245,25 +302,78 @@ impl std::error::Error for RequestError {
 }
 
 /// A trait implemented by all response types corresponding to Kubernetes API functions.
+#[cfg(feature = "api")]
 pub trait Response: Sized {
     /// Tries to parse the response from the given status code and response body.
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ResponseError>;
+
+    /// How the body of a successful response is framed.
+    ///
+    /// [`ResponseBody`](struct.ResponseBody.html) uses this to find the end of a response in the data buffered so far,
+    /// so that it only calls `try_from_parts` once there's a complete response to parse. The default is `ResponseFraming::Unframed`.
+    fn framing() -> ResponseFraming {
+        ResponseFraming::Unframed
+    }
+}
+
+/// How the body of a successful response is framed. See [`Response::framing`](trait.Response.html#method.framing).
+#[cfg(feature = "api")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResponseFraming {
+    /// The response can be parsed from any amount of data, like the text of a pod's logs.
+    Unframed,
+
+    /// The response body is a single JSON value, which is only parsed once it's complete.
+    JsonValue,
+
+    /// The response body is a sequence of JSON values each terminated by a newline, like the events of a watch.
+    /// Each value is only parsed once its line is complete.
+    JsonLines,
+}
+
+/// A trait implemented by all list types, like `PodList`.
+pub trait List: Sized {
+    /// The type of the items of the list.
+    type Item;
+
+    /// The continue token of the list, ie the value of its `metadata.continue` field, if the server has more items to return.
+    fn continue_token(&self) -> Option<&str>;
+
+    /// Consumes the list and returns its items.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// A trait implemented by the response types of list operations, like `ListCoreV1NamespacedPodResponse`.
+#[cfg(feature = "api")]
+pub trait ListResponse: Response {
+    /// The list type of a successful response.
+    type List: List;
+
+    /// Returns the list if this is a successful response, otherwise `None`.
+    fn into_list(self) -> Option<Self::List>;
 }
 
 /// A helper that holds a growable buffer that can be parsed into a Kubernetes API function's response.
+///
+/// The buffered data is only parsed once it has a complete response according to the [framing](trait.Response.html#method.framing)
+/// of the response type, so a large response received in many small pieces is still only parsed once.
+#[cfg(feature = "api")]
 pub struct ResponseBody {
     /// The HTTP status code of the response.
     pub status_code: http::StatusCode,
 
     buf: bytes::BytesMut,
+    scanner: FrameScanner,
 }
 
+#[cfg(feature = "api")]
 impl ResponseBody {
     /// Construct a value for a response that has the specified HTTP status code.
     pub fn new(status_code: http::StatusCode) -> Self {
         ResponseBody {
             status_code,
             buf: Default::default(),
+            scanner: Default::default(),
         }
     }
 
@@ -275,18 +385,93 @@ impl ResponseBody {
     /// Append a slice of data from the HTTP response, and try to parse all the data buffered so far into a response type.
     pub fn append_slice_and_parse<T>(&mut self, buf: &[u8]) -> Result<T, ResponseError> where T: Response {
         self.append_slice(buf);
+        self.parse().map(|(result, _)| result)
+    }
+
+    /// Tries to parse the data buffered so far into a response type, and returns it along with the number of bytes it consumed.
+    fn parse<T>(&mut self) -> Result<(T, usize), ResponseError> where T: Response {
+        let framing = if self.status_code.is_success() { T::framing() } else { ResponseFraming::Unframed };
 
-        match T::try_from_parts(self.status_code, &*self.buf) {
-            Ok((result, read)) => {
-                self.buf.advance(read);
-                Ok(result)
-            },
+        let len = match self.scanner.scan(&self.buf, framing) {
+            Some(len) => len,
+            None => return Err(ResponseError::NeedMoreData),
+        };
 
-            Err(err) => Err(err),
+        let (result, read) = T::try_from_parts(self.status_code, &self.buf[..len])?;
+        self.buf.advance(read);
+        self.scanner = Default::default();
+        Ok((result, read))
+    }
+}
+
+/// Scans the data of a [`ResponseBody`](struct.ResponseBody.html) for the end of the first response in it.
+///
+/// The scanner remembers how far it has scanned, so each byte is only scanned once no matter how many pieces the response is received in.
+#[cfg(feature = "api")]
+#[derive(Debug, Default)]
+struct FrameScanner {
+    scanned: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    in_scalar: bool,
+}
+
+#[cfg(feature = "api")]
+impl FrameScanner {
+    /// Returns the length of the first response in `buf`, or `None` if it isn't complete yet.
+    fn scan(&mut self, buf: &[u8], framing: ResponseFraming) -> Option<usize> {
+        match framing {
+            ResponseFraming::Unframed => return Some(buf.len()),
+
+            ResponseFraming::JsonLines =>
+                if let Some(newline) = buf[self.scanned..].iter().position(|&b| b == b'\n') {
+                    return Some(self.scanned + newline + 1);
+                },
+
+            ResponseFraming::JsonValue =>
+                for (i, &b) in buf.iter().enumerate().skip(self.scanned) {
+                    if self.in_string {
+                        if self.escaped {
+                            self.escaped = false;
+                        }
+                        else if b == b'\\' {
+                            self.escaped = true;
+                        }
+                        else if b == b'"' {
+                            self.in_string = false;
+                            if self.depth == 0 {
+                                return Some(i + 1);
+                            }
+                        }
+
+                        continue;
+                    }
+
+                    match b {
+                        b'{' | b'[' => self.depth += 1,
+                        b'}' | b']' if self.depth <= 1 => return Some(i + 1),
+                        b'}' | b']' => self.depth -= 1,
+                        b'"' => self.in_string = true,
+                        b' ' | b'\t' | b'\r' | b'\n' if self.in_scalar => return Some(i),
+                        b' ' | b'\t' | b'\r' | b'\n' => (),
+
+                        // A top-level scalar like a number doesn't have a delimiter, so it's only complete once whitespace follows it.
+                        // The API server ends its responses with a newline. Otherwise the caller parses the rest of the body at the end of the stream,
+                        // like `ResponseStream` does.
+                        _ if self.depth == 0 => self.in_scalar = true,
+
+                        _ => (),
+                    }
+                },
         }
+
+        self.scanned = buf.len();
+        None
     }
 }
 
+#[cfg(feature = "api")]
 impl std::ops::Deref for ResponseBody {
     type Target = [u8];
 
@@ -296,6 +481,7 @@ impl std::ops::Deref for ResponseBody {
 }
 
 /// The type of errors from parsing an HTTP response as one of the Kubernetes API functions' response types.
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ResponseError {
     /// An error from deserializing the HTTP response, indicating more data is needed to complete deserialization.
@@ -308,6 +494,7 @@ pub enum ResponseError {
     Utf8(std::str::Utf8Error),
 }
 
+#[cfg(feature = "api")]
 impl std::fmt::Display for ResponseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -318,6 +505,7 @@ impl std::fmt::Display for ResponseError {
     }
 }
 
+#[cfg(feature = "api")]
 impl std::error::Error for ResponseError {
     fn description(&self) -> &str {
         match self {
@@ -336,22 +524,60 @@ impl std::error::Error for ResponseError {
     }
 }
 
-#[cfg(feature = "v1_7")]
+#[cfg(feature = "api")]
+pub mod channel;
+
+pub mod conversion;
+
+#[cfg(feature = "api")]
+pub mod discovery;
+
+pub mod dynamic;
+
+pub mod field_selector;
+
+pub mod label_selector;
+
+#[cfg(feature = "api")]
+pub mod logs;
+
+pub mod manifest;
+
+#[cfg(feature = "api")]
+pub mod pager;
+
+#[cfg(all(feature = "schema", k8s_openapi_apiextensions_v1beta1))]
+pub mod schema;
+
+#[cfg(feature = "api")]
+pub mod server_version;
+
+#[cfg(feature = "stream")]
+pub mod stream;
+
+// Public for the code generated by `#[derive(CustomResource)]` in the k8s-openapi-derive crate
+#[doc(hidden)]
+pub mod tagged;
+
+#[cfg(k8s_openapi_v1_7)]
 pub mod v1_7;
 
-#[cfg(feature = "v1_8")]
+#[cfg(k8s_openapi_v1_8)]
 pub mod v1_8;
 
-#[cfg(feature = "v1_9")]
+#[cfg(k8s_openapi_v1_9)]
 pub mod v1_9;
 
-#[cfg(feature = "v1_10")]
+#[cfg(k8s_openapi_v1_10)]
 pub mod v1_10;
 
-#[cfg(feature = "v1_11")]
+#[cfg(k8s_openapi_v1_11)]
 pub mod v1_11;
 
-#[cfg(feature = "v1_12")]
+#[cfg(k8s_openapi_v1_12)]
 pub mod v1_12;
 
+// Re-exports the modules of the enabled version, like `v1_12::api`, at the crate root.
+include!(concat!(env!("OUT_DIR"), "/enabled_version.rs"));
+
 include!(concat!(env!("OUT_DIR"), "/conditional_compilation_macros.rs"));
@@ -0,0 +1,336 @@
+//! Reading the logs of a pod's container line by line.
+//!
+//! [`request`](fn.request.html) prepares the request for a container's logs from a [`LogOptions`](struct.LogOptions.html).
+//! Unlike `Pod::read_core_v1_namespaced_pod_log`, it supports the `sinceTime` parameter, and doesn't require the `core/v1` API group's feature.
+//!
+//! A [`LogReader`](struct.LogReader.html) reads the response body and returns complete lines, regardless of how the body was split into chunks.
+//! If the logs were requested with timestamps, the RFC3339 timestamp at the start of each line is parsed and removed.
+//! [`read_line`](struct.LogReader.html#method.read_line) returns the lines as strings and fails on lines that aren't valid UTF-8,
+//! whereas [`read_raw_line`](struct.LogReader.html#method.read_raw_line) returns the lines as bytes.
+//!
+//! The connection of a followed log stream can end before the container does, like when the API server restarts or a proxy times out the connection.
+//! If the logs were requested with timestamps, the stream can be resumed with a new request that uses the options returned by
+//! [`LogReader::resume_options`](struct.LogReader.html#method.resume_options). Since `sinceTime` only has a precision of seconds,
+//! the API server sends some of the lines again, and the reader skips the lines it has already returned.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! extern crate k8s_openapi;
+//!
+//! use k8s_openapi::http;
+//! use k8s_openapi::logs::{ self, LogOptions, LogReader };
+//!
+//! // `execute` is some function that executes the request and returns the response body.
+//! fn execute(request: http::Request<Vec<u8>>) -> Box<std::io::Read> { unimplemented!(); }
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let mut options = LogOptions {
+//!         container: Some("kube-addon-manager".to_string()),
+//!         follow: true,
+//!         timestamps: true,
+//!         ..Default::default()
+//!     };
+//!
+//!     let mut reader = LogReader::new(execute(logs::request("kube-addon-manager-minikube", "kube-system", &options)?), options.timestamps);
+//!     loop {
+//!         match reader.read_line()? {
+//!             Some(line) => println!("{:?} {}", line.timestamp, line.content),
+//!
+//!             None => {
+//!                 // The connection ended. Resume from the last line.
+//!                 options = reader.resume_options(&options);
+//!                 reader.resume(execute(logs::request("kube-addon-manager-minikube", "kube-system", &options)?));
+//!             },
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::io::Read;
+
+/// The parameters of a request for the logs of a pod's container.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogOptions {
+    /// The container whose logs to read. Defaults to the pod's only container if it has one container.
+    pub container: Option<String>,
+
+    /// Follow the logs, ie keep the response open and send new lines as they're written.
+    pub follow: bool,
+
+    /// The number of bytes to read before the API server ends the response. The last line may be incomplete.
+    pub limit_bytes: Option<i64>,
+
+    /// Read the logs of the previous terminated instance of the container.
+    pub previous: bool,
+
+    /// Only read the lines written in the given number of seconds before now. Only one of this and `since_time` may be set.
+    pub since_seconds: Option<i64>,
+
+    /// Only read the lines written at or after the given time. It's sent with a precision of seconds. Only one of this and `since_seconds` may be set.
+    pub since_time: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Only read the given number of lines from the end of the logs.
+    pub tail_lines: Option<i64>,
+
+    /// Add an RFC3339 timestamp to the start of each line.
+    pub timestamps: bool,
+}
+
+/// Prepares the request for the logs of the given pod's container.
+pub fn request(name: &str, namespace: &str, options: &LogOptions) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+    let url = format!("/api/v1/namespaces/{namespace}/pods/{name}/log?", name = name, namespace = namespace);
+    let mut query_pairs = url::form_urlencoded::Serializer::new(url);
+    if let Some(container) = &options.container {
+        query_pairs.append_pair("container", container);
+    }
+    if options.follow {
+        query_pairs.append_pair("follow", "true");
+    }
+    if let Some(limit_bytes) = options.limit_bytes {
+        query_pairs.append_pair("limitBytes", &limit_bytes.to_string());
+    }
+    if options.previous {
+        query_pairs.append_pair("previous", "true");
+    }
+    if let Some(since_seconds) = options.since_seconds {
+        query_pairs.append_pair("sinceSeconds", &since_seconds.to_string());
+    }
+    if let Some(since_time) = options.since_time {
+        query_pairs.append_pair("sinceTime", &since_time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    }
+    if let Some(tail_lines) = options.tail_lines {
+        query_pairs.append_pair("tailLines", &tail_lines.to_string());
+    }
+    if options.timestamps {
+        query_pairs.append_pair("timestamps", "true");
+    }
+    let url = query_pairs.finish();
+
+    let mut request = http::Request::get(url);
+    let body = vec![];
+    request.body(body).map_err(::RequestError::Http)
+}
+
+/// A line of a container's logs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Line<T> {
+    /// The time at which the line was written, if the logs were requested with timestamps.
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// The content of the line, without its timestamp and trailing newline.
+    pub content: T,
+}
+
+/// Reads the lines of the response body of a log request. See the [module docs](index.html) for details.
+pub struct LogReader<R> {
+    body: R,
+    timestamps: bool,
+    buf: Vec<u8>,
+    eof: bool,
+
+    /// The timestamp of the last returned line, and the number of returned lines with that timestamp.
+    last_timestamp: Option<(chrono::DateTime<chrono::Utc>, usize)>,
+
+    /// The timestamp of the last line returned before the stream was resumed,
+    /// and the number of lines with that timestamp that the resumed response still has to send again.
+    skip: Option<(chrono::DateTime<chrono::Utc>, usize)>,
+}
+
+impl<R> LogReader<R> where R: Read {
+    /// Constructs a reader for the given response body. `timestamps` must be the same as that of the request's options.
+    pub fn new(body: R, timestamps: bool) -> Self {
+        LogReader {
+            body,
+            timestamps,
+            buf: vec![],
+            eof: false,
+            last_timestamp: None,
+            skip: None,
+        }
+    }
+
+    /// Reads the next line as a string. Returns `None` when the response body has ended.
+    ///
+    /// Returns [`Error::Utf8`](enum.Error.html#variant.Utf8) if the line isn't valid UTF-8. The line is consumed regardless,
+    /// so the next call reads the next line.
+    pub fn read_line(&mut self) -> Result<Option<Line<String>>, Error> {
+        match self.read_raw_line()? {
+            Some(Line { timestamp, content }) => {
+                let content = String::from_utf8(content).map_err(Error::Utf8)?;
+                Ok(Some(Line { timestamp, content }))
+            },
+
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the next line as bytes. Returns `None` when the response body has ended.
+    pub fn read_raw_line(&mut self) -> Result<Option<Line<Vec<u8>>>, Error> {
+        loop {
+            let (mut line, complete) = match self.next_line()? {
+                Some(line) => line,
+                None => return Ok(None),
+            };
+
+            if !self.timestamps {
+                return Ok(Some(Line { timestamp: None, content: line }));
+            }
+
+            let timestamp = {
+                let end = line.iter().position(|&b| b == b' ').unwrap_or(line.len());
+                let timestamp =
+                    std::str::from_utf8(&line[..end]).ok()
+                    .and_then(|timestamp| chrono::DateTime::parse_from_rfc3339(timestamp).ok())
+                    .ok_or_else(|| Error::Timestamp(String::from_utf8_lossy(&line).into_owned()))?;
+                line.drain(..std::cmp::min(end + 1, line.len()));
+                timestamp.with_timezone(&chrono::Utc)
+            };
+
+            // Skip the lines that were returned before the stream was resumed
+            if let Some((skip_timestamp, remaining)) = &mut self.skip {
+                if timestamp < *skip_timestamp {
+                    continue;
+                }
+
+                if timestamp == *skip_timestamp && *remaining > 0 {
+                    *remaining -= 1;
+                    continue;
+                }
+
+                self.skip = None;
+            }
+
+            // An incomplete line is sent again in full when the stream is resumed, so it isn't counted as returned
+            if complete {
+                match &mut self.last_timestamp {
+                    Some((last_timestamp, count)) if *last_timestamp == timestamp => *count += 1,
+                    last_timestamp => *last_timestamp = Some((timestamp, 1)),
+                }
+            }
+
+            return Ok(Some(Line { timestamp: Some(timestamp), content: line }));
+        }
+    }
+
+    /// Returns the options of the request that resumes the logs after the last line that was read.
+    ///
+    /// The returned options are the given options with `since_time` set to the timestamp of the last line, and `since_seconds` and `tail_lines` unset.
+    /// If no line has been read yet, or the logs were requested without timestamps, the given options are returned unchanged.
+    pub fn resume_options(&self, options: &LogOptions) -> LogOptions {
+        match self.last_timestamp {
+            Some((last_timestamp, _)) => LogOptions {
+                since_seconds: None,
+                since_time: Some(last_timestamp),
+                tail_lines: None,
+                ..options.clone()
+            },
+
+            None => options.clone(),
+        }
+    }
+
+    /// Continues reading from the response body of the request that used the options returned by [`resume_options`](#method.resume_options).
+    ///
+    /// Any unread data of the previous response body is discarded. If the previous response body ended in the middle of a line,
+    /// that line was returned incomplete, and the new response body returns it again in full.
+    pub fn resume(&mut self, body: R) {
+        self.body = body;
+        self.buf.clear();
+        self.eof = false;
+        self.skip = self.last_timestamp;
+    }
+
+    /// Returns the inner response body.
+    pub fn into_inner(self) -> R {
+        self.body
+    }
+
+    /// Returns the next line, and whether it ended with a newline.
+    fn next_line(&mut self) -> Result<Option<(Vec<u8>, bool)>, Error> {
+        let mut searched = 0;
+
+        loop {
+            if let Some(pos) = self.buf[searched..].iter().position(|&b| b == b'\n') {
+                let end = searched + pos;
+                let mut line: Vec<_> = self.buf.drain(..=end).collect();
+                line.pop();
+                return Ok(Some((line, true)));
+            }
+            searched = self.buf.len();
+
+            if self.eof {
+                // The last line doesn't end with a newline, such as if the response was cut off by `limit_bytes`
+                if self.buf.is_empty() {
+                    return Ok(None);
+                }
+
+                return Ok(Some((std::mem::take(&mut self.buf), false)));
+            }
+
+            let mut chunk = [0u8; 4096];
+            let read = match self.body.read(&mut chunk) {
+                Ok(read) => read,
+                Err(ref err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(Error::Io(err)),
+            };
+            if read == 0 {
+                self.eof = true;
+            }
+            self.buf.extend_from_slice(&chunk[..read]);
+        }
+    }
+}
+
+impl<R> Iterator for LogReader<R> where R: Read {
+    type Item = Result<Line<String>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_line() {
+            Ok(Some(line)) => Some(Ok(line)),
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// The type of errors from reading the lines of a container's logs.
+#[derive(Debug)]
+pub enum Error {
+    /// An error from reading the response body.
+    Io(std::io::Error),
+
+    /// The logs were requested with timestamps, but a line doesn't start with one.
+    Timestamp(String),
+
+    /// A line isn't valid UTF-8. Use [`LogReader::read_raw_line`](struct.LogReader.html#method.read_raw_line) to read such lines as bytes.
+    Utf8(std::string::FromUtf8Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Timestamp(line) => write!(f, "line does not start with a timestamp: {:?}", line),
+            Error::Utf8(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::Io(err) => err.description(),
+            Error::Timestamp(_) => "line does not start with a timestamp",
+            Error::Utf8(err) => err.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&std::error::Error> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Timestamp(_) => None,
+            Error::Utf8(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Loading manifests of resources, like the files given to `kubectl apply -f`.
+//!
+//! A manifest is parsed into a list of values of any type that can be deserialized, usually the `Resource` enum of a version module
+//! like [`v1_12::Resource`](../v1_12/enum.Resource.html). Each of its values has the type of the resource kind identified by its `apiVersion` and `kind` fields,
+//! and values that are not of any of the enabled resource kinds are returned as `Resource::Unknown`.
+//!
+//! Libraries should name the `Resource` enum of the version they use, not the one at the crate root, since the default version
+//! can be changed by other crates in the dependency graph.
+//!
+//! A manifest can be a single JSON document or a sequence of JSON documents. If the `yaml` feature is enabled, it can also be a multi-document YAML stream.
+//! Documents that are lists, like a `v1` `List` or a `PodList`, are flattened into their items.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use] extern crate k8s_openapi;
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! let resources: Vec<k8s_openapi::Resource> = k8s_openapi::manifest::from_str(r#"{
+//!     "apiVersion": "v1",
+//!     "kind": "List",
+//!     "items": [
+//!         { "apiVersion": "v1", "kind": "Namespace", "metadata": { "name": "foo" } },
+//!         { "apiVersion": "example.com/v1", "kind": "Foo", "metadata": { "name": "foo" } }
+//!     ]
+//! }"#)?;
+//!
+//! match &resources[..] {
+//!     [k8s_openapi::Resource::CoreV1Namespace(namespace), k8s_openapi::Resource::Unknown(_)] =>
+//!         assert_eq!(namespace.metadata.as_ref().and_then(|metadata| metadata.name.as_ref()), Some(&"foo".to_string())),
+//!     resources => panic!("{:?}", resources),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+/// Parses a manifest of resources from a string.
+///
+/// If the `yaml` feature is enabled, the string is parsed as a multi-document YAML stream unless it starts with `{` or `[`.
+/// Otherwise it is parsed as a sequence of JSON documents.
+///
+/// Each document, or each item of a document that is a list, is deserialized as an `R`.
+pub fn from_str<R>(s: &str) -> Result<Vec<R>, Error> where R: serde::de::DeserializeOwned {
+    let mut resources = vec![];
+
+    #[cfg(feature = "yaml")]
+    {
+        if !s.trim_start().starts_with(|c| c == '{' || c == '[') {
+            for document in split_yaml_documents(s) {
+                if document.lines().all(|line| { let line = line.trim(); line.is_empty() || line.starts_with('#') }) {
+                    continue;
+                }
+
+                let value = serde_yaml::from_str(document).map_err(Error::Yaml)?;
+                push_resources(value, &mut resources)?;
+            }
+
+            return Ok(resources);
+        }
+    }
+
+    for value in serde_json::Deserializer::from_str(s).into_iter() {
+        let value = value.map_err(Error::Json)?;
+        push_resources(value, &mut resources)?;
+    }
+
+    Ok(resources)
+}
+
+/// Parses a manifest of resources from a reader, like an open file.
+///
+/// See [`from_str`](fn.from_str.html) for the formats that are supported.
+pub fn from_reader<R, T>(mut reader: T) -> Result<Vec<R>, Error> where R: serde::de::DeserializeOwned, T: std::io::Read {
+    let mut s = String::new();
+    reader.read_to_string(&mut s).map_err(Error::Io)?;
+    from_str(&s)
+}
+
+/// The type of errors from loading a manifest.
+#[derive(Debug)]
+pub enum Error {
+    /// An error from reading the manifest.
+    Io(std::io::Error),
+
+    /// An error from parsing a JSON document of the manifest, or from deserializing a value as the type of its resource kind.
+    Json(serde_json::Error),
+
+    /// An error from parsing a YAML document of the manifest.
+    #[cfg(feature = "yaml")]
+    Yaml(serde_yaml::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Json(err) => write!(f, "{}", err),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::Io(err) => err.description(),
+            Error::Json(err) => err.description(),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(err) => err.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&std::error::Error> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json(err) => Some(err),
+            #[cfg(feature = "yaml")]
+            Error::Yaml(err) => Some(err),
+        }
+    }
+}
+
+/// Deserializes the given value as an `R` and appends it to `resources`. If the value is a list, its items are appended instead.
+///
+/// The items of typed lists like `PodList` don't always have their own `apiVersion` and `kind`, so they're set from the list's if missing.
+/// The items of a `v1` `List` can be of any kind, so they're left as they are.
+fn push_resources<R>(value: serde_json::Value, resources: &mut Vec<R>) -> Result<(), Error> where R: serde::de::DeserializeOwned {
+    let mut value = match value {
+        serde_json::Value::Null => return Ok(()),
+
+        serde_json::Value::Array(items) => {
+            for item in items {
+                push_resources(item, resources)?;
+            }
+
+            return Ok(());
+        },
+
+        serde_json::Value::Object(value) => value,
+
+        value => {
+            resources.push(serde_json::from_value(value).map_err(Error::Json)?);
+            return Ok(());
+        },
+    };
+
+    let item_kind = match (value.get("kind"), value.get("items")) {
+        (Some(serde_json::Value::String(kind)), Some(serde_json::Value::Array(_))) if kind.ends_with("List") =>
+            Some(kind[..kind.len() - "List".len()].to_string()),
+        _ => None,
+    };
+
+    if let Some(item_kind) = item_kind {
+        if let Some(serde_json::Value::Array(items)) = value.remove("items") {
+            let api_version = value.get("apiVersion").cloned();
+
+            for mut item in items {
+                if !item_kind.is_empty() {
+                    if let serde_json::Value::Object(item) = &mut item {
+                        if let Some(api_version) = &api_version {
+                            item.entry("apiVersion").or_insert_with(|| api_version.clone());
+                        }
+                        item.entry("kind").or_insert_with(|| serde_json::Value::String(item_kind.clone()));
+                    }
+                }
+
+                push_resources(item, resources)?;
+            }
+        }
+    }
+    else {
+        resources.push(serde_json::from_value(serde_json::Value::Object(value)).map_err(Error::Json)?);
+    }
+
+    Ok(())
+}
+
+/// Splits a YAML stream into its documents, which are separated by lines starting with `---`.
+#[cfg(feature = "yaml")]
+fn split_yaml_documents(s: &str) -> Vec<&str> {
+    let mut documents = vec![];
+
+    let mut document_start = 0;
+    let mut line_start = 0;
+
+    for line in s.split('\n') {
+        if line.starts_with("---") && line[3..].chars().next().map_or(true, char::is_whitespace) {
+            documents.push(&s[document_start..line_start]);
+
+            // The document can start on the same line as the separator, like `--- !!map`
+            document_start = line_start + 3;
+        }
+
+        line_start += line.len() + 1;
+    }
+
+    documents.push(&s[document_start..]);
+
+    documents
+}
@@ -0,0 +1,152 @@
+//! Paging through the results of list operations using their `continue` tokens.
+//!
+//! A [`Pager`](struct.Pager.html) wraps a function that prepares the request of a list operation for a given continue token.
+//! It returns the request for each page in turn, and takes the parsed response of each page to return its items and remember its continue token.
+//! It doesn't execute the requests itself, so it can be used with any HTTP client, synchronous or asynchronous.
+//!
+//! If the server doesn't support paging, the first response has all the items and no continue token, so the pager is done after one page.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! #[macro_use] extern crate k8s_openapi;
+//!
+//! use k8s_openapi::http;
+//! use k8s_openapi::pager::{ OnExpired, Pager };
+//!
+//! use k8s_openapi::api::core::v1 as api;
+//!
+//! // `execute` is some function that executes the request and parses the response,
+//! // like the one in the crate root's example.
+//! fn execute<R>(request: http::Request<Vec<u8>>) -> (http::StatusCode, R) { unimplemented!(); }
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let mut pager = Pager::new(OnExpired::Fail, |continue_| {
+//!         k8s_if_le_1_7! {
+//!             let _ = continue_;
+//!             api::Pod::list_core_v1_namespaced_pod(
+//!                 "kube-system",
+//!                 None, None, None, None, None, None, None)
+//!         }
+//!         k8s_if_ge_1_8! {
+//!             api::Pod::list_core_v1_namespaced_pod(
+//!                 "kube-system",
+//!                 continue_, None, None, None, Some(100), None, None, None, None)
+//!         }
+//!     });
+//!
+//!     let mut pods = vec![];
+//!     while let Some(request) = pager.next_request() {
+//!         let (status_code, response): (_, api::ListCoreV1NamespacedPodResponse) = execute(request?);
+//!         pods.extend(pager.handle_response(status_code, response)?);
+//!     }
+//!
+//!     println!("{} pods", pods.len());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// Pages through the results of a list operation. See the [module docs](index.html) for details.
+pub struct Pager<F> {
+    make_request: F,
+    on_expired: OnExpired,
+    continue_: Option<String>,
+    done: bool,
+}
+
+/// What a [`Pager`](struct.Pager.html) does when the continue token of the list has expired, ie the server responded with 410 Gone.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OnExpired {
+    /// Fail with [`Error::Expired`](enum.Error.html#variant.Expired). The items of the previous pages are a consistent snapshot of the list
+    /// as of the first page, so the caller can decide whether they are still useful.
+    Fail,
+
+    /// Restart the list from the first page. The items of the previous pages will be returned again, possibly with changes,
+    /// so the caller should discard the items it has collected so far.
+    Restart,
+}
+
+impl<F> Pager<F> where F: FnMut(Option<&str>) -> Result<http::Request<Vec<u8>>, ::RequestError> {
+    /// Constructs a pager for the list operation whose request is prepared by the given function.
+    ///
+    /// The function is called with the continue token to use for each page, which is `None` for the first page.
+    /// It should set the same `limit` and other parameters for every page.
+    pub fn new(on_expired: OnExpired, make_request: F) -> Self {
+        Pager {
+            make_request,
+            on_expired,
+            continue_: None,
+            done: false,
+        }
+    }
+
+    /// Returns the request for the next page, or `None` if all pages have been received.
+    pub fn next_request(&mut self) -> Option<Result<http::Request<Vec<u8>>, ::RequestError>> {
+        if self.done {
+            return None;
+        }
+
+        Some((self.make_request)(self.continue_.as_ref().map(String::as_str)))
+    }
+
+    /// Handles the response to the request returned by the last call to [`next_request`](#method.next_request), and returns the items of its page.
+    ///
+    /// If the continue token has expired and the pager was created with `OnExpired::Restart`, this returns no items,
+    /// and the next request is for the first page again.
+    pub fn handle_response<R>(&mut self, status_code: http::StatusCode, response: R) -> Result<Vec<<R::List as ::List>::Item>, Error> where R: ::ListResponse {
+        if status_code == http::StatusCode::GONE && self.continue_.is_some() {
+            self.continue_ = None;
+
+            return match self.on_expired {
+                OnExpired::Fail => {
+                    self.done = true;
+                    Err(Error::Expired)
+                },
+
+                OnExpired::Restart => Ok(vec![]),
+            };
+        }
+
+        match response.into_list() {
+            Some(list) => {
+                self.continue_ = ::List::continue_token(&list).filter(|continue_| !continue_.is_empty()).map(ToOwned::to_owned);
+                self.done = self.continue_.is_none();
+                Ok(::List::into_items(list))
+            },
+
+            None => {
+                self.done = true;
+                Err(Error::UnexpectedResponse(status_code))
+            },
+        }
+    }
+}
+
+/// The type of errors from paging through the results of a list operation.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The continue token expired before all pages were received, ie the server responded with 410 Gone.
+    Expired,
+
+    /// The server responded to a request with an unsuccessful status code.
+    UnexpectedResponse(http::StatusCode),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Expired => write!(f, "continue token expired"),
+            Error::UnexpectedResponse(status_code) => write!(f, "unexpected response {}", status_code),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::Expired => "continue token expired",
+            Error::UnexpectedResponse(_) => "unexpected response",
+        }
+    }
+}
@@ -0,0 +1,504 @@
+//! Validating values against the `JSONSchemaProps` of a custom resource definition, and generating the `JSONSchemaProps` of Rust types.
+//!
+//! [`validate`](fn.validate.html) evaluates a JSON value, like a custom resource loaded from a manifest, against the validation schema
+//! of its custom resource definition, with the same semantics as the API server. This can be used to find invalid custom resources
+//! before they're sent to the API server.
+//!
+//! The following properties of the schema are evaluated:
+//!
+//! - `type`, where `integer` matches any number without a fractional part
+//! - `enum`, where numbers are compared by value
+//! - `pattern`, `minLength` and `maxLength` of strings, where lengths are in Unicode scalar values
+//! - `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum` and `multipleOf` of numbers
+//! - `items`, `additionalItems`, `minItems`, `maxItems` and `uniqueItems` of arrays
+//! - `properties`, `patternProperties`, `additionalProperties`, `required`, `minProperties` and `maxProperties` of objects
+//! - `allOf`, `anyOf`, `oneOf` and `not`
+//!
+//! The API server doesn't allow the other properties, like `$ref`, in the schema of a custom resource definition, so they're ignored.
+//!
+//! The [`JsonSchema`](trait.JsonSchema.html) trait returns the schema of a Rust type, like the spec of a custom resource.
+//! It's implemented for primitive types, `Option`, `Vec`, `BTreeMap` and other containers, and the generated types that are commonly used
+//! in custom resources, like `ObjectMeta`, `Quantity` and `IntOrString`. It can be derived for structs and enums with
+//! `#[derive(JsonSchema)]` from the `k8s-openapi-derive` crate.
+//!
+//! This module is enabled by the `schema` feature. It uses the `apiextensions/v1beta1` API group of the default version, so it's only compiled
+//! if that version is v1.8 or later and the API group version is enabled, either by the `v1_N` feature or by the `v1_N-apiextensions-v1beta1` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! extern crate k8s_openapi;
+//!
+//! use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1 as apiextensions;
+//! use k8s_openapi::serde_json::json;
+//!
+//! # fn main() -> Result<(), Box<std::error::Error>> {
+//! let schema: apiextensions::JSONSchemaProps = k8s_openapi::serde_json::from_value(json!({
+//!     "properties": {
+//!         "spec": {
+//!             "properties": {
+//!                 "replicas": { "type": "integer", "minimum": 1 },
+//!             },
+//!             "required": ["replicas"],
+//!         },
+//!     },
+//! }))?;
+//!
+//! assert!(k8s_openapi::schema::validate(&schema, &json!({ "spec": { "replicas": 3 } })).is_ok());
+//!
+//! let errors = k8s_openapi::schema::validate(&schema, &json!({ "spec": { "replicas": 0 } })).unwrap_err();
+//! assert_eq!(errors[0].to_string(), "spec.replicas in body should be greater than or equal to 1");
+//! # Ok(())
+//! # }
+//! ```
+
+use serde_json::Value;
+
+use ::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::{ JSONSchemaProps, JSONSchemaPropsOrArray, JSONSchemaPropsOrBool };
+
+/// Validates the given value against the given schema, and returns all the reasons that it's invalid.
+pub fn validate(schema: &JSONSchemaProps, value: &Value) -> Result<(), Vec<ValidationError>> {
+    let mut errors = vec![];
+    validate_value(schema, value, "", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    }
+    else {
+        Err(errors)
+    }
+}
+
+/// A reason that a value is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    /// The path of the invalid field, like `spec.containers.0.name`. An empty string for the value itself.
+    pub path: String,
+
+    /// Why the field is invalid, like `is required`.
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "in body {}", self.message)
+        }
+        else {
+            write!(f, "{} in body {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A type that has a JSON schema, like the spec of a custom resource. See the [module docs](index.html) for details.
+///
+/// The schema of `Option<T>` is the schema of `T`. Whether a property is optional is part of the schema of the object that has the property.
+pub trait JsonSchema {
+    /// Returns the schema of values of this type.
+    fn json_schema() -> JSONSchemaProps;
+}
+
+macro_rules! json_schema_impl {
+    ($($ty:ty => { $($field:ident: $value:expr,)* },)*) => {
+        $(
+            impl JsonSchema for $ty {
+                fn json_schema() -> JSONSchemaProps {
+                    JSONSchemaProps {
+                        $($field: $value,)*
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+json_schema_impl! {
+    bool => { type_: Some("boolean".to_string()), },
+    i8 => { type_: Some("integer".to_string()), },
+    i16 => { type_: Some("integer".to_string()), },
+    i32 => { format: Some("int32".to_string()), type_: Some("integer".to_string()), },
+    i64 => { format: Some("int64".to_string()), type_: Some("integer".to_string()), },
+    u8 => { minimum: Some(0.0), type_: Some("integer".to_string()), },
+    u16 => { minimum: Some(0.0), type_: Some("integer".to_string()), },
+    u32 => { minimum: Some(0.0), type_: Some("integer".to_string()), },
+    u64 => { minimum: Some(0.0), type_: Some("integer".to_string()), },
+    f32 => { format: Some("float".to_string()), type_: Some("number".to_string()), },
+    f64 => { format: Some("double".to_string()), type_: Some("number".to_string()), },
+    str => { type_: Some("string".to_string()), },
+    String => { type_: Some("string".to_string()), },
+    serde_json::Value => { },
+    chrono::DateTime<chrono::Utc> => { format: Some("date-time".to_string()), type_: Some("string".to_string()), },
+    ::ByteString => { format: Some("byte".to_string()), type_: Some("string".to_string()), },
+    ::apimachinery::pkg::api::resource::Quantity => { type_: Some("string".to_string()), },
+    ::apimachinery::pkg::apis::meta::v1::ObjectMeta => { type_: Some("object".to_string()), },
+    ::apimachinery::pkg::apis::meta::v1::Time => { format: Some("date-time".to_string()), type_: Some("string".to_string()), },
+    ::apimachinery::pkg::util::intstr::IntOrString => {
+        any_of: Some(vec![
+            JSONSchemaProps { type_: Some("integer".to_string()), ..Default::default() },
+            JSONSchemaProps { type_: Some("string".to_string()), ..Default::default() },
+        ]),
+    },
+}
+
+impl<'a, T> JsonSchema for &'a T where T: JsonSchema + ?Sized {
+    fn json_schema() -> JSONSchemaProps {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Box<T> where T: JsonSchema + ?Sized {
+    fn json_schema() -> JSONSchemaProps {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Option<T> where T: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for [T] where T: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        JSONSchemaProps {
+            items: Some(JSONSchemaPropsOrArray::Schema(Box::new(T::json_schema()))),
+            type_: Some("array".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T> JsonSchema for Vec<T> where T: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        <[T]>::json_schema()
+    }
+}
+
+impl<T> JsonSchema for std::collections::BTreeSet<T> where T: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        JSONSchemaProps {
+            unique_items: Some(true),
+            ..<[T]>::json_schema()
+        }
+    }
+}
+
+impl<T, S> JsonSchema for std::collections::HashSet<T, S> where T: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        std::collections::BTreeSet::<T>::json_schema()
+    }
+}
+
+impl<V> JsonSchema for std::collections::BTreeMap<String, V> where V: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        JSONSchemaProps {
+            additional_properties: Some(JSONSchemaPropsOrBool::Schema(Box::new(V::json_schema()))),
+            type_: Some("object".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl<V, S> JsonSchema for std::collections::HashMap<String, V, S> where V: JsonSchema {
+    fn json_schema() -> JSONSchemaProps {
+        std::collections::BTreeMap::<String, V>::json_schema()
+    }
+}
+
+fn validate_value(schema: &JSONSchemaProps, value: &Value, path: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(type_) = &schema.type_ {
+        if !has_type(value, type_) {
+            push_error(errors, path, format!("must be of type {}: {:?}", type_, type_name(value)));
+
+            // The other properties of the schema don't apply to a value of the wrong type
+            return;
+        }
+    }
+
+    if let Some(enum_) = &schema.enum_ {
+        if !enum_.iter().any(|allowed| json_equal(&allowed.0, value)) {
+            let allowed: Vec<_> = enum_.iter().map(|allowed| &allowed.0).collect();
+            push_error(errors, path, format!("should be one of {}", serde_json::to_string(&allowed).unwrap_or_default()));
+        }
+    }
+
+    match value {
+        Value::String(value) => validate_string(schema, value, path, errors),
+        Value::Number(value) => if let Some(value) = value.as_f64() { validate_number(schema, value, path, errors) },
+        Value::Array(value) => validate_array(schema, value, path, errors),
+        Value::Object(value) => validate_object(schema, value, path, errors),
+        Value::Bool(_) | Value::Null => (),
+    }
+
+    if let Some(all_of) = &schema.all_of {
+        let mut all_of_errors = vec![];
+        for sub_schema in all_of {
+            validate_value(sub_schema, value, path, &mut all_of_errors);
+        }
+
+        if !all_of_errors.is_empty() {
+            push_error(errors, path, "must validate all the schemas (allOf)".to_string());
+            errors.extend(all_of_errors);
+        }
+    }
+
+    if let Some(any_of) = &schema.any_of {
+        if !any_of.iter().any(|sub_schema| is_valid(sub_schema, value, path)) {
+            push_error(errors, path, "must validate at least one schema (anyOf)".to_string());
+        }
+    }
+
+    if let Some(one_of) = &schema.one_of {
+        if one_of.iter().filter(|sub_schema| is_valid(sub_schema, value, path)).count() != 1 {
+            push_error(errors, path, "must validate one and only one schema (oneOf)".to_string());
+        }
+    }
+
+    if let Some(not) = &schema.not {
+        if is_valid(not, value, path) {
+            push_error(errors, path, "must not validate the schema (not)".to_string());
+        }
+    }
+}
+
+fn validate_string(schema: &JSONSchemaProps, value: &str, path: &str, errors: &mut Vec<ValidationError>) {
+    if schema.max_length.is_some() || schema.min_length.is_some() {
+        let len = value.chars().count() as i64;
+
+        if let Some(max_length) = schema.max_length {
+            if len > max_length {
+                push_error(errors, path, format!("should be at most {} chars long", max_length));
+            }
+        }
+
+        if let Some(min_length) = schema.min_length {
+            if len < min_length {
+                push_error(errors, path, format!("should be at least {} chars long", min_length));
+            }
+        }
+    }
+
+    if let Some(pattern) = &schema.pattern {
+        match regex::Regex::new(pattern) {
+            Ok(regex) => if !regex.is_match(value) {
+                push_error(errors, path, format!("should match '{}'", pattern));
+            },
+
+            Err(err) => push_error(errors, path, format!("has an invalid pattern '{}': {}", pattern, err)),
+        }
+    }
+}
+
+fn validate_number(schema: &JSONSchemaProps, value: f64, path: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(maximum) = schema.maximum {
+        if schema.exclusive_maximum == Some(true) {
+            if value >= maximum {
+                push_error(errors, path, format!("should be less than {}", maximum));
+            }
+        }
+        else if value > maximum {
+            push_error(errors, path, format!("should be less than or equal to {}", maximum));
+        }
+    }
+
+    if let Some(minimum) = schema.minimum {
+        if schema.exclusive_minimum == Some(true) {
+            if value <= minimum {
+                push_error(errors, path, format!("should be greater than {}", minimum));
+            }
+        }
+        else if value < minimum {
+            push_error(errors, path, format!("should be greater than or equal to {}", minimum));
+        }
+    }
+
+    if let Some(multiple_of) = schema.multiple_of {
+        if multiple_of > 0.0 {
+            // Allow for the rounding error of dividing, like 0.3 / 0.1 == 2.9999999999999996
+            let quotient = value / multiple_of;
+            if (quotient - quotient.round()).abs() > 1e-9 * quotient.abs().max(1.0) {
+                push_error(errors, path, format!("should be a multiple of {}", multiple_of));
+            }
+        }
+    }
+}
+
+fn validate_array(schema: &JSONSchemaProps, value: &[Value], path: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(max_items) = schema.max_items {
+        if value.len() as i64 > max_items {
+            push_error(errors, path, format!("should have at most {} items", max_items));
+        }
+    }
+
+    if let Some(min_items) = schema.min_items {
+        if (value.len() as i64) < min_items {
+            push_error(errors, path, format!("should have at least {} items", min_items));
+        }
+    }
+
+    if schema.unique_items == Some(true) {
+        let has_duplicates = value.iter().enumerate().any(|(i, item)| value[(i + 1)..].iter().any(|other| json_equal(item, other)));
+        if has_duplicates {
+            push_error(errors, path, "shouldn't contain duplicates".to_string());
+        }
+    }
+
+    match &schema.items {
+        Some(JSONSchemaPropsOrArray::Schema(items)) =>
+            for (i, item) in value.iter().enumerate() {
+                validate_value(items, item, &child_path(path, &i.to_string()), errors);
+            },
+
+        Some(JSONSchemaPropsOrArray::Schemas(items)) => {
+            for (i, (item_schema, item)) in items.iter().zip(value).enumerate() {
+                validate_value(item_schema, item, &child_path(path, &i.to_string()), errors);
+            }
+
+            for (i, item) in value.iter().enumerate().skip(items.len()) {
+                match &schema.additional_items {
+                    Some(JSONSchemaPropsOrBool::Bool(false)) => {
+                        push_error(errors, path, "can't have additional items".to_string());
+                        break;
+                    },
+
+                    Some(JSONSchemaPropsOrBool::Schema(additional_items)) =>
+                        validate_value(additional_items, item, &child_path(path, &i.to_string()), errors),
+
+                    Some(JSONSchemaPropsOrBool::Bool(true)) | None => break,
+                }
+            }
+        },
+
+        None => (),
+    }
+}
+
+fn validate_object(schema: &JSONSchemaProps, value: &serde_json::Map<String, Value>, path: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(max_properties) = schema.max_properties {
+        if value.len() as i64 > max_properties {
+            push_error(errors, path, format!("should have at most {} properties", max_properties));
+        }
+    }
+
+    if let Some(min_properties) = schema.min_properties {
+        if (value.len() as i64) < min_properties {
+            push_error(errors, path, format!("should have at least {} properties", min_properties));
+        }
+    }
+
+    if let Some(required) = &schema.required {
+        for name in required {
+            if !value.contains_key(name) {
+                push_error(errors, &child_path(path, name), "is required".to_string());
+            }
+        }
+    }
+
+    let pattern_properties: Vec<_> = match &schema.pattern_properties {
+        Some(pattern_properties) =>
+            pattern_properties.iter()
+            .filter_map(|(pattern, property_schema)| match regex::Regex::new(pattern) {
+                Ok(regex) => Some((regex, property_schema)),
+                Err(err) => {
+                    push_error(errors, path, format!("has an invalid pattern property '{}': {}", pattern, err));
+                    None
+                },
+            })
+            .collect(),
+        None => vec![],
+    };
+
+    for (name, property) in value {
+        let property_path = child_path(path, name);
+        let mut is_additional = true;
+
+        if let Some(property_schema) = schema.properties.as_ref().and_then(|properties| properties.get(name)) {
+            validate_value(property_schema, property, &property_path, errors);
+            is_additional = false;
+        }
+
+        for (regex, property_schema) in &pattern_properties {
+            if regex.is_match(name) {
+                validate_value(property_schema, property, &property_path, errors);
+                is_additional = false;
+            }
+        }
+
+        if is_additional {
+            match &schema.additional_properties {
+                Some(JSONSchemaPropsOrBool::Bool(false)) => push_error(errors, &property_path, "is a forbidden property".to_string()),
+                Some(JSONSchemaPropsOrBool::Schema(additional_properties)) => validate_value(additional_properties, property, &property_path, errors),
+                Some(JSONSchemaPropsOrBool::Bool(true)) | None => (),
+            }
+        }
+    }
+}
+
+fn is_valid(schema: &JSONSchemaProps, value: &Value, path: &str) -> bool {
+    let mut errors = vec![];
+    validate_value(schema, value, path, &mut errors);
+    errors.is_empty()
+}
+
+fn has_type(value: &Value, type_: &str) -> bool {
+    match (type_, value) {
+        ("array", Value::Array(_)) |
+        ("boolean", Value::Bool(_)) |
+        ("null", Value::Null) |
+        ("number", Value::Number(_)) |
+        ("object", Value::Object(_)) |
+        ("string", Value::String(_)) => true,
+        ("integer", Value::Number(value)) => is_integer(value),
+        _ => false,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Array(_) => "array",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+        Value::Number(value) if is_integer(value) => "integer",
+        Value::Number(_) => "number",
+        Value::Object(_) => "object",
+        Value::String(_) => "string",
+    }
+}
+
+fn is_integer(value: &serde_json::Number) -> bool {
+    value.is_i64() || value.is_u64() || matches!(value.as_f64(), Some(value) if value.fract() == 0.0)
+}
+
+/// Compares JSON values like the API server does, ie numbers are equal if they have the same value regardless of how they're written.
+fn json_equal(value1: &Value, value2: &Value) -> bool {
+    match (value1, value2) {
+        (Value::Number(value1), Value::Number(value2)) => value1 == value2 || value1.as_f64() == value2.as_f64(),
+        (Value::Array(value1), Value::Array(value2)) =>
+            value1.len() == value2.len() && value1.iter().zip(value2).all(|(value1, value2)| json_equal(value1, value2)),
+        (Value::Object(value1), Value::Object(value2)) =>
+            value1.len() == value2.len() && value1.iter().all(|(key, value1)| matches!(value2.get(key), Some(value2) if json_equal(value1, value2))),
+        (value1, value2) => value1 == value2,
+    }
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    }
+    else {
+        format!("{}.{}", path, name)
+    }
+}
+
+fn push_error(errors: &mut Vec<ValidationError>, path: &str, message: String) {
+    errors.push(ValidationError {
+        path: path.to_string(),
+        message,
+    });
+}
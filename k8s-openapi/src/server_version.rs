@@ -0,0 +1,345 @@
+//! Detecting the version of the API server at runtime, and dispatching to the version module that matches it.
+//!
+//! Any number of the `v1_*` features can be enabled together. Each enabled version's module, like `::v1_9`, is compiled,
+//! and the highest one is also re-exported at the crate root. A program that talks to clusters of different versions
+//! can enable the features of all of them and select the module to use for each cluster at runtime:
+//!
+//! 1. Prepare the request with [`ServerVersion::request`](struct.ServerVersion.html#method.request), which is the `get_code_version` API operation,
+//!    and parse its response with [`ServerVersion::from_response`](struct.ServerVersion.html#method.from_response).
+//!
+//! 1. Select the enabled version that matches the server with [`Version::for_server`](enum.Version.html#method.for_server).
+//!
+//! 1. Implement the [`Dispatch`](trait.Dispatch.html) trait, which has one method for each enabled version, and call
+//!    [`Version::dispatch`](enum.Version.html#method.dispatch) to run the method of the selected version.
+//!
+//! Only the enabled versions have a variant in [`Version`](enum.Version.html) and a method in [`Dispatch`](trait.Dispatch.html).
+//! Since another crate in the same program can enable more versions, each method of `Dispatch` defaults to calling its required
+//! [`unsupported`](trait.Dispatch.html#tymethod.unsupported) method, and `Version` has a hidden variant so that a `match` on it
+//! needs a wildcard arm. So code written against some versions continues to compile when more of them are enabled.
+//!
+//! # Examples
+//!
+//! With the `v1_11` and `v1_12` features enabled:
+//!
+//! ```rust,ignore
+//! extern crate k8s_openapi;
+//!
+//! use k8s_openapi::http;
+//! use k8s_openapi::server_version::{ Dispatch, ServerVersion, Version };
+//!
+//! // `execute` is some function that executes the request and parses the response,
+//! // like the one in the crate root's example.
+//! fn execute<R>(request: http::Request<Vec<u8>>) -> (http::StatusCode, R) { unimplemented!(); }
+//!
+//! struct ListPods<'a> {
+//!     namespace: &'a str,
+//! }
+//!
+//! impl<'a> Dispatch for ListPods<'a> {
+//!     type Output = Result<Vec<String>, Box<std::error::Error>>;
+//!
+//!     fn unsupported(self, version: Version) -> Self::Output {
+//!         Err(format!("listing pods is not implemented for {}", version).into())
+//!     }
+//!
+//!     fn v1_11(self) -> Self::Output {
+//!         use k8s_openapi::v1_11::api::core::v1 as api;
+//!
+//!         let request = api::Pod::list_core_v1_namespaced_pod(self.namespace, None, None, None, None, None, None, None, None, None)?;
+//!         match execute(request) {
+//!             (_, api::ListCoreV1NamespacedPodResponse::Ok(pod_list)) =>
+//!                 Ok(pod_list.items.into_iter().filter_map(|pod| pod.metadata?.name).collect()),
+//!             (status_code, _) => Err(format!("unexpected response {}", status_code).into()),
+//!         }
+//!     }
+//!
+//!     fn v1_12(self) -> Self::Output {
+//!         use k8s_openapi::v1_12::api::core::v1 as api;
+//!
+//!         let request = api::Pod::list_core_v1_namespaced_pod(self.namespace, None, None, None, None, None, None, None, None, None)?;
+//!         match execute(request) {
+//!             (_, api::ListCoreV1NamespacedPodResponse::Ok(pod_list)) =>
+//!                 Ok(pod_list.items.into_iter().filter_map(|pod| pod.metadata?.name).collect()),
+//!             (status_code, _) => Err(format!("unexpected response {}", status_code).into()),
+//!         }
+//!     }
+//! }
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let (status_code, response) = execute(ServerVersion::request()?);
+//!     let server_version = ServerVersion::from_response(status_code, response)?;
+//!
+//!     let version = Version::for_server(&server_version)?;
+//!     let pods = version.dispatch(ListPods { namespace: "kube-system" })?;
+//!     println!("{:?}", pods);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// The version of the API server, as reported by its `/version` endpoint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServerVersion {
+    /// The major version, like `1`.
+    pub major: u32,
+
+    /// The minor version, like `12`.
+    pub minor: u32,
+
+    /// The full version, like `v1.12.3` or `v1.12.3-gke.1`.
+    pub git_version: String,
+}
+
+impl ServerVersion {
+    /// Returns the request of the `get_code_version` API operation, whose response is parsed by [`from_response`](#method.from_response).
+    pub fn request() -> Result<http::Request<Vec<u8>>, ::RequestError> {
+        ::get_code_version()
+    }
+
+    /// Parses the server version from the response of the request returned by [`request`](#method.request).
+    pub fn from_response(status_code: http::StatusCode, response: ::GetCodeVersionResponse) -> Result<Self, Error> {
+        match response {
+            ::GetCodeVersionResponse::Ok(info) => ServerVersion::from_info(&info),
+            _ => Err(Error::UnexpectedResponse(status_code)),
+        }
+    }
+
+    /// Parses the server version from the given `version::Info`.
+    ///
+    /// Some providers set `minor` to a value like `12+`, so the leading digits of `major` and `minor` are used.
+    /// If either of them is empty, the version is parsed from `gitVersion` instead.
+    pub fn from_info(info: &::apimachinery::pkg::version::Info) -> Result<Self, Error> {
+        fn parse_leading_digits(s: &str) -> Option<u32> {
+            let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+            s[..end].parse().ok()
+        }
+
+        let (major, minor) = match (parse_leading_digits(&info.major), parse_leading_digits(&info.minor)) {
+            (Some(major), Some(minor)) => (major, minor),
+            _ => {
+                let mut parts = info.git_version.trim_start_matches('v').splitn(3, '.');
+                match (parts.next().and_then(parse_leading_digits), parts.next().and_then(parse_leading_digits)) {
+                    (Some(major), Some(minor)) => (major, minor),
+                    _ => return Err(Error::InvalidVersion(info.git_version.clone())),
+                }
+            },
+        };
+
+        Ok(ServerVersion {
+            major,
+            minor,
+            git_version: info.git_version.clone(),
+        })
+    }
+}
+
+/// One of the enabled versions, whose module is compiled.
+///
+/// Other crates can enable more versions, so a `match` on a `Version` must have a wildcard arm.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Version {
+    #[cfg(k8s_openapi_v1_7)]
+    V1_7,
+
+    #[cfg(k8s_openapi_v1_8)]
+    V1_8,
+
+    #[cfg(k8s_openapi_v1_9)]
+    V1_9,
+
+    #[cfg(k8s_openapi_v1_10)]
+    V1_10,
+
+    #[cfg(k8s_openapi_v1_11)]
+    V1_11,
+
+    #[cfg(k8s_openapi_v1_12)]
+    V1_12,
+
+    // Never constructed. It makes matches on this enum need a wildcard arm, so that they still compile when more versions are enabled.
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
+impl Version {
+    /// Returns the enabled versions, from lowest to highest.
+    pub fn enabled() -> &'static [Version] {
+        &[
+            #[cfg(k8s_openapi_v1_7)]
+            Version::V1_7,
+
+            #[cfg(k8s_openapi_v1_8)]
+            Version::V1_8,
+
+            #[cfg(k8s_openapi_v1_9)]
+            Version::V1_9,
+
+            #[cfg(k8s_openapi_v1_10)]
+            Version::V1_10,
+
+            #[cfg(k8s_openapi_v1_11)]
+            Version::V1_11,
+
+            #[cfg(k8s_openapi_v1_12)]
+            Version::V1_12,
+        ]
+    }
+
+    /// The minor version of Kubernetes that this version's module was generated from, like `12` for `V1_12`.
+    pub fn minor(self) -> u32 {
+        match self {
+            #[cfg(k8s_openapi_v1_7)]
+            Version::V1_7 => 7,
+
+            #[cfg(k8s_openapi_v1_8)]
+            Version::V1_8 => 8,
+
+            #[cfg(k8s_openapi_v1_9)]
+            Version::V1_9 => 9,
+
+            #[cfg(k8s_openapi_v1_10)]
+            Version::V1_10 => 10,
+
+            #[cfg(k8s_openapi_v1_11)]
+            Version::V1_11 => 11,
+
+            #[cfg(k8s_openapi_v1_12)]
+            Version::V1_12 => 12,
+
+            Version::__Nonexhaustive => unreachable!("Version::__Nonexhaustive is never constructed"),
+        }
+    }
+
+    /// Selects the enabled version to use with the given server.
+    ///
+    /// This is the server's own version if it's enabled. Otherwise it's the highest enabled version that is lower than the server's,
+    /// since the API server continues to serve the API groups and fields of the previous versions. A server that is older than
+    /// all the enabled versions is not supported, since it might not serve the API groups and fields of any of them.
+    pub fn for_server(server_version: &ServerVersion) -> Result<Self, Error> {
+        if server_version.major != 1 {
+            return Err(Error::UnsupportedVersion(server_version.clone()));
+        }
+
+        Version::enabled().iter()
+            .cloned()
+            .filter(|version| version.minor() <= server_version.minor)
+            .max()
+            .ok_or_else(|| Error::UnsupportedVersion(server_version.clone()))
+    }
+
+    /// Calls the method of the given `Dispatch` that corresponds to this version, and returns its result.
+    pub fn dispatch<D>(self, dispatch: D) -> D::Output where D: Dispatch {
+        match self {
+            #[cfg(k8s_openapi_v1_7)]
+            Version::V1_7 => dispatch.v1_7(),
+
+            #[cfg(k8s_openapi_v1_8)]
+            Version::V1_8 => dispatch.v1_8(),
+
+            #[cfg(k8s_openapi_v1_9)]
+            Version::V1_9 => dispatch.v1_9(),
+
+            #[cfg(k8s_openapi_v1_10)]
+            Version::V1_10 => dispatch.v1_10(),
+
+            #[cfg(k8s_openapi_v1_11)]
+            Version::V1_11 => dispatch.v1_11(),
+
+            #[cfg(k8s_openapi_v1_12)]
+            Version::V1_12 => dispatch.v1_12(),
+
+            Version::__Nonexhaustive => unreachable!("Version::__Nonexhaustive is never constructed"),
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "v1_{}", self.minor())
+    }
+}
+
+/// Code that uses the API of one of the enabled versions, selected at runtime by [`Version::dispatch`](enum.Version.html#method.dispatch).
+///
+/// There is one method for each enabled version, which would usually use the types and API functions of that version's module, like `::v1_12`.
+/// Only the enabled versions have a method, and each of them defaults to calling [`unsupported`](#tymethod.unsupported).
+/// So an implementation only needs to implement the methods of the versions it supports, and continues to compile when more versions are enabled.
+pub trait Dispatch: Sized {
+    /// The type returned by the methods.
+    type Output;
+
+    /// Runs the code against a version that this implementation doesn't have a method for.
+    fn unsupported(self, version: Version) -> Self::Output;
+
+    /// Runs the code against the `v1_7` module.
+    #[cfg(k8s_openapi_v1_7)]
+    fn v1_7(self) -> Self::Output {
+        self.unsupported(Version::V1_7)
+    }
+
+    /// Runs the code against the `v1_8` module.
+    #[cfg(k8s_openapi_v1_8)]
+    fn v1_8(self) -> Self::Output {
+        self.unsupported(Version::V1_8)
+    }
+
+    /// Runs the code against the `v1_9` module.
+    #[cfg(k8s_openapi_v1_9)]
+    fn v1_9(self) -> Self::Output {
+        self.unsupported(Version::V1_9)
+    }
+
+    /// Runs the code against the `v1_10` module.
+    #[cfg(k8s_openapi_v1_10)]
+    fn v1_10(self) -> Self::Output {
+        self.unsupported(Version::V1_10)
+    }
+
+    /// Runs the code against the `v1_11` module.
+    #[cfg(k8s_openapi_v1_11)]
+    fn v1_11(self) -> Self::Output {
+        self.unsupported(Version::V1_11)
+    }
+
+    /// Runs the code against the `v1_12` module.
+    #[cfg(k8s_openapi_v1_12)]
+    fn v1_12(self) -> Self::Output {
+        self.unsupported(Version::V1_12)
+    }
+}
+
+/// The type of errors from detecting the version of the API server.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The `version::Info` returned by the server doesn't have a version of the form `1.12`. The value is its `gitVersion`.
+    InvalidVersion(String),
+
+    /// The server responded to the request with an unsuccessful status code.
+    UnexpectedResponse(http::StatusCode),
+
+    /// The server is older than all the enabled versions, or isn't a 1.x server.
+    UnsupportedVersion(ServerVersion),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidVersion(git_version) => write!(f, "invalid version {:?}", git_version),
+            Error::UnexpectedResponse(status_code) => write!(f, "unexpected response {}", status_code),
+            Error::UnsupportedVersion(server_version) => write!(
+                f,
+                "server version {}.{} is not supported by the enabled versions {:?}",
+                server_version.major, server_version.minor, Version::enabled().iter().map(ToString::to_string).collect::<Vec<_>>()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::InvalidVersion(_) => "invalid version",
+            Error::UnexpectedResponse(_) => "unexpected response",
+            Error::UnsupportedVersion(_) => "unsupported version",
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Parsing a stream of HTTP response body chunks into a stream of responses, like the events of a watch or the lines of a pod's logs.
+//!
+//! A [`ResponseStream`](struct.ResponseStream.html) wraps any `futures::Stream` of body chunks, like a `hyper::Body`, and implements `futures::Stream`
+//! itself. Each item is a value of a Kubernetes API function's response type, parsed from the chunks received so far.
+//! It takes care of buffering the chunks and handling `ResponseError::NeedMoreData`, so that asynchronous clients don't need to.
+//!
+//! This module is enabled by the `stream` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! #[macro_use] extern crate k8s_openapi;
+//! extern crate futures;
+//!
+//! use futures::{ Future, Stream };
+//! use k8s_openapi::http;
+//! use k8s_openapi::stream::ResponseStream;
+//!
+//! use k8s_openapi::api::core::v1 as api;
+//!
+//! fn main() {
+//!     // The chunks of the response body, as they would be received from an HTTP client like `hyper`.
+//!     // A chunk can have part of an event, or more than one event.
+//!     let body = futures::stream::iter_ok::<_, std::io::Error>(vec![
+//!         &br#"{"type":"ADDED","object":{"metadata":{"name":"#[..],
+//!         &br#""foo"}}}
+//!         {"type":"DELETED","object":{"metadata":{"name":"foo"}}}
+//!         "#[..],
+//!     ]);
+//!
+//!     let events: ResponseStream<_, api::WatchCoreV1NamespacedPodListResponse> = ResponseStream::new(http::StatusCode::OK, body);
+//!     let events = events.collect().wait().unwrap();
+//!     assert_eq!(events.len(), 2);
+//! }
+//! ```
+
+/// A `futures::Stream` of the responses parsed from a stream of HTTP response body chunks. See the [module docs](index.html) for details.
+///
+/// Responses that don't consume any of the response body, like the `Unauthorized` and `Other` variants of the response types, are only returned
+/// once the inner stream has ended, and are then the last item of the stream.
+pub struct ResponseStream<S, R> {
+    inner: S,
+    response_body: ::ResponseBody,
+    inner_done: bool,
+    done: bool,
+    _response: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<S, R> ResponseStream<S, R> where S: futures::Stream, S::Item: AsRef<[u8]>, R: ::Response {
+    /// Constructs a stream of responses for a response that has the given HTTP status code and whose body is received as the chunks of `inner`.
+    pub fn new(status_code: http::StatusCode, inner: S) -> Self {
+        ResponseStream {
+            inner,
+            response_body: ::ResponseBody::new(status_code),
+            inner_done: false,
+            done: false,
+            _response: Default::default(),
+        }
+    }
+
+    /// Consumes this stream and returns the inner stream. Any chunks that were received but not yet parsed are discarded.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, R> futures::Stream for ResponseStream<S, R> where S: futures::Stream, S::Item: AsRef<[u8]>, R: ::Response {
+    type Item = R;
+    type Error = Error<S::Error>;
+
+    fn poll(&mut self) -> futures::Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            if self.done {
+                return Ok(futures::Async::Ready(None));
+            }
+
+            if self.inner_done {
+                self.done = true;
+
+                // The body is complete. If there's nothing left but whitespace, like the newline after the last event of a watch,
+                // this is the end of the stream, unless the status code indicates an error response that hasn't been returned yet.
+                if self.response_body.iter().all(u8::is_ascii_whitespace) && self.response_body.status_code.is_success() {
+                    return Ok(futures::Async::Ready(None));
+                }
+
+                let (result, read) = R::try_from_parts(self.response_body.status_code, &self.response_body).map_err(Error::Response)?;
+                self.response_body.buf.advance(read);
+                self.done = read == 0 || self.response_body.is_empty();
+                return Ok(futures::Async::Ready(Some(result)));
+            }
+
+            // Try to parse the buffered data before waiting for more. A response that consumed no data may just be waiting for more
+            // (like an empty string from an incomplete UTF-8 sequence), so it's deferred until the body is complete.
+            if !self.response_body.is_empty() {
+                match self.response_body.parse() {
+                    Ok((result, read)) if read > 0 => return Ok(futures::Async::Ready(Some(result))),
+                    Ok(_) | Err(::ResponseError::NeedMoreData) => (),
+                    Err(err) => {
+                        self.done = true;
+                        return Err(Error::Response(err));
+                    },
+                }
+            }
+
+            match self.inner.poll() {
+                Ok(futures::Async::Ready(Some(chunk))) => self.response_body.append_slice(chunk.as_ref()),
+                Ok(futures::Async::Ready(None)) => self.inner_done = true,
+                Ok(futures::Async::NotReady) => return Ok(futures::Async::NotReady),
+                Err(err) => {
+                    self.done = true;
+                    return Err(Error::Stream(err));
+                },
+            }
+        }
+    }
+}
+
+/// The type of errors from a [`ResponseStream`](struct.ResponseStream.html).
+#[derive(Debug)]
+pub enum Error<E> {
+    /// An error from the inner stream of response body chunks.
+    Stream(E),
+
+    /// An error from parsing the response body. `ResponseError::NeedMoreData` indicates the response body ended in the middle of a response.
+    Response(::ResponseError),
+}
+
+impl<E> std::fmt::Display for Error<E> where E: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Stream(err) => write!(f, "{}", err),
+            Error::Response(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<E> std::error::Error for Error<E> where E: std::error::Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::Stream(err) => err.description(),
+            Error::Response(err) => err.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&std::error::Error> {
+        match self {
+            Error::Stream(err) => Some(err),
+            Error::Response(err) => Some(err),
+        }
+    }
+}
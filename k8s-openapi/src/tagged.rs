@@ -0,0 +1,111 @@
+//! Single-pass deserialization of tagged unions, ie JSON objects whose type depends on the values of some of their fields, like `kind`.
+//!
+//! The fields of the object are read until all the tag fields have been found. Only the fields before the tag fields are buffered,
+//! which is usually none of them since the API server writes `apiVersion` and `kind` first. The type chosen by the tags is then deserialized
+//! from the buffered fields followed by the rest of the object, without buffering the whole object as a `serde_json::Value` first.
+
+/// A type that is deserialized from an object as one of several types, depending on the string values of the object's `TAGS` fields.
+pub trait Tagged<'de>: Sized {
+    /// The names of the tag fields.
+    const TAGS: &'static [&'static str];
+
+    /// Deserializes the object from the given deserializer, given the values of its tag fields in the same order as `TAGS`.
+    fn deserialize_tagged<D>(tags: &[Option<String>], deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de>;
+}
+
+/// Deserializes a tagged union in a single pass. Used as the implementation of `serde::Deserialize::deserialize` for `T`.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error> where D: serde::Deserializer<'de>, T: Tagged<'de> {
+    struct Visitor<T>(std::marker::PhantomData<T>);
+
+    impl<'de, T> serde::de::Visitor<'de> for Visitor<T> where T: Tagged<'de> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "an object with fields {:?}", T::TAGS)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+            let mut tags = vec![None; T::TAGS.len()];
+            let mut buffered = vec![];
+
+            while tags.iter().any(Option::is_none) {
+                let key: String = match serde::de::MapAccess::next_key(&mut map)? {
+                    Some(key) => key,
+                    None => break,
+                };
+
+                let value: serde_json::Value = serde::de::MapAccess::next_value(&mut map)?;
+
+                if let Some(i) = T::TAGS.iter().position(|tag| *tag == key) {
+                    if let serde_json::Value::String(value) = &value {
+                        tags[i] = Some(value.clone());
+                    }
+                }
+
+                buffered.push((key, value));
+            }
+
+            T::deserialize_tagged(&tags, serde::de::value::MapAccessDeserializer::new(Replay {
+                buffered: buffered.into_iter(),
+                value: None,
+                rest: map,
+            }))
+        }
+    }
+
+    deserializer.deserialize_map(Visitor(Default::default()))
+}
+
+/// A `serde::de::MapAccess` that returns the buffered fields, followed by the fields that haven't been read from the inner `MapAccess` yet.
+struct Replay<A> {
+    buffered: std::vec::IntoIter<(String, serde_json::Value)>,
+    value: Option<serde_json::Value>,
+    rest: A,
+}
+
+impl<'de, A> serde::de::MapAccess<'de> for Replay<A> where A: serde::de::MapAccess<'de> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> where K: serde::de::DeserializeSeed<'de> {
+        match self.buffered.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(serde::de::IntoDeserializer::into_deserializer(key)).map(Some)
+            },
+
+            None => self.rest.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value).map_err(serde::de::Error::custom),
+            None => self.rest.next_value_seed(seed),
+        }
+    }
+}
+
+/// The value returned by a DELETE operation, which is either a `Status` or the object, depending on whether the object was deleted immediately.
+///
+/// The type of the `Status` is a parameter so that the code of each version module uses its own `Status`, and defaults to the one at the crate root.
+pub enum StatusOr<T, S = ::apimachinery::pkg::apis::meta::v1::Status> {
+    Status(S),
+    Value(T),
+}
+
+impl<'de, T, S> Tagged<'de> for StatusOr<T, S> where T: serde::Deserialize<'de>, S: serde::Deserialize<'de> {
+    const TAGS: &'static [&'static str] = &["kind"];
+
+    fn deserialize_tagged<D>(tags: &[Option<String>], deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        match tags[0].as_ref().map(String::as_str) {
+            Some("Status") => Ok(StatusOr::Status(serde::Deserialize::deserialize(deserializer)?)),
+            _ => Ok(StatusOr::Value(serde::Deserialize::deserialize(deserializer)?)),
+        }
+    }
+}
+
+impl<'de, T, S> serde::Deserialize<'de> for StatusOr<T, S> where T: serde::Deserialize<'de>, S: serde::Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        deserialize(deserializer)
+    }
+}
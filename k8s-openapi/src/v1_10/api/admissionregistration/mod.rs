@@ -1,3 +1,5 @@
+#[cfg(feature = "v1_10-admissionregistration-v1alpha1")]
 pub mod v1alpha1;
 
+#[cfg(feature = "v1_10-admissionregistration-v1beta1")]
 pub mod v1beta1;
@@ -20,6 +20,7 @@ pub struct MutatingWebhookConfiguration {
 
 // Generated from operation createAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// create a MutatingWebhookConfiguration
     ///
@@ -50,6 +51,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::create_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.create_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::MutatingWebhookConfiguration),
@@ -59,6 +61,7 @@ pub enum CreateAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -90,10 +93,15 @@ impl ::Response for CreateAdmissionregistrationV1beta1MutatingWebhookConfigurati
             _ => Ok((CreateAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// delete collection of MutatingWebhookConfiguration
     ///
@@ -187,6 +195,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::delete_admissionregistration_v1beta1_collection_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.delete_admissionregistration_v1beta1_collection_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -195,38 +204,34 @@ pub enum DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfiguratio
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse::Unauthorized, 0)),
             _ => Ok((DeleteAdmissionregistrationV1beta1CollectionMutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// delete a MutatingWebhookConfiguration
     ///
@@ -285,6 +290,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::delete_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.delete_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -293,38 +299,34 @@ pub enum DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Unauthorized, 0)),
             _ => Ok((DeleteAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// list or watch objects of kind MutatingWebhookConfiguration
     ///
@@ -418,6 +420,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::list_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.list_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::MutatingWebhookConfigurationList),
@@ -425,6 +428,7 @@ pub enum ListAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -440,10 +444,27 @@ impl ::Response for ListAdmissionregistrationV1beta1MutatingWebhookConfiguration
             _ => Ok((ListAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
+    type List = ::v1_10::api::admissionregistration::v1beta1::MutatingWebhookConfigurationList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// partially update the specified MutatingWebhookConfiguration
     ///
@@ -479,6 +500,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::patch_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.patch_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::MutatingWebhookConfiguration),
@@ -486,6 +508,7 @@ pub enum PatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -501,10 +524,15 @@ impl ::Response for PatchAdmissionregistrationV1beta1MutatingWebhookConfiguratio
             _ => Ok((PatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// read the specified MutatingWebhookConfiguration
     ///
@@ -553,6 +581,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::read_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.read_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::MutatingWebhookConfiguration),
@@ -560,6 +589,7 @@ pub enum ReadAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -575,10 +605,15 @@ impl ::Response for ReadAdmissionregistrationV1beta1MutatingWebhookConfiguration
             _ => Ok((ReadAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// replace the specified MutatingWebhookConfiguration
     ///
@@ -614,6 +649,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::replace_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.replace_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::MutatingWebhookConfiguration),
@@ -622,6 +658,7 @@ pub enum ReplaceAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +682,15 @@ impl ::Response for ReplaceAdmissionregistrationV1beta1MutatingWebhookConfigurat
             _ => Ok((ReplaceAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAdmissionregistrationV1beta1MutatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// watch changes to an object of kind MutatingWebhookConfiguration
     ///
@@ -747,6 +789,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::watch_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.MutatingWebhookConfiguration.html#method.watch_admissionregistration_v1beta1_mutating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -754,27 +797,31 @@ pub enum WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Unauthorized, 0)),
             _ => Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAdmissionregistrationV1beta1MutatingWebhookConfigurationList
 
+#[cfg(feature = "api")]
 impl MutatingWebhookConfiguration {
     /// watch individual changes to a list of MutatingWebhookConfiguration
     ///
@@ -868,6 +915,7 @@ impl MutatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`MutatingWebhookConfiguration::watch_admissionregistration_v1beta1_mutating_webhook_configuration_list`](./struct.MutatingWebhookConfiguration.html#method.watch_admissionregistration_v1beta1_mutating_webhook_configuration_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -875,23 +923,26 @@ pub enum WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListRespon
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListResponse::Unauthorized, 0)),
             _ => Ok((WatchAdmissionregistrationV1beta1MutatingWebhookConfigurationListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End admissionregistration.k8s.io/v1beta1/MutatingWebhookConfiguration
@@ -1005,3 +1056,25 @@ impl ::serde::Serialize for MutatingWebhookConfiguration {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`MutatingWebhookConfiguration`](./struct.MutatingWebhookConfiguration.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MutatingWebhookConfigurationField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for MutatingWebhookConfigurationField {
+    fn path(&self) -> &'static str {
+        match self {
+            MutatingWebhookConfigurationField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(MutatingWebhookConfigurationField::MetadataName),
+            _ => None,
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub struct ValidatingWebhookConfiguration {
 
 // Generated from operation createAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// create a ValidatingWebhookConfiguration
     ///
@@ -50,6 +51,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::create_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.create_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::ValidatingWebhookConfiguration),
@@ -59,6 +61,7 @@ pub enum CreateAdmissionregistrationV1beta1ValidatingWebhookConfigurationRespons
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -90,10 +93,15 @@ impl ::Response for CreateAdmissionregistrationV1beta1ValidatingWebhookConfigura
             _ => Ok((CreateAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// delete collection of ValidatingWebhookConfiguration
     ///
@@ -187,6 +195,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::delete_admissionregistration_v1beta1_collection_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.delete_admissionregistration_v1beta1_collection_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -195,38 +204,34 @@ pub enum DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurat
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse::Unauthorized, 0)),
             _ => Ok((DeleteAdmissionregistrationV1beta1CollectionValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// delete a ValidatingWebhookConfiguration
     ///
@@ -285,6 +290,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::delete_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.delete_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -293,38 +299,34 @@ pub enum DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationRespons
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Unauthorized, 0)),
             _ => Ok((DeleteAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// list or watch objects of kind ValidatingWebhookConfiguration
     ///
@@ -418,6 +420,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::list_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.list_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::ValidatingWebhookConfigurationList),
@@ -425,6 +428,7 @@ pub enum ListAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -440,10 +444,27 @@ impl ::Response for ListAdmissionregistrationV1beta1ValidatingWebhookConfigurati
             _ => Ok((ListAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
+    type List = ::v1_10::api::admissionregistration::v1beta1::ValidatingWebhookConfigurationList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// partially update the specified ValidatingWebhookConfiguration
     ///
@@ -479,6 +500,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::patch_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.patch_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::ValidatingWebhookConfiguration),
@@ -486,6 +508,7 @@ pub enum PatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -501,10 +524,15 @@ impl ::Response for PatchAdmissionregistrationV1beta1ValidatingWebhookConfigurat
             _ => Ok((PatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// read the specified ValidatingWebhookConfiguration
     ///
@@ -553,6 +581,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::read_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.read_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::ValidatingWebhookConfiguration),
@@ -560,6 +589,7 @@ pub enum ReadAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -575,10 +605,15 @@ impl ::Response for ReadAdmissionregistrationV1beta1ValidatingWebhookConfigurati
             _ => Ok((ReadAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// replace the specified ValidatingWebhookConfiguration
     ///
@@ -614,6 +649,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::replace_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.replace_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     Ok(::v1_10::api::admissionregistration::v1beta1::ValidatingWebhookConfiguration),
@@ -622,6 +658,7 @@ pub enum ReplaceAdmissionregistrationV1beta1ValidatingWebhookConfigurationRespon
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +682,15 @@ impl ::Response for ReplaceAdmissionregistrationV1beta1ValidatingWebhookConfigur
             _ => Ok((ReplaceAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAdmissionregistrationV1beta1ValidatingWebhookConfiguration
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// watch changes to an object of kind ValidatingWebhookConfiguration
     ///
@@ -747,6 +789,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::watch_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.ValidatingWebhookConfiguration.html#method.watch_admissionregistration_v1beta1_validating_webhook_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -754,27 +797,31 @@ pub enum WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Unauthorized, 0)),
             _ => Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAdmissionregistrationV1beta1ValidatingWebhookConfigurationList
 
+#[cfg(feature = "api")]
 impl ValidatingWebhookConfiguration {
     /// watch individual changes to a list of ValidatingWebhookConfiguration
     ///
@@ -868,6 +915,7 @@ impl ValidatingWebhookConfiguration {
 }
 
 /// Parses the HTTP response of [`ValidatingWebhookConfiguration::watch_admissionregistration_v1beta1_validating_webhook_configuration_list`](./struct.ValidatingWebhookConfiguration.html#method.watch_admissionregistration_v1beta1_validating_webhook_configuration_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -875,23 +923,26 @@ pub enum WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResp
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResponse::Unauthorized, 0)),
             _ => Ok((WatchAdmissionregistrationV1beta1ValidatingWebhookConfigurationListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End admissionregistration.k8s.io/v1beta1/ValidatingWebhookConfiguration
@@ -1005,3 +1056,25 @@ impl ::serde::Serialize for ValidatingWebhookConfiguration {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ValidatingWebhookConfiguration`](./struct.ValidatingWebhookConfiguration.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValidatingWebhookConfigurationField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for ValidatingWebhookConfigurationField {
+    fn path(&self) -> &'static str {
+        match self {
+            ValidatingWebhookConfigurationField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ValidatingWebhookConfigurationField::MetadataName),
+            _ => None,
+        }
+    }
+}
@@ -134,3 +134,16 @@ impl ::serde::Serialize for DaemonSetSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::DaemonSetSpec> for DaemonSetSpec {
+    fn from(value: ::v1_10::api::apps::v1beta2::DaemonSetSpec) -> Self {
+        DaemonSetSpec {
+            min_ready_seconds: value.min_ready_seconds,
+            revision_history_limit: value.revision_history_limit,
+            selector: value.selector,
+            template: value.template,
+            update_strategy: value.update_strategy.map(Into::into),
+        }
+    }
+}
@@ -173,3 +173,19 @@ impl ::serde::Serialize for DeploymentSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::DeploymentSpec> for DeploymentSpec {
+    fn from(value: ::v1_10::api::apps::v1beta2::DeploymentSpec) -> Self {
+        DeploymentSpec {
+            min_ready_seconds: value.min_ready_seconds,
+            paused: value.paused,
+            progress_deadline_seconds: value.progress_deadline_seconds,
+            replicas: value.replicas,
+            revision_history_limit: value.revision_history_limit,
+            selector: value.selector,
+            strategy: value.strategy.map(Into::into),
+            template: value.template,
+        }
+    }
+}
@@ -86,3 +86,12 @@ impl ::serde::Serialize for RollingUpdateDaemonSet {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::RollingUpdateDaemonSet> for RollingUpdateDaemonSet {
+    fn from(value: ::v1_10::api::apps::v1beta2::RollingUpdateDaemonSet) -> Self {
+        RollingUpdateDaemonSet {
+            max_unavailable: value.max_unavailable,
+        }
+    }
+}
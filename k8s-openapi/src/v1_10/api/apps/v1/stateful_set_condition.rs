@@ -134,3 +134,16 @@ impl ::serde::Serialize for StatefulSetCondition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::StatefulSetCondition> for StatefulSetCondition {
+    fn from(value: ::v1_10::api::apps::v1beta2::StatefulSetCondition) -> Self {
+        StatefulSetCondition {
+            last_transition_time: value.last_transition_time,
+            message: value.message,
+            reason: value.reason,
+            status: value.status,
+            type_: value.type_,
+        }
+    }
+}
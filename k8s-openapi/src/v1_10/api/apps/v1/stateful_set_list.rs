@@ -121,3 +121,15 @@ impl ::serde::Serialize for StatefulSetList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for StatefulSetList {
+    type Item = ::v1_10::api::apps::v1::StatefulSet;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
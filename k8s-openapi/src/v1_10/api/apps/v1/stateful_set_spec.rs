@@ -171,3 +171,19 @@ impl ::serde::Serialize for StatefulSetSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::StatefulSetSpec> for StatefulSetSpec {
+    fn from(value: ::v1_10::api::apps::v1beta2::StatefulSetSpec) -> Self {
+        StatefulSetSpec {
+            pod_management_policy: value.pod_management_policy,
+            replicas: value.replicas,
+            revision_history_limit: value.revision_history_limit,
+            selector: value.selector,
+            service_name: value.service_name,
+            template: value.template,
+            update_strategy: value.update_strategy.map(Into::into),
+            volume_claim_templates: value.volume_claim_templates,
+        }
+    }
+}
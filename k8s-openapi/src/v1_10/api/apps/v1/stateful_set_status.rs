@@ -188,3 +188,20 @@ impl ::serde::Serialize for StatefulSetStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::StatefulSetStatus> for StatefulSetStatus {
+    fn from(value: ::v1_10::api::apps::v1beta2::StatefulSetStatus) -> Self {
+        StatefulSetStatus {
+            collision_count: value.collision_count,
+            conditions: value.conditions.map(|value| value.into_iter().map(Into::into).collect()),
+            current_replicas: value.current_replicas,
+            current_revision: value.current_revision,
+            observed_generation: value.observed_generation,
+            ready_replicas: value.ready_replicas,
+            replicas: value.replicas,
+            update_revision: value.update_revision,
+            updated_replicas: value.updated_replicas,
+        }
+    }
+}
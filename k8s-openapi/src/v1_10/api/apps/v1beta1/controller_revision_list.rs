@@ -123,3 +123,15 @@ impl ::serde::Serialize for ControllerRevisionList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for ControllerRevisionList {
+    type Item = ::v1_10::api::apps::v1beta1::ControllerRevision;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
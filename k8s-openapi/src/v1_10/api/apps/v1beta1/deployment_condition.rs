@@ -147,3 +147,17 @@ impl ::serde::Serialize for DeploymentCondition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::DeploymentCondition> for DeploymentCondition {
+    fn from(value: ::v1_10::api::extensions::v1beta1::DeploymentCondition) -> Self {
+        DeploymentCondition {
+            last_transition_time: value.last_transition_time,
+            last_update_time: value.last_update_time,
+            message: value.message,
+            reason: value.reason,
+            status: value.status,
+            type_: value.type_,
+        }
+    }
+}
@@ -177,3 +177,19 @@ impl ::serde::Serialize for DeploymentStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::DeploymentStatus> for DeploymentStatus {
+    fn from(value: ::v1_10::api::extensions::v1beta1::DeploymentStatus) -> Self {
+        DeploymentStatus {
+            available_replicas: value.available_replicas,
+            collision_count: value.collision_count,
+            conditions: value.conditions.map(|value| value.into_iter().map(Into::into).collect()),
+            observed_generation: value.observed_generation,
+            ready_replicas: value.ready_replicas,
+            replicas: value.replicas,
+            unavailable_replicas: value.unavailable_replicas,
+            updated_replicas: value.updated_replicas,
+        }
+    }
+}
@@ -86,3 +86,12 @@ impl ::serde::Serialize for RollbackConfig {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::RollbackConfig> for RollbackConfig {
+    fn from(value: ::v1_10::api::extensions::v1beta1::RollbackConfig) -> Self {
+        RollbackConfig {
+            revision: value.revision,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct ControllerRevision {
 
 // Generated from operation createAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// create a ControllerRevision
     ///
@@ -58,6 +59,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::create_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.create_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAppsV1beta2NamespacedControllerRevisionResponse {
     Ok(::v1_10::api::apps::v1beta2::ControllerRevision),
@@ -67,6 +69,7 @@ pub enum CreateAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateAppsV1beta2NamespacedControllerRevisionResponse {
             _ => Ok((CreateAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAppsV1beta2CollectionNamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// delete collection of ControllerRevision
     ///
@@ -200,6 +208,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::delete_apps_v1beta2_collection_namespaced_controller_revision`](./struct.ControllerRevision.html#method.delete_apps_v1beta2_collection_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse::Unauthorized, 0)),
             _ => Ok((DeleteAppsV1beta2CollectionNamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// delete a ControllerRevision
     ///
@@ -303,6 +308,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::delete_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.delete_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAppsV1beta2NamespacedControllerRevisionResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2NamespacedControllerRevisionResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2NamespacedControllerRevisionResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAppsV1beta2NamespacedControllerRevisionResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAppsV1beta2NamespacedControllerRevisionResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAppsV1beta2NamespacedControllerRevisionResponse::Unauthorized, 0)),
             _ => Ok((DeleteAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAppsV1beta2ControllerRevisionForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// list or watch objects of kind ControllerRevision
     ///
@@ -436,6 +438,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::list_apps_v1beta2_controller_revision_for_all_namespaces`](./struct.ControllerRevision.html#method.list_apps_v1beta2_controller_revision_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAppsV1beta2ControllerRevisionForAllNamespacesResponse {
     Ok(::v1_10::api::apps::v1beta2::ControllerRevisionList),
@@ -443,6 +446,7 @@ pub enum ListAppsV1beta2ControllerRevisionForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAppsV1beta2ControllerRevisionForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListAppsV1beta2ControllerRevisionForAllNamespacesResponse {
             _ => Ok((ListAppsV1beta2ControllerRevisionForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAppsV1beta2ControllerRevisionForAllNamespacesResponse {
+    type List = ::v1_10::api::apps::v1beta2::ControllerRevisionList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAppsV1beta2ControllerRevisionForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// list or watch objects of kind ControllerRevision
     ///
@@ -560,6 +581,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::list_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.list_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAppsV1beta2NamespacedControllerRevisionResponse {
     Ok(::v1_10::api::apps::v1beta2::ControllerRevisionList),
@@ -567,6 +589,7 @@ pub enum ListAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListAppsV1beta2NamespacedControllerRevisionResponse {
             _ => Ok((ListAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAppsV1beta2NamespacedControllerRevisionResponse {
+    type List = ::v1_10::api::apps::v1beta2::ControllerRevisionList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAppsV1beta2NamespacedControllerRevisionResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// partially update the specified ControllerRevision
     ///
@@ -626,6 +666,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::patch_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.patch_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedControllerRevisionResponse {
     Ok(::v1_10::api::apps::v1beta2::ControllerRevision),
@@ -633,6 +674,7 @@ pub enum PatchAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchAppsV1beta2NamespacedControllerRevisionResponse {
             _ => Ok((PatchAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// read the specified ControllerRevision
     ///
@@ -705,6 +752,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::read_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.read_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedControllerRevisionResponse {
     Ok(::v1_10::api::apps::v1beta2::ControllerRevision),
@@ -712,6 +760,7 @@ pub enum ReadAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -727,10 +776,15 @@ impl ::Response for ReadAppsV1beta2NamespacedControllerRevisionResponse {
             _ => Ok((ReadAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// replace the specified ControllerRevision
     ///
@@ -771,6 +825,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::replace_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.replace_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedControllerRevisionResponse {
     Ok(::v1_10::api::apps::v1beta2::ControllerRevision),
@@ -779,6 +834,7 @@ pub enum ReplaceAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -802,10 +858,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedControllerRevisionResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAppsV1beta2ControllerRevisionListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// watch individual changes to a list of ControllerRevision
     ///
@@ -899,6 +960,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::watch_apps_v1beta2_controller_revision_list_for_all_namespaces`](./struct.ControllerRevision.html#method.watch_apps_v1beta2_controller_revision_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -906,27 +968,31 @@ pub enum WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2ControllerRevisionListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAppsV1beta2NamespacedControllerRevision
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// watch changes to an object of kind ControllerRevision
     ///
@@ -1030,6 +1096,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::watch_apps_v1beta2_namespaced_controller_revision`](./struct.ControllerRevision.html#method.watch_apps_v1beta2_namespaced_controller_revision)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2NamespacedControllerRevisionResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1037,27 +1104,31 @@ pub enum WatchAppsV1beta2NamespacedControllerRevisionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2NamespacedControllerRevisionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2NamespacedControllerRevisionResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2NamespacedControllerRevisionResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2NamespacedControllerRevisionResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2NamespacedControllerRevisionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAppsV1beta2NamespacedControllerRevisionList
 
+#[cfg(feature = "api")]
 impl ControllerRevision {
     /// watch individual changes to a list of ControllerRevision
     ///
@@ -1156,6 +1227,7 @@ impl ControllerRevision {
 }
 
 /// Parses the HTTP response of [`ControllerRevision::watch_apps_v1beta2_namespaced_controller_revision_list`](./struct.ControllerRevision.html#method.watch_apps_v1beta2_namespaced_controller_revision_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2NamespacedControllerRevisionListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1163,23 +1235,26 @@ pub enum WatchAppsV1beta2NamespacedControllerRevisionListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2NamespacedControllerRevisionListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2NamespacedControllerRevisionListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2NamespacedControllerRevisionListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2NamespacedControllerRevisionListResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2NamespacedControllerRevisionListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End apps/v1beta2/ControllerRevision
@@ -1301,3 +1376,56 @@ impl ::serde::Serialize for ControllerRevision {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ControllerRevision`](./struct.ControllerRevision.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ControllerRevisionField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for ControllerRevisionField {
+    fn path(&self) -> &'static str {
+        match self {
+            ControllerRevisionField::MetadataName => "metadata.name",
+            ControllerRevisionField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ControllerRevisionField::MetadataName),
+            "metadata.namespace" => Some(ControllerRevisionField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::ControllerRevision> for ControllerRevision {
+    fn from(value: ::v1_10::api::apps::v1::ControllerRevision) -> Self {
+        ControllerRevision {
+            api_version: value.api_version.map(|_| "apps/v1beta2".to_string()),
+            data: value.data,
+            kind: value.kind,
+            metadata: value.metadata,
+            revision: value.revision,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl From<::v1_10::api::apps::v1beta1::ControllerRevision> for ControllerRevision {
+    fn from(value: ::v1_10::api::apps::v1beta1::ControllerRevision) -> Self {
+        ControllerRevision {
+            api_version: value.api_version.map(|_| "apps/v1beta2".to_string()),
+            data: value.data,
+            kind: value.kind,
+            metadata: value.metadata,
+            revision: value.revision,
+        }
+    }
+}
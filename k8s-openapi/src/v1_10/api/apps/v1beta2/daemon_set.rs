@@ -23,6 +23,7 @@ pub struct DaemonSet {
 
 // Generated from operation createAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// create a DaemonSet
     ///
@@ -58,6 +59,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::create_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.create_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAppsV1beta2NamespacedDaemonSetResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -67,6 +69,7 @@ pub enum CreateAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateAppsV1beta2NamespacedDaemonSetResponse {
             _ => Ok((CreateAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAppsV1beta2CollectionNamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// delete collection of DaemonSet
     ///
@@ -200,6 +208,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::delete_apps_v1beta2_collection_namespaced_daemon_set`](./struct.DaemonSet.html#method.delete_apps_v1beta2_collection_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteAppsV1beta2CollectionNamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// delete a DaemonSet
     ///
@@ -303,6 +308,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::delete_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.delete_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAppsV1beta2NamespacedDaemonSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2NamespacedDaemonSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2NamespacedDaemonSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAppsV1beta2NamespacedDaemonSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAppsV1beta2NamespacedDaemonSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAppsV1beta2NamespacedDaemonSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAppsV1beta2DaemonSetForAllNamespaces
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// list or watch objects of kind DaemonSet
     ///
@@ -436,6 +438,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::list_apps_v1beta2_daemon_set_for_all_namespaces`](./struct.DaemonSet.html#method.list_apps_v1beta2_daemon_set_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAppsV1beta2DaemonSetForAllNamespacesResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSetList),
@@ -443,6 +446,7 @@ pub enum ListAppsV1beta2DaemonSetForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAppsV1beta2DaemonSetForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListAppsV1beta2DaemonSetForAllNamespacesResponse {
             _ => Ok((ListAppsV1beta2DaemonSetForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAppsV1beta2DaemonSetForAllNamespacesResponse {
+    type List = ::v1_10::api::apps::v1beta2::DaemonSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAppsV1beta2DaemonSetForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// list or watch objects of kind DaemonSet
     ///
@@ -560,6 +581,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::list_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.list_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAppsV1beta2NamespacedDaemonSetResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSetList),
@@ -567,6 +589,7 @@ pub enum ListAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListAppsV1beta2NamespacedDaemonSetResponse {
             _ => Ok((ListAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAppsV1beta2NamespacedDaemonSetResponse {
+    type List = ::v1_10::api::apps::v1beta2::DaemonSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAppsV1beta2NamespacedDaemonSetResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// partially update the specified DaemonSet
     ///
@@ -626,6 +666,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::patch_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.patch_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedDaemonSetResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -633,6 +674,7 @@ pub enum PatchAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchAppsV1beta2NamespacedDaemonSetResponse {
             _ => Ok((PatchAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedDaemonSetStatus
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// partially update status of the specified DaemonSet
     ///
@@ -692,6 +739,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::patch_apps_v1beta2_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.patch_apps_v1beta2_namespaced_daemon_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedDaemonSetStatusResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -699,6 +747,7 @@ pub enum PatchAppsV1beta2NamespacedDaemonSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedDaemonSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchAppsV1beta2NamespacedDaemonSetStatusResponse {
             _ => Ok((PatchAppsV1beta2NamespacedDaemonSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// read the specified DaemonSet
     ///
@@ -771,6 +825,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::read_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.read_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedDaemonSetResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -778,6 +833,7 @@ pub enum ReadAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadAppsV1beta2NamespacedDaemonSetResponse {
             _ => Ok((ReadAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedDaemonSetStatus
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// read status of the specified DaemonSet
     ///
@@ -834,6 +895,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::read_apps_v1beta2_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.read_apps_v1beta2_namespaced_daemon_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedDaemonSetStatusResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -841,6 +903,7 @@ pub enum ReadAppsV1beta2NamespacedDaemonSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedDaemonSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadAppsV1beta2NamespacedDaemonSetStatusResponse {
             _ => Ok((ReadAppsV1beta2NamespacedDaemonSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// replace the specified DaemonSet
     ///
@@ -900,6 +968,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::replace_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.replace_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedDaemonSetResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -908,6 +977,7 @@ pub enum ReplaceAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedDaemonSetResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedDaemonSetStatus
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// replace status of the specified DaemonSet
     ///
@@ -975,6 +1050,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::replace_apps_v1beta2_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.replace_apps_v1beta2_namespaced_daemon_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedDaemonSetStatusResponse {
     Ok(::v1_10::api::apps::v1beta2::DaemonSet),
@@ -983,6 +1059,7 @@ pub enum ReplaceAppsV1beta2NamespacedDaemonSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedDaemonSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedDaemonSetStatusResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedDaemonSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAppsV1beta2DaemonSetListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// watch individual changes to a list of DaemonSet
     ///
@@ -1103,6 +1185,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::watch_apps_v1beta2_daemon_set_list_for_all_namespaces`](./struct.DaemonSet.html#method.watch_apps_v1beta2_daemon_set_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2DaemonSetListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchAppsV1beta2DaemonSetListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2DaemonSetListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2DaemonSetListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2DaemonSetListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2DaemonSetListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2DaemonSetListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAppsV1beta2NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// watch changes to an object of kind DaemonSet
     ///
@@ -1234,6 +1321,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::watch_apps_v1beta2_namespaced_daemon_set`](./struct.DaemonSet.html#method.watch_apps_v1beta2_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2NamespacedDaemonSetResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchAppsV1beta2NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2NamespacedDaemonSetResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2NamespacedDaemonSetResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2NamespacedDaemonSetResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAppsV1beta2NamespacedDaemonSetList
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// watch individual changes to a list of DaemonSet
     ///
@@ -1360,6 +1452,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::watch_apps_v1beta2_namespaced_daemon_set_list`](./struct.DaemonSet.html#method.watch_apps_v1beta2_namespaced_daemon_set_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2NamespacedDaemonSetListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchAppsV1beta2NamespacedDaemonSetListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2NamespacedDaemonSetListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2NamespacedDaemonSetListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2NamespacedDaemonSetListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2NamespacedDaemonSetListResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2NamespacedDaemonSetListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End apps/v1beta2/DaemonSet
@@ -1507,3 +1603,54 @@ impl ::serde::Serialize for DaemonSet {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`DaemonSet`](./struct.DaemonSet.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DaemonSetField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for DaemonSetField {
+    fn path(&self) -> &'static str {
+        match self {
+            DaemonSetField::MetadataName => "metadata.name",
+            DaemonSetField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(DaemonSetField::MetadataName),
+            "metadata.namespace" => Some(DaemonSetField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::DaemonSet> for DaemonSet {
+    fn from(value: ::v1_10::api::apps::v1::DaemonSet) -> Self {
+        DaemonSet {
+            api_version: value.api_version.map(|_| "apps/v1beta2".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl ::std::convert::TryFrom<::v1_10::api::extensions::v1beta1::DaemonSet> for DaemonSet {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::extensions::v1beta1::DaemonSet) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "apps/v1beta2".to_string());
+        Ok(result)
+    }
+}
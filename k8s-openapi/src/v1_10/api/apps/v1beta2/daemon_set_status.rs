@@ -195,3 +195,21 @@ impl ::serde::Serialize for DaemonSetStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::DaemonSetStatus> for DaemonSetStatus {
+    fn from(value: ::v1_10::api::apps::v1::DaemonSetStatus) -> Self {
+        DaemonSetStatus {
+            collision_count: value.collision_count,
+            conditions: value.conditions.map(|value| value.into_iter().map(Into::into).collect()),
+            current_number_scheduled: value.current_number_scheduled,
+            desired_number_scheduled: value.desired_number_scheduled,
+            number_available: value.number_available,
+            number_misscheduled: value.number_misscheduled,
+            number_ready: value.number_ready,
+            number_unavailable: value.number_unavailable,
+            observed_generation: value.observed_generation,
+            updated_number_scheduled: value.updated_number_scheduled,
+        }
+    }
+}
@@ -99,3 +99,13 @@ impl ::serde::Serialize for DeploymentStrategy {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::DeploymentStrategy> for DeploymentStrategy {
+    fn from(value: ::v1_10::api::apps::v1::DeploymentStrategy) -> Self {
+        DeploymentStrategy {
+            rolling_update: value.rolling_update.map(Into::into),
+            type_: value.type_,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for ReplicaSetSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::ReplicaSetSpec> for ReplicaSetSpec {
+    fn from(value: ::v1_10::api::apps::v1::ReplicaSetSpec) -> Self {
+        ReplicaSetSpec {
+            min_ready_seconds: value.min_ready_seconds,
+            replicas: value.replicas,
+            selector: value.selector,
+            template: value.template,
+        }
+    }
+}
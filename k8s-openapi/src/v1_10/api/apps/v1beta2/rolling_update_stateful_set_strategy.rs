@@ -86,3 +86,12 @@ impl ::serde::Serialize for RollingUpdateStatefulSetStrategy {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::RollingUpdateStatefulSetStrategy> for RollingUpdateStatefulSetStrategy {
+    fn from(value: ::v1_10::api::apps::v1::RollingUpdateStatefulSetStrategy) -> Self {
+        RollingUpdateStatefulSetStrategy {
+            partition: value.partition,
+        }
+    }
+}
@@ -25,6 +25,7 @@ pub struct StatefulSet {
 
 // Generated from operation createAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// create a StatefulSet
     ///
@@ -60,6 +61,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::create_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.create_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAppsV1beta2NamespacedStatefulSetResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -69,6 +71,7 @@ pub enum CreateAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -100,10 +103,15 @@ impl ::Response for CreateAppsV1beta2NamespacedStatefulSetResponse {
             _ => Ok((CreateAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAppsV1beta2CollectionNamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// delete collection of StatefulSet
     ///
@@ -202,6 +210,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::delete_apps_v1beta2_collection_namespaced_stateful_set`](./struct.StatefulSet.html#method.delete_apps_v1beta2_collection_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -210,38 +219,34 @@ pub enum DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteAppsV1beta2CollectionNamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// delete a StatefulSet
     ///
@@ -305,6 +310,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::delete_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.delete_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAppsV1beta2NamespacedStatefulSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -313,38 +319,34 @@ pub enum DeleteAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2NamespacedStatefulSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAppsV1beta2NamespacedStatefulSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAppsV1beta2NamespacedStatefulSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAppsV1beta2NamespacedStatefulSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAppsV1beta2NamespacedStatefulSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// list or watch objects of kind StatefulSet
     ///
@@ -443,6 +445,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::list_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.list_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAppsV1beta2NamespacedStatefulSetResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSetList),
@@ -450,6 +453,7 @@ pub enum ListAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -465,10 +469,27 @@ impl ::Response for ListAppsV1beta2NamespacedStatefulSetResponse {
             _ => Ok((ListAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAppsV1beta2NamespacedStatefulSetResponse {
+    type List = ::v1_10::api::apps::v1beta2::StatefulSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAppsV1beta2NamespacedStatefulSetResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listAppsV1beta2StatefulSetForAllNamespaces
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// list or watch objects of kind StatefulSet
     ///
@@ -562,6 +583,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::list_apps_v1beta2_stateful_set_for_all_namespaces`](./struct.StatefulSet.html#method.list_apps_v1beta2_stateful_set_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAppsV1beta2StatefulSetForAllNamespacesResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSetList),
@@ -569,6 +591,7 @@ pub enum ListAppsV1beta2StatefulSetForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAppsV1beta2StatefulSetForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -584,10 +607,27 @@ impl ::Response for ListAppsV1beta2StatefulSetForAllNamespacesResponse {
             _ => Ok((ListAppsV1beta2StatefulSetForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAppsV1beta2StatefulSetForAllNamespacesResponse {
+    type List = ::v1_10::api::apps::v1beta2::StatefulSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAppsV1beta2StatefulSetForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// partially update the specified StatefulSet
     ///
@@ -628,6 +668,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::patch_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.patch_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedStatefulSetResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -635,6 +676,7 @@ pub enum PatchAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -650,10 +692,15 @@ impl ::Response for PatchAppsV1beta2NamespacedStatefulSetResponse {
             _ => Ok((PatchAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedStatefulSetStatus
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// partially update status of the specified StatefulSet
     ///
@@ -694,6 +741,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::patch_apps_v1beta2_namespaced_stateful_set_status`](./struct.StatefulSet.html#method.patch_apps_v1beta2_namespaced_stateful_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedStatefulSetStatusResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -701,6 +749,7 @@ pub enum PatchAppsV1beta2NamespacedStatefulSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedStatefulSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -716,10 +765,15 @@ impl ::Response for PatchAppsV1beta2NamespacedStatefulSetStatusResponse {
             _ => Ok((PatchAppsV1beta2NamespacedStatefulSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// read the specified StatefulSet
     ///
@@ -773,6 +827,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::read_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.read_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedStatefulSetResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -780,6 +835,7 @@ pub enum ReadAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -795,10 +851,15 @@ impl ::Response for ReadAppsV1beta2NamespacedStatefulSetResponse {
             _ => Ok((ReadAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedStatefulSetStatus
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// read status of the specified StatefulSet
     ///
@@ -836,6 +897,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::read_apps_v1beta2_namespaced_stateful_set_status`](./struct.StatefulSet.html#method.read_apps_v1beta2_namespaced_stateful_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedStatefulSetStatusResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -843,6 +905,7 @@ pub enum ReadAppsV1beta2NamespacedStatefulSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedStatefulSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -858,10 +921,15 @@ impl ::Response for ReadAppsV1beta2NamespacedStatefulSetStatusResponse {
             _ => Ok((ReadAppsV1beta2NamespacedStatefulSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// replace the specified StatefulSet
     ///
@@ -902,6 +970,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::replace_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.replace_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedStatefulSetResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -910,6 +979,7 @@ pub enum ReplaceAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -933,10 +1003,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedStatefulSetResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedStatefulSetStatus
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// replace status of the specified StatefulSet
     ///
@@ -977,6 +1052,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::replace_apps_v1beta2_namespaced_stateful_set_status`](./struct.StatefulSet.html#method.replace_apps_v1beta2_namespaced_stateful_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedStatefulSetStatusResponse {
     Ok(::v1_10::api::apps::v1beta2::StatefulSet),
@@ -985,6 +1061,7 @@ pub enum ReplaceAppsV1beta2NamespacedStatefulSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedStatefulSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1008,10 +1085,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedStatefulSetStatusResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedStatefulSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAppsV1beta2NamespacedStatefulSet
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// watch changes to an object of kind StatefulSet
     ///
@@ -1115,6 +1197,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::watch_apps_v1beta2_namespaced_stateful_set`](./struct.StatefulSet.html#method.watch_apps_v1beta2_namespaced_stateful_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2NamespacedStatefulSetResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1122,27 +1205,31 @@ pub enum WatchAppsV1beta2NamespacedStatefulSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2NamespacedStatefulSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2NamespacedStatefulSetResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2NamespacedStatefulSetResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2NamespacedStatefulSetResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2NamespacedStatefulSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAppsV1beta2NamespacedStatefulSetList
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// watch individual changes to a list of StatefulSet
     ///
@@ -1241,6 +1328,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::watch_apps_v1beta2_namespaced_stateful_set_list`](./struct.StatefulSet.html#method.watch_apps_v1beta2_namespaced_stateful_set_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2NamespacedStatefulSetListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1248,27 +1336,31 @@ pub enum WatchAppsV1beta2NamespacedStatefulSetListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2NamespacedStatefulSetListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2NamespacedStatefulSetListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2NamespacedStatefulSetListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2NamespacedStatefulSetListResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2NamespacedStatefulSetListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAppsV1beta2StatefulSetListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl StatefulSet {
     /// watch individual changes to a list of StatefulSet
     ///
@@ -1362,6 +1454,7 @@ impl StatefulSet {
 }
 
 /// Parses the HTTP response of [`StatefulSet::watch_apps_v1beta2_stateful_set_list_for_all_namespaces`](./struct.StatefulSet.html#method.watch_apps_v1beta2_stateful_set_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAppsV1beta2StatefulSetListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1369,23 +1462,26 @@ pub enum WatchAppsV1beta2StatefulSetListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAppsV1beta2StatefulSetListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAppsV1beta2StatefulSetListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAppsV1beta2StatefulSetListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAppsV1beta2StatefulSetListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchAppsV1beta2StatefulSetListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End apps/v1beta2/StatefulSet
@@ -1509,3 +1605,54 @@ impl ::serde::Serialize for StatefulSet {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`StatefulSet`](./struct.StatefulSet.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatefulSetField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for StatefulSetField {
+    fn path(&self) -> &'static str {
+        match self {
+            StatefulSetField::MetadataName => "metadata.name",
+            StatefulSetField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(StatefulSetField::MetadataName),
+            "metadata.namespace" => Some(StatefulSetField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl From<::v1_10::api::apps::v1::StatefulSet> for StatefulSet {
+    fn from(value: ::v1_10::api::apps::v1::StatefulSet) -> Self {
+        StatefulSet {
+            api_version: value.api_version.map(|_| "apps/v1beta2".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1beta1::StatefulSet> for StatefulSet {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1beta1::StatefulSet) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "apps/v1beta2".to_string());
+        Ok(result)
+    }
+}
@@ -112,3 +112,14 @@ impl ::serde::Serialize for TokenReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-authentication-v1")]
+impl From<::v1_10::api::authentication::v1::TokenReviewStatus> for TokenReviewStatus {
+    fn from(value: ::v1_10::api::authentication::v1::TokenReviewStatus) -> Self {
+        TokenReviewStatus {
+            authenticated: value.authenticated,
+            error: value.error,
+            user: value.user.map(Into::into),
+        }
+    }
+}
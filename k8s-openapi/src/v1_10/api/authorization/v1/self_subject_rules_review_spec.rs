@@ -85,3 +85,12 @@ impl ::serde::Serialize for SelfSubjectRulesReviewSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-authorization-v1beta1")]
+impl From<::v1_10::api::authorization::v1beta1::SelfSubjectRulesReviewSpec> for SelfSubjectRulesReviewSpec {
+    fn from(value: ::v1_10::api::authorization::v1beta1::SelfSubjectRulesReviewSpec) -> Self {
+        SelfSubjectRulesReviewSpec {
+            namespace: value.namespace,
+        }
+    }
+}
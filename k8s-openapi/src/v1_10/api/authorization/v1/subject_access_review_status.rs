@@ -123,3 +123,15 @@ impl ::serde::Serialize for SubjectAccessReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-authorization-v1beta1")]
+impl From<::v1_10::api::authorization::v1beta1::SubjectAccessReviewStatus> for SubjectAccessReviewStatus {
+    fn from(value: ::v1_10::api::authorization::v1beta1::SubjectAccessReviewStatus) -> Self {
+        SubjectAccessReviewStatus {
+            allowed: value.allowed,
+            denied: value.denied,
+            evaluation_error: value.evaluation_error,
+            reason: value.reason,
+        }
+    }
+}
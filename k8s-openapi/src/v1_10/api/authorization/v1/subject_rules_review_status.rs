@@ -119,3 +119,15 @@ impl ::serde::Serialize for SubjectRulesReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-authorization-v1beta1")]
+impl From<::v1_10::api::authorization::v1beta1::SubjectRulesReviewStatus> for SubjectRulesReviewStatus {
+    fn from(value: ::v1_10::api::authorization::v1beta1::SubjectRulesReviewStatus) -> Self {
+        SubjectRulesReviewStatus {
+            evaluation_error: value.evaluation_error,
+            incomplete: value.incomplete,
+            non_resource_rules: value.non_resource_rules.into_iter().map(Into::into).collect(),
+            resource_rules: value.resource_rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -164,3 +164,18 @@ impl ::serde::Serialize for ResourceAttributes {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-authorization-v1")]
+impl From<::v1_10::api::authorization::v1::ResourceAttributes> for ResourceAttributes {
+    fn from(value: ::v1_10::api::authorization::v1::ResourceAttributes) -> Self {
+        ResourceAttributes {
+            group: value.group,
+            name: value.name,
+            namespace: value.namespace,
+            resource: value.resource,
+            subresource: value.subresource,
+            verb: value.verb,
+            version: value.version,
+        }
+    }
+}
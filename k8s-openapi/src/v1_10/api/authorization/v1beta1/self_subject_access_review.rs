@@ -22,6 +22,7 @@ pub struct SelfSubjectAccessReview {
 
 // Generated from operation createAuthorizationV1beta1SelfSubjectAccessReview
 
+#[cfg(feature = "api")]
 impl SelfSubjectAccessReview {
     /// create a SelfSubjectAccessReview
     ///
@@ -52,6 +53,7 @@ impl SelfSubjectAccessReview {
 }
 
 /// Parses the HTTP response of [`SelfSubjectAccessReview::create_authorization_v1beta1_self_subject_access_review`](./struct.SelfSubjectAccessReview.html#method.create_authorization_v1beta1_self_subject_access_review)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAuthorizationV1beta1SelfSubjectAccessReviewResponse {
     Ok(::v1_10::api::authorization::v1beta1::SelfSubjectAccessReview),
@@ -61,6 +63,7 @@ pub enum CreateAuthorizationV1beta1SelfSubjectAccessReviewResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAuthorizationV1beta1SelfSubjectAccessReviewResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,6 +95,10 @@ impl ::Response for CreateAuthorizationV1beta1SelfSubjectAccessReviewResponse {
             _ => Ok((CreateAuthorizationV1beta1SelfSubjectAccessReviewResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End authorization.k8s.io/v1beta1/SelfSubjectAccessReview
@@ -213,3 +220,16 @@ impl ::serde::Serialize for SelfSubjectAccessReview {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-authorization-v1")]
+impl From<::v1_10::api::authorization::v1::SelfSubjectAccessReview> for SelfSubjectAccessReview {
+    fn from(value: ::v1_10::api::authorization::v1::SelfSubjectAccessReview) -> Self {
+        SelfSubjectAccessReview {
+            api_version: value.api_version.map(|_| "authorization.k8s.io/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.into(),
+            status: value.status.map(Into::into),
+        }
+    }
+}
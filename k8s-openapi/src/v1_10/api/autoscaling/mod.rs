@@ -1,3 +1,5 @@
+#[cfg(feature = "v1_10-autoscaling-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_10-autoscaling-v2beta1")]
 pub mod v2beta1;
@@ -23,6 +23,7 @@ pub struct Scale {
 
 // Generated from operation patchAppsV1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified Deployment
     ///
@@ -63,6 +64,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1_namespaced_deployment_scale`](./struct.Scale.html#method.patch_apps_v1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1NamespacedDeploymentScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -70,6 +72,7 @@ pub enum PatchAppsV1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -85,10 +88,15 @@ impl ::Response for PatchAppsV1NamespacedDeploymentScaleResponse {
             _ => Ok((PatchAppsV1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified ReplicaSet
     ///
@@ -129,6 +137,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1_namespaced_replica_set_scale`](./struct.Scale.html#method.patch_apps_v1_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1NamespacedReplicaSetScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -136,6 +145,7 @@ pub enum PatchAppsV1NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -151,10 +161,15 @@ impl ::Response for PatchAppsV1NamespacedReplicaSetScaleResponse {
             _ => Ok((PatchAppsV1NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified StatefulSet
     ///
@@ -195,6 +210,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1_namespaced_stateful_set_scale`](./struct.Scale.html#method.patch_apps_v1_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1NamespacedStatefulSetScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -202,6 +218,7 @@ pub enum PatchAppsV1NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -217,10 +234,15 @@ impl ::Response for PatchAppsV1NamespacedStatefulSetScaleResponse {
             _ => Ok((PatchAppsV1NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedReplicationControllerScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified ReplicationController
     ///
@@ -261,6 +283,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_core_v1_namespaced_replication_controller_scale`](./struct.Scale.html#method.patch_core_v1_namespaced_replication_controller_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedReplicationControllerScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -268,6 +291,7 @@ pub enum PatchCoreV1NamespacedReplicationControllerScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedReplicationControllerScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -283,10 +307,15 @@ impl ::Response for PatchCoreV1NamespacedReplicationControllerScaleResponse {
             _ => Ok((PatchCoreV1NamespacedReplicationControllerScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified Deployment
     ///
@@ -324,6 +353,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1_namespaced_deployment_scale`](./struct.Scale.html#method.read_apps_v1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1NamespacedDeploymentScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -331,6 +361,7 @@ pub enum ReadAppsV1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -346,10 +377,15 @@ impl ::Response for ReadAppsV1NamespacedDeploymentScaleResponse {
             _ => Ok((ReadAppsV1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified ReplicaSet
     ///
@@ -387,6 +423,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1_namespaced_replica_set_scale`](./struct.Scale.html#method.read_apps_v1_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1NamespacedReplicaSetScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -394,6 +431,7 @@ pub enum ReadAppsV1NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -409,10 +447,15 @@ impl ::Response for ReadAppsV1NamespacedReplicaSetScaleResponse {
             _ => Ok((ReadAppsV1NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified StatefulSet
     ///
@@ -450,6 +493,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1_namespaced_stateful_set_scale`](./struct.Scale.html#method.read_apps_v1_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1NamespacedStatefulSetScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -457,6 +501,7 @@ pub enum ReadAppsV1NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -472,10 +517,15 @@ impl ::Response for ReadAppsV1NamespacedStatefulSetScaleResponse {
             _ => Ok((ReadAppsV1NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedReplicationControllerScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified ReplicationController
     ///
@@ -513,6 +563,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_core_v1_namespaced_replication_controller_scale`](./struct.Scale.html#method.read_core_v1_namespaced_replication_controller_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedReplicationControllerScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -520,6 +571,7 @@ pub enum ReadCoreV1NamespacedReplicationControllerScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedReplicationControllerScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -535,10 +587,15 @@ impl ::Response for ReadCoreV1NamespacedReplicationControllerScaleResponse {
             _ => Ok((ReadCoreV1NamespacedReplicationControllerScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified Deployment
     ///
@@ -579,6 +636,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1_namespaced_deployment_scale`](./struct.Scale.html#method.replace_apps_v1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1NamespacedDeploymentScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -587,6 +645,7 @@ pub enum ReplaceAppsV1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -610,10 +669,15 @@ impl ::Response for ReplaceAppsV1NamespacedDeploymentScaleResponse {
             _ => Ok((ReplaceAppsV1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified ReplicaSet
     ///
@@ -654,6 +718,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1_namespaced_replica_set_scale`](./struct.Scale.html#method.replace_apps_v1_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1NamespacedReplicaSetScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -662,6 +727,7 @@ pub enum ReplaceAppsV1NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -685,10 +751,15 @@ impl ::Response for ReplaceAppsV1NamespacedReplicaSetScaleResponse {
             _ => Ok((ReplaceAppsV1NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified StatefulSet
     ///
@@ -729,6 +800,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1_namespaced_stateful_set_scale`](./struct.Scale.html#method.replace_apps_v1_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1NamespacedStatefulSetScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -737,6 +809,7 @@ pub enum ReplaceAppsV1NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -760,10 +833,15 @@ impl ::Response for ReplaceAppsV1NamespacedStatefulSetScaleResponse {
             _ => Ok((ReplaceAppsV1NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedReplicationControllerScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified ReplicationController
     ///
@@ -804,6 +882,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_core_v1_namespaced_replication_controller_scale`](./struct.Scale.html#method.replace_core_v1_namespaced_replication_controller_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedReplicationControllerScaleResponse {
     Ok(::v1_10::api::autoscaling::v1::Scale),
@@ -812,6 +891,7 @@ pub enum ReplaceCoreV1NamespacedReplicationControllerScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedReplicationControllerScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -835,6 +915,10 @@ impl ::Response for ReplaceCoreV1NamespacedReplicationControllerScaleResponse {
             _ => Ok((ReplaceCoreV1NamespacedReplicationControllerScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End autoscaling/v1/Scale
@@ -958,3 +1042,36 @@ impl ::serde::Serialize for Scale {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1beta1::Scale> for Scale {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1beta1::Scale) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "autoscaling/v1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1beta2::Scale> for Scale {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1beta2::Scale) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "autoscaling/v1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl ::std::convert::TryFrom<::v1_10::api::extensions::v1beta1::Scale> for Scale {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::extensions::v1beta1::Scale) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "autoscaling/v1".to_string());
+        Ok(result)
+    }
+}
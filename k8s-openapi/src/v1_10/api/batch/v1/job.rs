@@ -23,6 +23,7 @@ pub struct Job {
 
 // Generated from operation createBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// create a Job
     ///
@@ -58,6 +59,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::create_batch_v1_namespaced_job`](./struct.Job.html#method.create_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateBatchV1NamespacedJobResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -67,6 +69,7 @@ pub enum CreateBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateBatchV1NamespacedJobResponse {
             _ => Ok((CreateBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteBatchV1CollectionNamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// delete collection of Job
     ///
@@ -200,6 +208,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::delete_batch_v1_collection_namespaced_job`](./struct.Job.html#method.delete_batch_v1_collection_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteBatchV1CollectionNamespacedJobResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteBatchV1CollectionNamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteBatchV1CollectionNamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV1CollectionNamespacedJobResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV1CollectionNamespacedJobResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteBatchV1CollectionNamespacedJobResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteBatchV1CollectionNamespacedJobResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteBatchV1CollectionNamespacedJobResponse::Unauthorized, 0)),
             _ => Ok((DeleteBatchV1CollectionNamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// delete a Job
     ///
@@ -303,6 +308,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::delete_batch_v1_namespaced_job`](./struct.Job.html#method.delete_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteBatchV1NamespacedJobResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV1NamespacedJobResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV1NamespacedJobResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteBatchV1NamespacedJobResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteBatchV1NamespacedJobResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteBatchV1NamespacedJobResponse::Unauthorized, 0)),
             _ => Ok((DeleteBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listBatchV1JobForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Job {
     /// list or watch objects of kind Job
     ///
@@ -436,6 +438,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::list_batch_v1_job_for_all_namespaces`](./struct.Job.html#method.list_batch_v1_job_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListBatchV1JobForAllNamespacesResponse {
     Ok(::v1_10::api::batch::v1::JobList),
@@ -443,6 +446,7 @@ pub enum ListBatchV1JobForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListBatchV1JobForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListBatchV1JobForAllNamespacesResponse {
             _ => Ok((ListBatchV1JobForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListBatchV1JobForAllNamespacesResponse {
+    type List = ::v1_10::api::batch::v1::JobList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListBatchV1JobForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// list or watch objects of kind Job
     ///
@@ -560,6 +581,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::list_batch_v1_namespaced_job`](./struct.Job.html#method.list_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListBatchV1NamespacedJobResponse {
     Ok(::v1_10::api::batch::v1::JobList),
@@ -567,6 +589,7 @@ pub enum ListBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListBatchV1NamespacedJobResponse {
             _ => Ok((ListBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListBatchV1NamespacedJobResponse {
+    type List = ::v1_10::api::batch::v1::JobList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListBatchV1NamespacedJobResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// partially update the specified Job
     ///
@@ -626,6 +666,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::patch_batch_v1_namespaced_job`](./struct.Job.html#method.patch_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchBatchV1NamespacedJobResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -633,6 +674,7 @@ pub enum PatchBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchBatchV1NamespacedJobResponse {
             _ => Ok((PatchBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchBatchV1NamespacedJobStatus
 
+#[cfg(feature = "api")]
 impl Job {
     /// partially update status of the specified Job
     ///
@@ -692,6 +739,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::patch_batch_v1_namespaced_job_status`](./struct.Job.html#method.patch_batch_v1_namespaced_job_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchBatchV1NamespacedJobStatusResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -699,6 +747,7 @@ pub enum PatchBatchV1NamespacedJobStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchBatchV1NamespacedJobStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchBatchV1NamespacedJobStatusResponse {
             _ => Ok((PatchBatchV1NamespacedJobStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// read the specified Job
     ///
@@ -771,6 +825,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::read_batch_v1_namespaced_job`](./struct.Job.html#method.read_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadBatchV1NamespacedJobResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -778,6 +833,7 @@ pub enum ReadBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadBatchV1NamespacedJobResponse {
             _ => Ok((ReadBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readBatchV1NamespacedJobStatus
 
+#[cfg(feature = "api")]
 impl Job {
     /// read status of the specified Job
     ///
@@ -834,6 +895,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::read_batch_v1_namespaced_job_status`](./struct.Job.html#method.read_batch_v1_namespaced_job_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadBatchV1NamespacedJobStatusResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -841,6 +903,7 @@ pub enum ReadBatchV1NamespacedJobStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadBatchV1NamespacedJobStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadBatchV1NamespacedJobStatusResponse {
             _ => Ok((ReadBatchV1NamespacedJobStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// replace the specified Job
     ///
@@ -900,6 +968,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::replace_batch_v1_namespaced_job`](./struct.Job.html#method.replace_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceBatchV1NamespacedJobResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -908,6 +977,7 @@ pub enum ReplaceBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceBatchV1NamespacedJobResponse {
             _ => Ok((ReplaceBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceBatchV1NamespacedJobStatus
 
+#[cfg(feature = "api")]
 impl Job {
     /// replace status of the specified Job
     ///
@@ -975,6 +1050,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::replace_batch_v1_namespaced_job_status`](./struct.Job.html#method.replace_batch_v1_namespaced_job_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceBatchV1NamespacedJobStatusResponse {
     Ok(::v1_10::api::batch::v1::Job),
@@ -983,6 +1059,7 @@ pub enum ReplaceBatchV1NamespacedJobStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceBatchV1NamespacedJobStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceBatchV1NamespacedJobStatusResponse {
             _ => Ok((ReplaceBatchV1NamespacedJobStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchBatchV1JobListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Job {
     /// watch individual changes to a list of Job
     ///
@@ -1103,6 +1185,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::watch_batch_v1_job_list_for_all_namespaces`](./struct.Job.html#method.watch_batch_v1_job_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchBatchV1JobListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchBatchV1JobListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchBatchV1JobListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchBatchV1JobListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchBatchV1JobListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchBatchV1JobListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchBatchV1JobListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchBatchV1NamespacedJob
 
+#[cfg(feature = "api")]
 impl Job {
     /// watch changes to an object of kind Job
     ///
@@ -1234,6 +1321,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::watch_batch_v1_namespaced_job`](./struct.Job.html#method.watch_batch_v1_namespaced_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchBatchV1NamespacedJobResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchBatchV1NamespacedJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchBatchV1NamespacedJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchBatchV1NamespacedJobResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchBatchV1NamespacedJobResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchBatchV1NamespacedJobResponse::Unauthorized, 0)),
             _ => Ok((WatchBatchV1NamespacedJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchBatchV1NamespacedJobList
 
+#[cfg(feature = "api")]
 impl Job {
     /// watch individual changes to a list of Job
     ///
@@ -1360,6 +1452,7 @@ impl Job {
 }
 
 /// Parses the HTTP response of [`Job::watch_batch_v1_namespaced_job_list`](./struct.Job.html#method.watch_batch_v1_namespaced_job_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchBatchV1NamespacedJobListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchBatchV1NamespacedJobListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchBatchV1NamespacedJobListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchBatchV1NamespacedJobListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchBatchV1NamespacedJobListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchBatchV1NamespacedJobListResponse::Unauthorized, 0)),
             _ => Ok((WatchBatchV1NamespacedJobListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End batch/v1/Job
@@ -1507,3 +1603,35 @@ impl ::serde::Serialize for Job {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Job`](./struct.Job.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JobField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+
+    /// `status.successful`
+    StatusSuccessful,
+}
+
+impl ::field_selector::Field for JobField {
+    fn path(&self) -> &'static str {
+        match self {
+            JobField::MetadataName => "metadata.name",
+            JobField::MetadataNamespace => "metadata.namespace",
+            JobField::StatusSuccessful => "status.successful",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(JobField::MetadataName),
+            "metadata.namespace" => Some(JobField::MetadataNamespace),
+            "status.successful" => Some(JobField::StatusSuccessful),
+            _ => None,
+        }
+    }
+}
@@ -22,6 +22,7 @@ pub struct CertificateSigningRequest {
 
 // Generated from operation createCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// create a CertificateSigningRequest
     ///
@@ -52,6 +53,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::create_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.create_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCertificatesV1beta1CertificateSigningRequestResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequest),
@@ -61,6 +63,7 @@ pub enum CreateCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,10 +95,15 @@ impl ::Response for CreateCertificatesV1beta1CertificateSigningRequestResponse {
             _ => Ok((CreateCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// delete a CertificateSigningRequest
     ///
@@ -154,6 +162,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::delete_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.delete_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCertificatesV1beta1CertificateSigningRequestResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -162,38 +171,34 @@ pub enum DeleteCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCertificatesV1beta1CertificateSigningRequestResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCertificatesV1beta1CertificateSigningRequestResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCertificatesV1beta1CertificateSigningRequestResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCertificatesV1beta1CertificateSigningRequestResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCertificatesV1beta1CertificateSigningRequestResponse::Unauthorized, 0)),
             _ => Ok((DeleteCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCertificatesV1beta1CollectionCertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// delete collection of CertificateSigningRequest
     ///
@@ -287,6 +292,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::delete_certificates_v1beta1_collection_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.delete_certificates_v1beta1_collection_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -295,38 +301,34 @@ pub enum DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse::Unauthorized, 0)),
             _ => Ok((DeleteCertificatesV1beta1CollectionCertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// list or watch objects of kind CertificateSigningRequest
     ///
@@ -420,6 +422,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::list_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.list_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCertificatesV1beta1CertificateSigningRequestResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequestList),
@@ -427,6 +430,7 @@ pub enum ListCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -442,10 +446,27 @@ impl ::Response for ListCertificatesV1beta1CertificateSigningRequestResponse {
             _ => Ok((ListCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCertificatesV1beta1CertificateSigningRequestResponse {
+    type List = ::v1_10::api::certificates::v1beta1::CertificateSigningRequestList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCertificatesV1beta1CertificateSigningRequestResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// partially update the specified CertificateSigningRequest
     ///
@@ -481,6 +502,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::patch_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.patch_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCertificatesV1beta1CertificateSigningRequestResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequest),
@@ -488,6 +510,7 @@ pub enum PatchCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -503,10 +526,15 @@ impl ::Response for PatchCertificatesV1beta1CertificateSigningRequestResponse {
             _ => Ok((PatchCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// read the specified CertificateSigningRequest
     ///
@@ -555,6 +583,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::read_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.read_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCertificatesV1beta1CertificateSigningRequestResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequest),
@@ -562,6 +591,7 @@ pub enum ReadCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -577,10 +607,15 @@ impl ::Response for ReadCertificatesV1beta1CertificateSigningRequestResponse {
             _ => Ok((ReadCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// replace the specified CertificateSigningRequest
     ///
@@ -616,6 +651,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::replace_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.replace_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCertificatesV1beta1CertificateSigningRequestResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequest),
@@ -624,6 +660,7 @@ pub enum ReplaceCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -647,10 +684,15 @@ impl ::Response for ReplaceCertificatesV1beta1CertificateSigningRequestResponse
             _ => Ok((ReplaceCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCertificatesV1beta1CertificateSigningRequestApproval
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// replace approval of the specified CertificateSigningRequest
     ///
@@ -686,6 +728,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::replace_certificates_v1beta1_certificate_signing_request_approval`](./struct.CertificateSigningRequest.html#method.replace_certificates_v1beta1_certificate_signing_request_approval)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCertificatesV1beta1CertificateSigningRequestApprovalResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequest),
@@ -694,6 +737,7 @@ pub enum ReplaceCertificatesV1beta1CertificateSigningRequestApprovalResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCertificatesV1beta1CertificateSigningRequestApprovalResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -717,10 +761,15 @@ impl ::Response for ReplaceCertificatesV1beta1CertificateSigningRequestApprovalR
             _ => Ok((ReplaceCertificatesV1beta1CertificateSigningRequestApprovalResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCertificatesV1beta1CertificateSigningRequestStatus
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// replace status of the specified CertificateSigningRequest
     ///
@@ -756,6 +805,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::replace_certificates_v1beta1_certificate_signing_request_status`](./struct.CertificateSigningRequest.html#method.replace_certificates_v1beta1_certificate_signing_request_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCertificatesV1beta1CertificateSigningRequestStatusResponse {
     Ok(::v1_10::api::certificates::v1beta1::CertificateSigningRequest),
@@ -764,6 +814,7 @@ pub enum ReplaceCertificatesV1beta1CertificateSigningRequestStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCertificatesV1beta1CertificateSigningRequestStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -787,10 +838,15 @@ impl ::Response for ReplaceCertificatesV1beta1CertificateSigningRequestStatusRes
             _ => Ok((ReplaceCertificatesV1beta1CertificateSigningRequestStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCertificatesV1beta1CertificateSigningRequest
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// watch changes to an object of kind CertificateSigningRequest
     ///
@@ -889,6 +945,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::watch_certificates_v1beta1_certificate_signing_request`](./struct.CertificateSigningRequest.html#method.watch_certificates_v1beta1_certificate_signing_request)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCertificatesV1beta1CertificateSigningRequestResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -896,27 +953,31 @@ pub enum WatchCertificatesV1beta1CertificateSigningRequestResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCertificatesV1beta1CertificateSigningRequestResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCertificatesV1beta1CertificateSigningRequestResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCertificatesV1beta1CertificateSigningRequestResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCertificatesV1beta1CertificateSigningRequestResponse::Unauthorized, 0)),
             _ => Ok((WatchCertificatesV1beta1CertificateSigningRequestResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCertificatesV1beta1CertificateSigningRequestList
 
+#[cfg(feature = "api")]
 impl CertificateSigningRequest {
     /// watch individual changes to a list of CertificateSigningRequest
     ///
@@ -1010,6 +1071,7 @@ impl CertificateSigningRequest {
 }
 
 /// Parses the HTTP response of [`CertificateSigningRequest::watch_certificates_v1beta1_certificate_signing_request_list`](./struct.CertificateSigningRequest.html#method.watch_certificates_v1beta1_certificate_signing_request_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCertificatesV1beta1CertificateSigningRequestListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1017,23 +1079,26 @@ pub enum WatchCertificatesV1beta1CertificateSigningRequestListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCertificatesV1beta1CertificateSigningRequestListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCertificatesV1beta1CertificateSigningRequestListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCertificatesV1beta1CertificateSigningRequestListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCertificatesV1beta1CertificateSigningRequestListResponse::Unauthorized, 0)),
             _ => Ok((WatchCertificatesV1beta1CertificateSigningRequestListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End certificates.k8s.io/v1beta1/CertificateSigningRequest
@@ -1157,3 +1222,25 @@ impl ::serde::Serialize for CertificateSigningRequest {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`CertificateSigningRequest`](./struct.CertificateSigningRequest.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CertificateSigningRequestField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for CertificateSigningRequestField {
+    fn path(&self) -> &'static str {
+        match self {
+            CertificateSigningRequestField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(CertificateSigningRequestField::MetadataName),
+            _ => None,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct ConfigMap {
 
 // Generated from operation createCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// create a ConfigMap
     ///
@@ -58,6 +59,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::create_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.create_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedConfigMapResponse {
     Ok(::v1_10::api::core::v1::ConfigMap),
@@ -67,6 +69,7 @@ pub enum CreateCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateCoreV1NamespacedConfigMapResponse {
             _ => Ok((CreateCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// delete collection of ConfigMap
     ///
@@ -200,6 +208,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::delete_core_v1_collection_namespaced_config_map`](./struct.ConfigMap.html#method.delete_core_v1_collection_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedConfigMapResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteCoreV1CollectionNamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedConfigMapResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedConfigMapResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedConfigMapResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedConfigMapResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedConfigMapResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// delete a ConfigMap
     ///
@@ -303,6 +308,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::delete_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.delete_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedConfigMapResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedConfigMapResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedConfigMapResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedConfigMapResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedConfigMapResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedConfigMapResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1ConfigMapForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// list or watch objects of kind ConfigMap
     ///
@@ -436,6 +438,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::list_core_v1_config_map_for_all_namespaces`](./struct.ConfigMap.html#method.list_core_v1_config_map_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ConfigMapForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::ConfigMapList),
@@ -443,6 +446,7 @@ pub enum ListCoreV1ConfigMapForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ConfigMapForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListCoreV1ConfigMapForAllNamespacesResponse {
             _ => Ok((ListCoreV1ConfigMapForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ConfigMapForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::ConfigMapList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ConfigMapForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// list or watch objects of kind ConfigMap
     ///
@@ -560,6 +581,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::list_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.list_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedConfigMapResponse {
     Ok(::v1_10::api::core::v1::ConfigMapList),
@@ -567,6 +589,7 @@ pub enum ListCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListCoreV1NamespacedConfigMapResponse {
             _ => Ok((ListCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedConfigMapResponse {
+    type List = ::v1_10::api::core::v1::ConfigMapList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedConfigMapResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// partially update the specified ConfigMap
     ///
@@ -626,6 +666,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::patch_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.patch_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedConfigMapResponse {
     Ok(::v1_10::api::core::v1::ConfigMap),
@@ -633,6 +674,7 @@ pub enum PatchCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchCoreV1NamespacedConfigMapResponse {
             _ => Ok((PatchCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// read the specified ConfigMap
     ///
@@ -705,6 +752,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::read_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.read_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedConfigMapResponse {
     Ok(::v1_10::api::core::v1::ConfigMap),
@@ -712,6 +760,7 @@ pub enum ReadCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -727,10 +776,15 @@ impl ::Response for ReadCoreV1NamespacedConfigMapResponse {
             _ => Ok((ReadCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// replace the specified ConfigMap
     ///
@@ -771,6 +825,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::replace_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.replace_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedConfigMapResponse {
     Ok(::v1_10::api::core::v1::ConfigMap),
@@ -779,6 +834,7 @@ pub enum ReplaceCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -802,10 +858,15 @@ impl ::Response for ReplaceCoreV1NamespacedConfigMapResponse {
             _ => Ok((ReplaceCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1ConfigMapListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// watch individual changes to a list of ConfigMap
     ///
@@ -899,6 +960,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::watch_core_v1_config_map_list_for_all_namespaces`](./struct.ConfigMap.html#method.watch_core_v1_config_map_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1ConfigMapListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -906,27 +968,31 @@ pub enum WatchCoreV1ConfigMapListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1ConfigMapListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1ConfigMapListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1ConfigMapListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1ConfigMapListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1ConfigMapListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedConfigMap
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// watch changes to an object of kind ConfigMap
     ///
@@ -1030,6 +1096,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::watch_core_v1_namespaced_config_map`](./struct.ConfigMap.html#method.watch_core_v1_namespaced_config_map)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedConfigMapResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1037,27 +1104,31 @@ pub enum WatchCoreV1NamespacedConfigMapResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedConfigMapResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedConfigMapResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedConfigMapResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedConfigMapResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedConfigMapResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedConfigMapList
 
+#[cfg(feature = "api")]
 impl ConfigMap {
     /// watch individual changes to a list of ConfigMap
     ///
@@ -1156,6 +1227,7 @@ impl ConfigMap {
 }
 
 /// Parses the HTTP response of [`ConfigMap::watch_core_v1_namespaced_config_map_list`](./struct.ConfigMap.html#method.watch_core_v1_namespaced_config_map_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedConfigMapListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1163,23 +1235,26 @@ pub enum WatchCoreV1NamespacedConfigMapListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedConfigMapListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedConfigMapListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedConfigMapListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedConfigMapListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedConfigMapListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/ConfigMap
@@ -1303,3 +1378,30 @@ impl ::serde::Serialize for ConfigMap {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ConfigMap`](./struct.ConfigMap.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigMapField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for ConfigMapField {
+    fn path(&self) -> &'static str {
+        match self {
+            ConfigMapField::MetadataName => "metadata.name",
+            ConfigMapField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ConfigMapField::MetadataName),
+            "metadata.namespace" => Some(ConfigMapField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -31,6 +31,7 @@ pub struct Endpoints {
 
 // Generated from operation createCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// create Endpoints
     ///
@@ -66,6 +67,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::create_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.create_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedEndpointsResponse {
     Ok(::v1_10::api::core::v1::Endpoints),
@@ -75,6 +77,7 @@ pub enum CreateCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -106,10 +109,15 @@ impl ::Response for CreateCoreV1NamespacedEndpointsResponse {
             _ => Ok((CreateCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// delete collection of Endpoints
     ///
@@ -208,6 +216,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::delete_core_v1_collection_namespaced_endpoints`](./struct.Endpoints.html#method.delete_core_v1_collection_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedEndpointsResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -216,38 +225,34 @@ pub enum DeleteCoreV1CollectionNamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedEndpointsResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedEndpointsResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedEndpointsResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedEndpointsResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedEndpointsResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// delete Endpoints
     ///
@@ -311,6 +316,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::delete_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.delete_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedEndpointsResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -319,38 +325,34 @@ pub enum DeleteCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedEndpointsResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedEndpointsResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedEndpointsResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedEndpointsResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedEndpointsResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1EndpointsForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// list or watch objects of kind Endpoints
     ///
@@ -444,6 +446,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::list_core_v1_endpoints_for_all_namespaces`](./struct.Endpoints.html#method.list_core_v1_endpoints_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1EndpointsForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::EndpointsList),
@@ -451,6 +454,7 @@ pub enum ListCoreV1EndpointsForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1EndpointsForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -466,10 +470,27 @@ impl ::Response for ListCoreV1EndpointsForAllNamespacesResponse {
             _ => Ok((ListCoreV1EndpointsForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1EndpointsForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::EndpointsList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1EndpointsForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// list or watch objects of kind Endpoints
     ///
@@ -568,6 +589,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::list_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.list_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedEndpointsResponse {
     Ok(::v1_10::api::core::v1::EndpointsList),
@@ -575,6 +597,7 @@ pub enum ListCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -590,10 +613,27 @@ impl ::Response for ListCoreV1NamespacedEndpointsResponse {
             _ => Ok((ListCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedEndpointsResponse {
+    type List = ::v1_10::api::core::v1::EndpointsList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedEndpointsResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// partially update the specified Endpoints
     ///
@@ -634,6 +674,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::patch_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.patch_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedEndpointsResponse {
     Ok(::v1_10::api::core::v1::Endpoints),
@@ -641,6 +682,7 @@ pub enum PatchCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -656,10 +698,15 @@ impl ::Response for PatchCoreV1NamespacedEndpointsResponse {
             _ => Ok((PatchCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// read the specified Endpoints
     ///
@@ -713,6 +760,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::read_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.read_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedEndpointsResponse {
     Ok(::v1_10::api::core::v1::Endpoints),
@@ -720,6 +768,7 @@ pub enum ReadCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -735,10 +784,15 @@ impl ::Response for ReadCoreV1NamespacedEndpointsResponse {
             _ => Ok((ReadCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// replace the specified Endpoints
     ///
@@ -779,6 +833,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::replace_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.replace_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedEndpointsResponse {
     Ok(::v1_10::api::core::v1::Endpoints),
@@ -787,6 +842,7 @@ pub enum ReplaceCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -810,10 +866,15 @@ impl ::Response for ReplaceCoreV1NamespacedEndpointsResponse {
             _ => Ok((ReplaceCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1EndpointsListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// watch individual changes to a list of Endpoints
     ///
@@ -907,6 +968,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::watch_core_v1_endpoints_list_for_all_namespaces`](./struct.Endpoints.html#method.watch_core_v1_endpoints_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1EndpointsListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -914,27 +976,31 @@ pub enum WatchCoreV1EndpointsListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1EndpointsListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1EndpointsListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1EndpointsListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1EndpointsListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1EndpointsListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedEndpoints
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// watch changes to an object of kind Endpoints
     ///
@@ -1038,6 +1104,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::watch_core_v1_namespaced_endpoints`](./struct.Endpoints.html#method.watch_core_v1_namespaced_endpoints)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedEndpointsResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1045,27 +1112,31 @@ pub enum WatchCoreV1NamespacedEndpointsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedEndpointsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedEndpointsResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedEndpointsResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedEndpointsResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedEndpointsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedEndpointsList
 
+#[cfg(feature = "api")]
 impl Endpoints {
     /// watch individual changes to a list of Endpoints
     ///
@@ -1164,6 +1235,7 @@ impl Endpoints {
 }
 
 /// Parses the HTTP response of [`Endpoints::watch_core_v1_namespaced_endpoints_list`](./struct.Endpoints.html#method.watch_core_v1_namespaced_endpoints_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedEndpointsListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1171,23 +1243,26 @@ pub enum WatchCoreV1NamespacedEndpointsListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedEndpointsListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedEndpointsListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedEndpointsListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedEndpointsListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedEndpointsListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Endpoints
@@ -1301,3 +1376,30 @@ impl ::serde::Serialize for Endpoints {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Endpoints`](./struct.Endpoints.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EndpointsField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for EndpointsField {
+    fn path(&self) -> &'static str {
+        match self {
+            EndpointsField::MetadataName => "metadata.name",
+            EndpointsField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(EndpointsField::MetadataName),
+            "metadata.namespace" => Some(EndpointsField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
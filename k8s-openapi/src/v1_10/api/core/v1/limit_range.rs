@@ -20,6 +20,7 @@ pub struct LimitRange {
 
 // Generated from operation createCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// create a LimitRange
     ///
@@ -55,6 +56,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::create_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.create_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedLimitRangeResponse {
     Ok(::v1_10::api::core::v1::LimitRange),
@@ -64,6 +66,7 @@ pub enum CreateCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateCoreV1NamespacedLimitRangeResponse {
             _ => Ok((CreateCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// delete collection of LimitRange
     ///
@@ -197,6 +205,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::delete_core_v1_collection_namespaced_limit_range`](./struct.LimitRange.html#method.delete_core_v1_collection_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedLimitRangeResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -205,38 +214,34 @@ pub enum DeleteCoreV1CollectionNamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedLimitRangeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedLimitRangeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedLimitRangeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedLimitRangeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedLimitRangeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// delete a LimitRange
     ///
@@ -300,6 +305,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::delete_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.delete_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedLimitRangeResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -308,38 +314,34 @@ pub enum DeleteCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedLimitRangeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedLimitRangeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedLimitRangeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedLimitRangeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedLimitRangeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1LimitRangeForAllNamespaces
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// list or watch objects of kind LimitRange
     ///
@@ -433,6 +435,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::list_core_v1_limit_range_for_all_namespaces`](./struct.LimitRange.html#method.list_core_v1_limit_range_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1LimitRangeForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::LimitRangeList),
@@ -440,6 +443,7 @@ pub enum ListCoreV1LimitRangeForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1LimitRangeForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -455,10 +459,27 @@ impl ::Response for ListCoreV1LimitRangeForAllNamespacesResponse {
             _ => Ok((ListCoreV1LimitRangeForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1LimitRangeForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::LimitRangeList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1LimitRangeForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// list or watch objects of kind LimitRange
     ///
@@ -557,6 +578,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::list_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.list_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedLimitRangeResponse {
     Ok(::v1_10::api::core::v1::LimitRangeList),
@@ -564,6 +586,7 @@ pub enum ListCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -579,10 +602,27 @@ impl ::Response for ListCoreV1NamespacedLimitRangeResponse {
             _ => Ok((ListCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedLimitRangeResponse {
+    type List = ::v1_10::api::core::v1::LimitRangeList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedLimitRangeResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// partially update the specified LimitRange
     ///
@@ -623,6 +663,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::patch_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.patch_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedLimitRangeResponse {
     Ok(::v1_10::api::core::v1::LimitRange),
@@ -630,6 +671,7 @@ pub enum PatchCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +687,15 @@ impl ::Response for PatchCoreV1NamespacedLimitRangeResponse {
             _ => Ok((PatchCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// read the specified LimitRange
     ///
@@ -702,6 +749,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::read_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.read_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedLimitRangeResponse {
     Ok(::v1_10::api::core::v1::LimitRange),
@@ -709,6 +757,7 @@ pub enum ReadCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -724,10 +773,15 @@ impl ::Response for ReadCoreV1NamespacedLimitRangeResponse {
             _ => Ok((ReadCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// replace the specified LimitRange
     ///
@@ -768,6 +822,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::replace_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.replace_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedLimitRangeResponse {
     Ok(::v1_10::api::core::v1::LimitRange),
@@ -776,6 +831,7 @@ pub enum ReplaceCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -799,10 +855,15 @@ impl ::Response for ReplaceCoreV1NamespacedLimitRangeResponse {
             _ => Ok((ReplaceCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1LimitRangeListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// watch individual changes to a list of LimitRange
     ///
@@ -896,6 +957,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::watch_core_v1_limit_range_list_for_all_namespaces`](./struct.LimitRange.html#method.watch_core_v1_limit_range_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1LimitRangeListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -903,27 +965,31 @@ pub enum WatchCoreV1LimitRangeListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1LimitRangeListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1LimitRangeListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1LimitRangeListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1LimitRangeListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1LimitRangeListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedLimitRange
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// watch changes to an object of kind LimitRange
     ///
@@ -1027,6 +1093,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::watch_core_v1_namespaced_limit_range`](./struct.LimitRange.html#method.watch_core_v1_namespaced_limit_range)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedLimitRangeResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1034,27 +1101,31 @@ pub enum WatchCoreV1NamespacedLimitRangeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedLimitRangeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedLimitRangeResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedLimitRangeResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedLimitRangeResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedLimitRangeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedLimitRangeList
 
+#[cfg(feature = "api")]
 impl LimitRange {
     /// watch individual changes to a list of LimitRange
     ///
@@ -1153,6 +1224,7 @@ impl LimitRange {
 }
 
 /// Parses the HTTP response of [`LimitRange::watch_core_v1_namespaced_limit_range_list`](./struct.LimitRange.html#method.watch_core_v1_namespaced_limit_range_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedLimitRangeListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1160,23 +1232,26 @@ pub enum WatchCoreV1NamespacedLimitRangeListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedLimitRangeListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedLimitRangeListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedLimitRangeListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedLimitRangeListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedLimitRangeListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/LimitRange
@@ -1290,3 +1365,30 @@ impl ::serde::Serialize for LimitRange {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`LimitRange`](./struct.LimitRange.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LimitRangeField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for LimitRangeField {
+    fn path(&self) -> &'static str {
+        match self {
+            LimitRangeField::MetadataName => "metadata.name",
+            LimitRangeField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(LimitRangeField::MetadataName),
+            "metadata.namespace" => Some(LimitRangeField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
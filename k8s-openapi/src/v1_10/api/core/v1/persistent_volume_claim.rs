@@ -23,6 +23,7 @@ pub struct PersistentVolumeClaim {
 
 // Generated from operation createCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// create a PersistentVolumeClaim
     ///
@@ -58,6 +59,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::create_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.create_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedPersistentVolumeClaimResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -67,6 +69,7 @@ pub enum CreateCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateCoreV1NamespacedPersistentVolumeClaimResponse {
             _ => Ok((CreateCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// delete collection of PersistentVolumeClaim
     ///
@@ -200,6 +208,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::delete_core_v1_collection_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.delete_core_v1_collection_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// delete a PersistentVolumeClaim
     ///
@@ -303,6 +308,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::delete_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.delete_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedPersistentVolumeClaimResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedPersistentVolumeClaimResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedPersistentVolumeClaimResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedPersistentVolumeClaimResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedPersistentVolumeClaimResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedPersistentVolumeClaimResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// list or watch objects of kind PersistentVolumeClaim
     ///
@@ -441,6 +443,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::list_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.list_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedPersistentVolumeClaimResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaimList),
@@ -448,6 +451,7 @@ pub enum ListCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -463,10 +467,27 @@ impl ::Response for ListCoreV1NamespacedPersistentVolumeClaimResponse {
             _ => Ok((ListCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedPersistentVolumeClaimResponse {
+    type List = ::v1_10::api::core::v1::PersistentVolumeClaimList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedPersistentVolumeClaimResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1PersistentVolumeClaimForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// list or watch objects of kind PersistentVolumeClaim
     ///
@@ -560,6 +581,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::list_core_v1_persistent_volume_claim_for_all_namespaces`](./struct.PersistentVolumeClaim.html#method.list_core_v1_persistent_volume_claim_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1PersistentVolumeClaimForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaimList),
@@ -567,6 +589,7 @@ pub enum ListCoreV1PersistentVolumeClaimForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1PersistentVolumeClaimForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListCoreV1PersistentVolumeClaimForAllNamespacesResponse {
             _ => Ok((ListCoreV1PersistentVolumeClaimForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1PersistentVolumeClaimForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::PersistentVolumeClaimList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1PersistentVolumeClaimForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// partially update the specified PersistentVolumeClaim
     ///
@@ -626,6 +666,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::patch_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.patch_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedPersistentVolumeClaimResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -633,6 +674,7 @@ pub enum PatchCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchCoreV1NamespacedPersistentVolumeClaimResponse {
             _ => Ok((PatchCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedPersistentVolumeClaimStatus
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// partially update status of the specified PersistentVolumeClaim
     ///
@@ -692,6 +739,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::patch_core_v1_namespaced_persistent_volume_claim_status`](./struct.PersistentVolumeClaim.html#method.patch_core_v1_namespaced_persistent_volume_claim_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -699,6 +747,7 @@ pub enum PatchCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchCoreV1NamespacedPersistentVolumeClaimStatusResponse {
             _ => Ok((PatchCoreV1NamespacedPersistentVolumeClaimStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// read the specified PersistentVolumeClaim
     ///
@@ -771,6 +825,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::read_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.read_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedPersistentVolumeClaimResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -778,6 +833,7 @@ pub enum ReadCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadCoreV1NamespacedPersistentVolumeClaimResponse {
             _ => Ok((ReadCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedPersistentVolumeClaimStatus
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// read status of the specified PersistentVolumeClaim
     ///
@@ -834,6 +895,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::read_core_v1_namespaced_persistent_volume_claim_status`](./struct.PersistentVolumeClaim.html#method.read_core_v1_namespaced_persistent_volume_claim_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -841,6 +903,7 @@ pub enum ReadCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadCoreV1NamespacedPersistentVolumeClaimStatusResponse {
             _ => Ok((ReadCoreV1NamespacedPersistentVolumeClaimStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// replace the specified PersistentVolumeClaim
     ///
@@ -900,6 +968,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::replace_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.replace_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedPersistentVolumeClaimResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -908,6 +977,7 @@ pub enum ReplaceCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceCoreV1NamespacedPersistentVolumeClaimResponse {
             _ => Ok((ReplaceCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedPersistentVolumeClaimStatus
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// replace status of the specified PersistentVolumeClaim
     ///
@@ -975,6 +1050,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::replace_core_v1_namespaced_persistent_volume_claim_status`](./struct.PersistentVolumeClaim.html#method.replace_core_v1_namespaced_persistent_volume_claim_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     Ok(::v1_10::api::core::v1::PersistentVolumeClaim),
@@ -983,6 +1059,7 @@ pub enum ReplaceCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedPersistentVolumeClaimStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceCoreV1NamespacedPersistentVolumeClaimStatusResponse {
             _ => Ok((ReplaceCoreV1NamespacedPersistentVolumeClaimStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedPersistentVolumeClaim
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// watch changes to an object of kind PersistentVolumeClaim
     ///
@@ -1113,6 +1195,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::watch_core_v1_namespaced_persistent_volume_claim`](./struct.PersistentVolumeClaim.html#method.watch_core_v1_namespaced_persistent_volume_claim)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedPersistentVolumeClaimResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1120,27 +1203,31 @@ pub enum WatchCoreV1NamespacedPersistentVolumeClaimResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedPersistentVolumeClaimResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedPersistentVolumeClaimResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedPersistentVolumeClaimResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedPersistentVolumeClaimResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedPersistentVolumeClaimResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedPersistentVolumeClaimList
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// watch individual changes to a list of PersistentVolumeClaim
     ///
@@ -1239,6 +1326,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::watch_core_v1_namespaced_persistent_volume_claim_list`](./struct.PersistentVolumeClaim.html#method.watch_core_v1_namespaced_persistent_volume_claim_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedPersistentVolumeClaimListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1246,27 +1334,31 @@ pub enum WatchCoreV1NamespacedPersistentVolumeClaimListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedPersistentVolumeClaimListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedPersistentVolumeClaimListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedPersistentVolumeClaimListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedPersistentVolumeClaimListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedPersistentVolumeClaimListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1PersistentVolumeClaimListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PersistentVolumeClaim {
     /// watch individual changes to a list of PersistentVolumeClaim
     ///
@@ -1360,6 +1452,7 @@ impl PersistentVolumeClaim {
 }
 
 /// Parses the HTTP response of [`PersistentVolumeClaim::watch_core_v1_persistent_volume_claim_list_for_all_namespaces`](./struct.PersistentVolumeClaim.html#method.watch_core_v1_persistent_volume_claim_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1PersistentVolumeClaimListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/PersistentVolumeClaim
@@ -1507,3 +1603,30 @@ impl ::serde::Serialize for PersistentVolumeClaim {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PersistentVolumeClaim`](./struct.PersistentVolumeClaim.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PersistentVolumeClaimField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for PersistentVolumeClaimField {
+    fn path(&self) -> &'static str {
+        match self {
+            PersistentVolumeClaimField::MetadataName => "metadata.name",
+            PersistentVolumeClaimField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PersistentVolumeClaimField::MetadataName),
+            "metadata.namespace" => Some(PersistentVolumeClaimField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct ReplicationController {
 
 // Generated from operation createCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// create a ReplicationController
     ///
@@ -58,6 +59,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::create_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.create_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedReplicationControllerResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -67,6 +69,7 @@ pub enum CreateCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateCoreV1NamespacedReplicationControllerResponse {
             _ => Ok((CreateCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// delete collection of ReplicationController
     ///
@@ -200,6 +208,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::delete_core_v1_collection_namespaced_replication_controller`](./struct.ReplicationController.html#method.delete_core_v1_collection_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedReplicationControllerResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteCoreV1CollectionNamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedReplicationControllerResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedReplicationControllerResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedReplicationControllerResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedReplicationControllerResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedReplicationControllerResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// delete a ReplicationController
     ///
@@ -303,6 +308,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::delete_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.delete_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedReplicationControllerResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedReplicationControllerResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedReplicationControllerResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedReplicationControllerResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedReplicationControllerResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedReplicationControllerResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// list or watch objects of kind ReplicationController
     ///
@@ -441,6 +443,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::list_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.list_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedReplicationControllerResponse {
     Ok(::v1_10::api::core::v1::ReplicationControllerList),
@@ -448,6 +451,7 @@ pub enum ListCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -463,10 +467,27 @@ impl ::Response for ListCoreV1NamespacedReplicationControllerResponse {
             _ => Ok((ListCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedReplicationControllerResponse {
+    type List = ::v1_10::api::core::v1::ReplicationControllerList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedReplicationControllerResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1ReplicationControllerForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// list or watch objects of kind ReplicationController
     ///
@@ -560,6 +581,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::list_core_v1_replication_controller_for_all_namespaces`](./struct.ReplicationController.html#method.list_core_v1_replication_controller_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ReplicationControllerForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::ReplicationControllerList),
@@ -567,6 +589,7 @@ pub enum ListCoreV1ReplicationControllerForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ReplicationControllerForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListCoreV1ReplicationControllerForAllNamespacesResponse {
             _ => Ok((ListCoreV1ReplicationControllerForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ReplicationControllerForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::ReplicationControllerList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ReplicationControllerForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// partially update the specified ReplicationController
     ///
@@ -626,6 +666,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::patch_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.patch_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedReplicationControllerResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -633,6 +674,7 @@ pub enum PatchCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchCoreV1NamespacedReplicationControllerResponse {
             _ => Ok((PatchCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedReplicationControllerStatus
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// partially update status of the specified ReplicationController
     ///
@@ -692,6 +739,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::patch_core_v1_namespaced_replication_controller_status`](./struct.ReplicationController.html#method.patch_core_v1_namespaced_replication_controller_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedReplicationControllerStatusResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -699,6 +747,7 @@ pub enum PatchCoreV1NamespacedReplicationControllerStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedReplicationControllerStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchCoreV1NamespacedReplicationControllerStatusResponse {
             _ => Ok((PatchCoreV1NamespacedReplicationControllerStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// read the specified ReplicationController
     ///
@@ -771,6 +825,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::read_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.read_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedReplicationControllerResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -778,6 +833,7 @@ pub enum ReadCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadCoreV1NamespacedReplicationControllerResponse {
             _ => Ok((ReadCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedReplicationControllerStatus
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// read status of the specified ReplicationController
     ///
@@ -834,6 +895,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::read_core_v1_namespaced_replication_controller_status`](./struct.ReplicationController.html#method.read_core_v1_namespaced_replication_controller_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedReplicationControllerStatusResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -841,6 +903,7 @@ pub enum ReadCoreV1NamespacedReplicationControllerStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedReplicationControllerStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadCoreV1NamespacedReplicationControllerStatusResponse {
             _ => Ok((ReadCoreV1NamespacedReplicationControllerStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// replace the specified ReplicationController
     ///
@@ -900,6 +968,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::replace_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.replace_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedReplicationControllerResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -908,6 +977,7 @@ pub enum ReplaceCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceCoreV1NamespacedReplicationControllerResponse {
             _ => Ok((ReplaceCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedReplicationControllerStatus
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// replace status of the specified ReplicationController
     ///
@@ -975,6 +1050,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::replace_core_v1_namespaced_replication_controller_status`](./struct.ReplicationController.html#method.replace_core_v1_namespaced_replication_controller_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedReplicationControllerStatusResponse {
     Ok(::v1_10::api::core::v1::ReplicationController),
@@ -983,6 +1059,7 @@ pub enum ReplaceCoreV1NamespacedReplicationControllerStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedReplicationControllerStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceCoreV1NamespacedReplicationControllerStatusResponse {
             _ => Ok((ReplaceCoreV1NamespacedReplicationControllerStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedReplicationController
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// watch changes to an object of kind ReplicationController
     ///
@@ -1113,6 +1195,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::watch_core_v1_namespaced_replication_controller`](./struct.ReplicationController.html#method.watch_core_v1_namespaced_replication_controller)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedReplicationControllerResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1120,27 +1203,31 @@ pub enum WatchCoreV1NamespacedReplicationControllerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedReplicationControllerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedReplicationControllerResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedReplicationControllerResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedReplicationControllerResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedReplicationControllerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedReplicationControllerList
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// watch individual changes to a list of ReplicationController
     ///
@@ -1239,6 +1326,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::watch_core_v1_namespaced_replication_controller_list`](./struct.ReplicationController.html#method.watch_core_v1_namespaced_replication_controller_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedReplicationControllerListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1246,27 +1334,31 @@ pub enum WatchCoreV1NamespacedReplicationControllerListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedReplicationControllerListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedReplicationControllerListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedReplicationControllerListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedReplicationControllerListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedReplicationControllerListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1ReplicationControllerListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ReplicationController {
     /// watch individual changes to a list of ReplicationController
     ///
@@ -1360,6 +1452,7 @@ impl ReplicationController {
 }
 
 /// Parses the HTTP response of [`ReplicationController::watch_core_v1_replication_controller_list_for_all_namespaces`](./struct.ReplicationController.html#method.watch_core_v1_replication_controller_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1ReplicationControllerListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchCoreV1ReplicationControllerListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1ReplicationControllerListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1ReplicationControllerListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1ReplicationControllerListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1ReplicationControllerListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1ReplicationControllerListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/ReplicationController
@@ -1507,3 +1603,35 @@ impl ::serde::Serialize for ReplicationController {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ReplicationController`](./struct.ReplicationController.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplicationControllerField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+
+    /// `status.replicas`
+    StatusReplicas,
+}
+
+impl ::field_selector::Field for ReplicationControllerField {
+    fn path(&self) -> &'static str {
+        match self {
+            ReplicationControllerField::MetadataName => "metadata.name",
+            ReplicationControllerField::MetadataNamespace => "metadata.namespace",
+            ReplicationControllerField::StatusReplicas => "status.replicas",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ReplicationControllerField::MetadataName),
+            "metadata.namespace" => Some(ReplicationControllerField::MetadataNamespace),
+            "status.replicas" => Some(ReplicationControllerField::StatusReplicas),
+            _ => None,
+        }
+    }
+}
@@ -26,6 +26,7 @@ pub struct Secret {
 
 // Generated from operation createCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// create a Secret
     ///
@@ -61,6 +62,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::create_core_v1_namespaced_secret`](./struct.Secret.html#method.create_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedSecretResponse {
     Ok(::v1_10::api::core::v1::Secret),
@@ -70,6 +72,7 @@ pub enum CreateCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -101,10 +104,15 @@ impl ::Response for CreateCoreV1NamespacedSecretResponse {
             _ => Ok((CreateCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// delete collection of Secret
     ///
@@ -203,6 +211,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::delete_core_v1_collection_namespaced_secret`](./struct.Secret.html#method.delete_core_v1_collection_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedSecretResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -211,38 +220,34 @@ pub enum DeleteCoreV1CollectionNamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedSecretResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedSecretResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedSecretResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedSecretResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedSecretResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// delete a Secret
     ///
@@ -306,6 +311,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::delete_core_v1_namespaced_secret`](./struct.Secret.html#method.delete_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedSecretResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -314,38 +320,34 @@ pub enum DeleteCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedSecretResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedSecretResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedSecretResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedSecretResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedSecretResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// list or watch objects of kind Secret
     ///
@@ -444,6 +446,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::list_core_v1_namespaced_secret`](./struct.Secret.html#method.list_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedSecretResponse {
     Ok(::v1_10::api::core::v1::SecretList),
@@ -451,6 +454,7 @@ pub enum ListCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -466,10 +470,27 @@ impl ::Response for ListCoreV1NamespacedSecretResponse {
             _ => Ok((ListCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedSecretResponse {
+    type List = ::v1_10::api::core::v1::SecretList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedSecretResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1SecretForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Secret {
     /// list or watch objects of kind Secret
     ///
@@ -563,6 +584,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::list_core_v1_secret_for_all_namespaces`](./struct.Secret.html#method.list_core_v1_secret_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1SecretForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::SecretList),
@@ -570,6 +592,7 @@ pub enum ListCoreV1SecretForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1SecretForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -585,10 +608,27 @@ impl ::Response for ListCoreV1SecretForAllNamespacesResponse {
             _ => Ok((ListCoreV1SecretForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1SecretForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::SecretList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1SecretForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// partially update the specified Secret
     ///
@@ -629,6 +669,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::patch_core_v1_namespaced_secret`](./struct.Secret.html#method.patch_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedSecretResponse {
     Ok(::v1_10::api::core::v1::Secret),
@@ -636,6 +677,7 @@ pub enum PatchCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -651,10 +693,15 @@ impl ::Response for PatchCoreV1NamespacedSecretResponse {
             _ => Ok((PatchCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// read the specified Secret
     ///
@@ -708,6 +755,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::read_core_v1_namespaced_secret`](./struct.Secret.html#method.read_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedSecretResponse {
     Ok(::v1_10::api::core::v1::Secret),
@@ -715,6 +763,7 @@ pub enum ReadCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -730,10 +779,15 @@ impl ::Response for ReadCoreV1NamespacedSecretResponse {
             _ => Ok((ReadCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// replace the specified Secret
     ///
@@ -774,6 +828,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::replace_core_v1_namespaced_secret`](./struct.Secret.html#method.replace_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedSecretResponse {
     Ok(::v1_10::api::core::v1::Secret),
@@ -782,6 +837,7 @@ pub enum ReplaceCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -805,10 +861,15 @@ impl ::Response for ReplaceCoreV1NamespacedSecretResponse {
             _ => Ok((ReplaceCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedSecret
 
+#[cfg(feature = "api")]
 impl Secret {
     /// watch changes to an object of kind Secret
     ///
@@ -912,6 +973,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::watch_core_v1_namespaced_secret`](./struct.Secret.html#method.watch_core_v1_namespaced_secret)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedSecretResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -919,27 +981,31 @@ pub enum WatchCoreV1NamespacedSecretResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedSecretResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedSecretResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedSecretResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedSecretResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedSecretResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedSecretList
 
+#[cfg(feature = "api")]
 impl Secret {
     /// watch individual changes to a list of Secret
     ///
@@ -1038,6 +1104,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::watch_core_v1_namespaced_secret_list`](./struct.Secret.html#method.watch_core_v1_namespaced_secret_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedSecretListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1045,27 +1112,31 @@ pub enum WatchCoreV1NamespacedSecretListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedSecretListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedSecretListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedSecretListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedSecretListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedSecretListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1SecretListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Secret {
     /// watch individual changes to a list of Secret
     ///
@@ -1159,6 +1230,7 @@ impl Secret {
 }
 
 /// Parses the HTTP response of [`Secret::watch_core_v1_secret_list_for_all_namespaces`](./struct.Secret.html#method.watch_core_v1_secret_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1SecretListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1166,23 +1238,26 @@ pub enum WatchCoreV1SecretListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1SecretListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1SecretListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1SecretListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1SecretListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1SecretListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Secret
@@ -1316,3 +1391,35 @@ impl ::serde::Serialize for Secret {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Secret`](./struct.Secret.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SecretField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+
+    /// `type`
+    Type,
+}
+
+impl ::field_selector::Field for SecretField {
+    fn path(&self) -> &'static str {
+        match self {
+            SecretField::MetadataName => "metadata.name",
+            SecretField::MetadataNamespace => "metadata.namespace",
+            SecretField::Type => "type",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(SecretField::MetadataName),
+            "metadata.namespace" => Some(SecretField::MetadataNamespace),
+            "type" => Some(SecretField::Type),
+            _ => None,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct Service {
 
 // Generated from operation connectCoreV1DeleteNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect DELETE requests to proxy of Service
     ///
@@ -60,6 +61,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_delete_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_delete_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNamespacedServiceProxyResponse {
     Ok(String),
@@ -67,6 +69,7 @@ pub enum ConnectCoreV1DeleteNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -91,6 +94,7 @@ impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1DeleteNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect DELETE requests to proxy of Service
     ///
@@ -133,6 +137,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_delete_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_delete_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -140,6 +145,7 @@ pub enum ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -164,6 +170,7 @@ impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1GetNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect GET requests to proxy of Service
     ///
@@ -201,6 +208,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_get_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_get_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedServiceProxyResponse {
     Ok(String),
@@ -208,6 +216,7 @@ pub enum ConnectCoreV1GetNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -232,6 +241,7 @@ impl ::Response for ConnectCoreV1GetNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1GetNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect GET requests to proxy of Service
     ///
@@ -274,6 +284,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_get_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_get_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -281,6 +292,7 @@ pub enum ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -305,6 +317,7 @@ impl ::Response for ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PatchNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PATCH requests to proxy of Service
     ///
@@ -342,6 +355,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_patch_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_patch_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNamespacedServiceProxyResponse {
     Ok(String),
@@ -349,6 +363,7 @@ pub enum ConnectCoreV1PatchNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -373,6 +388,7 @@ impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1PatchNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PATCH requests to proxy of Service
     ///
@@ -415,6 +431,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_patch_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_patch_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -422,6 +439,7 @@ pub enum ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -446,6 +464,7 @@ impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PostNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect POST requests to proxy of Service
     ///
@@ -483,6 +502,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_post_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_post_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedServiceProxyResponse {
     Ok(String),
@@ -490,6 +510,7 @@ pub enum ConnectCoreV1PostNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -514,6 +535,7 @@ impl ::Response for ConnectCoreV1PostNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1PostNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect POST requests to proxy of Service
     ///
@@ -556,6 +578,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_post_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_post_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -563,6 +586,7 @@ pub enum ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -587,6 +611,7 @@ impl ::Response for ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PutNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PUT requests to proxy of Service
     ///
@@ -624,6 +649,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_put_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_put_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNamespacedServiceProxyResponse {
     Ok(String),
@@ -631,6 +657,7 @@ pub enum ConnectCoreV1PutNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -655,6 +682,7 @@ impl ::Response for ConnectCoreV1PutNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1PutNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PUT requests to proxy of Service
     ///
@@ -697,6 +725,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_put_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_put_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -704,6 +733,7 @@ pub enum ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -728,6 +758,7 @@ impl ::Response for ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation createCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// create a Service
     ///
@@ -763,6 +794,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::create_core_v1_namespaced_service`](./struct.Service.html#method.create_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedServiceResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -772,6 +804,7 @@ pub enum CreateCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -803,10 +836,15 @@ impl ::Response for CreateCoreV1NamespacedServiceResponse {
             _ => Ok((CreateCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// delete a Service
     ///
@@ -870,6 +908,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::delete_core_v1_namespaced_service`](./struct.Service.html#method.delete_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedServiceResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -878,38 +917,34 @@ pub enum DeleteCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedServiceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedServiceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedServiceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedServiceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedServiceResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// list or watch objects of kind Service
     ///
@@ -1008,6 +1043,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::list_core_v1_namespaced_service`](./struct.Service.html#method.list_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedServiceResponse {
     Ok(::v1_10::api::core::v1::ServiceList),
@@ -1015,6 +1051,7 @@ pub enum ListCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1030,10 +1067,27 @@ impl ::Response for ListCoreV1NamespacedServiceResponse {
             _ => Ok((ListCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedServiceResponse {
+    type List = ::v1_10::api::core::v1::ServiceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedServiceResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1ServiceForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Service {
     /// list or watch objects of kind Service
     ///
@@ -1127,6 +1181,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::list_core_v1_service_for_all_namespaces`](./struct.Service.html#method.list_core_v1_service_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ServiceForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::ServiceList),
@@ -1134,6 +1189,7 @@ pub enum ListCoreV1ServiceForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ServiceForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1149,10 +1205,27 @@ impl ::Response for ListCoreV1ServiceForAllNamespacesResponse {
             _ => Ok((ListCoreV1ServiceForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ServiceForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::ServiceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ServiceForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// partially update the specified Service
     ///
@@ -1193,6 +1266,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::patch_core_v1_namespaced_service`](./struct.Service.html#method.patch_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedServiceResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -1200,6 +1274,7 @@ pub enum PatchCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1215,10 +1290,15 @@ impl ::Response for PatchCoreV1NamespacedServiceResponse {
             _ => Ok((PatchCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedServiceStatus
 
+#[cfg(feature = "api")]
 impl Service {
     /// partially update status of the specified Service
     ///
@@ -1259,6 +1339,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::patch_core_v1_namespaced_service_status`](./struct.Service.html#method.patch_core_v1_namespaced_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedServiceStatusResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -1266,6 +1347,7 @@ pub enum PatchCoreV1NamespacedServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1281,10 +1363,15 @@ impl ::Response for PatchCoreV1NamespacedServiceStatusResponse {
             _ => Ok((PatchCoreV1NamespacedServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// read the specified Service
     ///
@@ -1338,6 +1425,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::read_core_v1_namespaced_service`](./struct.Service.html#method.read_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedServiceResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -1345,6 +1433,7 @@ pub enum ReadCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1360,10 +1449,15 @@ impl ::Response for ReadCoreV1NamespacedServiceResponse {
             _ => Ok((ReadCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedServiceStatus
 
+#[cfg(feature = "api")]
 impl Service {
     /// read status of the specified Service
     ///
@@ -1401,6 +1495,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::read_core_v1_namespaced_service_status`](./struct.Service.html#method.read_core_v1_namespaced_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedServiceStatusResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -1408,6 +1503,7 @@ pub enum ReadCoreV1NamespacedServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1423,10 +1519,15 @@ impl ::Response for ReadCoreV1NamespacedServiceStatusResponse {
             _ => Ok((ReadCoreV1NamespacedServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// replace the specified Service
     ///
@@ -1467,6 +1568,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::replace_core_v1_namespaced_service`](./struct.Service.html#method.replace_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedServiceResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -1475,6 +1577,7 @@ pub enum ReplaceCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1498,10 +1601,15 @@ impl ::Response for ReplaceCoreV1NamespacedServiceResponse {
             _ => Ok((ReplaceCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedServiceStatus
 
+#[cfg(feature = "api")]
 impl Service {
     /// replace status of the specified Service
     ///
@@ -1542,6 +1650,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::replace_core_v1_namespaced_service_status`](./struct.Service.html#method.replace_core_v1_namespaced_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedServiceStatusResponse {
     Ok(::v1_10::api::core::v1::Service),
@@ -1550,6 +1659,7 @@ pub enum ReplaceCoreV1NamespacedServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1573,10 +1683,15 @@ impl ::Response for ReplaceCoreV1NamespacedServiceStatusResponse {
             _ => Ok((ReplaceCoreV1NamespacedServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// watch changes to an object of kind Service
     ///
@@ -1680,6 +1795,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::watch_core_v1_namespaced_service`](./struct.Service.html#method.watch_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedServiceResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1687,27 +1803,31 @@ pub enum WatchCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedServiceResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedServiceResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedServiceResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedServiceList
 
+#[cfg(feature = "api")]
 impl Service {
     /// watch individual changes to a list of Service
     ///
@@ -1806,6 +1926,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::watch_core_v1_namespaced_service_list`](./struct.Service.html#method.watch_core_v1_namespaced_service_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedServiceListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1813,27 +1934,31 @@ pub enum WatchCoreV1NamespacedServiceListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedServiceListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedServiceListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedServiceListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedServiceListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedServiceListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1ServiceListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Service {
     /// watch individual changes to a list of Service
     ///
@@ -1927,6 +2052,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::watch_core_v1_service_list_for_all_namespaces`](./struct.Service.html#method.watch_core_v1_service_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1ServiceListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1934,23 +2060,26 @@ pub enum WatchCoreV1ServiceListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1ServiceListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Service
@@ -2074,3 +2203,30 @@ impl ::serde::Serialize for Service {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Service`](./struct.Service.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServiceField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for ServiceField {
+    fn path(&self) -> &'static str {
+        match self {
+            ServiceField::MetadataName => "metadata.name",
+            ServiceField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ServiceField::MetadataName),
+            "metadata.namespace" => Some(ServiceField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -26,6 +26,7 @@ pub struct ServiceAccount {
 
 // Generated from operation createCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// create a ServiceAccount
     ///
@@ -61,6 +62,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::create_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.create_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedServiceAccountResponse {
     Ok(::v1_10::api::core::v1::ServiceAccount),
@@ -70,6 +72,7 @@ pub enum CreateCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -101,10 +104,15 @@ impl ::Response for CreateCoreV1NamespacedServiceAccountResponse {
             _ => Ok((CreateCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// delete collection of ServiceAccount
     ///
@@ -203,6 +211,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::delete_core_v1_collection_namespaced_service_account`](./struct.ServiceAccount.html#method.delete_core_v1_collection_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedServiceAccountResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -211,38 +220,34 @@ pub enum DeleteCoreV1CollectionNamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedServiceAccountResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedServiceAccountResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedServiceAccountResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedServiceAccountResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedServiceAccountResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// delete a ServiceAccount
     ///
@@ -306,6 +311,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::delete_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.delete_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedServiceAccountResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -314,38 +320,34 @@ pub enum DeleteCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedServiceAccountResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedServiceAccountResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedServiceAccountResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedServiceAccountResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedServiceAccountResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// list or watch objects of kind ServiceAccount
     ///
@@ -444,6 +446,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::list_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.list_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedServiceAccountResponse {
     Ok(::v1_10::api::core::v1::ServiceAccountList),
@@ -451,6 +454,7 @@ pub enum ListCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -466,10 +470,27 @@ impl ::Response for ListCoreV1NamespacedServiceAccountResponse {
             _ => Ok((ListCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedServiceAccountResponse {
+    type List = ::v1_10::api::core::v1::ServiceAccountList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedServiceAccountResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1ServiceAccountForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// list or watch objects of kind ServiceAccount
     ///
@@ -563,6 +584,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::list_core_v1_service_account_for_all_namespaces`](./struct.ServiceAccount.html#method.list_core_v1_service_account_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ServiceAccountForAllNamespacesResponse {
     Ok(::v1_10::api::core::v1::ServiceAccountList),
@@ -570,6 +592,7 @@ pub enum ListCoreV1ServiceAccountForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ServiceAccountForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -585,10 +608,27 @@ impl ::Response for ListCoreV1ServiceAccountForAllNamespacesResponse {
             _ => Ok((ListCoreV1ServiceAccountForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ServiceAccountForAllNamespacesResponse {
+    type List = ::v1_10::api::core::v1::ServiceAccountList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ServiceAccountForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// partially update the specified ServiceAccount
     ///
@@ -629,6 +669,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::patch_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.patch_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedServiceAccountResponse {
     Ok(::v1_10::api::core::v1::ServiceAccount),
@@ -636,6 +677,7 @@ pub enum PatchCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -651,10 +693,15 @@ impl ::Response for PatchCoreV1NamespacedServiceAccountResponse {
             _ => Ok((PatchCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// read the specified ServiceAccount
     ///
@@ -708,6 +755,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::read_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.read_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedServiceAccountResponse {
     Ok(::v1_10::api::core::v1::ServiceAccount),
@@ -715,6 +763,7 @@ pub enum ReadCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -730,10 +779,15 @@ impl ::Response for ReadCoreV1NamespacedServiceAccountResponse {
             _ => Ok((ReadCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// replace the specified ServiceAccount
     ///
@@ -774,6 +828,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::replace_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.replace_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedServiceAccountResponse {
     Ok(::v1_10::api::core::v1::ServiceAccount),
@@ -782,6 +837,7 @@ pub enum ReplaceCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -805,10 +861,15 @@ impl ::Response for ReplaceCoreV1NamespacedServiceAccountResponse {
             _ => Ok((ReplaceCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedServiceAccount
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// watch changes to an object of kind ServiceAccount
     ///
@@ -912,6 +973,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::watch_core_v1_namespaced_service_account`](./struct.ServiceAccount.html#method.watch_core_v1_namespaced_service_account)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedServiceAccountResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -919,27 +981,31 @@ pub enum WatchCoreV1NamespacedServiceAccountResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedServiceAccountResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedServiceAccountResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedServiceAccountResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedServiceAccountResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedServiceAccountResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedServiceAccountList
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// watch individual changes to a list of ServiceAccount
     ///
@@ -1038,6 +1104,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::watch_core_v1_namespaced_service_account_list`](./struct.ServiceAccount.html#method.watch_core_v1_namespaced_service_account_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedServiceAccountListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1045,27 +1112,31 @@ pub enum WatchCoreV1NamespacedServiceAccountListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedServiceAccountListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedServiceAccountListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedServiceAccountListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedServiceAccountListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedServiceAccountListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1ServiceAccountListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ServiceAccount {
     /// watch individual changes to a list of ServiceAccount
     ///
@@ -1159,6 +1230,7 @@ impl ServiceAccount {
 }
 
 /// Parses the HTTP response of [`ServiceAccount::watch_core_v1_service_account_list_for_all_namespaces`](./struct.ServiceAccount.html#method.watch_core_v1_service_account_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1ServiceAccountListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1166,23 +1238,26 @@ pub enum WatchCoreV1ServiceAccountListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1ServiceAccountListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1ServiceAccountListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1ServiceAccountListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1ServiceAccountListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1ServiceAccountListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/ServiceAccount
@@ -1316,3 +1391,30 @@ impl ::serde::Serialize for ServiceAccount {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ServiceAccount`](./struct.ServiceAccount.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServiceAccountField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for ServiceAccountField {
+    fn path(&self) -> &'static str {
+        match self {
+            ServiceAccountField::MetadataName => "metadata.name",
+            ServiceAccountField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ServiceAccountField::MetadataName),
+            "metadata.namespace" => Some(ServiceAccountField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
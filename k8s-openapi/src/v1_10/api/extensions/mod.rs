@@ -1 +1,2 @@
+#[cfg(feature = "v1_10-extensions-v1beta1")]
 pub mod v1beta1;
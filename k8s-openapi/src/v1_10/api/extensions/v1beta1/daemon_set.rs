@@ -23,6 +23,7 @@ pub struct DaemonSet {
 
 // Generated from operation createExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// create a DaemonSet
     ///
@@ -58,6 +59,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::create_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.create_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateExtensionsV1beta1NamespacedDaemonSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -67,6 +69,7 @@ pub enum CreateExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateExtensionsV1beta1NamespacedDaemonSetResponse {
             _ => Ok((CreateExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1CollectionNamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// delete collection of DaemonSet
     ///
@@ -200,6 +208,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::delete_extensions_v1beta1_collection_namespaced_daemon_set`](./struct.DaemonSet.html#method.delete_extensions_v1beta1_collection_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1CollectionNamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// delete a DaemonSet
     ///
@@ -303,6 +308,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::delete_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.delete_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1NamespacedDaemonSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedDaemonSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedDaemonSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1NamespacedDaemonSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1NamespacedDaemonSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1NamespacedDaemonSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listExtensionsV1beta1DaemonSetForAllNamespaces
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// list or watch objects of kind DaemonSet
     ///
@@ -436,6 +438,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::list_extensions_v1beta1_daemon_set_for_all_namespaces`](./struct.DaemonSet.html#method.list_extensions_v1beta1_daemon_set_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1DaemonSetForAllNamespacesResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSetList),
@@ -443,6 +446,7 @@ pub enum ListExtensionsV1beta1DaemonSetForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1DaemonSetForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListExtensionsV1beta1DaemonSetForAllNamespacesResponse {
             _ => Ok((ListExtensionsV1beta1DaemonSetForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1DaemonSetForAllNamespacesResponse {
+    type List = ::v1_10::api::extensions::v1beta1::DaemonSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1DaemonSetForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// list or watch objects of kind DaemonSet
     ///
@@ -560,6 +581,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::list_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.list_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1NamespacedDaemonSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSetList),
@@ -567,6 +589,7 @@ pub enum ListExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListExtensionsV1beta1NamespacedDaemonSetResponse {
             _ => Ok((ListExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1NamespacedDaemonSetResponse {
+    type List = ::v1_10::api::extensions::v1beta1::DaemonSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1NamespacedDaemonSetResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// partially update the specified DaemonSet
     ///
@@ -626,6 +666,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::patch_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.patch_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedDaemonSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -633,6 +674,7 @@ pub enum PatchExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedDaemonSetResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedDaemonSetStatus
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// partially update status of the specified DaemonSet
     ///
@@ -692,6 +739,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::patch_extensions_v1beta1_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.patch_extensions_v1beta1_namespaced_daemon_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -699,6 +747,7 @@ pub enum PatchExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedDaemonSetStatusResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedDaemonSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// read the specified DaemonSet
     ///
@@ -771,6 +825,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::read_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.read_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedDaemonSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -778,6 +833,7 @@ pub enum ReadExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedDaemonSetResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedDaemonSetStatus
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// read status of the specified DaemonSet
     ///
@@ -834,6 +895,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::read_extensions_v1beta1_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.read_extensions_v1beta1_namespaced_daemon_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -841,6 +903,7 @@ pub enum ReadExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedDaemonSetStatusResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedDaemonSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// replace the specified DaemonSet
     ///
@@ -900,6 +968,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::replace_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.replace_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedDaemonSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -908,6 +977,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedDaemonSetResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedDaemonSetStatus
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// replace status of the specified DaemonSet
     ///
@@ -975,6 +1050,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::replace_extensions_v1beta1_namespaced_daemon_set_status`](./struct.DaemonSet.html#method.replace_extensions_v1beta1_namespaced_daemon_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::DaemonSet),
@@ -983,6 +1059,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedDaemonSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedDaemonSetStatusResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedDaemonSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1DaemonSetListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// watch individual changes to a list of DaemonSet
     ///
@@ -1103,6 +1185,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::watch_extensions_v1beta1_daemon_set_list_for_all_namespaces`](./struct.DaemonSet.html#method.watch_extensions_v1beta1_daemon_set_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1DaemonSetListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedDaemonSet
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// watch changes to an object of kind DaemonSet
     ///
@@ -1234,6 +1321,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::watch_extensions_v1beta1_namespaced_daemon_set`](./struct.DaemonSet.html#method.watch_extensions_v1beta1_namespaced_daemon_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedDaemonSetResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchExtensionsV1beta1NamespacedDaemonSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedDaemonSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedDaemonSetResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedDaemonSetResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedDaemonSetResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedDaemonSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedDaemonSetList
 
+#[cfg(feature = "api")]
 impl DaemonSet {
     /// watch individual changes to a list of DaemonSet
     ///
@@ -1360,6 +1452,7 @@ impl DaemonSet {
 }
 
 /// Parses the HTTP response of [`DaemonSet::watch_extensions_v1beta1_namespaced_daemon_set_list`](./struct.DaemonSet.html#method.watch_extensions_v1beta1_namespaced_daemon_set_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedDaemonSetListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchExtensionsV1beta1NamespacedDaemonSetListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedDaemonSetListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedDaemonSetListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedDaemonSetListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedDaemonSetListResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedDaemonSetListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End extensions/v1beta1/DaemonSet
@@ -1507,3 +1603,52 @@ impl ::serde::Serialize for DaemonSet {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`DaemonSet`](./struct.DaemonSet.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DaemonSetField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for DaemonSetField {
+    fn path(&self) -> &'static str {
+        match self {
+            DaemonSetField::MetadataName => "metadata.name",
+            DaemonSetField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(DaemonSetField::MetadataName),
+            "metadata.namespace" => Some(DaemonSetField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1::DaemonSet> for DaemonSet {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1::DaemonSet) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1beta2::DaemonSet> for DaemonSet {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1beta2::DaemonSet) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
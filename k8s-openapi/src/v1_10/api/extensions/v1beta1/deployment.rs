@@ -23,6 +23,7 @@ pub struct Deployment {
 
 // Generated from operation createExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// create a Deployment
     ///
@@ -58,6 +59,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::create_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.create_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateExtensionsV1beta1NamespacedDeploymentResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -67,6 +69,7 @@ pub enum CreateExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateExtensionsV1beta1NamespacedDeploymentResponse {
             _ => Ok((CreateExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1CollectionNamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// delete collection of Deployment
     ///
@@ -200,6 +208,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::delete_extensions_v1beta1_collection_namespaced_deployment`](./struct.Deployment.html#method.delete_extensions_v1beta1_collection_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1CollectionNamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// delete a Deployment
     ///
@@ -303,6 +308,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::delete_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.delete_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1NamespacedDeploymentResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedDeploymentResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedDeploymentResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1NamespacedDeploymentResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1NamespacedDeploymentResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1NamespacedDeploymentResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listExtensionsV1beta1DeploymentForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// list or watch objects of kind Deployment
     ///
@@ -436,6 +438,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::list_extensions_v1beta1_deployment_for_all_namespaces`](./struct.Deployment.html#method.list_extensions_v1beta1_deployment_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1DeploymentForAllNamespacesResponse {
     Ok(::v1_10::api::extensions::v1beta1::DeploymentList),
@@ -443,6 +446,7 @@ pub enum ListExtensionsV1beta1DeploymentForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1DeploymentForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListExtensionsV1beta1DeploymentForAllNamespacesResponse {
             _ => Ok((ListExtensionsV1beta1DeploymentForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1DeploymentForAllNamespacesResponse {
+    type List = ::v1_10::api::extensions::v1beta1::DeploymentList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1DeploymentForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// list or watch objects of kind Deployment
     ///
@@ -560,6 +581,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::list_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.list_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1NamespacedDeploymentResponse {
     Ok(::v1_10::api::extensions::v1beta1::DeploymentList),
@@ -567,6 +589,7 @@ pub enum ListExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListExtensionsV1beta1NamespacedDeploymentResponse {
             _ => Ok((ListExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1NamespacedDeploymentResponse {
+    type List = ::v1_10::api::extensions::v1beta1::DeploymentList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1NamespacedDeploymentResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// partially update the specified Deployment
     ///
@@ -626,6 +666,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::patch_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.patch_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedDeploymentResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -633,6 +674,7 @@ pub enum PatchExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedDeploymentResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedDeploymentStatus
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// partially update status of the specified Deployment
     ///
@@ -692,6 +739,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::patch_extensions_v1beta1_namespaced_deployment_status`](./struct.Deployment.html#method.patch_extensions_v1beta1_namespaced_deployment_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedDeploymentStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -699,6 +747,7 @@ pub enum PatchExtensionsV1beta1NamespacedDeploymentStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedDeploymentStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedDeploymentStatusResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedDeploymentStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// read the specified Deployment
     ///
@@ -771,6 +825,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::read_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.read_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedDeploymentResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -778,6 +833,7 @@ pub enum ReadExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedDeploymentResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedDeploymentStatus
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// read status of the specified Deployment
     ///
@@ -834,6 +895,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::read_extensions_v1beta1_namespaced_deployment_status`](./struct.Deployment.html#method.read_extensions_v1beta1_namespaced_deployment_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedDeploymentStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -841,6 +903,7 @@ pub enum ReadExtensionsV1beta1NamespacedDeploymentStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedDeploymentStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedDeploymentStatusResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedDeploymentStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// replace the specified Deployment
     ///
@@ -900,6 +968,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::replace_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.replace_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedDeploymentResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -908,6 +977,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedDeploymentResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedDeploymentStatus
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// replace status of the specified Deployment
     ///
@@ -975,6 +1050,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::replace_extensions_v1beta1_namespaced_deployment_status`](./struct.Deployment.html#method.replace_extensions_v1beta1_namespaced_deployment_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedDeploymentStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::Deployment),
@@ -983,6 +1059,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedDeploymentStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedDeploymentStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedDeploymentStatusResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedDeploymentStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1DeploymentListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// watch individual changes to a list of Deployment
     ///
@@ -1103,6 +1185,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::watch_extensions_v1beta1_deployment_list_for_all_namespaces`](./struct.Deployment.html#method.watch_extensions_v1beta1_deployment_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1DeploymentListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedDeployment
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// watch changes to an object of kind Deployment
     ///
@@ -1234,6 +1321,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::watch_extensions_v1beta1_namespaced_deployment`](./struct.Deployment.html#method.watch_extensions_v1beta1_namespaced_deployment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedDeploymentResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchExtensionsV1beta1NamespacedDeploymentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedDeploymentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedDeploymentResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedDeploymentResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedDeploymentResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedDeploymentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedDeploymentList
 
+#[cfg(feature = "api")]
 impl Deployment {
     /// watch individual changes to a list of Deployment
     ///
@@ -1360,6 +1452,7 @@ impl Deployment {
 }
 
 /// Parses the HTTP response of [`Deployment::watch_extensions_v1beta1_namespaced_deployment_list`](./struct.Deployment.html#method.watch_extensions_v1beta1_namespaced_deployment_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedDeploymentListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchExtensionsV1beta1NamespacedDeploymentListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedDeploymentListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedDeploymentListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedDeploymentListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedDeploymentListResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedDeploymentListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End extensions/v1beta1/Deployment
@@ -1507,3 +1603,65 @@ impl ::serde::Serialize for Deployment {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Deployment`](./struct.Deployment.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeploymentField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for DeploymentField {
+    fn path(&self) -> &'static str {
+        match self {
+            DeploymentField::MetadataName => "metadata.name",
+            DeploymentField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(DeploymentField::MetadataName),
+            "metadata.namespace" => Some(DeploymentField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1::Deployment> for Deployment {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1::Deployment) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl From<::v1_10::api::apps::v1beta1::Deployment> for Deployment {
+    fn from(value: ::v1_10::api::apps::v1beta1::Deployment) -> Self {
+        Deployment {
+            api_version: value.api_version.map(|_| "extensions/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1beta2::Deployment> for Deployment {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1beta2::Deployment) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
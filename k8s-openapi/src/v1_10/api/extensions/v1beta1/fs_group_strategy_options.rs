@@ -99,3 +99,13 @@ impl ::serde::Serialize for FSGroupStrategyOptions {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-policy-v1beta1")]
+impl From<::v1_10::api::policy::v1beta1::FSGroupStrategyOptions> for FSGroupStrategyOptions {
+    fn from(value: ::v1_10::api::policy::v1beta1::FSGroupStrategyOptions) -> Self {
+        FSGroupStrategyOptions {
+            ranges: value.ranges.map(|value| value.into_iter().map(Into::into).collect()),
+            rule: value.rule,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct Ingress {
 
 // Generated from operation createExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// create an Ingress
     ///
@@ -58,6 +59,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::create_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.create_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateExtensionsV1beta1NamespacedIngressResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -67,6 +69,7 @@ pub enum CreateExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateExtensionsV1beta1NamespacedIngressResponse {
             _ => Ok((CreateExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1CollectionNamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// delete collection of Ingress
     ///
@@ -200,6 +208,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::delete_extensions_v1beta1_collection_namespaced_ingress`](./struct.Ingress.html#method.delete_extensions_v1beta1_collection_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1CollectionNamespacedIngressResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteExtensionsV1beta1CollectionNamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1CollectionNamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedIngressResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedIngressResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedIngressResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedIngressResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1CollectionNamespacedIngressResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1CollectionNamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// delete an Ingress
     ///
@@ -303,6 +308,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::delete_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.delete_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1NamespacedIngressResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedIngressResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedIngressResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1NamespacedIngressResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1NamespacedIngressResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1NamespacedIngressResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listExtensionsV1beta1IngressForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// list or watch objects of kind Ingress
     ///
@@ -436,6 +438,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::list_extensions_v1beta1_ingress_for_all_namespaces`](./struct.Ingress.html#method.list_extensions_v1beta1_ingress_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1IngressForAllNamespacesResponse {
     Ok(::v1_10::api::extensions::v1beta1::IngressList),
@@ -443,6 +446,7 @@ pub enum ListExtensionsV1beta1IngressForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1IngressForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListExtensionsV1beta1IngressForAllNamespacesResponse {
             _ => Ok((ListExtensionsV1beta1IngressForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1IngressForAllNamespacesResponse {
+    type List = ::v1_10::api::extensions::v1beta1::IngressList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1IngressForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// list or watch objects of kind Ingress
     ///
@@ -560,6 +581,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::list_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.list_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1NamespacedIngressResponse {
     Ok(::v1_10::api::extensions::v1beta1::IngressList),
@@ -567,6 +589,7 @@ pub enum ListExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListExtensionsV1beta1NamespacedIngressResponse {
             _ => Ok((ListExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1NamespacedIngressResponse {
+    type List = ::v1_10::api::extensions::v1beta1::IngressList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1NamespacedIngressResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// partially update the specified Ingress
     ///
@@ -626,6 +666,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::patch_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.patch_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedIngressResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -633,6 +674,7 @@ pub enum PatchExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedIngressResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedIngressStatus
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// partially update status of the specified Ingress
     ///
@@ -692,6 +739,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::patch_extensions_v1beta1_namespaced_ingress_status`](./struct.Ingress.html#method.patch_extensions_v1beta1_namespaced_ingress_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedIngressStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -699,6 +747,7 @@ pub enum PatchExtensionsV1beta1NamespacedIngressStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedIngressStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedIngressStatusResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedIngressStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// read the specified Ingress
     ///
@@ -771,6 +825,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::read_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.read_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedIngressResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -778,6 +833,7 @@ pub enum ReadExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedIngressResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedIngressStatus
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// read status of the specified Ingress
     ///
@@ -834,6 +895,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::read_extensions_v1beta1_namespaced_ingress_status`](./struct.Ingress.html#method.read_extensions_v1beta1_namespaced_ingress_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedIngressStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -841,6 +903,7 @@ pub enum ReadExtensionsV1beta1NamespacedIngressStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedIngressStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedIngressStatusResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedIngressStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// replace the specified Ingress
     ///
@@ -900,6 +968,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::replace_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.replace_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedIngressResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -908,6 +977,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedIngressResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedIngressStatus
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// replace status of the specified Ingress
     ///
@@ -975,6 +1050,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::replace_extensions_v1beta1_namespaced_ingress_status`](./struct.Ingress.html#method.replace_extensions_v1beta1_namespaced_ingress_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedIngressStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::Ingress),
@@ -983,6 +1059,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedIngressStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedIngressStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedIngressStatusResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedIngressStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1IngressListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// watch individual changes to a list of Ingress
     ///
@@ -1103,6 +1185,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::watch_extensions_v1beta1_ingress_list_for_all_namespaces`](./struct.Ingress.html#method.watch_extensions_v1beta1_ingress_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1IngressListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchExtensionsV1beta1IngressListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1IngressListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1IngressListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1IngressListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1IngressListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1IngressListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedIngress
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// watch changes to an object of kind Ingress
     ///
@@ -1234,6 +1321,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::watch_extensions_v1beta1_namespaced_ingress`](./struct.Ingress.html#method.watch_extensions_v1beta1_namespaced_ingress)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedIngressResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchExtensionsV1beta1NamespacedIngressResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedIngressResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedIngressResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedIngressResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedIngressResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedIngressResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedIngressList
 
+#[cfg(feature = "api")]
 impl Ingress {
     /// watch individual changes to a list of Ingress
     ///
@@ -1360,6 +1452,7 @@ impl Ingress {
 }
 
 /// Parses the HTTP response of [`Ingress::watch_extensions_v1beta1_namespaced_ingress_list`](./struct.Ingress.html#method.watch_extensions_v1beta1_namespaced_ingress_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedIngressListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchExtensionsV1beta1NamespacedIngressListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedIngressListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedIngressListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedIngressListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedIngressListResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedIngressListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End extensions/v1beta1/Ingress
@@ -1507,3 +1603,30 @@ impl ::serde::Serialize for Ingress {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Ingress`](./struct.Ingress.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IngressField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for IngressField {
+    fn path(&self) -> &'static str {
+        match self {
+            IngressField::MetadataName => "metadata.name",
+            IngressField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(IngressField::MetadataName),
+            "metadata.namespace" => Some(IngressField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
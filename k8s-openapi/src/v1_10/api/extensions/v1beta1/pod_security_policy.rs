@@ -20,6 +20,7 @@ pub struct PodSecurityPolicy {
 
 // Generated from operation createExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// create a PodSecurityPolicy
     ///
@@ -50,6 +51,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::create_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.create_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateExtensionsV1beta1PodSecurityPolicyResponse {
     Ok(::v1_10::api::extensions::v1beta1::PodSecurityPolicy),
@@ -59,6 +61,7 @@ pub enum CreateExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -90,10 +93,15 @@ impl ::Response for CreateExtensionsV1beta1PodSecurityPolicyResponse {
             _ => Ok((CreateExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1CollectionPodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// delete collection of PodSecurityPolicy
     ///
@@ -187,6 +195,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::delete_extensions_v1beta1_collection_pod_security_policy`](./struct.PodSecurityPolicy.html#method.delete_extensions_v1beta1_collection_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -195,38 +204,34 @@ pub enum DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1CollectionPodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// delete a PodSecurityPolicy
     ///
@@ -285,6 +290,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::delete_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.delete_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1PodSecurityPolicyResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -293,38 +299,34 @@ pub enum DeleteExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1PodSecurityPolicyResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1PodSecurityPolicyResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1PodSecurityPolicyResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1PodSecurityPolicyResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1PodSecurityPolicyResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// list or watch objects of kind PodSecurityPolicy
     ///
@@ -418,6 +420,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::list_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.list_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1PodSecurityPolicyResponse {
     Ok(::v1_10::api::extensions::v1beta1::PodSecurityPolicyList),
@@ -425,6 +428,7 @@ pub enum ListExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -440,10 +444,27 @@ impl ::Response for ListExtensionsV1beta1PodSecurityPolicyResponse {
             _ => Ok((ListExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1PodSecurityPolicyResponse {
+    type List = ::v1_10::api::extensions::v1beta1::PodSecurityPolicyList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1PodSecurityPolicyResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// partially update the specified PodSecurityPolicy
     ///
@@ -479,6 +500,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::patch_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.patch_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1PodSecurityPolicyResponse {
     Ok(::v1_10::api::extensions::v1beta1::PodSecurityPolicy),
@@ -486,6 +508,7 @@ pub enum PatchExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -501,10 +524,15 @@ impl ::Response for PatchExtensionsV1beta1PodSecurityPolicyResponse {
             _ => Ok((PatchExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// read the specified PodSecurityPolicy
     ///
@@ -553,6 +581,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::read_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.read_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1PodSecurityPolicyResponse {
     Ok(::v1_10::api::extensions::v1beta1::PodSecurityPolicy),
@@ -560,6 +589,7 @@ pub enum ReadExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -575,10 +605,15 @@ impl ::Response for ReadExtensionsV1beta1PodSecurityPolicyResponse {
             _ => Ok((ReadExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// replace the specified PodSecurityPolicy
     ///
@@ -614,6 +649,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::replace_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.replace_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1PodSecurityPolicyResponse {
     Ok(::v1_10::api::extensions::v1beta1::PodSecurityPolicy),
@@ -622,6 +658,7 @@ pub enum ReplaceExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +682,15 @@ impl ::Response for ReplaceExtensionsV1beta1PodSecurityPolicyResponse {
             _ => Ok((ReplaceExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1PodSecurityPolicy
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// watch changes to an object of kind PodSecurityPolicy
     ///
@@ -747,6 +789,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::watch_extensions_v1beta1_pod_security_policy`](./struct.PodSecurityPolicy.html#method.watch_extensions_v1beta1_pod_security_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1PodSecurityPolicyResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -754,27 +797,31 @@ pub enum WatchExtensionsV1beta1PodSecurityPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1PodSecurityPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1PodSecurityPolicyResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1PodSecurityPolicyResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1PodSecurityPolicyResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1PodSecurityPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1PodSecurityPolicyList
 
+#[cfg(feature = "api")]
 impl PodSecurityPolicy {
     /// watch individual changes to a list of PodSecurityPolicy
     ///
@@ -868,6 +915,7 @@ impl PodSecurityPolicy {
 }
 
 /// Parses the HTTP response of [`PodSecurityPolicy::watch_extensions_v1beta1_pod_security_policy_list`](./struct.PodSecurityPolicy.html#method.watch_extensions_v1beta1_pod_security_policy_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1PodSecurityPolicyListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -875,23 +923,26 @@ pub enum WatchExtensionsV1beta1PodSecurityPolicyListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1PodSecurityPolicyListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1PodSecurityPolicyListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1PodSecurityPolicyListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1PodSecurityPolicyListResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1PodSecurityPolicyListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End extensions/v1beta1/PodSecurityPolicy
@@ -1005,3 +1056,37 @@ impl ::serde::Serialize for PodSecurityPolicy {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PodSecurityPolicy`](./struct.PodSecurityPolicy.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PodSecurityPolicyField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for PodSecurityPolicyField {
+    fn path(&self) -> &'static str {
+        match self {
+            PodSecurityPolicyField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PodSecurityPolicyField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-policy-v1beta1")]
+impl From<::v1_10::api::policy::v1beta1::PodSecurityPolicy> for PodSecurityPolicy {
+    fn from(value: ::v1_10::api::policy::v1beta1::PodSecurityPolicy) -> Self {
+        PodSecurityPolicy {
+            api_version: value.api_version.map(|_| "extensions/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+        }
+    }
+}
@@ -299,3 +299,29 @@ impl ::serde::Serialize for PodSecurityPolicySpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-policy-v1beta1")]
+impl From<::v1_10::api::policy::v1beta1::PodSecurityPolicySpec> for PodSecurityPolicySpec {
+    fn from(value: ::v1_10::api::policy::v1beta1::PodSecurityPolicySpec) -> Self {
+        PodSecurityPolicySpec {
+            allow_privilege_escalation: value.allow_privilege_escalation,
+            allowed_capabilities: value.allowed_capabilities,
+            allowed_flex_volumes: value.allowed_flex_volumes.map(|value| value.into_iter().map(Into::into).collect()),
+            allowed_host_paths: value.allowed_host_paths.map(|value| value.into_iter().map(Into::into).collect()),
+            default_add_capabilities: value.default_add_capabilities,
+            default_allow_privilege_escalation: value.default_allow_privilege_escalation,
+            fs_group: value.fs_group.into(),
+            host_ipc: value.host_ipc,
+            host_network: value.host_network,
+            host_pid: value.host_pid,
+            host_ports: value.host_ports.map(|value| value.into_iter().map(Into::into).collect()),
+            privileged: value.privileged,
+            read_only_root_filesystem: value.read_only_root_filesystem,
+            required_drop_capabilities: value.required_drop_capabilities,
+            run_as_user: value.run_as_user.into(),
+            se_linux: value.se_linux.into(),
+            supplemental_groups: value.supplemental_groups.into(),
+            volumes: value.volumes,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct ReplicaSet {
 
 // Generated from operation createExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// create a ReplicaSet
     ///
@@ -58,6 +59,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::create_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.create_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateExtensionsV1beta1NamespacedReplicaSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -67,6 +69,7 @@ pub enum CreateExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateExtensionsV1beta1NamespacedReplicaSetResponse {
             _ => Ok((CreateExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1CollectionNamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// delete collection of ReplicaSet
     ///
@@ -200,6 +208,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::delete_extensions_v1beta1_collection_namespaced_replica_set`](./struct.ReplicaSet.html#method.delete_extensions_v1beta1_collection_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1CollectionNamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// delete a ReplicaSet
     ///
@@ -303,6 +308,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::delete_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.delete_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1NamespacedReplicaSetResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedReplicaSetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1NamespacedReplicaSetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1NamespacedReplicaSetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1NamespacedReplicaSetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1NamespacedReplicaSetResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// list or watch objects of kind ReplicaSet
     ///
@@ -441,6 +443,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::list_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.list_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1NamespacedReplicaSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSetList),
@@ -448,6 +451,7 @@ pub enum ListExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -463,10 +467,27 @@ impl ::Response for ListExtensionsV1beta1NamespacedReplicaSetResponse {
             _ => Ok((ListExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1NamespacedReplicaSetResponse {
+    type List = ::v1_10::api::extensions::v1beta1::ReplicaSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1NamespacedReplicaSetResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listExtensionsV1beta1ReplicaSetForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// list or watch objects of kind ReplicaSet
     ///
@@ -560,6 +581,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::list_extensions_v1beta1_replica_set_for_all_namespaces`](./struct.ReplicaSet.html#method.list_extensions_v1beta1_replica_set_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSetList),
@@ -567,6 +589,7 @@ pub enum ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse {
             _ => Ok((ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse {
+    type List = ::v1_10::api::extensions::v1beta1::ReplicaSetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1ReplicaSetForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// partially update the specified ReplicaSet
     ///
@@ -626,6 +666,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::patch_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.patch_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedReplicaSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -633,6 +674,7 @@ pub enum PatchExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedReplicaSetResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedReplicaSetStatus
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// partially update status of the specified ReplicaSet
     ///
@@ -692,6 +739,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::patch_extensions_v1beta1_namespaced_replica_set_status`](./struct.ReplicaSet.html#method.patch_extensions_v1beta1_namespaced_replica_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -699,6 +747,7 @@ pub enum PatchExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedReplicaSetStatusResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedReplicaSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// read the specified ReplicaSet
     ///
@@ -771,6 +825,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::read_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.read_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedReplicaSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -778,6 +833,7 @@ pub enum ReadExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedReplicaSetResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedReplicaSetStatus
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// read status of the specified ReplicaSet
     ///
@@ -834,6 +895,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::read_extensions_v1beta1_namespaced_replica_set_status`](./struct.ReplicaSet.html#method.read_extensions_v1beta1_namespaced_replica_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -841,6 +903,7 @@ pub enum ReadExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedReplicaSetStatusResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedReplicaSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// replace the specified ReplicaSet
     ///
@@ -900,6 +968,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::replace_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.replace_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedReplicaSetResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -908,6 +977,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicaSetResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedReplicaSetStatus
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// replace status of the specified ReplicaSet
     ///
@@ -975,6 +1050,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::replace_extensions_v1beta1_namespaced_replica_set_status`](./struct.ReplicaSet.html#method.replace_extensions_v1beta1_namespaced_replica_set_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     Ok(::v1_10::api::extensions::v1beta1::ReplicaSet),
@@ -983,6 +1059,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicaSetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicaSetStatusResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedReplicaSetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedReplicaSet
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// watch changes to an object of kind ReplicaSet
     ///
@@ -1113,6 +1195,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::watch_extensions_v1beta1_namespaced_replica_set`](./struct.ReplicaSet.html#method.watch_extensions_v1beta1_namespaced_replica_set)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedReplicaSetResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1120,27 +1203,31 @@ pub enum WatchExtensionsV1beta1NamespacedReplicaSetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedReplicaSetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedReplicaSetResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedReplicaSetResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedReplicaSetResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedReplicaSetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1NamespacedReplicaSetList
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// watch individual changes to a list of ReplicaSet
     ///
@@ -1239,6 +1326,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::watch_extensions_v1beta1_namespaced_replica_set_list`](./struct.ReplicaSet.html#method.watch_extensions_v1beta1_namespaced_replica_set_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1NamespacedReplicaSetListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1246,27 +1334,31 @@ pub enum WatchExtensionsV1beta1NamespacedReplicaSetListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1NamespacedReplicaSetListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1NamespacedReplicaSetListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1NamespacedReplicaSetListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1NamespacedReplicaSetListResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1NamespacedReplicaSetListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1ReplicaSetListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ReplicaSet {
     /// watch individual changes to a list of ReplicaSet
     ///
@@ -1360,6 +1452,7 @@ impl ReplicaSet {
 }
 
 /// Parses the HTTP response of [`ReplicaSet::watch_extensions_v1beta1_replica_set_list_for_all_namespaces`](./struct.ReplicaSet.html#method.watch_extensions_v1beta1_replica_set_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1ReplicaSetListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End extensions/v1beta1/ReplicaSet
@@ -1507,3 +1603,57 @@ impl ::serde::Serialize for ReplicaSet {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ReplicaSet`](./struct.ReplicaSet.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplicaSetField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+
+    /// `status.replicas`
+    StatusReplicas,
+}
+
+impl ::field_selector::Field for ReplicaSetField {
+    fn path(&self) -> &'static str {
+        match self {
+            ReplicaSetField::MetadataName => "metadata.name",
+            ReplicaSetField::MetadataNamespace => "metadata.namespace",
+            ReplicaSetField::StatusReplicas => "status.replicas",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ReplicaSetField::MetadataName),
+            "metadata.namespace" => Some(ReplicaSetField::MetadataNamespace),
+            "status.replicas" => Some(ReplicaSetField::StatusReplicas),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1::ReplicaSet> for ReplicaSet {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1::ReplicaSet) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl ::std::convert::TryFrom<::v1_10::api::apps::v1beta2::ReplicaSet> for ReplicaSet {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::apps::v1beta2::ReplicaSet) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
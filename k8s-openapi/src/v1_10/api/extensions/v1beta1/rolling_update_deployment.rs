@@ -99,3 +99,13 @@ impl ::serde::Serialize for RollingUpdateDeployment {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl From<::v1_10::api::apps::v1beta1::RollingUpdateDeployment> for RollingUpdateDeployment {
+    fn from(value: ::v1_10::api::apps::v1beta1::RollingUpdateDeployment) -> Self {
+        RollingUpdateDeployment {
+            max_surge: value.max_surge,
+            max_unavailable: value.max_unavailable,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct Scale {
 
 // Generated from operation patchExtensionsV1beta1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified Deployment
     ///
@@ -63,6 +64,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_extensions_v1beta1_namespaced_deployment_scale`](./struct.Scale.html#method.patch_extensions_v1beta1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedDeploymentScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -70,6 +72,7 @@ pub enum PatchExtensionsV1beta1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -85,10 +88,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedDeploymentScaleResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified ReplicaSet
     ///
@@ -129,6 +137,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_extensions_v1beta1_namespaced_replica_set_scale`](./struct.Scale.html#method.patch_extensions_v1beta1_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -136,6 +145,7 @@ pub enum PatchExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -151,10 +161,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedReplicaSetScaleResponse {
             _ => Ok((PatchExtensionsV1beta1NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1NamespacedReplicationControllerDummyScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified ReplicationControllerDummy
     ///
@@ -195,6 +210,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_extensions_v1beta1_namespaced_replication_controller_dummy_scale`](./struct.Scale.html#method.patch_extensions_v1beta1_namespaced_replication_controller_dummy_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -202,6 +218,7 @@ pub enum PatchExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -217,10 +234,15 @@ impl ::Response for PatchExtensionsV1beta1NamespacedReplicationControllerDummySc
             _ => Ok((PatchExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified Deployment
     ///
@@ -258,6 +280,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_extensions_v1beta1_namespaced_deployment_scale`](./struct.Scale.html#method.read_extensions_v1beta1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedDeploymentScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -265,6 +288,7 @@ pub enum ReadExtensionsV1beta1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -280,10 +304,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedDeploymentScaleResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified ReplicaSet
     ///
@@ -321,6 +350,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_extensions_v1beta1_namespaced_replica_set_scale`](./struct.Scale.html#method.read_extensions_v1beta1_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -328,6 +358,7 @@ pub enum ReadExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -343,10 +374,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedReplicaSetScaleResponse {
             _ => Ok((ReadExtensionsV1beta1NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1NamespacedReplicationControllerDummyScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified ReplicationControllerDummy
     ///
@@ -384,6 +420,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_extensions_v1beta1_namespaced_replication_controller_dummy_scale`](./struct.Scale.html#method.read_extensions_v1beta1_namespaced_replication_controller_dummy_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -391,6 +428,7 @@ pub enum ReadExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -406,10 +444,15 @@ impl ::Response for ReadExtensionsV1beta1NamespacedReplicationControllerDummySca
             _ => Ok((ReadExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified Deployment
     ///
@@ -450,6 +493,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_extensions_v1beta1_namespaced_deployment_scale`](./struct.Scale.html#method.replace_extensions_v1beta1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedDeploymentScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -458,6 +502,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -481,10 +526,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedDeploymentScaleResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified ReplicaSet
     ///
@@ -525,6 +575,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_extensions_v1beta1_namespaced_replica_set_scale`](./struct.Scale.html#method.replace_extensions_v1beta1_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -533,6 +584,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -556,10 +608,15 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicaSetScaleResponse {
             _ => Ok((ReplaceExtensionsV1beta1NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1NamespacedReplicationControllerDummyScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified ReplicationControllerDummy
     ///
@@ -600,6 +657,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_extensions_v1beta1_namespaced_replication_controller_dummy_scale`](./struct.Scale.html#method.replace_extensions_v1beta1_namespaced_replication_controller_dummy_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse {
     Ok(::v1_10::api::extensions::v1beta1::Scale),
@@ -608,6 +666,7 @@ pub enum ReplaceExtensionsV1beta1NamespacedReplicationControllerDummyScaleRespon
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -631,6 +690,10 @@ impl ::Response for ReplaceExtensionsV1beta1NamespacedReplicationControllerDummy
             _ => Ok((ReplaceExtensionsV1beta1NamespacedReplicationControllerDummyScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End extensions/v1beta1/Scale
@@ -754,3 +817,40 @@ impl ::serde::Serialize for Scale {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl From<::v1_10::api::apps::v1beta1::Scale> for Scale {
+    fn from(value: ::v1_10::api::apps::v1beta1::Scale) -> Self {
+        Scale {
+            api_version: value.api_version.map(|_| "extensions/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::Scale> for Scale {
+    fn from(value: ::v1_10::api::apps::v1beta2::Scale) -> Self {
+        Scale {
+            api_version: value.api_version.map(|_| "extensions/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-autoscaling-v1")]
+impl ::std::convert::TryFrom<::v1_10::api::autoscaling::v1::Scale> for Scale {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::autoscaling::v1::Scale) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "extensions/v1beta1".to_string());
+        Ok(result)
+    }
+}
@@ -86,3 +86,21 @@ impl ::serde::Serialize for ScaleSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apps-v1beta1")]
+impl From<::v1_10::api::apps::v1beta1::ScaleSpec> for ScaleSpec {
+    fn from(value: ::v1_10::api::apps::v1beta1::ScaleSpec) -> Self {
+        ScaleSpec {
+            replicas: value.replicas,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apps-v1beta2")]
+impl From<::v1_10::api::apps::v1beta2::ScaleSpec> for ScaleSpec {
+    fn from(value: ::v1_10::api::apps::v1beta2::ScaleSpec) -> Self {
+        ScaleSpec {
+            replicas: value.replicas,
+        }
+    }
+}
@@ -97,3 +97,13 @@ impl ::serde::Serialize for SELinuxStrategyOptions {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-policy-v1beta1")]
+impl From<::v1_10::api::policy::v1beta1::SELinuxStrategyOptions> for SELinuxStrategyOptions {
+    fn from(value: ::v1_10::api::policy::v1beta1::SELinuxStrategyOptions) -> Self {
+        SELinuxStrategyOptions {
+            rule: value.rule,
+            se_linux_options: value.se_linux_options,
+        }
+    }
+}
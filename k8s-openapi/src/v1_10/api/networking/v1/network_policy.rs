@@ -20,6 +20,7 @@ pub struct NetworkPolicy {
 
 // Generated from operation createNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// create a NetworkPolicy
     ///
@@ -55,6 +56,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::create_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.create_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateNetworkingV1NamespacedNetworkPolicyResponse {
     Ok(::v1_10::api::networking::v1::NetworkPolicy),
@@ -64,6 +66,7 @@ pub enum CreateNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateNetworkingV1NamespacedNetworkPolicyResponse {
             _ => Ok((CreateNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteNetworkingV1CollectionNamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// delete collection of NetworkPolicy
     ///
@@ -197,6 +205,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::delete_networking_v1_collection_namespaced_network_policy`](./struct.NetworkPolicy.html#method.delete_networking_v1_collection_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -205,38 +214,34 @@ pub enum DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse::Unauthorized, 0)),
             _ => Ok((DeleteNetworkingV1CollectionNamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// delete a NetworkPolicy
     ///
@@ -300,6 +305,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::delete_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.delete_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteNetworkingV1NamespacedNetworkPolicyResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -308,38 +314,34 @@ pub enum DeleteNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteNetworkingV1NamespacedNetworkPolicyResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteNetworkingV1NamespacedNetworkPolicyResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteNetworkingV1NamespacedNetworkPolicyResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteNetworkingV1NamespacedNetworkPolicyResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteNetworkingV1NamespacedNetworkPolicyResponse::Unauthorized, 0)),
             _ => Ok((DeleteNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// list or watch objects of kind NetworkPolicy
     ///
@@ -438,6 +440,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::list_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.list_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListNetworkingV1NamespacedNetworkPolicyResponse {
     Ok(::v1_10::api::networking::v1::NetworkPolicyList),
@@ -445,6 +448,7 @@ pub enum ListNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -460,10 +464,27 @@ impl ::Response for ListNetworkingV1NamespacedNetworkPolicyResponse {
             _ => Ok((ListNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListNetworkingV1NamespacedNetworkPolicyResponse {
+    type List = ::v1_10::api::networking::v1::NetworkPolicyList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListNetworkingV1NamespacedNetworkPolicyResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listNetworkingV1NetworkPolicyForAllNamespaces
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// list or watch objects of kind NetworkPolicy
     ///
@@ -557,6 +578,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::list_networking_v1_network_policy_for_all_namespaces`](./struct.NetworkPolicy.html#method.list_networking_v1_network_policy_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListNetworkingV1NetworkPolicyForAllNamespacesResponse {
     Ok(::v1_10::api::networking::v1::NetworkPolicyList),
@@ -564,6 +586,7 @@ pub enum ListNetworkingV1NetworkPolicyForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListNetworkingV1NetworkPolicyForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -579,10 +602,27 @@ impl ::Response for ListNetworkingV1NetworkPolicyForAllNamespacesResponse {
             _ => Ok((ListNetworkingV1NetworkPolicyForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListNetworkingV1NetworkPolicyForAllNamespacesResponse {
+    type List = ::v1_10::api::networking::v1::NetworkPolicyList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListNetworkingV1NetworkPolicyForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// partially update the specified NetworkPolicy
     ///
@@ -623,6 +663,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::patch_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.patch_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchNetworkingV1NamespacedNetworkPolicyResponse {
     Ok(::v1_10::api::networking::v1::NetworkPolicy),
@@ -630,6 +671,7 @@ pub enum PatchNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +687,15 @@ impl ::Response for PatchNetworkingV1NamespacedNetworkPolicyResponse {
             _ => Ok((PatchNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// read the specified NetworkPolicy
     ///
@@ -702,6 +749,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::read_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.read_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadNetworkingV1NamespacedNetworkPolicyResponse {
     Ok(::v1_10::api::networking::v1::NetworkPolicy),
@@ -709,6 +757,7 @@ pub enum ReadNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -724,10 +773,15 @@ impl ::Response for ReadNetworkingV1NamespacedNetworkPolicyResponse {
             _ => Ok((ReadNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// replace the specified NetworkPolicy
     ///
@@ -768,6 +822,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::replace_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.replace_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceNetworkingV1NamespacedNetworkPolicyResponse {
     Ok(::v1_10::api::networking::v1::NetworkPolicy),
@@ -776,6 +831,7 @@ pub enum ReplaceNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -799,10 +855,15 @@ impl ::Response for ReplaceNetworkingV1NamespacedNetworkPolicyResponse {
             _ => Ok((ReplaceNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchNetworkingV1NamespacedNetworkPolicy
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// watch changes to an object of kind NetworkPolicy
     ///
@@ -906,6 +967,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::watch_networking_v1_namespaced_network_policy`](./struct.NetworkPolicy.html#method.watch_networking_v1_namespaced_network_policy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchNetworkingV1NamespacedNetworkPolicyResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -913,27 +975,31 @@ pub enum WatchNetworkingV1NamespacedNetworkPolicyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchNetworkingV1NamespacedNetworkPolicyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchNetworkingV1NamespacedNetworkPolicyResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchNetworkingV1NamespacedNetworkPolicyResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchNetworkingV1NamespacedNetworkPolicyResponse::Unauthorized, 0)),
             _ => Ok((WatchNetworkingV1NamespacedNetworkPolicyResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchNetworkingV1NamespacedNetworkPolicyList
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// watch individual changes to a list of NetworkPolicy
     ///
@@ -1032,6 +1098,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::watch_networking_v1_namespaced_network_policy_list`](./struct.NetworkPolicy.html#method.watch_networking_v1_namespaced_network_policy_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchNetworkingV1NamespacedNetworkPolicyListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1039,27 +1106,31 @@ pub enum WatchNetworkingV1NamespacedNetworkPolicyListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchNetworkingV1NamespacedNetworkPolicyListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchNetworkingV1NamespacedNetworkPolicyListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchNetworkingV1NamespacedNetworkPolicyListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchNetworkingV1NamespacedNetworkPolicyListResponse::Unauthorized, 0)),
             _ => Ok((WatchNetworkingV1NamespacedNetworkPolicyListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchNetworkingV1NetworkPolicyListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl NetworkPolicy {
     /// watch individual changes to a list of NetworkPolicy
     ///
@@ -1153,6 +1224,7 @@ impl NetworkPolicy {
 }
 
 /// Parses the HTTP response of [`NetworkPolicy::watch_networking_v1_network_policy_list_for_all_namespaces`](./struct.NetworkPolicy.html#method.watch_networking_v1_network_policy_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1160,23 +1232,26 @@ pub enum WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchNetworkingV1NetworkPolicyListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End networking.k8s.io/v1/NetworkPolicy
@@ -1290,3 +1365,42 @@ impl ::serde::Serialize for NetworkPolicy {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`NetworkPolicy`](./struct.NetworkPolicy.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NetworkPolicyField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for NetworkPolicyField {
+    fn path(&self) -> &'static str {
+        match self {
+            NetworkPolicyField::MetadataName => "metadata.name",
+            NetworkPolicyField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(NetworkPolicyField::MetadataName),
+            "metadata.namespace" => Some(NetworkPolicyField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::NetworkPolicy> for NetworkPolicy {
+    fn from(value: ::v1_10::api::extensions::v1beta1::NetworkPolicy) -> Self {
+        NetworkPolicy {
+            api_version: value.api_version.map(|_| "networking.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+        }
+    }
+}
@@ -99,3 +99,13 @@ impl ::serde::Serialize for NetworkPolicyEgressRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::NetworkPolicyEgressRule> for NetworkPolicyEgressRule {
+    fn from(value: ::v1_10::api::extensions::v1beta1::NetworkPolicyEgressRule) -> Self {
+        NetworkPolicyEgressRule {
+            ports: value.ports.map(|value| value.into_iter().map(Into::into).collect()),
+            to: value.to.map(|value| value.into_iter().map(Into::into).collect()),
+        }
+    }
+}
@@ -88,3 +88,12 @@ impl ::serde::Serialize for AllowedHostPath {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::AllowedHostPath> for AllowedHostPath {
+    fn from(value: ::v1_10::api::extensions::v1beta1::AllowedHostPath) -> Self {
+        AllowedHostPath {
+            path_prefix: value.path_prefix,
+        }
+    }
+}
@@ -95,3 +95,13 @@ impl ::serde::Serialize for IDRange {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::IDRange> for IDRange {
+    fn from(value: ::v1_10::api::extensions::v1beta1::IDRange) -> Self {
+        IDRange {
+            max: value.max,
+            min: value.min,
+        }
+    }
+}
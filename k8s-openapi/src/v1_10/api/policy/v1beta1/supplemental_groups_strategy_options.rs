@@ -99,3 +99,13 @@ impl ::serde::Serialize for SupplementalGroupsStrategyOptions {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-extensions-v1beta1")]
+impl From<::v1_10::api::extensions::v1beta1::SupplementalGroupsStrategyOptions> for SupplementalGroupsStrategyOptions {
+    fn from(value: ::v1_10::api::extensions::v1beta1::SupplementalGroupsStrategyOptions) -> Self {
+        SupplementalGroupsStrategyOptions {
+            ranges: value.ranges.map(|value| value.into_iter().map(Into::into).collect()),
+            rule: value.rule,
+        }
+    }
+}
@@ -136,3 +136,29 @@ impl ::serde::Serialize for PolicyRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-rbac-v1alpha1")]
+impl From<::v1_10::api::rbac::v1alpha1::PolicyRule> for PolicyRule {
+    fn from(value: ::v1_10::api::rbac::v1alpha1::PolicyRule) -> Self {
+        PolicyRule {
+            api_groups: value.api_groups,
+            non_resource_urls: value.non_resource_urls,
+            resource_names: value.resource_names,
+            resources: value.resources,
+            verbs: value.verbs,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1beta1")]
+impl From<::v1_10::api::rbac::v1beta1::PolicyRule> for PolicyRule {
+    fn from(value: ::v1_10::api::rbac::v1beta1::PolicyRule) -> Self {
+        PolicyRule {
+            api_groups: value.api_groups,
+            non_resource_urls: value.non_resource_urls,
+            resource_names: value.resource_names,
+            resources: value.resources,
+            verbs: value.verbs,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct ClusterRoleBinding {
 
 // Generated from operation createRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// create a ClusterRoleBinding
     ///
@@ -53,6 +54,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::create_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.create_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1alpha1::ClusterRoleBinding),
@@ -62,6 +64,7 @@ pub enum CreateRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -93,10 +96,15 @@ impl ::Response for CreateRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
             _ => Ok((CreateRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// delete a ClusterRoleBinding
     ///
@@ -155,6 +163,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::delete_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.delete_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -163,38 +172,34 @@ pub enum DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1alpha1CollectionClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// delete collection of ClusterRoleBinding
     ///
@@ -288,6 +293,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::delete_rbac_authorization_v1alpha1_collection_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.delete_rbac_authorization_v1alpha1_collection_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -296,38 +302,34 @@ pub enum DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1alpha1CollectionClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// list or watch objects of kind ClusterRoleBinding
     ///
@@ -421,6 +423,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::list_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.list_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1alpha1::ClusterRoleBindingList),
@@ -428,6 +431,7 @@ pub enum ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -443,10 +447,27 @@ impl ::Response for ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
             _ => Ok((ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
+    type List = ::v1_10::api::rbac::v1alpha1::ClusterRoleBindingList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// partially update the specified ClusterRoleBinding
     ///
@@ -482,6 +503,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::patch_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.patch_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1alpha1::ClusterRoleBinding),
@@ -489,6 +511,7 @@ pub enum PatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -504,10 +527,15 @@ impl ::Response for PatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
             _ => Ok((PatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// read the specified ClusterRoleBinding
     ///
@@ -540,6 +568,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::read_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.read_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1alpha1::ClusterRoleBinding),
@@ -547,6 +576,7 @@ pub enum ReadRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -562,10 +592,15 @@ impl ::Response for ReadRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
             _ => Ok((ReadRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// replace the specified ClusterRoleBinding
     ///
@@ -601,6 +636,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::replace_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.replace_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1alpha1::ClusterRoleBinding),
@@ -609,6 +645,7 @@ pub enum ReplaceRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -632,10 +669,15 @@ impl ::Response for ReplaceRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
             _ => Ok((ReplaceRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1alpha1ClusterRoleBinding
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// watch changes to an object of kind ClusterRoleBinding
     ///
@@ -734,6 +776,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::watch_rbac_authorization_v1alpha1_cluster_role_binding`](./struct.ClusterRoleBinding.html#method.watch_rbac_authorization_v1alpha1_cluster_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -741,27 +784,31 @@ pub enum WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1alpha1ClusterRoleBindingList
 
+#[cfg(feature = "api")]
 impl ClusterRoleBinding {
     /// watch individual changes to a list of ClusterRoleBinding
     ///
@@ -855,6 +902,7 @@ impl ClusterRoleBinding {
 }
 
 /// Parses the HTTP response of [`ClusterRoleBinding::watch_rbac_authorization_v1alpha1_cluster_role_binding_list`](./struct.ClusterRoleBinding.html#method.watch_rbac_authorization_v1alpha1_cluster_role_binding_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -862,23 +910,26 @@ pub enum WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1alpha1ClusterRoleBindingListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End rbac.authorization.k8s.io/v1alpha1/ClusterRoleBinding
@@ -998,3 +1049,47 @@ impl ::serde::Serialize for ClusterRoleBinding {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ClusterRoleBinding`](./struct.ClusterRoleBinding.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClusterRoleBindingField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for ClusterRoleBindingField {
+    fn path(&self) -> &'static str {
+        match self {
+            ClusterRoleBindingField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ClusterRoleBindingField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1")]
+impl ::std::convert::TryFrom<::v1_10::api::rbac::v1::ClusterRoleBinding> for ClusterRoleBinding {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::rbac::v1::ClusterRoleBinding) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "rbac.authorization.k8s.io/v1alpha1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1beta1")]
+impl ::std::convert::TryFrom<::v1_10::api::rbac::v1beta1::ClusterRoleBinding> for ClusterRoleBinding {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::rbac::v1beta1::ClusterRoleBinding) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "rbac.authorization.k8s.io/v1alpha1".to_string());
+        Ok(result)
+    }
+}
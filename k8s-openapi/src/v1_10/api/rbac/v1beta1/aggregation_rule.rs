@@ -86,3 +86,21 @@ impl ::serde::Serialize for AggregationRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-rbac-v1")]
+impl From<::v1_10::api::rbac::v1::AggregationRule> for AggregationRule {
+    fn from(value: ::v1_10::api::rbac::v1::AggregationRule) -> Self {
+        AggregationRule {
+            cluster_role_selectors: value.cluster_role_selectors,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1alpha1")]
+impl From<::v1_10::api::rbac::v1alpha1::AggregationRule> for AggregationRule {
+    fn from(value: ::v1_10::api::rbac::v1alpha1::AggregationRule) -> Self {
+        AggregationRule {
+            cluster_role_selectors: value.cluster_role_selectors,
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub struct Role {
 
 // Generated from operation createRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// create a Role
     ///
@@ -55,6 +56,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::create_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.create_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateRbacAuthorizationV1beta1NamespacedRoleResponse {
     Ok(::v1_10::api::rbac::v1beta1::Role),
@@ -64,6 +66,7 @@ pub enum CreateRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateRbacAuthorizationV1beta1NamespacedRoleResponse {
             _ => Ok((CreateRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1beta1CollectionNamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// delete collection of Role
     ///
@@ -197,6 +205,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::delete_rbac_authorization_v1beta1_collection_namespaced_role`](./struct.Role.html#method.delete_rbac_authorization_v1beta1_collection_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -205,38 +214,34 @@ pub enum DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// delete a Role
     ///
@@ -300,6 +305,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::delete_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.delete_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1beta1NamespacedRoleResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -308,38 +314,34 @@ pub enum DeleteRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// list or watch objects of kind Role
     ///
@@ -438,6 +440,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::list_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.list_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListRbacAuthorizationV1beta1NamespacedRoleResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleList),
@@ -445,6 +448,7 @@ pub enum ListRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -460,10 +464,27 @@ impl ::Response for ListRbacAuthorizationV1beta1NamespacedRoleResponse {
             _ => Ok((ListRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListRbacAuthorizationV1beta1NamespacedRoleResponse {
+    type List = ::v1_10::api::rbac::v1beta1::RoleList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListRbacAuthorizationV1beta1NamespacedRoleResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listRbacAuthorizationV1beta1RoleForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Role {
     /// list or watch objects of kind Role
     ///
@@ -557,6 +578,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::list_rbac_authorization_v1beta1_role_for_all_namespaces`](./struct.Role.html#method.list_rbac_authorization_v1beta1_role_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleList),
@@ -564,6 +586,7 @@ pub enum ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -579,10 +602,27 @@ impl ::Response for ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse {
             _ => Ok((ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse {
+    type List = ::v1_10::api::rbac::v1beta1::RoleList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListRbacAuthorizationV1beta1RoleForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// partially update the specified Role
     ///
@@ -623,6 +663,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::patch_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.patch_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchRbacAuthorizationV1beta1NamespacedRoleResponse {
     Ok(::v1_10::api::rbac::v1beta1::Role),
@@ -630,6 +671,7 @@ pub enum PatchRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +687,15 @@ impl ::Response for PatchRbacAuthorizationV1beta1NamespacedRoleResponse {
             _ => Ok((PatchRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// read the specified Role
     ///
@@ -686,6 +733,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::read_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.read_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadRbacAuthorizationV1beta1NamespacedRoleResponse {
     Ok(::v1_10::api::rbac::v1beta1::Role),
@@ -693,6 +741,7 @@ pub enum ReadRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -708,10 +757,15 @@ impl ::Response for ReadRbacAuthorizationV1beta1NamespacedRoleResponse {
             _ => Ok((ReadRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// replace the specified Role
     ///
@@ -752,6 +806,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::replace_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.replace_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceRbacAuthorizationV1beta1NamespacedRoleResponse {
     Ok(::v1_10::api::rbac::v1beta1::Role),
@@ -760,6 +815,7 @@ pub enum ReplaceRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -783,10 +839,15 @@ impl ::Response for ReplaceRbacAuthorizationV1beta1NamespacedRoleResponse {
             _ => Ok((ReplaceRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1beta1NamespacedRole
 
+#[cfg(feature = "api")]
 impl Role {
     /// watch changes to an object of kind Role
     ///
@@ -890,6 +951,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::watch_rbac_authorization_v1beta1_namespaced_role`](./struct.Role.html#method.watch_rbac_authorization_v1beta1_namespaced_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1beta1NamespacedRoleResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -897,27 +959,31 @@ pub enum WatchRbacAuthorizationV1beta1NamespacedRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1beta1NamespacedRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1beta1NamespacedRoleList
 
+#[cfg(feature = "api")]
 impl Role {
     /// watch individual changes to a list of Role
     ///
@@ -1016,6 +1082,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::watch_rbac_authorization_v1beta1_namespaced_role_list`](./struct.Role.html#method.watch_rbac_authorization_v1beta1_namespaced_role_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1beta1NamespacedRoleListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1023,27 +1090,31 @@ pub enum WatchRbacAuthorizationV1beta1NamespacedRoleListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1beta1NamespacedRoleListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleListResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1beta1RoleListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Role {
     /// watch individual changes to a list of Role
     ///
@@ -1137,6 +1208,7 @@ impl Role {
 }
 
 /// Parses the HTTP response of [`Role::watch_rbac_authorization_v1beta1_role_list_for_all_namespaces`](./struct.Role.html#method.watch_rbac_authorization_v1beta1_role_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1144,23 +1216,26 @@ pub enum WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1beta1RoleListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End rbac.authorization.k8s.io/v1beta1/Role
@@ -1272,3 +1347,54 @@ impl ::serde::Serialize for Role {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Role`](./struct.Role.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoleField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for RoleField {
+    fn path(&self) -> &'static str {
+        match self {
+            RoleField::MetadataName => "metadata.name",
+            RoleField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(RoleField::MetadataName),
+            "metadata.namespace" => Some(RoleField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1")]
+impl From<::v1_10::api::rbac::v1::Role> for Role {
+    fn from(value: ::v1_10::api::rbac::v1::Role) -> Self {
+        Role {
+            api_version: value.api_version.map(|_| "rbac.authorization.k8s.io/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            rules: value.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1alpha1")]
+impl From<::v1_10::api::rbac::v1alpha1::Role> for Role {
+    fn from(value: ::v1_10::api::rbac::v1alpha1::Role) -> Self {
+        Role {
+            api_version: value.api_version.map(|_| "rbac.authorization.k8s.io/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            rules: value.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct RoleBinding {
 
 // Generated from operation createRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// create a RoleBinding
     ///
@@ -58,6 +59,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::create_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.create_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleBinding),
@@ -67,6 +69,7 @@ pub enum CreateRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateRbacAuthorizationV1beta1NamespacedRoleBindingResponse
             _ => Ok((CreateRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1beta1CollectionNamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// delete collection of RoleBinding
     ///
@@ -200,6 +208,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::delete_rbac_authorization_v1beta1_collection_namespaced_role_binding`](./struct.RoleBinding.html#method.delete_rbac_authorization_v1beta1_collection_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1beta1CollectionNamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// delete a RoleBinding
     ///
@@ -303,6 +308,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::delete_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.delete_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// list or watch objects of kind RoleBinding
     ///
@@ -441,6 +443,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::list_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.list_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleBindingList),
@@ -448,6 +451,7 @@ pub enum ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -463,10 +467,27 @@ impl ::Response for ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
             _ => Ok((ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
+    type List = ::v1_10::api::rbac::v1beta1::RoleBindingList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listRbacAuthorizationV1beta1RoleBindingForAllNamespaces
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// list or watch objects of kind RoleBinding
     ///
@@ -560,6 +581,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::list_rbac_authorization_v1beta1_role_binding_for_all_namespaces`](./struct.RoleBinding.html#method.list_rbac_authorization_v1beta1_role_binding_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleBindingList),
@@ -567,6 +589,7 @@ pub enum ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesRespo
             _ => Ok((ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesResponse {
+    type List = ::v1_10::api::rbac::v1beta1::RoleBindingList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListRbacAuthorizationV1beta1RoleBindingForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// partially update the specified RoleBinding
     ///
@@ -626,6 +666,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::patch_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.patch_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleBinding),
@@ -633,6 +674,7 @@ pub enum PatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
             _ => Ok((PatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// read the specified RoleBinding
     ///
@@ -689,6 +736,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::read_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.read_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleBinding),
@@ -696,6 +744,7 @@ pub enum ReadRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -711,10 +760,15 @@ impl ::Response for ReadRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
             _ => Ok((ReadRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// replace the specified RoleBinding
     ///
@@ -755,6 +809,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::replace_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.replace_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Ok(::v1_10::api::rbac::v1beta1::RoleBinding),
@@ -763,6 +818,7 @@ pub enum ReplaceRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -786,10 +842,15 @@ impl ::Response for ReplaceRbacAuthorizationV1beta1NamespacedRoleBindingResponse
             _ => Ok((ReplaceRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1beta1NamespacedRoleBinding
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// watch changes to an object of kind RoleBinding
     ///
@@ -893,6 +954,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::watch_rbac_authorization_v1beta1_namespaced_role_binding`](./struct.RoleBinding.html#method.watch_rbac_authorization_v1beta1_namespaced_role_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -900,27 +962,31 @@ pub enum WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1beta1NamespacedRoleBindingList
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// watch individual changes to a list of RoleBinding
     ///
@@ -1019,6 +1085,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::watch_rbac_authorization_v1beta1_namespaced_role_binding_list`](./struct.RoleBinding.html#method.watch_rbac_authorization_v1beta1_namespaced_role_binding_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1026,27 +1093,31 @@ pub enum WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1beta1NamespacedRoleBindingListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1beta1RoleBindingListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl RoleBinding {
     /// watch individual changes to a list of RoleBinding
     ///
@@ -1140,6 +1211,7 @@ impl RoleBinding {
 }
 
 /// Parses the HTTP response of [`RoleBinding::watch_rbac_authorization_v1beta1_role_binding_list_for_all_namespaces`](./struct.RoleBinding.html#method.watch_rbac_authorization_v1beta1_role_binding_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1147,23 +1219,26 @@ pub enum WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1beta1RoleBindingListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End rbac.authorization.k8s.io/v1beta1/RoleBinding
@@ -1283,3 +1358,54 @@ impl ::serde::Serialize for RoleBinding {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`RoleBinding`](./struct.RoleBinding.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoleBindingField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for RoleBindingField {
+    fn path(&self) -> &'static str {
+        match self {
+            RoleBindingField::MetadataName => "metadata.name",
+            RoleBindingField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(RoleBindingField::MetadataName),
+            "metadata.namespace" => Some(RoleBindingField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1")]
+impl From<::v1_10::api::rbac::v1::RoleBinding> for RoleBinding {
+    fn from(value: ::v1_10::api::rbac::v1::RoleBinding) -> Self {
+        RoleBinding {
+            api_version: value.api_version.map(|_| "rbac.authorization.k8s.io/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            role_ref: value.role_ref.into(),
+            subjects: value.subjects.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-rbac-v1alpha1")]
+impl ::std::convert::TryFrom<::v1_10::api::rbac::v1alpha1::RoleBinding> for RoleBinding {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_10::api::rbac::v1alpha1::RoleBinding) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "rbac.authorization.k8s.io/v1beta1".to_string());
+        Ok(result)
+    }
+}
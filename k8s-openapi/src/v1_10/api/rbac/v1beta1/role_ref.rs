@@ -106,3 +106,14 @@ impl ::serde::Serialize for RoleRef {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-rbac-v1")]
+impl From<::v1_10::api::rbac::v1::RoleRef> for RoleRef {
+    fn from(value: ::v1_10::api::rbac::v1::RoleRef) -> Self {
+        RoleRef {
+            api_group: value.api_group,
+            kind: value.kind,
+            name: value.name,
+        }
+    }
+}
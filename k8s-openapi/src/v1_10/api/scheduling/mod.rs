@@ -1 +1,2 @@
+#[cfg(feature = "v1_10-scheduling-v1alpha1")]
 pub mod v1alpha1;
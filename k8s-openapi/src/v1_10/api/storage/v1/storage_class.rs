@@ -37,6 +37,7 @@ pub struct StorageClass {
 
 // Generated from operation createStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// create a StorageClass
     ///
@@ -67,6 +68,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::create_storage_v1_storage_class`](./struct.StorageClass.html#method.create_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateStorageV1StorageClassResponse {
     Ok(::v1_10::api::storage::v1::StorageClass),
@@ -76,6 +78,7 @@ pub enum CreateStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -107,10 +110,15 @@ impl ::Response for CreateStorageV1StorageClassResponse {
             _ => Ok((CreateStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteStorageV1CollectionStorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// delete collection of StorageClass
     ///
@@ -204,6 +212,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::delete_storage_v1_collection_storage_class`](./struct.StorageClass.html#method.delete_storage_v1_collection_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteStorageV1CollectionStorageClassResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -212,38 +221,34 @@ pub enum DeleteStorageV1CollectionStorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteStorageV1CollectionStorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1CollectionStorageClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1CollectionStorageClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteStorageV1CollectionStorageClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteStorageV1CollectionStorageClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteStorageV1CollectionStorageClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteStorageV1CollectionStorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// delete a StorageClass
     ///
@@ -302,6 +307,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::delete_storage_v1_storage_class`](./struct.StorageClass.html#method.delete_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteStorageV1StorageClassResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -310,38 +316,34 @@ pub enum DeleteStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1StorageClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1StorageClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteStorageV1StorageClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteStorageV1StorageClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteStorageV1StorageClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// list or watch objects of kind StorageClass
     ///
@@ -435,6 +437,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::list_storage_v1_storage_class`](./struct.StorageClass.html#method.list_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListStorageV1StorageClassResponse {
     Ok(::v1_10::api::storage::v1::StorageClassList),
@@ -442,6 +445,7 @@ pub enum ListStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -457,10 +461,27 @@ impl ::Response for ListStorageV1StorageClassResponse {
             _ => Ok((ListStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListStorageV1StorageClassResponse {
+    type List = ::v1_10::api::storage::v1::StorageClassList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListStorageV1StorageClassResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// partially update the specified StorageClass
     ///
@@ -496,6 +517,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::patch_storage_v1_storage_class`](./struct.StorageClass.html#method.patch_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchStorageV1StorageClassResponse {
     Ok(::v1_10::api::storage::v1::StorageClass),
@@ -503,6 +525,7 @@ pub enum PatchStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -518,10 +541,15 @@ impl ::Response for PatchStorageV1StorageClassResponse {
             _ => Ok((PatchStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// read the specified StorageClass
     ///
@@ -570,6 +598,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::read_storage_v1_storage_class`](./struct.StorageClass.html#method.read_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadStorageV1StorageClassResponse {
     Ok(::v1_10::api::storage::v1::StorageClass),
@@ -577,6 +606,7 @@ pub enum ReadStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -592,10 +622,15 @@ impl ::Response for ReadStorageV1StorageClassResponse {
             _ => Ok((ReadStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// replace the specified StorageClass
     ///
@@ -631,6 +666,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::replace_storage_v1_storage_class`](./struct.StorageClass.html#method.replace_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceStorageV1StorageClassResponse {
     Ok(::v1_10::api::storage::v1::StorageClass),
@@ -639,6 +675,7 @@ pub enum ReplaceStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -662,10 +699,15 @@ impl ::Response for ReplaceStorageV1StorageClassResponse {
             _ => Ok((ReplaceStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchStorageV1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// watch changes to an object of kind StorageClass
     ///
@@ -764,6 +806,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::watch_storage_v1_storage_class`](./struct.StorageClass.html#method.watch_storage_v1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchStorageV1StorageClassResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -771,27 +814,31 @@ pub enum WatchStorageV1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchStorageV1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchStorageV1StorageClassResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchStorageV1StorageClassResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchStorageV1StorageClassResponse::Unauthorized, 0)),
             _ => Ok((WatchStorageV1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchStorageV1StorageClassList
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// watch individual changes to a list of StorageClass
     ///
@@ -885,6 +932,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::watch_storage_v1_storage_class_list`](./struct.StorageClass.html#method.watch_storage_v1_storage_class_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchStorageV1StorageClassListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -892,23 +940,26 @@ pub enum WatchStorageV1StorageClassListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchStorageV1StorageClassListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchStorageV1StorageClassListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchStorageV1StorageClassListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchStorageV1StorageClassListResponse::Unauthorized, 0)),
             _ => Ok((WatchStorageV1StorageClassListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End storage.k8s.io/v1/StorageClass
@@ -1070,3 +1121,42 @@ impl ::serde::Serialize for StorageClass {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`StorageClass`](./struct.StorageClass.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageClassField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for StorageClassField {
+    fn path(&self) -> &'static str {
+        match self {
+            StorageClassField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(StorageClassField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-storage-v1beta1")]
+impl From<::v1_10::api::storage::v1beta1::StorageClass> for StorageClass {
+    fn from(value: ::v1_10::api::storage::v1beta1::StorageClass) -> Self {
+        StorageClass {
+            allow_volume_expansion: value.allow_volume_expansion,
+            api_version: value.api_version.map(|_| "storage.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            mount_options: value.mount_options,
+            parameters: value.parameters,
+            provisioner: value.provisioner,
+            reclaim_policy: value.reclaim_policy,
+            volume_binding_mode: value.volume_binding_mode,
+        }
+    }
+}
@@ -99,3 +99,13 @@ impl ::serde::Serialize for VolumeError {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-storage-v1beta1")]
+impl From<::v1_10::api::storage::v1beta1::VolumeError> for VolumeError {
+    fn from(value: ::v1_10::api::storage::v1beta1::VolumeError) -> Self {
+        VolumeError {
+            message: value.message,
+            time: value.time,
+        }
+    }
+}
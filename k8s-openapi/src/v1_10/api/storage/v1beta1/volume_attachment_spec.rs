@@ -106,3 +106,14 @@ impl ::serde::Serialize for VolumeAttachmentSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-storage-v1alpha1")]
+impl From<::v1_10::api::storage::v1alpha1::VolumeAttachmentSpec> for VolumeAttachmentSpec {
+    fn from(value: ::v1_10::api::storage::v1alpha1::VolumeAttachmentSpec) -> Self {
+        VolumeAttachmentSpec {
+            attacher: value.attacher,
+            node_name: value.node_name,
+            source: value.source.into(),
+        }
+    }
+}
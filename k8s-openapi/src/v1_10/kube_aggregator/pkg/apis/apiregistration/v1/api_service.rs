@@ -22,6 +22,7 @@ pub struct APIService {
 
 // Generated from operation createApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// create an APIService
     ///
@@ -52,6 +53,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::create_apiregistration_v1_api_service`](./struct.APIService.html#method.create_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateApiregistrationV1APIServiceResponse {
     Ok(::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIService),
@@ -61,6 +63,7 @@ pub enum CreateApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,10 +95,15 @@ impl ::Response for CreateApiregistrationV1APIServiceResponse {
             _ => Ok((CreateApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// delete an APIService
     ///
@@ -154,6 +162,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::delete_apiregistration_v1_api_service`](./struct.APIService.html#method.delete_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteApiregistrationV1APIServiceResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -162,38 +171,34 @@ pub enum DeleteApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiregistrationV1APIServiceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiregistrationV1APIServiceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteApiregistrationV1APIServiceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteApiregistrationV1APIServiceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteApiregistrationV1APIServiceResponse::Unauthorized, 0)),
             _ => Ok((DeleteApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteApiregistrationV1CollectionAPIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// delete collection of APIService
     ///
@@ -287,6 +292,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::delete_apiregistration_v1_collection_api_service`](./struct.APIService.html#method.delete_apiregistration_v1_collection_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteApiregistrationV1CollectionAPIServiceResponse {
     OkStatus(::v1_10::apimachinery::pkg::apis::meta::v1::Status),
@@ -295,38 +301,34 @@ pub enum DeleteApiregistrationV1CollectionAPIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteApiregistrationV1CollectionAPIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiregistrationV1CollectionAPIServiceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiregistrationV1CollectionAPIServiceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteApiregistrationV1CollectionAPIServiceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteApiregistrationV1CollectionAPIServiceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteApiregistrationV1CollectionAPIServiceResponse::Unauthorized, 0)),
             _ => Ok((DeleteApiregistrationV1CollectionAPIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// list or watch objects of kind APIService
     ///
@@ -420,6 +422,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::list_apiregistration_v1_api_service`](./struct.APIService.html#method.list_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListApiregistrationV1APIServiceResponse {
     Ok(::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceList),
@@ -427,6 +430,7 @@ pub enum ListApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -442,10 +446,27 @@ impl ::Response for ListApiregistrationV1APIServiceResponse {
             _ => Ok((ListApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListApiregistrationV1APIServiceResponse {
+    type List = ::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListApiregistrationV1APIServiceResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// partially update the specified APIService
     ///
@@ -481,6 +502,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::patch_apiregistration_v1_api_service`](./struct.APIService.html#method.patch_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchApiregistrationV1APIServiceResponse {
     Ok(::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIService),
@@ -488,6 +510,7 @@ pub enum PatchApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -503,10 +526,15 @@ impl ::Response for PatchApiregistrationV1APIServiceResponse {
             _ => Ok((PatchApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// read the specified APIService
     ///
@@ -555,6 +583,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::read_apiregistration_v1_api_service`](./struct.APIService.html#method.read_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadApiregistrationV1APIServiceResponse {
     Ok(::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIService),
@@ -562,6 +591,7 @@ pub enum ReadApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -577,10 +607,15 @@ impl ::Response for ReadApiregistrationV1APIServiceResponse {
             _ => Ok((ReadApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// replace the specified APIService
     ///
@@ -616,6 +651,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::replace_apiregistration_v1_api_service`](./struct.APIService.html#method.replace_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceApiregistrationV1APIServiceResponse {
     Ok(::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIService),
@@ -624,6 +660,7 @@ pub enum ReplaceApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -647,10 +684,15 @@ impl ::Response for ReplaceApiregistrationV1APIServiceResponse {
             _ => Ok((ReplaceApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceApiregistrationV1APIServiceStatus
 
+#[cfg(feature = "api")]
 impl APIService {
     /// replace status of the specified APIService
     ///
@@ -686,6 +728,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::replace_apiregistration_v1_api_service_status`](./struct.APIService.html#method.replace_apiregistration_v1_api_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceApiregistrationV1APIServiceStatusResponse {
     Ok(::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIService),
@@ -694,6 +737,7 @@ pub enum ReplaceApiregistrationV1APIServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceApiregistrationV1APIServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -717,10 +761,15 @@ impl ::Response for ReplaceApiregistrationV1APIServiceStatusResponse {
             _ => Ok((ReplaceApiregistrationV1APIServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchApiregistrationV1APIService
 
+#[cfg(feature = "api")]
 impl APIService {
     /// watch changes to an object of kind APIService
     ///
@@ -819,6 +868,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::watch_apiregistration_v1_api_service`](./struct.APIService.html#method.watch_apiregistration_v1_api_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchApiregistrationV1APIServiceResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -826,27 +876,31 @@ pub enum WatchApiregistrationV1APIServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchApiregistrationV1APIServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchApiregistrationV1APIServiceResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchApiregistrationV1APIServiceResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchApiregistrationV1APIServiceResponse::Unauthorized, 0)),
             _ => Ok((WatchApiregistrationV1APIServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchApiregistrationV1APIServiceList
 
+#[cfg(feature = "api")]
 impl APIService {
     /// watch individual changes to a list of APIService
     ///
@@ -940,6 +994,7 @@ impl APIService {
 }
 
 /// Parses the HTTP response of [`APIService::watch_apiregistration_v1_api_service_list`](./struct.APIService.html#method.watch_apiregistration_v1_api_service_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchApiregistrationV1APIServiceListResponse {
     Ok(::v1_10::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -947,23 +1002,26 @@ pub enum WatchApiregistrationV1APIServiceListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchApiregistrationV1APIServiceListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchApiregistrationV1APIServiceListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchApiregistrationV1APIServiceListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchApiregistrationV1APIServiceListResponse::Unauthorized, 0)),
             _ => Ok((WatchApiregistrationV1APIServiceListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End apiregistration.k8s.io/v1/APIService
@@ -1087,3 +1145,38 @@ impl ::serde::Serialize for APIService {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`APIService`](./struct.APIService.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum APIServiceField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for APIServiceField {
+    fn path(&self) -> &'static str {
+        match self {
+            APIServiceField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(APIServiceField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_10-apiregistration-v1beta1")]
+impl From<::v1_10::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIService> for APIService {
+    fn from(value: ::v1_10::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIService) -> Self {
+        APIService {
+            api_version: value.api_version.map(|_| "apiregistration.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
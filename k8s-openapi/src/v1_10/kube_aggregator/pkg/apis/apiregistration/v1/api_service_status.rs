@@ -86,3 +86,12 @@ impl ::serde::Serialize for APIServiceStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apiregistration-v1beta1")]
+impl From<::v1_10::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceStatus> for APIServiceStatus {
+    fn from(value: ::v1_10::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIServiceStatus) -> Self {
+        APIServiceStatus {
+            conditions: value.conditions.map(|value| value.into_iter().map(Into::into).collect()),
+        }
+    }
+}
@@ -156,3 +156,18 @@ impl ::serde::Serialize for APIServiceSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apiregistration-v1")]
+impl From<::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec> for APIServiceSpec {
+    fn from(value: ::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec) -> Self {
+        APIServiceSpec {
+            ca_bundle: value.ca_bundle,
+            group: value.group,
+            group_priority_minimum: value.group_priority_minimum,
+            insecure_skip_tls_verify: value.insecure_skip_tls_verify,
+            service: value.service.into(),
+            version: value.version,
+            version_priority: value.version_priority,
+        }
+    }
+}
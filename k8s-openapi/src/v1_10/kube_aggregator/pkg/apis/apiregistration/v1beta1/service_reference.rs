@@ -99,3 +99,13 @@ impl ::serde::Serialize for ServiceReference {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_10-apiregistration-v1")]
+impl From<::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference> for ServiceReference {
+    fn from(value: ::v1_10::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference) -> Self {
+        ServiceReference {
+            name: value.name,
+            namespace: value.namespace,
+        }
+    }
+}
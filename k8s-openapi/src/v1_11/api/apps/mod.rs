@@ -1,5 +1,8 @@
+#[cfg(feature = "v1_11-apps-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_11-apps-v1beta1")]
 pub mod v1beta1;
 
+#[cfg(feature = "v1_11-apps-v1beta2")]
 pub mod v1beta2;
@@ -147,3 +147,17 @@ impl ::serde::Serialize for DeploymentCondition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta2")]
+impl From<::v1_11::api::apps::v1beta2::DeploymentCondition> for DeploymentCondition {
+    fn from(value: ::v1_11::api::apps::v1beta2::DeploymentCondition) -> Self {
+        DeploymentCondition {
+            last_transition_time: value.last_transition_time,
+            last_update_time: value.last_update_time,
+            message: value.message,
+            reason: value.reason,
+            status: value.status,
+            type_: value.type_,
+        }
+    }
+}
@@ -134,3 +134,16 @@ impl ::serde::Serialize for ReplicaSetCondition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta2")]
+impl From<::v1_11::api::apps::v1beta2::ReplicaSetCondition> for ReplicaSetCondition {
+    fn from(value: ::v1_11::api::apps::v1beta2::ReplicaSetCondition) -> Self {
+        ReplicaSetCondition {
+            last_transition_time: value.last_transition_time,
+            message: value.message,
+            reason: value.reason,
+            status: value.status,
+            type_: value.type_,
+        }
+    }
+}
@@ -149,3 +149,17 @@ impl ::serde::Serialize for ReplicaSetStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta2")]
+impl From<::v1_11::api::apps::v1beta2::ReplicaSetStatus> for ReplicaSetStatus {
+    fn from(value: ::v1_11::api::apps::v1beta2::ReplicaSetStatus) -> Self {
+        ReplicaSetStatus {
+            available_replicas: value.available_replicas,
+            conditions: value.conditions.map(|value| value.into_iter().map(Into::into).collect()),
+            fully_labeled_replicas: value.fully_labeled_replicas,
+            observed_generation: value.observed_generation,
+            ready_replicas: value.ready_replicas,
+            replicas: value.replicas,
+        }
+    }
+}
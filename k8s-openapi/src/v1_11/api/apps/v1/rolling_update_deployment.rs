@@ -99,3 +99,13 @@ impl ::serde::Serialize for RollingUpdateDeployment {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta2")]
+impl From<::v1_11::api::apps::v1beta2::RollingUpdateDeployment> for RollingUpdateDeployment {
+    fn from(value: ::v1_11::api::apps::v1beta2::RollingUpdateDeployment) -> Self {
+        RollingUpdateDeployment {
+            max_surge: value.max_surge,
+            max_unavailable: value.max_unavailable,
+        }
+    }
+}
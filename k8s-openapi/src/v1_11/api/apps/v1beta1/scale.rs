@@ -23,6 +23,7 @@ pub struct Scale {
 
 // Generated from operation patchAppsV1beta1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified Deployment
     ///
@@ -63,6 +64,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1beta1_namespaced_deployment_scale`](./struct.Scale.html#method.patch_apps_v1beta1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta1NamespacedDeploymentScaleResponse {
     Ok(::v1_11::api::apps::v1beta1::Scale),
@@ -70,6 +72,7 @@ pub enum PatchAppsV1beta1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -85,10 +88,15 @@ impl ::Response for PatchAppsV1beta1NamespacedDeploymentScaleResponse {
             _ => Ok((PatchAppsV1beta1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1beta1NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified StatefulSet
     ///
@@ -129,6 +137,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1beta1_namespaced_stateful_set_scale`](./struct.Scale.html#method.patch_apps_v1beta1_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta1NamespacedStatefulSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta1::Scale),
@@ -136,6 +145,7 @@ pub enum PatchAppsV1beta1NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta1NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -151,10 +161,15 @@ impl ::Response for PatchAppsV1beta1NamespacedStatefulSetScaleResponse {
             _ => Ok((PatchAppsV1beta1NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified Deployment
     ///
@@ -192,6 +207,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1beta1_namespaced_deployment_scale`](./struct.Scale.html#method.read_apps_v1beta1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta1NamespacedDeploymentScaleResponse {
     Ok(::v1_11::api::apps::v1beta1::Scale),
@@ -199,6 +215,7 @@ pub enum ReadAppsV1beta1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -214,10 +231,15 @@ impl ::Response for ReadAppsV1beta1NamespacedDeploymentScaleResponse {
             _ => Ok((ReadAppsV1beta1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta1NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified StatefulSet
     ///
@@ -255,6 +277,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1beta1_namespaced_stateful_set_scale`](./struct.Scale.html#method.read_apps_v1beta1_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta1NamespacedStatefulSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta1::Scale),
@@ -262,6 +285,7 @@ pub enum ReadAppsV1beta1NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta1NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -277,10 +301,15 @@ impl ::Response for ReadAppsV1beta1NamespacedStatefulSetScaleResponse {
             _ => Ok((ReadAppsV1beta1NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta1NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified Deployment
     ///
@@ -321,6 +350,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1beta1_namespaced_deployment_scale`](./struct.Scale.html#method.replace_apps_v1beta1_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta1NamespacedDeploymentScaleResponse {
     Ok(::v1_11::api::apps::v1beta1::Scale),
@@ -329,6 +359,7 @@ pub enum ReplaceAppsV1beta1NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta1NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -352,10 +383,15 @@ impl ::Response for ReplaceAppsV1beta1NamespacedDeploymentScaleResponse {
             _ => Ok((ReplaceAppsV1beta1NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta1NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified StatefulSet
     ///
@@ -396,6 +432,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1beta1_namespaced_stateful_set_scale`](./struct.Scale.html#method.replace_apps_v1beta1_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta1NamespacedStatefulSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta1::Scale),
@@ -404,6 +441,7 @@ pub enum ReplaceAppsV1beta1NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta1NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -427,6 +465,10 @@ impl ::Response for ReplaceAppsV1beta1NamespacedStatefulSetScaleResponse {
             _ => Ok((ReplaceAppsV1beta1NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End apps/v1beta1/Scale
@@ -550,3 +592,40 @@ impl ::serde::Serialize for Scale {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta2")]
+impl From<::v1_11::api::apps::v1beta2::Scale> for Scale {
+    fn from(value: ::v1_11::api::apps::v1beta2::Scale) -> Self {
+        Scale {
+            api_version: value.api_version.map(|_| "apps/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-autoscaling-v1")]
+impl ::std::convert::TryFrom<::v1_11::api::autoscaling::v1::Scale> for Scale {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_11::api::autoscaling::v1::Scale) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "apps/v1beta1".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::Scale> for Scale {
+    fn from(value: ::v1_11::api::extensions::v1beta1::Scale) -> Self {
+        Scale {
+            api_version: value.api_version.map(|_| "apps/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
@@ -110,3 +110,25 @@ impl ::serde::Serialize for ScaleStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta2")]
+impl From<::v1_11::api::apps::v1beta2::ScaleStatus> for ScaleStatus {
+    fn from(value: ::v1_11::api::apps::v1beta2::ScaleStatus) -> Self {
+        ScaleStatus {
+            replicas: value.replicas,
+            selector: value.selector,
+            target_selector: value.target_selector,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::ScaleStatus> for ScaleStatus {
+    fn from(value: ::v1_11::api::extensions::v1beta1::ScaleStatus) -> Self {
+        ScaleStatus {
+            replicas: value.replicas,
+            selector: value.selector,
+            target_selector: value.target_selector,
+        }
+    }
+}
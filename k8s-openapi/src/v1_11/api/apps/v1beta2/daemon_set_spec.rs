@@ -134,3 +134,16 @@ impl ::serde::Serialize for DaemonSetSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1")]
+impl From<::v1_11::api::apps::v1::DaemonSetSpec> for DaemonSetSpec {
+    fn from(value: ::v1_11::api::apps::v1::DaemonSetSpec) -> Self {
+        DaemonSetSpec {
+            min_ready_seconds: value.min_ready_seconds,
+            revision_history_limit: value.revision_history_limit,
+            selector: value.selector,
+            template: value.template,
+            update_strategy: value.update_strategy.map(Into::into),
+        }
+    }
+}
@@ -86,3 +86,12 @@ impl ::serde::Serialize for RollingUpdateDaemonSet {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1")]
+impl From<::v1_11::api::apps::v1::RollingUpdateDaemonSet> for RollingUpdateDaemonSet {
+    fn from(value: ::v1_11::api::apps::v1::RollingUpdateDaemonSet) -> Self {
+        RollingUpdateDaemonSet {
+            max_unavailable: value.max_unavailable,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct Scale {
 
 // Generated from operation patchAppsV1beta2NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified Deployment
     ///
@@ -63,6 +64,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1beta2_namespaced_deployment_scale`](./struct.Scale.html#method.patch_apps_v1beta2_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedDeploymentScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -70,6 +72,7 @@ pub enum PatchAppsV1beta2NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -85,10 +88,15 @@ impl ::Response for PatchAppsV1beta2NamespacedDeploymentScaleResponse {
             _ => Ok((PatchAppsV1beta2NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified ReplicaSet
     ///
@@ -129,6 +137,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1beta2_namespaced_replica_set_scale`](./struct.Scale.html#method.patch_apps_v1beta2_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedReplicaSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -136,6 +145,7 @@ pub enum PatchAppsV1beta2NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -151,10 +161,15 @@ impl ::Response for PatchAppsV1beta2NamespacedReplicaSetScaleResponse {
             _ => Ok((PatchAppsV1beta2NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAppsV1beta2NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// partially update scale of the specified StatefulSet
     ///
@@ -195,6 +210,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::patch_apps_v1beta2_namespaced_stateful_set_scale`](./struct.Scale.html#method.patch_apps_v1beta2_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAppsV1beta2NamespacedStatefulSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -202,6 +218,7 @@ pub enum PatchAppsV1beta2NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAppsV1beta2NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -217,10 +234,15 @@ impl ::Response for PatchAppsV1beta2NamespacedStatefulSetScaleResponse {
             _ => Ok((PatchAppsV1beta2NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified Deployment
     ///
@@ -258,6 +280,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1beta2_namespaced_deployment_scale`](./struct.Scale.html#method.read_apps_v1beta2_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedDeploymentScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -265,6 +288,7 @@ pub enum ReadAppsV1beta2NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -280,10 +304,15 @@ impl ::Response for ReadAppsV1beta2NamespacedDeploymentScaleResponse {
             _ => Ok((ReadAppsV1beta2NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified ReplicaSet
     ///
@@ -321,6 +350,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1beta2_namespaced_replica_set_scale`](./struct.Scale.html#method.read_apps_v1beta2_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedReplicaSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -328,6 +358,7 @@ pub enum ReadAppsV1beta2NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -343,10 +374,15 @@ impl ::Response for ReadAppsV1beta2NamespacedReplicaSetScaleResponse {
             _ => Ok((ReadAppsV1beta2NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAppsV1beta2NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// read scale of the specified StatefulSet
     ///
@@ -384,6 +420,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::read_apps_v1beta2_namespaced_stateful_set_scale`](./struct.Scale.html#method.read_apps_v1beta2_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAppsV1beta2NamespacedStatefulSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -391,6 +428,7 @@ pub enum ReadAppsV1beta2NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAppsV1beta2NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -406,10 +444,15 @@ impl ::Response for ReadAppsV1beta2NamespacedStatefulSetScaleResponse {
             _ => Ok((ReadAppsV1beta2NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedDeploymentScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified Deployment
     ///
@@ -450,6 +493,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1beta2_namespaced_deployment_scale`](./struct.Scale.html#method.replace_apps_v1beta2_namespaced_deployment_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedDeploymentScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -458,6 +502,7 @@ pub enum ReplaceAppsV1beta2NamespacedDeploymentScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedDeploymentScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -481,10 +526,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedDeploymentScaleResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedDeploymentScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedReplicaSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified ReplicaSet
     ///
@@ -525,6 +575,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1beta2_namespaced_replica_set_scale`](./struct.Scale.html#method.replace_apps_v1beta2_namespaced_replica_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedReplicaSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -533,6 +584,7 @@ pub enum ReplaceAppsV1beta2NamespacedReplicaSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedReplicaSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -556,10 +608,15 @@ impl ::Response for ReplaceAppsV1beta2NamespacedReplicaSetScaleResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedReplicaSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAppsV1beta2NamespacedStatefulSetScale
 
+#[cfg(feature = "api")]
 impl Scale {
     /// replace scale of the specified StatefulSet
     ///
@@ -600,6 +657,7 @@ impl Scale {
 }
 
 /// Parses the HTTP response of [`Scale::replace_apps_v1beta2_namespaced_stateful_set_scale`](./struct.Scale.html#method.replace_apps_v1beta2_namespaced_stateful_set_scale)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAppsV1beta2NamespacedStatefulSetScaleResponse {
     Ok(::v1_11::api::apps::v1beta2::Scale),
@@ -608,6 +666,7 @@ pub enum ReplaceAppsV1beta2NamespacedStatefulSetScaleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAppsV1beta2NamespacedStatefulSetScaleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -631,6 +690,10 @@ impl ::Response for ReplaceAppsV1beta2NamespacedStatefulSetScaleResponse {
             _ => Ok((ReplaceAppsV1beta2NamespacedStatefulSetScaleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End apps/v1beta2/Scale
@@ -754,3 +817,40 @@ impl ::serde::Serialize for Scale {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta1")]
+impl From<::v1_11::api::apps::v1beta1::Scale> for Scale {
+    fn from(value: ::v1_11::api::apps::v1beta1::Scale) -> Self {
+        Scale {
+            api_version: value.api_version.map(|_| "apps/v1beta2".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-autoscaling-v1")]
+impl ::std::convert::TryFrom<::v1_11::api::autoscaling::v1::Scale> for Scale {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_11::api::autoscaling::v1::Scale) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "apps/v1beta2".to_string());
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::Scale> for Scale {
+    fn from(value: ::v1_11::api::extensions::v1beta1::Scale) -> Self {
+        Scale {
+            api_version: value.api_version.map(|_| "apps/v1beta2".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
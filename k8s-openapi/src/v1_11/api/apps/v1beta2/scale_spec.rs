@@ -86,3 +86,21 @@ impl ::serde::Serialize for ScaleSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apps-v1beta1")]
+impl From<::v1_11::api::apps::v1beta1::ScaleSpec> for ScaleSpec {
+    fn from(value: ::v1_11::api::apps::v1beta1::ScaleSpec) -> Self {
+        ScaleSpec {
+            replicas: value.replicas,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::ScaleSpec> for ScaleSpec {
+    fn from(value: ::v1_11::api::extensions::v1beta1::ScaleSpec) -> Self {
+        ScaleSpec {
+            replicas: value.replicas,
+        }
+    }
+}
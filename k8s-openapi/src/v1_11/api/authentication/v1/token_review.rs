@@ -22,6 +22,7 @@ pub struct TokenReview {
 
 // Generated from operation createAuthenticationV1TokenReview
 
+#[cfg(feature = "api")]
 impl TokenReview {
     /// create a TokenReview
     ///
@@ -52,6 +53,7 @@ impl TokenReview {
 }
 
 /// Parses the HTTP response of [`TokenReview::create_authentication_v1_token_review`](./struct.TokenReview.html#method.create_authentication_v1_token_review)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAuthenticationV1TokenReviewResponse {
     Ok(::v1_11::api::authentication::v1::TokenReview),
@@ -61,6 +63,7 @@ pub enum CreateAuthenticationV1TokenReviewResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAuthenticationV1TokenReviewResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,6 +95,10 @@ impl ::Response for CreateAuthenticationV1TokenReviewResponse {
             _ => Ok((CreateAuthenticationV1TokenReviewResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End authentication.k8s.io/v1/TokenReview
@@ -213,3 +220,16 @@ impl ::serde::Serialize for TokenReview {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authentication-v1beta1")]
+impl From<::v1_11::api::authentication::v1beta1::TokenReview> for TokenReview {
+    fn from(value: ::v1_11::api::authentication::v1beta1::TokenReview) -> Self {
+        TokenReview {
+            api_version: value.api_version.map(|_| "authentication.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.into(),
+            status: value.status.map(Into::into),
+        }
+    }
+}
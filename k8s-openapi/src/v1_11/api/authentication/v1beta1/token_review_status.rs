@@ -112,3 +112,14 @@ impl ::serde::Serialize for TokenReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authentication-v1")]
+impl From<::v1_11::api::authentication::v1::TokenReviewStatus> for TokenReviewStatus {
+    fn from(value: ::v1_11::api::authentication::v1::TokenReviewStatus) -> Self {
+        TokenReviewStatus {
+            authenticated: value.authenticated,
+            error: value.error,
+            user: value.user.map(Into::into),
+        }
+    }
+}
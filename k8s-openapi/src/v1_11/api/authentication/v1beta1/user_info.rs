@@ -125,3 +125,15 @@ impl ::serde::Serialize for UserInfo {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authentication-v1")]
+impl From<::v1_11::api::authentication::v1::UserInfo> for UserInfo {
+    fn from(value: ::v1_11::api::authentication::v1::UserInfo) -> Self {
+        UserInfo {
+            extra: value.extra,
+            groups: value.groups,
+            uid: value.uid,
+            username: value.username,
+        }
+    }
+}
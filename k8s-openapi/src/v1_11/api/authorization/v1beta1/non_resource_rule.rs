@@ -97,3 +97,13 @@ impl ::serde::Serialize for NonResourceRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authorization-v1")]
+impl From<::v1_11::api::authorization::v1::NonResourceRule> for NonResourceRule {
+    fn from(value: ::v1_11::api::authorization::v1::NonResourceRule) -> Self {
+        NonResourceRule {
+            non_resource_urls: value.non_resource_urls,
+            verbs: value.verbs,
+        }
+    }
+}
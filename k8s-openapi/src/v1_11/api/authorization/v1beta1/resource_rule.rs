@@ -124,3 +124,15 @@ impl ::serde::Serialize for ResourceRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authorization-v1")]
+impl From<::v1_11::api::authorization::v1::ResourceRule> for ResourceRule {
+    fn from(value: ::v1_11::api::authorization::v1::ResourceRule) -> Self {
+        ResourceRule {
+            api_groups: value.api_groups,
+            resource_names: value.resource_names,
+            resources: value.resources,
+            verbs: value.verbs,
+        }
+    }
+}
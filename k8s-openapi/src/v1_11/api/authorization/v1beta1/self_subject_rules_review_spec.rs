@@ -85,3 +85,12 @@ impl ::serde::Serialize for SelfSubjectRulesReviewSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authorization-v1")]
+impl From<::v1_11::api::authorization::v1::SelfSubjectRulesReviewSpec> for SelfSubjectRulesReviewSpec {
+    fn from(value: ::v1_11::api::authorization::v1::SelfSubjectRulesReviewSpec) -> Self {
+        SelfSubjectRulesReviewSpec {
+            namespace: value.namespace,
+        }
+    }
+}
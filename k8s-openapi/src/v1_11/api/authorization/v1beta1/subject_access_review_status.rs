@@ -123,3 +123,15 @@ impl ::serde::Serialize for SubjectAccessReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-authorization-v1")]
+impl From<::v1_11::api::authorization::v1::SubjectAccessReviewStatus> for SubjectAccessReviewStatus {
+    fn from(value: ::v1_11::api::authorization::v1::SubjectAccessReviewStatus) -> Self {
+        SubjectAccessReviewStatus {
+            allowed: value.allowed,
+            denied: value.denied,
+            evaluation_error: value.evaluation_error,
+            reason: value.reason,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct HorizontalPodAutoscaler {
 
 // Generated from operation createAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// create a HorizontalPodAutoscaler
     ///
@@ -58,6 +59,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::create_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.create_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -67,6 +69,7 @@ pub enum CreateAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateAutoscalingV2beta1NamespacedHorizontalPodAutoscalerRes
             _ => Ok((CreateAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// delete collection of HorizontalPodAutoscaler
     ///
@@ -200,6 +208,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::delete_autoscaling_v2beta1_collection_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.delete_autoscaling_v2beta1_collection_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResp
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse::Unauthorized, 0)),
             _ => Ok((DeleteAutoscalingV2beta1CollectionNamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// delete a HorizontalPodAutoscaler
     ///
@@ -303,6 +308,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::delete_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.delete_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Unauthorized, 0)),
             _ => Ok((DeleteAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespaces
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// list or watch objects of kind HorizontalPodAutoscaler
     ///
@@ -436,6 +438,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::list_autoscaling_v2beta1_horizontal_pod_autoscaler_for_all_namespaces`](./struct.HorizontalPodAutoscaler.html#method.list_autoscaling_v2beta1_horizontal_pod_autoscaler_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespacesResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscalerList),
@@ -443,6 +446,7 @@ pub enum ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespace
             _ => Ok((ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespacesResponse {
+    type List = ::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscalerList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAutoscalingV2beta1HorizontalPodAutoscalerForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// list or watch objects of kind HorizontalPodAutoscaler
     ///
@@ -560,6 +581,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::list_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.list_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscalerList),
@@ -567,6 +589,7 @@ pub enum ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerRespo
             _ => Ok((ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
+    type List = ::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscalerList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// partially update the specified HorizontalPodAutoscaler
     ///
@@ -626,6 +666,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::patch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.patch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -633,6 +674,7 @@ pub enum PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResp
             _ => Ok((PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatus
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// partially update status of the specified HorizontalPodAutoscaler
     ///
@@ -692,6 +739,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::patch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_status`](./struct.HorizontalPodAutoscaler.html#method.patch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -699,6 +747,7 @@ pub enum PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStat
             _ => Ok((PatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// read the specified HorizontalPodAutoscaler
     ///
@@ -771,6 +825,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::read_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.read_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -778,6 +833,7 @@ pub enum ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerRespo
             _ => Ok((ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatus
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// read status of the specified HorizontalPodAutoscaler
     ///
@@ -834,6 +895,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::read_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_status`](./struct.HorizontalPodAutoscaler.html#method.read_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -841,6 +903,7 @@ pub enum ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatu
             _ => Ok((ReadAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// replace the specified HorizontalPodAutoscaler
     ///
@@ -900,6 +968,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::replace_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.replace_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -908,6 +977,7 @@ pub enum ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerRe
             _ => Ok((ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatus
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// replace status of the specified HorizontalPodAutoscaler
     ///
@@ -975,6 +1050,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::replace_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_status`](./struct.HorizontalPodAutoscaler.html#method.replace_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     Ok(::v1_11::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
@@ -983,6 +1059,7 @@ pub enum ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusRespons
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerSt
             _ => Ok((ReplaceAutoscalingV2beta1NamespacedHorizontalPodAutoscalerStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// watch individual changes to a list of HorizontalPodAutoscaler
     ///
@@ -1103,6 +1185,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::watch_autoscaling_v2beta1_horizontal_pod_autoscaler_list_for_all_namespaces`](./struct.HorizontalPodAutoscaler.html#method.watch_autoscaling_v2beta1_horizontal_pod_autoscaler_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesRespo
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchAutoscalingV2beta1HorizontalPodAutoscalerListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAutoscalingV2beta1NamespacedHorizontalPodAutoscaler
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// watch changes to an object of kind HorizontalPodAutoscaler
     ///
@@ -1234,6 +1321,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::watch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler`](./struct.HorizontalPodAutoscaler.html#method.watch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Unauthorized, 0)),
             _ => Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerList
 
+#[cfg(feature = "api")]
 impl HorizontalPodAutoscaler {
     /// watch individual changes to a list of HorizontalPodAutoscaler
     ///
@@ -1360,6 +1452,7 @@ impl HorizontalPodAutoscaler {
 }
 
 /// Parses the HTTP response of [`HorizontalPodAutoscaler::watch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_list`](./struct.HorizontalPodAutoscaler.html#method.watch_autoscaling_v2beta1_namespaced_horizontal_pod_autoscaler_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse::Unauthorized, 0)),
             _ => Ok((WatchAutoscalingV2beta1NamespacedHorizontalPodAutoscalerListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End autoscaling/v2beta1/HorizontalPodAutoscaler
@@ -1507,3 +1603,41 @@ impl ::serde::Serialize for HorizontalPodAutoscaler {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`HorizontalPodAutoscaler`](./struct.HorizontalPodAutoscaler.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HorizontalPodAutoscalerField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for HorizontalPodAutoscalerField {
+    fn path(&self) -> &'static str {
+        match self {
+            HorizontalPodAutoscalerField::MetadataName => "metadata.name",
+            HorizontalPodAutoscalerField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(HorizontalPodAutoscalerField::MetadataName),
+            "metadata.namespace" => Some(HorizontalPodAutoscalerField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-autoscaling-v1")]
+impl ::std::convert::TryFrom<::v1_11::api::autoscaling::v1::HorizontalPodAutoscaler> for HorizontalPodAutoscaler {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_11::api::autoscaling::v1::HorizontalPodAutoscaler) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "autoscaling/v2beta1".to_string());
+        Ok(result)
+    }
+}
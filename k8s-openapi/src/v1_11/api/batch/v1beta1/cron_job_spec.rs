@@ -160,3 +160,18 @@ impl ::serde::Serialize for CronJobSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-batch-v2alpha1")]
+impl From<::v1_11::api::batch::v2alpha1::CronJobSpec> for CronJobSpec {
+    fn from(value: ::v1_11::api::batch::v2alpha1::CronJobSpec) -> Self {
+        CronJobSpec {
+            concurrency_policy: value.concurrency_policy,
+            failed_jobs_history_limit: value.failed_jobs_history_limit,
+            job_template: value.job_template.into(),
+            schedule: value.schedule,
+            starting_deadline_seconds: value.starting_deadline_seconds,
+            successful_jobs_history_limit: value.successful_jobs_history_limit,
+            suspend: value.suspend,
+        }
+    }
+}
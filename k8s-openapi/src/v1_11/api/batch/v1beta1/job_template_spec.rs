@@ -99,3 +99,13 @@ impl ::serde::Serialize for JobTemplateSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-batch-v2alpha1")]
+impl From<::v1_11::api::batch::v2alpha1::JobTemplateSpec> for JobTemplateSpec {
+    fn from(value: ::v1_11::api::batch::v2alpha1::JobTemplateSpec) -> Self {
+        JobTemplateSpec {
+            metadata: value.metadata,
+            spec: value.spec,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct CronJob {
 
 // Generated from operation createBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// create a CronJob
     ///
@@ -58,6 +59,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::create_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.create_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateBatchV2alpha1NamespacedCronJobResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -67,6 +69,7 @@ pub enum CreateBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -98,10 +101,15 @@ impl ::Response for CreateBatchV2alpha1NamespacedCronJobResponse {
             _ => Ok((CreateBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteBatchV2alpha1CollectionNamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// delete collection of CronJob
     ///
@@ -200,6 +208,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::delete_batch_v2alpha1_collection_namespaced_cron_job`](./struct.CronJob.html#method.delete_batch_v2alpha1_collection_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteBatchV2alpha1CollectionNamespacedCronJobResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -208,38 +217,34 @@ pub enum DeleteBatchV2alpha1CollectionNamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteBatchV2alpha1CollectionNamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV2alpha1CollectionNamespacedCronJobResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV2alpha1CollectionNamespacedCronJobResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteBatchV2alpha1CollectionNamespacedCronJobResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteBatchV2alpha1CollectionNamespacedCronJobResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteBatchV2alpha1CollectionNamespacedCronJobResponse::Unauthorized, 0)),
             _ => Ok((DeleteBatchV2alpha1CollectionNamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// delete a CronJob
     ///
@@ -303,6 +308,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::delete_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.delete_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteBatchV2alpha1NamespacedCronJobResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -311,38 +317,34 @@ pub enum DeleteBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV2alpha1NamespacedCronJobResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteBatchV2alpha1NamespacedCronJobResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteBatchV2alpha1NamespacedCronJobResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteBatchV2alpha1NamespacedCronJobResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteBatchV2alpha1NamespacedCronJobResponse::Unauthorized, 0)),
             _ => Ok((DeleteBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listBatchV2alpha1CronJobForAllNamespaces
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// list or watch objects of kind CronJob
     ///
@@ -436,6 +438,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::list_batch_v2alpha1_cron_job_for_all_namespaces`](./struct.CronJob.html#method.list_batch_v2alpha1_cron_job_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListBatchV2alpha1CronJobForAllNamespacesResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJobList),
@@ -443,6 +446,7 @@ pub enum ListBatchV2alpha1CronJobForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListBatchV2alpha1CronJobForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListBatchV2alpha1CronJobForAllNamespacesResponse {
             _ => Ok((ListBatchV2alpha1CronJobForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListBatchV2alpha1CronJobForAllNamespacesResponse {
+    type List = ::v1_11::api::batch::v2alpha1::CronJobList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListBatchV2alpha1CronJobForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// list or watch objects of kind CronJob
     ///
@@ -560,6 +581,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::list_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.list_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListBatchV2alpha1NamespacedCronJobResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJobList),
@@ -567,6 +589,7 @@ pub enum ListBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -582,10 +605,27 @@ impl ::Response for ListBatchV2alpha1NamespacedCronJobResponse {
             _ => Ok((ListBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListBatchV2alpha1NamespacedCronJobResponse {
+    type List = ::v1_11::api::batch::v2alpha1::CronJobList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListBatchV2alpha1NamespacedCronJobResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// partially update the specified CronJob
     ///
@@ -626,6 +666,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::patch_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.patch_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchBatchV2alpha1NamespacedCronJobResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -633,6 +674,7 @@ pub enum PatchBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -648,10 +690,15 @@ impl ::Response for PatchBatchV2alpha1NamespacedCronJobResponse {
             _ => Ok((PatchBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchBatchV2alpha1NamespacedCronJobStatus
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// partially update status of the specified CronJob
     ///
@@ -692,6 +739,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::patch_batch_v2alpha1_namespaced_cron_job_status`](./struct.CronJob.html#method.patch_batch_v2alpha1_namespaced_cron_job_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchBatchV2alpha1NamespacedCronJobStatusResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -699,6 +747,7 @@ pub enum PatchBatchV2alpha1NamespacedCronJobStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchBatchV2alpha1NamespacedCronJobStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -714,10 +763,15 @@ impl ::Response for PatchBatchV2alpha1NamespacedCronJobStatusResponse {
             _ => Ok((PatchBatchV2alpha1NamespacedCronJobStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// read the specified CronJob
     ///
@@ -771,6 +825,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::read_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.read_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadBatchV2alpha1NamespacedCronJobResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -778,6 +833,7 @@ pub enum ReadBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -793,10 +849,15 @@ impl ::Response for ReadBatchV2alpha1NamespacedCronJobResponse {
             _ => Ok((ReadBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readBatchV2alpha1NamespacedCronJobStatus
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// read status of the specified CronJob
     ///
@@ -834,6 +895,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::read_batch_v2alpha1_namespaced_cron_job_status`](./struct.CronJob.html#method.read_batch_v2alpha1_namespaced_cron_job_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadBatchV2alpha1NamespacedCronJobStatusResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -841,6 +903,7 @@ pub enum ReadBatchV2alpha1NamespacedCronJobStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadBatchV2alpha1NamespacedCronJobStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -856,10 +919,15 @@ impl ::Response for ReadBatchV2alpha1NamespacedCronJobStatusResponse {
             _ => Ok((ReadBatchV2alpha1NamespacedCronJobStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// replace the specified CronJob
     ///
@@ -900,6 +968,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::replace_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.replace_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceBatchV2alpha1NamespacedCronJobResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -908,6 +977,7 @@ pub enum ReplaceBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -931,10 +1001,15 @@ impl ::Response for ReplaceBatchV2alpha1NamespacedCronJobResponse {
             _ => Ok((ReplaceBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceBatchV2alpha1NamespacedCronJobStatus
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// replace status of the specified CronJob
     ///
@@ -975,6 +1050,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::replace_batch_v2alpha1_namespaced_cron_job_status`](./struct.CronJob.html#method.replace_batch_v2alpha1_namespaced_cron_job_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceBatchV2alpha1NamespacedCronJobStatusResponse {
     Ok(::v1_11::api::batch::v2alpha1::CronJob),
@@ -983,6 +1059,7 @@ pub enum ReplaceBatchV2alpha1NamespacedCronJobStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceBatchV2alpha1NamespacedCronJobStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1006,10 +1083,15 @@ impl ::Response for ReplaceBatchV2alpha1NamespacedCronJobStatusResponse {
             _ => Ok((ReplaceBatchV2alpha1NamespacedCronJobStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchBatchV2alpha1CronJobListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// watch individual changes to a list of CronJob
     ///
@@ -1103,6 +1185,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::watch_batch_v2alpha1_cron_job_list_for_all_namespaces`](./struct.CronJob.html#method.watch_batch_v2alpha1_cron_job_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchBatchV2alpha1CronJobListForAllNamespacesResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1110,27 +1193,31 @@ pub enum WatchBatchV2alpha1CronJobListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchBatchV2alpha1CronJobListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchBatchV2alpha1CronJobListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchBatchV2alpha1CronJobListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchBatchV2alpha1CronJobListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchBatchV2alpha1CronJobListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchBatchV2alpha1NamespacedCronJob
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// watch changes to an object of kind CronJob
     ///
@@ -1234,6 +1321,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::watch_batch_v2alpha1_namespaced_cron_job`](./struct.CronJob.html#method.watch_batch_v2alpha1_namespaced_cron_job)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchBatchV2alpha1NamespacedCronJobResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1241,27 +1329,31 @@ pub enum WatchBatchV2alpha1NamespacedCronJobResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchBatchV2alpha1NamespacedCronJobResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchBatchV2alpha1NamespacedCronJobResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchBatchV2alpha1NamespacedCronJobResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchBatchV2alpha1NamespacedCronJobResponse::Unauthorized, 0)),
             _ => Ok((WatchBatchV2alpha1NamespacedCronJobResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchBatchV2alpha1NamespacedCronJobList
 
+#[cfg(feature = "api")]
 impl CronJob {
     /// watch individual changes to a list of CronJob
     ///
@@ -1360,6 +1452,7 @@ impl CronJob {
 }
 
 /// Parses the HTTP response of [`CronJob::watch_batch_v2alpha1_namespaced_cron_job_list`](./struct.CronJob.html#method.watch_batch_v2alpha1_namespaced_cron_job_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchBatchV2alpha1NamespacedCronJobListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1367,23 +1460,26 @@ pub enum WatchBatchV2alpha1NamespacedCronJobListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchBatchV2alpha1NamespacedCronJobListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchBatchV2alpha1NamespacedCronJobListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchBatchV2alpha1NamespacedCronJobListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchBatchV2alpha1NamespacedCronJobListResponse::Unauthorized, 0)),
             _ => Ok((WatchBatchV2alpha1NamespacedCronJobListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End batch/v2alpha1/CronJob
@@ -1507,3 +1603,43 @@ impl ::serde::Serialize for CronJob {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`CronJob`](./struct.CronJob.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CronJobField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for CronJobField {
+    fn path(&self) -> &'static str {
+        match self {
+            CronJobField::MetadataName => "metadata.name",
+            CronJobField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(CronJobField::MetadataName),
+            "metadata.namespace" => Some(CronJobField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-batch-v1beta1")]
+impl From<::v1_11::api::batch::v1beta1::CronJob> for CronJob {
+    fn from(value: ::v1_11::api::batch::v1beta1::CronJob) -> Self {
+        CronJob {
+            api_version: value.api_version.map(|_| "batch/v2alpha1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status.map(Into::into),
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub struct Binding {
 
 // Generated from operation createCoreV1NamespacedBinding
 
+#[cfg(feature = "api")]
 impl Binding {
     /// create a Binding
     ///
@@ -55,6 +56,7 @@ impl Binding {
 }
 
 /// Parses the HTTP response of [`Binding::create_core_v1_namespaced_binding`](./struct.Binding.html#method.create_core_v1_namespaced_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedBindingResponse {
     Ok(::v1_11::api::core::v1::Binding),
@@ -64,6 +66,7 @@ pub enum CreateCoreV1NamespacedBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateCoreV1NamespacedBindingResponse {
             _ => Ok((CreateCoreV1NamespacedBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation createCoreV1NamespacedPodBinding
 
+#[cfg(feature = "api")]
 impl Binding {
     /// create binding of a Pod
     ///
@@ -139,6 +147,7 @@ impl Binding {
 }
 
 /// Parses the HTTP response of [`Binding::create_core_v1_namespaced_pod_binding`](./struct.Binding.html#method.create_core_v1_namespaced_pod_binding)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedPodBindingResponse {
     Ok(::v1_11::api::core::v1::Binding),
@@ -148,6 +157,7 @@ pub enum CreateCoreV1NamespacedPodBindingResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedPodBindingResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -179,6 +189,10 @@ impl ::Response for CreateCoreV1NamespacedPodBindingResponse {
             _ => Ok((CreateCoreV1NamespacedPodBindingResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End /v1/Binding
@@ -59,6 +59,7 @@ pub struct Event {
 
 // Generated from operation createCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// create an Event
     ///
@@ -94,6 +95,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::create_core_v1_namespaced_event`](./struct.Event.html#method.create_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedEventResponse {
     Ok(::v1_11::api::core::v1::Event),
@@ -103,6 +105,7 @@ pub enum CreateCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -134,10 +137,15 @@ impl ::Response for CreateCoreV1NamespacedEventResponse {
             _ => Ok((CreateCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// delete collection of Event
     ///
@@ -236,6 +244,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::delete_core_v1_collection_namespaced_event`](./struct.Event.html#method.delete_core_v1_collection_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedEventResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -244,38 +253,34 @@ pub enum DeleteCoreV1CollectionNamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedEventResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedEventResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedEventResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedEventResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedEventResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// delete an Event
     ///
@@ -339,6 +344,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::delete_core_v1_namespaced_event`](./struct.Event.html#method.delete_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedEventResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -347,38 +353,34 @@ pub enum DeleteCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedEventResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedEventResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedEventResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedEventResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedEventResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1EventForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Event {
     /// list or watch objects of kind Event
     ///
@@ -472,6 +474,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::list_core_v1_event_for_all_namespaces`](./struct.Event.html#method.list_core_v1_event_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1EventForAllNamespacesResponse {
     Ok(::v1_11::api::core::v1::EventList),
@@ -479,6 +482,7 @@ pub enum ListCoreV1EventForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1EventForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -494,10 +498,27 @@ impl ::Response for ListCoreV1EventForAllNamespacesResponse {
             _ => Ok((ListCoreV1EventForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1EventForAllNamespacesResponse {
+    type List = ::v1_11::api::core::v1::EventList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1EventForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// list or watch objects of kind Event
     ///
@@ -596,6 +617,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::list_core_v1_namespaced_event`](./struct.Event.html#method.list_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedEventResponse {
     Ok(::v1_11::api::core::v1::EventList),
@@ -603,6 +625,7 @@ pub enum ListCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -618,10 +641,27 @@ impl ::Response for ListCoreV1NamespacedEventResponse {
             _ => Ok((ListCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedEventResponse {
+    type List = ::v1_11::api::core::v1::EventList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedEventResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// partially update the specified Event
     ///
@@ -662,6 +702,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::patch_core_v1_namespaced_event`](./struct.Event.html#method.patch_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedEventResponse {
     Ok(::v1_11::api::core::v1::Event),
@@ -669,6 +710,7 @@ pub enum PatchCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -684,10 +726,15 @@ impl ::Response for PatchCoreV1NamespacedEventResponse {
             _ => Ok((PatchCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// read the specified Event
     ///
@@ -741,6 +788,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::read_core_v1_namespaced_event`](./struct.Event.html#method.read_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedEventResponse {
     Ok(::v1_11::api::core::v1::Event),
@@ -748,6 +796,7 @@ pub enum ReadCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -763,10 +812,15 @@ impl ::Response for ReadCoreV1NamespacedEventResponse {
             _ => Ok((ReadCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// replace the specified Event
     ///
@@ -807,6 +861,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::replace_core_v1_namespaced_event`](./struct.Event.html#method.replace_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedEventResponse {
     Ok(::v1_11::api::core::v1::Event),
@@ -815,6 +870,7 @@ pub enum ReplaceCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -838,10 +894,15 @@ impl ::Response for ReplaceCoreV1NamespacedEventResponse {
             _ => Ok((ReplaceCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1EventListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Event {
     /// watch individual changes to a list of Event
     ///
@@ -935,6 +996,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::watch_core_v1_event_list_for_all_namespaces`](./struct.Event.html#method.watch_core_v1_event_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1EventListForAllNamespacesResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -942,27 +1004,31 @@ pub enum WatchCoreV1EventListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1EventListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1EventListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1EventListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1EventListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1EventListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// watch changes to an object of kind Event
     ///
@@ -1066,6 +1132,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::watch_core_v1_namespaced_event`](./struct.Event.html#method.watch_core_v1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedEventResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1073,27 +1140,31 @@ pub enum WatchCoreV1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedEventResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedEventResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedEventResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedEventList
 
+#[cfg(feature = "api")]
 impl Event {
     /// watch individual changes to a list of Event
     ///
@@ -1192,6 +1263,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::watch_core_v1_namespaced_event_list`](./struct.Event.html#method.watch_core_v1_namespaced_event_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedEventListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1199,23 +1271,26 @@ pub enum WatchCoreV1NamespacedEventListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedEventListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedEventListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedEventListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedEventListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedEventListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Event
@@ -1455,3 +1530,91 @@ impl ::serde::Serialize for Event {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Event`](./struct.Event.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+
+    /// `involvedObject.apiVersion`
+    InvolvedObjectApiVersion,
+
+    /// `involvedObject.fieldPath`
+    InvolvedObjectFieldPath,
+
+    /// `involvedObject.kind`
+    InvolvedObjectKind,
+
+    /// `involvedObject.name`
+    InvolvedObjectName,
+
+    /// `involvedObject.namespace`
+    InvolvedObjectNamespace,
+
+    /// `involvedObject.resourceVersion`
+    InvolvedObjectResourceVersion,
+
+    /// `involvedObject.uid`
+    InvolvedObjectUid,
+
+    /// `reason`
+    Reason,
+
+    /// `source`
+    Source,
+
+    /// `type`
+    Type,
+}
+
+impl ::field_selector::Field for EventField {
+    fn path(&self) -> &'static str {
+        match self {
+            EventField::MetadataName => "metadata.name",
+            EventField::MetadataNamespace => "metadata.namespace",
+            EventField::InvolvedObjectApiVersion => "involvedObject.apiVersion",
+            EventField::InvolvedObjectFieldPath => "involvedObject.fieldPath",
+            EventField::InvolvedObjectKind => "involvedObject.kind",
+            EventField::InvolvedObjectName => "involvedObject.name",
+            EventField::InvolvedObjectNamespace => "involvedObject.namespace",
+            EventField::InvolvedObjectResourceVersion => "involvedObject.resourceVersion",
+            EventField::InvolvedObjectUid => "involvedObject.uid",
+            EventField::Reason => "reason",
+            EventField::Source => "source",
+            EventField::Type => "type",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(EventField::MetadataName),
+            "metadata.namespace" => Some(EventField::MetadataNamespace),
+            "involvedObject.apiVersion" => Some(EventField::InvolvedObjectApiVersion),
+            "involvedObject.fieldPath" => Some(EventField::InvolvedObjectFieldPath),
+            "involvedObject.kind" => Some(EventField::InvolvedObjectKind),
+            "involvedObject.name" => Some(EventField::InvolvedObjectName),
+            "involvedObject.namespace" => Some(EventField::InvolvedObjectNamespace),
+            "involvedObject.resourceVersion" => Some(EventField::InvolvedObjectResourceVersion),
+            "involvedObject.uid" => Some(EventField::InvolvedObjectUid),
+            "reason" => Some(EventField::Reason),
+            "source" => Some(EventField::Source),
+            "type" => Some(EventField::Type),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-events-v1beta1")]
+impl ::std::convert::TryFrom<::v1_11::api::events::v1beta1::Event> for Event {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_11::api::events::v1beta1::Event) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "v1".to_string());
+        Ok(result)
+    }
+}
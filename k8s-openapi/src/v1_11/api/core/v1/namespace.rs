@@ -23,6 +23,7 @@ pub struct Namespace {
 
 // Generated from operation createCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// create a Namespace
     ///
@@ -53,6 +54,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::create_core_v1_namespace`](./struct.Namespace.html#method.create_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespaceResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -62,6 +64,7 @@ pub enum CreateCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -93,10 +96,15 @@ impl ::Response for CreateCoreV1NamespaceResponse {
             _ => Ok((CreateCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// delete a Namespace
     ///
@@ -155,6 +163,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::delete_core_v1_namespace`](./struct.Namespace.html#method.delete_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespaceResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -163,38 +172,34 @@ pub enum DeleteCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespaceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespaceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespaceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespaceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespaceResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// list or watch objects of kind Namespace
     ///
@@ -288,6 +293,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::list_core_v1_namespace`](./struct.Namespace.html#method.list_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespaceResponse {
     Ok(::v1_11::api::core::v1::NamespaceList),
@@ -295,6 +301,7 @@ pub enum ListCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -310,10 +317,27 @@ impl ::Response for ListCoreV1NamespaceResponse {
             _ => Ok((ListCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespaceResponse {
+    type List = ::v1_11::api::core::v1::NamespaceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespaceResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// partially update the specified Namespace
     ///
@@ -349,6 +373,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::patch_core_v1_namespace`](./struct.Namespace.html#method.patch_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespaceResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -356,6 +381,7 @@ pub enum PatchCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -371,10 +397,15 @@ impl ::Response for PatchCoreV1NamespaceResponse {
             _ => Ok((PatchCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespaceStatus
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// partially update status of the specified Namespace
     ///
@@ -410,6 +441,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::patch_core_v1_namespace_status`](./struct.Namespace.html#method.patch_core_v1_namespace_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespaceStatusResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -417,6 +449,7 @@ pub enum PatchCoreV1NamespaceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespaceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -432,10 +465,15 @@ impl ::Response for PatchCoreV1NamespaceStatusResponse {
             _ => Ok((PatchCoreV1NamespaceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// read the specified Namespace
     ///
@@ -484,6 +522,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::read_core_v1_namespace`](./struct.Namespace.html#method.read_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespaceResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -491,6 +530,7 @@ pub enum ReadCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -506,10 +546,15 @@ impl ::Response for ReadCoreV1NamespaceResponse {
             _ => Ok((ReadCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespaceStatus
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// read status of the specified Namespace
     ///
@@ -542,6 +587,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::read_core_v1_namespace_status`](./struct.Namespace.html#method.read_core_v1_namespace_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespaceStatusResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -549,6 +595,7 @@ pub enum ReadCoreV1NamespaceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespaceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -564,10 +611,15 @@ impl ::Response for ReadCoreV1NamespaceStatusResponse {
             _ => Ok((ReadCoreV1NamespaceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// replace the specified Namespace
     ///
@@ -603,6 +655,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::replace_core_v1_namespace`](./struct.Namespace.html#method.replace_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespaceResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -611,6 +664,7 @@ pub enum ReplaceCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -634,10 +688,15 @@ impl ::Response for ReplaceCoreV1NamespaceResponse {
             _ => Ok((ReplaceCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespaceFinalize
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// replace finalize of the specified Namespace
     ///
@@ -673,6 +732,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::replace_core_v1_namespace_finalize`](./struct.Namespace.html#method.replace_core_v1_namespace_finalize)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespaceFinalizeResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -681,6 +741,7 @@ pub enum ReplaceCoreV1NamespaceFinalizeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespaceFinalizeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -704,10 +765,15 @@ impl ::Response for ReplaceCoreV1NamespaceFinalizeResponse {
             _ => Ok((ReplaceCoreV1NamespaceFinalizeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespaceStatus
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// replace status of the specified Namespace
     ///
@@ -743,6 +809,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::replace_core_v1_namespace_status`](./struct.Namespace.html#method.replace_core_v1_namespace_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespaceStatusResponse {
     Ok(::v1_11::api::core::v1::Namespace),
@@ -751,6 +818,7 @@ pub enum ReplaceCoreV1NamespaceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespaceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -774,10 +842,15 @@ impl ::Response for ReplaceCoreV1NamespaceStatusResponse {
             _ => Ok((ReplaceCoreV1NamespaceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// watch changes to an object of kind Namespace
     ///
@@ -876,6 +949,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::watch_core_v1_namespace`](./struct.Namespace.html#method.watch_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespaceResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -883,27 +957,31 @@ pub enum WatchCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespaceResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespaceResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespaceResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespaceList
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// watch individual changes to a list of Namespace
     ///
@@ -997,6 +1075,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::watch_core_v1_namespace_list`](./struct.Namespace.html#method.watch_core_v1_namespace_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespaceListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1004,23 +1083,26 @@ pub enum WatchCoreV1NamespaceListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespaceListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespaceListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespaceListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespaceListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespaceListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Namespace
@@ -1144,3 +1226,30 @@ impl ::serde::Serialize for Namespace {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Namespace`](./struct.Namespace.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NamespaceField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `status.phase`
+    StatusPhase,
+}
+
+impl ::field_selector::Field for NamespaceField {
+    fn path(&self) -> &'static str {
+        match self {
+            NamespaceField::MetadataName => "metadata.name",
+            NamespaceField::StatusPhase => "status.phase",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(NamespaceField::MetadataName),
+            "status.phase" => Some(NamespaceField::StatusPhase),
+            _ => None,
+        }
+    }
+}
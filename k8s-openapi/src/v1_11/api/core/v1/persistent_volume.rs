@@ -23,6 +23,7 @@ pub struct PersistentVolume {
 
 // Generated from operation createCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// create a PersistentVolume
     ///
@@ -53,6 +54,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::create_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.create_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1PersistentVolumeResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -62,6 +64,7 @@ pub enum CreateCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -93,10 +96,15 @@ impl ::Response for CreateCoreV1PersistentVolumeResponse {
             _ => Ok((CreateCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionPersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// delete collection of PersistentVolume
     ///
@@ -190,6 +198,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::delete_core_v1_collection_persistent_volume`](./struct.PersistentVolume.html#method.delete_core_v1_collection_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionPersistentVolumeResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -198,38 +207,34 @@ pub enum DeleteCoreV1CollectionPersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionPersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionPersistentVolumeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionPersistentVolumeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionPersistentVolumeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionPersistentVolumeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionPersistentVolumeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionPersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// delete a PersistentVolume
     ///
@@ -288,6 +293,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::delete_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.delete_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1PersistentVolumeResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -296,38 +302,34 @@ pub enum DeleteCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1PersistentVolumeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1PersistentVolumeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1PersistentVolumeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1PersistentVolumeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1PersistentVolumeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// list or watch objects of kind PersistentVolume
     ///
@@ -421,6 +423,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::list_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.list_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1PersistentVolumeResponse {
     Ok(::v1_11::api::core::v1::PersistentVolumeList),
@@ -428,6 +431,7 @@ pub enum ListCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -443,10 +447,27 @@ impl ::Response for ListCoreV1PersistentVolumeResponse {
             _ => Ok((ListCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1PersistentVolumeResponse {
+    type List = ::v1_11::api::core::v1::PersistentVolumeList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1PersistentVolumeResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// partially update the specified PersistentVolume
     ///
@@ -482,6 +503,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::patch_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.patch_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1PersistentVolumeResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -489,6 +511,7 @@ pub enum PatchCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -504,10 +527,15 @@ impl ::Response for PatchCoreV1PersistentVolumeResponse {
             _ => Ok((PatchCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1PersistentVolumeStatus
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// partially update status of the specified PersistentVolume
     ///
@@ -543,6 +571,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::patch_core_v1_persistent_volume_status`](./struct.PersistentVolume.html#method.patch_core_v1_persistent_volume_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1PersistentVolumeStatusResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -550,6 +579,7 @@ pub enum PatchCoreV1PersistentVolumeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1PersistentVolumeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -565,10 +595,15 @@ impl ::Response for PatchCoreV1PersistentVolumeStatusResponse {
             _ => Ok((PatchCoreV1PersistentVolumeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// read the specified PersistentVolume
     ///
@@ -617,6 +652,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::read_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.read_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1PersistentVolumeResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -624,6 +660,7 @@ pub enum ReadCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -639,10 +676,15 @@ impl ::Response for ReadCoreV1PersistentVolumeResponse {
             _ => Ok((ReadCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1PersistentVolumeStatus
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// read status of the specified PersistentVolume
     ///
@@ -675,6 +717,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::read_core_v1_persistent_volume_status`](./struct.PersistentVolume.html#method.read_core_v1_persistent_volume_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1PersistentVolumeStatusResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -682,6 +725,7 @@ pub enum ReadCoreV1PersistentVolumeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1PersistentVolumeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -697,10 +741,15 @@ impl ::Response for ReadCoreV1PersistentVolumeStatusResponse {
             _ => Ok((ReadCoreV1PersistentVolumeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// replace the specified PersistentVolume
     ///
@@ -736,6 +785,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::replace_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.replace_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1PersistentVolumeResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -744,6 +794,7 @@ pub enum ReplaceCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -767,10 +818,15 @@ impl ::Response for ReplaceCoreV1PersistentVolumeResponse {
             _ => Ok((ReplaceCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1PersistentVolumeStatus
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// replace status of the specified PersistentVolume
     ///
@@ -806,6 +862,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::replace_core_v1_persistent_volume_status`](./struct.PersistentVolume.html#method.replace_core_v1_persistent_volume_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1PersistentVolumeStatusResponse {
     Ok(::v1_11::api::core::v1::PersistentVolume),
@@ -814,6 +871,7 @@ pub enum ReplaceCoreV1PersistentVolumeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1PersistentVolumeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -837,10 +895,15 @@ impl ::Response for ReplaceCoreV1PersistentVolumeStatusResponse {
             _ => Ok((ReplaceCoreV1PersistentVolumeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1PersistentVolume
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// watch changes to an object of kind PersistentVolume
     ///
@@ -939,6 +1002,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::watch_core_v1_persistent_volume`](./struct.PersistentVolume.html#method.watch_core_v1_persistent_volume)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1PersistentVolumeResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -946,27 +1010,31 @@ pub enum WatchCoreV1PersistentVolumeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1PersistentVolumeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1PersistentVolumeResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1PersistentVolumeResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1PersistentVolumeResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1PersistentVolumeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1PersistentVolumeList
 
+#[cfg(feature = "api")]
 impl PersistentVolume {
     /// watch individual changes to a list of PersistentVolume
     ///
@@ -1060,6 +1128,7 @@ impl PersistentVolume {
 }
 
 /// Parses the HTTP response of [`PersistentVolume::watch_core_v1_persistent_volume_list`](./struct.PersistentVolume.html#method.watch_core_v1_persistent_volume_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1PersistentVolumeListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1067,23 +1136,26 @@ pub enum WatchCoreV1PersistentVolumeListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1PersistentVolumeListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1PersistentVolumeListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1PersistentVolumeListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1PersistentVolumeListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1PersistentVolumeListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/PersistentVolume
@@ -1207,3 +1279,25 @@ impl ::serde::Serialize for PersistentVolume {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PersistentVolume`](./struct.PersistentVolume.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PersistentVolumeField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for PersistentVolumeField {
+    fn path(&self) -> &'static str {
+        match self {
+            PersistentVolumeField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PersistentVolumeField::MetadataName),
+            _ => None,
+        }
+    }
+}
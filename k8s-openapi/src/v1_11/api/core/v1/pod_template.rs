@@ -20,6 +20,7 @@ pub struct PodTemplate {
 
 // Generated from operation createCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// create a PodTemplate
     ///
@@ -55,6 +56,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::create_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.create_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedPodTemplateResponse {
     Ok(::v1_11::api::core::v1::PodTemplate),
@@ -64,6 +66,7 @@ pub enum CreateCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateCoreV1NamespacedPodTemplateResponse {
             _ => Ok((CreateCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// delete collection of PodTemplate
     ///
@@ -197,6 +205,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::delete_core_v1_collection_namespaced_pod_template`](./struct.PodTemplate.html#method.delete_core_v1_collection_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedPodTemplateResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -205,38 +214,34 @@ pub enum DeleteCoreV1CollectionNamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedPodTemplateResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedPodTemplateResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedPodTemplateResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedPodTemplateResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedPodTemplateResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// delete a PodTemplate
     ///
@@ -300,6 +305,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::delete_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.delete_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedPodTemplateResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -308,38 +314,34 @@ pub enum DeleteCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedPodTemplateResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedPodTemplateResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedPodTemplateResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedPodTemplateResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedPodTemplateResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// list or watch objects of kind PodTemplate
     ///
@@ -438,6 +440,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::list_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.list_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedPodTemplateResponse {
     Ok(::v1_11::api::core::v1::PodTemplateList),
@@ -445,6 +448,7 @@ pub enum ListCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -460,10 +464,27 @@ impl ::Response for ListCoreV1NamespacedPodTemplateResponse {
             _ => Ok((ListCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedPodTemplateResponse {
+    type List = ::v1_11::api::core::v1::PodTemplateList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedPodTemplateResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1PodTemplateForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// list or watch objects of kind PodTemplate
     ///
@@ -557,6 +578,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::list_core_v1_pod_template_for_all_namespaces`](./struct.PodTemplate.html#method.list_core_v1_pod_template_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1PodTemplateForAllNamespacesResponse {
     Ok(::v1_11::api::core::v1::PodTemplateList),
@@ -564,6 +586,7 @@ pub enum ListCoreV1PodTemplateForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1PodTemplateForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -579,10 +602,27 @@ impl ::Response for ListCoreV1PodTemplateForAllNamespacesResponse {
             _ => Ok((ListCoreV1PodTemplateForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1PodTemplateForAllNamespacesResponse {
+    type List = ::v1_11::api::core::v1::PodTemplateList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1PodTemplateForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// partially update the specified PodTemplate
     ///
@@ -623,6 +663,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::patch_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.patch_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedPodTemplateResponse {
     Ok(::v1_11::api::core::v1::PodTemplate),
@@ -630,6 +671,7 @@ pub enum PatchCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -645,10 +687,15 @@ impl ::Response for PatchCoreV1NamespacedPodTemplateResponse {
             _ => Ok((PatchCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// read the specified PodTemplate
     ///
@@ -702,6 +749,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::read_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.read_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedPodTemplateResponse {
     Ok(::v1_11::api::core::v1::PodTemplate),
@@ -709,6 +757,7 @@ pub enum ReadCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -724,10 +773,15 @@ impl ::Response for ReadCoreV1NamespacedPodTemplateResponse {
             _ => Ok((ReadCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// replace the specified PodTemplate
     ///
@@ -768,6 +822,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::replace_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.replace_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedPodTemplateResponse {
     Ok(::v1_11::api::core::v1::PodTemplate),
@@ -776,6 +831,7 @@ pub enum ReplaceCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -799,10 +855,15 @@ impl ::Response for ReplaceCoreV1NamespacedPodTemplateResponse {
             _ => Ok((ReplaceCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedPodTemplate
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// watch changes to an object of kind PodTemplate
     ///
@@ -906,6 +967,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::watch_core_v1_namespaced_pod_template`](./struct.PodTemplate.html#method.watch_core_v1_namespaced_pod_template)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedPodTemplateResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -913,27 +975,31 @@ pub enum WatchCoreV1NamespacedPodTemplateResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedPodTemplateResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedPodTemplateResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedPodTemplateResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedPodTemplateResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedPodTemplateResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedPodTemplateList
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// watch individual changes to a list of PodTemplate
     ///
@@ -1032,6 +1098,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::watch_core_v1_namespaced_pod_template_list`](./struct.PodTemplate.html#method.watch_core_v1_namespaced_pod_template_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedPodTemplateListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1039,27 +1106,31 @@ pub enum WatchCoreV1NamespacedPodTemplateListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedPodTemplateListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedPodTemplateListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedPodTemplateListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedPodTemplateListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedPodTemplateListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1PodTemplateListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PodTemplate {
     /// watch individual changes to a list of PodTemplate
     ///
@@ -1153,6 +1224,7 @@ impl PodTemplate {
 }
 
 /// Parses the HTTP response of [`PodTemplate::watch_core_v1_pod_template_list_for_all_namespaces`](./struct.PodTemplate.html#method.watch_core_v1_pod_template_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1PodTemplateListForAllNamespacesResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1160,23 +1232,26 @@ pub enum WatchCoreV1PodTemplateListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1PodTemplateListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1PodTemplateListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1PodTemplateListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1PodTemplateListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1PodTemplateListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/PodTemplate
@@ -1290,3 +1365,30 @@ impl ::serde::Serialize for PodTemplate {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PodTemplate`](./struct.PodTemplate.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PodTemplateField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for PodTemplateField {
+    fn path(&self) -> &'static str {
+        match self {
+            PodTemplateField::MetadataName => "metadata.name",
+            PodTemplateField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PodTemplateField::MetadataName),
+            "metadata.namespace" => Some(PodTemplateField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for PodTemplateList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for PodTemplateList {
+    type Item = ::v1_11::api::core::v1::PodTemplate;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -58,6 +58,7 @@ pub struct Event {
 
 // Generated from operation createEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// create an Event
     ///
@@ -93,6 +94,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::create_events_v1beta1_namespaced_event`](./struct.Event.html#method.create_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateEventsV1beta1NamespacedEventResponse {
     Ok(::v1_11::api::events::v1beta1::Event),
@@ -102,6 +104,7 @@ pub enum CreateEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -133,10 +136,15 @@ impl ::Response for CreateEventsV1beta1NamespacedEventResponse {
             _ => Ok((CreateEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteEventsV1beta1CollectionNamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// delete collection of Event
     ///
@@ -235,6 +243,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::delete_events_v1beta1_collection_namespaced_event`](./struct.Event.html#method.delete_events_v1beta1_collection_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteEventsV1beta1CollectionNamespacedEventResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -243,38 +252,34 @@ pub enum DeleteEventsV1beta1CollectionNamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteEventsV1beta1CollectionNamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteEventsV1beta1CollectionNamespacedEventResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteEventsV1beta1CollectionNamespacedEventResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteEventsV1beta1CollectionNamespacedEventResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteEventsV1beta1CollectionNamespacedEventResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteEventsV1beta1CollectionNamespacedEventResponse::Unauthorized, 0)),
             _ => Ok((DeleteEventsV1beta1CollectionNamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// delete an Event
     ///
@@ -338,6 +343,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::delete_events_v1beta1_namespaced_event`](./struct.Event.html#method.delete_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteEventsV1beta1NamespacedEventResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -346,38 +352,34 @@ pub enum DeleteEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteEventsV1beta1NamespacedEventResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteEventsV1beta1NamespacedEventResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteEventsV1beta1NamespacedEventResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteEventsV1beta1NamespacedEventResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteEventsV1beta1NamespacedEventResponse::Unauthorized, 0)),
             _ => Ok((DeleteEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listEventsV1beta1EventForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Event {
     /// list or watch objects of kind Event
     ///
@@ -471,6 +473,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::list_events_v1beta1_event_for_all_namespaces`](./struct.Event.html#method.list_events_v1beta1_event_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListEventsV1beta1EventForAllNamespacesResponse {
     Ok(::v1_11::api::events::v1beta1::EventList),
@@ -478,6 +481,7 @@ pub enum ListEventsV1beta1EventForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListEventsV1beta1EventForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -493,10 +497,27 @@ impl ::Response for ListEventsV1beta1EventForAllNamespacesResponse {
             _ => Ok((ListEventsV1beta1EventForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListEventsV1beta1EventForAllNamespacesResponse {
+    type List = ::v1_11::api::events::v1beta1::EventList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListEventsV1beta1EventForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// list or watch objects of kind Event
     ///
@@ -595,6 +616,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::list_events_v1beta1_namespaced_event`](./struct.Event.html#method.list_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListEventsV1beta1NamespacedEventResponse {
     Ok(::v1_11::api::events::v1beta1::EventList),
@@ -602,6 +624,7 @@ pub enum ListEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -617,10 +640,27 @@ impl ::Response for ListEventsV1beta1NamespacedEventResponse {
             _ => Ok((ListEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListEventsV1beta1NamespacedEventResponse {
+    type List = ::v1_11::api::events::v1beta1::EventList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListEventsV1beta1NamespacedEventResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// partially update the specified Event
     ///
@@ -661,6 +701,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::patch_events_v1beta1_namespaced_event`](./struct.Event.html#method.patch_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchEventsV1beta1NamespacedEventResponse {
     Ok(::v1_11::api::events::v1beta1::Event),
@@ -668,6 +709,7 @@ pub enum PatchEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -683,10 +725,15 @@ impl ::Response for PatchEventsV1beta1NamespacedEventResponse {
             _ => Ok((PatchEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// read the specified Event
     ///
@@ -740,6 +787,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::read_events_v1beta1_namespaced_event`](./struct.Event.html#method.read_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadEventsV1beta1NamespacedEventResponse {
     Ok(::v1_11::api::events::v1beta1::Event),
@@ -747,6 +795,7 @@ pub enum ReadEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -762,10 +811,15 @@ impl ::Response for ReadEventsV1beta1NamespacedEventResponse {
             _ => Ok((ReadEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// replace the specified Event
     ///
@@ -806,6 +860,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::replace_events_v1beta1_namespaced_event`](./struct.Event.html#method.replace_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceEventsV1beta1NamespacedEventResponse {
     Ok(::v1_11::api::events::v1beta1::Event),
@@ -814,6 +869,7 @@ pub enum ReplaceEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -837,10 +893,15 @@ impl ::Response for ReplaceEventsV1beta1NamespacedEventResponse {
             _ => Ok((ReplaceEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchEventsV1beta1EventListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Event {
     /// watch individual changes to a list of Event
     ///
@@ -934,6 +995,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::watch_events_v1beta1_event_list_for_all_namespaces`](./struct.Event.html#method.watch_events_v1beta1_event_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchEventsV1beta1EventListForAllNamespacesResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -941,27 +1003,31 @@ pub enum WatchEventsV1beta1EventListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchEventsV1beta1EventListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchEventsV1beta1EventListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchEventsV1beta1EventListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchEventsV1beta1EventListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchEventsV1beta1EventListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchEventsV1beta1NamespacedEvent
 
+#[cfg(feature = "api")]
 impl Event {
     /// watch changes to an object of kind Event
     ///
@@ -1065,6 +1131,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::watch_events_v1beta1_namespaced_event`](./struct.Event.html#method.watch_events_v1beta1_namespaced_event)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchEventsV1beta1NamespacedEventResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1072,27 +1139,31 @@ pub enum WatchEventsV1beta1NamespacedEventResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchEventsV1beta1NamespacedEventResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchEventsV1beta1NamespacedEventResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchEventsV1beta1NamespacedEventResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchEventsV1beta1NamespacedEventResponse::Unauthorized, 0)),
             _ => Ok((WatchEventsV1beta1NamespacedEventResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchEventsV1beta1NamespacedEventList
 
+#[cfg(feature = "api")]
 impl Event {
     /// watch individual changes to a list of Event
     ///
@@ -1191,6 +1262,7 @@ impl Event {
 }
 
 /// Parses the HTTP response of [`Event::watch_events_v1beta1_namespaced_event_list`](./struct.Event.html#method.watch_events_v1beta1_namespaced_event_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchEventsV1beta1NamespacedEventListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1198,23 +1270,26 @@ pub enum WatchEventsV1beta1NamespacedEventListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchEventsV1beta1NamespacedEventListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchEventsV1beta1NamespacedEventListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchEventsV1beta1NamespacedEventListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchEventsV1beta1NamespacedEventListResponse::Unauthorized, 0)),
             _ => Ok((WatchEventsV1beta1NamespacedEventListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End events.k8s.io/v1beta1/Event
@@ -1456,3 +1531,41 @@ impl ::serde::Serialize for Event {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Event`](./struct.Event.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for EventField {
+    fn path(&self) -> &'static str {
+        match self {
+            EventField::MetadataName => "metadata.name",
+            EventField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(EventField::MetadataName),
+            "metadata.namespace" => Some(EventField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-core-v1")]
+impl ::std::convert::TryFrom<::v1_11::api::core::v1::Event> for Event {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_11::api::core::v1::Event) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "events.k8s.io/v1beta1".to_string());
+        Ok(result)
+    }
+}
@@ -101,3 +101,13 @@ impl ::serde::Serialize for AllowedHostPath {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-policy-v1beta1")]
+impl From<::v1_11::api::policy::v1beta1::AllowedHostPath> for AllowedHostPath {
+    fn from(value: ::v1_11::api::policy::v1beta1::AllowedHostPath) -> Self {
+        AllowedHostPath {
+            path_prefix: value.path_prefix,
+            read_only: value.read_only,
+        }
+    }
+}
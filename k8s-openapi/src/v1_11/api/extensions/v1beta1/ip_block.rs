@@ -97,3 +97,13 @@ impl ::serde::Serialize for IPBlock {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-networking-v1")]
+impl From<::v1_11::api::networking::v1::IPBlock> for IPBlock {
+    fn from(value: ::v1_11::api::networking::v1::IPBlock) -> Self {
+        IPBlock {
+            cidr: value.cidr,
+            except: value.except,
+        }
+    }
+}
@@ -116,3 +116,14 @@ impl ::serde::Serialize for NetworkPolicyPeer {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-networking-v1")]
+impl From<::v1_11::api::networking::v1::NetworkPolicyPeer> for NetworkPolicyPeer {
+    fn from(value: ::v1_11::api::networking::v1::NetworkPolicyPeer) -> Self {
+        NetworkPolicyPeer {
+            ip_block: value.ip_block.map(Into::into),
+            namespace_selector: value.namespace_selector,
+            pod_selector: value.pod_selector,
+        }
+    }
+}
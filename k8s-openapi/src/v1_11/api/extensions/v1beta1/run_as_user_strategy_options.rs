@@ -97,3 +97,13 @@ impl ::serde::Serialize for RunAsUserStrategyOptions {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-policy-v1beta1")]
+impl From<::v1_11::api::policy::v1beta1::RunAsUserStrategyOptions> for RunAsUserStrategyOptions {
+    fn from(value: ::v1_11::api::policy::v1beta1::RunAsUserStrategyOptions) -> Self {
+        RunAsUserStrategyOptions {
+            ranges: value.ranges.map(|value| value.into_iter().map(Into::into).collect()),
+            rule: value.rule,
+        }
+    }
+}
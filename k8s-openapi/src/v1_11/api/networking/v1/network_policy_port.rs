@@ -99,3 +99,13 @@ impl ::serde::Serialize for NetworkPolicyPort {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::NetworkPolicyPort> for NetworkPolicyPort {
+    fn from(value: ::v1_11::api::extensions::v1beta1::NetworkPolicyPort) -> Self {
+        NetworkPolicyPort {
+            port: value.port,
+            protocol: value.protocol,
+        }
+    }
+}
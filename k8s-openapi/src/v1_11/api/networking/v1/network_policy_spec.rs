@@ -123,3 +123,15 @@ impl ::serde::Serialize for NetworkPolicySpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::NetworkPolicySpec> for NetworkPolicySpec {
+    fn from(value: ::v1_11::api::extensions::v1beta1::NetworkPolicySpec) -> Self {
+        NetworkPolicySpec {
+            egress: value.egress.map(|value| value.into_iter().map(Into::into).collect()),
+            ingress: value.ingress.map(|value| value.into_iter().map(Into::into).collect()),
+            pod_selector: value.pod_selector,
+            policy_types: value.policy_types,
+        }
+    }
+}
@@ -84,3 +84,12 @@ impl ::serde::Serialize for AllowedFlexVolume {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::AllowedFlexVolume> for AllowedFlexVolume {
+    fn from(value: ::v1_11::api::extensions::v1beta1::AllowedFlexVolume) -> Self {
+        AllowedFlexVolume {
+            driver: value.driver,
+        }
+    }
+}
@@ -95,3 +95,13 @@ impl ::serde::Serialize for HostPortRange {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-extensions-v1beta1")]
+impl From<::v1_11::api::extensions::v1beta1::HostPortRange> for HostPortRange {
+    fn from(value: ::v1_11::api::extensions::v1beta1::HostPortRange) -> Self {
+        HostPortRange {
+            max: value.max,
+            min: value.min,
+        }
+    }
+}
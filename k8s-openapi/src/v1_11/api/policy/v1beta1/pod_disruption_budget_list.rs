@@ -121,3 +121,15 @@ impl ::serde::Serialize for PodDisruptionBudgetList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for PodDisruptionBudgetList {
+    type Item = ::v1_11::api::policy::v1beta1::PodDisruptionBudget;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -23,6 +23,7 @@ pub struct ClusterRole {
 
 // Generated from operation createRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// create a ClusterRole
     ///
@@ -53,6 +54,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::create_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.create_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateRbacAuthorizationV1ClusterRoleResponse {
     Ok(::v1_11::api::rbac::v1::ClusterRole),
@@ -62,6 +64,7 @@ pub enum CreateRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -93,10 +96,15 @@ impl ::Response for CreateRbacAuthorizationV1ClusterRoleResponse {
             _ => Ok((CreateRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// delete a ClusterRole
     ///
@@ -155,6 +163,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::delete_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.delete_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1ClusterRoleResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -163,38 +172,34 @@ pub enum DeleteRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1ClusterRoleResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1ClusterRoleResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1ClusterRoleResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1ClusterRoleResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1ClusterRoleResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteRbacAuthorizationV1CollectionClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// delete collection of ClusterRole
     ///
@@ -288,6 +293,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::delete_rbac_authorization_v1_collection_cluster_role`](./struct.ClusterRole.html#method.delete_rbac_authorization_v1_collection_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteRbacAuthorizationV1CollectionClusterRoleResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -296,38 +302,34 @@ pub enum DeleteRbacAuthorizationV1CollectionClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteRbacAuthorizationV1CollectionClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1CollectionClusterRoleResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteRbacAuthorizationV1CollectionClusterRoleResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteRbacAuthorizationV1CollectionClusterRoleResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteRbacAuthorizationV1CollectionClusterRoleResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteRbacAuthorizationV1CollectionClusterRoleResponse::Unauthorized, 0)),
             _ => Ok((DeleteRbacAuthorizationV1CollectionClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// list or watch objects of kind ClusterRole
     ///
@@ -421,6 +423,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::list_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.list_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListRbacAuthorizationV1ClusterRoleResponse {
     Ok(::v1_11::api::rbac::v1::ClusterRoleList),
@@ -428,6 +431,7 @@ pub enum ListRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -443,10 +447,27 @@ impl ::Response for ListRbacAuthorizationV1ClusterRoleResponse {
             _ => Ok((ListRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListRbacAuthorizationV1ClusterRoleResponse {
+    type List = ::v1_11::api::rbac::v1::ClusterRoleList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListRbacAuthorizationV1ClusterRoleResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// partially update the specified ClusterRole
     ///
@@ -482,6 +503,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::patch_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.patch_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchRbacAuthorizationV1ClusterRoleResponse {
     Ok(::v1_11::api::rbac::v1::ClusterRole),
@@ -489,6 +511,7 @@ pub enum PatchRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -504,10 +527,15 @@ impl ::Response for PatchRbacAuthorizationV1ClusterRoleResponse {
             _ => Ok((PatchRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// read the specified ClusterRole
     ///
@@ -540,6 +568,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::read_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.read_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadRbacAuthorizationV1ClusterRoleResponse {
     Ok(::v1_11::api::rbac::v1::ClusterRole),
@@ -547,6 +576,7 @@ pub enum ReadRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -562,10 +592,15 @@ impl ::Response for ReadRbacAuthorizationV1ClusterRoleResponse {
             _ => Ok((ReadRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// replace the specified ClusterRole
     ///
@@ -601,6 +636,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::replace_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.replace_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceRbacAuthorizationV1ClusterRoleResponse {
     Ok(::v1_11::api::rbac::v1::ClusterRole),
@@ -609,6 +645,7 @@ pub enum ReplaceRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -632,10 +669,15 @@ impl ::Response for ReplaceRbacAuthorizationV1ClusterRoleResponse {
             _ => Ok((ReplaceRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1ClusterRole
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// watch changes to an object of kind ClusterRole
     ///
@@ -734,6 +776,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::watch_rbac_authorization_v1_cluster_role`](./struct.ClusterRole.html#method.watch_rbac_authorization_v1_cluster_role)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1ClusterRoleResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -741,27 +784,31 @@ pub enum WatchRbacAuthorizationV1ClusterRoleResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1ClusterRoleResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1ClusterRoleResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1ClusterRoleResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1ClusterRoleResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1ClusterRoleResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchRbacAuthorizationV1ClusterRoleList
 
+#[cfg(feature = "api")]
 impl ClusterRole {
     /// watch individual changes to a list of ClusterRole
     ///
@@ -855,6 +902,7 @@ impl ClusterRole {
 }
 
 /// Parses the HTTP response of [`ClusterRole::watch_rbac_authorization_v1_cluster_role_list`](./struct.ClusterRole.html#method.watch_rbac_authorization_v1_cluster_role_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchRbacAuthorizationV1ClusterRoleListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -862,23 +910,26 @@ pub enum WatchRbacAuthorizationV1ClusterRoleListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchRbacAuthorizationV1ClusterRoleListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchRbacAuthorizationV1ClusterRoleListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchRbacAuthorizationV1ClusterRoleListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchRbacAuthorizationV1ClusterRoleListResponse::Unauthorized, 0)),
             _ => Ok((WatchRbacAuthorizationV1ClusterRoleListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End rbac.authorization.k8s.io/v1/ClusterRole
@@ -1000,3 +1051,51 @@ impl ::serde::Serialize for ClusterRole {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ClusterRole`](./struct.ClusterRole.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClusterRoleField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for ClusterRoleField {
+    fn path(&self) -> &'static str {
+        match self {
+            ClusterRoleField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ClusterRoleField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-rbac-v1alpha1")]
+impl From<::v1_11::api::rbac::v1alpha1::ClusterRole> for ClusterRole {
+    fn from(value: ::v1_11::api::rbac::v1alpha1::ClusterRole) -> Self {
+        ClusterRole {
+            aggregation_rule: value.aggregation_rule.map(Into::into),
+            api_version: value.api_version.map(|_| "rbac.authorization.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            rules: value.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-rbac-v1beta1")]
+impl From<::v1_11::api::rbac::v1beta1::ClusterRole> for ClusterRole {
+    fn from(value: ::v1_11::api::rbac::v1beta1::ClusterRole) -> Self {
+        ClusterRole {
+            aggregation_rule: value.aggregation_rule.map(Into::into),
+            api_version: value.api_version.map(|_| "rbac.authorization.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            rules: value.rules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for RoleList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for RoleList {
+    type Item = ::v1_11::api::rbac::v1::Role;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
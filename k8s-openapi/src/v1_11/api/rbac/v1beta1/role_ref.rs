@@ -106,3 +106,14 @@ impl ::serde::Serialize for RoleRef {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-rbac-v1")]
+impl From<::v1_11::api::rbac::v1::RoleRef> for RoleRef {
+    fn from(value: ::v1_11::api::rbac::v1::RoleRef) -> Self {
+        RoleRef {
+            api_group: value.api_group,
+            kind: value.kind,
+            name: value.name,
+        }
+    }
+}
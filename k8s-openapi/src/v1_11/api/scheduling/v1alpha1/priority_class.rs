@@ -26,6 +26,7 @@ pub struct PriorityClass {
 
 // Generated from operation createSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// create a PriorityClass
     ///
@@ -56,6 +57,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::create_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.create_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateSchedulingV1alpha1PriorityClassResponse {
     Ok(::v1_11::api::scheduling::v1alpha1::PriorityClass),
@@ -65,6 +67,7 @@ pub enum CreateSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -96,10 +99,15 @@ impl ::Response for CreateSchedulingV1alpha1PriorityClassResponse {
             _ => Ok((CreateSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteSchedulingV1alpha1CollectionPriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// delete collection of PriorityClass
     ///
@@ -193,6 +201,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::delete_scheduling_v1alpha1_collection_priority_class`](./struct.PriorityClass.html#method.delete_scheduling_v1alpha1_collection_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteSchedulingV1alpha1CollectionPriorityClassResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -201,38 +210,34 @@ pub enum DeleteSchedulingV1alpha1CollectionPriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteSchedulingV1alpha1CollectionPriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1alpha1CollectionPriorityClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1alpha1CollectionPriorityClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteSchedulingV1alpha1CollectionPriorityClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteSchedulingV1alpha1CollectionPriorityClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteSchedulingV1alpha1CollectionPriorityClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteSchedulingV1alpha1CollectionPriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// delete a PriorityClass
     ///
@@ -291,6 +296,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::delete_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.delete_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteSchedulingV1alpha1PriorityClassResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -299,38 +305,34 @@ pub enum DeleteSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1alpha1PriorityClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1alpha1PriorityClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteSchedulingV1alpha1PriorityClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteSchedulingV1alpha1PriorityClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteSchedulingV1alpha1PriorityClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// list or watch objects of kind PriorityClass
     ///
@@ -424,6 +426,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::list_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.list_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListSchedulingV1alpha1PriorityClassResponse {
     Ok(::v1_11::api::scheduling::v1alpha1::PriorityClassList),
@@ -431,6 +434,7 @@ pub enum ListSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -446,10 +450,27 @@ impl ::Response for ListSchedulingV1alpha1PriorityClassResponse {
             _ => Ok((ListSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListSchedulingV1alpha1PriorityClassResponse {
+    type List = ::v1_11::api::scheduling::v1alpha1::PriorityClassList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListSchedulingV1alpha1PriorityClassResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// partially update the specified PriorityClass
     ///
@@ -485,6 +506,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::patch_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.patch_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchSchedulingV1alpha1PriorityClassResponse {
     Ok(::v1_11::api::scheduling::v1alpha1::PriorityClass),
@@ -492,6 +514,7 @@ pub enum PatchSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -507,10 +530,15 @@ impl ::Response for PatchSchedulingV1alpha1PriorityClassResponse {
             _ => Ok((PatchSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// read the specified PriorityClass
     ///
@@ -559,6 +587,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::read_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.read_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadSchedulingV1alpha1PriorityClassResponse {
     Ok(::v1_11::api::scheduling::v1alpha1::PriorityClass),
@@ -566,6 +595,7 @@ pub enum ReadSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -581,10 +611,15 @@ impl ::Response for ReadSchedulingV1alpha1PriorityClassResponse {
             _ => Ok((ReadSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// replace the specified PriorityClass
     ///
@@ -620,6 +655,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::replace_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.replace_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceSchedulingV1alpha1PriorityClassResponse {
     Ok(::v1_11::api::scheduling::v1alpha1::PriorityClass),
@@ -628,6 +664,7 @@ pub enum ReplaceSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -651,10 +688,15 @@ impl ::Response for ReplaceSchedulingV1alpha1PriorityClassResponse {
             _ => Ok((ReplaceSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchSchedulingV1alpha1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// watch changes to an object of kind PriorityClass
     ///
@@ -753,6 +795,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::watch_scheduling_v1alpha1_priority_class`](./struct.PriorityClass.html#method.watch_scheduling_v1alpha1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSchedulingV1alpha1PriorityClassResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -760,27 +803,31 @@ pub enum WatchSchedulingV1alpha1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSchedulingV1alpha1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSchedulingV1alpha1PriorityClassResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSchedulingV1alpha1PriorityClassResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSchedulingV1alpha1PriorityClassResponse::Unauthorized, 0)),
             _ => Ok((WatchSchedulingV1alpha1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchSchedulingV1alpha1PriorityClassList
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// watch individual changes to a list of PriorityClass
     ///
@@ -874,6 +921,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::watch_scheduling_v1alpha1_priority_class_list`](./struct.PriorityClass.html#method.watch_scheduling_v1alpha1_priority_class_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSchedulingV1alpha1PriorityClassListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -881,23 +929,26 @@ pub enum WatchSchedulingV1alpha1PriorityClassListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSchedulingV1alpha1PriorityClassListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSchedulingV1alpha1PriorityClassListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSchedulingV1alpha1PriorityClassListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSchedulingV1alpha1PriorityClassListResponse::Unauthorized, 0)),
             _ => Ok((WatchSchedulingV1alpha1PriorityClassListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End scheduling.k8s.io/v1alpha1/PriorityClass
@@ -1029,3 +1080,39 @@ impl ::serde::Serialize for PriorityClass {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PriorityClass`](./struct.PriorityClass.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PriorityClassField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for PriorityClassField {
+    fn path(&self) -> &'static str {
+        match self {
+            PriorityClassField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PriorityClassField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-scheduling-v1beta1")]
+impl From<::v1_11::api::scheduling::v1beta1::PriorityClass> for PriorityClass {
+    fn from(value: ::v1_11::api::scheduling::v1beta1::PriorityClass) -> Self {
+        PriorityClass {
+            api_version: value.api_version.map(|_| "scheduling.k8s.io/v1alpha1".to_string()),
+            description: value.description,
+            global_default: value.global_default,
+            kind: value.kind,
+            metadata: value.metadata,
+            value: value.value,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for PriorityClassList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for PriorityClassList {
+    type Item = ::v1_11::api::scheduling::v1alpha1::PriorityClass;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
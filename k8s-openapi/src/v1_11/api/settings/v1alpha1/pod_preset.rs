@@ -18,6 +18,7 @@ pub struct PodPreset {
 
 // Generated from operation createSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// create a PodPreset
     ///
@@ -53,6 +54,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::create_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.create_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateSettingsV1alpha1NamespacedPodPresetResponse {
     Ok(::v1_11::api::settings::v1alpha1::PodPreset),
@@ -62,6 +64,7 @@ pub enum CreateSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -93,10 +96,15 @@ impl ::Response for CreateSettingsV1alpha1NamespacedPodPresetResponse {
             _ => Ok((CreateSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteSettingsV1alpha1CollectionNamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// delete collection of PodPreset
     ///
@@ -195,6 +203,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::delete_settings_v1alpha1_collection_namespaced_pod_preset`](./struct.PodPreset.html#method.delete_settings_v1alpha1_collection_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -203,38 +212,34 @@ pub enum DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse::Unauthorized, 0)),
             _ => Ok((DeleteSettingsV1alpha1CollectionNamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// delete a PodPreset
     ///
@@ -298,6 +303,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::delete_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.delete_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteSettingsV1alpha1NamespacedPodPresetResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -306,38 +312,34 @@ pub enum DeleteSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSettingsV1alpha1NamespacedPodPresetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSettingsV1alpha1NamespacedPodPresetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteSettingsV1alpha1NamespacedPodPresetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteSettingsV1alpha1NamespacedPodPresetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteSettingsV1alpha1NamespacedPodPresetResponse::Unauthorized, 0)),
             _ => Ok((DeleteSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// list or watch objects of kind PodPreset
     ///
@@ -436,6 +438,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::list_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.list_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListSettingsV1alpha1NamespacedPodPresetResponse {
     Ok(::v1_11::api::settings::v1alpha1::PodPresetList),
@@ -443,6 +446,7 @@ pub enum ListSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -458,10 +462,27 @@ impl ::Response for ListSettingsV1alpha1NamespacedPodPresetResponse {
             _ => Ok((ListSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListSettingsV1alpha1NamespacedPodPresetResponse {
+    type List = ::v1_11::api::settings::v1alpha1::PodPresetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListSettingsV1alpha1NamespacedPodPresetResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listSettingsV1alpha1PodPresetForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// list or watch objects of kind PodPreset
     ///
@@ -555,6 +576,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::list_settings_v1alpha1_pod_preset_for_all_namespaces`](./struct.PodPreset.html#method.list_settings_v1alpha1_pod_preset_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListSettingsV1alpha1PodPresetForAllNamespacesResponse {
     Ok(::v1_11::api::settings::v1alpha1::PodPresetList),
@@ -562,6 +584,7 @@ pub enum ListSettingsV1alpha1PodPresetForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListSettingsV1alpha1PodPresetForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -577,10 +600,27 @@ impl ::Response for ListSettingsV1alpha1PodPresetForAllNamespacesResponse {
             _ => Ok((ListSettingsV1alpha1PodPresetForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListSettingsV1alpha1PodPresetForAllNamespacesResponse {
+    type List = ::v1_11::api::settings::v1alpha1::PodPresetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListSettingsV1alpha1PodPresetForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// partially update the specified PodPreset
     ///
@@ -621,6 +661,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::patch_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.patch_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchSettingsV1alpha1NamespacedPodPresetResponse {
     Ok(::v1_11::api::settings::v1alpha1::PodPreset),
@@ -628,6 +669,7 @@ pub enum PatchSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -643,10 +685,15 @@ impl ::Response for PatchSettingsV1alpha1NamespacedPodPresetResponse {
             _ => Ok((PatchSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// read the specified PodPreset
     ///
@@ -700,6 +747,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::read_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.read_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadSettingsV1alpha1NamespacedPodPresetResponse {
     Ok(::v1_11::api::settings::v1alpha1::PodPreset),
@@ -707,6 +755,7 @@ pub enum ReadSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -722,10 +771,15 @@ impl ::Response for ReadSettingsV1alpha1NamespacedPodPresetResponse {
             _ => Ok((ReadSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// replace the specified PodPreset
     ///
@@ -766,6 +820,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::replace_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.replace_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceSettingsV1alpha1NamespacedPodPresetResponse {
     Ok(::v1_11::api::settings::v1alpha1::PodPreset),
@@ -774,6 +829,7 @@ pub enum ReplaceSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -797,10 +853,15 @@ impl ::Response for ReplaceSettingsV1alpha1NamespacedPodPresetResponse {
             _ => Ok((ReplaceSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchSettingsV1alpha1NamespacedPodPreset
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// watch changes to an object of kind PodPreset
     ///
@@ -904,6 +965,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::watch_settings_v1alpha1_namespaced_pod_preset`](./struct.PodPreset.html#method.watch_settings_v1alpha1_namespaced_pod_preset)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSettingsV1alpha1NamespacedPodPresetResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -911,27 +973,31 @@ pub enum WatchSettingsV1alpha1NamespacedPodPresetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSettingsV1alpha1NamespacedPodPresetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSettingsV1alpha1NamespacedPodPresetResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSettingsV1alpha1NamespacedPodPresetResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSettingsV1alpha1NamespacedPodPresetResponse::Unauthorized, 0)),
             _ => Ok((WatchSettingsV1alpha1NamespacedPodPresetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchSettingsV1alpha1NamespacedPodPresetList
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// watch individual changes to a list of PodPreset
     ///
@@ -1030,6 +1096,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::watch_settings_v1alpha1_namespaced_pod_preset_list`](./struct.PodPreset.html#method.watch_settings_v1alpha1_namespaced_pod_preset_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSettingsV1alpha1NamespacedPodPresetListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1037,27 +1104,31 @@ pub enum WatchSettingsV1alpha1NamespacedPodPresetListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSettingsV1alpha1NamespacedPodPresetListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSettingsV1alpha1NamespacedPodPresetListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSettingsV1alpha1NamespacedPodPresetListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSettingsV1alpha1NamespacedPodPresetListResponse::Unauthorized, 0)),
             _ => Ok((WatchSettingsV1alpha1NamespacedPodPresetListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchSettingsV1alpha1PodPresetListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PodPreset {
     /// watch individual changes to a list of PodPreset
     ///
@@ -1151,6 +1222,7 @@ impl PodPreset {
 }
 
 /// Parses the HTTP response of [`PodPreset::watch_settings_v1alpha1_pod_preset_list_for_all_namespaces`](./struct.PodPreset.html#method.watch_settings_v1alpha1_pod_preset_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1158,23 +1230,26 @@ pub enum WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchSettingsV1alpha1PodPresetListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End settings.k8s.io/v1alpha1/PodPreset
@@ -1288,3 +1363,30 @@ impl ::serde::Serialize for PodPreset {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PodPreset`](./struct.PodPreset.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PodPresetField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for PodPresetField {
+    fn path(&self) -> &'static str {
+        match self {
+            PodPresetField::MetadataName => "metadata.name",
+            PodPresetField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PodPresetField::MetadataName),
+            "metadata.namespace" => Some(PodPresetField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "v1_11-storage-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_11-storage-v1alpha1")]
 pub mod v1alpha1;
 
+#[cfg(feature = "v1_11-storage-v1beta1")]
 pub mod v1beta1;
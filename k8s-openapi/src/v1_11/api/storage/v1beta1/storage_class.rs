@@ -40,6 +40,7 @@ pub struct StorageClass {
 
 // Generated from operation createStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// create a StorageClass
     ///
@@ -70,6 +71,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::create_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.create_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateStorageV1beta1StorageClassResponse {
     Ok(::v1_11::api::storage::v1beta1::StorageClass),
@@ -79,6 +81,7 @@ pub enum CreateStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -110,10 +113,15 @@ impl ::Response for CreateStorageV1beta1StorageClassResponse {
             _ => Ok((CreateStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteStorageV1beta1CollectionStorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// delete collection of StorageClass
     ///
@@ -207,6 +215,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::delete_storage_v1beta1_collection_storage_class`](./struct.StorageClass.html#method.delete_storage_v1beta1_collection_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteStorageV1beta1CollectionStorageClassResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -215,38 +224,34 @@ pub enum DeleteStorageV1beta1CollectionStorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteStorageV1beta1CollectionStorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1CollectionStorageClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1CollectionStorageClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteStorageV1beta1CollectionStorageClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteStorageV1beta1CollectionStorageClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteStorageV1beta1CollectionStorageClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteStorageV1beta1CollectionStorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// delete a StorageClass
     ///
@@ -305,6 +310,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::delete_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.delete_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteStorageV1beta1StorageClassResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -313,38 +319,34 @@ pub enum DeleteStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1StorageClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1StorageClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteStorageV1beta1StorageClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteStorageV1beta1StorageClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteStorageV1beta1StorageClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// list or watch objects of kind StorageClass
     ///
@@ -438,6 +440,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::list_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.list_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListStorageV1beta1StorageClassResponse {
     Ok(::v1_11::api::storage::v1beta1::StorageClassList),
@@ -445,6 +448,7 @@ pub enum ListStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -460,10 +464,27 @@ impl ::Response for ListStorageV1beta1StorageClassResponse {
             _ => Ok((ListStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListStorageV1beta1StorageClassResponse {
+    type List = ::v1_11::api::storage::v1beta1::StorageClassList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListStorageV1beta1StorageClassResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// partially update the specified StorageClass
     ///
@@ -499,6 +520,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::patch_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.patch_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchStorageV1beta1StorageClassResponse {
     Ok(::v1_11::api::storage::v1beta1::StorageClass),
@@ -506,6 +528,7 @@ pub enum PatchStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -521,10 +544,15 @@ impl ::Response for PatchStorageV1beta1StorageClassResponse {
             _ => Ok((PatchStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// read the specified StorageClass
     ///
@@ -573,6 +601,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::read_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.read_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadStorageV1beta1StorageClassResponse {
     Ok(::v1_11::api::storage::v1beta1::StorageClass),
@@ -580,6 +609,7 @@ pub enum ReadStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -595,10 +625,15 @@ impl ::Response for ReadStorageV1beta1StorageClassResponse {
             _ => Ok((ReadStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// replace the specified StorageClass
     ///
@@ -634,6 +669,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::replace_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.replace_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceStorageV1beta1StorageClassResponse {
     Ok(::v1_11::api::storage::v1beta1::StorageClass),
@@ -642,6 +678,7 @@ pub enum ReplaceStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -665,10 +702,15 @@ impl ::Response for ReplaceStorageV1beta1StorageClassResponse {
             _ => Ok((ReplaceStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchStorageV1beta1StorageClass
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// watch changes to an object of kind StorageClass
     ///
@@ -767,6 +809,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::watch_storage_v1beta1_storage_class`](./struct.StorageClass.html#method.watch_storage_v1beta1_storage_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchStorageV1beta1StorageClassResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -774,27 +817,31 @@ pub enum WatchStorageV1beta1StorageClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchStorageV1beta1StorageClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchStorageV1beta1StorageClassResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchStorageV1beta1StorageClassResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchStorageV1beta1StorageClassResponse::Unauthorized, 0)),
             _ => Ok((WatchStorageV1beta1StorageClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchStorageV1beta1StorageClassList
 
+#[cfg(feature = "api")]
 impl StorageClass {
     /// watch individual changes to a list of StorageClass
     ///
@@ -888,6 +935,7 @@ impl StorageClass {
 }
 
 /// Parses the HTTP response of [`StorageClass::watch_storage_v1beta1_storage_class_list`](./struct.StorageClass.html#method.watch_storage_v1beta1_storage_class_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchStorageV1beta1StorageClassListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -895,23 +943,26 @@ pub enum WatchStorageV1beta1StorageClassListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchStorageV1beta1StorageClassListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchStorageV1beta1StorageClassListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchStorageV1beta1StorageClassListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchStorageV1beta1StorageClassListResponse::Unauthorized, 0)),
             _ => Ok((WatchStorageV1beta1StorageClassListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End storage.k8s.io/v1beta1/StorageClass
@@ -1083,3 +1134,43 @@ impl ::serde::Serialize for StorageClass {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`StorageClass`](./struct.StorageClass.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageClassField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for StorageClassField {
+    fn path(&self) -> &'static str {
+        match self {
+            StorageClassField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(StorageClassField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-storage-v1")]
+impl From<::v1_11::api::storage::v1::StorageClass> for StorageClass {
+    fn from(value: ::v1_11::api::storage::v1::StorageClass) -> Self {
+        StorageClass {
+            allow_volume_expansion: value.allow_volume_expansion,
+            allowed_topologies: value.allowed_topologies,
+            api_version: value.api_version.map(|_| "storage.k8s.io/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            mount_options: value.mount_options,
+            parameters: value.parameters,
+            provisioner: value.provisioner,
+            reclaim_policy: value.reclaim_policy,
+            volume_binding_mode: value.volume_binding_mode,
+        }
+    }
+}
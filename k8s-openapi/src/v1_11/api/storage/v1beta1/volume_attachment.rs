@@ -25,6 +25,7 @@ pub struct VolumeAttachment {
 
 // Generated from operation createStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// create a VolumeAttachment
     ///
@@ -55,6 +56,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::create_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.create_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateStorageV1beta1VolumeAttachmentResponse {
     Ok(::v1_11::api::storage::v1beta1::VolumeAttachment),
@@ -64,6 +66,7 @@ pub enum CreateStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateStorageV1beta1VolumeAttachmentResponse {
             _ => Ok((CreateStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteStorageV1beta1CollectionVolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// delete collection of VolumeAttachment
     ///
@@ -192,6 +200,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::delete_storage_v1beta1_collection_volume_attachment`](./struct.VolumeAttachment.html#method.delete_storage_v1beta1_collection_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteStorageV1beta1CollectionVolumeAttachmentResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -200,38 +209,34 @@ pub enum DeleteStorageV1beta1CollectionVolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteStorageV1beta1CollectionVolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1CollectionVolumeAttachmentResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1CollectionVolumeAttachmentResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteStorageV1beta1CollectionVolumeAttachmentResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteStorageV1beta1CollectionVolumeAttachmentResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteStorageV1beta1CollectionVolumeAttachmentResponse::Unauthorized, 0)),
             _ => Ok((DeleteStorageV1beta1CollectionVolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// delete a VolumeAttachment
     ///
@@ -290,6 +295,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::delete_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.delete_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteStorageV1beta1VolumeAttachmentResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -298,38 +304,34 @@ pub enum DeleteStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1VolumeAttachmentResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteStorageV1beta1VolumeAttachmentResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteStorageV1beta1VolumeAttachmentResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteStorageV1beta1VolumeAttachmentResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteStorageV1beta1VolumeAttachmentResponse::Unauthorized, 0)),
             _ => Ok((DeleteStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// list or watch objects of kind VolumeAttachment
     ///
@@ -423,6 +425,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::list_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.list_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListStorageV1beta1VolumeAttachmentResponse {
     Ok(::v1_11::api::storage::v1beta1::VolumeAttachmentList),
@@ -430,6 +433,7 @@ pub enum ListStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -445,10 +449,27 @@ impl ::Response for ListStorageV1beta1VolumeAttachmentResponse {
             _ => Ok((ListStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListStorageV1beta1VolumeAttachmentResponse {
+    type List = ::v1_11::api::storage::v1beta1::VolumeAttachmentList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListStorageV1beta1VolumeAttachmentResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// partially update the specified VolumeAttachment
     ///
@@ -484,6 +505,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::patch_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.patch_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchStorageV1beta1VolumeAttachmentResponse {
     Ok(::v1_11::api::storage::v1beta1::VolumeAttachment),
@@ -491,6 +513,7 @@ pub enum PatchStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -506,10 +529,15 @@ impl ::Response for PatchStorageV1beta1VolumeAttachmentResponse {
             _ => Ok((PatchStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// read the specified VolumeAttachment
     ///
@@ -558,6 +586,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::read_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.read_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadStorageV1beta1VolumeAttachmentResponse {
     Ok(::v1_11::api::storage::v1beta1::VolumeAttachment),
@@ -565,6 +594,7 @@ pub enum ReadStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -580,10 +610,15 @@ impl ::Response for ReadStorageV1beta1VolumeAttachmentResponse {
             _ => Ok((ReadStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// replace the specified VolumeAttachment
     ///
@@ -619,6 +654,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::replace_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.replace_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceStorageV1beta1VolumeAttachmentResponse {
     Ok(::v1_11::api::storage::v1beta1::VolumeAttachment),
@@ -627,6 +663,7 @@ pub enum ReplaceStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -650,10 +687,15 @@ impl ::Response for ReplaceStorageV1beta1VolumeAttachmentResponse {
             _ => Ok((ReplaceStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchStorageV1beta1VolumeAttachment
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// watch changes to an object of kind VolumeAttachment
     ///
@@ -752,6 +794,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::watch_storage_v1beta1_volume_attachment`](./struct.VolumeAttachment.html#method.watch_storage_v1beta1_volume_attachment)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchStorageV1beta1VolumeAttachmentResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -759,27 +802,31 @@ pub enum WatchStorageV1beta1VolumeAttachmentResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchStorageV1beta1VolumeAttachmentResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchStorageV1beta1VolumeAttachmentResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchStorageV1beta1VolumeAttachmentResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchStorageV1beta1VolumeAttachmentResponse::Unauthorized, 0)),
             _ => Ok((WatchStorageV1beta1VolumeAttachmentResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchStorageV1beta1VolumeAttachmentList
 
+#[cfg(feature = "api")]
 impl VolumeAttachment {
     /// watch individual changes to a list of VolumeAttachment
     ///
@@ -873,6 +920,7 @@ impl VolumeAttachment {
 }
 
 /// Parses the HTTP response of [`VolumeAttachment::watch_storage_v1beta1_volume_attachment_list`](./struct.VolumeAttachment.html#method.watch_storage_v1beta1_volume_attachment_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchStorageV1beta1VolumeAttachmentListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -880,23 +928,26 @@ pub enum WatchStorageV1beta1VolumeAttachmentListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchStorageV1beta1VolumeAttachmentListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchStorageV1beta1VolumeAttachmentListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchStorageV1beta1VolumeAttachmentListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchStorageV1beta1VolumeAttachmentListResponse::Unauthorized, 0)),
             _ => Ok((WatchStorageV1beta1VolumeAttachmentListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End storage.k8s.io/v1beta1/VolumeAttachment
@@ -1018,3 +1069,38 @@ impl ::serde::Serialize for VolumeAttachment {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`VolumeAttachment`](./struct.VolumeAttachment.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VolumeAttachmentField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for VolumeAttachmentField {
+    fn path(&self) -> &'static str {
+        match self {
+            VolumeAttachmentField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(VolumeAttachmentField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_11-storage-v1alpha1")]
+impl From<::v1_11::api::storage::v1alpha1::VolumeAttachment> for VolumeAttachment {
+    fn from(value: ::v1_11::api::storage::v1alpha1::VolumeAttachment) -> Self {
+        VolumeAttachment {
+            api_version: value.api_version.map(|_| "storage.k8s.io/v1beta1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.into(),
+            status: value.status.map(Into::into),
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for VolumeAttachmentStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-storage-v1alpha1")]
+impl From<::v1_11::api::storage::v1alpha1::VolumeAttachmentStatus> for VolumeAttachmentStatus {
+    fn from(value: ::v1_11::api::storage::v1alpha1::VolumeAttachmentStatus) -> Self {
+        VolumeAttachmentStatus {
+            attach_error: value.attach_error.map(Into::into),
+            attached: value.attached,
+            attachment_metadata: value.attachment_metadata,
+            detach_error: value.detach_error.map(Into::into),
+        }
+    }
+}
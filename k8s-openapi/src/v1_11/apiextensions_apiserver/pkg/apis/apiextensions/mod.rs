@@ -1 +1,2 @@
+#[cfg(feature = "v1_11-apiextensions-v1beta1")]
 pub mod v1beta1;
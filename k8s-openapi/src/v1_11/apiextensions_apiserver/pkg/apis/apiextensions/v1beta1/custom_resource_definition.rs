@@ -22,6 +22,7 @@ pub struct CustomResourceDefinition {
 
 // Generated from operation createApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// create a CustomResourceDefinition
     ///
@@ -52,6 +53,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::create_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.create_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateApiextensionsV1beta1CustomResourceDefinitionResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -61,6 +63,7 @@ pub enum CreateApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,10 +95,15 @@ impl ::Response for CreateApiextensionsV1beta1CustomResourceDefinitionResponse {
             _ => Ok((CreateApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteApiextensionsV1beta1CollectionCustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// delete collection of CustomResourceDefinition
     ///
@@ -189,6 +197,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::delete_apiextensions_v1beta1_collection_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.delete_apiextensions_v1beta1_collection_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -197,38 +206,34 @@ pub enum DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse::Unauthorized, 0)),
             _ => Ok((DeleteApiextensionsV1beta1CollectionCustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// delete a CustomResourceDefinition
     ///
@@ -287,6 +292,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::delete_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.delete_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteApiextensionsV1beta1CustomResourceDefinitionResponse {
     OkStatus(::v1_11::apimachinery::pkg::apis::meta::v1::Status),
@@ -295,38 +301,34 @@ pub enum DeleteApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiextensionsV1beta1CustomResourceDefinitionResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteApiextensionsV1beta1CustomResourceDefinitionResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteApiextensionsV1beta1CustomResourceDefinitionResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteApiextensionsV1beta1CustomResourceDefinitionResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteApiextensionsV1beta1CustomResourceDefinitionResponse::Unauthorized, 0)),
             _ => Ok((DeleteApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// list or watch objects of kind CustomResourceDefinition
     ///
@@ -420,6 +422,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::list_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.list_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListApiextensionsV1beta1CustomResourceDefinitionResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionList),
@@ -427,6 +430,7 @@ pub enum ListApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -442,10 +446,27 @@ impl ::Response for ListApiextensionsV1beta1CustomResourceDefinitionResponse {
             _ => Ok((ListApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListApiextensionsV1beta1CustomResourceDefinitionResponse {
+    type List = ::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinitionList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListApiextensionsV1beta1CustomResourceDefinitionResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// partially update the specified CustomResourceDefinition
     ///
@@ -481,6 +502,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::patch_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.patch_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchApiextensionsV1beta1CustomResourceDefinitionResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -488,6 +510,7 @@ pub enum PatchApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -503,10 +526,15 @@ impl ::Response for PatchApiextensionsV1beta1CustomResourceDefinitionResponse {
             _ => Ok((PatchApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchApiextensionsV1beta1CustomResourceDefinitionStatus
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// partially update status of the specified CustomResourceDefinition
     ///
@@ -542,6 +570,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::patch_apiextensions_v1beta1_custom_resource_definition_status`](./struct.CustomResourceDefinition.html#method.patch_apiextensions_v1beta1_custom_resource_definition_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -549,6 +578,7 @@ pub enum PatchApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -564,10 +594,15 @@ impl ::Response for PatchApiextensionsV1beta1CustomResourceDefinitionStatusRespo
             _ => Ok((PatchApiextensionsV1beta1CustomResourceDefinitionStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// read the specified CustomResourceDefinition
     ///
@@ -616,6 +651,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::read_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.read_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadApiextensionsV1beta1CustomResourceDefinitionResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -623,6 +659,7 @@ pub enum ReadApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -638,10 +675,15 @@ impl ::Response for ReadApiextensionsV1beta1CustomResourceDefinitionResponse {
             _ => Ok((ReadApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readApiextensionsV1beta1CustomResourceDefinitionStatus
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// read status of the specified CustomResourceDefinition
     ///
@@ -674,6 +716,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::read_apiextensions_v1beta1_custom_resource_definition_status`](./struct.CustomResourceDefinition.html#method.read_apiextensions_v1beta1_custom_resource_definition_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -681,6 +724,7 @@ pub enum ReadApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -696,10 +740,15 @@ impl ::Response for ReadApiextensionsV1beta1CustomResourceDefinitionStatusRespon
             _ => Ok((ReadApiextensionsV1beta1CustomResourceDefinitionStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// replace the specified CustomResourceDefinition
     ///
@@ -735,6 +784,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::replace_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.replace_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceApiextensionsV1beta1CustomResourceDefinitionResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -743,6 +793,7 @@ pub enum ReplaceApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -766,10 +817,15 @@ impl ::Response for ReplaceApiextensionsV1beta1CustomResourceDefinitionResponse
             _ => Ok((ReplaceApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceApiextensionsV1beta1CustomResourceDefinitionStatus
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// replace status of the specified CustomResourceDefinition
     ///
@@ -805,6 +861,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::replace_apiextensions_v1beta1_custom_resource_definition_status`](./struct.CustomResourceDefinition.html#method.replace_apiextensions_v1beta1_custom_resource_definition_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     Ok(::v1_11::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
@@ -813,6 +870,7 @@ pub enum ReplaceApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceApiextensionsV1beta1CustomResourceDefinitionStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -836,10 +894,15 @@ impl ::Response for ReplaceApiextensionsV1beta1CustomResourceDefinitionStatusRes
             _ => Ok((ReplaceApiextensionsV1beta1CustomResourceDefinitionStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchApiextensionsV1beta1CustomResourceDefinition
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// watch changes to an object of kind CustomResourceDefinition
     ///
@@ -938,6 +1001,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::watch_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.watch_apiextensions_v1beta1_custom_resource_definition)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchApiextensionsV1beta1CustomResourceDefinitionResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -945,27 +1009,31 @@ pub enum WatchApiextensionsV1beta1CustomResourceDefinitionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchApiextensionsV1beta1CustomResourceDefinitionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchApiextensionsV1beta1CustomResourceDefinitionResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchApiextensionsV1beta1CustomResourceDefinitionResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchApiextensionsV1beta1CustomResourceDefinitionResponse::Unauthorized, 0)),
             _ => Ok((WatchApiextensionsV1beta1CustomResourceDefinitionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchApiextensionsV1beta1CustomResourceDefinitionList
 
+#[cfg(feature = "api")]
 impl CustomResourceDefinition {
     /// watch individual changes to a list of CustomResourceDefinition
     ///
@@ -1059,6 +1127,7 @@ impl CustomResourceDefinition {
 }
 
 /// Parses the HTTP response of [`CustomResourceDefinition::watch_apiextensions_v1beta1_custom_resource_definition_list`](./struct.CustomResourceDefinition.html#method.watch_apiextensions_v1beta1_custom_resource_definition_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchApiextensionsV1beta1CustomResourceDefinitionListResponse {
     Ok(::v1_11::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1066,23 +1135,26 @@ pub enum WatchApiextensionsV1beta1CustomResourceDefinitionListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchApiextensionsV1beta1CustomResourceDefinitionListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchApiextensionsV1beta1CustomResourceDefinitionListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchApiextensionsV1beta1CustomResourceDefinitionListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchApiextensionsV1beta1CustomResourceDefinitionListResponse::Unauthorized, 0)),
             _ => Ok((WatchApiextensionsV1beta1CustomResourceDefinitionListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End apiextensions.k8s.io/v1beta1/CustomResourceDefinition
@@ -1206,3 +1278,25 @@ impl ::serde::Serialize for CustomResourceDefinition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`CustomResourceDefinition`](./struct.CustomResourceDefinition.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CustomResourceDefinitionField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for CustomResourceDefinitionField {
+    fn path(&self) -> &'static str {
+        match self {
+            CustomResourceDefinitionField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(CustomResourceDefinitionField::MetadataName),
+            _ => None,
+        }
+    }
+}
@@ -99,3 +99,53 @@ impl ::serde::Serialize for LabelSelector {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl LabelSelector {
+    /// Converts this label selector into a `::label_selector::Selector`.
+    ///
+    /// Returns an error if one of the match expressions has an unknown operator, or has values that don't match its operator.
+    pub fn to_selector(&self) -> Result<::label_selector::Selector, ::label_selector::Error> {
+        let mut requirements = vec![];
+
+        if let Some(match_labels) = &self.match_labels {
+            for (key, value) in match_labels {
+                requirements.push(::label_selector::Requirement { key: key.clone(), operator: ::label_selector::Operator::Equals(value.clone()) });
+            }
+        }
+
+        if let Some(match_expressions) = &self.match_expressions {
+            for match_expression in match_expressions {
+                let values = match_expression.values.as_ref().map_or(&[][..], |values| &values[..]);
+                let operator = ::label_selector::Operator::from_parts(&match_expression.operator, values)?;
+                requirements.push(::label_selector::Requirement { key: match_expression.key.clone(), operator });
+            }
+        }
+
+        Ok(::label_selector::Selector(requirements))
+    }
+}
+
+impl From<::label_selector::Selector> for LabelSelector {
+    fn from(selector: ::label_selector::Selector) -> Self {
+        let mut match_labels: ::std::collections::BTreeMap<_, _> = Default::default();
+        let mut match_expressions = vec![];
+
+        for ::label_selector::Requirement { key, operator } in selector.0 {
+            match operator {
+                ::label_selector::Operator::Equals(ref value) if !match_labels.contains_key(&key) => {
+                    match_labels.insert(key, value.clone());
+                },
+
+                operator => {
+                    let (operator, values) = operator.into_parts();
+                    match_expressions.push(::v1_11::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement { key, operator: operator.to_string(), values });
+                },
+            }
+        }
+
+        LabelSelector {
+            match_expressions: if match_expressions.is_empty() { None } else { Some(match_expressions) },
+            match_labels: if match_labels.is_empty() { None } else { Some(match_labels) },
+        }
+    }
+}
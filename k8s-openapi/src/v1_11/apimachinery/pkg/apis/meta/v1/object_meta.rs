@@ -297,3 +297,9 @@ impl ::serde::Serialize for ObjectMeta {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::label_selector::Labels for ObjectMeta {
+    fn labels(&self) -> Option<&::std::collections::BTreeMap<String, String>> {
+        self.labels.as_ref()
+    }
+}
@@ -133,3 +133,16 @@ impl ::serde::Serialize for APIServiceCondition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apiregistration-v1")]
+impl From<::v1_11::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition> for APIServiceCondition {
+    fn from(value: ::v1_11::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition) -> Self {
+        APIServiceCondition {
+            last_transition_time: value.last_transition_time,
+            message: value.message,
+            reason: value.reason,
+            status: value.status,
+            type_: value.type_,
+        }
+    }
+}
@@ -86,3 +86,12 @@ impl ::serde::Serialize for APIServiceStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_11-apiregistration-v1")]
+impl From<::v1_11::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus> for APIServiceStatus {
+    fn from(value: ::v1_11::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus) -> Self {
+        APIServiceStatus {
+            conditions: value.conditions.map(|value| value.into_iter().map(Into::into).collect()),
+        }
+    }
+}
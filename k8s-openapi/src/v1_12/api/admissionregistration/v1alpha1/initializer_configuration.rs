@@ -20,6 +20,7 @@ pub struct InitializerConfiguration {
 
 // Generated from operation createAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// create an InitializerConfiguration
     ///
@@ -50,6 +51,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::create_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.create_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Ok(::v1_12::api::admissionregistration::v1alpha1::InitializerConfiguration),
@@ -59,6 +61,7 @@ pub enum CreateAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -90,10 +93,15 @@ impl ::Response for CreateAdmissionregistrationV1alpha1InitializerConfigurationR
             _ => Ok((CreateAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAdmissionregistrationV1alpha1CollectionInitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// delete collection of InitializerConfiguration
     ///
@@ -189,6 +197,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::delete_admissionregistration_v1alpha1_collection_initializer_configuration`](./struct.InitializerConfiguration.html#method.delete_admissionregistration_v1alpha1_collection_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -197,38 +206,34 @@ pub enum DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationRe
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse::Unauthorized, 0)),
             _ => Ok((DeleteAdmissionregistrationV1alpha1CollectionInitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// delete an InitializerConfiguration
     ///
@@ -295,6 +300,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::delete_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.delete_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -304,28 +310,19 @@ pub enum DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::ACCEPTED => {
@@ -340,10 +337,15 @@ impl ::Response for DeleteAdmissionregistrationV1alpha1InitializerConfigurationR
             _ => Ok((DeleteAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// list or watch objects of kind InitializerConfiguration
     ///
@@ -439,6 +441,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::list_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.list_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Ok(::v1_12::api::admissionregistration::v1alpha1::InitializerConfigurationList),
@@ -446,6 +449,7 @@ pub enum ListAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -461,10 +465,27 @@ impl ::Response for ListAdmissionregistrationV1alpha1InitializerConfigurationRes
             _ => Ok((ListAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListAdmissionregistrationV1alpha1InitializerConfigurationResponse {
+    type List = ::v1_12::api::admissionregistration::v1alpha1::InitializerConfigurationList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListAdmissionregistrationV1alpha1InitializerConfigurationResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// partially update the specified InitializerConfiguration
     ///
@@ -500,6 +521,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::patch_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.patch_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Ok(::v1_12::api::admissionregistration::v1alpha1::InitializerConfiguration),
@@ -507,6 +529,7 @@ pub enum PatchAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -522,10 +545,15 @@ impl ::Response for PatchAdmissionregistrationV1alpha1InitializerConfigurationRe
             _ => Ok((PatchAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// read the specified InitializerConfiguration
     ///
@@ -574,6 +602,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::read_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.read_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Ok(::v1_12::api::admissionregistration::v1alpha1::InitializerConfiguration),
@@ -581,6 +610,7 @@ pub enum ReadAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -596,10 +626,15 @@ impl ::Response for ReadAdmissionregistrationV1alpha1InitializerConfigurationRes
             _ => Ok((ReadAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// replace the specified InitializerConfiguration
     ///
@@ -635,6 +670,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::replace_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.replace_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Ok(::v1_12::api::admissionregistration::v1alpha1::InitializerConfiguration),
@@ -643,6 +679,7 @@ pub enum ReplaceAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -666,10 +703,15 @@ impl ::Response for ReplaceAdmissionregistrationV1alpha1InitializerConfiguration
             _ => Ok((ReplaceAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchAdmissionregistrationV1alpha1InitializerConfiguration
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// watch changes to an object of kind InitializerConfiguration. deprecated: use the 'watch' parameter with a list operation instead, filtered to a single item with the 'fieldSelector' parameter.
     ///
@@ -770,6 +812,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::watch_admissionregistration_v1alpha1_initializer_configuration`](./struct.InitializerConfiguration.html#method.watch_admissionregistration_v1alpha1_initializer_configuration)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -777,27 +820,31 @@ pub enum WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse::Unauthorized, 0)),
             _ => Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchAdmissionregistrationV1alpha1InitializerConfigurationList
 
+#[cfg(feature = "api")]
 impl InitializerConfiguration {
     /// watch individual changes to a list of InitializerConfiguration. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -893,6 +940,7 @@ impl InitializerConfiguration {
 }
 
 /// Parses the HTTP response of [`InitializerConfiguration::watch_admissionregistration_v1alpha1_initializer_configuration_list`](./struct.InitializerConfiguration.html#method.watch_admissionregistration_v1alpha1_initializer_configuration_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -900,23 +948,26 @@ pub enum WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse::Unauthorized, 0)),
             _ => Ok((WatchAdmissionregistrationV1alpha1InitializerConfigurationListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End admissionregistration.k8s.io/v1alpha1/InitializerConfiguration
@@ -1030,3 +1081,25 @@ impl ::serde::Serialize for InitializerConfiguration {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`InitializerConfiguration`](./struct.InitializerConfiguration.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InitializerConfigurationField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for InitializerConfigurationField {
+    fn path(&self) -> &'static str {
+        match self {
+            InitializerConfigurationField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(InitializerConfigurationField::MetadataName),
+            _ => None,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for ValidatingWebhookConfigurationList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for ValidatingWebhookConfigurationList {
+    type Item = ::v1_12::api::admissionregistration::v1beta1::ValidatingWebhookConfiguration;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
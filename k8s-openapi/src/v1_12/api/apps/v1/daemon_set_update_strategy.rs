@@ -99,3 +99,13 @@ impl ::serde::Serialize for DaemonSetUpdateStrategy {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-apps-v1beta2")]
+impl From<::v1_12::api::apps::v1beta2::DaemonSetUpdateStrategy> for DaemonSetUpdateStrategy {
+    fn from(value: ::v1_12::api::apps::v1beta2::DaemonSetUpdateStrategy) -> Self {
+        DaemonSetUpdateStrategy {
+            rolling_update: value.rolling_update.map(Into::into),
+            type_: value.type_,
+        }
+    }
+}
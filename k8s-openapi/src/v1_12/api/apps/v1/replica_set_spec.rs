@@ -123,3 +123,15 @@ impl ::serde::Serialize for ReplicaSetSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-apps-v1beta2")]
+impl From<::v1_12::api::apps::v1beta2::ReplicaSetSpec> for ReplicaSetSpec {
+    fn from(value: ::v1_12::api::apps::v1beta2::ReplicaSetSpec) -> Self {
+        ReplicaSetSpec {
+            min_ready_seconds: value.min_ready_seconds,
+            replicas: value.replicas,
+            selector: value.selector,
+            template: value.template,
+        }
+    }
+}
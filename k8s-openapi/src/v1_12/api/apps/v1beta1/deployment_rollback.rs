@@ -23,6 +23,7 @@ pub struct DeploymentRollback {
 
 // Generated from operation createAppsV1beta1NamespacedDeploymentRollback
 
+#[cfg(feature = "api")]
 impl DeploymentRollback {
     /// create rollback of a Deployment
     ///
@@ -63,6 +64,7 @@ impl DeploymentRollback {
 }
 
 /// Parses the HTTP response of [`DeploymentRollback::create_apps_v1beta1_namespaced_deployment_rollback`](./struct.DeploymentRollback.html#method.create_apps_v1beta1_namespaced_deployment_rollback)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAppsV1beta1NamespacedDeploymentRollbackResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -72,6 +74,7 @@ pub enum CreateAppsV1beta1NamespacedDeploymentRollbackResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAppsV1beta1NamespacedDeploymentRollbackResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -103,6 +106,10 @@ impl ::Response for CreateAppsV1beta1NamespacedDeploymentRollbackResponse {
             _ => Ok((CreateAppsV1beta1NamespacedDeploymentRollbackResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End apps/v1beta1/DeploymentRollback
@@ -222,3 +229,16 @@ impl ::serde::Serialize for DeploymentRollback {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-extensions-v1beta1")]
+impl From<::v1_12::api::extensions::v1beta1::DeploymentRollback> for DeploymentRollback {
+    fn from(value: ::v1_12::api::extensions::v1beta1::DeploymentRollback) -> Self {
+        DeploymentRollback {
+            api_version: value.api_version.map(|_| "apps/v1beta1".to_string()),
+            kind: value.kind,
+            name: value.name,
+            rollback_to: value.rollback_to.into(),
+            updated_annotations: value.updated_annotations,
+        }
+    }
+}
@@ -86,3 +86,12 @@ impl ::serde::Serialize for TokenReviewSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authentication-v1beta1")]
+impl From<::v1_12::api::authentication::v1beta1::TokenReviewSpec> for TokenReviewSpec {
+    fn from(value: ::v1_12::api::authentication::v1beta1::TokenReviewSpec) -> Self {
+        TokenReviewSpec {
+            token: value.token,
+        }
+    }
+}
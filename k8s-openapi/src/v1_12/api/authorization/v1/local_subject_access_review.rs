@@ -22,6 +22,7 @@ pub struct LocalSubjectAccessReview {
 
 // Generated from operation createAuthorizationV1NamespacedLocalSubjectAccessReview
 
+#[cfg(feature = "api")]
 impl LocalSubjectAccessReview {
     /// create a LocalSubjectAccessReview
     ///
@@ -57,6 +58,7 @@ impl LocalSubjectAccessReview {
 }
 
 /// Parses the HTTP response of [`LocalSubjectAccessReview::create_authorization_v1_namespaced_local_subject_access_review`](./struct.LocalSubjectAccessReview.html#method.create_authorization_v1_namespaced_local_subject_access_review)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAuthorizationV1NamespacedLocalSubjectAccessReviewResponse {
     Ok(::v1_12::api::authorization::v1::LocalSubjectAccessReview),
@@ -66,6 +68,7 @@ pub enum CreateAuthorizationV1NamespacedLocalSubjectAccessReviewResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAuthorizationV1NamespacedLocalSubjectAccessReviewResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -97,6 +100,10 @@ impl ::Response for CreateAuthorizationV1NamespacedLocalSubjectAccessReviewRespo
             _ => Ok((CreateAuthorizationV1NamespacedLocalSubjectAccessReviewResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End authorization.k8s.io/v1/LocalSubjectAccessReview
@@ -218,3 +225,14 @@ impl ::serde::Serialize for LocalSubjectAccessReview {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1beta1")]
+impl ::std::convert::TryFrom<::v1_12::api::authorization::v1beta1::LocalSubjectAccessReview> for LocalSubjectAccessReview {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_12::api::authorization::v1beta1::LocalSubjectAccessReview) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "authorization.k8s.io/v1".to_string());
+        Ok(result)
+    }
+}
@@ -99,3 +99,13 @@ impl ::serde::Serialize for NonResourceAttributes {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1beta1")]
+impl From<::v1_12::api::authorization::v1beta1::NonResourceAttributes> for NonResourceAttributes {
+    fn from(value: ::v1_12::api::authorization::v1beta1::NonResourceAttributes) -> Self {
+        NonResourceAttributes {
+            path: value.path,
+            verb: value.verb,
+        }
+    }
+}
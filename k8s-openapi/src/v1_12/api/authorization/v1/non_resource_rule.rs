@@ -97,3 +97,13 @@ impl ::serde::Serialize for NonResourceRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1beta1")]
+impl From<::v1_12::api::authorization::v1beta1::NonResourceRule> for NonResourceRule {
+    fn from(value: ::v1_12::api::authorization::v1beta1::NonResourceRule) -> Self {
+        NonResourceRule {
+            non_resource_urls: value.non_resource_urls,
+            verbs: value.verbs,
+        }
+    }
+}
@@ -99,3 +99,13 @@ impl ::serde::Serialize for SelfSubjectAccessReviewSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1beta1")]
+impl From<::v1_12::api::authorization::v1beta1::SelfSubjectAccessReviewSpec> for SelfSubjectAccessReviewSpec {
+    fn from(value: ::v1_12::api::authorization::v1beta1::SelfSubjectAccessReviewSpec) -> Self {
+        SelfSubjectAccessReviewSpec {
+            non_resource_attributes: value.non_resource_attributes.map(Into::into),
+            resource_attributes: value.resource_attributes.map(Into::into),
+        }
+    }
+}
@@ -22,6 +22,7 @@ pub struct SelfSubjectRulesReview {
 
 // Generated from operation createAuthorizationV1SelfSubjectRulesReview
 
+#[cfg(feature = "api")]
 impl SelfSubjectRulesReview {
     /// create a SelfSubjectRulesReview
     ///
@@ -52,6 +53,7 @@ impl SelfSubjectRulesReview {
 }
 
 /// Parses the HTTP response of [`SelfSubjectRulesReview::create_authorization_v1_self_subject_rules_review`](./struct.SelfSubjectRulesReview.html#method.create_authorization_v1_self_subject_rules_review)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAuthorizationV1SelfSubjectRulesReviewResponse {
     Ok(::v1_12::api::authorization::v1::SelfSubjectRulesReview),
@@ -61,6 +63,7 @@ pub enum CreateAuthorizationV1SelfSubjectRulesReviewResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAuthorizationV1SelfSubjectRulesReviewResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,6 +95,10 @@ impl ::Response for CreateAuthorizationV1SelfSubjectRulesReviewResponse {
             _ => Ok((CreateAuthorizationV1SelfSubjectRulesReviewResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End authorization.k8s.io/v1/SelfSubjectRulesReview
@@ -213,3 +220,16 @@ impl ::serde::Serialize for SelfSubjectRulesReview {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1beta1")]
+impl From<::v1_12::api::authorization::v1beta1::SelfSubjectRulesReview> for SelfSubjectRulesReview {
+    fn from(value: ::v1_12::api::authorization::v1beta1::SelfSubjectRulesReview) -> Self {
+        SelfSubjectRulesReview {
+            api_version: value.api_version.map(|_| "authorization.k8s.io/v1".to_string()),
+            kind: value.kind,
+            metadata: value.metadata,
+            spec: value.spec.into(),
+            status: value.status.map(Into::into),
+        }
+    }
+}
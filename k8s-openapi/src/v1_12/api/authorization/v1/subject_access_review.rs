@@ -22,6 +22,7 @@ pub struct SubjectAccessReview {
 
 // Generated from operation createAuthorizationV1SubjectAccessReview
 
+#[cfg(feature = "api")]
 impl SubjectAccessReview {
     /// create a SubjectAccessReview
     ///
@@ -52,6 +53,7 @@ impl SubjectAccessReview {
 }
 
 /// Parses the HTTP response of [`SubjectAccessReview::create_authorization_v1_subject_access_review`](./struct.SubjectAccessReview.html#method.create_authorization_v1_subject_access_review)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateAuthorizationV1SubjectAccessReviewResponse {
     Ok(::v1_12::api::authorization::v1::SubjectAccessReview),
@@ -61,6 +63,7 @@ pub enum CreateAuthorizationV1SubjectAccessReviewResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateAuthorizationV1SubjectAccessReviewResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -92,6 +95,10 @@ impl ::Response for CreateAuthorizationV1SubjectAccessReviewResponse {
             _ => Ok((CreateAuthorizationV1SubjectAccessReviewResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End authorization.k8s.io/v1/SubjectAccessReview
@@ -213,3 +220,14 @@ impl ::serde::Serialize for SubjectAccessReview {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1beta1")]
+impl ::std::convert::TryFrom<::v1_12::api::authorization::v1beta1::SubjectAccessReview> for SubjectAccessReview {
+    type Error = ::conversion::Error;
+
+    fn try_from(value: ::v1_12::api::authorization::v1beta1::SubjectAccessReview) -> Result<Self, Self::Error> {
+        let mut result: Self = ::conversion::convert(value)?;
+        result.api_version = result.api_version.map(|_| "authorization.k8s.io/v1".to_string());
+        Ok(result)
+    }
+}
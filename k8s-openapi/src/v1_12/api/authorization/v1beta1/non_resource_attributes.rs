@@ -99,3 +99,13 @@ impl ::serde::Serialize for NonResourceAttributes {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-authorization-v1")]
+impl From<::v1_12::api::authorization::v1::NonResourceAttributes> for NonResourceAttributes {
+    fn from(value: ::v1_12::api::authorization::v1::NonResourceAttributes) -> Self {
+        NonResourceAttributes {
+            path: value.path,
+            verb: value.verb,
+        }
+    }
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "v1_12-autoscaling-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_12-autoscaling-v2beta1")]
 pub mod v2beta1;
 
+#[cfg(feature = "v1_12-autoscaling-v2beta2")]
 pub mod v2beta2;
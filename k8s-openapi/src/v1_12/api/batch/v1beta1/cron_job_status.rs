@@ -99,3 +99,13 @@ impl ::serde::Serialize for CronJobStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-batch-v2alpha1")]
+impl From<::v1_12::api::batch::v2alpha1::CronJobStatus> for CronJobStatus {
+    fn from(value: ::v1_12::api::batch::v2alpha1::CronJobStatus) -> Self {
+        CronJobStatus {
+            active: value.active,
+            last_schedule_time: value.last_schedule_time,
+        }
+    }
+}
@@ -1 +1,2 @@
+#[cfg(feature = "v1_12-coordination-v1beta1")]
 pub mod v1beta1;
@@ -20,6 +20,7 @@ pub struct Lease {
 
 // Generated from operation createCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// create a Lease
     ///
@@ -55,6 +56,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::create_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.create_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoordinationV1beta1NamespacedLeaseResponse {
     Ok(::v1_12::api::coordination::v1beta1::Lease),
@@ -64,6 +66,7 @@ pub enum CreateCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -95,10 +98,15 @@ impl ::Response for CreateCoordinationV1beta1NamespacedLeaseResponse {
             _ => Ok((CreateCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoordinationV1beta1CollectionNamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// delete collection of Lease
     ///
@@ -199,6 +207,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::delete_coordination_v1beta1_collection_namespaced_lease`](./struct.Lease.html#method.delete_coordination_v1beta1_collection_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -207,38 +216,34 @@ pub enum DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoordinationV1beta1CollectionNamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// delete a Lease
     ///
@@ -310,6 +315,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::delete_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.delete_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoordinationV1beta1NamespacedLeaseResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -319,28 +325,19 @@ pub enum DeleteCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoordinationV1beta1NamespacedLeaseResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoordinationV1beta1NamespacedLeaseResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoordinationV1beta1NamespacedLeaseResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoordinationV1beta1NamespacedLeaseResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::ACCEPTED => {
@@ -355,10 +352,15 @@ impl ::Response for DeleteCoordinationV1beta1NamespacedLeaseResponse {
             _ => Ok((DeleteCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoordinationV1beta1LeaseForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Lease {
     /// list or watch objects of kind Lease
     ///
@@ -454,6 +456,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::list_coordination_v1beta1_lease_for_all_namespaces`](./struct.Lease.html#method.list_coordination_v1beta1_lease_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoordinationV1beta1LeaseForAllNamespacesResponse {
     Ok(::v1_12::api::coordination::v1beta1::LeaseList),
@@ -461,6 +464,7 @@ pub enum ListCoordinationV1beta1LeaseForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoordinationV1beta1LeaseForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -476,10 +480,27 @@ impl ::Response for ListCoordinationV1beta1LeaseForAllNamespacesResponse {
             _ => Ok((ListCoordinationV1beta1LeaseForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoordinationV1beta1LeaseForAllNamespacesResponse {
+    type List = ::v1_12::api::coordination::v1beta1::LeaseList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoordinationV1beta1LeaseForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// list or watch objects of kind Lease
     ///
@@ -580,6 +601,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::list_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.list_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoordinationV1beta1NamespacedLeaseResponse {
     Ok(::v1_12::api::coordination::v1beta1::LeaseList),
@@ -587,6 +609,7 @@ pub enum ListCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -602,10 +625,27 @@ impl ::Response for ListCoordinationV1beta1NamespacedLeaseResponse {
             _ => Ok((ListCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoordinationV1beta1NamespacedLeaseResponse {
+    type List = ::v1_12::api::coordination::v1beta1::LeaseList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoordinationV1beta1NamespacedLeaseResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// partially update the specified Lease
     ///
@@ -646,6 +686,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::patch_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.patch_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoordinationV1beta1NamespacedLeaseResponse {
     Ok(::v1_12::api::coordination::v1beta1::Lease),
@@ -653,6 +694,7 @@ pub enum PatchCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -668,10 +710,15 @@ impl ::Response for PatchCoordinationV1beta1NamespacedLeaseResponse {
             _ => Ok((PatchCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// read the specified Lease
     ///
@@ -725,6 +772,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::read_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.read_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoordinationV1beta1NamespacedLeaseResponse {
     Ok(::v1_12::api::coordination::v1beta1::Lease),
@@ -732,6 +780,7 @@ pub enum ReadCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -747,10 +796,15 @@ impl ::Response for ReadCoordinationV1beta1NamespacedLeaseResponse {
             _ => Ok((ReadCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// replace the specified Lease
     ///
@@ -791,6 +845,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::replace_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.replace_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoordinationV1beta1NamespacedLeaseResponse {
     Ok(::v1_12::api::coordination::v1beta1::Lease),
@@ -799,6 +854,7 @@ pub enum ReplaceCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -822,10 +878,15 @@ impl ::Response for ReplaceCoordinationV1beta1NamespacedLeaseResponse {
             _ => Ok((ReplaceCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoordinationV1beta1LeaseListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Lease {
     /// watch individual changes to a list of Lease. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -921,6 +982,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::watch_coordination_v1beta1_lease_list_for_all_namespaces`](./struct.Lease.html#method.watch_coordination_v1beta1_lease_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoordinationV1beta1LeaseListForAllNamespacesResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -928,27 +990,31 @@ pub enum WatchCoordinationV1beta1LeaseListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoordinationV1beta1LeaseListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoordinationV1beta1LeaseListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoordinationV1beta1LeaseListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoordinationV1beta1LeaseListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoordinationV1beta1LeaseListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoordinationV1beta1NamespacedLease
 
+#[cfg(feature = "api")]
 impl Lease {
     /// watch changes to an object of kind Lease. deprecated: use the 'watch' parameter with a list operation instead, filtered to a single item with the 'fieldSelector' parameter.
     ///
@@ -1054,6 +1120,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::watch_coordination_v1beta1_namespaced_lease`](./struct.Lease.html#method.watch_coordination_v1beta1_namespaced_lease)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoordinationV1beta1NamespacedLeaseResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1061,27 +1128,31 @@ pub enum WatchCoordinationV1beta1NamespacedLeaseResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoordinationV1beta1NamespacedLeaseResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoordinationV1beta1NamespacedLeaseResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoordinationV1beta1NamespacedLeaseResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoordinationV1beta1NamespacedLeaseResponse::Unauthorized, 0)),
             _ => Ok((WatchCoordinationV1beta1NamespacedLeaseResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoordinationV1beta1NamespacedLeaseList
 
+#[cfg(feature = "api")]
 impl Lease {
     /// watch individual changes to a list of Lease. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -1182,6 +1253,7 @@ impl Lease {
 }
 
 /// Parses the HTTP response of [`Lease::watch_coordination_v1beta1_namespaced_lease_list`](./struct.Lease.html#method.watch_coordination_v1beta1_namespaced_lease_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoordinationV1beta1NamespacedLeaseListResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1189,23 +1261,26 @@ pub enum WatchCoordinationV1beta1NamespacedLeaseListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoordinationV1beta1NamespacedLeaseListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoordinationV1beta1NamespacedLeaseListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoordinationV1beta1NamespacedLeaseListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoordinationV1beta1NamespacedLeaseListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoordinationV1beta1NamespacedLeaseListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End coordination.k8s.io/v1beta1/Lease
@@ -1319,3 +1394,30 @@ impl ::serde::Serialize for Lease {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Lease`](./struct.Lease.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LeaseField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for LeaseField {
+    fn path(&self) -> &'static str {
+        match self {
+            LeaseField::MetadataName => "metadata.name",
+            LeaseField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(LeaseField::MetadataName),
+            "metadata.namespace" => Some(LeaseField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub struct Node {
 
 // Generated from operation connectCoreV1DeleteNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect DELETE requests to proxy of Node
     ///
@@ -55,6 +56,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_delete_node_proxy`](./struct.Node.html#method.connect_core_v1_delete_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNodeProxyResponse {
     Ok(String),
@@ -62,6 +64,7 @@ pub enum ConnectCoreV1DeleteNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -86,6 +89,7 @@ impl ::Response for ConnectCoreV1DeleteNodeProxyResponse {
 
 // Generated from operation connectCoreV1DeleteNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect DELETE requests to proxy of Node
     ///
@@ -123,6 +127,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_delete_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_delete_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNodeProxyWithPathResponse {
     Ok(String),
@@ -130,6 +135,7 @@ pub enum ConnectCoreV1DeleteNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -154,6 +160,7 @@ impl ::Response for ConnectCoreV1DeleteNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1GetNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect GET requests to proxy of Node
     ///
@@ -186,6 +193,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_get_node_proxy`](./struct.Node.html#method.connect_core_v1_get_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNodeProxyResponse {
     Ok(String),
@@ -193,6 +201,7 @@ pub enum ConnectCoreV1GetNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -217,6 +226,7 @@ impl ::Response for ConnectCoreV1GetNodeProxyResponse {
 
 // Generated from operation connectCoreV1GetNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect GET requests to proxy of Node
     ///
@@ -254,6 +264,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_get_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_get_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNodeProxyWithPathResponse {
     Ok(String),
@@ -261,6 +272,7 @@ pub enum ConnectCoreV1GetNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -285,6 +297,7 @@ impl ::Response for ConnectCoreV1GetNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PatchNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PATCH requests to proxy of Node
     ///
@@ -317,6 +330,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_patch_node_proxy`](./struct.Node.html#method.connect_core_v1_patch_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNodeProxyResponse {
     Ok(String),
@@ -324,6 +338,7 @@ pub enum ConnectCoreV1PatchNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -348,6 +363,7 @@ impl ::Response for ConnectCoreV1PatchNodeProxyResponse {
 
 // Generated from operation connectCoreV1PatchNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PATCH requests to proxy of Node
     ///
@@ -385,6 +401,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_patch_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_patch_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNodeProxyWithPathResponse {
     Ok(String),
@@ -392,6 +409,7 @@ pub enum ConnectCoreV1PatchNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -416,6 +434,7 @@ impl ::Response for ConnectCoreV1PatchNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PostNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect POST requests to proxy of Node
     ///
@@ -448,6 +467,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_post_node_proxy`](./struct.Node.html#method.connect_core_v1_post_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNodeProxyResponse {
     Ok(String),
@@ -455,6 +475,7 @@ pub enum ConnectCoreV1PostNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -479,6 +500,7 @@ impl ::Response for ConnectCoreV1PostNodeProxyResponse {
 
 // Generated from operation connectCoreV1PostNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect POST requests to proxy of Node
     ///
@@ -516,6 +538,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_post_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_post_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNodeProxyWithPathResponse {
     Ok(String),
@@ -523,6 +546,7 @@ pub enum ConnectCoreV1PostNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -547,6 +571,7 @@ impl ::Response for ConnectCoreV1PostNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PutNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PUT requests to proxy of Node
     ///
@@ -579,6 +604,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_put_node_proxy`](./struct.Node.html#method.connect_core_v1_put_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNodeProxyResponse {
     Ok(String),
@@ -586,6 +612,7 @@ pub enum ConnectCoreV1PutNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -610,6 +637,7 @@ impl ::Response for ConnectCoreV1PutNodeProxyResponse {
 
 // Generated from operation connectCoreV1PutNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PUT requests to proxy of Node
     ///
@@ -647,6 +675,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_put_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_put_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNodeProxyWithPathResponse {
     Ok(String),
@@ -654,6 +683,7 @@ pub enum ConnectCoreV1PutNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -678,6 +708,7 @@ impl ::Response for ConnectCoreV1PutNodeProxyWithPathResponse {
 
 // Generated from operation createCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// create a Node
     ///
@@ -708,6 +739,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::create_core_v1_node`](./struct.Node.html#method.create_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NodeResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -717,6 +749,7 @@ pub enum CreateCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -748,10 +781,15 @@ impl ::Response for CreateCoreV1NodeResponse {
             _ => Ok((CreateCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNode
 
+#[cfg(feature = "api")]
 impl Node {
     /// delete collection of Node
     ///
@@ -847,6 +885,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::delete_core_v1_collection_node`](./struct.Node.html#method.delete_core_v1_collection_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNodeResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -855,38 +894,34 @@ pub enum DeleteCoreV1CollectionNodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNodeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNodeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNodeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNodeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNodeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// delete a Node
     ///
@@ -953,6 +988,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::delete_core_v1_node`](./struct.Node.html#method.delete_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NodeResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -962,28 +998,19 @@ pub enum DeleteCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NodeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NodeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NodeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NodeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::ACCEPTED => {
@@ -998,10 +1025,15 @@ impl ::Response for DeleteCoreV1NodeResponse {
             _ => Ok((DeleteCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// list or watch objects of kind Node
     ///
@@ -1097,6 +1129,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::list_core_v1_node`](./struct.Node.html#method.list_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NodeResponse {
     Ok(::v1_12::api::core::v1::NodeList),
@@ -1104,6 +1137,7 @@ pub enum ListCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1119,10 +1153,27 @@ impl ::Response for ListCoreV1NodeResponse {
             _ => Ok((ListCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NodeResponse {
+    type List = ::v1_12::api::core::v1::NodeList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NodeResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// partially update the specified Node
     ///
@@ -1158,6 +1209,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::patch_core_v1_node`](./struct.Node.html#method.patch_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NodeResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -1165,6 +1217,7 @@ pub enum PatchCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1180,10 +1233,15 @@ impl ::Response for PatchCoreV1NodeResponse {
             _ => Ok((PatchCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NodeStatus
 
+#[cfg(feature = "api")]
 impl Node {
     /// partially update status of the specified Node
     ///
@@ -1219,6 +1277,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::patch_core_v1_node_status`](./struct.Node.html#method.patch_core_v1_node_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NodeStatusResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -1226,6 +1285,7 @@ pub enum PatchCoreV1NodeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NodeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1241,10 +1301,15 @@ impl ::Response for PatchCoreV1NodeStatusResponse {
             _ => Ok((PatchCoreV1NodeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// read the specified Node
     ///
@@ -1293,6 +1358,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::read_core_v1_node`](./struct.Node.html#method.read_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NodeResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -1300,6 +1366,7 @@ pub enum ReadCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1315,10 +1382,15 @@ impl ::Response for ReadCoreV1NodeResponse {
             _ => Ok((ReadCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NodeStatus
 
+#[cfg(feature = "api")]
 impl Node {
     /// read status of the specified Node
     ///
@@ -1351,6 +1423,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::read_core_v1_node_status`](./struct.Node.html#method.read_core_v1_node_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NodeStatusResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -1358,6 +1431,7 @@ pub enum ReadCoreV1NodeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NodeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1373,10 +1447,15 @@ impl ::Response for ReadCoreV1NodeStatusResponse {
             _ => Ok((ReadCoreV1NodeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// replace the specified Node
     ///
@@ -1412,6 +1491,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::replace_core_v1_node`](./struct.Node.html#method.replace_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NodeResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -1420,6 +1500,7 @@ pub enum ReplaceCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1443,10 +1524,15 @@ impl ::Response for ReplaceCoreV1NodeResponse {
             _ => Ok((ReplaceCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NodeStatus
 
+#[cfg(feature = "api")]
 impl Node {
     /// replace status of the specified Node
     ///
@@ -1482,6 +1568,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::replace_core_v1_node_status`](./struct.Node.html#method.replace_core_v1_node_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NodeStatusResponse {
     Ok(::v1_12::api::core::v1::Node),
@@ -1490,6 +1577,7 @@ pub enum ReplaceCoreV1NodeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NodeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1513,10 +1601,15 @@ impl ::Response for ReplaceCoreV1NodeStatusResponse {
             _ => Ok((ReplaceCoreV1NodeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// watch changes to an object of kind Node. deprecated: use the 'watch' parameter with a list operation instead, filtered to a single item with the 'fieldSelector' parameter.
     ///
@@ -1617,6 +1710,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::watch_core_v1_node`](./struct.Node.html#method.watch_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NodeResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1624,27 +1718,31 @@ pub enum WatchCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NodeResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NodeResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NodeResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NodeList
 
+#[cfg(feature = "api")]
 impl Node {
     /// watch individual changes to a list of Node. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -1740,6 +1838,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::watch_core_v1_node_list`](./struct.Node.html#method.watch_core_v1_node_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NodeListResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1747,23 +1846,26 @@ pub enum WatchCoreV1NodeListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NodeListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NodeListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NodeListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NodeListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NodeListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Node
@@ -1887,3 +1989,30 @@ impl ::serde::Serialize for Node {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Node`](./struct.Node.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NodeField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `spec.unschedulable`
+    SpecUnschedulable,
+}
+
+impl ::field_selector::Field for NodeField {
+    fn path(&self) -> &'static str {
+        match self {
+            NodeField::MetadataName => "metadata.name",
+            NodeField::SpecUnschedulable => "spec.unschedulable",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(NodeField::MetadataName),
+            "spec.unschedulable" => Some(NodeField::SpecUnschedulable),
+            _ => None,
+        }
+    }
+}
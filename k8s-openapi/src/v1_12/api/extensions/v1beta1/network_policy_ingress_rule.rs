@@ -99,3 +99,13 @@ impl ::serde::Serialize for NetworkPolicyIngressRule {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-networking-v1")]
+impl From<::v1_12::api::networking::v1::NetworkPolicyIngressRule> for NetworkPolicyIngressRule {
+    fn from(value: ::v1_12::api::networking::v1::NetworkPolicyIngressRule) -> Self {
+        NetworkPolicyIngressRule {
+            from: value.from.map(|value| value.into_iter().map(Into::into).collect()),
+            ports: value.ports.map(|value| value.into_iter().map(Into::into).collect()),
+        }
+    }
+}
@@ -342,3 +342,32 @@ impl ::serde::Serialize for PodSecurityPolicySpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-policy-v1beta1")]
+impl From<::v1_12::api::policy::v1beta1::PodSecurityPolicySpec> for PodSecurityPolicySpec {
+    fn from(value: ::v1_12::api::policy::v1beta1::PodSecurityPolicySpec) -> Self {
+        PodSecurityPolicySpec {
+            allow_privilege_escalation: value.allow_privilege_escalation,
+            allowed_capabilities: value.allowed_capabilities,
+            allowed_flex_volumes: value.allowed_flex_volumes.map(|value| value.into_iter().map(Into::into).collect()),
+            allowed_host_paths: value.allowed_host_paths.map(|value| value.into_iter().map(Into::into).collect()),
+            allowed_proc_mount_types: value.allowed_proc_mount_types,
+            allowed_unsafe_sysctls: value.allowed_unsafe_sysctls,
+            default_add_capabilities: value.default_add_capabilities,
+            default_allow_privilege_escalation: value.default_allow_privilege_escalation,
+            forbidden_sysctls: value.forbidden_sysctls,
+            fs_group: value.fs_group.into(),
+            host_ipc: value.host_ipc,
+            host_network: value.host_network,
+            host_pid: value.host_pid,
+            host_ports: value.host_ports.map(|value| value.into_iter().map(Into::into).collect()),
+            privileged: value.privileged,
+            read_only_root_filesystem: value.read_only_root_filesystem,
+            required_drop_capabilities: value.required_drop_capabilities,
+            run_as_user: value.run_as_user.into(),
+            se_linux: value.se_linux.into(),
+            supplemental_groups: value.supplemental_groups.into(),
+            volumes: value.volumes,
+        }
+    }
+}
@@ -1 +1,2 @@
+#[cfg(feature = "v1_12-networking-v1")]
 pub mod v1;
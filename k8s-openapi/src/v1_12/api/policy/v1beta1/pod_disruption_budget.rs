@@ -22,6 +22,7 @@ pub struct PodDisruptionBudget {
 
 // Generated from operation createPolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// create a PodDisruptionBudget
     ///
@@ -57,6 +58,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::create_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.create_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreatePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -66,6 +68,7 @@ pub enum CreatePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreatePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -97,10 +100,15 @@ impl ::Response for CreatePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
             _ => Ok((CreatePolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deletePolicyV1beta1CollectionNamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// delete collection of PodDisruptionBudget
     ///
@@ -201,6 +209,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::delete_policy_v1beta1_collection_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.delete_policy_v1beta1_collection_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -209,38 +218,34 @@ pub enum DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse::Unauthorized, 0)),
             _ => Ok((DeletePolicyV1beta1CollectionNamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deletePolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// delete a PodDisruptionBudget
     ///
@@ -312,6 +317,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::delete_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.delete_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -321,28 +327,19 @@ pub enum DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::ACCEPTED => {
@@ -357,10 +354,15 @@ impl ::Response for DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
             _ => Ok((DeletePolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listPolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// list or watch objects of kind PodDisruptionBudget
     ///
@@ -461,6 +463,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::list_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.list_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudgetList),
@@ -468,6 +471,7 @@ pub enum ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -483,10 +487,27 @@ impl ::Response for ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
             _ => Ok((ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
+    type List = ::v1_12::api::policy::v1beta1::PodDisruptionBudgetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listPolicyV1beta1PodDisruptionBudgetForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// list or watch objects of kind PodDisruptionBudget
     ///
@@ -582,6 +603,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::list_policy_v1beta1_pod_disruption_budget_for_all_namespaces`](./struct.PodDisruptionBudget.html#method.list_policy_v1beta1_pod_disruption_budget_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudgetList),
@@ -589,6 +611,7 @@ pub enum ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -604,10 +627,27 @@ impl ::Response for ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse
             _ => Ok((ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse {
+    type List = ::v1_12::api::policy::v1beta1::PodDisruptionBudgetList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListPolicyV1beta1PodDisruptionBudgetForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchPolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// partially update the specified PodDisruptionBudget
     ///
@@ -648,6 +688,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::patch_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.patch_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -655,6 +696,7 @@ pub enum PatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -670,10 +712,15 @@ impl ::Response for PatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
             _ => Ok((PatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchPolicyV1beta1NamespacedPodDisruptionBudgetStatus
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// partially update status of the specified PodDisruptionBudget
     ///
@@ -714,6 +761,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::patch_policy_v1beta1_namespaced_pod_disruption_budget_status`](./struct.PodDisruptionBudget.html#method.patch_policy_v1beta1_namespaced_pod_disruption_budget_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -721,6 +769,7 @@ pub enum PatchPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -736,10 +785,15 @@ impl ::Response for PatchPolicyV1beta1NamespacedPodDisruptionBudgetStatusRespons
             _ => Ok((PatchPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readPolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// read the specified PodDisruptionBudget
     ///
@@ -793,6 +847,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::read_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.read_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -800,6 +855,7 @@ pub enum ReadPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -815,10 +871,15 @@ impl ::Response for ReadPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
             _ => Ok((ReadPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readPolicyV1beta1NamespacedPodDisruptionBudgetStatus
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// read status of the specified PodDisruptionBudget
     ///
@@ -856,6 +917,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::read_policy_v1beta1_namespaced_pod_disruption_budget_status`](./struct.PodDisruptionBudget.html#method.read_policy_v1beta1_namespaced_pod_disruption_budget_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -863,6 +925,7 @@ pub enum ReadPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -878,10 +941,15 @@ impl ::Response for ReadPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse
             _ => Ok((ReadPolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replacePolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// replace the specified PodDisruptionBudget
     ///
@@ -922,6 +990,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::replace_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.replace_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplacePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -930,6 +999,7 @@ pub enum ReplacePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplacePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -953,10 +1023,15 @@ impl ::Response for ReplacePolicyV1beta1NamespacedPodDisruptionBudgetResponse {
             _ => Ok((ReplacePolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replacePolicyV1beta1NamespacedPodDisruptionBudgetStatus
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// replace status of the specified PodDisruptionBudget
     ///
@@ -997,6 +1072,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::replace_policy_v1beta1_namespaced_pod_disruption_budget_status`](./struct.PodDisruptionBudget.html#method.replace_policy_v1beta1_namespaced_pod_disruption_budget_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplacePolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     Ok(::v1_12::api::policy::v1beta1::PodDisruptionBudget),
@@ -1005,6 +1081,7 @@ pub enum ReplacePolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplacePolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1028,10 +1105,15 @@ impl ::Response for ReplacePolicyV1beta1NamespacedPodDisruptionBudgetStatusRespo
             _ => Ok((ReplacePolicyV1beta1NamespacedPodDisruptionBudgetStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchPolicyV1beta1NamespacedPodDisruptionBudget
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// watch changes to an object of kind PodDisruptionBudget. deprecated: use the 'watch' parameter with a list operation instead, filtered to a single item with the 'fieldSelector' parameter.
     ///
@@ -1137,6 +1219,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::watch_policy_v1beta1_namespaced_pod_disruption_budget`](./struct.PodDisruptionBudget.html#method.watch_policy_v1beta1_namespaced_pod_disruption_budget)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1144,27 +1227,31 @@ pub enum WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Unauthorized, 0)),
             _ => Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchPolicyV1beta1NamespacedPodDisruptionBudgetList
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// watch individual changes to a list of PodDisruptionBudget. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -1265,6 +1352,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::watch_policy_v1beta1_namespaced_pod_disruption_budget_list`](./struct.PodDisruptionBudget.html#method.watch_policy_v1beta1_namespaced_pod_disruption_budget_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1272,27 +1360,31 @@ pub enum WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse::Unauthorized, 0)),
             _ => Ok((WatchPolicyV1beta1NamespacedPodDisruptionBudgetListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchPolicyV1beta1PodDisruptionBudgetListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl PodDisruptionBudget {
     /// watch individual changes to a list of PodDisruptionBudget. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -1388,6 +1480,7 @@ impl PodDisruptionBudget {
 }
 
 /// Parses the HTTP response of [`PodDisruptionBudget::watch_policy_v1beta1_pod_disruption_budget_list_for_all_namespaces`](./struct.PodDisruptionBudget.html#method.watch_policy_v1beta1_pod_disruption_budget_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1395,23 +1488,26 @@ pub enum WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchPolicyV1beta1PodDisruptionBudgetListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End policy/v1beta1/PodDisruptionBudget
@@ -1535,3 +1631,30 @@ impl ::serde::Serialize for PodDisruptionBudget {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PodDisruptionBudget`](./struct.PodDisruptionBudget.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PodDisruptionBudgetField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for PodDisruptionBudgetField {
+    fn path(&self) -> &'static str {
+        match self {
+            PodDisruptionBudgetField::MetadataName => "metadata.name",
+            PodDisruptionBudgetField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PodDisruptionBudgetField::MetadataName),
+            "metadata.namespace" => Some(PodDisruptionBudgetField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -121,3 +121,15 @@ impl ::serde::Serialize for Subject {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-rbac-v1beta1")]
+impl From<::v1_12::api::rbac::v1beta1::Subject> for Subject {
+    fn from(value: ::v1_12::api::rbac::v1beta1::Subject) -> Self {
+        Subject {
+            api_group: value.api_group,
+            kind: value.kind,
+            name: value.name,
+            namespace: value.namespace,
+        }
+    }
+}
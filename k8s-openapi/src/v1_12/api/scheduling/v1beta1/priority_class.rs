@@ -26,6 +26,7 @@ pub struct PriorityClass {
 
 // Generated from operation createSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// create a PriorityClass
     ///
@@ -56,6 +57,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::create_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.create_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateSchedulingV1beta1PriorityClassResponse {
     Ok(::v1_12::api::scheduling::v1beta1::PriorityClass),
@@ -65,6 +67,7 @@ pub enum CreateSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -96,10 +99,15 @@ impl ::Response for CreateSchedulingV1beta1PriorityClassResponse {
             _ => Ok((CreateSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteSchedulingV1beta1CollectionPriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// delete collection of PriorityClass
     ///
@@ -195,6 +203,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::delete_scheduling_v1beta1_collection_priority_class`](./struct.PriorityClass.html#method.delete_scheduling_v1beta1_collection_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteSchedulingV1beta1CollectionPriorityClassResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -203,38 +212,34 @@ pub enum DeleteSchedulingV1beta1CollectionPriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteSchedulingV1beta1CollectionPriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1beta1CollectionPriorityClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1beta1CollectionPriorityClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteSchedulingV1beta1CollectionPriorityClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteSchedulingV1beta1CollectionPriorityClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteSchedulingV1beta1CollectionPriorityClassResponse::Unauthorized, 0)),
             _ => Ok((DeleteSchedulingV1beta1CollectionPriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// delete a PriorityClass
     ///
@@ -301,6 +306,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::delete_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.delete_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteSchedulingV1beta1PriorityClassResponse {
     OkStatus(::v1_12::apimachinery::pkg::apis::meta::v1::Status),
@@ -310,28 +316,19 @@ pub enum DeleteSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1beta1PriorityClassResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteSchedulingV1beta1PriorityClassResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteSchedulingV1beta1PriorityClassResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteSchedulingV1beta1PriorityClassResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::ACCEPTED => {
@@ -346,10 +343,15 @@ impl ::Response for DeleteSchedulingV1beta1PriorityClassResponse {
             _ => Ok((DeleteSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// list or watch objects of kind PriorityClass
     ///
@@ -445,6 +447,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::list_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.list_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListSchedulingV1beta1PriorityClassResponse {
     Ok(::v1_12::api::scheduling::v1beta1::PriorityClassList),
@@ -452,6 +455,7 @@ pub enum ListSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -467,10 +471,27 @@ impl ::Response for ListSchedulingV1beta1PriorityClassResponse {
             _ => Ok((ListSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListSchedulingV1beta1PriorityClassResponse {
+    type List = ::v1_12::api::scheduling::v1beta1::PriorityClassList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListSchedulingV1beta1PriorityClassResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// partially update the specified PriorityClass
     ///
@@ -506,6 +527,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::patch_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.patch_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchSchedulingV1beta1PriorityClassResponse {
     Ok(::v1_12::api::scheduling::v1beta1::PriorityClass),
@@ -513,6 +535,7 @@ pub enum PatchSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -528,10 +551,15 @@ impl ::Response for PatchSchedulingV1beta1PriorityClassResponse {
             _ => Ok((PatchSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// read the specified PriorityClass
     ///
@@ -580,6 +608,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::read_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.read_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadSchedulingV1beta1PriorityClassResponse {
     Ok(::v1_12::api::scheduling::v1beta1::PriorityClass),
@@ -587,6 +616,7 @@ pub enum ReadSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -602,10 +632,15 @@ impl ::Response for ReadSchedulingV1beta1PriorityClassResponse {
             _ => Ok((ReadSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// replace the specified PriorityClass
     ///
@@ -641,6 +676,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::replace_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.replace_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceSchedulingV1beta1PriorityClassResponse {
     Ok(::v1_12::api::scheduling::v1beta1::PriorityClass),
@@ -649,6 +685,7 @@ pub enum ReplaceSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -672,10 +709,15 @@ impl ::Response for ReplaceSchedulingV1beta1PriorityClassResponse {
             _ => Ok((ReplaceSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchSchedulingV1beta1PriorityClass
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// watch changes to an object of kind PriorityClass. deprecated: use the 'watch' parameter with a list operation instead, filtered to a single item with the 'fieldSelector' parameter.
     ///
@@ -776,6 +818,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::watch_scheduling_v1beta1_priority_class`](./struct.PriorityClass.html#method.watch_scheduling_v1beta1_priority_class)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSchedulingV1beta1PriorityClassResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -783,27 +826,31 @@ pub enum WatchSchedulingV1beta1PriorityClassResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSchedulingV1beta1PriorityClassResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSchedulingV1beta1PriorityClassResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSchedulingV1beta1PriorityClassResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSchedulingV1beta1PriorityClassResponse::Unauthorized, 0)),
             _ => Ok((WatchSchedulingV1beta1PriorityClassResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchSchedulingV1beta1PriorityClassList
 
+#[cfg(feature = "api")]
 impl PriorityClass {
     /// watch individual changes to a list of PriorityClass. deprecated: use the 'watch' parameter with a list operation instead.
     ///
@@ -899,6 +946,7 @@ impl PriorityClass {
 }
 
 /// Parses the HTTP response of [`PriorityClass::watch_scheduling_v1beta1_priority_class_list`](./struct.PriorityClass.html#method.watch_scheduling_v1beta1_priority_class_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchSchedulingV1beta1PriorityClassListResponse {
     Ok(::v1_12::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -906,23 +954,26 @@ pub enum WatchSchedulingV1beta1PriorityClassListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchSchedulingV1beta1PriorityClassListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchSchedulingV1beta1PriorityClassListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchSchedulingV1beta1PriorityClassListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchSchedulingV1beta1PriorityClassListResponse::Unauthorized, 0)),
             _ => Ok((WatchSchedulingV1beta1PriorityClassListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End scheduling.k8s.io/v1beta1/PriorityClass
@@ -1054,3 +1105,39 @@ impl ::serde::Serialize for PriorityClass {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`PriorityClass`](./struct.PriorityClass.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PriorityClassField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for PriorityClassField {
+    fn path(&self) -> &'static str {
+        match self {
+            PriorityClassField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PriorityClassField::MetadataName),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "v1_12-scheduling-v1alpha1")]
+impl From<::v1_12::api::scheduling::v1alpha1::PriorityClass> for PriorityClass {
+    fn from(value: ::v1_12::api::scheduling::v1alpha1::PriorityClass) -> Self {
+        PriorityClass {
+            api_version: value.api_version.map(|_| "scheduling.k8s.io/v1beta1".to_string()),
+            description: value.description,
+            global_default: value.global_default,
+            kind: value.kind,
+            metadata: value.metadata,
+            value: value.value,
+        }
+    }
+}
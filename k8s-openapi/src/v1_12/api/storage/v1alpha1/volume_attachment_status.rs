@@ -123,3 +123,15 @@ impl ::serde::Serialize for VolumeAttachmentStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-storage-v1beta1")]
+impl From<::v1_12::api::storage::v1beta1::VolumeAttachmentStatus> for VolumeAttachmentStatus {
+    fn from(value: ::v1_12::api::storage::v1beta1::VolumeAttachmentStatus) -> Self {
+        VolumeAttachmentStatus {
+            attach_error: value.attach_error.map(Into::into),
+            attached: value.attached,
+            attachment_metadata: value.attachment_metadata,
+            detach_error: value.detach_error.map(Into::into),
+        }
+    }
+}
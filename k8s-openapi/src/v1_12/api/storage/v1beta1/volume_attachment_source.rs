@@ -86,3 +86,12 @@ impl ::serde::Serialize for VolumeAttachmentSource {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_12-storage-v1alpha1")]
+impl From<::v1_12::api::storage::v1alpha1::VolumeAttachmentSource> for VolumeAttachmentSource {
+    fn from(value: ::v1_12::api::storage::v1alpha1::VolumeAttachmentSource) -> Self {
+        VolumeAttachmentSource {
+            persistent_volume_name: value.persistent_volume_name,
+        }
+    }
+}
@@ -122,3 +122,15 @@ impl ::serde::Serialize for CustomResourceDefinitionList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for CustomResourceDefinitionList {
+    type Item = ::v1_12::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -13,6 +13,62 @@ impl Default for IntOrString {
     }
 }
 
+impl IntOrString {
+    /// Returns the percentage held by this value if it is a string of the form `"N%"`.
+    ///
+    /// `N` may have a leading sign, like `"-10%"`, since the API server parses it like Go's `strconv.Atoi`.
+    ///
+    /// Returns `Ok(None)` if this value is an integer, and an error if it is a string that is not a valid percentage.
+    pub fn percentage(&self) -> Result<Option<i32>, ::IntOrStringError> {
+        match self {
+            IntOrString::Int(_) => Ok(None),
+            IntOrString::String(s) => {
+                let digits = if s.ends_with('%') { &s[..s.len() - 1] } else { "" };
+                let unsigned_digits = if digits.starts_with('-') || digits.starts_with('+') { &digits[1..] } else { digits };
+                if unsigned_digits.is_empty() || !unsigned_digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(::IntOrStringError::NotAPercentage(s.clone()));
+                }
+
+                let percentage = digits.parse().map_err(|_| ::IntOrStringError::OutOfRange(s.clone()))?;
+                Ok(Some(percentage))
+            },
+        }
+    }
+
+    /// Resolves this value against the given total, such as the number of replicas of a deployment.
+    ///
+    /// An integer is returned as-is. A percentage is scaled against `total` and rounded up if `round_up` is true, otherwise rounded down.
+    /// The API server rounds up `maxSurge` and rounds down `maxUnavailable`.
+    pub fn scaled_value(&self, total: i32, round_up: bool) -> Result<i32, ::IntOrStringError> {
+        let (s, percentage) = match self {
+            IntOrString::Int(i) => return Ok(*i),
+            IntOrString::String(s) => (s, self.percentage()?.unwrap_or_default()),
+        };
+
+        let value = f64::from(percentage) * f64::from(total) / 100.0;
+        let value = if round_up { value.ceil() } else { value.floor() };
+        if value < f64::from(::std::i32::MIN) || value > f64::from(::std::i32::MAX) {
+            return Err(::IntOrStringError::OutOfRange(s.clone()));
+        }
+
+        Ok(value as i32)
+    }
+
+    /// Resolves this value as a port number, such as the `targetPort` of a service port.
+    ///
+    /// An integer is returned as-is. A string is looked up by name in the given container ports, and `None` is returned if no port has that name.
+    #[cfg(feature = "v1_12-core-v1")]
+    pub fn port_number(&self, ports: &[::v1_12::api::core::v1::ContainerPort]) -> Option<i32> {
+        match self {
+            IntOrString::Int(i) => Some(*i),
+            IntOrString::String(s) =>
+                ports.iter()
+                .find(|port| port.name.as_ref() == Some(s))
+                .map(|port| port.container_port),
+        }
+    }
+}
+
 impl<'de> ::serde::Deserialize<'de> for IntOrString {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {
         struct Visitor;
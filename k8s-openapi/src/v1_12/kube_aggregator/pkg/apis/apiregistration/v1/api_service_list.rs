@@ -121,3 +121,15 @@ impl ::serde::Serialize for APIServiceList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for APIServiceList {
+    type Item = ::v1_12::kube_aggregator::pkg::apis::apiregistration::v1::APIService;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
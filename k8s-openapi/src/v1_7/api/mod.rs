@@ -0,0 +1,83 @@
+// Re-exports of the modules under `kubernetes::pkg::api` and `kubernetes::pkg::apis`, so that they have the same paths as in newer versions.
+
+pub mod admissionregistration {
+    #[cfg(feature = "v1_7-admissionregistration-v1alpha1")]
+    pub use ::v1_7::kubernetes::pkg::apis::admissionregistration::v1alpha1;
+}
+
+pub mod apps {
+    #[cfg(feature = "v1_7-apps-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::apps::v1beta1;
+}
+
+pub mod authentication {
+    #[cfg(feature = "v1_7-authentication-v1")]
+    pub use ::v1_7::kubernetes::pkg::apis::authentication::v1;
+    #[cfg(feature = "v1_7-authentication-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::authentication::v1beta1;
+}
+
+pub mod authorization {
+    #[cfg(feature = "v1_7-authorization-v1")]
+    pub use ::v1_7::kubernetes::pkg::apis::authorization::v1;
+    #[cfg(feature = "v1_7-authorization-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::authorization::v1beta1;
+}
+
+pub mod autoscaling {
+    #[cfg(feature = "v1_7-autoscaling-v1")]
+    pub use ::v1_7::kubernetes::pkg::apis::autoscaling::v1;
+    #[cfg(feature = "v1_7-autoscaling-v2alpha1")]
+    pub use ::v1_7::kubernetes::pkg::apis::autoscaling::v2alpha1;
+}
+
+pub mod batch {
+    #[cfg(feature = "v1_7-batch-v1")]
+    pub use ::v1_7::kubernetes::pkg::apis::batch::v1;
+    #[cfg(feature = "v1_7-batch-v2alpha1")]
+    pub use ::v1_7::kubernetes::pkg::apis::batch::v2alpha1;
+}
+
+pub mod certificates {
+    #[cfg(feature = "v1_7-certificates-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::certificates::v1beta1;
+}
+
+pub mod core {
+    #[cfg(feature = "v1_7-core-v1")]
+    pub use ::v1_7::kubernetes::pkg::api::v1;
+}
+
+pub mod extensions {
+    #[cfg(feature = "v1_7-extensions-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::extensions::v1beta1;
+}
+
+pub mod networking {
+    #[cfg(feature = "v1_7-networking-v1")]
+    pub use ::v1_7::kubernetes::pkg::apis::networking::v1;
+}
+
+pub mod policy {
+    #[cfg(feature = "v1_7-policy-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::policy::v1beta1;
+}
+
+pub mod rbac {
+    #[cfg(feature = "v1_7-rbac-v1alpha1")]
+    pub use ::v1_7::kubernetes::pkg::apis::rbac::v1alpha1;
+    #[cfg(feature = "v1_7-rbac-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::rbac::v1beta1;
+}
+
+pub mod settings {
+    #[cfg(feature = "v1_7-settings-v1alpha1")]
+    pub use ::v1_7::kubernetes::pkg::apis::settings::v1alpha1;
+}
+
+pub mod storage {
+    #[cfg(feature = "v1_7-storage-v1")]
+    pub use ::v1_7::kubernetes::pkg::apis::storage::v1;
+    #[cfg(feature = "v1_7-storage-v1beta1")]
+    pub use ::v1_7::kubernetes::pkg::apis::storage::v1beta1;
+}
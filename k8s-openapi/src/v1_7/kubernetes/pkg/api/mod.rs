@@ -1 +1,2 @@
+#[cfg(feature = "v1_7-core-v1")]
 pub mod v1;
@@ -123,3 +123,15 @@ impl ::serde::Serialize for LimitRangeList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for LimitRangeList {
+    type Item = ::v1_7::kubernetes::pkg::api::v1::LimitRange;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -23,6 +23,7 @@ pub struct Namespace {
 
 // Generated from operation createCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// create a Namespace
     ///
@@ -53,6 +54,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::create_core_v1_namespace`](./struct.Namespace.html#method.create_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespaceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -60,6 +62,7 @@ pub enum CreateCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -75,10 +78,15 @@ impl ::Response for CreateCoreV1NamespaceResponse {
             _ => Ok((CreateCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// delete a Namespace
     ///
@@ -137,6 +145,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::delete_core_v1_namespace`](./struct.Namespace.html#method.delete_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespaceResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -145,38 +154,34 @@ pub enum DeleteCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespaceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespaceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespaceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespaceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespaceResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// list or watch objects of kind Namespace
     ///
@@ -252,6 +257,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::list_core_v1_namespace`](./struct.Namespace.html#method.list_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespaceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::NamespaceList),
@@ -259,6 +265,7 @@ pub enum ListCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -274,10 +281,27 @@ impl ::Response for ListCoreV1NamespaceResponse {
             _ => Ok((ListCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespaceResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::NamespaceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespaceResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// partially update the specified Namespace
     ///
@@ -313,6 +337,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::patch_core_v1_namespace`](./struct.Namespace.html#method.patch_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespaceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -320,6 +345,7 @@ pub enum PatchCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -335,10 +361,15 @@ impl ::Response for PatchCoreV1NamespaceResponse {
             _ => Ok((PatchCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespaceStatus
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// partially update status of the specified Namespace
     ///
@@ -374,6 +405,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::patch_core_v1_namespace_status`](./struct.Namespace.html#method.patch_core_v1_namespace_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespaceStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -381,6 +413,7 @@ pub enum PatchCoreV1NamespaceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespaceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -396,10 +429,15 @@ impl ::Response for PatchCoreV1NamespaceStatusResponse {
             _ => Ok((PatchCoreV1NamespaceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// read the specified Namespace
     ///
@@ -448,6 +486,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::read_core_v1_namespace`](./struct.Namespace.html#method.read_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespaceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -455,6 +494,7 @@ pub enum ReadCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -470,10 +510,15 @@ impl ::Response for ReadCoreV1NamespaceResponse {
             _ => Ok((ReadCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespaceStatus
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// read status of the specified Namespace
     ///
@@ -506,6 +551,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::read_core_v1_namespace_status`](./struct.Namespace.html#method.read_core_v1_namespace_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespaceStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -513,6 +559,7 @@ pub enum ReadCoreV1NamespaceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespaceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -528,10 +575,15 @@ impl ::Response for ReadCoreV1NamespaceStatusResponse {
             _ => Ok((ReadCoreV1NamespaceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// replace the specified Namespace
     ///
@@ -567,6 +619,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::replace_core_v1_namespace`](./struct.Namespace.html#method.replace_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespaceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -574,6 +627,7 @@ pub enum ReplaceCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -589,10 +643,15 @@ impl ::Response for ReplaceCoreV1NamespaceResponse {
             _ => Ok((ReplaceCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespaceFinalize
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// replace finalize of the specified Namespace
     ///
@@ -628,6 +687,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::replace_core_v1_namespace_finalize`](./struct.Namespace.html#method.replace_core_v1_namespace_finalize)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespaceFinalizeResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -635,6 +695,7 @@ pub enum ReplaceCoreV1NamespaceFinalizeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespaceFinalizeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -650,10 +711,15 @@ impl ::Response for ReplaceCoreV1NamespaceFinalizeResponse {
             _ => Ok((ReplaceCoreV1NamespaceFinalizeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespaceStatus
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// replace status of the specified Namespace
     ///
@@ -689,6 +755,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::replace_core_v1_namespace_status`](./struct.Namespace.html#method.replace_core_v1_namespace_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespaceStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Namespace),
@@ -696,6 +763,7 @@ pub enum ReplaceCoreV1NamespaceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespaceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -711,10 +779,15 @@ impl ::Response for ReplaceCoreV1NamespaceStatusResponse {
             _ => Ok((ReplaceCoreV1NamespaceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1Namespace
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// watch changes to an object of kind Namespace
     ///
@@ -795,6 +868,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::watch_core_v1_namespace`](./struct.Namespace.html#method.watch_core_v1_namespace)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespaceResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -802,27 +876,31 @@ pub enum WatchCoreV1NamespaceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespaceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespaceResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespaceResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespaceResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespaceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespaceList
 
+#[cfg(feature = "api")]
 impl Namespace {
     /// watch individual changes to a list of Namespace
     ///
@@ -898,6 +976,7 @@ impl Namespace {
 }
 
 /// Parses the HTTP response of [`Namespace::watch_core_v1_namespace_list`](./struct.Namespace.html#method.watch_core_v1_namespace_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespaceListResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -905,23 +984,26 @@ pub enum WatchCoreV1NamespaceListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespaceListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespaceListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespaceListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespaceListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespaceListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Namespace
@@ -1045,3 +1127,30 @@ impl ::serde::Serialize for Namespace {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Namespace`](./struct.Namespace.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NamespaceField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `status.phase`
+    StatusPhase,
+}
+
+impl ::field_selector::Field for NamespaceField {
+    fn path(&self) -> &'static str {
+        match self {
+            NamespaceField::MetadataName => "metadata.name",
+            NamespaceField::StatusPhase => "status.phase",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(NamespaceField::MetadataName),
+            "status.phase" => Some(NamespaceField::StatusPhase),
+            _ => None,
+        }
+    }
+}
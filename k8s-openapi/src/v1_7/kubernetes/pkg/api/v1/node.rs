@@ -23,6 +23,7 @@ pub struct Node {
 
 // Generated from operation connectCoreV1DeleteNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect DELETE requests to proxy of Node
     ///
@@ -55,6 +56,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_delete_node_proxy`](./struct.Node.html#method.connect_core_v1_delete_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNodeProxyResponse {
     Ok(String),
@@ -62,6 +64,7 @@ pub enum ConnectCoreV1DeleteNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -86,6 +89,7 @@ impl ::Response for ConnectCoreV1DeleteNodeProxyResponse {
 
 // Generated from operation connectCoreV1DeleteNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect DELETE requests to proxy of Node
     ///
@@ -123,6 +127,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_delete_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_delete_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNodeProxyWithPathResponse {
     Ok(String),
@@ -130,6 +135,7 @@ pub enum ConnectCoreV1DeleteNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -154,6 +160,7 @@ impl ::Response for ConnectCoreV1DeleteNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1GetNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect GET requests to proxy of Node
     ///
@@ -186,6 +193,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_get_node_proxy`](./struct.Node.html#method.connect_core_v1_get_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNodeProxyResponse {
     Ok(String),
@@ -193,6 +201,7 @@ pub enum ConnectCoreV1GetNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -217,6 +226,7 @@ impl ::Response for ConnectCoreV1GetNodeProxyResponse {
 
 // Generated from operation connectCoreV1GetNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect GET requests to proxy of Node
     ///
@@ -254,6 +264,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_get_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_get_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNodeProxyWithPathResponse {
     Ok(String),
@@ -261,6 +272,7 @@ pub enum ConnectCoreV1GetNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -285,6 +297,7 @@ impl ::Response for ConnectCoreV1GetNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PatchNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PATCH requests to proxy of Node
     ///
@@ -317,6 +330,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_patch_node_proxy`](./struct.Node.html#method.connect_core_v1_patch_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNodeProxyResponse {
     Ok(String),
@@ -324,6 +338,7 @@ pub enum ConnectCoreV1PatchNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -348,6 +363,7 @@ impl ::Response for ConnectCoreV1PatchNodeProxyResponse {
 
 // Generated from operation connectCoreV1PatchNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PATCH requests to proxy of Node
     ///
@@ -385,6 +401,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_patch_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_patch_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNodeProxyWithPathResponse {
     Ok(String),
@@ -392,6 +409,7 @@ pub enum ConnectCoreV1PatchNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -416,6 +434,7 @@ impl ::Response for ConnectCoreV1PatchNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PostNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect POST requests to proxy of Node
     ///
@@ -448,6 +467,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_post_node_proxy`](./struct.Node.html#method.connect_core_v1_post_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNodeProxyResponse {
     Ok(String),
@@ -455,6 +475,7 @@ pub enum ConnectCoreV1PostNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -479,6 +500,7 @@ impl ::Response for ConnectCoreV1PostNodeProxyResponse {
 
 // Generated from operation connectCoreV1PostNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect POST requests to proxy of Node
     ///
@@ -516,6 +538,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_post_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_post_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNodeProxyWithPathResponse {
     Ok(String),
@@ -523,6 +546,7 @@ pub enum ConnectCoreV1PostNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -547,6 +571,7 @@ impl ::Response for ConnectCoreV1PostNodeProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PutNodeProxy
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PUT requests to proxy of Node
     ///
@@ -579,6 +604,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_put_node_proxy`](./struct.Node.html#method.connect_core_v1_put_node_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNodeProxyResponse {
     Ok(String),
@@ -586,6 +612,7 @@ pub enum ConnectCoreV1PutNodeProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNodeProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -610,6 +637,7 @@ impl ::Response for ConnectCoreV1PutNodeProxyResponse {
 
 // Generated from operation connectCoreV1PutNodeProxyWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// connect PUT requests to proxy of Node
     ///
@@ -647,6 +675,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::connect_core_v1_put_node_proxy_with_path`](./struct.Node.html#method.connect_core_v1_put_node_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNodeProxyWithPathResponse {
     Ok(String),
@@ -654,6 +683,7 @@ pub enum ConnectCoreV1PutNodeProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNodeProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -678,6 +708,7 @@ impl ::Response for ConnectCoreV1PutNodeProxyWithPathResponse {
 
 // Generated from operation createCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// create a Node
     ///
@@ -708,6 +739,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::create_core_v1_node`](./struct.Node.html#method.create_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NodeResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -715,6 +747,7 @@ pub enum CreateCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -730,10 +763,15 @@ impl ::Response for CreateCoreV1NodeResponse {
             _ => Ok((CreateCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNode
 
+#[cfg(feature = "api")]
 impl Node {
     /// delete collection of Node
     ///
@@ -809,6 +847,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::delete_core_v1_collection_node`](./struct.Node.html#method.delete_core_v1_collection_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNodeResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -817,38 +856,34 @@ pub enum DeleteCoreV1CollectionNodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNodeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNodeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNodeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNodeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNodeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// delete a Node
     ///
@@ -907,6 +942,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::delete_core_v1_node`](./struct.Node.html#method.delete_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NodeResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -915,38 +951,34 @@ pub enum DeleteCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NodeResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NodeResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NodeResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NodeResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NodeResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// list or watch objects of kind Node
     ///
@@ -1022,6 +1054,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::list_core_v1_node`](./struct.Node.html#method.list_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NodeResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::NodeList),
@@ -1029,6 +1062,7 @@ pub enum ListCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1044,10 +1078,27 @@ impl ::Response for ListCoreV1NodeResponse {
             _ => Ok((ListCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NodeResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::NodeList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NodeResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// partially update the specified Node
     ///
@@ -1083,6 +1134,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::patch_core_v1_node`](./struct.Node.html#method.patch_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NodeResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -1090,6 +1142,7 @@ pub enum PatchCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1105,10 +1158,15 @@ impl ::Response for PatchCoreV1NodeResponse {
             _ => Ok((PatchCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NodeStatus
 
+#[cfg(feature = "api")]
 impl Node {
     /// partially update status of the specified Node
     ///
@@ -1144,6 +1202,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::patch_core_v1_node_status`](./struct.Node.html#method.patch_core_v1_node_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NodeStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -1151,6 +1210,7 @@ pub enum PatchCoreV1NodeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NodeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1166,10 +1226,15 @@ impl ::Response for PatchCoreV1NodeStatusResponse {
             _ => Ok((PatchCoreV1NodeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation proxyCoreV1DELETENode
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy DELETE requests to Node
     ///
@@ -1192,6 +1257,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_delete_node`](./struct.Node.html#method.proxy_core_v1_delete_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1DELETENodeResponse {
     Ok(String),
@@ -1199,6 +1265,7 @@ pub enum ProxyCoreV1DELETENodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1DELETENodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1223,6 +1290,7 @@ impl ::Response for ProxyCoreV1DELETENodeResponse {
 
 // Generated from operation proxyCoreV1DELETENodeWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy DELETE requests to Node
     ///
@@ -1250,6 +1318,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_delete_node_with_path`](./struct.Node.html#method.proxy_core_v1_delete_node_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1DELETENodeWithPathResponse {
     Ok(String),
@@ -1257,6 +1326,7 @@ pub enum ProxyCoreV1DELETENodeWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1DELETENodeWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1281,6 +1351,7 @@ impl ::Response for ProxyCoreV1DELETENodeWithPathResponse {
 
 // Generated from operation proxyCoreV1GETNode
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy GET requests to Node
     ///
@@ -1303,6 +1374,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_get_node`](./struct.Node.html#method.proxy_core_v1_get_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1GETNodeResponse {
     Ok(String),
@@ -1310,6 +1382,7 @@ pub enum ProxyCoreV1GETNodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1GETNodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1334,6 +1407,7 @@ impl ::Response for ProxyCoreV1GETNodeResponse {
 
 // Generated from operation proxyCoreV1GETNodeWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy GET requests to Node
     ///
@@ -1361,6 +1435,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_get_node_with_path`](./struct.Node.html#method.proxy_core_v1_get_node_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1GETNodeWithPathResponse {
     Ok(String),
@@ -1368,6 +1443,7 @@ pub enum ProxyCoreV1GETNodeWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1GETNodeWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1392,6 +1468,7 @@ impl ::Response for ProxyCoreV1GETNodeWithPathResponse {
 
 // Generated from operation proxyCoreV1PATCHNode
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy PATCH requests to Node
     ///
@@ -1414,6 +1491,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_patch_node`](./struct.Node.html#method.proxy_core_v1_patch_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PATCHNodeResponse {
     Ok(String),
@@ -1421,6 +1499,7 @@ pub enum ProxyCoreV1PATCHNodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PATCHNodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1445,6 +1524,7 @@ impl ::Response for ProxyCoreV1PATCHNodeResponse {
 
 // Generated from operation proxyCoreV1PATCHNodeWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy PATCH requests to Node
     ///
@@ -1472,6 +1552,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_patch_node_with_path`](./struct.Node.html#method.proxy_core_v1_patch_node_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PATCHNodeWithPathResponse {
     Ok(String),
@@ -1479,6 +1560,7 @@ pub enum ProxyCoreV1PATCHNodeWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PATCHNodeWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1503,6 +1585,7 @@ impl ::Response for ProxyCoreV1PATCHNodeWithPathResponse {
 
 // Generated from operation proxyCoreV1POSTNode
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy POST requests to Node
     ///
@@ -1525,6 +1608,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_post_node`](./struct.Node.html#method.proxy_core_v1_post_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1POSTNodeResponse {
     Ok(String),
@@ -1532,6 +1616,7 @@ pub enum ProxyCoreV1POSTNodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1POSTNodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1556,6 +1641,7 @@ impl ::Response for ProxyCoreV1POSTNodeResponse {
 
 // Generated from operation proxyCoreV1POSTNodeWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy POST requests to Node
     ///
@@ -1583,6 +1669,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_post_node_with_path`](./struct.Node.html#method.proxy_core_v1_post_node_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1POSTNodeWithPathResponse {
     Ok(String),
@@ -1590,6 +1677,7 @@ pub enum ProxyCoreV1POSTNodeWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1POSTNodeWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1614,6 +1702,7 @@ impl ::Response for ProxyCoreV1POSTNodeWithPathResponse {
 
 // Generated from operation proxyCoreV1PUTNode
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy PUT requests to Node
     ///
@@ -1636,6 +1725,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_put_node`](./struct.Node.html#method.proxy_core_v1_put_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PUTNodeResponse {
     Ok(String),
@@ -1643,6 +1733,7 @@ pub enum ProxyCoreV1PUTNodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PUTNodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1667,6 +1758,7 @@ impl ::Response for ProxyCoreV1PUTNodeResponse {
 
 // Generated from operation proxyCoreV1PUTNodeWithPath
 
+#[cfg(feature = "api")]
 impl Node {
     /// proxy PUT requests to Node
     ///
@@ -1694,6 +1786,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::proxy_core_v1_put_node_with_path`](./struct.Node.html#method.proxy_core_v1_put_node_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PUTNodeWithPathResponse {
     Ok(String),
@@ -1701,6 +1794,7 @@ pub enum ProxyCoreV1PUTNodeWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PUTNodeWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1725,6 +1819,7 @@ impl ::Response for ProxyCoreV1PUTNodeWithPathResponse {
 
 // Generated from operation readCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// read the specified Node
     ///
@@ -1773,6 +1868,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::read_core_v1_node`](./struct.Node.html#method.read_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NodeResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -1780,6 +1876,7 @@ pub enum ReadCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1795,10 +1892,15 @@ impl ::Response for ReadCoreV1NodeResponse {
             _ => Ok((ReadCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NodeStatus
 
+#[cfg(feature = "api")]
 impl Node {
     /// read status of the specified Node
     ///
@@ -1831,6 +1933,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::read_core_v1_node_status`](./struct.Node.html#method.read_core_v1_node_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NodeStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -1838,6 +1941,7 @@ pub enum ReadCoreV1NodeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NodeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1853,10 +1957,15 @@ impl ::Response for ReadCoreV1NodeStatusResponse {
             _ => Ok((ReadCoreV1NodeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// replace the specified Node
     ///
@@ -1892,6 +2001,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::replace_core_v1_node`](./struct.Node.html#method.replace_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NodeResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -1899,6 +2009,7 @@ pub enum ReplaceCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1914,10 +2025,15 @@ impl ::Response for ReplaceCoreV1NodeResponse {
             _ => Ok((ReplaceCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NodeStatus
 
+#[cfg(feature = "api")]
 impl Node {
     /// replace status of the specified Node
     ///
@@ -1953,6 +2069,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::replace_core_v1_node_status`](./struct.Node.html#method.replace_core_v1_node_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NodeStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Node),
@@ -1960,6 +2077,7 @@ pub enum ReplaceCoreV1NodeStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NodeStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1975,10 +2093,15 @@ impl ::Response for ReplaceCoreV1NodeStatusResponse {
             _ => Ok((ReplaceCoreV1NodeStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1Node
 
+#[cfg(feature = "api")]
 impl Node {
     /// watch changes to an object of kind Node
     ///
@@ -2059,6 +2182,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::watch_core_v1_node`](./struct.Node.html#method.watch_core_v1_node)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NodeResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -2066,27 +2190,31 @@ pub enum WatchCoreV1NodeResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NodeResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NodeResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NodeResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NodeResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NodeResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NodeList
 
+#[cfg(feature = "api")]
 impl Node {
     /// watch individual changes to a list of Node
     ///
@@ -2162,6 +2290,7 @@ impl Node {
 }
 
 /// Parses the HTTP response of [`Node::watch_core_v1_node_list`](./struct.Node.html#method.watch_core_v1_node_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NodeListResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -2169,23 +2298,26 @@ pub enum WatchCoreV1NodeListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NodeListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NodeListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NodeListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NodeListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NodeListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Node
@@ -2309,3 +2441,30 @@ impl ::serde::Serialize for Node {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Node`](./struct.Node.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NodeField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `spec.unschedulable`
+    SpecUnschedulable,
+}
+
+impl ::field_selector::Field for NodeField {
+    fn path(&self) -> &'static str {
+        match self {
+            NodeField::MetadataName => "metadata.name",
+            NodeField::SpecUnschedulable => "spec.unschedulable",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(NodeField::MetadataName),
+            "spec.unschedulable" => Some(NodeField::SpecUnschedulable),
+            _ => None,
+        }
+    }
+}
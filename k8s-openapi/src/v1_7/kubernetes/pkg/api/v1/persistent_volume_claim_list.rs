@@ -123,3 +123,15 @@ impl ::serde::Serialize for PersistentVolumeClaimList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for PersistentVolumeClaimList {
+    type Item = ::v1_7::kubernetes::pkg::api::v1::PersistentVolumeClaim;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -23,6 +23,7 @@ pub struct Pod {
 
 // Generated from operation connectCoreV1DeleteNamespacedPodProxy
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect DELETE requests to proxy of Pod
     ///
@@ -60,6 +61,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_delete_namespaced_pod_proxy`](./struct.Pod.html#method.connect_core_v1_delete_namespaced_pod_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNamespacedPodProxyResponse {
     Ok(String),
@@ -67,6 +69,7 @@ pub enum ConnectCoreV1DeleteNamespacedPodProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNamespacedPodProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -91,6 +94,7 @@ impl ::Response for ConnectCoreV1DeleteNamespacedPodProxyResponse {
 
 // Generated from operation connectCoreV1DeleteNamespacedPodProxyWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect DELETE requests to proxy of Pod
     ///
@@ -133,6 +137,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_delete_namespaced_pod_proxy_with_path`](./struct.Pod.html#method.connect_core_v1_delete_namespaced_pod_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNamespacedPodProxyWithPathResponse {
     Ok(String),
@@ -140,6 +145,7 @@ pub enum ConnectCoreV1DeleteNamespacedPodProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNamespacedPodProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -164,6 +170,7 @@ impl ::Response for ConnectCoreV1DeleteNamespacedPodProxyWithPathResponse {
 
 // Generated from operation connectCoreV1GetNamespacedPodAttach
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect GET requests to attach of Pod
     ///
@@ -233,6 +240,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_get_namespaced_pod_attach`](./struct.Pod.html#method.connect_core_v1_get_namespaced_pod_attach)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedPodAttachResponse {
     Ok(String),
@@ -240,6 +248,7 @@ pub enum ConnectCoreV1GetNamespacedPodAttachResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedPodAttachResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -264,6 +273,7 @@ impl ::Response for ConnectCoreV1GetNamespacedPodAttachResponse {
 
 // Generated from operation connectCoreV1GetNamespacedPodExec
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect GET requests to exec of Pod
     ///
@@ -341,6 +351,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_get_namespaced_pod_exec`](./struct.Pod.html#method.connect_core_v1_get_namespaced_pod_exec)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedPodExecResponse {
     Ok(String),
@@ -348,6 +359,7 @@ pub enum ConnectCoreV1GetNamespacedPodExecResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedPodExecResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -372,6 +384,7 @@ impl ::Response for ConnectCoreV1GetNamespacedPodExecResponse {
 
 // Generated from operation connectCoreV1GetNamespacedPodPortforward
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect GET requests to portforward of Pod
     ///
@@ -409,6 +422,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_get_namespaced_pod_portforward`](./struct.Pod.html#method.connect_core_v1_get_namespaced_pod_portforward)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedPodPortforwardResponse {
     Ok(String),
@@ -416,6 +430,7 @@ pub enum ConnectCoreV1GetNamespacedPodPortforwardResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedPodPortforwardResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -440,6 +455,7 @@ impl ::Response for ConnectCoreV1GetNamespacedPodPortforwardResponse {
 
 // Generated from operation connectCoreV1GetNamespacedPodProxy
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect GET requests to proxy of Pod
     ///
@@ -477,6 +493,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_get_namespaced_pod_proxy`](./struct.Pod.html#method.connect_core_v1_get_namespaced_pod_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedPodProxyResponse {
     Ok(String),
@@ -484,6 +501,7 @@ pub enum ConnectCoreV1GetNamespacedPodProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedPodProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -508,6 +526,7 @@ impl ::Response for ConnectCoreV1GetNamespacedPodProxyResponse {
 
 // Generated from operation connectCoreV1GetNamespacedPodProxyWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect GET requests to proxy of Pod
     ///
@@ -550,6 +569,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_get_namespaced_pod_proxy_with_path`](./struct.Pod.html#method.connect_core_v1_get_namespaced_pod_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedPodProxyWithPathResponse {
     Ok(String),
@@ -557,6 +577,7 @@ pub enum ConnectCoreV1GetNamespacedPodProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedPodProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -581,6 +602,7 @@ impl ::Response for ConnectCoreV1GetNamespacedPodProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PatchNamespacedPodProxy
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect PATCH requests to proxy of Pod
     ///
@@ -618,6 +640,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_patch_namespaced_pod_proxy`](./struct.Pod.html#method.connect_core_v1_patch_namespaced_pod_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNamespacedPodProxyResponse {
     Ok(String),
@@ -625,6 +648,7 @@ pub enum ConnectCoreV1PatchNamespacedPodProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNamespacedPodProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -649,6 +673,7 @@ impl ::Response for ConnectCoreV1PatchNamespacedPodProxyResponse {
 
 // Generated from operation connectCoreV1PatchNamespacedPodProxyWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect PATCH requests to proxy of Pod
     ///
@@ -691,6 +716,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_patch_namespaced_pod_proxy_with_path`](./struct.Pod.html#method.connect_core_v1_patch_namespaced_pod_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNamespacedPodProxyWithPathResponse {
     Ok(String),
@@ -698,6 +724,7 @@ pub enum ConnectCoreV1PatchNamespacedPodProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNamespacedPodProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -722,6 +749,7 @@ impl ::Response for ConnectCoreV1PatchNamespacedPodProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PostNamespacedPodAttach
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect POST requests to attach of Pod
     ///
@@ -791,6 +819,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_post_namespaced_pod_attach`](./struct.Pod.html#method.connect_core_v1_post_namespaced_pod_attach)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedPodAttachResponse {
     Ok(String),
@@ -798,6 +827,7 @@ pub enum ConnectCoreV1PostNamespacedPodAttachResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedPodAttachResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -822,6 +852,7 @@ impl ::Response for ConnectCoreV1PostNamespacedPodAttachResponse {
 
 // Generated from operation connectCoreV1PostNamespacedPodExec
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect POST requests to exec of Pod
     ///
@@ -899,6 +930,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_post_namespaced_pod_exec`](./struct.Pod.html#method.connect_core_v1_post_namespaced_pod_exec)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedPodExecResponse {
     Ok(String),
@@ -906,6 +938,7 @@ pub enum ConnectCoreV1PostNamespacedPodExecResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedPodExecResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -930,6 +963,7 @@ impl ::Response for ConnectCoreV1PostNamespacedPodExecResponse {
 
 // Generated from operation connectCoreV1PostNamespacedPodPortforward
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect POST requests to portforward of Pod
     ///
@@ -967,6 +1001,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_post_namespaced_pod_portforward`](./struct.Pod.html#method.connect_core_v1_post_namespaced_pod_portforward)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedPodPortforwardResponse {
     Ok(String),
@@ -974,6 +1009,7 @@ pub enum ConnectCoreV1PostNamespacedPodPortforwardResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedPodPortforwardResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -998,6 +1034,7 @@ impl ::Response for ConnectCoreV1PostNamespacedPodPortforwardResponse {
 
 // Generated from operation connectCoreV1PostNamespacedPodProxy
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect POST requests to proxy of Pod
     ///
@@ -1035,6 +1072,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_post_namespaced_pod_proxy`](./struct.Pod.html#method.connect_core_v1_post_namespaced_pod_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedPodProxyResponse {
     Ok(String),
@@ -1042,6 +1080,7 @@ pub enum ConnectCoreV1PostNamespacedPodProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedPodProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1066,6 +1105,7 @@ impl ::Response for ConnectCoreV1PostNamespacedPodProxyResponse {
 
 // Generated from operation connectCoreV1PostNamespacedPodProxyWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect POST requests to proxy of Pod
     ///
@@ -1108,6 +1148,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_post_namespaced_pod_proxy_with_path`](./struct.Pod.html#method.connect_core_v1_post_namespaced_pod_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedPodProxyWithPathResponse {
     Ok(String),
@@ -1115,6 +1156,7 @@ pub enum ConnectCoreV1PostNamespacedPodProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedPodProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1139,6 +1181,7 @@ impl ::Response for ConnectCoreV1PostNamespacedPodProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PutNamespacedPodProxy
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect PUT requests to proxy of Pod
     ///
@@ -1176,6 +1219,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_put_namespaced_pod_proxy`](./struct.Pod.html#method.connect_core_v1_put_namespaced_pod_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNamespacedPodProxyResponse {
     Ok(String),
@@ -1183,6 +1227,7 @@ pub enum ConnectCoreV1PutNamespacedPodProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNamespacedPodProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1207,6 +1252,7 @@ impl ::Response for ConnectCoreV1PutNamespacedPodProxyResponse {
 
 // Generated from operation connectCoreV1PutNamespacedPodProxyWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// connect PUT requests to proxy of Pod
     ///
@@ -1249,6 +1295,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::connect_core_v1_put_namespaced_pod_proxy_with_path`](./struct.Pod.html#method.connect_core_v1_put_namespaced_pod_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNamespacedPodProxyWithPathResponse {
     Ok(String),
@@ -1256,6 +1303,7 @@ pub enum ConnectCoreV1PutNamespacedPodProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNamespacedPodProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1280,6 +1328,7 @@ impl ::Response for ConnectCoreV1PutNamespacedPodProxyWithPathResponse {
 
 // Generated from operation createCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// create a Pod
     ///
@@ -1315,6 +1364,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::create_core_v1_namespaced_pod`](./struct.Pod.html#method.create_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedPodResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -1322,6 +1372,7 @@ pub enum CreateCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1337,10 +1388,15 @@ impl ::Response for CreateCoreV1NamespacedPodResponse {
             _ => Ok((CreateCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// delete collection of Pod
     ///
@@ -1421,6 +1477,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::delete_core_v1_collection_namespaced_pod`](./struct.Pod.html#method.delete_core_v1_collection_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedPodResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -1429,38 +1486,34 @@ pub enum DeleteCoreV1CollectionNamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedPodResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedPodResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedPodResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedPodResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedPodResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// delete a Pod
     ///
@@ -1524,6 +1577,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::delete_core_v1_namespaced_pod`](./struct.Pod.html#method.delete_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedPodResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -1532,38 +1586,34 @@ pub enum DeleteCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedPodResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedPodResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedPodResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedPodResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedPodResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// list or watch objects of kind Pod
     ///
@@ -1644,6 +1694,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::list_core_v1_namespaced_pod`](./struct.Pod.html#method.list_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedPodResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::PodList),
@@ -1651,6 +1702,7 @@ pub enum ListCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1666,10 +1718,27 @@ impl ::Response for ListCoreV1NamespacedPodResponse {
             _ => Ok((ListCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedPodResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::PodList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedPodResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1PodForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Pod {
     /// list or watch objects of kind Pod
     ///
@@ -1745,6 +1814,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::list_core_v1_pod_for_all_namespaces`](./struct.Pod.html#method.list_core_v1_pod_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1PodForAllNamespacesResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::PodList),
@@ -1752,6 +1822,7 @@ pub enum ListCoreV1PodForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1PodForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1767,10 +1838,27 @@ impl ::Response for ListCoreV1PodForAllNamespacesResponse {
             _ => Ok((ListCoreV1PodForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1PodForAllNamespacesResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::PodList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1PodForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// partially update the specified Pod
     ///
@@ -1811,6 +1899,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::patch_core_v1_namespaced_pod`](./struct.Pod.html#method.patch_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedPodResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -1818,6 +1907,7 @@ pub enum PatchCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1833,10 +1923,15 @@ impl ::Response for PatchCoreV1NamespacedPodResponse {
             _ => Ok((PatchCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedPodStatus
 
+#[cfg(feature = "api")]
 impl Pod {
     /// partially update status of the specified Pod
     ///
@@ -1877,6 +1972,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::patch_core_v1_namespaced_pod_status`](./struct.Pod.html#method.patch_core_v1_namespaced_pod_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedPodStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -1884,6 +1980,7 @@ pub enum PatchCoreV1NamespacedPodStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedPodStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1899,10 +1996,15 @@ impl ::Response for PatchCoreV1NamespacedPodStatusResponse {
             _ => Ok((PatchCoreV1NamespacedPodStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation proxyCoreV1DELETENamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy DELETE requests to Pod
     ///
@@ -1930,6 +2032,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_delete_namespaced_pod`](./struct.Pod.html#method.proxy_core_v1_delete_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1DELETENamespacedPodResponse {
     Ok(String),
@@ -1937,6 +2040,7 @@ pub enum ProxyCoreV1DELETENamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1DELETENamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1961,6 +2065,7 @@ impl ::Response for ProxyCoreV1DELETENamespacedPodResponse {
 
 // Generated from operation proxyCoreV1DELETENamespacedPodWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy DELETE requests to Pod
     ///
@@ -1993,6 +2098,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_delete_namespaced_pod_with_path`](./struct.Pod.html#method.proxy_core_v1_delete_namespaced_pod_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1DELETENamespacedPodWithPathResponse {
     Ok(String),
@@ -2000,6 +2106,7 @@ pub enum ProxyCoreV1DELETENamespacedPodWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1DELETENamespacedPodWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2024,6 +2131,7 @@ impl ::Response for ProxyCoreV1DELETENamespacedPodWithPathResponse {
 
 // Generated from operation proxyCoreV1GETNamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy GET requests to Pod
     ///
@@ -2051,6 +2159,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_get_namespaced_pod`](./struct.Pod.html#method.proxy_core_v1_get_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1GETNamespacedPodResponse {
     Ok(String),
@@ -2058,6 +2167,7 @@ pub enum ProxyCoreV1GETNamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1GETNamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2082,6 +2192,7 @@ impl ::Response for ProxyCoreV1GETNamespacedPodResponse {
 
 // Generated from operation proxyCoreV1GETNamespacedPodWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy GET requests to Pod
     ///
@@ -2114,6 +2225,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_get_namespaced_pod_with_path`](./struct.Pod.html#method.proxy_core_v1_get_namespaced_pod_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1GETNamespacedPodWithPathResponse {
     Ok(String),
@@ -2121,6 +2233,7 @@ pub enum ProxyCoreV1GETNamespacedPodWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1GETNamespacedPodWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2145,6 +2258,7 @@ impl ::Response for ProxyCoreV1GETNamespacedPodWithPathResponse {
 
 // Generated from operation proxyCoreV1PATCHNamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy PATCH requests to Pod
     ///
@@ -2172,6 +2286,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_patch_namespaced_pod`](./struct.Pod.html#method.proxy_core_v1_patch_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PATCHNamespacedPodResponse {
     Ok(String),
@@ -2179,6 +2294,7 @@ pub enum ProxyCoreV1PATCHNamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PATCHNamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2203,6 +2319,7 @@ impl ::Response for ProxyCoreV1PATCHNamespacedPodResponse {
 
 // Generated from operation proxyCoreV1PATCHNamespacedPodWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy PATCH requests to Pod
     ///
@@ -2235,6 +2352,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_patch_namespaced_pod_with_path`](./struct.Pod.html#method.proxy_core_v1_patch_namespaced_pod_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PATCHNamespacedPodWithPathResponse {
     Ok(String),
@@ -2242,6 +2360,7 @@ pub enum ProxyCoreV1PATCHNamespacedPodWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PATCHNamespacedPodWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2266,6 +2385,7 @@ impl ::Response for ProxyCoreV1PATCHNamespacedPodWithPathResponse {
 
 // Generated from operation proxyCoreV1POSTNamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy POST requests to Pod
     ///
@@ -2293,6 +2413,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_post_namespaced_pod`](./struct.Pod.html#method.proxy_core_v1_post_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1POSTNamespacedPodResponse {
     Ok(String),
@@ -2300,6 +2421,7 @@ pub enum ProxyCoreV1POSTNamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1POSTNamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2324,6 +2446,7 @@ impl ::Response for ProxyCoreV1POSTNamespacedPodResponse {
 
 // Generated from operation proxyCoreV1POSTNamespacedPodWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy POST requests to Pod
     ///
@@ -2356,6 +2479,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_post_namespaced_pod_with_path`](./struct.Pod.html#method.proxy_core_v1_post_namespaced_pod_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1POSTNamespacedPodWithPathResponse {
     Ok(String),
@@ -2363,6 +2487,7 @@ pub enum ProxyCoreV1POSTNamespacedPodWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1POSTNamespacedPodWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2387,6 +2512,7 @@ impl ::Response for ProxyCoreV1POSTNamespacedPodWithPathResponse {
 
 // Generated from operation proxyCoreV1PUTNamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy PUT requests to Pod
     ///
@@ -2414,6 +2540,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_put_namespaced_pod`](./struct.Pod.html#method.proxy_core_v1_put_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PUTNamespacedPodResponse {
     Ok(String),
@@ -2421,6 +2548,7 @@ pub enum ProxyCoreV1PUTNamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PUTNamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2445,6 +2573,7 @@ impl ::Response for ProxyCoreV1PUTNamespacedPodResponse {
 
 // Generated from operation proxyCoreV1PUTNamespacedPodWithPath
 
+#[cfg(feature = "api")]
 impl Pod {
     /// proxy PUT requests to Pod
     ///
@@ -2477,6 +2606,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::proxy_core_v1_put_namespaced_pod_with_path`](./struct.Pod.html#method.proxy_core_v1_put_namespaced_pod_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PUTNamespacedPodWithPathResponse {
     Ok(String),
@@ -2484,6 +2614,7 @@ pub enum ProxyCoreV1PUTNamespacedPodWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PUTNamespacedPodWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2508,6 +2639,7 @@ impl ::Response for ProxyCoreV1PUTNamespacedPodWithPathResponse {
 
 // Generated from operation readCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// read the specified Pod
     ///
@@ -2561,6 +2693,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::read_core_v1_namespaced_pod`](./struct.Pod.html#method.read_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedPodResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -2568,6 +2701,7 @@ pub enum ReadCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2583,10 +2717,15 @@ impl ::Response for ReadCoreV1NamespacedPodResponse {
             _ => Ok((ReadCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedPodLog
 
+#[cfg(feature = "api")]
 impl Pod {
     /// read log of the specified Pod
     ///
@@ -2680,6 +2819,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::read_core_v1_namespaced_pod_log`](./struct.Pod.html#method.read_core_v1_namespaced_pod_log)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedPodLogResponse {
     Ok(String),
@@ -2687,6 +2827,7 @@ pub enum ReadCoreV1NamespacedPodLogResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedPodLogResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2711,6 +2852,7 @@ impl ::Response for ReadCoreV1NamespacedPodLogResponse {
 
 // Generated from operation readCoreV1NamespacedPodStatus
 
+#[cfg(feature = "api")]
 impl Pod {
     /// read status of the specified Pod
     ///
@@ -2748,6 +2890,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::read_core_v1_namespaced_pod_status`](./struct.Pod.html#method.read_core_v1_namespaced_pod_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedPodStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -2755,6 +2898,7 @@ pub enum ReadCoreV1NamespacedPodStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedPodStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2770,10 +2914,15 @@ impl ::Response for ReadCoreV1NamespacedPodStatusResponse {
             _ => Ok((ReadCoreV1NamespacedPodStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// replace the specified Pod
     ///
@@ -2814,6 +2963,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::replace_core_v1_namespaced_pod`](./struct.Pod.html#method.replace_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedPodResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -2821,6 +2971,7 @@ pub enum ReplaceCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2836,10 +2987,15 @@ impl ::Response for ReplaceCoreV1NamespacedPodResponse {
             _ => Ok((ReplaceCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedPodStatus
 
+#[cfg(feature = "api")]
 impl Pod {
     /// replace status of the specified Pod
     ///
@@ -2880,6 +3036,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::replace_core_v1_namespaced_pod_status`](./struct.Pod.html#method.replace_core_v1_namespaced_pod_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedPodStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Pod),
@@ -2887,6 +3044,7 @@ pub enum ReplaceCoreV1NamespacedPodStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedPodStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2902,10 +3060,15 @@ impl ::Response for ReplaceCoreV1NamespacedPodStatusResponse {
             _ => Ok((ReplaceCoreV1NamespacedPodStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedPod
 
+#[cfg(feature = "api")]
 impl Pod {
     /// watch changes to an object of kind Pod
     ///
@@ -2991,6 +3154,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::watch_core_v1_namespaced_pod`](./struct.Pod.html#method.watch_core_v1_namespaced_pod)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedPodResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -2998,27 +3162,31 @@ pub enum WatchCoreV1NamespacedPodResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedPodResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedPodResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedPodResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedPodResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedPodResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedPodList
 
+#[cfg(feature = "api")]
 impl Pod {
     /// watch individual changes to a list of Pod
     ///
@@ -3099,6 +3267,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::watch_core_v1_namespaced_pod_list`](./struct.Pod.html#method.watch_core_v1_namespaced_pod_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedPodListResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -3106,27 +3275,31 @@ pub enum WatchCoreV1NamespacedPodListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedPodListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedPodListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedPodListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedPodListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedPodListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1PodListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Pod {
     /// watch individual changes to a list of Pod
     ///
@@ -3202,6 +3375,7 @@ impl Pod {
 }
 
 /// Parses the HTTP response of [`Pod::watch_core_v1_pod_list_for_all_namespaces`](./struct.Pod.html#method.watch_core_v1_pod_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1PodListForAllNamespacesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -3209,23 +3383,26 @@ pub enum WatchCoreV1PodListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1PodListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1PodListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1PodListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1PodListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1PodListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Pod
@@ -3349,3 +3526,50 @@ impl ::serde::Serialize for Pod {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Pod`](./struct.Pod.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PodField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+
+    /// `spec.nodeName`
+    SpecNodeName,
+
+    /// `spec.restartPolicy`
+    SpecRestartPolicy,
+
+    /// `status.phase`
+    StatusPhase,
+
+    /// `status.podIP`
+    StatusPodIP,
+}
+
+impl ::field_selector::Field for PodField {
+    fn path(&self) -> &'static str {
+        match self {
+            PodField::MetadataName => "metadata.name",
+            PodField::MetadataNamespace => "metadata.namespace",
+            PodField::SpecNodeName => "spec.nodeName",
+            PodField::SpecRestartPolicy => "spec.restartPolicy",
+            PodField::StatusPhase => "status.phase",
+            PodField::StatusPodIP => "status.podIP",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(PodField::MetadataName),
+            "metadata.namespace" => Some(PodField::MetadataNamespace),
+            "spec.nodeName" => Some(PodField::SpecNodeName),
+            "spec.restartPolicy" => Some(PodField::SpecRestartPolicy),
+            "status.phase" => Some(PodField::StatusPhase),
+            "status.podIP" => Some(PodField::StatusPodIP),
+            _ => None,
+        }
+    }
+}
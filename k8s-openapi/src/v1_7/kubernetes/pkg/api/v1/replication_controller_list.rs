@@ -123,3 +123,15 @@ impl ::serde::Serialize for ReplicationControllerList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for ReplicationControllerList {
+    type Item = ::v1_7::kubernetes::pkg::api::v1::ReplicationController;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
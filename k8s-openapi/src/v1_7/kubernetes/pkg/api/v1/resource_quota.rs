@@ -23,6 +23,7 @@ pub struct ResourceQuota {
 
 // Generated from operation createCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// create a ResourceQuota
     ///
@@ -58,6 +59,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::create_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.create_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedResourceQuotaResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -65,6 +67,7 @@ pub enum CreateCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -80,10 +83,15 @@ impl ::Response for CreateCoreV1NamespacedResourceQuotaResponse {
             _ => Ok((CreateCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1CollectionNamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// delete collection of ResourceQuota
     ///
@@ -164,6 +172,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::delete_core_v1_collection_namespaced_resource_quota`](./struct.ResourceQuota.html#method.delete_core_v1_collection_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1CollectionNamespacedResourceQuotaResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -172,38 +181,34 @@ pub enum DeleteCoreV1CollectionNamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1CollectionNamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedResourceQuotaResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1CollectionNamespacedResourceQuotaResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1CollectionNamespacedResourceQuotaResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1CollectionNamespacedResourceQuotaResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1CollectionNamespacedResourceQuotaResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1CollectionNamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// delete a ResourceQuota
     ///
@@ -267,6 +272,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::delete_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.delete_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedResourceQuotaResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -275,38 +281,34 @@ pub enum DeleteCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedResourceQuotaResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedResourceQuotaResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedResourceQuotaResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedResourceQuotaResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedResourceQuotaResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// list or watch objects of kind ResourceQuota
     ///
@@ -387,6 +389,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::list_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.list_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedResourceQuotaResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuotaList),
@@ -394,6 +397,7 @@ pub enum ListCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -409,10 +413,27 @@ impl ::Response for ListCoreV1NamespacedResourceQuotaResponse {
             _ => Ok((ListCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedResourceQuotaResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::ResourceQuotaList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedResourceQuotaResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1ResourceQuotaForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// list or watch objects of kind ResourceQuota
     ///
@@ -488,6 +509,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::list_core_v1_resource_quota_for_all_namespaces`](./struct.ResourceQuota.html#method.list_core_v1_resource_quota_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ResourceQuotaForAllNamespacesResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuotaList),
@@ -495,6 +517,7 @@ pub enum ListCoreV1ResourceQuotaForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ResourceQuotaForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -510,10 +533,27 @@ impl ::Response for ListCoreV1ResourceQuotaForAllNamespacesResponse {
             _ => Ok((ListCoreV1ResourceQuotaForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ResourceQuotaForAllNamespacesResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::ResourceQuotaList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ResourceQuotaForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// partially update the specified ResourceQuota
     ///
@@ -554,6 +594,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::patch_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.patch_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedResourceQuotaResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -561,6 +602,7 @@ pub enum PatchCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -576,10 +618,15 @@ impl ::Response for PatchCoreV1NamespacedResourceQuotaResponse {
             _ => Ok((PatchCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedResourceQuotaStatus
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// partially update status of the specified ResourceQuota
     ///
@@ -620,6 +667,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::patch_core_v1_namespaced_resource_quota_status`](./struct.ResourceQuota.html#method.patch_core_v1_namespaced_resource_quota_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedResourceQuotaStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -627,6 +675,7 @@ pub enum PatchCoreV1NamespacedResourceQuotaStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedResourceQuotaStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -642,10 +691,15 @@ impl ::Response for PatchCoreV1NamespacedResourceQuotaStatusResponse {
             _ => Ok((PatchCoreV1NamespacedResourceQuotaStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// read the specified ResourceQuota
     ///
@@ -699,6 +753,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::read_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.read_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedResourceQuotaResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -706,6 +761,7 @@ pub enum ReadCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -721,10 +777,15 @@ impl ::Response for ReadCoreV1NamespacedResourceQuotaResponse {
             _ => Ok((ReadCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedResourceQuotaStatus
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// read status of the specified ResourceQuota
     ///
@@ -762,6 +823,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::read_core_v1_namespaced_resource_quota_status`](./struct.ResourceQuota.html#method.read_core_v1_namespaced_resource_quota_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedResourceQuotaStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -769,6 +831,7 @@ pub enum ReadCoreV1NamespacedResourceQuotaStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedResourceQuotaStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -784,10 +847,15 @@ impl ::Response for ReadCoreV1NamespacedResourceQuotaStatusResponse {
             _ => Ok((ReadCoreV1NamespacedResourceQuotaStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// replace the specified ResourceQuota
     ///
@@ -828,6 +896,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::replace_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.replace_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedResourceQuotaResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -835,6 +904,7 @@ pub enum ReplaceCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -850,10 +920,15 @@ impl ::Response for ReplaceCoreV1NamespacedResourceQuotaResponse {
             _ => Ok((ReplaceCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedResourceQuotaStatus
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// replace status of the specified ResourceQuota
     ///
@@ -894,6 +969,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::replace_core_v1_namespaced_resource_quota_status`](./struct.ResourceQuota.html#method.replace_core_v1_namespaced_resource_quota_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedResourceQuotaStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ResourceQuota),
@@ -901,6 +977,7 @@ pub enum ReplaceCoreV1NamespacedResourceQuotaStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedResourceQuotaStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -916,10 +993,15 @@ impl ::Response for ReplaceCoreV1NamespacedResourceQuotaStatusResponse {
             _ => Ok((ReplaceCoreV1NamespacedResourceQuotaStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedResourceQuota
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// watch changes to an object of kind ResourceQuota
     ///
@@ -1005,6 +1087,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::watch_core_v1_namespaced_resource_quota`](./struct.ResourceQuota.html#method.watch_core_v1_namespaced_resource_quota)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedResourceQuotaResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1012,27 +1095,31 @@ pub enum WatchCoreV1NamespacedResourceQuotaResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedResourceQuotaResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedResourceQuotaResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedResourceQuotaResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedResourceQuotaResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedResourceQuotaResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedResourceQuotaList
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// watch individual changes to a list of ResourceQuota
     ///
@@ -1113,6 +1200,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::watch_core_v1_namespaced_resource_quota_list`](./struct.ResourceQuota.html#method.watch_core_v1_namespaced_resource_quota_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedResourceQuotaListResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1120,27 +1208,31 @@ pub enum WatchCoreV1NamespacedResourceQuotaListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedResourceQuotaListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedResourceQuotaListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedResourceQuotaListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedResourceQuotaListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedResourceQuotaListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1ResourceQuotaListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl ResourceQuota {
     /// watch individual changes to a list of ResourceQuota
     ///
@@ -1216,6 +1308,7 @@ impl ResourceQuota {
 }
 
 /// Parses the HTTP response of [`ResourceQuota::watch_core_v1_resource_quota_list_for_all_namespaces`](./struct.ResourceQuota.html#method.watch_core_v1_resource_quota_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1ResourceQuotaListForAllNamespacesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -1223,23 +1316,26 @@ pub enum WatchCoreV1ResourceQuotaListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1ResourceQuotaListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1ResourceQuotaListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1ResourceQuotaListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1ResourceQuotaListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1ResourceQuotaListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/ResourceQuota
@@ -1363,3 +1459,30 @@ impl ::serde::Serialize for ResourceQuota {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ResourceQuota`](./struct.ResourceQuota.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResourceQuotaField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for ResourceQuotaField {
+    fn path(&self) -> &'static str {
+        match self {
+            ResourceQuotaField::MetadataName => "metadata.name",
+            ResourceQuotaField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ResourceQuotaField::MetadataName),
+            "metadata.namespace" => Some(ResourceQuotaField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
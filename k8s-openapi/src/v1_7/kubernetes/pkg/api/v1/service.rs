@@ -23,6 +23,7 @@ pub struct Service {
 
 // Generated from operation connectCoreV1DeleteNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect DELETE requests to proxy of Service
     ///
@@ -60,6 +61,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_delete_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_delete_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNamespacedServiceProxyResponse {
     Ok(String),
@@ -67,6 +69,7 @@ pub enum ConnectCoreV1DeleteNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -91,6 +94,7 @@ impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1DeleteNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect DELETE requests to proxy of Service
     ///
@@ -133,6 +137,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_delete_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_delete_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -140,6 +145,7 @@ pub enum ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -164,6 +170,7 @@ impl ::Response for ConnectCoreV1DeleteNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1GetNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect GET requests to proxy of Service
     ///
@@ -201,6 +208,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_get_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_get_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedServiceProxyResponse {
     Ok(String),
@@ -208,6 +216,7 @@ pub enum ConnectCoreV1GetNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -232,6 +241,7 @@ impl ::Response for ConnectCoreV1GetNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1GetNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect GET requests to proxy of Service
     ///
@@ -274,6 +284,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_get_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_get_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -281,6 +292,7 @@ pub enum ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -305,6 +317,7 @@ impl ::Response for ConnectCoreV1GetNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PatchNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PATCH requests to proxy of Service
     ///
@@ -342,6 +355,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_patch_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_patch_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNamespacedServiceProxyResponse {
     Ok(String),
@@ -349,6 +363,7 @@ pub enum ConnectCoreV1PatchNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -373,6 +388,7 @@ impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1PatchNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PATCH requests to proxy of Service
     ///
@@ -415,6 +431,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_patch_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_patch_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -422,6 +439,7 @@ pub enum ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -446,6 +464,7 @@ impl ::Response for ConnectCoreV1PatchNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PostNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect POST requests to proxy of Service
     ///
@@ -483,6 +502,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_post_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_post_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedServiceProxyResponse {
     Ok(String),
@@ -490,6 +510,7 @@ pub enum ConnectCoreV1PostNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -514,6 +535,7 @@ impl ::Response for ConnectCoreV1PostNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1PostNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect POST requests to proxy of Service
     ///
@@ -556,6 +578,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_post_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_post_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -563,6 +586,7 @@ pub enum ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -587,6 +611,7 @@ impl ::Response for ConnectCoreV1PostNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation connectCoreV1PutNamespacedServiceProxy
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PUT requests to proxy of Service
     ///
@@ -624,6 +649,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_put_namespaced_service_proxy`](./struct.Service.html#method.connect_core_v1_put_namespaced_service_proxy)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNamespacedServiceProxyResponse {
     Ok(String),
@@ -631,6 +657,7 @@ pub enum ConnectCoreV1PutNamespacedServiceProxyResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNamespacedServiceProxyResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -655,6 +682,7 @@ impl ::Response for ConnectCoreV1PutNamespacedServiceProxyResponse {
 
 // Generated from operation connectCoreV1PutNamespacedServiceProxyWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// connect PUT requests to proxy of Service
     ///
@@ -697,6 +725,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::connect_core_v1_put_namespaced_service_proxy_with_path`](./struct.Service.html#method.connect_core_v1_put_namespaced_service_proxy_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
     Ok(String),
@@ -704,6 +733,7 @@ pub enum ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -728,6 +758,7 @@ impl ::Response for ConnectCoreV1PutNamespacedServiceProxyWithPathResponse {
 
 // Generated from operation createCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// create a Service
     ///
@@ -763,6 +794,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::create_core_v1_namespaced_service`](./struct.Service.html#method.create_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedServiceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -770,6 +802,7 @@ pub enum CreateCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -785,10 +818,15 @@ impl ::Response for CreateCoreV1NamespacedServiceResponse {
             _ => Ok((CreateCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// delete a Service
     ///
@@ -826,6 +864,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::delete_core_v1_namespaced_service`](./struct.Service.html#method.delete_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteCoreV1NamespacedServiceResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -834,38 +873,34 @@ pub enum DeleteCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedServiceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteCoreV1NamespacedServiceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteCoreV1NamespacedServiceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteCoreV1NamespacedServiceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteCoreV1NamespacedServiceResponse::Unauthorized, 0)),
             _ => Ok((DeleteCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// list or watch objects of kind Service
     ///
@@ -946,6 +981,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::list_core_v1_namespaced_service`](./struct.Service.html#method.list_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1NamespacedServiceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ServiceList),
@@ -953,6 +989,7 @@ pub enum ListCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -968,10 +1005,27 @@ impl ::Response for ListCoreV1NamespacedServiceResponse {
             _ => Ok((ListCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1NamespacedServiceResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::ServiceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1NamespacedServiceResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation listCoreV1ServiceForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Service {
     /// list or watch objects of kind Service
     ///
@@ -1047,6 +1101,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::list_core_v1_service_for_all_namespaces`](./struct.Service.html#method.list_core_v1_service_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ServiceForAllNamespacesResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::ServiceList),
@@ -1054,6 +1109,7 @@ pub enum ListCoreV1ServiceForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ServiceForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1069,10 +1125,27 @@ impl ::Response for ListCoreV1ServiceForAllNamespacesResponse {
             _ => Ok((ListCoreV1ServiceForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ServiceForAllNamespacesResponse {
+    type List = ::v1_7::kubernetes::pkg::api::v1::ServiceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ServiceForAllNamespacesResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// partially update the specified Service
     ///
@@ -1113,6 +1186,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::patch_core_v1_namespaced_service`](./struct.Service.html#method.patch_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedServiceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -1120,6 +1194,7 @@ pub enum PatchCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1135,10 +1210,15 @@ impl ::Response for PatchCoreV1NamespacedServiceResponse {
             _ => Ok((PatchCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation patchCoreV1NamespacedServiceStatus
 
+#[cfg(feature = "api")]
 impl Service {
     /// partially update status of the specified Service
     ///
@@ -1179,6 +1259,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::patch_core_v1_namespaced_service_status`](./struct.Service.html#method.patch_core_v1_namespaced_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchCoreV1NamespacedServiceStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -1186,6 +1267,7 @@ pub enum PatchCoreV1NamespacedServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchCoreV1NamespacedServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1201,10 +1283,15 @@ impl ::Response for PatchCoreV1NamespacedServiceStatusResponse {
             _ => Ok((PatchCoreV1NamespacedServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation proxyCoreV1DELETENamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy DELETE requests to Service
     ///
@@ -1232,6 +1319,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_delete_namespaced_service`](./struct.Service.html#method.proxy_core_v1_delete_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1DELETENamespacedServiceResponse {
     Ok(String),
@@ -1239,6 +1327,7 @@ pub enum ProxyCoreV1DELETENamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1DELETENamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1263,6 +1352,7 @@ impl ::Response for ProxyCoreV1DELETENamespacedServiceResponse {
 
 // Generated from operation proxyCoreV1DELETENamespacedServiceWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy DELETE requests to Service
     ///
@@ -1295,6 +1385,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_delete_namespaced_service_with_path`](./struct.Service.html#method.proxy_core_v1_delete_namespaced_service_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1DELETENamespacedServiceWithPathResponse {
     Ok(String),
@@ -1302,6 +1393,7 @@ pub enum ProxyCoreV1DELETENamespacedServiceWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1DELETENamespacedServiceWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1326,6 +1418,7 @@ impl ::Response for ProxyCoreV1DELETENamespacedServiceWithPathResponse {
 
 // Generated from operation proxyCoreV1GETNamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy GET requests to Service
     ///
@@ -1353,6 +1446,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_get_namespaced_service`](./struct.Service.html#method.proxy_core_v1_get_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1GETNamespacedServiceResponse {
     Ok(String),
@@ -1360,6 +1454,7 @@ pub enum ProxyCoreV1GETNamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1GETNamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1384,6 +1479,7 @@ impl ::Response for ProxyCoreV1GETNamespacedServiceResponse {
 
 // Generated from operation proxyCoreV1GETNamespacedServiceWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy GET requests to Service
     ///
@@ -1416,6 +1512,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_get_namespaced_service_with_path`](./struct.Service.html#method.proxy_core_v1_get_namespaced_service_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1GETNamespacedServiceWithPathResponse {
     Ok(String),
@@ -1423,6 +1520,7 @@ pub enum ProxyCoreV1GETNamespacedServiceWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1GETNamespacedServiceWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1447,6 +1545,7 @@ impl ::Response for ProxyCoreV1GETNamespacedServiceWithPathResponse {
 
 // Generated from operation proxyCoreV1PATCHNamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy PATCH requests to Service
     ///
@@ -1474,6 +1573,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_patch_namespaced_service`](./struct.Service.html#method.proxy_core_v1_patch_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PATCHNamespacedServiceResponse {
     Ok(String),
@@ -1481,6 +1581,7 @@ pub enum ProxyCoreV1PATCHNamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PATCHNamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1505,6 +1606,7 @@ impl ::Response for ProxyCoreV1PATCHNamespacedServiceResponse {
 
 // Generated from operation proxyCoreV1PATCHNamespacedServiceWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy PATCH requests to Service
     ///
@@ -1537,6 +1639,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_patch_namespaced_service_with_path`](./struct.Service.html#method.proxy_core_v1_patch_namespaced_service_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PATCHNamespacedServiceWithPathResponse {
     Ok(String),
@@ -1544,6 +1647,7 @@ pub enum ProxyCoreV1PATCHNamespacedServiceWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PATCHNamespacedServiceWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1568,6 +1672,7 @@ impl ::Response for ProxyCoreV1PATCHNamespacedServiceWithPathResponse {
 
 // Generated from operation proxyCoreV1POSTNamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy POST requests to Service
     ///
@@ -1595,6 +1700,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_post_namespaced_service`](./struct.Service.html#method.proxy_core_v1_post_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1POSTNamespacedServiceResponse {
     Ok(String),
@@ -1602,6 +1708,7 @@ pub enum ProxyCoreV1POSTNamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1POSTNamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1626,6 +1733,7 @@ impl ::Response for ProxyCoreV1POSTNamespacedServiceResponse {
 
 // Generated from operation proxyCoreV1POSTNamespacedServiceWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy POST requests to Service
     ///
@@ -1658,6 +1766,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_post_namespaced_service_with_path`](./struct.Service.html#method.proxy_core_v1_post_namespaced_service_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1POSTNamespacedServiceWithPathResponse {
     Ok(String),
@@ -1665,6 +1774,7 @@ pub enum ProxyCoreV1POSTNamespacedServiceWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1POSTNamespacedServiceWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1689,6 +1799,7 @@ impl ::Response for ProxyCoreV1POSTNamespacedServiceWithPathResponse {
 
 // Generated from operation proxyCoreV1PUTNamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy PUT requests to Service
     ///
@@ -1716,6 +1827,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_put_namespaced_service`](./struct.Service.html#method.proxy_core_v1_put_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PUTNamespacedServiceResponse {
     Ok(String),
@@ -1723,6 +1835,7 @@ pub enum ProxyCoreV1PUTNamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PUTNamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1747,6 +1860,7 @@ impl ::Response for ProxyCoreV1PUTNamespacedServiceResponse {
 
 // Generated from operation proxyCoreV1PUTNamespacedServiceWithPath
 
+#[cfg(feature = "api")]
 impl Service {
     /// proxy PUT requests to Service
     ///
@@ -1779,6 +1893,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::proxy_core_v1_put_namespaced_service_with_path`](./struct.Service.html#method.proxy_core_v1_put_namespaced_service_with_path)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ProxyCoreV1PUTNamespacedServiceWithPathResponse {
     Ok(String),
@@ -1786,6 +1901,7 @@ pub enum ProxyCoreV1PUTNamespacedServiceWithPathResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ProxyCoreV1PUTNamespacedServiceWithPathResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1810,6 +1926,7 @@ impl ::Response for ProxyCoreV1PUTNamespacedServiceWithPathResponse {
 
 // Generated from operation readCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// read the specified Service
     ///
@@ -1863,6 +1980,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::read_core_v1_namespaced_service`](./struct.Service.html#method.read_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedServiceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -1870,6 +1988,7 @@ pub enum ReadCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1885,10 +2004,15 @@ impl ::Response for ReadCoreV1NamespacedServiceResponse {
             _ => Ok((ReadCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readCoreV1NamespacedServiceStatus
 
+#[cfg(feature = "api")]
 impl Service {
     /// read status of the specified Service
     ///
@@ -1926,6 +2050,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::read_core_v1_namespaced_service_status`](./struct.Service.html#method.read_core_v1_namespaced_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1NamespacedServiceStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -1933,6 +2058,7 @@ pub enum ReadCoreV1NamespacedServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1NamespacedServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1948,10 +2074,15 @@ impl ::Response for ReadCoreV1NamespacedServiceStatusResponse {
             _ => Ok((ReadCoreV1NamespacedServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// replace the specified Service
     ///
@@ -1992,6 +2123,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::replace_core_v1_namespaced_service`](./struct.Service.html#method.replace_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedServiceResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -1999,6 +2131,7 @@ pub enum ReplaceCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2014,10 +2147,15 @@ impl ::Response for ReplaceCoreV1NamespacedServiceResponse {
             _ => Ok((ReplaceCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceCoreV1NamespacedServiceStatus
 
+#[cfg(feature = "api")]
 impl Service {
     /// replace status of the specified Service
     ///
@@ -2058,6 +2196,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::replace_core_v1_namespaced_service_status`](./struct.Service.html#method.replace_core_v1_namespaced_service_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceCoreV1NamespacedServiceStatusResponse {
     Ok(::v1_7::kubernetes::pkg::api::v1::Service),
@@ -2065,6 +2204,7 @@ pub enum ReplaceCoreV1NamespacedServiceStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceCoreV1NamespacedServiceStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -2080,10 +2220,15 @@ impl ::Response for ReplaceCoreV1NamespacedServiceStatusResponse {
             _ => Ok((ReplaceCoreV1NamespacedServiceStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedService
 
+#[cfg(feature = "api")]
 impl Service {
     /// watch changes to an object of kind Service
     ///
@@ -2169,6 +2314,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::watch_core_v1_namespaced_service`](./struct.Service.html#method.watch_core_v1_namespaced_service)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedServiceResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -2176,27 +2322,31 @@ pub enum WatchCoreV1NamespacedServiceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedServiceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedServiceResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedServiceResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedServiceResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedServiceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1NamespacedServiceList
 
+#[cfg(feature = "api")]
 impl Service {
     /// watch individual changes to a list of Service
     ///
@@ -2277,6 +2427,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::watch_core_v1_namespaced_service_list`](./struct.Service.html#method.watch_core_v1_namespaced_service_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1NamespacedServiceListResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -2284,27 +2435,31 @@ pub enum WatchCoreV1NamespacedServiceListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1NamespacedServiceListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1NamespacedServiceListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1NamespacedServiceListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1NamespacedServiceListResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1NamespacedServiceListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchCoreV1ServiceListForAllNamespaces
 
+#[cfg(feature = "api")]
 impl Service {
     /// watch individual changes to a list of Service
     ///
@@ -2380,6 +2535,7 @@ impl Service {
 }
 
 /// Parses the HTTP response of [`Service::watch_core_v1_service_list_for_all_namespaces`](./struct.Service.html#method.watch_core_v1_service_list_for_all_namespaces)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchCoreV1ServiceListForAllNamespacesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -2387,23 +2543,26 @@ pub enum WatchCoreV1ServiceListForAllNamespacesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchCoreV1ServiceListForAllNamespacesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Unauthorized, 0)),
             _ => Ok((WatchCoreV1ServiceListForAllNamespacesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End /v1/Service
@@ -2527,3 +2686,30 @@ impl ::serde::Serialize for Service {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`Service`](./struct.Service.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ServiceField {
+    /// `metadata.name`
+    MetadataName,
+
+    /// `metadata.namespace`
+    MetadataNamespace,
+}
+
+impl ::field_selector::Field for ServiceField {
+    fn path(&self) -> &'static str {
+        match self {
+            ServiceField::MetadataName => "metadata.name",
+            ServiceField::MetadataNamespace => "metadata.namespace",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ServiceField::MetadataName),
+            "metadata.namespace" => Some(ServiceField::MetadataNamespace),
+            _ => None,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for ServiceAccountList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for ServiceAccountList {
+    type Item = ::v1_7::kubernetes::pkg::api::v1::ServiceAccount;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
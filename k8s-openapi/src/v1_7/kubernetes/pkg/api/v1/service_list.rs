@@ -123,3 +123,15 @@ impl ::serde::Serialize for ServiceList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for ServiceList {
+    type Item = ::v1_7::kubernetes::pkg::api::v1::Service;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
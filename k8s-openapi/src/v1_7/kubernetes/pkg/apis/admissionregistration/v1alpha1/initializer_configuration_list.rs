@@ -123,3 +123,15 @@ impl ::serde::Serialize for InitializerConfigurationList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for InitializerConfigurationList {
+    type Item = ::v1_7::kubernetes::pkg::apis::admissionregistration::v1alpha1::InitializerConfiguration;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
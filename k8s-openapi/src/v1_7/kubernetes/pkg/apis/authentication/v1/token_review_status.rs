@@ -112,3 +112,14 @@ impl ::serde::Serialize for TokenReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-authentication-v1beta1")]
+impl From<::v1_7::kubernetes::pkg::apis::authentication::v1beta1::TokenReviewStatus> for TokenReviewStatus {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::authentication::v1beta1::TokenReviewStatus) -> Self {
+        TokenReviewStatus {
+            authenticated: value.authenticated,
+            error: value.error,
+            user: value.user.map(Into::into),
+        }
+    }
+}
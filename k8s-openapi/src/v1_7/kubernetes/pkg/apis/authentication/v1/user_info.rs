@@ -125,3 +125,15 @@ impl ::serde::Serialize for UserInfo {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-authentication-v1beta1")]
+impl From<::v1_7::kubernetes::pkg::apis::authentication::v1beta1::UserInfo> for UserInfo {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::authentication::v1beta1::UserInfo) -> Self {
+        UserInfo {
+            extra: value.extra,
+            groups: value.groups,
+            uid: value.uid,
+            username: value.username,
+        }
+    }
+}
@@ -110,3 +110,14 @@ impl ::serde::Serialize for SubjectAccessReviewStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-authorization-v1")]
+impl From<::v1_7::kubernetes::pkg::apis::authorization::v1::SubjectAccessReviewStatus> for SubjectAccessReviewStatus {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::authorization::v1::SubjectAccessReviewStatus) -> Self {
+        SubjectAccessReviewStatus {
+            allowed: value.allowed,
+            evaluation_error: value.evaluation_error,
+            reason: value.reason,
+        }
+    }
+}
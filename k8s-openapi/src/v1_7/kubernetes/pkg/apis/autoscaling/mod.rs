@@ -1,3 +1,5 @@
+#[cfg(feature = "v1_7-autoscaling-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_7-autoscaling-v2alpha1")]
 pub mod v2alpha1;
@@ -123,3 +123,15 @@ impl ::serde::Serialize for JobList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for JobList {
+    type Item = ::v1_7::kubernetes::pkg::apis::batch::v1::Job;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -1 +1,2 @@
+#[cfg(feature = "v1_7-certificates-v1beta1")]
 pub mod v1beta1;
@@ -120,3 +120,15 @@ impl ::serde::Serialize for CertificateSigningRequestList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for CertificateSigningRequestList {
+    type Item = ::v1_7::kubernetes::pkg::apis::certificates::v1beta1::CertificateSigningRequest;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for IngressList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for IngressList {
+    type Item = ::v1_7::kubernetes::pkg::apis::extensions::v1beta1::Ingress;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
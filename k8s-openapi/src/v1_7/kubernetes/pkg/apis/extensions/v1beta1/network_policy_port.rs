@@ -98,3 +98,13 @@ impl ::serde::Serialize for NetworkPolicyPort {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-networking-v1")]
+impl From<::v1_7::kubernetes::pkg::apis::networking::v1::NetworkPolicyPort> for NetworkPolicyPort {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::networking::v1::NetworkPolicyPort) -> Self {
+        NetworkPolicyPort {
+            port: value.port,
+            protocol: value.protocol,
+        }
+    }
+}
@@ -96,3 +96,13 @@ impl ::serde::Serialize for NetworkPolicySpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-networking-v1")]
+impl From<::v1_7::kubernetes::pkg::apis::networking::v1::NetworkPolicySpec> for NetworkPolicySpec {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::networking::v1::NetworkPolicySpec) -> Self {
+        NetworkPolicySpec {
+            ingress: value.ingress.map(|value| value.into_iter().map(Into::into).collect()),
+            pod_selector: value.pod_selector,
+        }
+    }
+}
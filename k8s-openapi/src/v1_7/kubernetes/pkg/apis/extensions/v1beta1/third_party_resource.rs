@@ -23,6 +23,7 @@ pub struct ThirdPartyResource {
 
 // Generated from operation createExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// create a ThirdPartyResource
     ///
@@ -53,6 +54,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::create_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.create_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateExtensionsV1beta1ThirdPartyResourceResponse {
     Ok(::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResource),
@@ -60,6 +62,7 @@ pub enum CreateExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -75,10 +78,15 @@ impl ::Response for CreateExtensionsV1beta1ThirdPartyResourceResponse {
             _ => Ok((CreateExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1CollectionThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// delete collection of ThirdPartyResource
     ///
@@ -154,6 +162,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::delete_extensions_v1beta1_collection_third_party_resource`](./struct.ThirdPartyResource.html#method.delete_extensions_v1beta1_collection_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -162,38 +171,34 @@ pub enum DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1CollectionThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation deleteExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// delete a ThirdPartyResource
     ///
@@ -252,6 +257,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::delete_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.delete_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum DeleteExtensionsV1beta1ThirdPartyResourceResponse {
     OkStatus(::v1_7::apimachinery::pkg::apis::meta::v1::Status),
@@ -260,38 +266,34 @@ pub enum DeleteExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for DeleteExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {
+                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {
                     Ok(value) => value,
                     Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
                     Err(err) => return Err(::ResponseError::Json(err)),
                 };
-                let is_status = match result.get("kind") {
-                    Some(::serde_json::Value::String(s)) if s == "Status" => true,
-                    _ => false,
-                };
-                if is_status {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1ThirdPartyResourceResponse::OkStatus(result), buf.len()))
-                }
-                else {
-                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));
-                    let result = result.map_err(::ResponseError::Json)?;
-                    Ok((DeleteExtensionsV1beta1ThirdPartyResourceResponse::OkValue(result), buf.len()))
+                match result {
+                    ::tagged::StatusOr::Status(result) => Ok((DeleteExtensionsV1beta1ThirdPartyResourceResponse::OkStatus(result), buf.len())),
+                    ::tagged::StatusOr::Value(result) => Ok((DeleteExtensionsV1beta1ThirdPartyResourceResponse::OkValue(result), buf.len())),
                 }
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((DeleteExtensionsV1beta1ThirdPartyResourceResponse::Unauthorized, 0)),
             _ => Ok((DeleteExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation listExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// list or watch objects of kind ThirdPartyResource
     ///
@@ -367,6 +369,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::list_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.list_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListExtensionsV1beta1ThirdPartyResourceResponse {
     Ok(::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResourceList),
@@ -374,6 +377,7 @@ pub enum ListExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -389,10 +393,27 @@ impl ::Response for ListExtensionsV1beta1ThirdPartyResourceResponse {
             _ => Ok((ListExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListExtensionsV1beta1ThirdPartyResourceResponse {
+    type List = ::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResourceList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListExtensionsV1beta1ThirdPartyResourceResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation patchExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// partially update the specified ThirdPartyResource
     ///
@@ -428,6 +449,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::patch_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.patch_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum PatchExtensionsV1beta1ThirdPartyResourceResponse {
     Ok(::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResource),
@@ -435,6 +457,7 @@ pub enum PatchExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for PatchExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -450,10 +473,15 @@ impl ::Response for PatchExtensionsV1beta1ThirdPartyResourceResponse {
             _ => Ok((PatchExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation readExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// read the specified ThirdPartyResource
     ///
@@ -502,6 +530,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::read_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.read_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadExtensionsV1beta1ThirdPartyResourceResponse {
     Ok(::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResource),
@@ -509,6 +538,7 @@ pub enum ReadExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -524,10 +554,15 @@ impl ::Response for ReadExtensionsV1beta1ThirdPartyResourceResponse {
             _ => Ok((ReadExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation replaceExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// replace the specified ThirdPartyResource
     ///
@@ -563,6 +598,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::replace_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.replace_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReplaceExtensionsV1beta1ThirdPartyResourceResponse {
     Ok(::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResource),
@@ -570,6 +606,7 @@ pub enum ReplaceExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReplaceExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -585,10 +622,15 @@ impl ::Response for ReplaceExtensionsV1beta1ThirdPartyResourceResponse {
             _ => Ok((ReplaceExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1ThirdPartyResource
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// watch changes to an object of kind ThirdPartyResource
     ///
@@ -669,6 +711,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::watch_extensions_v1beta1_third_party_resource`](./struct.ThirdPartyResource.html#method.watch_extensions_v1beta1_third_party_resource)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1ThirdPartyResourceResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -676,27 +719,31 @@ pub enum WatchExtensionsV1beta1ThirdPartyResourceResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1ThirdPartyResourceResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1ThirdPartyResourceResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1ThirdPartyResourceResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1ThirdPartyResourceResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1ThirdPartyResourceResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // Generated from operation watchExtensionsV1beta1ThirdPartyResourceList
 
+#[cfg(feature = "api")]
 impl ThirdPartyResource {
     /// watch individual changes to a list of ThirdPartyResource
     ///
@@ -772,6 +819,7 @@ impl ThirdPartyResource {
 }
 
 /// Parses the HTTP response of [`ThirdPartyResource::watch_extensions_v1beta1_third_party_resource_list`](./struct.ThirdPartyResource.html#method.watch_extensions_v1beta1_third_party_resource_list)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum WatchExtensionsV1beta1ThirdPartyResourceListResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::WatchEvent),
@@ -779,23 +827,26 @@ pub enum WatchExtensionsV1beta1ThirdPartyResourceListResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for WatchExtensionsV1beta1ThirdPartyResourceListResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
             ::http::StatusCode::OK => {
-                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();
-                let (result, byte_offset) = match deserializer.next() {
-                    Some(Ok(value)) => (value, deserializer.byte_offset()),
-                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),
-                    Some(Err(err)) => return Err(::ResponseError::Json(err)),
+                let line_len = match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => newline + 1,
                     None => return Err(::ResponseError::NeedMoreData),
                 };
-                Ok((WatchExtensionsV1beta1ThirdPartyResourceListResponse::Ok(result), byte_offset))
+                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;
+                Ok((WatchExtensionsV1beta1ThirdPartyResourceListResponse::Ok(result), line_len))
             },
             ::http::StatusCode::UNAUTHORIZED => Ok((WatchExtensionsV1beta1ThirdPartyResourceListResponse::Unauthorized, 0)),
             _ => Ok((WatchExtensionsV1beta1ThirdPartyResourceListResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonLines
+    }
 }
 
 // End extensions/v1beta1/ThirdPartyResource
@@ -919,3 +970,25 @@ impl ::serde::Serialize for ThirdPartyResource {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ThirdPartyResource`](./struct.ThirdPartyResource.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThirdPartyResourceField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for ThirdPartyResourceField {
+    fn path(&self) -> &'static str {
+        match self {
+            ThirdPartyResourceField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ThirdPartyResourceField::MetadataName),
+            _ => None,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for ThirdPartyResourceList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for ThirdPartyResourceList {
+    type Item = ::v1_7::kubernetes::pkg::apis::extensions::v1beta1::ThirdPartyResource;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
@@ -1 +1,2 @@
+#[cfg(feature = "v1_7-networking-v1")]
 pub mod v1;
@@ -99,3 +99,13 @@ impl ::serde::Serialize for NetworkPolicyPeer {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-extensions-v1beta1")]
+impl From<::v1_7::kubernetes::pkg::apis::extensions::v1beta1::NetworkPolicyPeer> for NetworkPolicyPeer {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::extensions::v1beta1::NetworkPolicyPeer) -> Self {
+        NetworkPolicyPeer {
+            namespace_selector: value.namespace_selector,
+            pod_selector: value.pod_selector,
+        }
+    }
+}
@@ -97,3 +97,13 @@ impl ::serde::Serialize for NetworkPolicySpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_7-extensions-v1beta1")]
+impl From<::v1_7::kubernetes::pkg::apis::extensions::v1beta1::NetworkPolicySpec> for NetworkPolicySpec {
+    fn from(value: ::v1_7::kubernetes::pkg::apis::extensions::v1beta1::NetworkPolicySpec) -> Self {
+        NetworkPolicySpec {
+            ingress: value.ingress.map(|value| value.into_iter().map(Into::into).collect()),
+            pod_selector: value.pod_selector,
+        }
+    }
+}
@@ -1 +1,2 @@
+#[cfg(feature = "v1_7-policy-v1beta1")]
 pub mod v1beta1;
@@ -20,6 +20,7 @@ pub struct Eviction {
 
 // Generated from operation createCoreV1NamespacedPodEviction
 
+#[cfg(feature = "api")]
 impl Eviction {
     /// create eviction of a Pod
     ///
@@ -60,6 +61,7 @@ impl Eviction {
 }
 
 /// Parses the HTTP response of [`Eviction::create_core_v1_namespaced_pod_eviction`](./struct.Eviction.html#method.create_core_v1_namespaced_pod_eviction)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum CreateCoreV1NamespacedPodEvictionResponse {
     Ok(::v1_7::kubernetes::pkg::apis::policy::v1beta1::Eviction),
@@ -67,6 +69,7 @@ pub enum CreateCoreV1NamespacedPodEvictionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for CreateCoreV1NamespacedPodEvictionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -82,6 +85,10 @@ impl ::Response for CreateCoreV1NamespacedPodEvictionResponse {
             _ => Ok((CreateCoreV1NamespacedPodEvictionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End policy/v1beta1/Eviction
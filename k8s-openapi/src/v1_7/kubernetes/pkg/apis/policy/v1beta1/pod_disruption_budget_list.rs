@@ -121,3 +121,15 @@ impl ::serde::Serialize for PodDisruptionBudgetList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for PodDisruptionBudgetList {
+    type Item = ::v1_7::kubernetes::pkg::apis::policy::v1beta1::PodDisruptionBudget;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
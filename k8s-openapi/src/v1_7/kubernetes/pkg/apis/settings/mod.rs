@@ -1 +1,2 @@
+#[cfg(feature = "v1_7-settings-v1alpha1")]
 pub mod v1alpha1;
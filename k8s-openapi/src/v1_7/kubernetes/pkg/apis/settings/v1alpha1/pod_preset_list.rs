@@ -123,3 +123,15 @@ impl ::serde::Serialize for PodPresetList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for PodPresetList {
+    type Item = ::v1_7::kubernetes::pkg::apis::settings::v1alpha1::PodPreset;
+
+    fn continue_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
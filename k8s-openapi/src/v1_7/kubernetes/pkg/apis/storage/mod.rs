@@ -1,3 +1,5 @@
+#[cfg(feature = "v1_7-storage-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_7-storage-v1beta1")]
 pub mod v1beta1;
@@ -4,8 +4,14 @@ pub mod kube_aggregator;
 
 pub mod kubernetes;
 
+pub mod api;
+
+mod resource;
+pub use self::resource::*;
+
 // Generated from operation getAPIVersions
 
+#[cfg(feature = "api")]
 /// get available API versions
 ///
 /// Use [`GetAPIVersionsResponse`](./enum.GetAPIVersionsResponse.html) to parse the HTTP response.
@@ -19,6 +25,7 @@ pub fn get_api_versions(
 }
 
 /// Parses the HTTP response of [`get_api_versions`](./fn.get_api_versions.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAPIVersionsResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroupList),
@@ -26,6 +33,7 @@ pub enum GetAPIVersionsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAPIVersionsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -41,10 +49,15 @@ impl ::Response for GetAPIVersionsResponse {
             _ => Ok((GetAPIVersionsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAdmissionregistrationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAdmissionregistrationAPIGroupResponse`](./enum.GetAdmissionregistrationAPIGroupResponse.html) to parse the HTTP response.
@@ -58,6 +71,7 @@ pub fn get_admissionregistration_api_group(
 }
 
 /// Parses the HTTP response of [`get_admissionregistration_api_group`](./fn.get_admissionregistration_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAdmissionregistrationAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -65,6 +79,7 @@ pub enum GetAdmissionregistrationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAdmissionregistrationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -80,10 +95,15 @@ impl ::Response for GetAdmissionregistrationAPIGroupResponse {
             _ => Ok((GetAdmissionregistrationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAdmissionregistrationV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAdmissionregistrationV1alpha1APIResourcesResponse`](./enum.GetAdmissionregistrationV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -97,6 +117,7 @@ pub fn get_admissionregistration_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_admissionregistration_v1alpha1_api_resources`](./fn.get_admissionregistration_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAdmissionregistrationV1alpha1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -104,6 +125,7 @@ pub enum GetAdmissionregistrationV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAdmissionregistrationV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -119,10 +141,15 @@ impl ::Response for GetAdmissionregistrationV1alpha1APIResourcesResponse {
             _ => Ok((GetAdmissionregistrationV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getApiregistrationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetApiregistrationAPIGroupResponse`](./enum.GetApiregistrationAPIGroupResponse.html) to parse the HTTP response.
@@ -136,6 +163,7 @@ pub fn get_apiregistration_api_group(
 }
 
 /// Parses the HTTP response of [`get_apiregistration_api_group`](./fn.get_apiregistration_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetApiregistrationAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -143,6 +171,7 @@ pub enum GetApiregistrationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetApiregistrationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -158,10 +187,15 @@ impl ::Response for GetApiregistrationAPIGroupResponse {
             _ => Ok((GetApiregistrationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getApiregistrationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetApiregistrationV1beta1APIResourcesResponse`](./enum.GetApiregistrationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -175,6 +209,7 @@ pub fn get_apiregistration_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_apiregistration_v1beta1_api_resources`](./fn.get_apiregistration_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetApiregistrationV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -182,6 +217,7 @@ pub enum GetApiregistrationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetApiregistrationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -197,10 +233,15 @@ impl ::Response for GetApiregistrationV1beta1APIResourcesResponse {
             _ => Ok((GetApiregistrationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAppsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAppsAPIGroupResponse`](./enum.GetAppsAPIGroupResponse.html) to parse the HTTP response.
@@ -214,6 +255,7 @@ pub fn get_apps_api_group(
 }
 
 /// Parses the HTTP response of [`get_apps_api_group`](./fn.get_apps_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAppsAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -221,6 +263,7 @@ pub enum GetAppsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAppsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -236,10 +279,15 @@ impl ::Response for GetAppsAPIGroupResponse {
             _ => Ok((GetAppsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAppsV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAppsV1beta1APIResourcesResponse`](./enum.GetAppsV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -253,6 +301,7 @@ pub fn get_apps_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_apps_v1beta1_api_resources`](./fn.get_apps_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAppsV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -260,6 +309,7 @@ pub enum GetAppsV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAppsV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -275,10 +325,15 @@ impl ::Response for GetAppsV1beta1APIResourcesResponse {
             _ => Ok((GetAppsV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthenticationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAuthenticationAPIGroupResponse`](./enum.GetAuthenticationAPIGroupResponse.html) to parse the HTTP response.
@@ -292,6 +347,7 @@ pub fn get_authentication_api_group(
 }
 
 /// Parses the HTTP response of [`get_authentication_api_group`](./fn.get_authentication_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthenticationAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -299,6 +355,7 @@ pub enum GetAuthenticationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthenticationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -314,10 +371,15 @@ impl ::Response for GetAuthenticationAPIGroupResponse {
             _ => Ok((GetAuthenticationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthenticationV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthenticationV1APIResourcesResponse`](./enum.GetAuthenticationV1APIResourcesResponse.html) to parse the HTTP response.
@@ -331,6 +393,7 @@ pub fn get_authentication_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authentication_v1_api_resources`](./fn.get_authentication_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthenticationV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -338,6 +401,7 @@ pub enum GetAuthenticationV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthenticationV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -353,10 +417,15 @@ impl ::Response for GetAuthenticationV1APIResourcesResponse {
             _ => Ok((GetAuthenticationV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthenticationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthenticationV1beta1APIResourcesResponse`](./enum.GetAuthenticationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -370,6 +439,7 @@ pub fn get_authentication_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authentication_v1beta1_api_resources`](./fn.get_authentication_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthenticationV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -377,6 +447,7 @@ pub enum GetAuthenticationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthenticationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -392,10 +463,15 @@ impl ::Response for GetAuthenticationV1beta1APIResourcesResponse {
             _ => Ok((GetAuthenticationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthorizationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAuthorizationAPIGroupResponse`](./enum.GetAuthorizationAPIGroupResponse.html) to parse the HTTP response.
@@ -409,6 +485,7 @@ pub fn get_authorization_api_group(
 }
 
 /// Parses the HTTP response of [`get_authorization_api_group`](./fn.get_authorization_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthorizationAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -416,6 +493,7 @@ pub enum GetAuthorizationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthorizationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -431,10 +509,15 @@ impl ::Response for GetAuthorizationAPIGroupResponse {
             _ => Ok((GetAuthorizationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthorizationV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthorizationV1APIResourcesResponse`](./enum.GetAuthorizationV1APIResourcesResponse.html) to parse the HTTP response.
@@ -448,6 +531,7 @@ pub fn get_authorization_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authorization_v1_api_resources`](./fn.get_authorization_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthorizationV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -455,6 +539,7 @@ pub enum GetAuthorizationV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthorizationV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -470,10 +555,15 @@ impl ::Response for GetAuthorizationV1APIResourcesResponse {
             _ => Ok((GetAuthorizationV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthorizationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthorizationV1beta1APIResourcesResponse`](./enum.GetAuthorizationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -487,6 +577,7 @@ pub fn get_authorization_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authorization_v1beta1_api_resources`](./fn.get_authorization_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthorizationV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -494,6 +585,7 @@ pub enum GetAuthorizationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthorizationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -509,10 +601,15 @@ impl ::Response for GetAuthorizationV1beta1APIResourcesResponse {
             _ => Ok((GetAuthorizationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAutoscalingAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAutoscalingAPIGroupResponse`](./enum.GetAutoscalingAPIGroupResponse.html) to parse the HTTP response.
@@ -526,6 +623,7 @@ pub fn get_autoscaling_api_group(
 }
 
 /// Parses the HTTP response of [`get_autoscaling_api_group`](./fn.get_autoscaling_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAutoscalingAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -533,6 +631,7 @@ pub enum GetAutoscalingAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAutoscalingAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -548,10 +647,15 @@ impl ::Response for GetAutoscalingAPIGroupResponse {
             _ => Ok((GetAutoscalingAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAutoscalingV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAutoscalingV1APIResourcesResponse`](./enum.GetAutoscalingV1APIResourcesResponse.html) to parse the HTTP response.
@@ -565,6 +669,7 @@ pub fn get_autoscaling_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_autoscaling_v1_api_resources`](./fn.get_autoscaling_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAutoscalingV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -572,6 +677,7 @@ pub enum GetAutoscalingV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAutoscalingV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -587,10 +693,15 @@ impl ::Response for GetAutoscalingV1APIResourcesResponse {
             _ => Ok((GetAutoscalingV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAutoscalingV2alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAutoscalingV2alpha1APIResourcesResponse`](./enum.GetAutoscalingV2alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -604,6 +715,7 @@ pub fn get_autoscaling_v2alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_autoscaling_v2alpha1_api_resources`](./fn.get_autoscaling_v2alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAutoscalingV2alpha1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -611,6 +723,7 @@ pub enum GetAutoscalingV2alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAutoscalingV2alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -626,10 +739,15 @@ impl ::Response for GetAutoscalingV2alpha1APIResourcesResponse {
             _ => Ok((GetAutoscalingV2alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetBatchAPIGroupResponse`](./enum.GetBatchAPIGroupResponse.html) to parse the HTTP response.
@@ -643,6 +761,7 @@ pub fn get_batch_api_group(
 }
 
 /// Parses the HTTP response of [`get_batch_api_group`](./fn.get_batch_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -650,6 +769,7 @@ pub enum GetBatchAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -665,10 +785,15 @@ impl ::Response for GetBatchAPIGroupResponse {
             _ => Ok((GetBatchAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetBatchV1APIResourcesResponse`](./enum.GetBatchV1APIResourcesResponse.html) to parse the HTTP response.
@@ -682,6 +807,7 @@ pub fn get_batch_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_batch_v1_api_resources`](./fn.get_batch_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -689,6 +815,7 @@ pub enum GetBatchV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -704,10 +831,15 @@ impl ::Response for GetBatchV1APIResourcesResponse {
             _ => Ok((GetBatchV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchV2alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetBatchV2alpha1APIResourcesResponse`](./enum.GetBatchV2alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -721,6 +853,7 @@ pub fn get_batch_v2alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_batch_v2alpha1_api_resources`](./fn.get_batch_v2alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchV2alpha1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -728,6 +861,7 @@ pub enum GetBatchV2alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchV2alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -743,10 +877,15 @@ impl ::Response for GetBatchV2alpha1APIResourcesResponse {
             _ => Ok((GetBatchV2alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCertificatesAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetCertificatesAPIGroupResponse`](./enum.GetCertificatesAPIGroupResponse.html) to parse the HTTP response.
@@ -760,6 +899,7 @@ pub fn get_certificates_api_group(
 }
 
 /// Parses the HTTP response of [`get_certificates_api_group`](./fn.get_certificates_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCertificatesAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -767,6 +907,7 @@ pub enum GetCertificatesAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCertificatesAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -782,10 +923,15 @@ impl ::Response for GetCertificatesAPIGroupResponse {
             _ => Ok((GetCertificatesAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCertificatesV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetCertificatesV1beta1APIResourcesResponse`](./enum.GetCertificatesV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -799,6 +945,7 @@ pub fn get_certificates_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_certificates_v1beta1_api_resources`](./fn.get_certificates_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCertificatesV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -806,6 +953,7 @@ pub enum GetCertificatesV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCertificatesV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -821,10 +969,15 @@ impl ::Response for GetCertificatesV1beta1APIResourcesResponse {
             _ => Ok((GetCertificatesV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCodeVersion
 
+#[cfg(feature = "api")]
 /// get the code version
 ///
 /// Use [`GetCodeVersionResponse`](./enum.GetCodeVersionResponse.html) to parse the HTTP response.
@@ -838,6 +991,7 @@ pub fn get_code_version(
 }
 
 /// Parses the HTTP response of [`get_code_version`](./fn.get_code_version.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCodeVersionResponse {
     Ok(::v1_7::apimachinery::pkg::version::Info),
@@ -845,6 +999,7 @@ pub enum GetCodeVersionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCodeVersionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -860,10 +1015,15 @@ impl ::Response for GetCodeVersionResponse {
             _ => Ok((GetCodeVersionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCoreAPIVersions
 
+#[cfg(feature = "api")]
 /// get available API versions
 ///
 /// Use [`GetCoreAPIVersionsResponse`](./enum.GetCoreAPIVersionsResponse.html) to parse the HTTP response.
@@ -877,6 +1037,7 @@ pub fn get_core_api_versions(
 }
 
 /// Parses the HTTP response of [`get_core_api_versions`](./fn.get_core_api_versions.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCoreAPIVersionsResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIVersions),
@@ -884,6 +1045,7 @@ pub enum GetCoreAPIVersionsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCoreAPIVersionsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -899,10 +1061,15 @@ impl ::Response for GetCoreAPIVersionsResponse {
             _ => Ok((GetCoreAPIVersionsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCoreV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetCoreV1APIResourcesResponse`](./enum.GetCoreV1APIResourcesResponse.html) to parse the HTTP response.
@@ -916,6 +1083,7 @@ pub fn get_core_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_core_v1_api_resources`](./fn.get_core_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCoreV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -923,6 +1091,7 @@ pub enum GetCoreV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCoreV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -938,10 +1107,15 @@ impl ::Response for GetCoreV1APIResourcesResponse {
             _ => Ok((GetCoreV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getExtensionsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetExtensionsAPIGroupResponse`](./enum.GetExtensionsAPIGroupResponse.html) to parse the HTTP response.
@@ -955,6 +1129,7 @@ pub fn get_extensions_api_group(
 }
 
 /// Parses the HTTP response of [`get_extensions_api_group`](./fn.get_extensions_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetExtensionsAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -962,6 +1137,7 @@ pub enum GetExtensionsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetExtensionsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -977,10 +1153,15 @@ impl ::Response for GetExtensionsAPIGroupResponse {
             _ => Ok((GetExtensionsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getExtensionsV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetExtensionsV1beta1APIResourcesResponse`](./enum.GetExtensionsV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -994,6 +1175,7 @@ pub fn get_extensions_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_extensions_v1beta1_api_resources`](./fn.get_extensions_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetExtensionsV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1001,6 +1183,7 @@ pub enum GetExtensionsV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetExtensionsV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1016,10 +1199,15 @@ impl ::Response for GetExtensionsV1beta1APIResourcesResponse {
             _ => Ok((GetExtensionsV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getNetworkingAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetNetworkingAPIGroupResponse`](./enum.GetNetworkingAPIGroupResponse.html) to parse the HTTP response.
@@ -1033,6 +1221,7 @@ pub fn get_networking_api_group(
 }
 
 /// Parses the HTTP response of [`get_networking_api_group`](./fn.get_networking_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetNetworkingAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1040,6 +1229,7 @@ pub enum GetNetworkingAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetNetworkingAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1055,10 +1245,15 @@ impl ::Response for GetNetworkingAPIGroupResponse {
             _ => Ok((GetNetworkingAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getNetworkingV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetNetworkingV1APIResourcesResponse`](./enum.GetNetworkingV1APIResourcesResponse.html) to parse the HTTP response.
@@ -1072,6 +1267,7 @@ pub fn get_networking_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_networking_v1_api_resources`](./fn.get_networking_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetNetworkingV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1079,6 +1275,7 @@ pub enum GetNetworkingV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetNetworkingV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1094,10 +1291,15 @@ impl ::Response for GetNetworkingV1APIResourcesResponse {
             _ => Ok((GetNetworkingV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getPolicyAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetPolicyAPIGroupResponse`](./enum.GetPolicyAPIGroupResponse.html) to parse the HTTP response.
@@ -1111,6 +1313,7 @@ pub fn get_policy_api_group(
 }
 
 /// Parses the HTTP response of [`get_policy_api_group`](./fn.get_policy_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetPolicyAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1118,6 +1321,7 @@ pub enum GetPolicyAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetPolicyAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1133,10 +1337,15 @@ impl ::Response for GetPolicyAPIGroupResponse {
             _ => Ok((GetPolicyAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getPolicyV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetPolicyV1beta1APIResourcesResponse`](./enum.GetPolicyV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1150,6 +1359,7 @@ pub fn get_policy_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_policy_v1beta1_api_resources`](./fn.get_policy_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetPolicyV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1157,6 +1367,7 @@ pub enum GetPolicyV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetPolicyV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1172,10 +1383,15 @@ impl ::Response for GetPolicyV1beta1APIResourcesResponse {
             _ => Ok((GetPolicyV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetRbacAuthorizationAPIGroupResponse`](./enum.GetRbacAuthorizationAPIGroupResponse.html) to parse the HTTP response.
@@ -1189,6 +1405,7 @@ pub fn get_rbac_authorization_api_group(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_api_group`](./fn.get_rbac_authorization_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1196,6 +1413,7 @@ pub enum GetRbacAuthorizationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1211,10 +1429,15 @@ impl ::Response for GetRbacAuthorizationAPIGroupResponse {
             _ => Ok((GetRbacAuthorizationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetRbacAuthorizationV1alpha1APIResourcesResponse`](./enum.GetRbacAuthorizationV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -1228,6 +1451,7 @@ pub fn get_rbac_authorization_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_v1alpha1_api_resources`](./fn.get_rbac_authorization_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationV1alpha1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1235,6 +1459,7 @@ pub enum GetRbacAuthorizationV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1250,10 +1475,15 @@ impl ::Response for GetRbacAuthorizationV1alpha1APIResourcesResponse {
             _ => Ok((GetRbacAuthorizationV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetRbacAuthorizationV1beta1APIResourcesResponse`](./enum.GetRbacAuthorizationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1267,6 +1497,7 @@ pub fn get_rbac_authorization_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_v1beta1_api_resources`](./fn.get_rbac_authorization_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1274,6 +1505,7 @@ pub enum GetRbacAuthorizationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1289,10 +1521,15 @@ impl ::Response for GetRbacAuthorizationV1beta1APIResourcesResponse {
             _ => Ok((GetRbacAuthorizationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getSettingsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetSettingsAPIGroupResponse`](./enum.GetSettingsAPIGroupResponse.html) to parse the HTTP response.
@@ -1306,6 +1543,7 @@ pub fn get_settings_api_group(
 }
 
 /// Parses the HTTP response of [`get_settings_api_group`](./fn.get_settings_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetSettingsAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1313,6 +1551,7 @@ pub enum GetSettingsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetSettingsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1328,10 +1567,15 @@ impl ::Response for GetSettingsAPIGroupResponse {
             _ => Ok((GetSettingsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getSettingsV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetSettingsV1alpha1APIResourcesResponse`](./enum.GetSettingsV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -1345,6 +1589,7 @@ pub fn get_settings_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_settings_v1alpha1_api_resources`](./fn.get_settings_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetSettingsV1alpha1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1352,6 +1597,7 @@ pub enum GetSettingsV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetSettingsV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1367,10 +1613,15 @@ impl ::Response for GetSettingsV1alpha1APIResourcesResponse {
             _ => Ok((GetSettingsV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getStorageAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetStorageAPIGroupResponse`](./enum.GetStorageAPIGroupResponse.html) to parse the HTTP response.
@@ -1384,6 +1635,7 @@ pub fn get_storage_api_group(
 }
 
 /// Parses the HTTP response of [`get_storage_api_group`](./fn.get_storage_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetStorageAPIGroupResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1391,6 +1643,7 @@ pub enum GetStorageAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetStorageAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1406,10 +1659,15 @@ impl ::Response for GetStorageAPIGroupResponse {
             _ => Ok((GetStorageAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getStorageV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetStorageV1APIResourcesResponse`](./enum.GetStorageV1APIResourcesResponse.html) to parse the HTTP response.
@@ -1423,6 +1681,7 @@ pub fn get_storage_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_storage_v1_api_resources`](./fn.get_storage_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetStorageV1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1430,6 +1689,7 @@ pub enum GetStorageV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetStorageV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1445,10 +1705,15 @@ impl ::Response for GetStorageV1APIResourcesResponse {
             _ => Ok((GetStorageV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getStorageV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetStorageV1beta1APIResourcesResponse`](./enum.GetStorageV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1462,6 +1727,7 @@ pub fn get_storage_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_storage_v1beta1_api_resources`](./fn.get_storage_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetStorageV1beta1APIResourcesResponse {
     Ok(::v1_7::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1469,6 +1735,7 @@ pub enum GetStorageV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetStorageV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1484,10 +1751,15 @@ impl ::Response for GetStorageV1beta1APIResourcesResponse {
             _ => Ok((GetStorageV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation logFileHandler
 
+#[cfg(feature = "api")]
 /// Use [`LogFileHandlerResponse`](./enum.LogFileHandlerResponse.html) to parse the HTTP response.
 ///
 /// # Arguments
@@ -1506,12 +1778,14 @@ pub fn log_file_handler(
 }
 
 /// Parses the HTTP response of [`log_file_handler`](./fn.log_file_handler.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum LogFileHandlerResponse {
     Unauthorized,
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for LogFileHandlerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, _: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1523,6 +1797,7 @@ impl ::Response for LogFileHandlerResponse {
 
 // Generated from operation logFileListHandler
 
+#[cfg(feature = "api")]
 /// Use [`LogFileListHandlerResponse`](./enum.LogFileListHandlerResponse.html) to parse the HTTP response.
 pub fn log_file_list_handler(
 ) -> Result<::http::Request<Vec<u8>>, ::RequestError> {
@@ -1534,12 +1809,14 @@ pub fn log_file_list_handler(
 }
 
 /// Parses the HTTP response of [`log_file_list_handler`](./fn.log_file_list_handler.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum LogFileListHandlerResponse {
     Unauthorized,
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for LogFileListHandlerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, _: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1_8-apps-v1beta1")]
 pub mod v1beta1;
 
+#[cfg(feature = "v1_8-apps-v1beta2")]
 pub mod v1beta2;
@@ -86,3 +86,12 @@ impl ::serde::Serialize for TokenReviewSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_8-authentication-v1")]
+impl From<::v1_8::api::authentication::v1::TokenReviewSpec> for TokenReviewSpec {
+    fn from(value: ::v1_8::api::authentication::v1::TokenReviewSpec) -> Self {
+        TokenReviewSpec {
+            token: value.token,
+        }
+    }
+}
@@ -123,3 +123,15 @@ impl ::serde::Serialize for JobList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for JobList {
+    type Item = ::v1_8::api::batch::v1::Job;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
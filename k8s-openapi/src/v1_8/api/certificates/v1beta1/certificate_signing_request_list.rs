@@ -120,3 +120,15 @@ impl ::serde::Serialize for CertificateSigningRequestList {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+impl ::List for CertificateSigningRequestList {
+    type Item = ::v1_8::api::certificates::v1beta1::CertificateSigningRequest;
+
+    fn continue_token(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
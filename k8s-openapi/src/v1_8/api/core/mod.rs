@@ -1 +1,2 @@
+#[cfg(feature = "v1_8-core-v1")]
 pub mod v1;
@@ -20,6 +20,7 @@ pub struct ComponentStatus {
 
 // Generated from operation listCoreV1ComponentStatus
 
+#[cfg(feature = "api")]
 impl ComponentStatus {
     /// list objects of kind ComponentStatus
     ///
@@ -113,6 +114,7 @@ impl ComponentStatus {
 }
 
 /// Parses the HTTP response of [`ComponentStatus::list_core_v1_component_status`](./struct.ComponentStatus.html#method.list_core_v1_component_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ListCoreV1ComponentStatusResponse {
     Ok(::v1_8::api::core::v1::ComponentStatusList),
@@ -120,6 +122,7 @@ pub enum ListCoreV1ComponentStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ListCoreV1ComponentStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -135,10 +138,27 @@ impl ::Response for ListCoreV1ComponentStatusResponse {
             _ => Ok((ListCoreV1ComponentStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
+}
+
+#[cfg(feature = "api")]
+impl ::ListResponse for ListCoreV1ComponentStatusResponse {
+    type List = ::v1_8::api::core::v1::ComponentStatusList;
+
+    fn into_list(self) -> Option<Self::List> {
+        match self {
+            ListCoreV1ComponentStatusResponse::Ok(list) => Some(list),
+            _ => None,
+        }
+    }
 }
 
 // Generated from operation readCoreV1ComponentStatus
 
+#[cfg(feature = "api")]
 impl ComponentStatus {
     /// read the specified ComponentStatus
     ///
@@ -171,6 +191,7 @@ impl ComponentStatus {
 }
 
 /// Parses the HTTP response of [`ComponentStatus::read_core_v1_component_status`](./struct.ComponentStatus.html#method.read_core_v1_component_status)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum ReadCoreV1ComponentStatusResponse {
     Ok(::v1_8::api::core::v1::ComponentStatus),
@@ -178,6 +199,7 @@ pub enum ReadCoreV1ComponentStatusResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for ReadCoreV1ComponentStatusResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -193,6 +215,10 @@ impl ::Response for ReadCoreV1ComponentStatusResponse {
             _ => Ok((ReadCoreV1ComponentStatusResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // End /v1/ComponentStatus
@@ -306,3 +332,25 @@ impl ::serde::Serialize for ComponentStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+/// The fields of [`ComponentStatus`](./struct.ComponentStatus.html) that can be used in a [`::field_selector::FieldSelector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ComponentStatusField {
+    /// `metadata.name`
+    MetadataName,
+}
+
+impl ::field_selector::Field for ComponentStatusField {
+    fn path(&self) -> &'static str {
+        match self {
+            ComponentStatusField::MetadataName => "metadata.name",
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Self> {
+        match path {
+            "metadata.name" => Some(ComponentStatusField::MetadataName),
+            _ => None,
+        }
+    }
+}
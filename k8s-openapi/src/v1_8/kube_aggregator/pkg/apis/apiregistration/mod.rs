@@ -1 +1,2 @@
+#[cfg(feature = "v1_8-apiregistration-v1beta1")]
 pub mod v1beta1;
@@ -6,8 +6,12 @@ pub mod apimachinery;
 
 pub mod kube_aggregator;
 
+mod resource;
+pub use self::resource::*;
+
 // Generated from operation getAPIVersions
 
+#[cfg(feature = "api")]
 /// get available API versions
 ///
 /// Use [`GetAPIVersionsResponse`](./enum.GetAPIVersionsResponse.html) to parse the HTTP response.
@@ -21,6 +25,7 @@ pub fn get_api_versions(
 }
 
 /// Parses the HTTP response of [`get_api_versions`](./fn.get_api_versions.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAPIVersionsResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroupList),
@@ -28,6 +33,7 @@ pub enum GetAPIVersionsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAPIVersionsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -43,10 +49,15 @@ impl ::Response for GetAPIVersionsResponse {
             _ => Ok((GetAPIVersionsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAdmissionregistrationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAdmissionregistrationAPIGroupResponse`](./enum.GetAdmissionregistrationAPIGroupResponse.html) to parse the HTTP response.
@@ -60,6 +71,7 @@ pub fn get_admissionregistration_api_group(
 }
 
 /// Parses the HTTP response of [`get_admissionregistration_api_group`](./fn.get_admissionregistration_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAdmissionregistrationAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -67,6 +79,7 @@ pub enum GetAdmissionregistrationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAdmissionregistrationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -82,10 +95,15 @@ impl ::Response for GetAdmissionregistrationAPIGroupResponse {
             _ => Ok((GetAdmissionregistrationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAdmissionregistrationV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAdmissionregistrationV1alpha1APIResourcesResponse`](./enum.GetAdmissionregistrationV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -99,6 +117,7 @@ pub fn get_admissionregistration_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_admissionregistration_v1alpha1_api_resources`](./fn.get_admissionregistration_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAdmissionregistrationV1alpha1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -106,6 +125,7 @@ pub enum GetAdmissionregistrationV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAdmissionregistrationV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -121,10 +141,15 @@ impl ::Response for GetAdmissionregistrationV1alpha1APIResourcesResponse {
             _ => Ok((GetAdmissionregistrationV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getApiextensionsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetApiextensionsAPIGroupResponse`](./enum.GetApiextensionsAPIGroupResponse.html) to parse the HTTP response.
@@ -138,6 +163,7 @@ pub fn get_apiextensions_api_group(
 }
 
 /// Parses the HTTP response of [`get_apiextensions_api_group`](./fn.get_apiextensions_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetApiextensionsAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -145,6 +171,7 @@ pub enum GetApiextensionsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetApiextensionsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -160,10 +187,15 @@ impl ::Response for GetApiextensionsAPIGroupResponse {
             _ => Ok((GetApiextensionsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getApiextensionsV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetApiextensionsV1beta1APIResourcesResponse`](./enum.GetApiextensionsV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -177,6 +209,7 @@ pub fn get_apiextensions_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_apiextensions_v1beta1_api_resources`](./fn.get_apiextensions_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetApiextensionsV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -184,6 +217,7 @@ pub enum GetApiextensionsV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetApiextensionsV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -199,10 +233,15 @@ impl ::Response for GetApiextensionsV1beta1APIResourcesResponse {
             _ => Ok((GetApiextensionsV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getApiregistrationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetApiregistrationAPIGroupResponse`](./enum.GetApiregistrationAPIGroupResponse.html) to parse the HTTP response.
@@ -216,6 +255,7 @@ pub fn get_apiregistration_api_group(
 }
 
 /// Parses the HTTP response of [`get_apiregistration_api_group`](./fn.get_apiregistration_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetApiregistrationAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -223,6 +263,7 @@ pub enum GetApiregistrationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetApiregistrationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -238,10 +279,15 @@ impl ::Response for GetApiregistrationAPIGroupResponse {
             _ => Ok((GetApiregistrationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getApiregistrationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetApiregistrationV1beta1APIResourcesResponse`](./enum.GetApiregistrationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -255,6 +301,7 @@ pub fn get_apiregistration_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_apiregistration_v1beta1_api_resources`](./fn.get_apiregistration_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetApiregistrationV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -262,6 +309,7 @@ pub enum GetApiregistrationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetApiregistrationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -277,10 +325,15 @@ impl ::Response for GetApiregistrationV1beta1APIResourcesResponse {
             _ => Ok((GetApiregistrationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAppsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAppsAPIGroupResponse`](./enum.GetAppsAPIGroupResponse.html) to parse the HTTP response.
@@ -294,6 +347,7 @@ pub fn get_apps_api_group(
 }
 
 /// Parses the HTTP response of [`get_apps_api_group`](./fn.get_apps_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAppsAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -301,6 +355,7 @@ pub enum GetAppsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAppsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -316,10 +371,15 @@ impl ::Response for GetAppsAPIGroupResponse {
             _ => Ok((GetAppsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAppsV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAppsV1beta1APIResourcesResponse`](./enum.GetAppsV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -333,6 +393,7 @@ pub fn get_apps_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_apps_v1beta1_api_resources`](./fn.get_apps_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAppsV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -340,6 +401,7 @@ pub enum GetAppsV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAppsV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -355,10 +417,15 @@ impl ::Response for GetAppsV1beta1APIResourcesResponse {
             _ => Ok((GetAppsV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAppsV1beta2APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAppsV1beta2APIResourcesResponse`](./enum.GetAppsV1beta2APIResourcesResponse.html) to parse the HTTP response.
@@ -372,6 +439,7 @@ pub fn get_apps_v1beta2_api_resources(
 }
 
 /// Parses the HTTP response of [`get_apps_v1beta2_api_resources`](./fn.get_apps_v1beta2_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAppsV1beta2APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -379,6 +447,7 @@ pub enum GetAppsV1beta2APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAppsV1beta2APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -394,10 +463,15 @@ impl ::Response for GetAppsV1beta2APIResourcesResponse {
             _ => Ok((GetAppsV1beta2APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthenticationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAuthenticationAPIGroupResponse`](./enum.GetAuthenticationAPIGroupResponse.html) to parse the HTTP response.
@@ -411,6 +485,7 @@ pub fn get_authentication_api_group(
 }
 
 /// Parses the HTTP response of [`get_authentication_api_group`](./fn.get_authentication_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthenticationAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -418,6 +493,7 @@ pub enum GetAuthenticationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthenticationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -433,10 +509,15 @@ impl ::Response for GetAuthenticationAPIGroupResponse {
             _ => Ok((GetAuthenticationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthenticationV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthenticationV1APIResourcesResponse`](./enum.GetAuthenticationV1APIResourcesResponse.html) to parse the HTTP response.
@@ -450,6 +531,7 @@ pub fn get_authentication_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authentication_v1_api_resources`](./fn.get_authentication_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthenticationV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -457,6 +539,7 @@ pub enum GetAuthenticationV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthenticationV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -472,10 +555,15 @@ impl ::Response for GetAuthenticationV1APIResourcesResponse {
             _ => Ok((GetAuthenticationV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthenticationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthenticationV1beta1APIResourcesResponse`](./enum.GetAuthenticationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -489,6 +577,7 @@ pub fn get_authentication_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authentication_v1beta1_api_resources`](./fn.get_authentication_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthenticationV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -496,6 +585,7 @@ pub enum GetAuthenticationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthenticationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -511,10 +601,15 @@ impl ::Response for GetAuthenticationV1beta1APIResourcesResponse {
             _ => Ok((GetAuthenticationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthorizationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAuthorizationAPIGroupResponse`](./enum.GetAuthorizationAPIGroupResponse.html) to parse the HTTP response.
@@ -528,6 +623,7 @@ pub fn get_authorization_api_group(
 }
 
 /// Parses the HTTP response of [`get_authorization_api_group`](./fn.get_authorization_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthorizationAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -535,6 +631,7 @@ pub enum GetAuthorizationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthorizationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -550,10 +647,15 @@ impl ::Response for GetAuthorizationAPIGroupResponse {
             _ => Ok((GetAuthorizationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthorizationV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthorizationV1APIResourcesResponse`](./enum.GetAuthorizationV1APIResourcesResponse.html) to parse the HTTP response.
@@ -567,6 +669,7 @@ pub fn get_authorization_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authorization_v1_api_resources`](./fn.get_authorization_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthorizationV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -574,6 +677,7 @@ pub enum GetAuthorizationV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthorizationV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -589,10 +693,15 @@ impl ::Response for GetAuthorizationV1APIResourcesResponse {
             _ => Ok((GetAuthorizationV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAuthorizationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAuthorizationV1beta1APIResourcesResponse`](./enum.GetAuthorizationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -606,6 +715,7 @@ pub fn get_authorization_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_authorization_v1beta1_api_resources`](./fn.get_authorization_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAuthorizationV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -613,6 +723,7 @@ pub enum GetAuthorizationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAuthorizationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -628,10 +739,15 @@ impl ::Response for GetAuthorizationV1beta1APIResourcesResponse {
             _ => Ok((GetAuthorizationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAutoscalingAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetAutoscalingAPIGroupResponse`](./enum.GetAutoscalingAPIGroupResponse.html) to parse the HTTP response.
@@ -645,6 +761,7 @@ pub fn get_autoscaling_api_group(
 }
 
 /// Parses the HTTP response of [`get_autoscaling_api_group`](./fn.get_autoscaling_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAutoscalingAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -652,6 +769,7 @@ pub enum GetAutoscalingAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAutoscalingAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -667,10 +785,15 @@ impl ::Response for GetAutoscalingAPIGroupResponse {
             _ => Ok((GetAutoscalingAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAutoscalingV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAutoscalingV1APIResourcesResponse`](./enum.GetAutoscalingV1APIResourcesResponse.html) to parse the HTTP response.
@@ -684,6 +807,7 @@ pub fn get_autoscaling_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_autoscaling_v1_api_resources`](./fn.get_autoscaling_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAutoscalingV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -691,6 +815,7 @@ pub enum GetAutoscalingV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAutoscalingV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -706,10 +831,15 @@ impl ::Response for GetAutoscalingV1APIResourcesResponse {
             _ => Ok((GetAutoscalingV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getAutoscalingV2beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetAutoscalingV2beta1APIResourcesResponse`](./enum.GetAutoscalingV2beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -723,6 +853,7 @@ pub fn get_autoscaling_v2beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_autoscaling_v2beta1_api_resources`](./fn.get_autoscaling_v2beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetAutoscalingV2beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -730,6 +861,7 @@ pub enum GetAutoscalingV2beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetAutoscalingV2beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -745,10 +877,15 @@ impl ::Response for GetAutoscalingV2beta1APIResourcesResponse {
             _ => Ok((GetAutoscalingV2beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetBatchAPIGroupResponse`](./enum.GetBatchAPIGroupResponse.html) to parse the HTTP response.
@@ -762,6 +899,7 @@ pub fn get_batch_api_group(
 }
 
 /// Parses the HTTP response of [`get_batch_api_group`](./fn.get_batch_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -769,6 +907,7 @@ pub enum GetBatchAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -784,10 +923,15 @@ impl ::Response for GetBatchAPIGroupResponse {
             _ => Ok((GetBatchAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetBatchV1APIResourcesResponse`](./enum.GetBatchV1APIResourcesResponse.html) to parse the HTTP response.
@@ -801,6 +945,7 @@ pub fn get_batch_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_batch_v1_api_resources`](./fn.get_batch_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -808,6 +953,7 @@ pub enum GetBatchV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -823,10 +969,15 @@ impl ::Response for GetBatchV1APIResourcesResponse {
             _ => Ok((GetBatchV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetBatchV1beta1APIResourcesResponse`](./enum.GetBatchV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -840,6 +991,7 @@ pub fn get_batch_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_batch_v1beta1_api_resources`](./fn.get_batch_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -847,6 +999,7 @@ pub enum GetBatchV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -862,10 +1015,15 @@ impl ::Response for GetBatchV1beta1APIResourcesResponse {
             _ => Ok((GetBatchV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getBatchV2alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetBatchV2alpha1APIResourcesResponse`](./enum.GetBatchV2alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -879,6 +1037,7 @@ pub fn get_batch_v2alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_batch_v2alpha1_api_resources`](./fn.get_batch_v2alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetBatchV2alpha1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -886,6 +1045,7 @@ pub enum GetBatchV2alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetBatchV2alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -901,10 +1061,15 @@ impl ::Response for GetBatchV2alpha1APIResourcesResponse {
             _ => Ok((GetBatchV2alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCertificatesAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetCertificatesAPIGroupResponse`](./enum.GetCertificatesAPIGroupResponse.html) to parse the HTTP response.
@@ -918,6 +1083,7 @@ pub fn get_certificates_api_group(
 }
 
 /// Parses the HTTP response of [`get_certificates_api_group`](./fn.get_certificates_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCertificatesAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -925,6 +1091,7 @@ pub enum GetCertificatesAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCertificatesAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -940,10 +1107,15 @@ impl ::Response for GetCertificatesAPIGroupResponse {
             _ => Ok((GetCertificatesAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCertificatesV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetCertificatesV1beta1APIResourcesResponse`](./enum.GetCertificatesV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -957,6 +1129,7 @@ pub fn get_certificates_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_certificates_v1beta1_api_resources`](./fn.get_certificates_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCertificatesV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -964,6 +1137,7 @@ pub enum GetCertificatesV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCertificatesV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -979,10 +1153,15 @@ impl ::Response for GetCertificatesV1beta1APIResourcesResponse {
             _ => Ok((GetCertificatesV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCodeVersion
 
+#[cfg(feature = "api")]
 /// get the code version
 ///
 /// Use [`GetCodeVersionResponse`](./enum.GetCodeVersionResponse.html) to parse the HTTP response.
@@ -996,6 +1175,7 @@ pub fn get_code_version(
 }
 
 /// Parses the HTTP response of [`get_code_version`](./fn.get_code_version.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCodeVersionResponse {
     Ok(::v1_8::apimachinery::pkg::version::Info),
@@ -1003,6 +1183,7 @@ pub enum GetCodeVersionResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCodeVersionResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1018,10 +1199,15 @@ impl ::Response for GetCodeVersionResponse {
             _ => Ok((GetCodeVersionResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCoreAPIVersions
 
+#[cfg(feature = "api")]
 /// get available API versions
 ///
 /// Use [`GetCoreAPIVersionsResponse`](./enum.GetCoreAPIVersionsResponse.html) to parse the HTTP response.
@@ -1035,6 +1221,7 @@ pub fn get_core_api_versions(
 }
 
 /// Parses the HTTP response of [`get_core_api_versions`](./fn.get_core_api_versions.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCoreAPIVersionsResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIVersions),
@@ -1042,6 +1229,7 @@ pub enum GetCoreAPIVersionsResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCoreAPIVersionsResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1057,10 +1245,15 @@ impl ::Response for GetCoreAPIVersionsResponse {
             _ => Ok((GetCoreAPIVersionsResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getCoreV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetCoreV1APIResourcesResponse`](./enum.GetCoreV1APIResourcesResponse.html) to parse the HTTP response.
@@ -1074,6 +1267,7 @@ pub fn get_core_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_core_v1_api_resources`](./fn.get_core_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetCoreV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1081,6 +1275,7 @@ pub enum GetCoreV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetCoreV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1096,10 +1291,15 @@ impl ::Response for GetCoreV1APIResourcesResponse {
             _ => Ok((GetCoreV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getExtensionsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetExtensionsAPIGroupResponse`](./enum.GetExtensionsAPIGroupResponse.html) to parse the HTTP response.
@@ -1113,6 +1313,7 @@ pub fn get_extensions_api_group(
 }
 
 /// Parses the HTTP response of [`get_extensions_api_group`](./fn.get_extensions_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetExtensionsAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1120,6 +1321,7 @@ pub enum GetExtensionsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetExtensionsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1135,10 +1337,15 @@ impl ::Response for GetExtensionsAPIGroupResponse {
             _ => Ok((GetExtensionsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getExtensionsV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetExtensionsV1beta1APIResourcesResponse`](./enum.GetExtensionsV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1152,6 +1359,7 @@ pub fn get_extensions_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_extensions_v1beta1_api_resources`](./fn.get_extensions_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetExtensionsV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1159,6 +1367,7 @@ pub enum GetExtensionsV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetExtensionsV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1174,10 +1383,15 @@ impl ::Response for GetExtensionsV1beta1APIResourcesResponse {
             _ => Ok((GetExtensionsV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getNetworkingAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetNetworkingAPIGroupResponse`](./enum.GetNetworkingAPIGroupResponse.html) to parse the HTTP response.
@@ -1191,6 +1405,7 @@ pub fn get_networking_api_group(
 }
 
 /// Parses the HTTP response of [`get_networking_api_group`](./fn.get_networking_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetNetworkingAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1198,6 +1413,7 @@ pub enum GetNetworkingAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetNetworkingAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1213,10 +1429,15 @@ impl ::Response for GetNetworkingAPIGroupResponse {
             _ => Ok((GetNetworkingAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getNetworkingV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetNetworkingV1APIResourcesResponse`](./enum.GetNetworkingV1APIResourcesResponse.html) to parse the HTTP response.
@@ -1230,6 +1451,7 @@ pub fn get_networking_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_networking_v1_api_resources`](./fn.get_networking_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetNetworkingV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1237,6 +1459,7 @@ pub enum GetNetworkingV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetNetworkingV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1252,10 +1475,15 @@ impl ::Response for GetNetworkingV1APIResourcesResponse {
             _ => Ok((GetNetworkingV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getPolicyAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetPolicyAPIGroupResponse`](./enum.GetPolicyAPIGroupResponse.html) to parse the HTTP response.
@@ -1269,6 +1497,7 @@ pub fn get_policy_api_group(
 }
 
 /// Parses the HTTP response of [`get_policy_api_group`](./fn.get_policy_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetPolicyAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1276,6 +1505,7 @@ pub enum GetPolicyAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetPolicyAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1291,10 +1521,15 @@ impl ::Response for GetPolicyAPIGroupResponse {
             _ => Ok((GetPolicyAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getPolicyV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetPolicyV1beta1APIResourcesResponse`](./enum.GetPolicyV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1308,6 +1543,7 @@ pub fn get_policy_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_policy_v1beta1_api_resources`](./fn.get_policy_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetPolicyV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1315,6 +1551,7 @@ pub enum GetPolicyV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetPolicyV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1330,10 +1567,15 @@ impl ::Response for GetPolicyV1beta1APIResourcesResponse {
             _ => Ok((GetPolicyV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetRbacAuthorizationAPIGroupResponse`](./enum.GetRbacAuthorizationAPIGroupResponse.html) to parse the HTTP response.
@@ -1347,6 +1589,7 @@ pub fn get_rbac_authorization_api_group(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_api_group`](./fn.get_rbac_authorization_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1354,6 +1597,7 @@ pub enum GetRbacAuthorizationAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1369,10 +1613,15 @@ impl ::Response for GetRbacAuthorizationAPIGroupResponse {
             _ => Ok((GetRbacAuthorizationAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetRbacAuthorizationV1APIResourcesResponse`](./enum.GetRbacAuthorizationV1APIResourcesResponse.html) to parse the HTTP response.
@@ -1386,6 +1635,7 @@ pub fn get_rbac_authorization_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_v1_api_resources`](./fn.get_rbac_authorization_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1393,6 +1643,7 @@ pub enum GetRbacAuthorizationV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1408,10 +1659,15 @@ impl ::Response for GetRbacAuthorizationV1APIResourcesResponse {
             _ => Ok((GetRbacAuthorizationV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetRbacAuthorizationV1alpha1APIResourcesResponse`](./enum.GetRbacAuthorizationV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -1425,6 +1681,7 @@ pub fn get_rbac_authorization_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_v1alpha1_api_resources`](./fn.get_rbac_authorization_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationV1alpha1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1432,6 +1689,7 @@ pub enum GetRbacAuthorizationV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1447,10 +1705,15 @@ impl ::Response for GetRbacAuthorizationV1alpha1APIResourcesResponse {
             _ => Ok((GetRbacAuthorizationV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getRbacAuthorizationV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetRbacAuthorizationV1beta1APIResourcesResponse`](./enum.GetRbacAuthorizationV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1464,6 +1727,7 @@ pub fn get_rbac_authorization_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_rbac_authorization_v1beta1_api_resources`](./fn.get_rbac_authorization_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetRbacAuthorizationV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1471,6 +1735,7 @@ pub enum GetRbacAuthorizationV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetRbacAuthorizationV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1486,10 +1751,15 @@ impl ::Response for GetRbacAuthorizationV1beta1APIResourcesResponse {
             _ => Ok((GetRbacAuthorizationV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getSchedulingAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetSchedulingAPIGroupResponse`](./enum.GetSchedulingAPIGroupResponse.html) to parse the HTTP response.
@@ -1503,6 +1773,7 @@ pub fn get_scheduling_api_group(
 }
 
 /// Parses the HTTP response of [`get_scheduling_api_group`](./fn.get_scheduling_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetSchedulingAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1510,6 +1781,7 @@ pub enum GetSchedulingAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetSchedulingAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1525,10 +1797,15 @@ impl ::Response for GetSchedulingAPIGroupResponse {
             _ => Ok((GetSchedulingAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getSchedulingV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetSchedulingV1alpha1APIResourcesResponse`](./enum.GetSchedulingV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -1542,6 +1819,7 @@ pub fn get_scheduling_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_scheduling_v1alpha1_api_resources`](./fn.get_scheduling_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetSchedulingV1alpha1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1549,6 +1827,7 @@ pub enum GetSchedulingV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetSchedulingV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1564,10 +1843,15 @@ impl ::Response for GetSchedulingV1alpha1APIResourcesResponse {
             _ => Ok((GetSchedulingV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getSettingsAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetSettingsAPIGroupResponse`](./enum.GetSettingsAPIGroupResponse.html) to parse the HTTP response.
@@ -1581,6 +1865,7 @@ pub fn get_settings_api_group(
 }
 
 /// Parses the HTTP response of [`get_settings_api_group`](./fn.get_settings_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetSettingsAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1588,6 +1873,7 @@ pub enum GetSettingsAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetSettingsAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1603,10 +1889,15 @@ impl ::Response for GetSettingsAPIGroupResponse {
             _ => Ok((GetSettingsAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getSettingsV1alpha1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetSettingsV1alpha1APIResourcesResponse`](./enum.GetSettingsV1alpha1APIResourcesResponse.html) to parse the HTTP response.
@@ -1620,6 +1911,7 @@ pub fn get_settings_v1alpha1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_settings_v1alpha1_api_resources`](./fn.get_settings_v1alpha1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetSettingsV1alpha1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1627,6 +1919,7 @@ pub enum GetSettingsV1alpha1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetSettingsV1alpha1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1642,10 +1935,15 @@ impl ::Response for GetSettingsV1alpha1APIResourcesResponse {
             _ => Ok((GetSettingsV1alpha1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getStorageAPIGroup
 
+#[cfg(feature = "api")]
 /// get information of a group
 ///
 /// Use [`GetStorageAPIGroupResponse`](./enum.GetStorageAPIGroupResponse.html) to parse the HTTP response.
@@ -1659,6 +1957,7 @@ pub fn get_storage_api_group(
 }
 
 /// Parses the HTTP response of [`get_storage_api_group`](./fn.get_storage_api_group.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetStorageAPIGroupResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIGroup),
@@ -1666,6 +1965,7 @@ pub enum GetStorageAPIGroupResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetStorageAPIGroupResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1681,10 +1981,15 @@ impl ::Response for GetStorageAPIGroupResponse {
             _ => Ok((GetStorageAPIGroupResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getStorageV1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetStorageV1APIResourcesResponse`](./enum.GetStorageV1APIResourcesResponse.html) to parse the HTTP response.
@@ -1698,6 +2003,7 @@ pub fn get_storage_v1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_storage_v1_api_resources`](./fn.get_storage_v1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetStorageV1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1705,6 +2011,7 @@ pub enum GetStorageV1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetStorageV1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1720,10 +2027,15 @@ impl ::Response for GetStorageV1APIResourcesResponse {
             _ => Ok((GetStorageV1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation getStorageV1beta1APIResources
 
+#[cfg(feature = "api")]
 /// get available resources
 ///
 /// Use [`GetStorageV1beta1APIResourcesResponse`](./enum.GetStorageV1beta1APIResourcesResponse.html) to parse the HTTP response.
@@ -1737,6 +2049,7 @@ pub fn get_storage_v1beta1_api_resources(
 }
 
 /// Parses the HTTP response of [`get_storage_v1beta1_api_resources`](./fn.get_storage_v1beta1_api_resources.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum GetStorageV1beta1APIResourcesResponse {
     Ok(::v1_8::apimachinery::pkg::apis::meta::v1::APIResourceList),
@@ -1744,6 +2057,7 @@ pub enum GetStorageV1beta1APIResourcesResponse {
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for GetStorageV1beta1APIResourcesResponse {
     fn try_from_parts(status_code: ::http::StatusCode, buf: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1759,10 +2073,15 @@ impl ::Response for GetStorageV1beta1APIResourcesResponse {
             _ => Ok((GetStorageV1beta1APIResourcesResponse::Other, 0)),
         }
     }
+
+    fn framing() -> ::ResponseFraming {
+        ::ResponseFraming::JsonValue
+    }
 }
 
 // Generated from operation logFileHandler
 
+#[cfg(feature = "api")]
 /// Use [`LogFileHandlerResponse`](./enum.LogFileHandlerResponse.html) to parse the HTTP response.
 ///
 /// # Arguments
@@ -1781,12 +2100,14 @@ pub fn log_file_handler(
 }
 
 /// Parses the HTTP response of [`log_file_handler`](./fn.log_file_handler.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum LogFileHandlerResponse {
     Unauthorized,
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for LogFileHandlerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, _: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -1798,6 +2119,7 @@ impl ::Response for LogFileHandlerResponse {
 
 // Generated from operation logFileListHandler
 
+#[cfg(feature = "api")]
 /// Use [`LogFileListHandlerResponse`](./enum.LogFileListHandlerResponse.html) to parse the HTTP response.
 pub fn log_file_list_handler(
 ) -> Result<::http::Request<Vec<u8>>, ::RequestError> {
@@ -1809,12 +2131,14 @@ pub fn log_file_list_handler(
 }
 
 /// Parses the HTTP response of [`log_file_list_handler`](./fn.log_file_list_handler.html)
+#[cfg(feature = "api")]
 #[derive(Debug)]
 pub enum LogFileListHandlerResponse {
     Unauthorized,
     Other,
 }
 
+#[cfg(feature = "api")]
 impl ::Response for LogFileListHandlerResponse {
     fn try_from_parts(status_code: ::http::StatusCode, _: &[u8]) -> Result<(Self, usize), ::ResponseError> {
         match status_code {
@@ -0,0 +1,643 @@
+// Generated from the resource kinds of the spec
+
+/// A value of any resource kind, like an `apps/v1` `Deployment`, that is identified by its `apiVersion` and `kind` fields.
+///
+/// Values whose `apiVersion` and `kind` are not those of a resource kind, or of a resource kind whose API group version is not enabled,
+/// deserialize as [`Resource::Unknown`](#variant.Unknown).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resource {
+    /// `admissionregistration.k8s.io/v1alpha1` `ExternalAdmissionHookConfiguration`
+    #[cfg(feature = "v1_8-admissionregistration-v1alpha1")]
+    AdmissionregistrationV1alpha1ExternalAdmissionHookConfiguration(::v1_8::api::admissionregistration::v1alpha1::ExternalAdmissionHookConfiguration),
+
+    /// `admissionregistration.k8s.io/v1alpha1` `InitializerConfiguration`
+    #[cfg(feature = "v1_8-admissionregistration-v1alpha1")]
+    AdmissionregistrationV1alpha1InitializerConfiguration(::v1_8::api::admissionregistration::v1alpha1::InitializerConfiguration),
+
+    /// `apiextensions.k8s.io/v1beta1` `CustomResourceDefinition`
+    #[cfg(feature = "v1_8-apiextensions-v1beta1")]
+    ApiextensionsV1beta1CustomResourceDefinition(::v1_8::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::CustomResourceDefinition),
+
+    /// `apiregistration.k8s.io/v1beta1` `APIService`
+    #[cfg(feature = "v1_8-apiregistration-v1beta1")]
+    ApiregistrationV1beta1APIService(::v1_8::kube_aggregator::pkg::apis::apiregistration::v1beta1::APIService),
+
+    /// `apps/v1beta1` `ControllerRevision`
+    #[cfg(feature = "v1_8-apps-v1beta1")]
+    AppsV1beta1ControllerRevision(::v1_8::api::apps::v1beta1::ControllerRevision),
+
+    /// `apps/v1beta1` `Deployment`
+    #[cfg(feature = "v1_8-apps-v1beta1")]
+    AppsV1beta1Deployment(::v1_8::api::apps::v1beta1::Deployment),
+
+    /// `apps/v1beta1` `DeploymentRollback`
+    #[cfg(feature = "v1_8-apps-v1beta1")]
+    AppsV1beta1DeploymentRollback(::v1_8::api::apps::v1beta1::DeploymentRollback),
+
+    /// `apps/v1beta1` `Scale`
+    #[cfg(feature = "v1_8-apps-v1beta1")]
+    AppsV1beta1Scale(::v1_8::api::apps::v1beta1::Scale),
+
+    /// `apps/v1beta1` `StatefulSet`
+    #[cfg(feature = "v1_8-apps-v1beta1")]
+    AppsV1beta1StatefulSet(::v1_8::api::apps::v1beta1::StatefulSet),
+
+    /// `apps/v1beta2` `ControllerRevision`
+    #[cfg(feature = "v1_8-apps-v1beta2")]
+    AppsV1beta2ControllerRevision(::v1_8::api::apps::v1beta2::ControllerRevision),
+
+    /// `apps/v1beta2` `DaemonSet`
+    #[cfg(feature = "v1_8-apps-v1beta2")]
+    AppsV1beta2DaemonSet(::v1_8::api::apps::v1beta2::DaemonSet),
+
+    /// `apps/v1beta2` `Deployment`
+    #[cfg(feature = "v1_8-apps-v1beta2")]
+    AppsV1beta2Deployment(::v1_8::api::apps::v1beta2::Deployment),
+
+    /// `apps/v1beta2` `ReplicaSet`
+    #[cfg(feature = "v1_8-apps-v1beta2")]
+    AppsV1beta2ReplicaSet(::v1_8::api::apps::v1beta2::ReplicaSet),
+
+    /// `apps/v1beta2` `Scale`
+    #[cfg(feature = "v1_8-apps-v1beta2")]
+    AppsV1beta2Scale(::v1_8::api::apps::v1beta2::Scale),
+
+    /// `apps/v1beta2` `StatefulSet`
+    #[cfg(feature = "v1_8-apps-v1beta2")]
+    AppsV1beta2StatefulSet(::v1_8::api::apps::v1beta2::StatefulSet),
+
+    /// `authentication.k8s.io/v1` `TokenReview`
+    #[cfg(feature = "v1_8-authentication-v1")]
+    AuthenticationV1TokenReview(::v1_8::api::authentication::v1::TokenReview),
+
+    /// `authentication.k8s.io/v1beta1` `TokenReview`
+    #[cfg(feature = "v1_8-authentication-v1beta1")]
+    AuthenticationV1beta1TokenReview(::v1_8::api::authentication::v1beta1::TokenReview),
+
+    /// `authorization.k8s.io/v1` `LocalSubjectAccessReview`
+    #[cfg(feature = "v1_8-authorization-v1")]
+    AuthorizationV1LocalSubjectAccessReview(::v1_8::api::authorization::v1::LocalSubjectAccessReview),
+
+    /// `authorization.k8s.io/v1` `SelfSubjectAccessReview`
+    #[cfg(feature = "v1_8-authorization-v1")]
+    AuthorizationV1SelfSubjectAccessReview(::v1_8::api::authorization::v1::SelfSubjectAccessReview),
+
+    /// `authorization.k8s.io/v1` `SelfSubjectRulesReview`
+    #[cfg(feature = "v1_8-authorization-v1")]
+    AuthorizationV1SelfSubjectRulesReview(::v1_8::api::authorization::v1::SelfSubjectRulesReview),
+
+    /// `authorization.k8s.io/v1` `SubjectAccessReview`
+    #[cfg(feature = "v1_8-authorization-v1")]
+    AuthorizationV1SubjectAccessReview(::v1_8::api::authorization::v1::SubjectAccessReview),
+
+    /// `authorization.k8s.io/v1beta1` `LocalSubjectAccessReview`
+    #[cfg(feature = "v1_8-authorization-v1beta1")]
+    AuthorizationV1beta1LocalSubjectAccessReview(::v1_8::api::authorization::v1beta1::LocalSubjectAccessReview),
+
+    /// `authorization.k8s.io/v1beta1` `SelfSubjectAccessReview`
+    #[cfg(feature = "v1_8-authorization-v1beta1")]
+    AuthorizationV1beta1SelfSubjectAccessReview(::v1_8::api::authorization::v1beta1::SelfSubjectAccessReview),
+
+    /// `authorization.k8s.io/v1beta1` `SelfSubjectRulesReview`
+    #[cfg(feature = "v1_8-authorization-v1beta1")]
+    AuthorizationV1beta1SelfSubjectRulesReview(::v1_8::api::authorization::v1beta1::SelfSubjectRulesReview),
+
+    /// `authorization.k8s.io/v1beta1` `SubjectAccessReview`
+    #[cfg(feature = "v1_8-authorization-v1beta1")]
+    AuthorizationV1beta1SubjectAccessReview(::v1_8::api::authorization::v1beta1::SubjectAccessReview),
+
+    /// `autoscaling/v1` `HorizontalPodAutoscaler`
+    #[cfg(feature = "v1_8-autoscaling-v1")]
+    AutoscalingV1HorizontalPodAutoscaler(::v1_8::api::autoscaling::v1::HorizontalPodAutoscaler),
+
+    /// `autoscaling/v1` `Scale`
+    #[cfg(feature = "v1_8-autoscaling-v1")]
+    AutoscalingV1Scale(::v1_8::api::autoscaling::v1::Scale),
+
+    /// `autoscaling/v2beta1` `HorizontalPodAutoscaler`
+    #[cfg(feature = "v1_8-autoscaling-v2beta1")]
+    AutoscalingV2beta1HorizontalPodAutoscaler(::v1_8::api::autoscaling::v2beta1::HorizontalPodAutoscaler),
+
+    /// `batch/v1` `Job`
+    #[cfg(feature = "v1_8-batch-v1")]
+    BatchV1Job(::v1_8::api::batch::v1::Job),
+
+    /// `batch/v1beta1` `CronJob`
+    #[cfg(feature = "v1_8-batch-v1beta1")]
+    BatchV1beta1CronJob(::v1_8::api::batch::v1beta1::CronJob),
+
+    /// `batch/v2alpha1` `CronJob`
+    #[cfg(feature = "v1_8-batch-v2alpha1")]
+    BatchV2alpha1CronJob(::v1_8::api::batch::v2alpha1::CronJob),
+
+    /// `certificates.k8s.io/v1beta1` `CertificateSigningRequest`
+    #[cfg(feature = "v1_8-certificates-v1beta1")]
+    CertificatesV1beta1CertificateSigningRequest(::v1_8::api::certificates::v1beta1::CertificateSigningRequest),
+
+    /// `v1` `Binding`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Binding(::v1_8::api::core::v1::Binding),
+
+    /// `v1` `ComponentStatus`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1ComponentStatus(::v1_8::api::core::v1::ComponentStatus),
+
+    /// `v1` `ConfigMap`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1ConfigMap(::v1_8::api::core::v1::ConfigMap),
+
+    /// `v1` `Endpoints`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Endpoints(::v1_8::api::core::v1::Endpoints),
+
+    /// `v1` `Event`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Event(::v1_8::api::core::v1::Event),
+
+    /// `v1` `LimitRange`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1LimitRange(::v1_8::api::core::v1::LimitRange),
+
+    /// `v1` `Namespace`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Namespace(::v1_8::api::core::v1::Namespace),
+
+    /// `v1` `Node`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Node(::v1_8::api::core::v1::Node),
+
+    /// `v1` `PersistentVolume`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1PersistentVolume(::v1_8::api::core::v1::PersistentVolume),
+
+    /// `v1` `PersistentVolumeClaim`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1PersistentVolumeClaim(::v1_8::api::core::v1::PersistentVolumeClaim),
+
+    /// `v1` `Pod`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Pod(::v1_8::api::core::v1::Pod),
+
+    /// `v1` `PodTemplate`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1PodTemplate(::v1_8::api::core::v1::PodTemplate),
+
+    /// `v1` `ReplicationController`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1ReplicationController(::v1_8::api::core::v1::ReplicationController),
+
+    /// `v1` `ResourceQuota`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1ResourceQuota(::v1_8::api::core::v1::ResourceQuota),
+
+    /// `v1` `Secret`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Secret(::v1_8::api::core::v1::Secret),
+
+    /// `v1` `Service`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1Service(::v1_8::api::core::v1::Service),
+
+    /// `v1` `ServiceAccount`
+    #[cfg(feature = "v1_8-core-v1")]
+    CoreV1ServiceAccount(::v1_8::api::core::v1::ServiceAccount),
+
+    /// `extensions/v1beta1` `DaemonSet`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1DaemonSet(::v1_8::api::extensions::v1beta1::DaemonSet),
+
+    /// `extensions/v1beta1` `Deployment`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1Deployment(::v1_8::api::extensions::v1beta1::Deployment),
+
+    /// `extensions/v1beta1` `DeploymentRollback`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1DeploymentRollback(::v1_8::api::extensions::v1beta1::DeploymentRollback),
+
+    /// `extensions/v1beta1` `Ingress`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1Ingress(::v1_8::api::extensions::v1beta1::Ingress),
+
+    /// `extensions/v1beta1` `NetworkPolicy`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1NetworkPolicy(::v1_8::api::extensions::v1beta1::NetworkPolicy),
+
+    /// `extensions/v1beta1` `PodSecurityPolicy`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1PodSecurityPolicy(::v1_8::api::extensions::v1beta1::PodSecurityPolicy),
+
+    /// `extensions/v1beta1` `ReplicaSet`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1ReplicaSet(::v1_8::api::extensions::v1beta1::ReplicaSet),
+
+    /// `extensions/v1beta1` `Scale`
+    #[cfg(feature = "v1_8-extensions-v1beta1")]
+    ExtensionsV1beta1Scale(::v1_8::api::extensions::v1beta1::Scale),
+
+    /// `networking.k8s.io/v1` `NetworkPolicy`
+    #[cfg(feature = "v1_8-networking-v1")]
+    NetworkingV1NetworkPolicy(::v1_8::api::networking::v1::NetworkPolicy),
+
+    /// `policy/v1beta1` `Eviction`
+    #[cfg(feature = "v1_8-policy-v1beta1")]
+    PolicyV1beta1Eviction(::v1_8::api::policy::v1beta1::Eviction),
+
+    /// `policy/v1beta1` `PodDisruptionBudget`
+    #[cfg(feature = "v1_8-policy-v1beta1")]
+    PolicyV1beta1PodDisruptionBudget(::v1_8::api::policy::v1beta1::PodDisruptionBudget),
+
+    /// `rbac.authorization.k8s.io/v1` `ClusterRole`
+    #[cfg(feature = "v1_8-rbac-v1")]
+    RbacV1ClusterRole(::v1_8::api::rbac::v1::ClusterRole),
+
+    /// `rbac.authorization.k8s.io/v1` `ClusterRoleBinding`
+    #[cfg(feature = "v1_8-rbac-v1")]
+    RbacV1ClusterRoleBinding(::v1_8::api::rbac::v1::ClusterRoleBinding),
+
+    /// `rbac.authorization.k8s.io/v1` `Role`
+    #[cfg(feature = "v1_8-rbac-v1")]
+    RbacV1Role(::v1_8::api::rbac::v1::Role),
+
+    /// `rbac.authorization.k8s.io/v1` `RoleBinding`
+    #[cfg(feature = "v1_8-rbac-v1")]
+    RbacV1RoleBinding(::v1_8::api::rbac::v1::RoleBinding),
+
+    /// `rbac.authorization.k8s.io/v1alpha1` `ClusterRole`
+    #[cfg(feature = "v1_8-rbac-v1alpha1")]
+    RbacV1alpha1ClusterRole(::v1_8::api::rbac::v1alpha1::ClusterRole),
+
+    /// `rbac.authorization.k8s.io/v1alpha1` `ClusterRoleBinding`
+    #[cfg(feature = "v1_8-rbac-v1alpha1")]
+    RbacV1alpha1ClusterRoleBinding(::v1_8::api::rbac::v1alpha1::ClusterRoleBinding),
+
+    /// `rbac.authorization.k8s.io/v1alpha1` `Role`
+    #[cfg(feature = "v1_8-rbac-v1alpha1")]
+    RbacV1alpha1Role(::v1_8::api::rbac::v1alpha1::Role),
+
+    /// `rbac.authorization.k8s.io/v1alpha1` `RoleBinding`
+    #[cfg(feature = "v1_8-rbac-v1alpha1")]
+    RbacV1alpha1RoleBinding(::v1_8::api::rbac::v1alpha1::RoleBinding),
+
+    /// `rbac.authorization.k8s.io/v1beta1` `ClusterRole`
+    #[cfg(feature = "v1_8-rbac-v1beta1")]
+    RbacV1beta1ClusterRole(::v1_8::api::rbac::v1beta1::ClusterRole),
+
+    /// `rbac.authorization.k8s.io/v1beta1` `ClusterRoleBinding`
+    #[cfg(feature = "v1_8-rbac-v1beta1")]
+    RbacV1beta1ClusterRoleBinding(::v1_8::api::rbac::v1beta1::ClusterRoleBinding),
+
+    /// `rbac.authorization.k8s.io/v1beta1` `Role`
+    #[cfg(feature = "v1_8-rbac-v1beta1")]
+    RbacV1beta1Role(::v1_8::api::rbac::v1beta1::Role),
+
+    /// `rbac.authorization.k8s.io/v1beta1` `RoleBinding`
+    #[cfg(feature = "v1_8-rbac-v1beta1")]
+    RbacV1beta1RoleBinding(::v1_8::api::rbac::v1beta1::RoleBinding),
+
+    /// `scheduling.k8s.io/v1alpha1` `PriorityClass`
+    #[cfg(feature = "v1_8-scheduling-v1alpha1")]
+    SchedulingV1alpha1PriorityClass(::v1_8::api::scheduling::v1alpha1::PriorityClass),
+
+    /// `settings.k8s.io/v1alpha1` `PodPreset`
+    #[cfg(feature = "v1_8-settings-v1alpha1")]
+    SettingsV1alpha1PodPreset(::v1_8::api::settings::v1alpha1::PodPreset),
+
+    /// `storage.k8s.io/v1` `StorageClass`
+    #[cfg(feature = "v1_8-storage-v1")]
+    StorageV1StorageClass(::v1_8::api::storage::v1::StorageClass),
+
+    /// `storage.k8s.io/v1beta1` `StorageClass`
+    #[cfg(feature = "v1_8-storage-v1beta1")]
+    StorageV1beta1StorageClass(::v1_8::api::storage::v1beta1::StorageClass),
+
+    /// A value that is not of any enabled resource kind.
+    Unknown(::serde_json::Value),
+}
+
+impl<'de> ::serde::Deserialize<'de> for Resource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {
+        ::tagged::deserialize(deserializer)
+    }
+}
+
+impl<'de> ::tagged::Tagged<'de> for Resource {
+    const TAGS: &'static [&'static str] = &["apiVersion", "kind"];
+
+    fn deserialize_tagged<D>(tags: &[Option<String>], deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {
+        Ok(match (tags[0].as_ref().map(String::as_str), tags[1].as_ref().map(String::as_str)) {
+            #[cfg(feature = "v1_8-admissionregistration-v1alpha1")]
+            (Some("admissionregistration.k8s.io/v1alpha1"), Some("ExternalAdmissionHookConfiguration")) => Resource::AdmissionregistrationV1alpha1ExternalAdmissionHookConfiguration(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-admissionregistration-v1alpha1")]
+            (Some("admissionregistration.k8s.io/v1alpha1"), Some("InitializerConfiguration")) => Resource::AdmissionregistrationV1alpha1InitializerConfiguration(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apiextensions-v1beta1")]
+            (Some("apiextensions.k8s.io/v1beta1"), Some("CustomResourceDefinition")) => Resource::ApiextensionsV1beta1CustomResourceDefinition(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apiregistration-v1beta1")]
+            (Some("apiregistration.k8s.io/v1beta1"), Some("APIService")) => Resource::ApiregistrationV1beta1APIService(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            (Some("apps/v1beta1"), Some("ControllerRevision")) => Resource::AppsV1beta1ControllerRevision(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            (Some("apps/v1beta1"), Some("Deployment")) => Resource::AppsV1beta1Deployment(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            (Some("apps/v1beta1"), Some("DeploymentRollback")) => Resource::AppsV1beta1DeploymentRollback(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            (Some("apps/v1beta1"), Some("Scale")) => Resource::AppsV1beta1Scale(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            (Some("apps/v1beta1"), Some("StatefulSet")) => Resource::AppsV1beta1StatefulSet(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            (Some("apps/v1beta2"), Some("ControllerRevision")) => Resource::AppsV1beta2ControllerRevision(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            (Some("apps/v1beta2"), Some("DaemonSet")) => Resource::AppsV1beta2DaemonSet(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            (Some("apps/v1beta2"), Some("Deployment")) => Resource::AppsV1beta2Deployment(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            (Some("apps/v1beta2"), Some("ReplicaSet")) => Resource::AppsV1beta2ReplicaSet(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            (Some("apps/v1beta2"), Some("Scale")) => Resource::AppsV1beta2Scale(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            (Some("apps/v1beta2"), Some("StatefulSet")) => Resource::AppsV1beta2StatefulSet(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authentication-v1")]
+            (Some("authentication.k8s.io/v1"), Some("TokenReview")) => Resource::AuthenticationV1TokenReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authentication-v1beta1")]
+            (Some("authentication.k8s.io/v1beta1"), Some("TokenReview")) => Resource::AuthenticationV1beta1TokenReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            (Some("authorization.k8s.io/v1"), Some("LocalSubjectAccessReview")) => Resource::AuthorizationV1LocalSubjectAccessReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            (Some("authorization.k8s.io/v1"), Some("SelfSubjectAccessReview")) => Resource::AuthorizationV1SelfSubjectAccessReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            (Some("authorization.k8s.io/v1"), Some("SelfSubjectRulesReview")) => Resource::AuthorizationV1SelfSubjectRulesReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            (Some("authorization.k8s.io/v1"), Some("SubjectAccessReview")) => Resource::AuthorizationV1SubjectAccessReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            (Some("authorization.k8s.io/v1beta1"), Some("LocalSubjectAccessReview")) => Resource::AuthorizationV1beta1LocalSubjectAccessReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            (Some("authorization.k8s.io/v1beta1"), Some("SelfSubjectAccessReview")) => Resource::AuthorizationV1beta1SelfSubjectAccessReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            (Some("authorization.k8s.io/v1beta1"), Some("SelfSubjectRulesReview")) => Resource::AuthorizationV1beta1SelfSubjectRulesReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            (Some("authorization.k8s.io/v1beta1"), Some("SubjectAccessReview")) => Resource::AuthorizationV1beta1SubjectAccessReview(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-autoscaling-v1")]
+            (Some("autoscaling/v1"), Some("HorizontalPodAutoscaler")) => Resource::AutoscalingV1HorizontalPodAutoscaler(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-autoscaling-v1")]
+            (Some("autoscaling/v1"), Some("Scale")) => Resource::AutoscalingV1Scale(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-autoscaling-v2beta1")]
+            (Some("autoscaling/v2beta1"), Some("HorizontalPodAutoscaler")) => Resource::AutoscalingV2beta1HorizontalPodAutoscaler(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-batch-v1")]
+            (Some("batch/v1"), Some("Job")) => Resource::BatchV1Job(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-batch-v1beta1")]
+            (Some("batch/v1beta1"), Some("CronJob")) => Resource::BatchV1beta1CronJob(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-batch-v2alpha1")]
+            (Some("batch/v2alpha1"), Some("CronJob")) => Resource::BatchV2alpha1CronJob(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-certificates-v1beta1")]
+            (Some("certificates.k8s.io/v1beta1"), Some("CertificateSigningRequest")) => Resource::CertificatesV1beta1CertificateSigningRequest(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Binding")) => Resource::CoreV1Binding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("ComponentStatus")) => Resource::CoreV1ComponentStatus(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("ConfigMap")) => Resource::CoreV1ConfigMap(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Endpoints")) => Resource::CoreV1Endpoints(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Event")) => Resource::CoreV1Event(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("LimitRange")) => Resource::CoreV1LimitRange(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Namespace")) => Resource::CoreV1Namespace(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Node")) => Resource::CoreV1Node(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("PersistentVolume")) => Resource::CoreV1PersistentVolume(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("PersistentVolumeClaim")) => Resource::CoreV1PersistentVolumeClaim(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Pod")) => Resource::CoreV1Pod(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("PodTemplate")) => Resource::CoreV1PodTemplate(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("ReplicationController")) => Resource::CoreV1ReplicationController(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("ResourceQuota")) => Resource::CoreV1ResourceQuota(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Secret")) => Resource::CoreV1Secret(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("Service")) => Resource::CoreV1Service(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-core-v1")]
+            (Some("v1"), Some("ServiceAccount")) => Resource::CoreV1ServiceAccount(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("DaemonSet")) => Resource::ExtensionsV1beta1DaemonSet(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("Deployment")) => Resource::ExtensionsV1beta1Deployment(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("DeploymentRollback")) => Resource::ExtensionsV1beta1DeploymentRollback(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("Ingress")) => Resource::ExtensionsV1beta1Ingress(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("NetworkPolicy")) => Resource::ExtensionsV1beta1NetworkPolicy(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("PodSecurityPolicy")) => Resource::ExtensionsV1beta1PodSecurityPolicy(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("ReplicaSet")) => Resource::ExtensionsV1beta1ReplicaSet(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            (Some("extensions/v1beta1"), Some("Scale")) => Resource::ExtensionsV1beta1Scale(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-networking-v1")]
+            (Some("networking.k8s.io/v1"), Some("NetworkPolicy")) => Resource::NetworkingV1NetworkPolicy(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-policy-v1beta1")]
+            (Some("policy/v1beta1"), Some("Eviction")) => Resource::PolicyV1beta1Eviction(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-policy-v1beta1")]
+            (Some("policy/v1beta1"), Some("PodDisruptionBudget")) => Resource::PolicyV1beta1PodDisruptionBudget(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            (Some("rbac.authorization.k8s.io/v1"), Some("ClusterRole")) => Resource::RbacV1ClusterRole(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            (Some("rbac.authorization.k8s.io/v1"), Some("ClusterRoleBinding")) => Resource::RbacV1ClusterRoleBinding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            (Some("rbac.authorization.k8s.io/v1"), Some("Role")) => Resource::RbacV1Role(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            (Some("rbac.authorization.k8s.io/v1"), Some("RoleBinding")) => Resource::RbacV1RoleBinding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            (Some("rbac.authorization.k8s.io/v1alpha1"), Some("ClusterRole")) => Resource::RbacV1alpha1ClusterRole(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            (Some("rbac.authorization.k8s.io/v1alpha1"), Some("ClusterRoleBinding")) => Resource::RbacV1alpha1ClusterRoleBinding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            (Some("rbac.authorization.k8s.io/v1alpha1"), Some("Role")) => Resource::RbacV1alpha1Role(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            (Some("rbac.authorization.k8s.io/v1alpha1"), Some("RoleBinding")) => Resource::RbacV1alpha1RoleBinding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            (Some("rbac.authorization.k8s.io/v1beta1"), Some("ClusterRole")) => Resource::RbacV1beta1ClusterRole(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            (Some("rbac.authorization.k8s.io/v1beta1"), Some("ClusterRoleBinding")) => Resource::RbacV1beta1ClusterRoleBinding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            (Some("rbac.authorization.k8s.io/v1beta1"), Some("Role")) => Resource::RbacV1beta1Role(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            (Some("rbac.authorization.k8s.io/v1beta1"), Some("RoleBinding")) => Resource::RbacV1beta1RoleBinding(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-scheduling-v1alpha1")]
+            (Some("scheduling.k8s.io/v1alpha1"), Some("PriorityClass")) => Resource::SchedulingV1alpha1PriorityClass(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-settings-v1alpha1")]
+            (Some("settings.k8s.io/v1alpha1"), Some("PodPreset")) => Resource::SettingsV1alpha1PodPreset(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-storage-v1")]
+            (Some("storage.k8s.io/v1"), Some("StorageClass")) => Resource::StorageV1StorageClass(::serde::Deserialize::deserialize(deserializer)?),
+            #[cfg(feature = "v1_8-storage-v1beta1")]
+            (Some("storage.k8s.io/v1beta1"), Some("StorageClass")) => Resource::StorageV1beta1StorageClass(::serde::Deserialize::deserialize(deserializer)?),
+            _ => Resource::Unknown(::serde::Deserialize::deserialize(deserializer)?),
+        })
+    }
+}
+
+impl ::serde::Serialize for Resource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::serde::Serializer {
+        match self {
+            #[cfg(feature = "v1_8-admissionregistration-v1alpha1")]
+            Resource::AdmissionregistrationV1alpha1ExternalAdmissionHookConfiguration(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-admissionregistration-v1alpha1")]
+            Resource::AdmissionregistrationV1alpha1InitializerConfiguration(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apiextensions-v1beta1")]
+            Resource::ApiextensionsV1beta1CustomResourceDefinition(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apiregistration-v1beta1")]
+            Resource::ApiregistrationV1beta1APIService(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            Resource::AppsV1beta1ControllerRevision(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            Resource::AppsV1beta1Deployment(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            Resource::AppsV1beta1DeploymentRollback(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            Resource::AppsV1beta1Scale(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta1")]
+            Resource::AppsV1beta1StatefulSet(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            Resource::AppsV1beta2ControllerRevision(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            Resource::AppsV1beta2DaemonSet(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            Resource::AppsV1beta2Deployment(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            Resource::AppsV1beta2ReplicaSet(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            Resource::AppsV1beta2Scale(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-apps-v1beta2")]
+            Resource::AppsV1beta2StatefulSet(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authentication-v1")]
+            Resource::AuthenticationV1TokenReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authentication-v1beta1")]
+            Resource::AuthenticationV1beta1TokenReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            Resource::AuthorizationV1LocalSubjectAccessReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            Resource::AuthorizationV1SelfSubjectAccessReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            Resource::AuthorizationV1SelfSubjectRulesReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1")]
+            Resource::AuthorizationV1SubjectAccessReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            Resource::AuthorizationV1beta1LocalSubjectAccessReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            Resource::AuthorizationV1beta1SelfSubjectAccessReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            Resource::AuthorizationV1beta1SelfSubjectRulesReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-authorization-v1beta1")]
+            Resource::AuthorizationV1beta1SubjectAccessReview(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-autoscaling-v1")]
+            Resource::AutoscalingV1HorizontalPodAutoscaler(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-autoscaling-v1")]
+            Resource::AutoscalingV1Scale(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-autoscaling-v2beta1")]
+            Resource::AutoscalingV2beta1HorizontalPodAutoscaler(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-batch-v1")]
+            Resource::BatchV1Job(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-batch-v1beta1")]
+            Resource::BatchV1beta1CronJob(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-batch-v2alpha1")]
+            Resource::BatchV2alpha1CronJob(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-certificates-v1beta1")]
+            Resource::CertificatesV1beta1CertificateSigningRequest(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Binding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1ComponentStatus(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1ConfigMap(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Endpoints(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Event(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1LimitRange(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Namespace(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Node(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1PersistentVolume(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1PersistentVolumeClaim(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Pod(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1PodTemplate(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1ReplicationController(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1ResourceQuota(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Secret(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1Service(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-core-v1")]
+            Resource::CoreV1ServiceAccount(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1DaemonSet(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1Deployment(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1DeploymentRollback(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1Ingress(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1NetworkPolicy(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1PodSecurityPolicy(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1ReplicaSet(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-extensions-v1beta1")]
+            Resource::ExtensionsV1beta1Scale(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-networking-v1")]
+            Resource::NetworkingV1NetworkPolicy(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-policy-v1beta1")]
+            Resource::PolicyV1beta1Eviction(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-policy-v1beta1")]
+            Resource::PolicyV1beta1PodDisruptionBudget(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            Resource::RbacV1ClusterRole(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            Resource::RbacV1ClusterRoleBinding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            Resource::RbacV1Role(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1")]
+            Resource::RbacV1RoleBinding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            Resource::RbacV1alpha1ClusterRole(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            Resource::RbacV1alpha1ClusterRoleBinding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            Resource::RbacV1alpha1Role(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1alpha1")]
+            Resource::RbacV1alpha1RoleBinding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            Resource::RbacV1beta1ClusterRole(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            Resource::RbacV1beta1ClusterRoleBinding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            Resource::RbacV1beta1Role(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-rbac-v1beta1")]
+            Resource::RbacV1beta1RoleBinding(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-scheduling-v1alpha1")]
+            Resource::SchedulingV1alpha1PriorityClass(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-settings-v1alpha1")]
+            Resource::SettingsV1alpha1PodPreset(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-storage-v1")]
+            Resource::StorageV1StorageClass(value) => value.serialize(serializer),
+            #[cfg(feature = "v1_8-storage-v1beta1")]
+            Resource::StorageV1beta1StorageClass(value) => value.serialize(serializer),
+            Resource::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
@@ -134,3 +134,16 @@ impl ::serde::Serialize for DaemonSetCondition {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_9-apps-v1beta2")]
+impl From<::v1_9::api::apps::v1beta2::DaemonSetCondition> for DaemonSetCondition {
+    fn from(value: ::v1_9::api::apps::v1beta2::DaemonSetCondition) -> Self {
+        DaemonSetCondition {
+            last_transition_time: value.last_transition_time,
+            message: value.message,
+            reason: value.reason,
+            status: value.status,
+            type_: value.type_,
+        }
+    }
+}
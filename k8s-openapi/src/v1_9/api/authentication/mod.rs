@@ -1,3 +1,5 @@
+#[cfg(feature = "v1_9-authentication-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_9-authentication-v1beta1")]
 pub mod v1beta1;
@@ -1,5 +1,8 @@
+#[cfg(feature = "v1_9-batch-v1")]
 pub mod v1;
 
+#[cfg(feature = "v1_9-batch-v1beta1")]
 pub mod v1beta1;
 
+#[cfg(feature = "v1_9-batch-v2alpha1")]
 pub mod v2alpha1;
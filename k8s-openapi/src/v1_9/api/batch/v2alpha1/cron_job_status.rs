@@ -99,3 +99,13 @@ impl ::serde::Serialize for CronJobStatus {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_9-batch-v1beta1")]
+impl From<::v1_9::api::batch::v1beta1::CronJobStatus> for CronJobStatus {
+    fn from(value: ::v1_9::api::batch::v1beta1::CronJobStatus) -> Self {
+        CronJobStatus {
+            active: value.active,
+            last_schedule_time: value.last_schedule_time,
+        }
+    }
+}
@@ -99,3 +99,13 @@ impl ::serde::Serialize for JobTemplateSpec {
         ::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "v1_9-batch-v1beta1")]
+impl From<::v1_9::api::batch::v1beta1::JobTemplateSpec> for JobTemplateSpec {
+    fn from(value: ::v1_9::api::batch::v1beta1::JobTemplateSpec) -> Self {
+        JobTemplateSpec {
+            metadata: value.metadata,
+            spec: value.spec,
+        }
+    }
+}
@@ -54,14 +54,26 @@ fn main() -> Result<(), Error> {
 	let out_dir_base: &std::path::Path = env!("CARGO_MANIFEST_DIR").as_ref();
 	let out_dir_base = out_dir_base.join("k8s-openapi").join("src");
 
+	let mut features = vec![];
+
 	for &supported_version in supported_version::ALL {
-		run(supported_version, &out_dir_base, &client)?;
+		features.push((supported_version.mod_root(), run(supported_version, &out_dir_base, &client)?));
 	}
 
+	let cargo_toml_path = out_dir_base.parent().ok_or_else(|| "out_dir_base has no parent")?.join("Cargo.toml");
+	write_features(&cargo_toml_path, &features)?;
+
 	Ok(())
 }
 
-fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &std::path::Path, client: &reqwest::Client) -> Result<(), Error> {
+/// Generates the bindings for the given version.
+///
+/// Returns the cargo features of the version's API group versions, along with the features that each of them depends on.
+fn run(
+	supported_version: supported_version::SupportedVersion,
+	out_dir_base: &std::path::Path,
+	client: &reqwest::Client,
+) -> Result<std::collections::BTreeMap<String, std::collections::BTreeSet<String>>, Error> {
 	use std::io::Write;
 
 	let mod_root = supported_version.mod_root();
@@ -132,12 +144,116 @@ fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &st
 		}
 	}
 
+	// The apiVersion of every definition that has operations, ie every resource kind
+	let api_versions: std::collections::BTreeMap<_, _> =
+		spec.definitions.iter()
+		.filter_map(|(definition_path, definition)| {
+			let mut kubernetes_group_kind_versions: Vec<_> = definition.kubernetes_group_kind_versions.as_ref()?.iter().collect();
+			kubernetes_group_kind_versions.sort();
+			let kubernetes_group_kind_version =
+				kubernetes_group_kind_versions.into_iter()
+				.find(|kubernetes_group_kind_version| operations.contains_key(&Some(*kubernetes_group_kind_version)))?;
+			let api_version =
+				if kubernetes_group_kind_version.group.is_empty() {
+					kubernetes_group_kind_version.version.clone()
+				}
+				else {
+					format!("{}/{}", kubernetes_group_kind_version.group, kubernetes_group_kind_version.version)
+				};
+			Some((&**definition_path, api_version))
+		})
+		.collect();
+
+	// Every GVK that has operations, ie every resource kind, keyed by the name of its variant in the `Resource` enum
+	let mut resource_kinds: std::collections::BTreeMap<_, _> = Default::default();
+	for (definition_path, definition) in &spec.definitions {
+		if let Some(kubernetes_group_kind_versions) = &definition.kubernetes_group_kind_versions {
+			for kubernetes_group_kind_version in kubernetes_group_kind_versions {
+				if !operations.contains_key(&Some(kubernetes_group_kind_version)) {
+					continue;
+				}
+
+				let group = kubernetes_group_kind_version.group.split('.').next().filter(|group| !group.is_empty()).unwrap_or("core");
+				let variant_name = get_rust_variant_name(&format!("{}.{}.{}", group, kubernetes_group_kind_version.version, kubernetes_group_kind_version.kind));
+				if let Some((other_definition_path, _)) = resource_kinds.insert(variant_name.clone(), (&**definition_path, kubernetes_group_kind_version)) {
+					return Err(format!("{} and {} both have the Resource variant name {}", other_definition_path, definition_path, variant_name).into());
+				}
+			}
+		}
+	}
+
+	let conversions = get_conversions(&spec, &api_versions, &replace_namespaces, mod_root)?;
+
+	// The cargo feature of every API group version, and the features of the other API group versions that its types and operations refer to
+	let mut features: std::collections::BTreeMap<_, std::collections::BTreeSet<_>> = Default::default();
+	{
+		let mut refs: std::collections::BTreeMap<_, std::collections::BTreeSet<_>> = Default::default();
+
+		for (definition_path, definition) in &spec.definitions {
+			collect_refs(&definition.kind, refs.entry(&**definition_path).or_insert_with(Default::default));
+		}
+
+		// Operations are emitted with the first definition that has their GVK. Operations without a GVK are emitted in the version's root module.
+		let mut operation_definition_paths: std::collections::BTreeMap<_, _> = Default::default();
+		for (definition_path, definition) in &spec.definitions {
+			if let Some(kubernetes_group_kind_versions) = &definition.kubernetes_group_kind_versions {
+				for kubernetes_group_kind_version in kubernetes_group_kind_versions {
+					operation_definition_paths.entry(kubernetes_group_kind_version).or_insert(&**definition_path);
+				}
+			}
+		}
+
+		for (kubernetes_group_kind_version, operations) in &operations {
+			let definition_path =
+				kubernetes_group_kind_version
+				.and_then(|kubernetes_group_kind_version| operation_definition_paths.get(kubernetes_group_kind_version))
+				.cloned()
+				.unwrap_or("");
+			let refs = refs.entry(definition_path).or_insert_with(Default::default);
+
+			for (_, path_item, operation) in operations {
+				for parameter in path_item.parameters.iter().chain(&operation.parameters) {
+					collect_refs(&parameter.schema.kind, refs);
+				}
+
+				for schema in operation.responses.values() {
+					if let Some(schema) = schema {
+						collect_refs(&schema.kind, refs);
+					}
+				}
+			}
+		}
+
+		for (definition_path, refs) in refs {
+			let feature = get_api_group_version_feature(definition_path, mod_root);
+			if let Some(feature) = &feature {
+				features.entry(feature.clone()).or_insert_with(Default::default);
+			}
+
+			for ref_path in refs {
+				match (&feature, get_api_group_version_feature(ref_path, mod_root)) {
+					(Some(feature), Some(ref_feature)) => if *feature != ref_feature {
+						features.entry(feature.clone()).or_insert_with(Default::default).insert(ref_feature);
+					},
+
+					// Types outside API groups, like `apimachinery`, and the operations in the version's root module are always compiled,
+					// so they must not refer to types in API groups.
+					(None, Some(_)) => return Err(format!("{:?} is not in an API group but refers to {}", definition_path, ref_path).into()),
+
+					(_, None) => (),
+				}
+			}
+		}
+	}
+
 	info!("Generating types...");
 
 	for (definition_path, definition) in &spec.definitions {
 		trace!("Working on {} ...", definition_path);
 
-		let (mut file, type_name, type_ref_path) = create_file_for_type(&definition_path, &out_dir, &replace_namespaces)?;
+		let feature = get_api_group_version_feature(definition_path, mod_root);
+
+		let (mut file, type_name, type_ref_path) = create_file_for_type(&definition_path, &out_dir, &replace_namespaces, feature.as_ref().map(String::as_str))?;
 
 		writeln!(file, "// Generated from definition {}", definition_path)?;
 		writeln!(file)?;
@@ -237,11 +353,25 @@ fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &st
 				}
 				writeln!(file, "}}")?;
 
+				// The GVK whose list operations are emitted with this type, and whether that GVK is namespaced
+				let mut listable_kubernetes_group_kind_version = None;
+
 				if let Some(kubernetes_group_kind_versions) = &definition.kubernetes_group_kind_versions {
 					let mut kubernetes_group_kind_versions: Vec<_> = kubernetes_group_kind_versions.into_iter().collect();
 					kubernetes_group_kind_versions.sort();
 					for kubernetes_group_kind_version in kubernetes_group_kind_versions {
 						if let Some(operations) = operations.remove(&Some(kubernetes_group_kind_version)) {
+							let is_listable = operations.iter().any(|(_, _, operation)| match operation.kubernetes_action {
+								Some(swagger20::KubernetesAction::List) => true,
+								_ => false,
+							});
+							// A type can have list operations for more than one GVK, like v1.7's CronJob which is also served as ScheduledJob.
+							// The selectable fields are the same for all of them, so use the first one.
+							if is_listable && listable_kubernetes_group_kind_version.is_none() {
+								let is_namespaced = operations.iter().any(|(path, _, _)| path.contains("{namespace}"));
+								listable_kubernetes_group_kind_version = Some((kubernetes_group_kind_version, is_namespaced));
+							}
+
 							writeln!(file)?;
 							writeln!(file, "// Begin {}/{}/{}",
 								kubernetes_group_kind_version.group, kubernetes_group_kind_version.version, kubernetes_group_kind_version.kind)?;
@@ -388,6 +518,245 @@ fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &st
 				writeln!(file, "    }}")?;
 				writeln!(file, "}}")?;
 
+				match &**definition_path {
+					"io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelector" => {
+						let label_selector_requirement_type_name =
+							get_fully_qualified_type_name(
+								&swagger20::RefPath("io.k8s.apimachinery.pkg.apis.meta.v1.LabelSelectorRequirement".to_string()),
+								&replace_namespaces,
+								mod_root)?;
+
+						writeln!(file)?;
+						writeln!(file, "impl {} {{", type_name)?;
+						writeln!(file, "    /// Converts this label selector into a `::label_selector::Selector`.")?;
+						writeln!(file, "    ///")?;
+						writeln!(file, "    /// Returns an error if one of the match expressions has an unknown operator, or has values that don't match its operator.")?;
+						writeln!(file, "    pub fn to_selector(&self) -> Result<::label_selector::Selector, ::label_selector::Error> {{")?;
+						writeln!(file, "        let mut requirements = vec![];")?;
+						writeln!(file)?;
+						writeln!(file, "        if let Some(match_labels) = &self.match_labels {{")?;
+						writeln!(file, "            for (key, value) in match_labels {{")?;
+						writeln!(file, "                requirements.push(::label_selector::Requirement {{ key: key.clone(), operator: ::label_selector::Operator::Equals(value.clone()) }});")?;
+						writeln!(file, "            }}")?;
+						writeln!(file, "        }}")?;
+						writeln!(file)?;
+						writeln!(file, "        if let Some(match_expressions) = &self.match_expressions {{")?;
+						writeln!(file, "            for match_expression in match_expressions {{")?;
+						writeln!(file, "                let values = match_expression.values.as_ref().map_or(&[][..], |values| &values[..]);")?;
+						writeln!(file, "                let operator = ::label_selector::Operator::from_parts(&match_expression.operator, values)?;")?;
+						writeln!(file, "                requirements.push(::label_selector::Requirement {{ key: match_expression.key.clone(), operator }});")?;
+						writeln!(file, "            }}")?;
+						writeln!(file, "        }}")?;
+						writeln!(file)?;
+						writeln!(file, "        Ok(::label_selector::Selector(requirements))")?;
+						writeln!(file, "    }}")?;
+						writeln!(file, "}}")?;
+						writeln!(file)?;
+						writeln!(file, "impl From<::label_selector::Selector> for {} {{", type_name)?;
+						writeln!(file, "    fn from(selector: ::label_selector::Selector) -> Self {{")?;
+						writeln!(file, "        let mut match_labels: ::std::collections::BTreeMap<_, _> = Default::default();")?;
+						writeln!(file, "        let mut match_expressions = vec![];")?;
+						writeln!(file)?;
+						writeln!(file, "        for ::label_selector::Requirement {{ key, operator }} in selector.0 {{")?;
+						writeln!(file, "            match operator {{")?;
+						writeln!(file, "                ::label_selector::Operator::Equals(ref value) if !match_labels.contains_key(&key) => {{")?;
+						writeln!(file, "                    match_labels.insert(key, value.clone());")?;
+						writeln!(file, "                }},")?;
+						writeln!(file)?;
+						writeln!(file, "                operator => {{")?;
+						writeln!(file, "                    let (operator, values) = operator.into_parts();")?;
+						writeln!(file, "                    match_expressions.push({} {{ key, operator: operator.to_string(), values }});", label_selector_requirement_type_name)?;
+						writeln!(file, "                }},")?;
+						writeln!(file, "            }}")?;
+						writeln!(file, "        }}")?;
+						writeln!(file)?;
+						writeln!(file, "        {} {{", type_name)?;
+						writeln!(file, "            match_expressions: if match_expressions.is_empty() {{ None }} else {{ Some(match_expressions) }},")?;
+						writeln!(file, "            match_labels: if match_labels.is_empty() {{ None }} else {{ Some(match_labels) }},")?;
+						writeln!(file, "        }}")?;
+						writeln!(file, "    }}")?;
+						writeln!(file, "}}")?;
+					},
+
+					"io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta" => {
+						writeln!(file)?;
+						writeln!(file, "impl ::label_selector::Labels for {} {{", type_name)?;
+						writeln!(file, "    fn labels(&self) -> Option<&::std::collections::BTreeMap<String, String>> {{")?;
+						writeln!(file, "        self.labels.as_ref()")?;
+						writeln!(file, "    }}")?;
+						writeln!(file, "}}")?;
+					},
+
+					_ => (),
+				}
+
+				// List types, like `PodList`, have a `ListMeta` and an array of items
+				let list_properties = (
+					properties.iter().find(|property| &**property.name == "items"),
+					properties.iter().find(|property| &**property.name == "metadata"),
+				);
+				if let (Some(items_property), Some(metadata_property)) = list_properties {
+					match (&items_property.schema.kind, &metadata_property.schema.kind) {
+						(swagger20::SchemaKind::Ty(swagger20::Type::Array { items }), swagger20::SchemaKind::Ref(metadata_ref_path))
+							if &**metadata_ref_path == "io.k8s.apimachinery.pkg.apis.meta.v1.ListMeta" =>
+						{
+							let list_meta_has_continue =
+								match spec.definitions.get(&swagger20::DefinitionPath(metadata_ref_path.0.clone())).map(|definition| &definition.kind) {
+									Some(swagger20::SchemaKind::Properties(properties)) => properties.contains_key(&swagger20::PropertyName("continue".to_string())),
+									_ => false,
+								};
+
+							writeln!(file)?;
+							writeln!(file, "impl ::List for {} {{", type_name)?;
+							writeln!(file, "    type Item = {};", get_rust_type(&items.kind, &replace_namespaces, mod_root)?)?;
+							writeln!(file)?;
+							writeln!(file, "    fn continue_token(&self) -> Option<&str> {{")?;
+							match (list_meta_has_continue, metadata_property.required) {
+								(true, true) => writeln!(file, "        self.metadata.continue_.as_ref().map(String::as_str)")?,
+								(true, false) => writeln!(file, "        self.metadata.as_ref().and_then(|metadata| metadata.continue_.as_ref()).map(String::as_str)")?,
+								(false, _) => writeln!(file, "        None")?,
+							}
+							writeln!(file, "    }}")?;
+							writeln!(file)?;
+							writeln!(file, "    fn into_items(self) -> Vec<Self::Item> {{")?;
+							if items_property.required {
+								writeln!(file, "        self.items")?;
+							}
+							else {
+								writeln!(file, "        self.items.unwrap_or_default()")?;
+							}
+							writeln!(file, "    }}")?;
+							writeln!(file, "}}")?;
+						},
+
+						_ => (),
+					}
+				}
+
+				if let Some((kubernetes_group_kind_version, is_namespaced)) = listable_kubernetes_group_kind_version {
+					let mut fields = vec!["metadata.name"];
+					if is_namespaced {
+						fields.push("metadata.namespace");
+					}
+					fields.extend(supported_version.selectable_fields(&kubernetes_group_kind_version.group, &kubernetes_group_kind_version.kind));
+
+					let fields: Vec<_> = fields.into_iter().map(|field| (field, get_rust_variant_name(field))).collect();
+
+					writeln!(file)?;
+					writeln!(file, "/// The fields of [`{}`](./struct.{}.html) that can be used in a [`::field_selector::FieldSelector`].", type_name, type_name)?;
+					writeln!(file, "#[derive(Clone, Copy, Debug, Eq, PartialEq)]")?;
+					writeln!(file, "pub enum {}Field {{", type_name)?;
+					for (i, (field, variant_name)) in fields.iter().enumerate() {
+						if i > 0 {
+							writeln!(file)?;
+						}
+
+						writeln!(file, "    /// `{}`", field)?;
+						writeln!(file, "    {},", variant_name)?;
+					}
+					writeln!(file, "}}")?;
+					writeln!(file)?;
+					writeln!(file, "impl ::field_selector::Field for {}Field {{", type_name)?;
+					writeln!(file, "    fn path(&self) -> &'static str {{")?;
+					writeln!(file, "        match self {{")?;
+					for (field, variant_name) in &fields {
+						writeln!(file, r#"            {}Field::{} => "{}","#, type_name, variant_name, field)?;
+					}
+					writeln!(file, "        }}")?;
+					writeln!(file, "    }}")?;
+					writeln!(file)?;
+					writeln!(file, "    fn from_path(path: &str) -> Option<Self> {{")?;
+					writeln!(file, "        match path {{")?;
+					for (field, variant_name) in &fields {
+						writeln!(file, r#"            "{}" => Some({}Field::{}),"#, field, type_name, variant_name)?;
+					}
+					writeln!(file, "            _ => None,")?;
+					writeln!(file, "        }}")?;
+					writeln!(file, "    }}")?;
+					writeln!(file, "}}")?;
+				}
+
+				if let Some(conversions) = conversions.get(&**definition_path) {
+					let api_version = api_versions.get(&**definition_path);
+
+					for (source_definition_path, conversion) in conversions {
+						let source_type_name = get_fully_qualified_type_name(&swagger20::RefPath(source_definition_path.to_string()), &replace_namespaces, mod_root)?;
+
+						writeln!(file)?;
+
+						if let Some(source_feature) = get_api_group_version_feature(source_definition_path, mod_root) {
+							writeln!(file, r#"#[cfg(feature = "{}")]"#, source_feature)?;
+						}
+
+						match conversion {
+							Conversion::From => {
+								let source_properties = match &spec.definitions[&swagger20::DefinitionPath(source_definition_path.to_string())].kind {
+									swagger20::SchemaKind::Properties(properties) => properties,
+									_ => return Err(format!("{} is not structurally identical to {}", source_definition_path, definition_path).into()),
+								};
+
+								writeln!(file, "impl From<{}> for {} {{", source_type_name, type_name)?;
+								if properties.is_empty() {
+									writeln!(file, "    fn from(_: {}) -> Self {{", source_type_name)?;
+									writeln!(file, "        {} {{}}", type_name)?;
+								}
+								else {
+									writeln!(file, "    fn from(value: {}) -> Self {{", source_type_name)?;
+									writeln!(file, "        {} {{", type_name)?;
+									for Property { name, schema, required, field_name, .. } in &properties {
+										let (source_schema, _) = &source_properties[*name];
+
+										let value = format!("value.{}", field_name);
+
+										let expr = match (&***name, api_version) {
+											("apiVersion", Some(api_version)) if *required => format!(r#""{}".to_string()"#, api_version),
+											("apiVersion", Some(api_version)) => format!(r#"{}.map(|_| "{}".to_string())"#, value, api_version),
+											_ if *required => get_conversion_expr(&source_schema.kind, &schema.kind, &value).unwrap_or(value),
+											_ => match get_conversion_expr(&source_schema.kind, &schema.kind, "value") {
+												Some(expr) => format!("{}.map({})", value, get_conversion_closure(&expr)),
+												None => value,
+											},
+										};
+
+										writeln!(file, "            {}: {},", field_name, expr)?;
+									}
+								}
+								writeln!(file, "        }}")?;
+								writeln!(file, "    }}")?;
+								writeln!(file, "}}")?;
+							},
+
+							Conversion::TryFrom => {
+								let api_version_property =
+									properties.iter()
+									.find(|Property { name, .. }| &***name == "apiVersion")
+									.and_then(|Property { required, .. }| api_version.map(|api_version| (api_version, *required)));
+
+								writeln!(file, "impl ::std::convert::TryFrom<{}> for {} {{", source_type_name, type_name)?;
+								writeln!(file, "    type Error = ::conversion::Error;")?;
+								writeln!(file)?;
+								writeln!(file, "    fn try_from(value: {}) -> Result<Self, Self::Error> {{", source_type_name)?;
+								match api_version_property {
+									Some((api_version, required)) => {
+										writeln!(file, "        let mut result: Self = ::conversion::convert(value)?;")?;
+										if required {
+											writeln!(file, r#"        result.api_version = "{}".to_string();"#, api_version)?;
+										}
+										else {
+											writeln!(file, r#"        result.api_version = result.api_version.map(|_| "{}".to_string());"#, api_version)?;
+										}
+										writeln!(file, "        Ok(result)")?;
+									},
+
+									None => writeln!(file, "        ::conversion::convert(value)")?,
+								}
+								writeln!(file, "    }}")?;
+								writeln!(file, "}}")?;
+							},
+						}
+					}
+				}
+
 				num_generated_structs += 1;
 			},
 
@@ -406,6 +775,72 @@ fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &st
 				writeln!(file, "    }}")?;
 				writeln!(file, "}}")?;
 				writeln!(file)?;
+
+				let container_port_ref_path =
+					["io.k8s.api.core.v1.ContainerPort", "io.k8s.kubernetes.pkg.api.v1.ContainerPort"].iter()
+					.find(|ref_path| spec.definitions.contains_key(&swagger20::DefinitionPath(ref_path.to_string())))
+					.ok_or_else(|| "couldn't find ContainerPort definition")?;
+				let container_port_type_name = get_fully_qualified_type_name(&swagger20::RefPath(container_port_ref_path.to_string()), &replace_namespaces, mod_root)?;
+				let container_port_feature =
+					get_api_group_version_feature(container_port_ref_path, mod_root)
+					.ok_or_else(|| "ContainerPort definition is not in an API group")?;
+
+				writeln!(file, "impl {} {{", type_name)?;
+				writeln!(file, r#"    /// Returns the percentage held by this value if it is a string of the form `"N%"`."#)?;
+				writeln!(file, "    ///")?;
+				writeln!(file, r#"    /// `N` may have a leading sign, like `"-10%"`, since the API server parses it like Go's `strconv.Atoi`."#)?;
+				writeln!(file, "    ///")?;
+				writeln!(file, "    /// Returns `Ok(None)` if this value is an integer, and an error if it is a string that is not a valid percentage.")?;
+				writeln!(file, "    pub fn percentage(&self) -> Result<Option<i32>, ::IntOrStringError> {{")?;
+				writeln!(file, "        match self {{")?;
+				writeln!(file, "            {}::Int(_) => Ok(None),", type_name)?;
+				writeln!(file, "            {}::String(s) => {{", type_name)?;
+				writeln!(file, r#"                let digits = if s.ends_with('%') {{ &s[..s.len() - 1] }} else {{ "" }};"#)?;
+				writeln!(file, r#"                let unsigned_digits = if digits.starts_with('-') || digits.starts_with('+') {{ &digits[1..] }} else {{ digits }};"#)?;
+				writeln!(file, "                if unsigned_digits.is_empty() || !unsigned_digits.bytes().all(|b| b.is_ascii_digit()) {{")?;
+				writeln!(file, "                    return Err(::IntOrStringError::NotAPercentage(s.clone()));")?;
+				writeln!(file, "                }}")?;
+				writeln!(file)?;
+				writeln!(file, "                let percentage = digits.parse().map_err(|_| ::IntOrStringError::OutOfRange(s.clone()))?;")?;
+				writeln!(file, "                Ok(Some(percentage))")?;
+				writeln!(file, "            }},")?;
+				writeln!(file, "        }}")?;
+				writeln!(file, "    }}")?;
+				writeln!(file)?;
+				writeln!(file, "    /// Resolves this value against the given total, such as the number of replicas of a deployment.")?;
+				writeln!(file, "    ///")?;
+				writeln!(file, "    /// An integer is returned as-is. A percentage is scaled against `total` and rounded up if `round_up` is true, otherwise rounded down.")?;
+				writeln!(file, "    /// The API server rounds up `maxSurge` and rounds down `maxUnavailable`.")?;
+				writeln!(file, "    pub fn scaled_value(&self, total: i32, round_up: bool) -> Result<i32, ::IntOrStringError> {{")?;
+				writeln!(file, "        let (s, percentage) = match self {{")?;
+				writeln!(file, "            {}::Int(i) => return Ok(*i),", type_name)?;
+				writeln!(file, "            {}::String(s) => (s, self.percentage()?.unwrap_or_default()),", type_name)?;
+				writeln!(file, "        }};")?;
+				writeln!(file)?;
+				writeln!(file, "        let value = f64::from(percentage) * f64::from(total) / 100.0;")?;
+				writeln!(file, "        let value = if round_up {{ value.ceil() }} else {{ value.floor() }};")?;
+				writeln!(file, "        if value < f64::from(::std::i32::MIN) || value > f64::from(::std::i32::MAX) {{")?;
+				writeln!(file, "            return Err(::IntOrStringError::OutOfRange(s.clone()));")?;
+				writeln!(file, "        }}")?;
+				writeln!(file)?;
+				writeln!(file, "        Ok(value as i32)")?;
+				writeln!(file, "    }}")?;
+				writeln!(file)?;
+				writeln!(file, "    /// Resolves this value as a port number, such as the `targetPort` of a service port.")?;
+				writeln!(file, "    ///")?;
+				writeln!(file, "    /// An integer is returned as-is. A string is looked up by name in the given container ports, and `None` is returned if no port has that name.")?;
+				writeln!(file, r#"    #[cfg(feature = "{}")]"#, container_port_feature)?;
+				writeln!(file, "    pub fn port_number(&self, ports: &[{}]) -> Option<i32> {{", container_port_type_name)?;
+				writeln!(file, "        match self {{")?;
+				writeln!(file, "            {}::Int(i) => Some(*i),", type_name)?;
+				writeln!(file, "            {}::String(s) =>", type_name)?;
+				writeln!(file, "                ports.iter()")?;
+				writeln!(file, "                .find(|port| port.name.as_ref() == Some(s))")?;
+				writeln!(file, "                .map(|port| port.container_port),")?;
+				writeln!(file, "        }}")?;
+				writeln!(file, "    }}")?;
+				writeln!(file, "}}")?;
+				writeln!(file)?;
 				writeln!(file, "impl<'de> ::serde::Deserialize<'de> for {} {{", type_name)?;
 				writeln!(file, "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {{")?;
 				writeln!(file, "        struct Visitor;")?;
@@ -587,6 +1022,134 @@ fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &st
 		trace!("OK");
 	}
 
+	// Older specs have the core and API group types under `io.k8s.kubernetes.pkg.api` and `io.k8s.kubernetes.pkg.apis` instead of `io.k8s.api`,
+	// so re-export them under `api` to give them the same paths as in newer versions.
+	{
+		let mut api_group_versions: std::collections::BTreeMap<_, std::collections::BTreeSet<_>> = Default::default();
+
+		for definition_path in spec.definitions.keys() {
+			if !definition_path.starts_with("io.k8s.kubernetes.pkg.") {
+				continue;
+			}
+
+			if let Some((group, version)) = get_api_group_version(definition_path) {
+				api_group_versions.entry(group).or_insert_with(Default::default).insert(version);
+			}
+		}
+
+		if !api_group_versions.is_empty() {
+			{
+				let mut mod_root_file = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(out_dir.join("mod.rs"))?);
+				writeln!(mod_root_file)?;
+				writeln!(mod_root_file, "pub mod api;")?;
+			}
+
+			std::fs::create_dir(out_dir.join("api"))?;
+
+			let mut file = std::io::BufWriter::new(std::fs::File::create(out_dir.join("api").join("mod.rs"))?);
+			writeln!(file, "// Re-exports of the modules under `kubernetes::pkg::api` and `kubernetes::pkg::apis`, so that they have the same paths as in newer versions.")?;
+
+			for (group, versions) in api_group_versions {
+				writeln!(file)?;
+				writeln!(file, "pub mod {} {{", get_rust_ident(group))?;
+				for version in versions {
+					writeln!(file, r#"    #[cfg(feature = "{}-{}-{}")]"#, mod_root, group, version)?;
+					if group == "core" {
+						writeln!(file, "    pub use ::{}::kubernetes::pkg::api::{};", mod_root, get_rust_ident(version))?;
+					}
+					else {
+						writeln!(file, "    pub use ::{}::kubernetes::pkg::apis::{}::{};", mod_root, get_rust_ident(group), get_rust_ident(version))?;
+					}
+				}
+				writeln!(file, "}}")?;
+			}
+		}
+	}
+
+	// The `Resource` enum that deserializes a value into the type of the resource kind identified by its `apiVersion` and `kind`
+	{
+		{
+			let mut mod_root_file = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(out_dir.join("mod.rs"))?);
+			writeln!(mod_root_file)?;
+			writeln!(mod_root_file, "mod resource;")?;
+			writeln!(mod_root_file, "pub use self::resource::*;")?;
+		}
+
+		let mut file = std::io::BufWriter::new(std::fs::File::create(out_dir.join("resource.rs"))?);
+
+		let mut variants = vec![];
+		for (variant_name, (definition_path, kubernetes_group_kind_version)) in &resource_kinds {
+			let type_name = get_fully_qualified_type_name(&swagger20::RefPath(definition_path.to_string()), &replace_namespaces, mod_root)?;
+			let api_version =
+				if kubernetes_group_kind_version.group.is_empty() {
+					kubernetes_group_kind_version.version.clone()
+				}
+				else {
+					format!("{}/{}", kubernetes_group_kind_version.group, kubernetes_group_kind_version.version)
+				};
+			let feature = get_api_group_version_feature(definition_path, mod_root);
+			variants.push((variant_name, type_name, api_version, &kubernetes_group_kind_version.kind, feature));
+		}
+
+		writeln!(file, "// Generated from the resource kinds of the spec")?;
+		writeln!(file)?;
+		writeln!(file, "/// A value of any resource kind, like an `apps/v1` `Deployment`, that is identified by its `apiVersion` and `kind` fields.")?;
+		writeln!(file, "///")?;
+		writeln!(file, "/// Values whose `apiVersion` and `kind` are not those of a resource kind, or of a resource kind whose API group version is not enabled,")?;
+		writeln!(file, "/// deserialize as [`Resource::Unknown`](#variant.Unknown).")?;
+		writeln!(file, "#[derive(Clone, Debug, PartialEq)]")?;
+		writeln!(file, "pub enum Resource {{")?;
+		for (variant_name, type_name, api_version, kind, feature) in &variants {
+			writeln!(file, "    /// `{}` `{}`", api_version, kind)?;
+			if let Some(feature) = feature {
+				writeln!(file, r#"    #[cfg(feature = "{}")]"#, feature)?;
+			}
+			writeln!(file, "    {}({}),", variant_name, type_name)?;
+			writeln!(file)?;
+		}
+		writeln!(file, "    /// A value that is not of any enabled resource kind.")?;
+		writeln!(file, "    Unknown(::serde_json::Value),")?;
+		writeln!(file, "}}")?;
+		writeln!(file)?;
+		writeln!(file, "impl<'de> ::serde::Deserialize<'de> for Resource {{")?;
+		writeln!(file, "    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {{")?;
+		writeln!(file, "        ::tagged::deserialize(deserializer)")?;
+		writeln!(file, "    }}")?;
+		writeln!(file, "}}")?;
+		writeln!(file)?;
+		writeln!(file, "impl<'de> ::tagged::Tagged<'de> for Resource {{")?;
+		writeln!(file, r#"    const TAGS: &'static [&'static str] = &["apiVersion", "kind"];"#)?;
+		writeln!(file)?;
+		writeln!(file, "    fn deserialize_tagged<D>(tags: &[Option<String>], deserializer: D) -> Result<Self, D::Error> where D: ::serde::Deserializer<'de> {{")?;
+		writeln!(file, "        Ok(match (tags[0].as_ref().map(String::as_str), tags[1].as_ref().map(String::as_str)) {{")?;
+		for (variant_name, _, api_version, kind, feature) in &variants {
+			if let Some(feature) = feature {
+				writeln!(file, r#"            #[cfg(feature = "{}")]"#, feature)?;
+			}
+			writeln!(file,
+				r#"            (Some("{}"), Some("{}")) => Resource::{}(::serde::Deserialize::deserialize(deserializer)?),"#,
+				api_version, kind, variant_name)?;
+		}
+		writeln!(file, "            _ => Resource::Unknown(::serde::Deserialize::deserialize(deserializer)?),")?;
+		writeln!(file, "        }})")?;
+		writeln!(file, "    }}")?;
+		writeln!(file, "}}")?;
+		writeln!(file)?;
+		writeln!(file, "impl ::serde::Serialize for Resource {{")?;
+		writeln!(file, "    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::serde::Serializer {{")?;
+		writeln!(file, "        match self {{")?;
+		for (variant_name, _, _, _, feature) in &variants {
+			if let Some(feature) = feature {
+				writeln!(file, r#"            #[cfg(feature = "{}")]"#, feature)?;
+			}
+			writeln!(file, "            Resource::{}(value) => value.serialize(serializer),", variant_name)?;
+		}
+		writeln!(file, "            Resource::Unknown(value) => value.serialize(serializer),")?;
+		writeln!(file, "        }}")?;
+		writeln!(file, "    }}")?;
+		writeln!(file, "}}")?;
+	}
+
 	{
 		let mut mod_root_file = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(out_dir.join("mod.rs"))?);
 
@@ -619,7 +1182,7 @@ fn run(supported_version: supported_version::SupportedVersion, out_dir_base: &st
 
 	info!("");
 
-	Ok(())
+	Ok(features)
 }
 
 fn can_be_default(kind: &swagger20::SchemaKind, spec: &swagger20::Spec) -> Result<bool, Error> {
@@ -653,10 +1216,34 @@ fn can_be_default(kind: &swagger20::SchemaKind, spec: &swagger20::Spec) -> Resul
 	}
 }
 
+/// Collects the definitions that the given schema refers to.
+fn collect_refs<'a>(kind: &'a swagger20::SchemaKind, refs: &mut std::collections::BTreeSet<&'a str>) {
+	match kind {
+		swagger20::SchemaKind::Properties(properties) =>
+			for (schema, _) in properties.values() {
+				collect_refs(&schema.kind, refs);
+			},
+
+		swagger20::SchemaKind::Ref(ref_path) => {
+			refs.insert(ref_path);
+		},
+
+		swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) => collect_refs(&items.kind, refs),
+
+		swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) => collect_refs(&additional_properties.kind, refs),
+
+		swagger20::SchemaKind::Ty(_) => (),
+	}
+}
+
+/// Creates the file for the given definition, along with any parent modules that don't exist yet.
+///
+/// If `feature` is set, the module that contains the file is only compiled when that cargo feature is enabled.
 fn create_file_for_type(
 	definition_path: &swagger20::DefinitionPath,
 	out_dir: &std::path::Path,
 	replace_namespaces: &[(&[std::borrow::Cow<'static, str>], &[std::borrow::Cow<'static, str>])],
+	feature: Option<&str>,
 ) -> Result<(std::io::BufWriter<std::fs::File>, String, swagger20::RefPath), Error> {
 	use std::io::Write;
 
@@ -664,7 +1251,7 @@ fn create_file_for_type(
 
 	let mut current = out_dir.to_owned();
 
-	for part in parts.iter().rev().skip(1).rev() {
+	for (i, part) in parts.iter().enumerate().rev().skip(1).rev() {
 		trace!("Current directory: {}", current.display());
 
 		let mod_name = get_rust_ident(part);
@@ -682,6 +1269,9 @@ fn create_file_for_type(
 			if append_newline {
 				writeln!(parent_mod_rs)?;
 			}
+			if let (Some(feature), true) = (feature, i == parts.len() - 2) {
+				writeln!(parent_mod_rs, r#"#[cfg(feature = "{}")]"#, feature)?;
+			}
 			writeln!(parent_mod_rs, "pub mod {};", mod_name)?;
 
 			trace!("    OK");
@@ -712,6 +1302,39 @@ fn create_file_for_type(
 	Ok((file, type_name, ref_path))
 }
 
+/// Returns the API group and version that the given definition belongs to, like `("apps", "v1")` for `io.k8s.api.apps.v1.Deployment`,
+/// or `None` if the definition is not in an API group, like the types under `io.k8s.apimachinery`.
+fn get_api_group_version(definition_path: &str) -> Option<(&str, &str)> {
+	let (group, rest) =
+		if definition_path.starts_with("io.k8s.kubernetes.pkg.api.") {
+			("core", &definition_path["io.k8s.kubernetes.pkg.api.".len()..])
+		}
+		else {
+			let rest =
+				[
+					"io.k8s.api.",
+					"io.k8s.apiextensions-apiserver.pkg.apis.",
+					"io.k8s.kube-aggregator.pkg.apis.",
+					"io.k8s.kubernetes.pkg.apis.",
+				].iter()
+				.find(|prefix| definition_path.starts_with(*prefix))
+				.map(|prefix| &definition_path[prefix.len()..])?;
+			let mut parts = rest.splitn(2, '.');
+			let group = parts.next()?;
+			(group, parts.next()?)
+		};
+
+	let version = rest.split('.').next()?;
+
+	Some((group, version))
+}
+
+/// Returns the cargo feature of the API group version that the given definition belongs to, like `v1_12-apps-v1`.
+fn get_api_group_version_feature(definition_path: &str, mod_root: &str) -> Option<String> {
+	let (group, version) = get_api_group_version(definition_path)?;
+	Some(format!("{}-{}-{}", mod_root, group, version))
+}
+
 fn get_comment_text<'a>(s: &'a str, indent: &'a str) -> impl Iterator<Item = std::borrow::Cow<'static, str>> + 'a {
 	s.lines().map(move |line|
 		if line.is_empty() {
@@ -724,6 +1347,93 @@ fn get_comment_text<'a>(s: &'a str, indent: &'a str) -> impl Iterator<Item = std
 		})
 }
 
+/// Returns the closure to pass to `map` for a conversion expression of `value`.
+fn get_conversion_closure(expr: &str) -> std::borrow::Cow<'static, str> {
+	if expr == "value.into()" {
+		"Into::into".into()
+	}
+	else {
+		format!("|value| {}", expr).into()
+	}
+}
+
+/// Returns an expression that converts `value` of the type of `kind1` into the structurally identical type of `kind2`,
+/// or `None` if both are the same type and `value` can be used as is.
+fn get_conversion_expr(kind1: &swagger20::SchemaKind, kind2: &swagger20::SchemaKind, value: &str) -> Option<String> {
+	match (kind1, kind2) {
+		(swagger20::SchemaKind::Ref(ref_path1), swagger20::SchemaKind::Ref(ref_path2)) if ref_path1.0 != ref_path2.0 =>
+			Some(format!("{}.into()", value)),
+
+		(swagger20::SchemaKind::Ty(swagger20::Type::Array { items: items1 }), swagger20::SchemaKind::Ty(swagger20::Type::Array { items: items2 })) =>
+			get_conversion_expr(&items1.kind, &items2.kind, "value")
+			.map(|expr| format!("{}.into_iter().map({}).collect()", value, get_conversion_closure(&expr))),
+
+		(
+			swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties: additional_properties1 }),
+			swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties: additional_properties2 }),
+		) =>
+			get_conversion_expr(&additional_properties1.kind, &additional_properties2.kind, "value")
+			.map(|expr| format!("{}.into_iter().map(|(key, value)| (key, {})).collect()", value, expr)),
+
+		_ => None,
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Conversion {
+	/// The definitions are structurally identical, so the conversion is a field-by-field `From` impl.
+	From,
+
+	/// The definitions differ, so the conversion is a `TryFrom` impl that round-trips through serde and fails if any fields would be dropped.
+	TryFrom,
+}
+
+/// Finds the conversions between resource kinds that have the same type name in different group-versions, like `apps/v1` and `extensions/v1beta1` `Deployment`.
+///
+/// Returns a map of the target definition path to the source definition path to the conversion. This includes the `From` impls of
+/// the nested types that structurally identical kinds need for their field-by-field conversion.
+fn get_conversions<'a>(
+	spec: &'a swagger20::Spec,
+	api_versions: &std::collections::BTreeMap<&'a str, String>,
+	replace_namespaces: &[(&[std::borrow::Cow<'static, str>], &[std::borrow::Cow<'static, str>])],
+	mod_root: &str,
+) -> Result<std::collections::BTreeMap<&'a str, std::collections::BTreeMap<&'a str, Conversion>>, Error> {
+	let mut definition_paths_by_type_name: std::collections::BTreeMap<_, Vec<_>> = Default::default();
+	for definition_path in api_versions.keys() {
+		let type_name = definition_path.rsplit('.').next().ok_or_else(|| format!("path for {} has no parts", definition_path))?;
+		definition_paths_by_type_name.entry(type_name).or_insert_with(Default::default).push(*definition_path);
+	}
+
+	let mut result: std::collections::BTreeMap<_, std::collections::BTreeMap<_, _>> = Default::default();
+
+	for definition_paths in definition_paths_by_type_name.values() {
+		for &source in definition_paths {
+			for &target in definition_paths {
+				if source == target {
+					continue;
+				}
+
+				let source_definition = &spec.definitions[&swagger20::DefinitionPath(source.to_string())];
+				let target_definition = &spec.definitions[&swagger20::DefinitionPath(target.to_string())];
+
+				let mut nested = Default::default();
+				if is_structurally_identical(&source_definition.kind, &target_definition.kind, spec, &mut nested, replace_namespaces, mod_root)? {
+					for (nested_source, nested_target) in nested {
+						result.entry(nested_target).or_insert_with(Default::default).insert(nested_source, Conversion::From);
+					}
+
+					result.entry(target).or_insert_with(Default::default).insert(source, Conversion::From);
+				}
+				else {
+					result.entry(target).or_insert_with(Default::default).insert(source, Conversion::TryFrom);
+				}
+			}
+		}
+	}
+
+	Ok(result)
+}
+
 fn get_fully_qualified_type_name(
 	ref_path: &swagger20::RefPath,
 	replace_namespaces: &[(&[std::borrow::Cow<'static, str>], &[std::borrow::Cow<'static, str>])],
@@ -794,6 +1504,21 @@ fn get_rust_ident(name: &str) -> std::borrow::Cow<'static, str> {
 	result.into()
 }
 
+/// Converts a field path like `spec.nodeName` into an enum variant name like `SpecNodeName`
+fn get_rust_variant_name(path: &str) -> String {
+	let mut result = String::new();
+
+	for part in path.split('.') {
+		let mut chars = part.chars();
+		if let Some(first) = chars.next() {
+			result.extend(first.to_uppercase());
+			result.push_str(chars.as_str());
+		}
+	}
+
+	result
+}
+
 fn get_rust_borrow_type(
 	schema_kind: &swagger20::SchemaKind,
 	replace_namespaces: &[(&[std::borrow::Cow<'static, str>], &[std::borrow::Cow<'static, str>])],
@@ -866,6 +1591,76 @@ fn get_rust_type(
 	}
 }
 
+/// Returns whether the two schemas have the same properties with the same types, where refs to different definitions with the same type name
+/// are compared recursively.
+///
+/// `nested` collects the pairs of different definitions that were found to be identical, so that they can be given `From` impls too.
+fn is_structurally_identical<'a>(
+	kind1: &'a swagger20::SchemaKind,
+	kind2: &'a swagger20::SchemaKind,
+	spec: &'a swagger20::Spec,
+	nested: &mut std::collections::BTreeSet<(&'a str, &'a str)>,
+	replace_namespaces: &[(&[std::borrow::Cow<'static, str>], &[std::borrow::Cow<'static, str>])],
+	mod_root: &str,
+) -> Result<bool, Error> {
+	match (kind1, kind2) {
+		(swagger20::SchemaKind::Properties(properties1), swagger20::SchemaKind::Properties(properties2)) => {
+			if properties1.len() != properties2.len() {
+				return Ok(false);
+			}
+
+			for ((name1, (schema1, required1)), (name2, (schema2, required2))) in properties1.iter().zip(properties2) {
+				if name1 != name2 || required1 != required2 {
+					return Ok(false);
+				}
+
+				if !is_structurally_identical(&schema1.kind, &schema2.kind, spec, nested, replace_namespaces, mod_root)? {
+					return Ok(false);
+				}
+			}
+
+			Ok(true)
+		},
+
+		(swagger20::SchemaKind::Ref(ref_path1), swagger20::SchemaKind::Ref(ref_path2)) => {
+			if ref_path1.0 == ref_path2.0 {
+				return Ok(true);
+			}
+
+			if ref_path1.rsplit('.').next() != ref_path2.rsplit('.').next() {
+				return Ok(false);
+			}
+
+			if !nested.insert((&**ref_path1, &**ref_path2)) {
+				// Already being compared further up the stack
+				return Ok(true);
+			}
+
+			let definition1 =
+				spec.definitions.get(&swagger20::DefinitionPath(ref_path1.0.clone()))
+				.ok_or_else(|| format!("couldn't find target of ref path {}", ref_path1))?;
+			let definition2 =
+				spec.definitions.get(&swagger20::DefinitionPath(ref_path2.0.clone()))
+				.ok_or_else(|| format!("couldn't find target of ref path {}", ref_path2))?;
+			is_structurally_identical(&definition1.kind, &definition2.kind, spec, nested, replace_namespaces, mod_root)
+		},
+
+		(swagger20::SchemaKind::Ty(swagger20::Type::Array { items: items1 }), swagger20::SchemaKind::Ty(swagger20::Type::Array { items: items2 })) =>
+			is_structurally_identical(&items1.kind, &items2.kind, spec, nested, replace_namespaces, mod_root),
+
+		(
+			swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties: additional_properties1 }),
+			swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties: additional_properties2 }),
+		) =>
+			is_structurally_identical(&additional_properties1.kind, &additional_properties2.kind, spec, nested, replace_namespaces, mod_root),
+
+		(swagger20::SchemaKind::Ty(_), swagger20::SchemaKind::Ty(_)) =>
+			Ok(get_rust_type(kind1, replace_namespaces, mod_root)? == get_rust_type(kind2, replace_namespaces, mod_root)?),
+
+		_ => Ok(false),
+	}
+}
+
 fn replace_namespace<'a, I>(
 	parts: I,
 	replace_namespaces: &[(&[std::borrow::Cow<'static, str>], &[std::borrow::Cow<'static, str>])],
@@ -885,6 +1680,51 @@ fn replace_namespace<'a, I>(
 	parts
 }
 
+/// Replaces the version features in the `[features]` section of the given `Cargo.toml` with the features of the given versions.
+///
+/// Each version has a feature for each of its API group versions, like `v1_12-apps-v1`, which depends on the features of the API group versions
+/// that it refers to. The version's own feature, like `v1_12`, enables all of them.
+fn write_features(
+	cargo_toml_path: &std::path::Path,
+	features: &[(&str, std::collections::BTreeMap<String, std::collections::BTreeSet<String>>)],
+) -> Result<(), Error> {
+	use std::fmt::Write;
+
+	let cargo_toml = std::fs::read_to_string(cargo_toml_path)?;
+
+	// The version features start at the first `v1_*` feature in the `[features]` section and end at the next section
+	let features_start = cargo_toml.find("[features]\n").ok_or_else(|| format!("{} has no [features] section", cargo_toml_path.display()))?;
+	let start = cargo_toml[features_start..].find("\nv1_").map(|start| features_start + start + 1).ok_or_else(|| format!("{} has no version features", cargo_toml_path.display()))?;
+	let end = cargo_toml[start..].find("\n[").map_or(cargo_toml.len(), |end| start + end + 1);
+
+	let mut result = cargo_toml[..start].to_string();
+
+	for (i, (mod_root, features)) in features.iter().enumerate() {
+		if i > 0 {
+			writeln!(result)?;
+		}
+
+		writeln!(result, "{} = [", mod_root)?;
+		for feature in features.keys() {
+			writeln!(result, r#"    "{}","#, feature)?;
+		}
+		writeln!(result, "]")?;
+
+		for (feature, dependencies) in features {
+			let dependencies: Vec<_> = dependencies.iter().map(|dependency| format!("{:?}", dependency)).collect();
+			writeln!(result, "{} = [{}]", feature, dependencies.join(", "))?;
+		}
+	}
+
+	writeln!(result)?;
+
+	result.push_str(&cargo_toml[end..]);
+
+	std::fs::write(cargo_toml_path, result)?;
+
+	Ok(())
+}
+
 fn write_operation(
 	file: &mut std::io::BufWriter<std::fs::File>,
 	operation: &swagger20::Operation,
@@ -952,6 +1792,7 @@ fn write_operation(
 
 	writeln!(file)?;
 
+	writeln!(file, r#"#[cfg(feature = "api")]"#)?;
 	if let Some(type_name) = type_name {
 		writeln!(file, "impl {} {{", type_name)?;
 	}
@@ -1140,6 +1981,7 @@ fn write_operation(
 		writeln!(file, "/// Parses the HTTP response of [`{}`](./fn.{}.html)", operation_fn_name, operation_fn_name)?;
 	}
 
+	writeln!(file, r#"#[cfg(feature = "api")]"#)?;
 	writeln!(file, "#[derive(Debug)]")?;
 	writeln!(file, "pub enum {} {{", operation_result_name)?;
 
@@ -1167,6 +2009,7 @@ fn write_operation(
 	writeln!(file, "}}")?;
 	writeln!(file)?;
 
+	writeln!(file, r#"#[cfg(feature = "api")]"#)?;
 	writeln!(file, "impl ::Response for {} {{", operation_result_name)?;
 
 	let uses_buf = operation_responses.iter().any(|&(_, _, schema, _)| schema.is_some());
@@ -1205,34 +2048,22 @@ fn write_operation(
 				},
 
 				swagger20::SchemaKind::Ref(_) => if is_watch {
-					writeln!(file, "                let mut deserializer = ::serde_json::Deserializer::from_slice(buf).into_iter();")?;
-					writeln!(file, "                let (result, byte_offset) = match deserializer.next() {{")?;
-					writeln!(file, "                    Some(Ok(value)) => (value, deserializer.byte_offset()),")?;
-					writeln!(file, "                    Some(Err(ref err)) if err.is_eof() => return Err(::ResponseError::NeedMoreData),")?;
-					writeln!(file, "                    Some(Err(err)) => return Err(::ResponseError::Json(err)),")?;
+					writeln!(file, "                let line_len = match buf.iter().position(|&b| b == b'\\n') {{")?;
+					writeln!(file, "                    Some(newline) => newline + 1,")?;
 					writeln!(file, "                    None => return Err(::ResponseError::NeedMoreData),")?;
 					writeln!(file, "                }};")?;
-					writeln!(file, "                Ok(({}::{}(result), byte_offset))", operation_result_name, variant_name)?;
+					writeln!(file, "                let result = ::serde_json::from_slice(&buf[..line_len]).map_err(::ResponseError::Json)?;")?;
+					writeln!(file, "                Ok(({}::{}(result), line_len))", operation_result_name, variant_name)?;
 				}
 				else if is_delete_ok_status {
-					writeln!(file, "                let result: ::serde_json::Map<String, ::serde_json::Value> = match ::serde_json::from_slice(buf) {{")?;
+					writeln!(file, "                let result: ::tagged::StatusOr<_, _> = match ::serde_json::from_slice(buf) {{")?;
 					writeln!(file, "                    Ok(value) => value,")?;
 					writeln!(file, "                    Err(ref err) if err.is_eof() => return Err(::ResponseError::NeedMoreData),")?;
 					writeln!(file, "                    Err(err) => return Err(::ResponseError::Json(err)),")?;
 					writeln!(file, "                }};")?;
-					writeln!(file, r#"                let is_status = match result.get("kind") {{"#)?;
-					writeln!(file, r#"                    Some(::serde_json::Value::String(s)) if s == "Status" => true,"#)?;
-					writeln!(file, "                    _ => false,")?;
-					writeln!(file, "                }};")?;
-					writeln!(file, "                if is_status {{")?;
-					writeln!(file, "                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));")?;
-					writeln!(file, "                    let result = result.map_err(::ResponseError::Json)?;")?;
-					writeln!(file, "                    Ok(({}::{}Status(result), buf.len()))", operation_result_name, variant_name)?;
-					writeln!(file, "                }}")?;
-					writeln!(file, "                else {{")?;
-					writeln!(file, "                    let result = ::serde::Deserialize::deserialize(::serde_json::Value::Object(result));")?;
-					writeln!(file, "                    let result = result.map_err(::ResponseError::Json)?;")?;
-					writeln!(file, "                    Ok(({}::{}Value(result), buf.len()))", operation_result_name, variant_name)?;
+					writeln!(file, "                match result {{")?;
+					writeln!(file, "                    ::tagged::StatusOr::Status(result) => Ok(({}::{}Status(result), buf.len())),", operation_result_name, variant_name)?;
+					writeln!(file, "                    ::tagged::StatusOr::Value(result) => Ok(({}::{}Value(result), buf.len())),", operation_result_name, variant_name)?;
 					writeln!(file, "                }}")?;
 				}
 				else {
@@ -1256,7 +2087,40 @@ fn write_operation(
 	writeln!(file, "            _ => Ok(({}::Other, 0)),", operation_result_name)?;
 	writeln!(file, "        }}")?;
 	writeln!(file, "    }}")?;
+
+	let is_string = operation_responses.iter().any(|&(_, _, schema, _)| match schema {
+		Some(swagger20::Schema { kind: swagger20::SchemaKind::Ty(swagger20::Type::String { .. }), .. }) => true,
+		_ => false,
+	});
+	if uses_buf && !is_string {
+		writeln!(file)?;
+		writeln!(file, "    fn framing() -> ::ResponseFraming {{")?;
+		writeln!(file, "        ::ResponseFraming::{}", if is_watch { "JsonLines" } else { "JsonValue" })?;
+		writeln!(file, "    }}")?;
+	}
+
 	writeln!(file, "}}")?;
 
+	if let Some(swagger20::KubernetesAction::List) = operation.kubernetes_action {
+		let list_schema =
+			operation_responses.iter()
+			.find(|&&(http_status_code, _, _, _)| http_status_code == "OK")
+			.and_then(|&(_, _, schema, _)| schema);
+		if let Some(swagger20::Schema { kind: swagger20::SchemaKind::Ref(list_ref_path), .. }) = list_schema {
+			writeln!(file)?;
+			writeln!(file, r#"#[cfg(feature = "api")]"#)?;
+			writeln!(file, "impl ::ListResponse for {} {{", operation_result_name)?;
+			writeln!(file, "    type List = {};", get_fully_qualified_type_name(list_ref_path, replace_namespaces, mod_root)?)?;
+			writeln!(file)?;
+			writeln!(file, "    fn into_list(self) -> Option<Self::List> {{")?;
+			writeln!(file, "        match self {{")?;
+			writeln!(file, "            {}::Ok(list) => Some(list),", operation_result_name)?;
+			writeln!(file, "            _ => None,")?;
+			writeln!(file, "        }}")?;
+			writeln!(file, "    }}")?;
+			writeln!(file, "}}")?;
+		}
+	}
+
 	Ok(())
 }
@@ -133,4 +133,66 @@ impl SupportedVersion {
 
 		Ok(())
 	}
+
+	/// The fields of the given kind that can be used in field selectors, in addition to `metadata.name` and `metadata.namespace`.
+	///
+	/// These are not part of the spec, so they're transcribed from the selectable fields that the API server registers for each kind.
+	pub(crate) fn selectable_fields(self, group: &str, kind: &str) -> &'static [&'static str] {
+		match (group, kind) {
+			("", "Event") => &[
+				"involvedObject.apiVersion",
+				"involvedObject.fieldPath",
+				"involvedObject.kind",
+				"involvedObject.name",
+				"involvedObject.namespace",
+				"involvedObject.resourceVersion",
+				"involvedObject.uid",
+				"reason",
+				"source",
+				"type",
+			],
+
+			("", "Namespace") => &["status.phase"],
+
+			("", "Node") => &["spec.unschedulable"],
+
+			("", "Pod") => match self {
+				SupportedVersion::V1_7 | SupportedVersion::V1_8 => &[
+					"spec.nodeName",
+					"spec.restartPolicy",
+					"status.phase",
+					"status.podIP",
+				],
+
+				SupportedVersion::V1_9 | SupportedVersion::V1_10 | SupportedVersion::V1_11 => &[
+					"spec.nodeName",
+					"spec.restartPolicy",
+					"spec.schedulerName",
+					"spec.serviceAccountName",
+					"status.phase",
+					"status.podIP",
+				],
+
+				SupportedVersion::V1_12 => &[
+					"spec.nodeName",
+					"spec.restartPolicy",
+					"spec.schedulerName",
+					"spec.serviceAccountName",
+					"status.nominatedNodeName",
+					"status.phase",
+					"status.podIP",
+				],
+			},
+
+			("", "ReplicationController") |
+			("apps", "ReplicaSet") |
+			("extensions", "ReplicaSet") => &["status.replicas"],
+
+			("", "Secret") => &["type"],
+
+			("batch", "Job") => &["status.successful"],
+
+			_ => &[],
+		}
+	}
 }